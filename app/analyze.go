@@ -0,0 +1,91 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strconv"
+
+	"github.com/olekukonko/tablewriter"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/logical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// analyzeFile reads the whole file under the given path, which has to be the file of one of the configured data sources,
+// and collects its statistics. They get printed, and cached to be used when planning later queries.
+func (app *App) analyzeFile(ctx context.Context, stateStorage storage.Storage, path string) error {
+	dataSource, err := app.fileDataSource(path)
+	if err != nil {
+		return err
+	}
+	// The file info is taken up front, so that modifying the file while it's being read invalidates the statistics.
+	info, err := os.Stat(path)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get file info")
+	}
+
+	collector := newStatisticsCollector()
+	analyzer := *app
+	analyzer.outputSinkFn = ChangesOutputSinkFn(collector.AddRecord)
+	analyzer.describe = false
+	analyzer.resultCache = nil
+	if err := analyzer.RunPlan(ctx, stateStorage, logical.NewDataSource(dataSource.Name, "analyzed"), &logical.OutputOptions{}, nil); err != nil {
+		return errors.Wrapf(err, "couldn't read data source %s", dataSource.Name)
+	}
+	stats := collector.Statistics()
+
+	statisticsDirectory, err := defaultStatisticsDirectory()
+	if err != nil {
+		return err
+	}
+	if err := saveFileStatistics(statisticsDirectory, path, info, stats); err != nil {
+		return errors.Wrap(err, "couldn't cache statistics")
+	}
+
+	printFileStatistics(os.Stdout, stats)
+	return nil
+}
+
+// fileDataSource returns the configuration of the data source reading the file under the given path.
+func (app *App) fileDataSource(path string) (config.DataSourceConfig, error) {
+	absPath, err := filepath.Abs(path)
+	if err != nil {
+		return config.DataSourceConfig{}, errors.Wrap(err, "couldn't get absolute path of file")
+	}
+	for _, dataSource := range app.cfg.DataSources {
+		dataSourcePath, ok := dataSource.Config["path"].(string)
+		if !ok {
+			continue
+		}
+		if absDataSourcePath, err := filepath.Abs(dataSourcePath); err == nil && absDataSourcePath == absPath {
+			return dataSource, nil
+		}
+	}
+	return config.DataSourceConfig{}, errors.Errorf("no data source reads file %s, add it to the configuration or using --table", path)
+}
+
+func printFileStatistics(w io.Writer, stats *FileStatistics) {
+	table := tablewriter.NewWriter(w)
+	table.SetColWidth(64)
+	table.SetHeader([]string{"column", "nulls", "distinct", "min", "max"})
+	table.SetAutoFormatHeaders(false)
+
+	for _, column := range stats.Columns {
+		table.Append([]string{
+			column.Name,
+			strconv.FormatInt(column.NullCount, 10),
+			strconv.FormatInt(column.DistinctCount, 10),
+			column.Min.Show(),
+			column.Max.Show(),
+		})
+	}
+
+	table.Render()
+
+	fmt.Fprintf(w, "rows: %d\n", stats.RowCount)
+}
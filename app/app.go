@@ -3,8 +3,11 @@ package app
 import (
 	"context"
 	"fmt"
+	"log"
 	"os"
+	"runtime/trace"
 	"strings"
+	"sync"
 
 	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/config"
@@ -12,6 +15,9 @@ import (
 	"github.com/cube2222/octosql/graph"
 	"github.com/cube2222/octosql/logical"
 	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/file"
+	"github.com/cube2222/octosql/output/streaming"
+	"github.com/cube2222/octosql/parser"
 	"github.com/cube2222/octosql/physical"
 	"github.com/cube2222/octosql/physical/optimizer"
 	"github.com/cube2222/octosql/storage"
@@ -21,11 +27,23 @@ import (
 
 type OutputSinkFn func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *OutputOptions) (execution.IntermediateRecordStore, output.Printer)
 
+// ChangesOutputSinkFn creates an output which calls onRecord with every record sent by the query, as soon as it's available.
+// Records which change an earlier result are sent as a retraction of the old record followed by the new one,
+// so this can be used to incrementally maintain the result of a query over streaming sources.
+func ChangesOutputSinkFn(onRecord func(record *execution.Record)) OutputSinkFn {
+	return func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+		sink := streaming.NewInstantStreamOutput(streamID)
+		return sink, streaming.NewStreamPrinter(stateStorage, sink, onRecord)
+	}
+}
+
 type OutputOptions struct {
 	OrderByExpressions []execution.Expression
 	OrderByDirections  []execution.OrderDirection
 	Limit              *int
 	Offset             *int
+	OutputFile         string
+	RetractionFree     bool
 }
 
 func EvaluateOutputOptions(ctx context.Context, variables octosql.Variables, options *execution.OutputOptions) (*OutputOptions, error) {
@@ -49,20 +67,47 @@ func EvaluateOutputOptions(ctx context.Context, variables octosql.Variables, opt
 		offset = &offsetInt
 	}
 
+	// The outputs evaluate the order by expressions with only the fields of each record,
+	// but the expressions may also reference constants, parameters or values bound with LET.
+	orderByExpressions := make([]execution.Expression, len(options.OrderByExpressions))
+	for i := range options.OrderByExpressions {
+		orderByExpressions[i] = &withVariables{
+			expression: options.OrderByExpressions[i],
+			variables:  variables,
+		}
+	}
+
 	return &OutputOptions{
-		OrderByExpressions: options.OrderByExpressions,
+		OrderByExpressions: orderByExpressions,
 		OrderByDirections:  options.OrderByDirections,
 		Limit:              limit,
 		Offset:             offset,
+		OutputFile:         options.OutputFile,
 	}, nil
 }
 
+// withVariables evaluates the expression with the given variables added to the ones it's evaluated with.
+type withVariables struct {
+	expression execution.Expression
+	variables  octosql.Variables
+}
+
+func (e *withVariables) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	variables, err := variables.MergeWith(e.variables)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't merge variables with those of the query")
+	}
+	return e.expression.ExpressionValue(ctx, variables)
+}
+
 type App struct {
 	telemetryInfo        TelemetryInfo
 	cfg                  *config.Config
 	dataSourceRepository *physical.DataSourceRepository
 	outputSinkFn         OutputSinkFn
 	describe             bool
+	resultCache          *ResultCache
+	maxMemory            int64
 }
 
 func NewApp(cfg *config.Config, telemetryInfo TelemetryInfo, dataSourceRepository *physical.DataSourceRepository, outputSinkFn OutputSinkFn, describe bool) *App {
@@ -75,19 +120,169 @@ func NewApp(cfg *config.Config, telemetryInfo TelemetryInfo, dataSourceRepositor
 	}
 }
 
-func (app *App) RunPlan(ctx context.Context, stateStorage storage.Storage, plan logical.Node, outputOptions *logical.OutputOptions) error {
-	physicalPlanCreator := logical.NewPhysicalPlanCreator(app.dataSourceRepository, app.cfg.Physical)
+// WithMaxMemory returns a copy of the app in which each run of a query may keep at most the given number of bytes
+// of records in memory. A limit which isn't positive leaves the memory unlimited.
+func (app *App) WithMaxMemory(maxMemory int64) *App {
+	out := *app
+	out.maxMemory = maxMemory
+	return &out
+}
+
+// PreparedPlan is a query which has already been planned and materialized.
+// It can be run multiple times, possibly with different parameters, without planning it again.
+type PreparedPlan struct {
+	phys              physical.Node
+	exec              execution.Node
+	variables         octosql.Variables
+	parameters        []octosql.VariableName
+	execOutputOptions *execution.OutputOptions
+	explainAnalyze    bool
+	retractionFree    bool
+}
+
+var runsPrefix = []byte("$runs$")
+
+// runsMutex guards the count of runs kept in the storage, so that plans run concurrently on the same storage,
+// like the ones of different server connections, never share their streams.
+var runsMutex sync.Mutex
+
+// nextRun returns the number of the next run on the storage. The count is kept in the storage itself,
+// so that runs on a persisted storage directory don't reuse the streams, and the state, of earlier processes.
+func nextRun(stateStorage storage.Storage) (int, error) {
+	runsMutex.Lock()
+	defer runsMutex.Unlock()
+
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	runsState := storage.NewValueState(tx.WithPrefix(runsPrefix))
+
+	var runs octosql.Value
+	err := runsState.Get(&runs)
+	if err == storage.ErrNotFound {
+		runs = octosql.MakeInt(0)
+	} else if err != nil {
+		return 0, errors.Wrap(err, "couldn't get count of runs")
+	}
+
+	runs = octosql.MakeInt(runs.AsInt() + 1)
+	if err := runsState.Set(&runs); err != nil {
+		return 0, errors.Wrap(err, "couldn't set count of runs")
+	}
+	if err := tx.Commit(); err != nil {
+		return 0, errors.Wrap(err, "couldn't commit count of runs")
+	}
+
+	return runs.AsInt(), nil
+}
+
+// Parameters returns the names of the parameters used in the query.
+func (prepared *PreparedPlan) Parameters() []string {
+	out := make([]string, len(prepared.parameters))
+	for i := range prepared.parameters {
+		out[i] = prepared.parameters[i].ParameterName()
+	}
+	return out
+}
+
+func (app *App) RunPlan(ctx context.Context, stateStorage storage.Storage, plan logical.Node, outputOptions *logical.OutputOptions, parameters map[string]octosql.Value) error {
+	ctx, task := trace.NewTask(ctx, "query")
+	defer task.End()
+
+	if outputOptions.AnalyzeFile != "" {
+		return app.analyzeFile(ctx, stateStorage, outputOptions.AnalyzeFile)
+	}
+
+	phys, variables, physicalOutputOptions, err := app.createPhysicalPlan(ctx, plan, outputOptions)
+	if err != nil {
+		return err
+	}
+
+	if app.describe {
+		fmt.Print(graph.Show(phys.Visualize()).String())
+		return nil
+	}
+
+	if outputOptions.Explain && !outputOptions.ExplainAnalyze {
+		fmt.Printf("Logical plan:\n%s\nPhysical plan:\n%s", graph.ShowTree(plan.Visualize()), graph.ShowTree(phys.Visualize()))
+		return nil
+	}
+
+	prepared, err := app.materialize(ctx, stateStorage, phys, variables, physicalOutputOptions, outputOptions.ExplainAnalyze)
+	if err != nil {
+		return err
+	}
+
+	if app.resultCache != nil {
+		return app.runWithResultCache(ctx, stateStorage, prepared, parameters)
+	}
+	return app.RunPrepared(ctx, stateStorage, prepared, parameters)
+}
+
+// RunQuery parses the query and runs it with the given parameter values.
+func (app *App) RunQuery(ctx context.Context, stateStorage storage.Storage, query string, parameters map[string]octosql.Value) error {
+	plan, outputOptions, err := parser.ParseQuery(query)
+	if err != nil {
+		return errors.Wrap(err, "couldn't parse query")
+	}
+
+	return app.RunPlan(ctx, stateStorage, plan, outputOptions, parameters)
+}
+
+// Prepare plans and materializes the query, so that it can later be run using RunPrepared.
+func (app *App) Prepare(ctx context.Context, stateStorage storage.Storage, plan logical.Node, outputOptions *logical.OutputOptions) (*PreparedPlan, error) {
+	// Running the prepared plan would run the whole query, instead of only printing its plan.
+	if outputOptions.Explain && !outputOptions.ExplainAnalyze {
+		return nil, errors.New("EXPLAIN statements can't be prepared, they have to be run directly")
+	}
+
+	phys, variables, physicalOutputOptions, err := app.createPhysicalPlan(ctx, plan, outputOptions)
+	if err != nil {
+		return nil, err
+	}
+
+	return app.materialize(ctx, stateStorage, phys, variables, physicalOutputOptions, outputOptions.ExplainAnalyze)
+}
+
+// CheckServable returns an error for the statements which mustn't be run for the clients of a server,
+// as they write files on its host or print to its standard output.
+func CheckServable(outputOptions *logical.OutputOptions) error {
+	switch {
+	case outputOptions.OutputFile != "":
+		return errors.New("CREATE TABLE statements aren't supported by the server, as they write files on its host")
+	case outputOptions.Explain:
+		return errors.New("EXPLAIN statements aren't supported by the server")
+	case outputOptions.AnalyzeFile != "":
+		return errors.New("ANALYZE statements aren't supported by the server")
+	}
+	return nil
+}
+
+func (app *App) createPhysicalPlan(ctx context.Context, plan logical.Node, outputOptions *logical.OutputOptions) (physical.Node, octosql.Variables, *physical.OutputOptions, error) {
+	defer trace.StartRegion(ctx, "planning").End()
+
+	if outputOptions.AnalyzeFile != "" {
+		return nil, nil, nil, errors.New("ANALYZE statements can't be prepared, they have to be run directly")
+	}
+
+	physicalPlanCreator := logical.NewPhysicalPlanCreator(app.dataSourceRepository, app.cfg.Physical).
+		WithStatistics(dataSourceStatistics(app.cfg.DataSources))
 	sourceNodes, variables, err := plan.Physical(ctx, physicalPlanCreator)
 	if err != nil {
-		return errors.Wrap(err, "couldn't create physical plan")
+		return nil, nil, nil, errors.Wrap(err, "couldn't create physical plan")
 	}
 	physicalOutputOptions, outputOptionsVariables, err := outputOptions.Physical(ctx, physicalPlanCreator)
 	if err != nil {
-		return errors.Wrap(err, "couldn't create physical output options")
+		return nil, nil, nil, errors.Wrap(err, "couldn't create physical output options")
 	}
 	variables, err = variables.MergeWith(outputOptionsVariables)
 	if err != nil {
-		return errors.Wrap(err, "couldn't merge variables with output options variables")
+		return nil, nil, nil, errors.Wrap(err, "couldn't merge variables with output options variables")
+	}
+	if physicalOutputOptions.OutputFile != "" {
+		// Check the output format up front, so that the query doesn't get started just to fail afterwards.
+		if _, err := file.FormatterForPath(physicalOutputOptions.OutputFile); err != nil {
+			return nil, nil, nil, errors.Wrap(err, "couldn't infer output file format")
+		}
 	}
 
 	// We only want one partition at the end, to print the output easily.
@@ -96,50 +291,231 @@ func (app *App) RunPlan(ctx context.Context, stateStorage storage.Storage, plan
 	// Only the first partition is there.
 	var phys physical.Node = shuffled[0]
 
+	// Without ordering any records satisfy the limit, so we can stop reading the sources once we have enough.
+	// This is only correct if none of those records can get retracted later.
+	if physicalOutputOptions.Limit != nil && len(physicalOutputOptions.OrderByExpressions) == 0 && isRetractionFree(ctx, phys) {
+		limit := physicalOutputOptions.Limit
+		if physicalOutputOptions.Offset != nil {
+			limit = physical.NewFunctionExpression("+", []physical.Expression{limit, physicalOutputOptions.Offset})
+		}
+		phys = physical.NewLimit(limit, pushLimitIntoSources(limit, phys))
+	}
+
+	// The bound expressions are evaluated once for the whole query, so they're kept at its root, above the final shuffle.
+	if letBindings := physicalPlanCreator.LetBindings(); len(letBindings.Names) > 0 {
+		phys = physical.NewLet(letBindings.Names, letBindings.Expressions, phys)
+	}
+
 	if strings.TrimSpace(os.Getenv("OCTOSQL_TELEMETRY")) != "0" {
 		RunTelemetry(ctx, app.telemetryInfo, app.cfg.DataSources, phys, physicalOutputOptions)
 	}
 
-	phys = optimizer.Optimize(ctx, optimizer.DefaultScenarios, phys)
+	trace.WithRegion(ctx, "optimization", func() {
+		phys = optimizer.Optimize(ctx, optimizer.DefaultScenarios, phys)
+	})
 
-	if app.describe {
-		fmt.Print(graph.Show(phys.Visualize()).String())
-		return nil
+	return phys, variables, physicalOutputOptions, nil
+}
+
+func (app *App) materialize(ctx context.Context, stateStorage storage.Storage, phys physical.Node, variables octosql.Variables, physicalOutputOptions *physical.OutputOptions, explainAnalyze bool) (*PreparedPlan, error) {
+	defer trace.StartRegion(ctx, "materialization").End()
+
+	retractionFree := isRetractionFree(ctx, phys)
+
+	if explainAnalyze || getMetricsFromContext(ctx) != nil {
+		// The Let at the root only evaluates the bindings, which RunPrepared does on its own, so only its source gets analyzed.
+		if let, ok := phys.(*physical.Let); ok {
+			phys = physical.NewLet(let.Names, let.Expressions, physical.AnalyzeAll(ctx, let.Source))
+		} else {
+			phys = physical.AnalyzeAll(ctx, phys)
+		}
 	}
 
 	matCtx := physical.NewMaterializationContext(app.cfg, stateStorage)
 	exec, err := phys.Materialize(ctx, matCtx)
 	if err != nil {
-		return errors.Wrap(err, "couldn't materialize the physical plan into an execution plan")
+		return nil, errors.Wrap(err, "couldn't materialize the physical plan into an execution plan")
 	}
 	execOutputOptions, err := physicalOutputOptions.Materialize(ctx, matCtx)
 	if err != nil {
-		return errors.Wrap(err, "couldn't materialize output options")
+		return nil, errors.Wrap(err, "couldn't materialize output options")
+	}
+
+	return &PreparedPlan{
+		phys:              phys,
+		exec:              exec,
+		variables:         variables,
+		parameters:        getParameters(ctx, phys, physicalOutputOptions),
+		execOutputOptions: execOutputOptions,
+		explainAnalyze:    explainAnalyze,
+		retractionFree:    retractionFree,
+	}, nil
+}
+
+// RunPrepared runs the prepared plan with the given parameter values.
+// All the parameters used in the query have to be given.
+func (app *App) RunPrepared(ctx context.Context, stateStorage storage.Storage, prepared *PreparedPlan, parameters map[string]octosql.Value) error {
+	parameterVariables := octosql.NoVariables()
+	for name, value := range parameters {
+		parameterVariables[octosql.NewParameterVariableName(name)] = value
+	}
+	for _, name := range prepared.parameters {
+		if _, ok := parameterVariables[name]; !ok {
+			return errors.Errorf("no value given for query parameter %v", name.ParameterName())
+		}
 	}
-	evalOutputOptions, err := EvaluateOutputOptions(ctx, variables, execOutputOptions)
+	variables, err := prepared.variables.MergeWith(parameterVariables)
+	if err != nil {
+		return errors.Wrap(err, "couldn't merge variables with parameters")
+	}
+
+	// Each run gets its own streams, memory pool and subquery results, so that none of the state of a previous run is reused.
+	ctx = execution.WithMemoryPool(ctx, execution.NewMemoryPool(app.maxMemory))
+	ctx = execution.WithSubqueryCache(ctx)
+
+	// The expressions bound with LET are evaluated here, once, so that the query and its output options get the same values.
+	exec := prepared.exec
+	if let, ok := exec.(*execution.Let); ok {
+		variables, err = let.Bind(ctx, variables)
+		if err != nil {
+			return errors.Wrap(err, "couldn't evaluate bound expressions")
+		}
+		exec = let.Source()
+	}
+
+	evalOutputOptions, err := EvaluateOutputOptions(ctx, variables, prepared.execOutputOptions)
 	if err != nil {
 		return errors.Wrap(err, "couldn't get output options")
 	}
+	evalOutputOptions.RetractionFree = prepared.retractionFree
+
+	if metrics := getMetricsFromContext(ctx); metrics != nil {
+		query := metrics.addPlan(ctx, prepared.phys)
+		defer metrics.removeQuery(query)
+	}
+
+	run, err := nextRun(stateStorage)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get run number")
+	}
+	rootStreamID := execution.NewStreamID(fmt.Sprintf("root_%d", run))
+	outStreamID := &execution.StreamID{Id: fmt.Sprintf("output_%d", run)}
 
-	stream, execOutput, err := execution.GetAndStartAllShuffles(ctx, stateStorage, execution.NewStreamID("root"), []execution.Node{exec}, variables)
+	stream, execOutput, err := execution.GetAndStartAllShuffles(ctx, stateStorage, rootStreamID, []execution.Node{exec}, variables)
 	if err != nil {
 		return errors.Wrap(err, "couldn't get record stream from execution plan")
 	}
 
-	outStreamID := &execution.StreamID{Id: "output"}
+	eventTimeField := prepared.phys.Metadata().EventTimeField()
+
+	var outputSink execution.IntermediateRecordStore
+	var printer output.Printer
+	if evalOutputOptions.OutputFile != "" {
+		outputSink, printer, err = file.NewOutput(
+			stateStorage,
+			outStreamID,
+			eventTimeField,
+			evalOutputOptions.OrderByExpressions,
+			evalOutputOptions.OrderByDirections,
+			evalOutputOptions.Limit,
+			evalOutputOptions.Offset,
+			evalOutputOptions.RetractionFree,
+			evalOutputOptions.OutputFile,
+		)
+		if err != nil {
+			return errors.Wrap(err, "couldn't create file output")
+		}
+	} else {
+		outputSink, printer = app.outputSinkFn(stateStorage, outStreamID, eventTimeField, evalOutputOptions)
+	}
 
-	outputSink, printer := app.outputSinkFn(stateStorage, outStreamID, phys.Metadata().EventTimeField(), evalOutputOptions)
+	defer trace.StartRegion(ctx, "execution").End()
 
 	pullEngine := execution.NewPullEngine(outputSink, stateStorage, []execution.RecordStream{stream[0]}, outStreamID, execOutput[0].WatermarkSource, false, ctx)
 	go pullEngine.Run()
 
 	if err := printer.Run(ctx); err != nil {
-		return errors.Wrap(err, "couldn't run stdout printer")
+		// The pull engine still has to be stopped and its state cleared, also when the query has been canceled.
+		if closeErr := pullEngine.Close(ctx, stateStorage); closeErr != nil {
+			log.Println("couldn't close output pull engine: ", closeErr)
+		}
+		return errors.Wrap(err, "couldn't run output printer")
 	}
 
 	if err := pullEngine.Close(ctx, stateStorage); err != nil {
 		return errors.Wrap(err, "couldn't close output pull engine")
 	}
 
+	if prepared.explainAnalyze {
+		fmt.Printf("\nPhysical plan:\n%s", graph.ShowTree(prepared.phys.Visualize()))
+	}
+
 	return nil
 }
+
+// getParameters lists the query parameters used in the plan.
+func getParameters(ctx context.Context, plan physical.Node, outputOptions *physical.OutputOptions) []octosql.VariableName {
+	var parameters []octosql.VariableName
+	seen := make(map[octosql.VariableName]bool)
+	transformers := &physical.Transformers{
+		NamedExprT: func(expr physical.NamedExpression) physical.NamedExpression {
+			if variable, ok := expr.(*physical.Variable); ok && variable.Name.IsParameter() && !seen[variable.Name] {
+				seen[variable.Name] = true
+				parameters = append(parameters, variable.Name)
+			}
+			return expr
+		},
+	}
+
+	plan.Transform(ctx, transformers)
+	for _, expr := range outputOptions.OrderByExpressions {
+		expr.Transform(ctx, transformers)
+	}
+	if outputOptions.Limit != nil {
+		outputOptions.Limit.Transform(ctx, transformers)
+	}
+	if outputOptions.Offset != nil {
+		outputOptions.Offset.Transform(ctx, transformers)
+	}
+
+	return parameters
+}
+
+// pushLimitIntoSources puts the limit right above the data sources, through the nodes which don't change the count of records,
+// and into the sources of shuffles, so that each partition stops reading its source once it has read enough records,
+// instead of the whole source getting read into the shuffle. Those limits are only correct in a retraction free plan.
+// Limits are put above requalifiers, so that those can still be merged with the data sources.
+func pushLimitIntoSources(limit physical.Expression, node physical.Node) physical.Node {
+	switch node := node.(type) {
+	case *physical.Map:
+		return physical.NewMap(node.Expressions, pushLimitIntoSources(limit, node.Source), node.Keep)
+	case *physical.Shuffle:
+		sources := make([]physical.Node, len(node.Sources))
+		for i := range node.Sources {
+			sources[i] = pushLimitIntoSources(limit, node.Sources[i])
+		}
+		return &physical.Shuffle{
+			OutputPartitionCount: node.OutputPartitionCount,
+			Strategy:             node.Strategy,
+			Sources:              sources,
+		}
+	default:
+		return physical.NewLimit(limit, node)
+	}
+}
+
+// isRetractionFree checks if the plan consists only of nodes which never produce retractions.
+func isRetractionFree(ctx context.Context, plan physical.Node) bool {
+	retractionFree := true
+	plan.Transform(ctx, &physical.Transformers{
+		NodeT: func(node physical.Node) physical.Node {
+			switch node.(type) {
+			case *physical.DataSourceBuilder, *physical.Filter, *physical.Let, *physical.Map, *physical.Requalifier, *physical.Shuffle:
+			default:
+				retractionFree = false
+			}
+			return node
+		},
+	})
+	return retractionFree
+}
@@ -0,0 +1,88 @@
+package app
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/execution/functions"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestNextRun(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+
+	// The count is read from the storage, so a storage already used by earlier runs continues it.
+	for want := 1; want <= 3; want++ {
+		got, err := nextRun(stateStorage)
+		if err != nil {
+			t.Fatalf("nextRun() error = %v", err)
+		}
+		if got != want {
+			t.Errorf("nextRun() = %d, want %d", got, want)
+		}
+	}
+}
+
+func TestEvaluateOutputOptions(t *testing.T) {
+	ctx := context.Background()
+	variables := octosql.NewVariables(map[octosql.VariableName]octosql.Value{
+		"const_0": octosql.MakeInt(2),
+		"const_1": octosql.MakeInt(10),
+	})
+
+	options, err := EvaluateOutputOptions(ctx, variables, &execution.OutputOptions{
+		OrderByExpressions: []execution.Expression{
+			execution.NewFunctionExpression(&functions.FuncMultiply, []execution.Expression{
+				execution.NewVariable("p.age"),
+				execution.NewVariable("const_0"),
+			}),
+		},
+		OrderByDirections: []execution.OrderDirection{execution.Ascending},
+		Limit:             execution.NewVariable("const_1"),
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if options.Limit == nil || *options.Limit != 10 {
+		t.Errorf("got limit %v, want 10", options.Limit)
+	}
+
+	// The outputs evaluate the order by expressions with only the fields of the record, the values of the query are added to them.
+	record := execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"p.age"}, []interface{}{3})
+	value, err := options.OrderByExpressions[0].ExpressionValue(ctx, record.AsVariables())
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !octosql.AreEqual(value, octosql.MakeInt(6)) {
+		t.Errorf("got order by value %s, want 6", value.Show())
+	}
+}
+
+func TestPushLimitIntoSources(t *testing.T) {
+	stub := func() physical.Node {
+		return physical.NewStubNode(metadata.NewNodeMetadata(metadata.BoundedFitsInLocalStorage, "", metadata.EmptyNamespace()))
+	}
+	limit := physical.NewVariable("const_0")
+	filter := physical.NewFilter(physical.NewConstant(true), stub())
+	requalifier := physical.NewRequalifier("p", stub())
+
+	plan := physical.NewShuffle(1, physical.NewConstantStrategy(0), []physical.Node{
+		physical.NewMap([]physical.NamedExpression{physical.NewVariable("p.age")}, requalifier, false),
+		filter,
+	})[0]
+
+	want := physical.NewShuffle(1, physical.NewConstantStrategy(0), []physical.Node{
+		physical.NewMap([]physical.NamedExpression{physical.NewVariable("p.age")}, physical.NewLimit(limit, requalifier), false),
+		physical.NewLimit(limit, filter),
+	})[0]
+
+	// The limit is put above the requalifier, so that it can still get merged with the data source.
+	if got := pushLimitIntoSources(limit, plan); !reflect.DeepEqual(got, want) {
+		t.Errorf("pushLimitIntoSources() = %+v, want %+v", got, want)
+	}
+}
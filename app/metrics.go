@@ -0,0 +1,177 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"log"
+	"net/http"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+)
+
+// Metrics collects the execution statistics of each node of the queries run with it, the same ones EXPLAIN ANALYZE shows.
+// The statistics of a query are available while it's running.
+// It can be used to monitor long-running streaming queries, also as a Prometheus scrape endpoint.
+type Metrics struct {
+	mutex   sync.Mutex
+	queries int
+	nodes   []metricsNode
+}
+
+type metricsNode struct {
+	query int
+	node  int
+	name  string
+	stats *execution.NodeStats
+}
+
+// NodeMetrics are the statistics of a single node of a query, at the time they've been read.
+type NodeMetrics struct {
+	Query       int
+	Node        int
+	Name        string
+	Records     int64
+	Retractions int64
+	Duration    time.Duration
+}
+
+func NewMetrics() *Metrics {
+	return &Metrics{}
+}
+
+type metricsKey struct{}
+
+// WithMetrics makes the queries planned with the returned context collect their statistics into the given metrics.
+func WithMetrics(ctx context.Context, metrics *Metrics) context.Context {
+	return context.WithValue(ctx, metricsKey{}, metrics)
+}
+
+func getMetricsFromContext(ctx context.Context) *Metrics {
+	metrics, _ := ctx.Value(metricsKey{}).(*Metrics)
+	return metrics
+}
+
+// addPlan registers the nodes of a plan already wrapped with physical.AnalyzeAll, and returns the number of the query.
+func (metrics *Metrics) addPlan(ctx context.Context, plan physical.Node) int {
+	metrics.mutex.Lock()
+	defer metrics.mutex.Unlock()
+
+	metrics.queries++
+	node := 0
+	plan.Transform(ctx, &physical.Transformers{
+		NodeT: func(n physical.Node) physical.Node {
+			if analyze, ok := n.(*physical.Analyze); ok {
+				metrics.nodes = append(metrics.nodes, metricsNode{
+					query: metrics.queries,
+					node:  node,
+					name:  analyze.Source.Visualize().Name,
+					stats: analyze.Stats,
+				})
+				node++
+			}
+			return n
+		},
+	})
+	return metrics.queries
+}
+
+// removeQuery removes the nodes of the query, once it's stopped running.
+func (metrics *Metrics) removeQuery(query int) {
+	metrics.mutex.Lock()
+	defer metrics.mutex.Unlock()
+
+	nodes := metrics.nodes[:0]
+	for _, node := range metrics.nodes {
+		if node.query != query {
+			nodes = append(nodes, node)
+		}
+	}
+	metrics.nodes = nodes
+}
+
+// Nodes returns the current statistics of all the nodes.
+func (metrics *Metrics) Nodes() []NodeMetrics {
+	metrics.mutex.Lock()
+	defer metrics.mutex.Unlock()
+
+	out := make([]NodeMetrics, len(metrics.nodes))
+	for i, node := range metrics.nodes {
+		out[i] = NodeMetrics{
+			Query:       node.query,
+			Node:        node.node,
+			Name:        node.name,
+			Records:     node.stats.Records(),
+			Retractions: node.stats.Retractions(),
+			Duration:    node.stats.Duration(),
+		}
+	}
+	return out
+}
+
+var prometheusLabelEscaper = strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`)
+
+// WritePrometheus writes the statistics of all the nodes in the Prometheus text exposition format.
+func (metrics *Metrics) WritePrometheus(w io.Writer) error {
+	nodes := metrics.Nodes()
+
+	families := []struct {
+		name, help string
+		value      func(node NodeMetrics) string
+	}{
+		{
+			name: "octosql_node_records_total",
+			help: "Records produced by the node, not counting retractions.",
+			value: func(node NodeMetrics) string {
+				return fmt.Sprint(node.Records)
+			},
+		},
+		{
+			name: "octosql_node_retractions_total",
+			help: "Retractions produced by the node.",
+			value: func(node NodeMetrics) string {
+				return fmt.Sprint(node.Retractions)
+			},
+		},
+		{
+			name: "octosql_node_seconds_total",
+			help: "Wall time spent in the node, including the time spent in its sources.",
+			value: func(node NodeMetrics) string {
+				return fmt.Sprint(node.Duration.Seconds())
+			},
+		},
+	}
+
+	for _, family := range families {
+		if _, err := fmt.Fprintf(w, "# HELP %s %s\n# TYPE %s counter\n", family.name, family.help, family.name); err != nil {
+			return err
+		}
+		for _, node := range nodes {
+			if _, err := fmt.Fprintf(
+				w,
+				"%s{query=\"%d\",node=\"%d\",name=\"%s\"} %s\n",
+				family.name,
+				node.Query,
+				node.Node,
+				prometheusLabelEscaper.Replace(node.Name),
+				family.value(node),
+			); err != nil {
+				return err
+			}
+		}
+	}
+
+	return nil
+}
+
+// ServeHTTP serves the metrics in the Prometheus text exposition format.
+func (metrics *Metrics) ServeHTTP(w http.ResponseWriter, r *http.Request) {
+	w.Header().Set("Content-Type", "text/plain; version=0.0.4")
+	if err := metrics.WritePrometheus(w); err != nil {
+		log.Println("couldn't write metrics: ", err)
+	}
+}
@@ -0,0 +1,52 @@
+package app
+
+import (
+	"bytes"
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql/datasources/memory"
+	"github.com/cube2222/octosql/physical"
+)
+
+func TestMetrics(t *testing.T) {
+	ctx := context.Background()
+	metrics := NewMetrics()
+
+	source := memory.NewDataSourceBuilderFactory(nil)("people", "p")[0]
+	query := metrics.addPlan(ctx, physical.AnalyzeAll(ctx, physical.NewRequalifier("q", source)))
+	if query != 1 {
+		t.Errorf("expected query number 1, got %d", query)
+	}
+
+	nodes := metrics.Nodes()
+	if len(nodes) != 2 {
+		t.Fatalf("expected metrics of 2 nodes, got %d", len(nodes))
+	}
+	for _, node := range nodes {
+		if node.Query != 1 {
+			t.Errorf("expected node %d to be part of query 1, got %d", node.Node, node.Query)
+		}
+	}
+
+	var buf bytes.Buffer
+	if err := metrics.WritePrometheus(&buf); err != nil {
+		t.Fatal(err)
+	}
+	for _, line := range []string{
+		"# TYPE octosql_node_records_total counter\n",
+		"octosql_node_records_total{query=\"1\",node=\"0\",name=\"" + nodes[0].Name + "\"} 0\n",
+		"octosql_node_retractions_total{query=\"1\",node=\"1\",name=\"" + nodes[1].Name + "\"} 0\n",
+	} {
+		if !bytes.Contains(buf.Bytes(), []byte(line)) {
+			t.Errorf("expected metrics output to contain %q, got:\n%s", line, buf.String())
+		}
+	}
+
+	metrics.addPlan(ctx, physical.AnalyzeAll(ctx, source))
+	metrics.removeQuery(query)
+	nodes = metrics.Nodes()
+	if len(nodes) != 1 || nodes[0].Query != 2 {
+		t.Errorf("expected only the metrics of query 2 to be left, got %+v", nodes)
+	}
+}
@@ -0,0 +1,164 @@
+package app
+
+import (
+	"container/list"
+	"os"
+	"sync"
+
+	"github.com/mitchellh/hashstructure"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/logical"
+)
+
+// ParseFn turns query text into a logical plan with its output options.
+type ParseFn func(query string) (logical.Node, *logical.OutputOptions, error)
+
+type planCacheKey struct {
+	query       string
+	fingerprint uint64
+}
+
+type planCacheEntry struct {
+	key  planCacheKey
+	plan []byte
+}
+
+// PlanCache caches logical plans keyed by the query text and the fingerprint of the data sources,
+// so repeated queries don't have to be parsed and planned again.
+// Plans are stored serialized, so that each request gets its own copy, which may be used concurrently with the others.
+// Plans which can't be serialized aren't cached.
+// Entries planned against a different data source configuration, or before a file of a data source got modified,
+// are never returned, which invalidates them whenever a data source changes.
+// The least recently used entries are evicted once the capacity is exceeded.
+type PlanCache struct {
+	mutex    sync.Mutex
+	capacity int
+	entries  map[planCacheKey]*list.Element
+	lru      *list.List
+}
+
+func NewPlanCache(capacity int) *PlanCache {
+	return &PlanCache{
+		capacity: capacity,
+		entries:  make(map[planCacheKey]*list.Element),
+		lru:      list.New(),
+	}
+}
+
+// fileFingerprint identifies a version of the file of a data source, so that its schema changing gets noticed.
+type fileFingerprint struct {
+	Path    string
+	Size    int64
+	ModTime int64
+}
+
+// DataSourcesFingerprint returns a hash of the given data source configurations,
+// and of the size and modification time of their files.
+func DataSourcesFingerprint(dataSources []config.DataSourceConfig) (uint64, error) {
+	fingerprint, err := hashstructure.Hash(struct {
+		DataSources []config.DataSourceConfig
+		Files       []fileFingerprint
+	}{
+		DataSources: dataSources,
+		Files:       getFileFingerprints(dataSources),
+	}, nil)
+	if err != nil {
+		return 0, errors.Wrap(err, "couldn't hash data sources")
+	}
+	return fingerprint, nil
+}
+
+func getFileFingerprints(dataSources []config.DataSourceConfig) []fileFingerprint {
+	var files []fileFingerprint
+	for _, dataSource := range dataSources {
+		path, ok := dataSource.Config["path"].(string)
+		if !ok {
+			continue
+		}
+		file := fileFingerprint{Path: path}
+		// A missing file fails the query when it's planned, so it's only fingerprinted as missing.
+		if info, err := os.Stat(path); err == nil {
+			file.Size = info.Size()
+			file.ModTime = info.ModTime().UnixNano()
+		}
+		files = append(files, file)
+	}
+	return files
+}
+
+// GetOrCreate returns the cached plan for the given query and data sources, or creates it using parse.
+func (cache *PlanCache) GetOrCreate(query string, dataSources []config.DataSourceConfig, parse ParseFn) (logical.Node, *logical.OutputOptions, error) {
+	fingerprint, err := DataSourcesFingerprint(dataSources)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get data sources fingerprint")
+	}
+	key := planCacheKey{
+		query:       query,
+		fingerprint: fingerprint,
+	}
+
+	cache.mutex.Lock()
+	if elem, ok := cache.entries[key]; ok {
+		cache.lru.MoveToFront(elem)
+		data := elem.Value.(*planCacheEntry).plan
+		cache.mutex.Unlock()
+
+		plan, outputOptions, err := logical.UnmarshalPlan(data)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't read cached plan")
+		}
+		return plan, outputOptions, nil
+	}
+	cache.mutex.Unlock()
+
+	plan, outputOptions, err := parse(query)
+	if err != nil {
+		return nil, nil, err
+	}
+	data, err := logical.MarshalPlan(plan, outputOptions)
+	if err != nil {
+		return plan, outputOptions, nil
+	}
+
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	if _, ok := cache.entries[key]; !ok {
+		cache.entries[key] = cache.lru.PushFront(&planCacheEntry{
+			key:  key,
+			plan: data,
+		})
+	}
+	cache.evict()
+
+	return plan, outputOptions, nil
+}
+
+// Invalidate removes all cached plans.
+func (cache *PlanCache) Invalidate() {
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	cache.entries = make(map[planCacheKey]*list.Element)
+	cache.lru.Init()
+}
+
+// Len returns the number of cached plans.
+func (cache *PlanCache) Len() int {
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	return cache.lru.Len()
+}
+
+func (cache *PlanCache) evict() {
+	// Plans created against an outdated configuration will never be hit again,
+	// so they'll end up at the back of the list and get evicted first.
+	for cache.lru.Len() > cache.capacity {
+		oldest := cache.lru.Back()
+		cache.lru.Remove(oldest)
+		delete(cache.entries, oldest.Value.(*planCacheEntry).key)
+	}
+}
@@ -0,0 +1,164 @@
+package app
+
+import (
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/logical"
+	"github.com/cube2222/octosql/parser"
+)
+
+func TestPlanCache(t *testing.T) {
+	parseCount := 0
+	parse := func(query string) (logical.Node, *logical.OutputOptions, error) {
+		parseCount++
+		return logical.NewDataSource(query, "t"), &logical.OutputOptions{}, nil
+	}
+
+	dataSources := []config.DataSourceConfig{
+		{
+			Name:   "people",
+			Type:   "csv",
+			Config: map[string]interface{}{"path": "people.csv"},
+		},
+	}
+
+	cache := NewPlanCache(2)
+
+	if _, _, err := cache.GetOrCreate("a", dataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+	if _, _, err := cache.GetOrCreate("a", dataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+	if parseCount != 1 {
+		t.Errorf("expected the query to be parsed once, got %d", parseCount)
+	}
+
+	changedDataSources := []config.DataSourceConfig{
+		{
+			Name:   "people",
+			Type:   "csv",
+			Config: map[string]interface{}{"path": "people2.csv"},
+		},
+	}
+	if _, _, err := cache.GetOrCreate("a", changedDataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+	if parseCount != 2 {
+		t.Errorf("expected the query to be parsed again after the data sources changed, got %d parses", parseCount)
+	}
+
+	if _, _, err := cache.GetOrCreate("b", changedDataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+	if cache.Len() != 2 {
+		t.Errorf("expected the cache to be limited to 2 entries, got %d", cache.Len())
+	}
+
+	cache.Invalidate()
+	if cache.Len() != 0 {
+		t.Errorf("expected the cache to be empty after invalidation, got %d entries", cache.Len())
+	}
+}
+
+func TestPlanCache_ModifiedFile(t *testing.T) {
+	parseCount := 0
+	parse := func(query string) (logical.Node, *logical.OutputOptions, error) {
+		parseCount++
+		return logical.NewDataSource(query, "t"), &logical.OutputOptions{}, nil
+	}
+
+	dir, err := ioutil.TempDir("", "octosql-plan-cache")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	path := filepath.Join(dir, "people.csv")
+	if err := ioutil.WriteFile(path, []byte("name\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	dataSources := []config.DataSourceConfig{
+		{
+			Name:   "people",
+			Type:   "csv",
+			Config: map[string]interface{}{"path": path},
+		},
+	}
+
+	cache := NewPlanCache(2)
+
+	if _, _, err := cache.GetOrCreate("a", dataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+
+	modified := time.Now().Add(time.Hour)
+	if err := os.Chtimes(path, modified, modified); err != nil {
+		t.Fatal(err)
+	}
+	if _, _, err := cache.GetOrCreate("a", dataSources, parse); err != nil {
+		t.Fatal(err)
+	}
+	if parseCount != 2 {
+		t.Errorf("expected the query to be parsed again after the file got modified, got %d parses", parseCount)
+	}
+}
+
+func TestPlanCache_Concurrent(t *testing.T) {
+	query := "SELECT p.name, COUNT(*) as count FROM people p WHERE p.age > 3 GROUP BY p.name ORDER BY count DESC LIMIT 5"
+	wantPlan, wantOutputOptions, err := parser.ParseQuery(query)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want, err := logical.MarshalPlan(wantPlan, wantOutputOptions)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	cache := NewPlanCache(1)
+	const requests = 16
+	plans := make([]logical.Node, requests)
+	outputOptions := make([]*logical.OutputOptions, requests)
+	errs := make([]error, requests)
+
+	var wg sync.WaitGroup
+	for i := 0; i < requests; i++ {
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			plans[i], outputOptions[i], errs[i] = cache.GetOrCreate(query, nil, parser.ParseQuery)
+		}(i)
+	}
+	wg.Wait()
+
+	// Each request gets its own copy of the plan, so planning one of them can't affect the others.
+	seenPlans := make(map[logical.Node]bool)
+	seenOutputOptions := make(map[*logical.OutputOptions]bool)
+	for i := 0; i < requests; i++ {
+		if errs[i] != nil {
+			t.Fatal(errs[i])
+		}
+		got, err := logical.MarshalPlan(plans[i], outputOptions[i])
+		if err != nil {
+			t.Fatal(err)
+		}
+		if string(got) != string(want) {
+			t.Errorf("GetOrCreate() = %s, want %s", got, want)
+		}
+		if seenPlans[plans[i]] || seenOutputOptions[outputOptions[i]] {
+			t.Errorf("request %d got a plan shared with another request", i)
+		}
+		seenPlans[plans[i]] = true
+		seenOutputOptions[outputOptions[i]] = true
+	}
+	if cache.Len() != 1 {
+		t.Errorf("expected the plan to be cached, got %d entries", cache.Len())
+	}
+}
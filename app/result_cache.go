@@ -0,0 +1,491 @@
+package app
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"encoding/binary"
+	"fmt"
+	"hash/fnv"
+	"io"
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/golang/protobuf/proto"
+	"github.com/mitchellh/go-homedir"
+	"github.com/pkg/errors"
+	"gopkg.in/yaml.v2"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/execution/functions"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+	"github.com/cube2222/octosql/storage"
+)
+
+// ResultCache stores the results of queries on disk, so that running the same query again on unchanged files
+// only has to read the stored result. Only bounded queries reading nothing but files,
+// and using no nondeterministic functions, get cached.
+// Results are keyed by the physical plan, the values of its variables and parameters, the configuration,
+// and the sizes and modification times of the files of the data sources, so modifying a file invalidates them.
+// Files are named after a hash of the key, and the whole key is stored at their beginning,
+// so that a result stored under a different key with the same hash is never returned.
+// The values of each field are stored together, compressed using the codec chosen for the field,
+// and the codecs are stored in the file, so results can be read whatever the current codecs are.
+type ResultCache struct {
+	directory string
+	codecs    *ColumnCodecs
+}
+
+func NewResultCache(directory string) *ResultCache {
+	return &ResultCache{
+		directory: directory,
+		codecs:    &ColumnCodecs{Default: storage.CodecNone},
+	}
+}
+
+// WithCompression returns a copy of the cache which compresses the results it stores using the given codecs.
+func (cache *ResultCache) WithCompression(codecs *ColumnCodecs) *ResultCache {
+	out := *cache
+	out.codecs = codecs
+	return &out
+}
+
+// ColumnCodecs are the compression codecs of the fields of stored results.
+// Fields without a codec of their own use the default one.
+type ColumnCodecs struct {
+	Default storage.Codec
+	Columns map[string]storage.Codec
+}
+
+// ParseColumnCodecs parses a comma separated list of codecs, in which a lone codec is the default one,
+// and the codecs of fields are given as field=codec, like zstd,p.name=lz4,p.id=none.
+func ParseColumnCodecs(spec string) (*ColumnCodecs, error) {
+	codecs := &ColumnCodecs{
+		Default: storage.CodecNone,
+		Columns: make(map[string]storage.Codec),
+	}
+	if spec == "" {
+		return codecs, nil
+	}
+
+	for _, part := range strings.Split(spec, ",") {
+		parts := strings.SplitN(part, "=", 2)
+		codec, err := storage.ParseCodec(parts[len(parts)-1])
+		if err != nil {
+			return nil, err
+		}
+		if len(parts) == 1 {
+			codecs.Default = codec
+		} else {
+			codecs.Columns[parts[0]] = codec
+		}
+	}
+	return codecs, nil
+}
+
+// Codec returns the codec of the given field.
+func (codecs *ColumnCodecs) Codec(column string) storage.Codec {
+	if codec, ok := codecs.Columns[column]; ok {
+		return codec
+	}
+	return codecs.Default
+}
+
+// resultCacheFormat begins every stored result, so that results stored in another format are treated as missing.
+const resultCacheFormat = "octosql-result-v2"
+
+func (cache *ResultCache) path(key []byte) string {
+	hash := fnv.New64a()
+	hash.Write(key)
+	return filepath.Join(cache.directory, fmt.Sprintf("%016x", hash.Sum64()))
+}
+
+// Get returns the cached result with the given key, if there is one.
+func (cache *ResultCache) Get(key []byte) ([]*execution.Record, bool, error) {
+	f, err := os.Open(cache.path(key))
+	if os.IsNotExist(err) {
+		return nil, false, nil
+	} else if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't open cached result")
+	}
+	defer f.Close()
+
+	r := bufio.NewReader(f)
+	format := make([]byte, len(resultCacheFormat))
+	if _, err := io.ReadFull(r, format); err == io.EOF || err == io.ErrUnexpectedEOF {
+		return nil, false, nil
+	} else if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't read format of cached result")
+	}
+	if string(format) != resultCacheFormat {
+		return nil, false, nil
+	}
+
+	// The file holds the result of another key with the same hash.
+	storedKey, err := readBlock(r)
+	if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't read cached result key")
+	}
+	if !bytes.Equal(storedKey, key) {
+		return nil, false, nil
+	}
+
+	// The records are stored without their values first, followed by the values of each field.
+	data, err := readCompressedBlock(r)
+	if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't read cached records")
+	}
+	var records []*execution.Record
+	for len(data) > 0 {
+		recordData, n := nextBlock(data)
+		if n <= 0 {
+			return nil, false, errors.New("invalid size of cached record")
+		}
+		var record execution.Record
+		if err := proto.Unmarshal(recordData, &record); err != nil {
+			return nil, false, errors.Wrap(err, "couldn't decode cached record")
+		}
+		records = append(records, &record)
+		data = data[n:]
+	}
+
+	columns := make(map[string][]byte)
+	for {
+		name, err := readBlock(r)
+		if err == io.EOF {
+			break
+		} else if err != nil {
+			return nil, false, errors.Wrap(err, "couldn't read name of cached field")
+		}
+		columns[string(name)], err = readCompressedBlock(r)
+		if err != nil {
+			return nil, false, errors.Wrapf(err, "couldn't read values of cached field %s", name)
+		}
+	}
+
+	for _, record := range records {
+		record.Data = make([]*octosql.Value, len(record.FieldNames))
+		for i, field := range record.FieldNames {
+			valueData, n := nextBlock(columns[field])
+			if n <= 0 {
+				return nil, false, errors.Errorf("missing value of cached field %s", field)
+			}
+			var value octosql.Value
+			if err := proto.Unmarshal(valueData, &value); err != nil {
+				return nil, false, errors.Wrapf(err, "couldn't decode value of cached field %s", field)
+			}
+			record.Data[i] = &value
+			columns[field] = columns[field][n:]
+		}
+	}
+
+	return records, true, nil
+}
+
+// Put stores the result under the given key. The file is renamed into place once it's complete,
+// so that an interrupted write never leaves a partial result behind.
+func (cache *ResultCache) Put(key []byte, records []*execution.Record) error {
+	if err := os.MkdirAll(cache.directory, os.ModePerm); err != nil {
+		return errors.Wrap(err, "couldn't create result cache directory")
+	}
+
+	// The values are split into columns, in the order in which their fields first appear.
+	var recordsData []byte
+	var fields []string
+	columns := make(map[string][]byte)
+	for _, record := range records {
+		for i, field := range record.FieldNames {
+			data, err := proto.Marshal(record.Data[i])
+			if err != nil {
+				return errors.Wrapf(err, "couldn't encode value of field %s", field)
+			}
+			if _, ok := columns[field]; !ok {
+				fields = append(fields, field)
+			}
+			columns[field] = appendBlock(columns[field], data)
+		}
+
+		withoutValues := *record
+		withoutValues.Data = nil
+		data, err := proto.Marshal(&withoutValues)
+		if err != nil {
+			return errors.Wrap(err, "couldn't encode record")
+		}
+		recordsData = appendBlock(recordsData, data)
+	}
+
+	f, err := ioutil.TempFile(cache.directory, "tmp")
+	if err != nil {
+		return errors.Wrap(err, "couldn't create cached result file")
+	}
+	defer os.Remove(f.Name())
+	defer f.Close()
+
+	w := bufio.NewWriter(f)
+	if _, err := w.WriteString(resultCacheFormat); err != nil {
+		return errors.Wrap(err, "couldn't write format")
+	}
+	if _, err := w.Write(appendBlock(nil, key)); err != nil {
+		return errors.Wrap(err, "couldn't write key")
+	}
+	if err := writeCompressedBlock(w, cache.codecs.Default, recordsData); err != nil {
+		return errors.Wrap(err, "couldn't write records")
+	}
+	for _, field := range fields {
+		if _, err := w.Write(appendBlock(nil, []byte(field))); err != nil {
+			return errors.Wrapf(err, "couldn't write name of field %s", field)
+		}
+		if err := writeCompressedBlock(w, cache.codecs.Codec(field), columns[field]); err != nil {
+			return errors.Wrapf(err, "couldn't write values of field %s", field)
+		}
+	}
+	if err := w.Flush(); err != nil {
+		return errors.Wrap(err, "couldn't flush cached result")
+	}
+	if err := f.Close(); err != nil {
+		return errors.Wrap(err, "couldn't close cached result file")
+	}
+
+	if err := os.Rename(f.Name(), cache.path(key)); err != nil {
+		return errors.Wrap(err, "couldn't move cached result into place")
+	}
+	return nil
+}
+
+// appendBlock appends the data prefixed with its size.
+func appendBlock(buf []byte, data []byte) []byte {
+	sizeBuf := make([]byte, binary.MaxVarintLen64)
+	n := binary.PutUvarint(sizeBuf, uint64(len(data)))
+	return append(append(buf, sizeBuf[:n]...), data...)
+}
+
+// nextBlock returns the first block of the data, and the number of bytes it takes up, which isn't positive if it's invalid.
+func nextBlock(data []byte) ([]byte, int) {
+	size, n := binary.Uvarint(data)
+	if n <= 0 || uint64(len(data)-n) < size {
+		return nil, 0
+	}
+	return data[n : n+int(size)], n + int(size)
+}
+
+func readBlock(r *bufio.Reader) ([]byte, error) {
+	size, err := binary.ReadUvarint(r)
+	if err != nil {
+		return nil, err
+	}
+	data := make([]byte, size)
+	if _, err := io.ReadFull(r, data); err != nil {
+		return nil, err
+	}
+	return data, nil
+}
+
+// writeCompressedBlock writes the name of the codec, followed by the data compressed using it.
+func writeCompressedBlock(w io.Writer, codec storage.Codec, data []byte) error {
+	compressed, err := codec.Compress(data)
+	if err != nil {
+		return err
+	}
+	_, err = w.Write(appendBlock(appendBlock(nil, []byte(codec)), compressed))
+	return err
+}
+
+func readCompressedBlock(r *bufio.Reader) ([]byte, error) {
+	name, err := readBlock(r)
+	if err != nil {
+		return nil, err
+	}
+	codec, err := storage.ParseCodec(string(name))
+	if err != nil {
+		return nil, err
+	}
+	compressed, err := readBlock(r)
+	if err != nil {
+		return nil, err
+	}
+	return codec.Decompress(compressed)
+}
+
+// WithResultCache returns a copy of the app which caches the results of the queries it runs using RunPlan.
+func (app *App) WithResultCache(cache *ResultCache) *App {
+	out := *app
+	out.resultCache = cache
+	return &out
+}
+
+// runWithResultCache runs the prepared plan, reading its result from the result cache if it's there.
+// Otherwise the whole result gets collected and stored first, and is then sent to the output.
+func (app *App) runWithResultCache(ctx context.Context, stateStorage storage.Storage, prepared *PreparedPlan, parameters map[string]octosql.Value) error {
+	key, ok, err := app.resultCacheKey(ctx, prepared, parameters)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get result cache key")
+	}
+	if !ok {
+		return app.RunPrepared(ctx, stateStorage, prepared, parameters)
+	}
+
+	records, ok, err := app.resultCache.Get(key)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get cached result")
+	}
+	if !ok {
+		records, err = app.collectResult(ctx, stateStorage, prepared, parameters)
+		if err != nil {
+			return err
+		}
+		if err := app.resultCache.Put(key, records); err != nil {
+			return errors.Wrap(err, "couldn't cache result")
+		}
+	}
+
+	cached := *prepared
+	cached.exec = &cachedResult{records: records}
+	return app.RunPrepared(ctx, stateStorage, &cached, parameters)
+}
+
+// collectResult runs the prepared plan without ordering, limiting or offsetting its output, and returns its result.
+// Those get applied when the result is sent to the actual output, as they may use parameters.
+func (app *App) collectResult(ctx context.Context, stateStorage storage.Storage, prepared *PreparedPlan, parameters map[string]octosql.Value) ([]*execution.Record, error) {
+	var records []*execution.Record
+	var queryErr error
+	collector := *app
+	collector.outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+		sink := batch.NewTableOutput(streamID, eventTimeField, nil, nil, nil, nil, outputOptions.RetractionFree)
+		printer := batch.NewWholeTableWriterPrinter(stateStorage, sink, func(w io.Writer, result []*execution.Record, watermark time.Time, err error) error {
+			records, queryErr = result, err
+			return nil
+		}, ioutil.Discard)
+		return sink, printer
+	}
+
+	unordered := *prepared
+	unordered.execOutputOptions = &execution.OutputOptions{}
+	if err := collector.RunPrepared(ctx, stateStorage, &unordered, parameters); err != nil {
+		return nil, err
+	}
+	// With an error, the records are only a part of the result.
+	if queryErr != nil {
+		return nil, errors.Wrap(queryErr, "couldn't run query")
+	}
+	return records, nil
+}
+
+// resultCacheKey returns the key of the result of the prepared plan run with the given parameters,
+// or false if the result can't be cached.
+func (app *App) resultCacheKey(ctx context.Context, prepared *PreparedPlan, parameters map[string]octosql.Value) ([]byte, bool, error) {
+	if prepared.explainAnalyze || prepared.execOutputOptions.OutputFile != "" {
+		return nil, false, nil
+	}
+	if !isResultCacheable(ctx, app.cfg.DataSources, prepared.phys) {
+		return nil, false, nil
+	}
+
+	// Constants of the query are variables, so their values are a part of the key too.
+	// Values are hex encoded, and maps get encoded with sorted keys, so that the same values always give the same key.
+	values := make(map[string]string)
+	for name, value := range prepared.variables {
+		data, err := proto.Marshal(&value)
+		if err != nil {
+			return nil, false, errors.Wrapf(err, "couldn't encode variable %s", name)
+		}
+		values[name.String()] = fmt.Sprintf("%x", data)
+	}
+	for name, value := range parameters {
+		data, err := proto.Marshal(&value)
+		if err != nil {
+			return nil, false, errors.Wrapf(err, "couldn't encode parameter %s", name)
+		}
+		values[octosql.NewParameterVariableName(name).String()] = fmt.Sprintf("%x", data)
+	}
+
+	key, err := yaml.Marshal(struct {
+		Plan        string
+		Values      map[string]string
+		Execution   map[string]interface{}
+		Physical    map[string]interface{}
+		TimeZone    string
+		DataSources []config.DataSourceConfig
+		Files       []fileFingerprint
+	}{
+		Plan:        graph.ShowTree(prepared.phys.Visualize()),
+		Values:      values,
+		Execution:   app.cfg.Execution,
+		Physical:    app.cfg.Physical,
+		TimeZone:    execution.GetSessionTimeZone(ctx).String(),
+		DataSources: app.cfg.DataSources,
+		Files:       getFileFingerprints(app.cfg.DataSources),
+	})
+	if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't encode result cache key")
+	}
+	return key, true, nil
+}
+
+// isResultCacheable checks if the plan is bounded, only reads data sources with files and is deterministic,
+// so that its result stays the same as long as the files don't change.
+func isResultCacheable(ctx context.Context, dataSources []config.DataSourceConfig, plan physical.Node) bool {
+	if plan.Metadata().Cardinality() == metadata.Unbounded {
+		return false
+	}
+
+	filePaths := make(map[string]bool)
+	for _, dataSource := range dataSources {
+		if _, ok := dataSource.Config["path"].(string); ok {
+			filePaths[dataSource.Name] = true
+		}
+	}
+
+	cacheable := true
+	plan.Transform(ctx, &physical.Transformers{
+		NodeT: func(node physical.Node) physical.Node {
+			if dataSource, ok := node.(*physical.DataSourceBuilder); ok && !filePaths[dataSource.Name] {
+				cacheable = false
+			}
+			return node
+		},
+		ExprT: func(expr physical.Expression) physical.Expression {
+			if function, ok := expr.(*physical.FunctionExpression); ok {
+				if descriptor, ok := functions.Lookup(function.Name); !ok || descriptor.Nondeterministic {
+					cacheable = false
+				}
+			}
+			return expr
+		},
+	})
+	return cacheable
+}
+
+// cachedResult is an execution node sending the records of a cached result.
+type cachedResult struct {
+	records []*execution.Record
+}
+
+func (node *cachedResult) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	records := make([]*execution.Record, len(node.records))
+	for i, record := range node.records {
+		opts := []execution.RecordOption{
+			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamID, i)),
+		}
+		if eventTimeField := record.EventTimeField(); !eventTimeField.Empty() {
+			opts = append(opts, execution.WithEventTimeField(eventTimeField))
+		}
+		records[i] = execution.NewRecordFromSlice(record.GetVariableNames(), octosql.GetValuesFromPointers(record.Data), opts...)
+	}
+
+	return execution.NewInMemoryStream(ctx, records),
+		execution.NewExecutionOutput(
+			execution.NewZeroWatermarkGenerator(),
+			map[string]execution.ShuffleData{},
+			nil,
+		),
+		nil
+}
@@ -0,0 +1,219 @@
+package app
+
+import (
+	"io/ioutil"
+	"os"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestResultCache(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-result-cache")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	cache := NewResultCache(dir)
+
+	if _, ok, err := cache.Get([]byte("first")); err != nil {
+		t.Fatal(err)
+	} else if ok {
+		t.Errorf("expected no cached result before it's stored")
+	}
+
+	fields := []octosql.VariableName{"p.name", "p.age"}
+	records := []*execution.Record{
+		execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("wojtek"), octosql.MakeInt(7)}),
+		execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("kuba"), octosql.MakeNull()}),
+	}
+	if err := cache.Put([]byte("first"), records); err != nil {
+		t.Fatal(err)
+	}
+	if err := cache.Put([]byte("second"), nil); err != nil {
+		t.Fatal(err)
+	}
+
+	got, ok, err := cache.Get([]byte("first"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !ok {
+		t.Fatalf("expected the stored result to be cached")
+	}
+	if len(got) != len(records) {
+		t.Fatalf("Get() = %v, want %v", got, records)
+	}
+	for i := range records {
+		if !got[i].Equal(records[i]) {
+			t.Errorf("Get() record %d = %v, want %v", i, got[i].Show(), records[i].Show())
+		}
+	}
+
+	got, ok, err = cache.Get([]byte("second"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !ok || len(got) != 0 {
+		t.Errorf("Get() = %v, %v, want an empty cached result", got, ok)
+	}
+}
+
+func TestResultCache_HashCollision(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-result-cache")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	cache := NewResultCache(dir)
+
+	fields := []octosql.VariableName{"p.name"}
+	records := []*execution.Record{
+		execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("wojtek")}),
+	}
+	if err := cache.Put([]byte("first"), records); err != nil {
+		t.Fatal(err)
+	}
+	// The result of the first key is moved to where the result of the second key would be, as if their hashes collided.
+	if err := os.Rename(cache.path([]byte("first")), cache.path([]byte("second"))); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, key := range []string{"second", "first"} {
+		if got, ok, err := cache.Get([]byte(key)); err != nil {
+			t.Fatal(err)
+		} else if ok {
+			t.Errorf("Get(%s) = %v, want no cached result", key, got)
+		}
+	}
+}
+
+func TestResultCache_Compression(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-result-cache")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	codecs, err := ParseColumnCodecs("zstd,p.name=lz4,p.age=none,p.city=snappy")
+	if err != nil {
+		t.Fatal(err)
+	}
+	cache := NewResultCache(dir).WithCompression(codecs)
+
+	// The records have different fields, and the same field may be repeated in a record.
+	records := []*execution.Record{
+		execution.NewRecordFromSlice(
+			[]octosql.VariableName{"p.name", "p.age", "p.city"},
+			[]octosql.Value{octosql.MakeString(strings.Repeat("wojtek", 100)), octosql.MakeInt(7), octosql.MakeString("warsaw")},
+			execution.WithEventTimeField("p.age"),
+		),
+		execution.NewRecordFromSlice(
+			[]octosql.VariableName{"p.city", "p.name"},
+			[]octosql.Value{octosql.MakeNull(), octosql.MakeString("kuba")},
+			execution.WithUndo(),
+		),
+		execution.NewRecordFromSlice(
+			[]octosql.VariableName{"p.name", "p.name", "p.score"},
+			[]octosql.Value{octosql.MakeString("a"), octosql.MakeString("b"), octosql.MakeFloat(1.5)},
+		),
+	}
+	if err := cache.Put([]byte("first"), records); err != nil {
+		t.Fatal(err)
+	}
+
+	// The codecs are read from the stored result, so it's read back whatever the current codecs are.
+	for _, cache := range []*ResultCache{cache, NewResultCache(dir)} {
+		got, ok, err := cache.Get([]byte("first"))
+		if err != nil {
+			t.Fatal(err)
+		}
+		if !ok {
+			t.Fatalf("expected the stored result to be cached")
+		}
+		if len(got) != len(records) {
+			t.Fatalf("Get() = %v, want %v", got, records)
+		}
+		for i := range records {
+			if !got[i].Equal(records[i]) || got[i].EventTimeField() != records[i].EventTimeField() || got[i].IsUndo() != records[i].IsUndo() {
+				t.Errorf("Get() record %d = %v, want %v", i, got[i].Show(), records[i].Show())
+			}
+		}
+	}
+}
+
+func TestResultCache_OtherFormat(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-result-cache")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	cache := NewResultCache(dir)
+	if err := ioutil.WriteFile(cache.path([]byte("first")), []byte("\x05first"), os.ModePerm); err != nil {
+		t.Fatal(err)
+	}
+
+	if got, ok, err := cache.Get([]byte("first")); err != nil {
+		t.Fatal(err)
+	} else if ok {
+		t.Errorf("Get() = %v, want no cached result", got)
+	}
+}
+
+func TestParseColumnCodecs(t *testing.T) {
+	tests := []struct {
+		spec    string
+		want    *ColumnCodecs
+		wantErr bool
+	}{
+		{
+			spec: "",
+			want: &ColumnCodecs{Default: storage.CodecNone, Columns: map[string]storage.Codec{}},
+		},
+		{
+			spec: "zstd",
+			want: &ColumnCodecs{Default: storage.CodecZSTD, Columns: map[string]storage.Codec{}},
+		},
+		{
+			spec: "p.name=lz4,snappy,p.id=none",
+			want: &ColumnCodecs{
+				Default: storage.CodecSnappy,
+				Columns: map[string]storage.Codec{"p.name": storage.CodecLZ4, "p.id": storage.CodecNone},
+			},
+		},
+		{
+			spec:    "gzip",
+			wantErr: true,
+		},
+		{
+			spec:    "p.name=",
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.spec, func(t *testing.T) {
+			got, err := ParseColumnCodecs(tt.spec)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseColumnCodecs() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ParseColumnCodecs() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+
+	codecs := &ColumnCodecs{Default: storage.CodecZSTD, Columns: map[string]storage.Codec{"p.name": storage.CodecLZ4}}
+	if got := codecs.Codec("p.name"); got != storage.CodecLZ4 {
+		t.Errorf("Codec(p.name) = %v, want lz4", got)
+	}
+	if got := codecs.Codec("p.age"); got != storage.CodecZSTD {
+		t.Errorf("Codec(p.age) = %v, want zstd", got)
+	}
+}
@@ -0,0 +1,302 @@
+package app
+
+import (
+	"encoding/json"
+	"fmt"
+	"hash/fnv"
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/golang/protobuf/proto"
+	"github.com/mitchellh/go-homedir"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+)
+
+// estimatedRecordSize is the assumed average size of a record in a file, in bytes.
+const estimatedRecordSize = 100
+
+// dataSourceStatistics gets the number of records of the data sources reading files from the statistics
+// collected by ANALYZE. Files which haven't been analyzed since they were last modified get it estimated
+// from their size, which is only good enough to compare the sizes of data sources with each other.
+func dataSourceStatistics(dataSources []config.DataSourceConfig) *physical.Statistics {
+	statisticsDirectory, err := defaultStatisticsDirectory()
+	if err != nil {
+		statisticsDirectory = ""
+	}
+
+	statistics := physical.NewStatistics()
+	for _, dataSource := range dataSources {
+		path, ok := dataSource.Config["path"].(string)
+		if !ok {
+			continue
+		}
+		if statisticsDirectory != "" {
+			if fileStatistics, err := loadFileStatistics(statisticsDirectory, path); err == nil {
+				statistics.SetRowCount(dataSource.Name, fileStatistics.RowCount)
+				continue
+			}
+		}
+		info, err := os.Stat(path)
+		if err != nil {
+			continue
+		}
+		statistics.SetRowCount(dataSource.Name, info.Size()/estimatedRecordSize+1)
+	}
+	return statistics
+}
+
+// FileStatistics are the statistics of a file collected by ANALYZE.
+// They're only valid as long as the size and modification time of the file stay the same.
+type FileStatistics struct {
+	Path     string
+	Size     int64
+	ModTime  int64
+	RowCount int64
+	Columns  []ColumnStatistics
+}
+
+// ColumnStatistics are the statistics of a single column of a file.
+// Min and Max are null if the column has no values which can be compared with each other.
+type ColumnStatistics struct {
+	Name          string
+	NullCount     int64
+	DistinctCount int64
+	Min           octosql.Value
+	Max           octosql.Value
+}
+
+// jsonColumnStatistics is the cached form of ColumnStatistics, with the values encoded the way they're stored.
+type jsonColumnStatistics struct {
+	Name          string
+	NullCount     int64
+	DistinctCount int64
+	Min           []byte
+	Max           []byte
+}
+
+func (stats ColumnStatistics) MarshalJSON() ([]byte, error) {
+	min, err := proto.Marshal(&stats.Min)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't marshal min value")
+	}
+	max, err := proto.Marshal(&stats.Max)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't marshal max value")
+	}
+
+	return json.Marshal(jsonColumnStatistics{
+		Name:          stats.Name,
+		NullCount:     stats.NullCount,
+		DistinctCount: stats.DistinctCount,
+		Min:           min,
+		Max:           max,
+	})
+}
+
+func (stats *ColumnStatistics) UnmarshalJSON(data []byte) error {
+	var in jsonColumnStatistics
+	if err := json.Unmarshal(data, &in); err != nil {
+		return err
+	}
+
+	stats.Name = in.Name
+	stats.NullCount = in.NullCount
+	stats.DistinctCount = in.DistinctCount
+	if err := proto.Unmarshal(in.Min, &stats.Min); err != nil {
+		return errors.Wrap(err, "couldn't unmarshal min value")
+	}
+	if err := proto.Unmarshal(in.Max, &stats.Max); err != nil {
+		return errors.Wrap(err, "couldn't unmarshal max value")
+	}
+	return nil
+}
+
+// statisticsCollector computes the statistics of the records added to it.
+// The distinct counts are estimated, so that the memory used doesn't grow with the size of the file.
+type statisticsCollector struct {
+	rowCount int64
+	columns  map[string]*columnStatisticsCollector
+}
+
+type columnStatisticsCollector struct {
+	valueCount int64
+	distinct   *octosql.HyperLogLog
+	min        octosql.Value
+	max        octosql.Value
+	comparable bool
+}
+
+func newStatisticsCollector() *statisticsCollector {
+	return &statisticsCollector{
+		columns: make(map[string]*columnStatisticsCollector),
+	}
+}
+
+// AddRecord adds the values of the record to the statistics. Missing fields are counted as nulls.
+func (collector *statisticsCollector) AddRecord(record *execution.Record) {
+	if record.IsUndo() {
+		// Reading a file never retracts records.
+		return
+	}
+
+	collector.rowCount++
+	for _, field := range record.Fields() {
+		if field.Name.Source() == execution.SystemSource {
+			continue
+		}
+		value := record.Value(field.Name)
+		if value.GetType() == octosql.TypeNull {
+			continue
+		}
+
+		column, ok := collector.columns[field.Name.Name()]
+		if !ok {
+			column = &columnStatisticsCollector{
+				distinct:   octosql.NewHyperLogLog(),
+				min:        value,
+				max:        value,
+				comparable: true,
+			}
+			collector.columns[field.Name.Name()] = column
+		}
+
+		column.valueCount++
+		column.distinct.Add(value)
+		if !column.comparable {
+			continue
+		}
+		minComparison, minErr := octosql.Compare(value, column.min)
+		maxComparison, maxErr := octosql.Compare(value, column.max)
+		if minErr != nil || maxErr != nil {
+			// Values of different types have no order.
+			column.comparable = false
+			continue
+		}
+		if minComparison == octosql.LessThan {
+			column.min = value
+		}
+		if maxComparison == octosql.GreaterThan {
+			column.max = value
+		}
+	}
+}
+
+// Statistics returns the statistics of the records added so far, with the columns sorted by name.
+func (collector *statisticsCollector) Statistics() *FileStatistics {
+	stats := &FileStatistics{
+		RowCount: collector.rowCount,
+	}
+	for name, column := range collector.columns {
+		columnStats := ColumnStatistics{
+			Name:          name,
+			NullCount:     collector.rowCount - column.valueCount,
+			DistinctCount: int64(column.distinct.Estimate()),
+			Min:           octosql.MakeNull(),
+			Max:           octosql.MakeNull(),
+		}
+		// The estimate may be off by a bit, but there can't be more distinct values than the values themselves.
+		if columnStats.DistinctCount > column.valueCount {
+			columnStats.DistinctCount = column.valueCount
+		}
+		if column.comparable {
+			columnStats.Min = column.min
+			columnStats.Max = column.max
+		}
+		stats.Columns = append(stats.Columns, columnStats)
+	}
+	sort.Slice(stats.Columns, func(i, j int) bool {
+		return stats.Columns[i].Name < stats.Columns[j].Name
+	})
+	return stats
+}
+
+// defaultStatisticsDirectory returns the directory the statistics collected by ANALYZE are cached in.
+func defaultStatisticsDirectory() (string, error) {
+	dir, err := homedir.Dir()
+	if err != nil {
+		return "", errors.Wrap(err, "couldn't get home directory")
+	}
+
+	return filepath.Join(dir, ".octosql", "statistics"), nil
+}
+
+// statisticsCachePath returns the path of the file the statistics of the file under the given path are cached in.
+func statisticsCachePath(statisticsDirectory, path string) (string, error) {
+	absPath, err := filepath.Abs(path)
+	if err != nil {
+		return "", errors.Wrap(err, "couldn't get absolute path of file")
+	}
+
+	hash := fnv.New64a()
+	hash.Write([]byte(absPath))
+	return filepath.Join(statisticsDirectory, fmt.Sprintf("%016x.json", hash.Sum64())), nil
+}
+
+// loadFileStatistics reads the cached statistics of the file under the given path.
+// It fails if there are none, or if the file has been modified since they were collected.
+func loadFileStatistics(statisticsDirectory, path string) (*FileStatistics, error) {
+	cachePath, err := statisticsCachePath(statisticsDirectory, path)
+	if err != nil {
+		return nil, err
+	}
+	data, err := ioutil.ReadFile(cachePath)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read cached statistics")
+	}
+
+	var stats FileStatistics
+	if err := json.Unmarshal(data, &stats); err != nil {
+		return nil, errors.Wrap(err, "couldn't decode cached statistics")
+	}
+
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get file info")
+	}
+	absPath, err := filepath.Abs(path)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get absolute path of file")
+	}
+	if stats.Path != absPath || stats.Size != info.Size() || stats.ModTime != info.ModTime().UnixNano() {
+		return nil, errors.New("file has been modified since its statistics were collected")
+	}
+
+	return &stats, nil
+}
+
+// saveFileStatistics caches the statistics of the file under the given path.
+// The path, size and modification time of the statistics are set to the ones of the file.
+func saveFileStatistics(statisticsDirectory, path string, info os.FileInfo, stats *FileStatistics) error {
+	absPath, err := filepath.Abs(path)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get absolute path of file")
+	}
+	stats.Path = absPath
+	stats.Size = info.Size()
+	stats.ModTime = info.ModTime().UnixNano()
+
+	data, err := json.Marshal(stats)
+	if err != nil {
+		return errors.Wrap(err, "couldn't encode statistics")
+	}
+
+	if err := os.MkdirAll(statisticsDirectory, os.ModePerm); err != nil {
+		return errors.Wrap(err, "couldn't create statistics directory")
+	}
+	cachePath, err := statisticsCachePath(statisticsDirectory, path)
+	if err != nil {
+		return err
+	}
+	if err := ioutil.WriteFile(cachePath, data, 0644); err != nil {
+		return errors.Wrap(err, "couldn't write cached statistics")
+	}
+
+	return nil
+}
@@ -0,0 +1,92 @@
+package app
+
+import (
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+)
+
+func TestStatisticsCollector(t *testing.T) {
+	fields := []octosql.VariableName{"p.name", "p.age", "p.city"}
+	collector := newStatisticsCollector()
+	collector.AddRecord(execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("wojtek"), octosql.MakeInt(7), octosql.MakeNull()}))
+	collector.AddRecord(execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("kuba"), octosql.MakeInt(3), octosql.MakeString("warsaw")}))
+	collector.AddRecord(execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeString("wojtek"), octosql.MakeString("old"), octosql.MakeNull()}))
+	collector.AddRecord(execution.NewRecordFromSlice(fields[:1], []octosql.Value{octosql.MakeString("adam")}))
+	collector.AddRecord(execution.NewRecordFromSlice(fields[:1], []octosql.Value{octosql.MakeString("kuba")}, execution.WithUndo()))
+
+	want := &FileStatistics{
+		RowCount: 4,
+		Columns: []ColumnStatistics{
+			{Name: "age", NullCount: 1, DistinctCount: 3, Min: octosql.MakeNull(), Max: octosql.MakeNull()},
+			{Name: "city", NullCount: 3, DistinctCount: 1, Min: octosql.MakeString("warsaw"), Max: octosql.MakeString("warsaw")},
+			{Name: "name", NullCount: 0, DistinctCount: 3, Min: octosql.MakeString("adam"), Max: octosql.MakeString("wojtek")},
+		},
+	}
+
+	got := collector.Statistics()
+	if got.RowCount != want.RowCount {
+		t.Errorf("Statistics() row count = %v, want %v", got.RowCount, want.RowCount)
+	}
+	if len(got.Columns) != len(want.Columns) {
+		t.Fatalf("Statistics() columns = %+v, want %+v", got.Columns, want.Columns)
+	}
+	for i := range want.Columns {
+		if got.Columns[i].Name != want.Columns[i].Name ||
+			got.Columns[i].NullCount != want.Columns[i].NullCount ||
+			got.Columns[i].DistinctCount != want.Columns[i].DistinctCount ||
+			!octosql.AreEqual(got.Columns[i].Min, want.Columns[i].Min) ||
+			!octosql.AreEqual(got.Columns[i].Max, want.Columns[i].Max) {
+			t.Errorf("Statistics() column %d = %+v, want %+v", i, got.Columns[i], want.Columns[i])
+		}
+	}
+}
+
+func TestFileStatisticsCache(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-statistics")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	path := filepath.Join(dir, "people.csv")
+	if err := ioutil.WriteFile(path, []byte("name,age\nwojtek,7\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	info, err := os.Stat(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	statisticsDirectory := filepath.Join(dir, "statistics")
+	stats := &FileStatistics{
+		RowCount: 1,
+		Columns: []ColumnStatistics{
+			{Name: "age", NullCount: 0, DistinctCount: 1, Min: octosql.MakeInt(7), Max: octosql.MakeInt(7)},
+		},
+	}
+	if err := saveFileStatistics(statisticsDirectory, path, info, stats); err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := loadFileStatistics(statisticsDirectory, path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got.RowCount != 1 || len(got.Columns) != 1 || !octosql.AreEqual(got.Columns[0].Max, octosql.MakeInt(7)) {
+		t.Errorf("loadFileStatistics() = %+v, want %+v", got, stats)
+	}
+
+	modified := time.Now().Add(time.Hour)
+	if err := os.Chtimes(path, modified, modified); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := loadFileStatistics(statisticsDirectory, path); err == nil {
+		t.Errorf("expected the statistics to be invalid after the file got modified")
+	}
+}
@@ -124,7 +124,7 @@ func TelemetryTransformer(telemetry *Telemetry, datasources []config.DataSourceC
 				switch formula.Relation {
 				case physical.In:
 					telemetry.FormulasUsed.In = true
-				case physical.Like:
+				case physical.Like, physical.ILike:
 					telemetry.FormulasUsed.Like = true
 				case physical.Regexp:
 					telemetry.FormulasUsed.Regexp = true
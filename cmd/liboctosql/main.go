@@ -0,0 +1,346 @@
+// Command liboctosql is built as a C shared library, so that OctoSQL can be embedded in applications written in other languages:
+//   go build -buildmode=c-shared -o liboctosql.so ./cmd/liboctosql
+// which also generates the liboctosql.h header with the API.
+// Query results are returned using the Arrow C stream interface.
+package main
+
+/*
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef ARROW_C_DATA_INTERFACE
+#define ARROW_C_DATA_INTERFACE
+
+#define ARROW_FLAG_DICTIONARY_ORDERED 1
+#define ARROW_FLAG_NULLABLE 2
+#define ARROW_FLAG_MAP_KEYS_SORTED 4
+
+struct ArrowSchema {
+	const char *format;
+	const char *name;
+	const char *metadata;
+	int64_t flags;
+	int64_t n_children;
+	struct ArrowSchema **children;
+	struct ArrowSchema *dictionary;
+	void (*release)(struct ArrowSchema *);
+	void *private_data;
+};
+
+struct ArrowArray {
+	int64_t length;
+	int64_t null_count;
+	int64_t offset;
+	int64_t n_buffers;
+	int64_t n_children;
+	const void **buffers;
+	struct ArrowArray **children;
+	struct ArrowArray *dictionary;
+	void (*release)(struct ArrowArray *);
+	void *private_data;
+};
+
+#endif // ARROW_C_DATA_INTERFACE
+
+#ifndef ARROW_C_STREAM_INTERFACE
+#define ARROW_C_STREAM_INTERFACE
+
+struct ArrowArrayStream {
+	int (*get_schema)(struct ArrowArrayStream *, struct ArrowSchema *out);
+	int (*get_next)(struct ArrowArrayStream *, struct ArrowArray *out);
+	const char *(*get_last_error)(struct ArrowArrayStream *);
+	void (*release)(struct ArrowArrayStream *);
+	void *private_data;
+};
+
+#endif // ARROW_C_STREAM_INTERFACE
+
+// octosql_result is used internally to build the stream returned by octosql_execute.
+struct octosql_result;
+struct octosql_result *octosql_result_new(int64_t length, int64_t n_columns);
+void octosql_result_set_column(struct octosql_result *result, int64_t column, char *name, char *format, int64_t null_count, int64_t n_buffers);
+void octosql_result_set_buffer(struct octosql_result *result, int64_t column, int64_t buffer, void *data);
+void octosql_result_export(struct octosql_result *result, struct ArrowArrayStream *out);
+*/
+import "C"
+
+import (
+	"context"
+	"io"
+	"io/ioutil"
+	"log"
+	"sync"
+	"time"
+	"unsafe"
+
+	"github.com/dgraph-io/badger/v2"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/csv"
+	"github.com/cube2222/octosql/datasources/excel"
+	"github.com/cube2222/octosql/datasources/json"
+	"github.com/cube2222/octosql/datasources/parquet"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	batcharrow "github.com/cube2222/octosql/output/batch/arrow"
+	"github.com/cube2222/octosql/parser"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// The data source types of the tables which can be registered.
+var factories = map[string]physical.Factory{
+	"csv":     csv.NewDataSourceBuilderFactoryFromConfig,
+	"json":    json.NewDataSourceBuilderFactoryFromConfig,
+	"excel":   excel.NewDataSourceBuilderFactoryFromConfig,
+	"parquet": parquet.NewDataSourceBuilderFactoryFromConfig,
+}
+
+// queryContext holds the tables registered by the application, and the storage of the queries it runs.
+type queryContext struct {
+	mutex                sync.Mutex
+	cfg                  *config.Config
+	dataSourceRepository *physical.DataSourceRepository
+	db                   *badger.DB
+	lastError            *C.char
+}
+
+// Contexts are given to C as handles, as Go pointers can't be kept there.
+// The error of the last failed context creation is kept under the 0 handle, which is never given to a context.
+var (
+	contextsMutex   sync.Mutex
+	contexts        = make(map[C.int64_t]*queryContext)
+	lastHandle      C.int64_t
+	lastCreateError *C.char
+)
+
+func init() {
+	// Errors are returned to the application instead.
+	log.SetOutput(ioutil.Discard)
+}
+
+func main() {}
+
+func getContext(handle C.int64_t) *queryContext {
+	contextsMutex.Lock()
+	defer contextsMutex.Unlock()
+	return contexts[handle]
+}
+
+// setError keeps the error message, to be returned by octosql_last_error, and returns the error status.
+func (qc *queryContext) setError(err error) C.int {
+	C.free(unsafe.Pointer(qc.lastError))
+	qc.lastError = C.CString(err.Error())
+	return -1
+}
+
+// recoverError is deferred by the exported functions, so that a panic is returned as an error of the context with the error status,
+// instead of crashing the application.
+func (qc *queryContext) recoverError(status *C.int) {
+	if r := recover(); r != nil {
+		*status = qc.setError(errors.Errorf("panic: %v", r))
+	}
+}
+
+func setCreateError(err error) {
+	contextsMutex.Lock()
+	defer contextsMutex.Unlock()
+	C.free(unsafe.Pointer(lastCreateError))
+	lastCreateError = C.CString(err.Error())
+}
+
+// octosql_context_new creates a context, with no tables registered, returning its handle, or 0 if that fails.
+// The error is then available from octosql_last_error called with the 0 handle.
+//export octosql_context_new
+func octosql_context_new() (handle C.int64_t) {
+	defer func() {
+		if r := recover(); r != nil {
+			setCreateError(errors.Errorf("panic: %v", r))
+			handle = 0
+		}
+	}()
+
+	opts := badger.DefaultOptions("").WithInMemory(true)
+	opts.Logger = nil
+	db, err := badger.Open(opts)
+	if err != nil {
+		setCreateError(errors.Wrap(err, "couldn't open storage"))
+		return 0
+	}
+
+	cfg := &config.Config{}
+	dataSourceRepository, err := physical.CreateDataSourceRepositoryFromConfig(factories, cfg)
+	if err != nil {
+		db.Close()
+		setCreateError(errors.Wrap(err, "couldn't create data source repository"))
+		return 0
+	}
+
+	contextsMutex.Lock()
+	defer contextsMutex.Unlock()
+	lastHandle++
+	contexts[lastHandle] = &queryContext{
+		cfg:                  cfg,
+		dataSourceRepository: dataSourceRepository,
+		db:                   db,
+	}
+	return lastHandle
+}
+
+// octosql_context_free frees the context and its storage.
+//export octosql_context_free
+func octosql_context_free(handle C.int64_t) {
+	// There's no context left to return an error in.
+	defer func() {
+		recover()
+	}()
+
+	contextsMutex.Lock()
+	qc := contexts[handle]
+	delete(contexts, handle)
+	contextsMutex.Unlock()
+	if qc == nil {
+		return
+	}
+
+	qc.mutex.Lock()
+	defer qc.mutex.Unlock()
+	qc.db.Close()
+	C.free(unsafe.Pointer(qc.lastError))
+	qc.lastError = nil
+}
+
+// octosql_last_error returns the message of the last error in the context, which is valid until the next call using the context.
+// With the 0 handle, it returns the message of the last error of octosql_context_new, which is valid until its next call.
+//export octosql_last_error
+func octosql_last_error(handle C.int64_t) *C.char {
+	if handle == 0 {
+		contextsMutex.Lock()
+		defer contextsMutex.Unlock()
+		return lastCreateError
+	}
+
+	qc := getContext(handle)
+	if qc == nil {
+		return nil
+	}
+	qc.mutex.Lock()
+	defer qc.mutex.Unlock()
+	return qc.lastError
+}
+
+// octosql_register_table registers the file under the path as a table with the given name.
+// The type of the file is inferred from its extension: .csv, .tsv, .json, .jsonl, .ndjson, .parquet or .xlsx.
+// Returns 0 on success, and -1 on error.
+//export octosql_register_table
+func octosql_register_table(handle C.int64_t, name *C.char, path *C.char) (status C.int) {
+	qc := getContext(handle)
+	if qc == nil {
+		return -1
+	}
+	qc.mutex.Lock()
+	defer qc.mutex.Unlock()
+	defer qc.recoverError(&status)
+
+	dsConfig, err := config.FileDataSourceConfig(C.GoString(name), C.GoString(path))
+	if err != nil {
+		return qc.setError(errors.Wrap(err, "couldn't create configuration of table"))
+	}
+
+	cfg := *qc.cfg
+	cfg.DataSources = append(cfg.DataSources[:len(cfg.DataSources):len(cfg.DataSources)], dsConfig)
+	dataSourceRepository, err := physical.CreateDataSourceRepositoryFromConfig(factories, &cfg)
+	if err != nil {
+		return qc.setError(errors.Wrap(err, "couldn't register table"))
+	}
+
+	qc.cfg = &cfg
+	qc.dataSourceRepository = dataSourceRepository
+	return 0
+}
+
+// octosql_execute runs the query to completion, and exports its result to out as a stream with a single record batch.
+// Returns 0 on success, and -1 on error.
+//export octosql_execute
+func octosql_execute(handle C.int64_t, query *C.char, out *C.struct_ArrowArrayStream) (status C.int) {
+	qc := getContext(handle)
+	if qc == nil {
+		return -1
+	}
+	qc.mutex.Lock()
+	defer qc.mutex.Unlock()
+	defer qc.recoverError(&status)
+
+	records, err := qc.run(context.Background(), C.GoString(query))
+	if err != nil {
+		return qc.setError(err)
+	}
+
+	columns := batcharrow.GetColumns(records)
+	result := C.octosql_result_new(C.int64_t(len(records)), C.int64_t(len(columns)))
+	for i, column := range columns {
+		C.octosql_result_set_column(
+			result,
+			C.int64_t(i),
+			C.CString(column.Name),
+			C.CString(column.Format()),
+			C.int64_t(column.NullCount),
+			C.int64_t(len(column.Buffers)),
+		)
+		for j, buffer := range column.Buffers {
+			C.octosql_result_set_buffer(result, C.int64_t(i), C.int64_t(j), cBuffer(buffer))
+		}
+	}
+	C.octosql_result_export(result, out)
+	return 0
+}
+
+func (qc *queryContext) run(ctx context.Context, query string) ([]*execution.Record, error) {
+	plan, outputOptions, err := parser.ParseQuery(query)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't parse query")
+	}
+
+	var records []*execution.Record
+	var queryErr error
+	outputSinkFn := func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+		sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+		printer := batch.NewWholeTableWriterPrinter(stateStorage, sink, func(w io.Writer, result []*execution.Record, watermark time.Time, err error) error {
+			records, queryErr = result, err
+			return nil
+		}, ioutil.Discard)
+		return sink, printer
+	}
+
+	telemetryInfo := app.TelemetryInfo{
+		OutputFormat: "c",
+	}
+	queryApp := app.NewApp(qc.cfg, telemetryInfo, qc.dataSourceRepository, outputSinkFn, false)
+	stateStorage := storage.NewBadgerStorage(qc.db)
+	prepared, err := queryApp.Prepare(ctx, stateStorage, plan, outputOptions)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't prepare query")
+	}
+	if err := queryApp.RunPrepared(ctx, stateStorage, prepared, nil); err != nil {
+		return nil, errors.Wrap(err, "couldn't run query")
+	}
+	// With an error, the records are only a part of the result.
+	if queryErr != nil {
+		return nil, errors.Wrap(queryErr, "couldn't run query")
+	}
+
+	return records, nil
+}
+
+// cBuffer copies the buffer to memory allocated with malloc.
+// Empty buffers are allocated too, as some consumers don't accept null buffers.
+func cBuffer(buffer []byte) unsafe.Pointer {
+	if len(buffer) == 0 {
+		return C.malloc(1)
+	}
+	return C.CBytes(buffer)
+}
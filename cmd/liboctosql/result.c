@@ -0,0 +1,163 @@
+#include <string.h>
+
+#include "_cgo_export.h"
+
+// The result is exported as a stream with a single struct array, which has a child array for each column.
+// All its memory is allocated with malloc, and owned by the stream until the array is taken out of it.
+
+struct octosql_column {
+	char *name;
+	char *format;
+	int64_t null_count;
+	int64_t n_buffers;
+	void **buffers;
+};
+
+struct octosql_result {
+	int64_t length;
+	int64_t n_columns;
+	struct octosql_column *columns;
+	int array_taken;
+};
+
+static char *copy_string(const char *s) {
+	char *out = malloc(strlen(s) + 1);
+	strcpy(out, s);
+	return out;
+}
+
+struct octosql_result *octosql_result_new(int64_t length, int64_t n_columns) {
+	struct octosql_result *result = calloc(1, sizeof(struct octosql_result));
+	result->length = length;
+	result->n_columns = n_columns;
+	result->columns = calloc(n_columns, sizeof(struct octosql_column));
+	return result;
+}
+
+// octosql_result_set_column takes the ownership of the name and the format.
+void octosql_result_set_column(struct octosql_result *result, int64_t column, char *name, char *format, int64_t null_count, int64_t n_buffers) {
+	struct octosql_column *c = &result->columns[column];
+	c->name = name;
+	c->format = format;
+	c->null_count = null_count;
+	c->n_buffers = n_buffers;
+	c->buffers = calloc(n_buffers, sizeof(void *));
+}
+
+// octosql_result_set_buffer takes the ownership of the data.
+void octosql_result_set_buffer(struct octosql_result *result, int64_t column, int64_t buffer, void *data) {
+	result->columns[column].buffers[buffer] = data;
+}
+
+static void free_result(struct octosql_result *result) {
+	for (int64_t i = 0; i < result->n_columns; i++) {
+		struct octosql_column *c = &result->columns[i];
+		free(c->name);
+		free(c->format);
+		if (c->buffers != NULL) {
+			for (int64_t j = 0; j < c->n_buffers; j++) {
+				free(c->buffers[j]);
+			}
+			free(c->buffers);
+		}
+	}
+	free(result->columns);
+	free(result);
+}
+
+static void release_schema(struct ArrowSchema *schema) {
+	for (int64_t i = 0; i < schema->n_children; i++) {
+		struct ArrowSchema *child = schema->children[i];
+		if (child->release != NULL) {
+			child->release(child);
+		}
+		free(child);
+	}
+	free(schema->children);
+	free((void *)schema->format);
+	free((void *)schema->name);
+	schema->release = NULL;
+}
+
+static void release_array(struct ArrowArray *array) {
+	for (int64_t i = 0; i < array->n_children; i++) {
+		struct ArrowArray *child = array->children[i];
+		if (child->release != NULL) {
+			child->release(child);
+		}
+		free(child);
+	}
+	free(array->children);
+	for (int64_t i = 0; i < array->n_buffers; i++) {
+		free((void *)array->buffers[i]);
+	}
+	free(array->buffers);
+	array->release = NULL;
+}
+
+static int stream_get_schema(struct ArrowArrayStream *stream, struct ArrowSchema *out) {
+	struct octosql_result *result = stream->private_data;
+
+	memset(out, 0, sizeof(struct ArrowSchema));
+	out->format = copy_string("+s");
+	out->name = copy_string("");
+	out->n_children = result->n_columns;
+	out->children = calloc(result->n_columns, sizeof(struct ArrowSchema *));
+	for (int64_t i = 0; i < result->n_columns; i++) {
+		struct ArrowSchema *child = calloc(1, sizeof(struct ArrowSchema));
+		child->format = copy_string(result->columns[i].format);
+		child->name = copy_string(result->columns[i].name);
+		child->flags = ARROW_FLAG_NULLABLE;
+		child->release = release_schema;
+		out->children[i] = child;
+	}
+	out->release = release_schema;
+	return 0;
+}
+
+// stream_get_next moves the whole result out on the first call, and marks the end of the stream afterwards.
+static int stream_get_next(struct ArrowArrayStream *stream, struct ArrowArray *out) {
+	struct octosql_result *result = stream->private_data;
+
+	memset(out, 0, sizeof(struct ArrowArray));
+	if (result->array_taken) {
+		return 0;
+	}
+	result->array_taken = 1;
+
+	out->length = result->length;
+	out->n_buffers = 1;
+	out->buffers = calloc(1, sizeof(void *));
+	out->n_children = result->n_columns;
+	out->children = calloc(result->n_columns, sizeof(struct ArrowArray *));
+	for (int64_t i = 0; i < result->n_columns; i++) {
+		struct octosql_column *c = &result->columns[i];
+		struct ArrowArray *child = calloc(1, sizeof(struct ArrowArray));
+		child->length = result->length;
+		child->null_count = c->null_count;
+		child->n_buffers = c->n_buffers;
+		child->buffers = (const void **)c->buffers;
+		child->release = release_array;
+		c->buffers = NULL;
+		out->children[i] = child;
+	}
+	out->release = release_array;
+	return 0;
+}
+
+static const char *stream_get_last_error(struct ArrowArrayStream *stream) {
+	return NULL;
+}
+
+static void stream_release(struct ArrowArrayStream *stream) {
+	free_result(stream->private_data);
+	stream->release = NULL;
+}
+
+void octosql_result_export(struct octosql_result *result, struct ArrowArrayStream *out) {
+	out->get_schema = stream_get_schema;
+	out->get_next = stream_get_next;
+	out->get_last_error = stream_get_last_error;
+	out->release = stream_release;
+	out->private_data = result;
+}
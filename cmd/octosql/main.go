@@ -3,30 +3,22 @@ package main
 import (
 	"context"
 	"fmt"
-	"io/ioutil"
 	"log"
 	"net/http"
 	"os"
-	"path"
-	"reflect"
+	"os/signal"
 	"runtime"
 	"runtime/debug"
+	"runtime/trace"
+	"strings"
+	"time"
 
-	"github.com/dgraph-io/badger/v2"
-	"github.com/dgraph-io/badger/v2/options"
 	"github.com/go-chi/chi"
 
 	"github.com/go-chi/chi/middleware"
+	"github.com/pkg/errors"
 
 	"github.com/cube2222/octosql"
-	"github.com/cube2222/octosql/datasources/csv"
-	"github.com/cube2222/octosql/datasources/excel"
-	"github.com/cube2222/octosql/datasources/json"
-	"github.com/cube2222/octosql/datasources/kafka"
-	"github.com/cube2222/octosql/datasources/parquet"
-	"github.com/cube2222/octosql/datasources/redis"
-	"github.com/cube2222/octosql/datasources/sql/mysql"
-	"github.com/cube2222/octosql/datasources/sql/postgres"
 	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/logical"
 	"github.com/cube2222/octosql/output"
@@ -39,10 +31,7 @@ import (
 	"github.com/spf13/cobra"
 
 	"github.com/cube2222/octosql/app"
-	"github.com/cube2222/octosql/config"
 	"github.com/cube2222/octosql/parser"
-	"github.com/cube2222/octosql/parser/sqlparser"
-	"github.com/cube2222/octosql/physical"
 	"github.com/cube2222/octosql/storage"
 )
 
@@ -51,8 +40,27 @@ var configPath string
 var outputFormat string
 var storageDirectory string
 var storageInMemory bool
+var storageCompression string
+var checkpointInterval time.Duration
 var logFilePath string
 var describe bool
+var refreshInterval time.Duration
+var udfPaths []string
+var dataSourcePluginPaths []string
+var params []string
+var threads int
+var timeout time.Duration
+var showProgress bool
+var metricsPort int
+var tracePath string
+var tables []string
+var sessionTimeZone string
+var resultCache bool
+var resultCacheCompression string
+var maxMemory string
+
+// queryStopped is set when the query gets canceled or times out, so that the process can exit with an error after cleaning up.
+var queryStopped bool
 
 var rootCmd = &cobra.Command{
 	Use:   "octosql <query>",
@@ -64,29 +72,41 @@ OctoSQL does that by creating an internal representation of your query and later
 With OctoSQL you don't need O(n) client tools or a large data analysis system deployment. Everything's contained in a single binary.`,
 	Args: cobra.ExactValidArgs(1),
 	Run: func(cmd *cobra.Command, args []string) {
-		ctx := context.Background()
+		ctx, cancel := context.WithCancel(context.Background())
+		defer cancel()
+		if timeout > 0 {
+			var cancelTimeout context.CancelFunc
+			ctx, cancelTimeout = context.WithTimeout(ctx, timeout)
+			defer cancelTimeout()
+		}
 		query := args[0]
 
-		// Configuration
-		cfg, err := config.ReadConfig(configPath)
-		if err != nil {
-			log.Fatal(err)
+		if tracePath != "" {
+			traceFile, err := os.Create(tracePath)
+			if err != nil {
+				log.Fatal("couldn't create trace file: ", err)
+			}
+			defer traceFile.Close()
+			if err := trace.Start(traceFile); err != nil {
+				log.Fatal("couldn't start tracing: ", err)
+			}
+			defer trace.Stop()
 		}
-		dataSourceRespository, err := physical.CreateDataSourceRepositoryFromConfig(
-			map[string]physical.Factory{
-				"csv":      csv.NewDataSourceBuilderFactoryFromConfig,
-				"json":     json.NewDataSourceBuilderFactoryFromConfig,
-				"mysql":    mysql.NewDataSourceBuilderFactoryFromConfig,
-				"postgres": postgres.NewDataSourceBuilderFactoryFromConfig,
-				"redis":    redis.NewDataSourceBuilderFactoryFromConfig,
-				"excel":    excel.NewDataSourceBuilderFactoryFromConfig,
-				"kafka":    kafka.NewDataSourceBuilderFactoryFromConfig,
-				"parquet":  parquet.NewDataSourceBuilderFactoryFromConfig,
-			},
-			cfg,
-		)
-		if err != nil {
-			log.Fatal(err)
+
+		// The first interrupt stops the query, so that the storage still gets closed and cleaned up. A second one kills the process.
+		interrupts := make(chan os.Signal, 1)
+		signal.Notify(interrupts, os.Interrupt)
+		go func() {
+			<-interrupts
+			signal.Stop(interrupts)
+			cancel()
+		}()
+
+		cfg := loadConfig()
+		dataSourceRespository := createDataSourceRepository(cfg)
+
+		if refreshInterval <= 0 {
+			log.Fatal("refresh interval must be positive")
 		}
 
 		var streamingMode bool
@@ -94,36 +114,34 @@ With OctoSQL you don't need O(n) client tools or a large data analysis system de
 		switch outputFormat {
 		case "stream-json":
 			streamingMode = true
+			outputSinkFn = app.ChangesOutputSinkFn(streamingjson.JSONPrinter())
+		case "stream-sorted-json":
 			outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (store execution.IntermediateRecordStore, printer output.Printer) {
-				sink := streaming.NewInstantStreamOutput(streamID)
-				output := streaming.NewStreamPrinter(
-					stateStorage,
-					sink,
-					streamingjson.JSONPrinter(),
-				)
+				sink := streaming.NewSortedStreamOutput(streamID, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset)
+				output := streaming.NewStreamPrinter(stateStorage, sink, streamingjson.JSONPrinter())
 				return sink, output
 			}
 		case "live-csv":
 			outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (store execution.IntermediateRecordStore, printer output.Printer) {
-				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset)
-				output := batch.NewLiveTablePrinter(stateStorage, sink, batchcsv.TableFormatter(','))
+				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+				output := batch.NewLiveTablePrinter(stateStorage, sink, batchcsv.TableFormatter(','), refreshInterval)
 				return sink, output
 			}
 		case "live-table":
 			outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (store execution.IntermediateRecordStore, printer output.Printer) {
-				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset)
-				output := batch.NewLiveTablePrinter(stateStorage, sink, batchtable.TableFormatter(false))
+				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+				output := batch.NewLiveTablePrinter(stateStorage, sink, batchtable.TableFormatter(false), refreshInterval)
 				return sink, output
 			}
 		case "batch-csv":
 			outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (store execution.IntermediateRecordStore, printer output.Printer) {
-				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset)
+				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
 				output := batch.NewWholeTablePrinter(stateStorage, sink, batchcsv.TableFormatter(','))
 				return sink, output
 			}
 		case "batch-table":
 			outputSinkFn = func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (store execution.IntermediateRecordStore, printer output.Printer) {
-				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset)
+				sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
 				output := batch.NewWholeTablePrinter(stateStorage, sink, batchtable.TableFormatter(false))
 				return sink, output
 			}
@@ -136,71 +154,59 @@ With OctoSQL you don't need O(n) client tools or a large data analysis system de
 			Version:      version,
 		}
 
-		app := app.NewApp(cfg, telemetryInfo, dataSourceRespository, outputSinkFn, describe)
+		setUpFunctions()
+		ctx = withSessionTimeZone(ctx, cfg)
 
-		// Parse query
-		stmt, err := sqlparser.Parse(query)
-		if err != nil {
-			log.Fatal("couldn't parse query: ", err)
-		}
-		typed, ok := stmt.(sqlparser.SelectStatement)
-		if !ok {
-			log.Fatalf("invalid statement type, wanted sqlparser.SelectStatement got %v", reflect.TypeOf(stmt))
-		}
-		plan, outputOptions, err := parser.ParseNode(typed)
-		if err != nil {
-			log.Fatal("couldn't parse query: ", err)
-		}
-		if streamingMode {
-			plan = logical.NewOrderBy(outputOptions.OrderByExpressions, outputOptions.OrderByDirections, plan)
+		parameters := make(map[string]octosql.Value, len(params))
+		for _, param := range params {
+			parts := strings.SplitN(param, "=", 2)
+			if len(parts) != 2 {
+				log.Fatalf("invalid query parameter %s, expected name=value", param)
+			}
+			parameters[parts[0]] = execution.ParseType(parts[1])
 		}
 
-		if storageDirectory == "" {
-			tempDir, err := ioutil.TempDir("", "octosql")
-			if err != nil {
-				log.Fatal("couldn't create temporary directory: ", err)
-			}
-			storageDirectory = tempDir
+		if metricsPort > 0 {
+			metrics := app.NewMetrics()
+			ctx = app.WithMetrics(ctx, metrics)
+			go func() {
+				mux := http.NewServeMux()
+				mux.Handle("/metrics", metrics)
+				log.Println("couldn't serve metrics: ", http.ListenAndServe(fmt.Sprintf(":%d", metricsPort), mux))
+			}()
 		}
-		if err := os.MkdirAll(storageDirectory, os.ModePerm); err != nil {
-			log.Fatal("couldn't create storage directory")
+
+		app := app.NewApp(cfg, telemetryInfo, dataSourceRespository, outputSinkFn, describe)
+		if resultCache {
+			app = app.WithResultCache(createResultCache())
 		}
-		defer func() {
-			err = os.RemoveAll(storageDirectory)
+		if maxMemory != "" {
+			limit, err := parseBytes(maxMemory)
 			if err != nil {
-				log.SetOutput(os.Stderr)
-				log.Fatal("couldn't remove temporary directory: ", err)
+				log.Fatal("couldn't parse memory limit: ", err)
 			}
-		}()
+			app = app.WithMaxMemory(limit)
+		}
 
-		if logFilePath == "" {
-			logFilePath = path.Join(storageDirectory, "octosql.log")
+		// The default parallelism of shuffled operators is based on GOMAXPROCS, so this has to be set before planning.
+		if threads > 0 {
+			runtime.GOMAXPROCS(threads)
 		}
-		logFile, err := os.OpenFile(logFilePath, os.O_CREATE|os.O_APPEND|os.O_WRONLY, os.ModePerm)
+
+		// Parse query
+		plan, outputOptions, err := parser.ParseQuery(query)
 		if err != nil {
-			log.Fatalf("couldn't open file for logs %s: %s", logFilePath, err)
-		}
-		defer logFile.Close()
-		log.SetOutput(logFile)
-		log.Printf("Starting OctoSQL...")
-		log.Printf("Using Configuration: %+v", *cfg)
-
-		opts := badger.DefaultOptions(storageDirectory)
-		opts.Logger = badgerLogger{}
-		if runtime.GOOS == "windows" { // TODO - fix while refactoring config
-			opts = opts.WithValueLogLoadingMode(options.FileIO)
+			log.Fatal("couldn't parse query: ", err)
 		}
-		if storageInMemory {
-			opts = opts.
-				WithInMemory(true).
-				WithDir("").
-				WithValueDir("")
+		if streamingMode {
+			plan = logical.NewOrderBy(outputOptions.OrderByExpressions, outputOptions.OrderByDirections, plan)
 		}
 
-		db, err := badger.Open(opts)
-		if err != nil {
-			log.Fatal("couldn't open badger database: ", err)
-		}
+		cleanUpStorageDirectory := setUpStorageDirectory()
+		defer cleanUpStorageDirectory()
+		closeLogFile := setUpLogFile(cfg)
+		defer closeLogFile()
+		db := openDatabase()
 		defer func() {
 			err = db.Close()
 			if err != nil {
@@ -208,11 +214,26 @@ With OctoSQL you don't need O(n) client tools or a large data analysis system de
 			}
 		}()
 
-		stateStorage := storage.NewBadgerStorage(db)
+		stateStorage := createStateStorage(db)
+		if checkpointInterval > 0 && !storageInMemory {
+			go stateStorage.CheckpointPeriodically(ctx, checkpointInterval)
+		}
+
+		if showProgress {
+			progress := execution.NewProgress()
+			ctx = execution.InjectProgress(ctx, progress)
+			stopProgress := make(chan struct{})
+			defer close(stopProgress)
+			go printProgress(progress, refreshInterval, stopProgress)
+		}
 
 		// Run query
-		err = app.RunPlan(ctx, stateStorage, plan, outputOptions)
-		if err != nil {
+		err = app.RunPlan(ctx, stateStorage, plan, outputOptions, parameters)
+		if cause := errors.Cause(err); cause == context.Canceled || cause == context.DeadlineExceeded {
+			fmt.Fprintln(os.Stderr, "query stopped:", cause)
+			queryStopped = true
+			return
+		} else if err != nil {
 			log.Fatal("couldn't run plan: ", err)
 		}
 	},
@@ -228,12 +249,32 @@ func main() {
 	rootCmd.SetVersionTemplate(fmt.Sprintf("OctoSQL Version: %s\n", version))
 	rootCmd.Version = version
 
-	rootCmd.Flags().StringVarP(&configPath, "config", "c", os.Getenv("OCTOSQL_CONFIG"), "data source configuration path, defaults to $OCTOSQL_CONFIG")
-	rootCmd.Flags().StringVarP(&outputFormat, "output", "o", "live-table", "output format, one of [stream-json live-csv live-table batch-csv batch-table]")
-	rootCmd.Flags().StringVar(&storageDirectory, "storage-directory", "", "directory to store state storage in")
-	rootCmd.Flags().BoolVar(&storageInMemory, "storage-in-memory", false, "EXPERIMENTAL: Use badger in-memory mode for storage.")
-	rootCmd.Flags().StringVar(&logFilePath, "log-file", "", "Logs output file, will append if the file exists.")
+	rootCmd.PersistentFlags().StringVarP(&configPath, "config", "c", os.Getenv("OCTOSQL_CONFIG"), "data source configuration path, defaults to $OCTOSQL_CONFIG or ~/.octosql/config.yaml")
+	rootCmd.Flags().StringVarP(&outputFormat, "output", "o", "live-table", "output format, one of [stream-json stream-sorted-json live-csv live-table batch-csv batch-table]")
+	rootCmd.PersistentFlags().StringVar(&storageDirectory, "storage-directory", "", "directory to store state storage in")
+	rootCmd.PersistentFlags().BoolVar(&storageInMemory, "storage-in-memory", false, "EXPERIMENTAL: Use badger in-memory mode for storage.")
+	rootCmd.PersistentFlags().StringVar(&storageCompression, "storage-compression", "", "compression of the state storage, one of [none snappy lz4 zstd], defaults to the storage engine default. Snappy and zstd compress whole storage blocks, lz4 compresses each value. A storage directory has to be reused with the same compression.")
+	rootCmd.PersistentFlags().DurationVar(&checkpointInterval, "checkpoint-interval", 0, "write the state storage to disk in the background this often, instead of on every commit, which is faster but may lose the latest state on a crash, so the query resumes from the last checkpoint. By default every commit is written to disk.")
+	rootCmd.PersistentFlags().StringVar(&logFilePath, "log-file", "", "Logs output file, will append if the file exists.")
+	rootCmd.Flags().DurationVar(&refreshInterval, "refresh-interval", batch.REFRESH_DELAY, "how often live outputs show the current results")
 	rootCmd.Flags().BoolVar(&describe, "describe", false, "Print out the physical query plan in graphviz format. You can use a command like \"dot -Tpng file > output.png\" to view it.")
+	rootCmd.Flags().StringArrayVar(&params, "param", nil, "query parameter given as name=value, can be repeated. Named parameters are written as :name in the query, positional ones as $1 or ?, and given as 1=value.")
+	rootCmd.PersistentFlags().IntVar(&threads, "threads", 0, "number of threads running the query at once, set as GOMAXPROCS, also the default number of partitions of group bys and stream joins, defaults to the number of CPUs")
+	rootCmd.Flags().StringVar(&maxMemory, "max-memory", "", "maximum size of the records the query keeps in memory at once, like 512MB or 2GB, above which it fails with a memory limit exceeded error. Memoized subquery results are evicted first to make room. Unlimited by default.")
+	rootCmd.Flags().DurationVar(&timeout, "timeout", 0, "stop the query after the given duration, no timeout by default")
+	rootCmd.Flags().BoolVar(&showProgress, "progress", false, "periodically print to stderr how much of the input files has already been read")
+	rootCmd.Flags().IntVar(&metricsPort, "metrics-port", 0, "serve per node execution statistics of the query on this port at /metrics, in the Prometheus format")
+	rootCmd.Flags().StringVar(&tracePath, "trace", "", "write an execution trace of the query to this file, which can be viewed using \"go tool trace file\"")
+	rootCmd.Flags().BoolVar(&resultCache, "result-cache", false, "cache the results of bounded queries reading only files in ~/.octosql/results, so running the same query again on unchanged files reads the cached result instead")
+	rootCmd.Flags().StringVar(&resultCacheCompression, "result-cache-compression", "none", "compression of cached results, a default codec followed by the codecs of fields given as field=codec, like zstd,p.name=lz4, with codecs one of [none snappy lz4 zstd]")
+	rootCmd.PersistentFlags().StringVar(&sessionTimeZone, "timezone", "", "time zone used by date_trunc and date_part for times without one, as an IANA name like Europe/Warsaw or an offset like +02:00, defaults to the execution.timeZone configuration option or UTC")
+	rootCmd.PersistentFlags().StringArrayVar(&tables, "table", nil, "file to query as a table, given as name=path, can be repeated. The data source type is inferred from the file extension, one of [.csv .tsv .json .jsonl .ndjson .parquet .xlsx].")
+	rootCmd.PersistentFlags().StringSliceVar(&udfPaths, "udf", nil, "WebAssembly modules (.wasm) or Go plugins with user defined functions to load, can be repeated. WebAssembly modules export the functions with the octosql_udf_ prefix, described in the README. Go plugins have to export a Functions variable of type []execution.Function, and be built with the same Go and OctoSQL versions as this binary (Linux and macOS only).")
+	rootCmd.PersistentFlags().StringSliceVar(&dataSourcePluginPaths, "datasource-plugin", nil, "Go plugins with data sources to load, can be repeated. Each plugin has to export a Factories variable of type map[string]physical.Factory, keyed by data source type, and be built with the same Go and OctoSQL versions as this binary (Linux and macOS only).")
+
+	serveCmd.Flags().StringVar(&postgresAddress, "postgres", "", "serve the Postgres wire protocol on this address, like :5433, which listens on localhost only")
+	serveCmd.Flags().StringVar(&httpAddress, "http", "", "serve the HTTP query API on this address, like :8080, which listens on localhost only")
+	rootCmd.AddCommand(serveCmd)
 
 	go func() {
 		r := chi.NewRouter()
@@ -245,4 +286,7 @@ func main() {
 		fmt.Println(err)
 		os.Exit(1)
 	}
+	if queryStopped {
+		os.Exit(1)
+	}
 }
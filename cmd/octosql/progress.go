@@ -0,0 +1,43 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/cube2222/octosql/execution"
+)
+
+// printProgress periodically prints how much of their input the data sources have read, until stop is closed.
+// It's printed to stderr, so that it doesn't get mixed into the query output.
+func printProgress(progress *execution.Progress, interval time.Duration, stop <-chan struct{}) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-stop:
+			fmt.Fprintln(os.Stderr)
+			return
+		case <-ticker.C:
+		}
+
+		read, total := progress.Read(), progress.Total()
+		if total > 0 {
+			fmt.Fprintf(os.Stderr, "\rread %s of %s (%.1f%%)", formatBytes(read), formatBytes(total), float64(read)/float64(total)*100)
+		} else {
+			fmt.Fprintf(os.Stderr, "\rread %s", formatBytes(read))
+		}
+	}
+}
+
+func formatBytes(bytes int64) string {
+	units := []string{"B", "KB", "MB", "GB", "TB"}
+	value := float64(bytes)
+	unit := 0
+	for value >= 1024 && unit < len(units)-1 {
+		value /= 1024
+		unit++
+	}
+	return fmt.Sprintf("%.1f %s", value, units[unit])
+}
@@ -0,0 +1,125 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"log"
+	"net"
+	"os"
+	"os/signal"
+	"runtime"
+	"sync"
+
+	"github.com/spf13/cobra"
+
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/server/httpapi"
+	"github.com/cube2222/octosql/server/postgres"
+)
+
+var postgresAddress string
+var httpAddress string
+
+var serveCmd = &cobra.Command{
+	Use:   "serve",
+	Short: "Serve queries to the configured tables over the network.",
+	Long: `Serve queries to the configured tables over the network, until interrupted.
+
+With --postgres, OctoSQL speaks enough of the Postgres wire protocol for psql, database drivers and BI tools to run queries, like "psql -h localhost -p 5433".
+Any user name and password are accepted. Addresses without a host, like :5433, only listen on localhost, use 0.0.0.0:5433 to listen on all interfaces.
+
+With --http, queries can be sent to POST /query, with the results returned as JSON, CSV or in the Arrow IPC stream format, or streamed as JSON while the query runs, like "curl localhost:8080/query -d 'SELECT * FROM people'".
+Addresses without a host, like :8080, only listen on localhost too.
+
+Both may be given at once. There's no authentication, so only serve on trusted networks.
+Statements which would write files on the host or print to its output, like CREATE TABLE, EXPLAIN and ANALYZE, are rejected.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx, cancel := context.WithCancel(context.Background())
+		defer cancel()
+
+		if postgresAddress == "" && httpAddress == "" {
+			log.Fatal("no address to serve on given, use --postgres or --http")
+		}
+
+		interrupts := make(chan os.Signal, 1)
+		signal.Notify(interrupts, os.Interrupt)
+		go func() {
+			<-interrupts
+			signal.Stop(interrupts)
+			cancel()
+		}()
+
+		cfg := loadConfig()
+		dataSourceRespository := createDataSourceRepository(cfg)
+		setUpFunctions()
+		ctx = withSessionTimeZone(ctx, cfg)
+
+		if threads > 0 {
+			runtime.GOMAXPROCS(threads)
+		}
+
+		cleanUpStorageDirectory := setUpStorageDirectory()
+		defer cleanUpStorageDirectory()
+		closeLogFile := setUpLogFile(cfg)
+		defer closeLogFile()
+		db := openDatabase()
+		defer func() {
+			if err := db.Close(); err != nil {
+				log.Fatal("couldn't close the database: ", err)
+			}
+		}()
+
+		stateStorage := createStateStorage(db)
+		if checkpointInterval > 0 && !storageInMemory {
+			go stateStorage.CheckpointPeriodically(ctx, checkpointInterval)
+		}
+
+		// Each server runs until interrupted, or until one of them fails, which stops the other one too.
+		var wg sync.WaitGroup
+		errs := make(chan error, 2)
+		serve := func(name string, address string, listenAndServe func(ctx context.Context, address string) error) {
+			wg.Add(1)
+			go func() {
+				defer wg.Done()
+				fmt.Fprintf(os.Stderr, "serving %s on %s\n", name, address)
+				if err := listenAndServe(ctx, address); err != nil && err != context.Canceled {
+					errs <- err
+					cancel()
+				}
+			}()
+		}
+
+		if postgresAddress != "" {
+			telemetryInfo := app.TelemetryInfo{
+				OutputFormat: "postgres",
+				Version:      version,
+			}
+			serve("the Postgres protocol", localAddress(postgresAddress), postgres.NewServer(cfg, telemetryInfo, dataSourceRespository, stateStorage).ListenAndServe)
+		}
+		if httpAddress != "" {
+			telemetryInfo := app.TelemetryInfo{
+				OutputFormat: "http",
+				Version:      version,
+			}
+			serve("http", localAddress(httpAddress), httpapi.NewServer(cfg, telemetryInfo, dataSourceRespository, stateStorage).ListenAndServe)
+		}
+
+		wg.Wait()
+		close(errs)
+		if err := <-errs; err != nil {
+			log.SetOutput(os.Stderr)
+			log.Fatal("couldn't serve: ", err)
+		}
+	},
+}
+
+// localAddress makes the servers listen only on localhost when the address has no host, like :5433,
+// as they have no authentication.
+func localAddress(address string) string {
+	host, port, err := net.SplitHostPort(address)
+	if err != nil || host != "" {
+		return address
+	}
+	return net.JoinHostPort("localhost", port)
+}
@@ -0,0 +1,256 @@
+package main
+
+import (
+	"context"
+	"io/ioutil"
+	"log"
+	"os"
+	"path"
+	"runtime"
+	"strconv"
+	"strings"
+
+	"github.com/dgraph-io/badger/v2"
+	"github.com/dgraph-io/badger/v2/options"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/csv"
+	"github.com/cube2222/octosql/datasources/excel"
+	"github.com/cube2222/octosql/datasources/json"
+	"github.com/cube2222/octosql/datasources/kafka"
+	"github.com/cube2222/octosql/datasources/parquet"
+	"github.com/cube2222/octosql/datasources/redis"
+	"github.com/cube2222/octosql/datasources/sql/mysql"
+	"github.com/cube2222/octosql/datasources/sql/postgres"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/execution/functions"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// loadConfig reads the configuration, which is optional when all the tables are given on the command line.
+func loadConfig() *config.Config {
+	if configPath == "" {
+		if defaultPath, err := config.DefaultPath(); err == nil {
+			if _, err := os.Stat(defaultPath); err == nil {
+				configPath = defaultPath
+			}
+		}
+	}
+	cfg := &config.Config{}
+	if configPath != "" || len(tables) == 0 {
+		var err error
+		cfg, err = config.ReadConfig(configPath)
+		if err != nil {
+			log.Fatal(err)
+		}
+	}
+	for _, table := range tables {
+		parts := strings.SplitN(table, "=", 2)
+		if len(parts) != 2 {
+			log.Fatalf("invalid table %s, expected name=path", table)
+		}
+		dsConfig, err := config.FileDataSourceConfig(parts[0], parts[1])
+		if err != nil {
+			log.Fatalf("couldn't create configuration of table %s: %s", parts[0], err)
+		}
+		cfg.DataSources = append(cfg.DataSources, dsConfig)
+	}
+
+	return cfg
+}
+
+func createDataSourceRepository(cfg *config.Config) *physical.DataSourceRepository {
+	factories := map[string]physical.Factory{
+		"csv":      csv.NewDataSourceBuilderFactoryFromConfig,
+		"json":     json.NewDataSourceBuilderFactoryFromConfig,
+		"mysql":    mysql.NewDataSourceBuilderFactoryFromConfig,
+		"postgres": postgres.NewDataSourceBuilderFactoryFromConfig,
+		"redis":    redis.NewDataSourceBuilderFactoryFromConfig,
+		"excel":    excel.NewDataSourceBuilderFactoryFromConfig,
+		"kafka":    kafka.NewDataSourceBuilderFactoryFromConfig,
+		"parquet":  parquet.NewDataSourceBuilderFactoryFromConfig,
+	}
+	for _, pluginPath := range dataSourcePluginPaths {
+		pluginFactories, err := physical.LoadDataSourcePlugin(pluginPath)
+		if err != nil {
+			log.Fatal("couldn't load data source plugin: ", err)
+		}
+		for dataSourceType, factory := range pluginFactories {
+			if _, ok := factories[dataSourceType]; ok {
+				log.Fatalf("data source type %s from plugin %s is already defined", dataSourceType, pluginPath)
+			}
+			factories[dataSourceType] = factory
+		}
+	}
+
+	dataSourceRespository, err := physical.CreateDataSourceRepositoryFromConfig(factories, cfg)
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	return dataSourceRespository
+}
+
+// createResultCache creates the cache of query results in its default directory.
+func createResultCache() *app.ResultCache {
+	dir, err := app.DefaultResultCacheDirectory()
+	if err != nil {
+		log.Fatal("couldn't get result cache directory: ", err)
+	}
+	codecs, err := app.ParseColumnCodecs(resultCacheCompression)
+	if err != nil {
+		log.Fatal("couldn't parse result cache compression: ", err)
+	}
+
+	return app.NewResultCache(dir).WithCompression(codecs)
+}
+
+// parseBytes parses a size in bytes, optionally followed by one of the units formatBytes uses, like 512MB.
+func parseBytes(size string) (int64, error) {
+	text := strings.ToUpper(strings.TrimSpace(size))
+	multiplier := int64(1)
+	for i, unit := range []string{"KB", "MB", "GB", "TB"} {
+		if strings.HasSuffix(text, unit) {
+			text = strings.TrimSpace(strings.TrimSuffix(text, unit))
+			multiplier = int64(1) << (10 * uint(i+1))
+			break
+		}
+	}
+	if multiplier == 1 {
+		text = strings.TrimSpace(strings.TrimSuffix(text, "B"))
+	}
+
+	value, err := strconv.ParseInt(text, 10, 64)
+	if err != nil || value < 0 {
+		return 0, errors.Errorf("invalid size %s, expected a number of bytes optionally followed by one of [B KB MB GB TB]", size)
+	}
+	return value * multiplier, nil
+}
+
+// setUpFunctions loads the user defined functions.
+func setUpFunctions() {
+	for _, udfPath := range udfPaths {
+		load := functions.LoadPlugin
+		if strings.HasSuffix(udfPath, ".wasm") {
+			load = functions.LoadWasm
+		}
+		if err := load(udfPath); err != nil {
+			log.Fatal("couldn't load user defined functions: ", err)
+		}
+	}
+}
+
+// withSessionTimeZone returns a context in which queries use the session time zone given
+// on the command line, or in the configuration.
+func withSessionTimeZone(ctx context.Context, cfg *config.Config) context.Context {
+	name := sessionTimeZone
+	if name == "" {
+		var err error
+		name, err = config.GetString(cfg.Execution, "timeZone", config.WithDefault("UTC"))
+		if err != nil {
+			log.Fatal("couldn't get timeZone configuration: ", err)
+		}
+	}
+
+	loc, err := octosql.LoadTimeZone(name)
+	if err != nil {
+		log.Fatal("couldn't load session time zone: ", err)
+	}
+	return execution.WithSessionTimeZone(ctx, loc)
+}
+
+// setUpStorageDirectory creates the storage directory, and returns a function removing it if it's a temporary one.
+// A storage directory given by the user is kept, so that the query can be resumed from it later.
+func setUpStorageDirectory() (cleanUp func()) {
+	cleanUp = func() {}
+	if storageDirectory == "" {
+		tempDir, err := ioutil.TempDir("", "octosql")
+		if err != nil {
+			log.Fatal("couldn't create temporary directory: ", err)
+		}
+		storageDirectory = tempDir
+		cleanUp = func() {
+			err := os.RemoveAll(storageDirectory)
+			if err != nil {
+				log.SetOutput(os.Stderr)
+				log.Fatal("couldn't remove temporary directory: ", err)
+			}
+		}
+	}
+	if err := os.MkdirAll(storageDirectory, os.ModePerm); err != nil {
+		log.Fatal("couldn't create storage directory")
+	}
+
+	return cleanUp
+}
+
+// setUpLogFile redirects the logs to the log file, and returns a function closing it.
+func setUpLogFile(cfg *config.Config) (closeLogFile func()) {
+	if logFilePath == "" {
+		logFilePath = path.Join(storageDirectory, "octosql.log")
+	}
+	logFile, err := os.OpenFile(logFilePath, os.O_CREATE|os.O_APPEND|os.O_WRONLY, os.ModePerm)
+	if err != nil {
+		log.Fatalf("couldn't open file for logs %s: %s", logFilePath, err)
+	}
+	log.SetOutput(logFile)
+	log.Printf("Starting OctoSQL...")
+	log.Printf("Using Configuration: %+v", *cfg)
+
+	return func() {
+		logFile.Close()
+	}
+}
+
+func openDatabase() *badger.DB {
+	opts := badger.DefaultOptions(storageDirectory)
+	opts.Logger = badgerLogger{}
+	if runtime.GOOS == "windows" { // TODO - fix while refactoring config
+		opts = opts.WithValueLogLoadingMode(options.FileIO)
+	}
+	switch storageCompression {
+	case "":
+	case "none":
+		opts = opts.WithCompression(options.None)
+	case "snappy":
+		opts = opts.WithCompression(options.Snappy)
+	case "zstd":
+		opts = opts.WithCompression(options.ZSTD)
+	case "lz4":
+		// Badger can't compress its blocks using lz4, so the values get compressed one by one by the state storage instead.
+		opts = opts.WithCompression(options.None)
+	default:
+		log.Fatalf("invalid storage compression: %s", storageCompression)
+	}
+	// With checkpoints the writes are synced to disk periodically, instead of on every commit.
+	if checkpointInterval > 0 {
+		opts = opts.WithSyncWrites(false)
+	}
+	if storageInMemory {
+		opts = opts.
+			WithInMemory(true).
+			WithDir("").
+			WithValueDir("")
+	}
+
+	db, err := badger.Open(opts)
+	if err != nil {
+		log.Fatal("couldn't open badger database: ", err)
+	}
+
+	return db
+}
+
+// createStateStorage creates the state storage in the database, compressing its values if the storage compression requires it.
+func createStateStorage(db *badger.DB) *storage.BadgerStorage {
+	stateStorage := storage.NewBadgerStorage(db)
+	if storageCompression == string(storage.CodecLZ4) {
+		stateStorage = stateStorage.WithValueCompression(storage.CodecLZ4)
+	}
+
+	return stateStorage
+}
@@ -0,0 +1,215 @@
+// +build js,wasm
+
+// Command wasm is built as a WebAssembly module, so that OctoSQL can query JSON data in the browser:
+//   GOOS=js GOARCH=wasm go build -o octosql.wasm ./cmd/wasm
+// It's run using the wasm_exec.js support file of the Go distribution, and sets the global octosql object, with the functions:
+//   registerTable(name, records) registers an array of objects as a table,
+//   fetchTable(name, url) fetches a JSON array or newline delimited JSON objects, and registers them as a table,
+//   query(query) runs the query to completion, and returns its records as an array of objects.
+// All of them return promises.
+// Only in-memory tables are available, and the state of queries is kept in memory.
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"io"
+	"io/ioutil"
+	"net/http"
+	"os"
+	"sync"
+	"syscall/js"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/memory"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	"github.com/cube2222/octosql/parser"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+var (
+	mutex                sync.Mutex
+	dataSourceRepository = physical.NewDataSourceRepository()
+	stateStorage         = storage.NewMemoryStorage()
+)
+
+func main() {
+	// Telemetry would be sent from the pages of the application.
+	os.Setenv("OCTOSQL_TELEMETRY", "0")
+
+	js.Global().Set("octosql", js.ValueOf(map[string]interface{}{
+		"registerTable": js.FuncOf(func(this js.Value, args []js.Value) interface{} {
+			return promise(func() (interface{}, error) {
+				if len(args) != 2 {
+					return nil, errors.New("registerTable expects the name of the table and its records")
+				}
+				data := js.Global().Get("JSON").Call("stringify", args[1]).String()
+				var records []map[string]interface{}
+				if err := json.Unmarshal([]byte(data), &records); err != nil {
+					return nil, errors.Wrap(err, "couldn't decode records, they have to be an array of objects")
+				}
+				registerTable(args[0].String(), records)
+				return nil, nil
+			})
+		}),
+		"fetchTable": js.FuncOf(func(this js.Value, args []js.Value) interface{} {
+			return promise(func() (interface{}, error) {
+				if len(args) != 2 {
+					return nil, errors.New("fetchTable expects the name of the table and the url of its records")
+				}
+				records, err := fetchRecords(args[1].String())
+				if err != nil {
+					return nil, err
+				}
+				registerTable(args[0].String(), records)
+				return nil, nil
+			})
+		}),
+		"query": js.FuncOf(func(this js.Value, args []js.Value) interface{} {
+			return promise(func() (interface{}, error) {
+				if len(args) != 1 {
+					return nil, errors.New("query expects the query")
+				}
+				records, err := run(context.Background(), args[0].String())
+				if err != nil {
+					return nil, err
+				}
+				return toJS(records)
+			})
+		}),
+	}))
+
+	select {}
+}
+
+// promise runs fn in a separate goroutine, as blocking the JavaScript event loop would deadlock fetches,
+// and returns a promise of its result.
+func promise(fn func() (interface{}, error)) js.Value {
+	handler := js.FuncOf(func(this js.Value, args []js.Value) interface{} {
+		resolve, reject := args[0], args[1]
+		go func() {
+			result, err := fn()
+			if err != nil {
+				reject.Invoke(js.Global().Get("Error").New(err.Error()))
+				return
+			}
+			resolve.Invoke(result)
+		}()
+		return nil
+	})
+	defer handler.Release()
+
+	return js.Global().Get("Promise").New(handler)
+}
+
+func registerTable(name string, records []map[string]interface{}) {
+	mutex.Lock()
+	defer mutex.Unlock()
+	dataSourceRepository = dataSourceRepository.WithFactory(name, memory.NewDataSourceBuilderFactory(records))
+}
+
+// fetchRecords gets the records under the url, using the fetch API of the browser.
+// They can be a JSON array of objects, or newline delimited JSON objects.
+func fetchRecords(url string) ([]map[string]interface{}, error) {
+	res, err := http.Get(url)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't fetch records")
+	}
+	defer res.Body.Close()
+	if res.StatusCode != http.StatusOK {
+		return nil, errors.Errorf("couldn't fetch records, got status %s", res.Status)
+	}
+
+	data, err := ioutil.ReadAll(res.Body)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read records")
+	}
+
+	var records []map[string]interface{}
+	if trimmed := bytes.TrimSpace(data); len(trimmed) > 0 && trimmed[0] == '[' {
+		if err := json.Unmarshal(trimmed, &records); err != nil {
+			return nil, errors.Wrap(err, "couldn't decode records")
+		}
+		return records, nil
+	}
+
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	for {
+		var record map[string]interface{}
+		if err := decoder.Decode(&record); err == io.EOF {
+			break
+		} else if err != nil {
+			return nil, errors.Wrapf(err, "couldn't decode record with index %d", len(records))
+		}
+		records = append(records, record)
+	}
+	return records, nil
+}
+
+func run(ctx context.Context, query string) ([]*execution.Record, error) {
+	plan, outputOptions, err := parser.ParseQuery(query)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't parse query")
+	}
+
+	var records []*execution.Record
+	var queryErr error
+	outputSinkFn := func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+		sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+		printer := batch.NewWholeTableWriterPrinter(stateStorage, sink, func(w io.Writer, result []*execution.Record, watermark time.Time, err error) error {
+			records, queryErr = result, err
+			return nil
+		}, ioutil.Discard)
+		return sink, printer
+	}
+
+	mutex.Lock()
+	repository := dataSourceRepository
+	mutex.Unlock()
+
+	telemetryInfo := app.TelemetryInfo{
+		OutputFormat: "wasm",
+	}
+	queryApp := app.NewApp(&config.Config{}, telemetryInfo, repository, outputSinkFn, false)
+	prepared, err := queryApp.Prepare(ctx, stateStorage, plan, outputOptions)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't prepare query")
+	}
+	if err := queryApp.RunPrepared(ctx, stateStorage, prepared, nil); err != nil {
+		return nil, errors.Wrap(err, "couldn't run query")
+	}
+	// With an error, the records are only a part of the result.
+	if queryErr != nil {
+		return nil, errors.Wrap(queryErr, "couldn't run query")
+	}
+
+	return records, nil
+}
+
+// toJS converts the records to JavaScript objects, with their values encoded like in the json output format.
+func toJS(records []*execution.Record) (js.Value, error) {
+	fields := batch.GetAllFields(records)
+
+	objects := make([]map[string]interface{}, len(records))
+	for i, record := range records {
+		objects[i] = make(map[string]interface{}, len(fields))
+		for _, field := range fields {
+			objects[i][field] = record.Value(octosql.NewVariableName(field)).ToRawValue()
+		}
+	}
+
+	data, err := json.Marshal(objects)
+	if err != nil {
+		return js.Undefined(), errors.Wrap(err, "couldn't encode records")
+	}
+	return js.Global().Get("JSON").Call("parse", string(data)), nil
+}
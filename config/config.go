@@ -3,7 +3,10 @@ package config
 import (
 	"fmt"
 	"os"
+	"path/filepath"
+	"strings"
 
+	"github.com/mitchellh/go-homedir"
 	"github.com/pkg/errors"
 	"gopkg.in/yaml.v2"
 )
@@ -30,6 +33,45 @@ func (config *Config) GetDataSourceConfig(name string) (map[string]interface{},
 	return nil, ErrNotFound
 }
 
+// DefaultPath returns the path of the configuration file used when none is given explicitly.
+func DefaultPath() (string, error) {
+	dir, err := homedir.Dir()
+	if err != nil {
+		return "", errors.Wrap(err, "couldn't get home directory")
+	}
+
+	return filepath.Join(dir, ".octosql", "config.yaml"), nil
+}
+
+// FileDataSourceConfig creates the configuration of a data source reading the file under the given path.
+// The data source type is inferred from the file extension.
+func FileDataSourceConfig(name, path string) (DataSourceConfig, error) {
+	dsConfig := DataSourceConfig{
+		Name: name,
+		Config: map[string]interface{}{
+			"path": path,
+		},
+	}
+
+	switch ext := strings.ToLower(filepath.Ext(path)); ext {
+	case ".csv":
+		dsConfig.Type = "csv"
+	case ".tsv":
+		dsConfig.Type = "csv"
+		dsConfig.Config["separator"] = "\t"
+	case ".json", ".jsonl", ".ndjson":
+		dsConfig.Type = "json"
+	case ".parquet":
+		dsConfig.Type = "parquet"
+	case ".xlsx":
+		dsConfig.Type = "excel"
+	default:
+		return DataSourceConfig{}, errors.Errorf("unsupported file extension '%s', available extensions: .csv, .tsv, .json, .jsonl, .ndjson, .parquet, .xlsx", ext)
+	}
+
+	return dsConfig, nil
+}
+
 func ReadConfig(path string) (*Config, error) {
 	f, err := os.Open(path)
 	if err != nil {
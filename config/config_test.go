@@ -78,3 +78,57 @@ func TestReadConfig(t *testing.T) {
 		})
 	}
 }
+
+func TestFileDataSourceConfig(t *testing.T) {
+	tests := []struct {
+		name    string
+		path    string
+		want    DataSourceConfig
+		wantErr bool
+	}{
+		{
+			name: "csv",
+			path: "data/users.csv",
+			want: DataSourceConfig{
+				Name:   "users",
+				Type:   "csv",
+				Config: map[string]interface{}{"path": "data/users.csv"},
+			},
+		},
+		{
+			name: "tsv",
+			path: "data/users.TSV",
+			want: DataSourceConfig{
+				Name:   "users",
+				Type:   "csv",
+				Config: map[string]interface{}{"path": "data/users.TSV", "separator": "\t"},
+			},
+		},
+		{
+			name: "json lines",
+			path: "users.jsonl",
+			want: DataSourceConfig{
+				Name:   "users",
+				Type:   "json",
+				Config: map[string]interface{}{"path": "users.jsonl"},
+			},
+		},
+		{
+			name:    "unknown extension",
+			path:    "users.txt",
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := FileDataSourceConfig("users", tt.path)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("FileDataSourceConfig() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if !tt.wantErr && !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("FileDataSourceConfig() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,173 @@
+package dataframe
+
+import (
+	"strings"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/logical"
+)
+
+// Column is an expression evaluated for each record, like a field, a constant or a function call.
+type Column struct {
+	expr logical.Expression
+}
+
+// Col is the field with the given name, qualified with the alias of its table, like "p.age".
+func Col(name string) Column {
+	return Column{expr: logical.NewVariable(octosql.NewVariableName(name))}
+}
+
+// Lit is a constant value, given as a Go value like an int, float64, string, bool or time.Time.
+func Lit(value interface{}) Column {
+	return Column{expr: logical.NewConstant(value)}
+}
+
+// Star is all the fields of the table with the given alias, or of all the tables if it's empty.
+// It can only be selected.
+func Star(qualifier string) Column {
+	return Column{expr: logical.NewStarExpression(qualifier)}
+}
+
+// Fn is a call of the function with the given name, the same as in SQL.
+func Fn(name string, args ...Column) Column {
+	exprs := make([]logical.Expression, len(args))
+	for i := range args {
+		exprs[i] = args[i].expr
+	}
+	return Column{expr: logical.NewFunctionExpression(name, exprs)}
+}
+
+// As names the column, which is required for all the selected columns other than fields.
+func (c Column) As(name string) Column {
+	return Column{expr: logical.NewAliasedExpression(octosql.NewVariableName(name), c.expr)}
+}
+
+func (c Column) Eq(other Column) Condition {
+	return c.compare(logical.Equal, other)
+}
+
+func (c Column) NotEq(other Column) Condition {
+	return c.compare(logical.NotEqual, other)
+}
+
+func (c Column) Gt(other Column) Condition {
+	return c.compare(logical.MoreThan, other)
+}
+
+func (c Column) GtEq(other Column) Condition {
+	return c.compare(logical.GreaterEqual, other)
+}
+
+func (c Column) Lt(other Column) Condition {
+	return c.compare(logical.LessThan, other)
+}
+
+func (c Column) LtEq(other Column) Condition {
+	return c.compare(logical.LessEqual, other)
+}
+
+func (c Column) Like(pattern Column) Condition {
+	return c.compare(logical.Like, pattern)
+}
+
+// In checks if the value is one of the given values.
+func (c Column) In(values ...Column) Condition {
+	exprs := make([]logical.Expression, len(values))
+	for i := range values {
+		exprs[i] = values[i].expr
+	}
+	return Condition{formula: logical.NewPredicate(c.expr, logical.In, logical.NewTuple(exprs))}
+}
+
+func (c Column) IsNull() Condition {
+	return Condition{formula: logical.NewIsNull(c.expr)}
+}
+
+func (c Column) compare(relation logical.Relation, other Column) Condition {
+	return Condition{formula: logical.NewPredicate(c.expr, relation, other.expr)}
+}
+
+// named returns the column as a named expression, which it has to be to become a field.
+func (c Column) named() (logical.NamedExpression, error) {
+	named, ok := c.expr.(logical.NamedExpression)
+	if !ok {
+		return nil, errors.New("expressions other than fields must be named using As")
+	}
+	return named, nil
+}
+
+// Condition is a boolean formula used to filter and join records.
+type Condition struct {
+	formula logical.Formula
+}
+
+func (cond Condition) And(other Condition) Condition {
+	return Condition{formula: logical.NewInfixOperator(cond.formula, other.formula, "AND")}
+}
+
+func (cond Condition) Or(other Condition) Condition {
+	return Condition{formula: logical.NewInfixOperator(cond.formula, other.formula, "OR")}
+}
+
+func Not(cond Condition) Condition {
+	return Condition{formula: logical.NewPrefixOperator(cond.formula, "NOT")}
+}
+
+// Aggregation is an aggregate of a column computed for each group of records.
+type Aggregation struct {
+	aggregate logical.Aggregate
+	argument  *Column
+	name      octosql.VariableName
+}
+
+// Agg is the aggregate with the given name, the same as in SQL, like "count" or "sum_distinct".
+func Agg(aggregate string, argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Aggregate(strings.ToLower(aggregate)), argument: &argument}
+}
+
+// CountAll counts the records of each group, like COUNT(*).
+func CountAll() Aggregation {
+	return Aggregation{aggregate: logical.Count}
+}
+
+func Count(argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Count, argument: &argument}
+}
+
+func Sum(argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Sum, argument: &argument}
+}
+
+func Avg(argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Avg, argument: &argument}
+}
+
+func Min(argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Min, argument: &argument}
+}
+
+func Max(argument Column) Aggregation {
+	return Aggregation{aggregate: logical.Max, argument: &argument}
+}
+
+// As names the aggregated field. By default it's named after the field and the aggregate, like "p.age_sum".
+func (aggregation Aggregation) As(name string) Aggregation {
+	aggregation.name = octosql.NewVariableName(name)
+	return aggregation
+}
+
+// SortColumn is a column records are ordered by.
+type SortColumn struct {
+	expr      logical.Expression
+	direction logical.OrderDirection
+}
+
+func (c Column) Asc() SortColumn {
+	return SortColumn{expr: c.expr, direction: logical.OrderDirection("asc")}
+}
+
+func (c Column) Desc() SortColumn {
+	return SortColumn{expr: c.expr, direction: logical.OrderDirection("desc")}
+}
@@ -0,0 +1,220 @@
+// Package dataframe builds queries from Go code, as an alternative to writing them in SQL.
+//
+// A DataFrame creates the same logical plan nodes the SQL parser does, so its plan gets run the same way as a parsed query:
+//
+//	plan, outputOptions, err := dataframe.Table("people", "p").
+//		Filter(dataframe.Col("p.age").Gt(dataframe.Lit(3))).
+//		GroupBy(dataframe.Col("p.city")).
+//		Agg(dataframe.CountAll().As("count")).
+//		OrderBy(dataframe.Col("count").Desc()).
+//		Plan()
+//	if err != nil {
+//		...
+//	}
+//	err = app.RunPlan(ctx, stateStorage, plan, outputOptions, nil)
+package dataframe
+
+import (
+	"fmt"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/logical"
+)
+
+// DataFrame is a query, built by chaining its operations. Each operation returns a new DataFrame,
+// so a DataFrame can be reused as the base of multiple queries.
+// Errors, like selecting an unnamed expression, are returned by Plan.
+type DataFrame struct {
+	node          logical.Node
+	outputOptions logical.OutputOptions
+	err           error
+}
+
+// Table reads the data source with the given name, with its fields qualified with the alias.
+func Table(name, alias string) *DataFrame {
+	return &DataFrame{node: logical.NewDataSource(name, alias)}
+}
+
+// Plan returns the logical plan of the query, with its output options, which can be run using app.App's RunPlan.
+func (df *DataFrame) Plan() (logical.Node, *logical.OutputOptions, error) {
+	if df.err != nil {
+		return nil, nil, df.err
+	}
+	outputOptions := df.outputOptions
+	return df.node, &outputOptions, nil
+}
+
+func (df *DataFrame) with(node logical.Node) *DataFrame {
+	if df.err == nil && df.isOrderedOrLimited() {
+		return df.withError(errors.New("OrderBy, Limit and Offset apply to the output of the query, so they have to come last"))
+	}
+	return &DataFrame{node: node, err: df.err}
+}
+
+func (df *DataFrame) isOrderedOrLimited() bool {
+	return len(df.outputOptions.OrderByExpressions) > 0 || df.outputOptions.Limit != nil || df.outputOptions.Offset != nil
+}
+
+func (df *DataFrame) withError(err error) *DataFrame {
+	if df.err != nil {
+		return df
+	}
+	return &DataFrame{err: err}
+}
+
+// Filter keeps the records satisfying the condition, like WHERE.
+func (df *DataFrame) Filter(cond Condition) *DataFrame {
+	return df.with(logical.NewFilter(cond.formula, df.node))
+}
+
+// Select keeps only the given columns. Columns other than fields and stars have to be named using As.
+func (df *DataFrame) Select(columns ...Column) *DataFrame {
+	expressions := make([]logical.NamedExpression, len(columns))
+	for i := range columns {
+		named, err := columns[i].named()
+		if err != nil {
+			return df.withError(errors.Wrapf(err, "invalid selected column with index %d", i))
+		}
+		expressions[i] = named
+	}
+	return df.with(logical.NewMap(expressions, df.node, false))
+}
+
+// WithColumns adds the given named columns, keeping all the existing fields.
+func (df *DataFrame) WithColumns(columns ...Column) *DataFrame {
+	expressions := make([]logical.NamedExpression, len(columns))
+	for i := range columns {
+		named, err := columns[i].named()
+		if err != nil {
+			return df.withError(errors.Wrapf(err, "invalid added column with index %d", i))
+		}
+		expressions[i] = named
+	}
+	return df.with(logical.NewMap(expressions, df.node, true))
+}
+
+// Join joins the records of the other DataFrame satisfying the condition, like INNER JOIN.
+func (df *DataFrame) Join(other *DataFrame, on Condition) *DataFrame {
+	return df.join(other, on, execution.INNER_JOIN)
+}
+
+// LeftJoin joins the records of the other DataFrame satisfying the condition, like LEFT JOIN.
+func (df *DataFrame) LeftJoin(other *DataFrame, on Condition) *DataFrame {
+	return df.join(other, on, execution.LEFT_JOIN)
+}
+
+func (df *DataFrame) join(other *DataFrame, on Condition, joinType execution.JoinType) *DataFrame {
+	if other.err != nil {
+		return df.withError(errors.Wrap(other.err, "invalid joined data frame"))
+	}
+	if other.isOrderedOrLimited() {
+		return df.withError(errors.New("joined data frame can't be ordered or limited"))
+	}
+	return df.with(logical.NewJoin(df.node, logical.NewFilter(on.formula, other.node), joinType))
+}
+
+// Distinct removes duplicate records.
+func (df *DataFrame) Distinct() *DataFrame {
+	return df.with(logical.NewDistinct(df.node))
+}
+
+// OrderBy orders the output of the query by the given columns, which have to be output fields.
+// As with ORDER BY, it only applies to the final output.
+func (df *DataFrame) OrderBy(columns ...SortColumn) *DataFrame {
+	out := *df
+	out.outputOptions.OrderByExpressions = make([]logical.Expression, len(columns))
+	out.outputOptions.OrderByDirections = make([]logical.OrderDirection, len(columns))
+	for i := range columns {
+		out.outputOptions.OrderByExpressions[i] = columns[i].expr
+		out.outputOptions.OrderByDirections[i] = columns[i].direction
+	}
+	return &out
+}
+
+// Limit limits the output of the query to the given number of records.
+func (df *DataFrame) Limit(limit int) *DataFrame {
+	out := *df
+	out.outputOptions.Limit = logical.NewConstant(limit)
+	return &out
+}
+
+// Offset skips the given number of records of the output of the query.
+func (df *DataFrame) Offset(offset int) *DataFrame {
+	out := *df
+	out.outputOptions.Offset = logical.NewConstant(offset)
+	return &out
+}
+
+// GroupBy groups the records by the given key columns. The group is aggregated using Agg.
+func (df *DataFrame) GroupBy(keys ...Column) *GroupedDataFrame {
+	return &GroupedDataFrame{df: df, keys: keys}
+}
+
+// GroupedDataFrame is a DataFrame with its records grouped by a key, waiting to be aggregated.
+type GroupedDataFrame struct {
+	df   *DataFrame
+	keys []Column
+}
+
+// Agg aggregates each group of records, which results in a record with the key columns and the aggregated fields.
+// As in SQL, the keys and the arguments of the aggregates are evaluated by a map before grouping.
+func (g *GroupedDataFrame) Agg(aggregations ...Aggregation) *DataFrame {
+	df := g.df
+	var mapped []logical.NamedExpression
+	key := make([]logical.Expression, len(g.keys))
+	var fields []octosql.VariableName
+	var aggregates []logical.Aggregate
+	var as []octosql.VariableName
+	var outputs []logical.NamedExpression
+
+	for i := range g.keys {
+		named, err := g.keys[i].named()
+		if err != nil {
+			return df.withError(errors.Wrapf(err, "invalid group key with index %d", i))
+		}
+		mapped = append(mapped, named)
+		key[i] = logical.NewVariable(named.Name())
+
+		fields = append(fields, named.Name())
+		aggregates = append(aggregates, logical.Key)
+		as = append(as, named.Name())
+		outputs = append(outputs, logical.NewVariable(named.Name()))
+	}
+	if len(key) == 0 {
+		key = []logical.Expression{logical.NewConstant(true)}
+	}
+
+	for i, aggregation := range aggregations {
+		if _, ok := logical.AggregateFunctions[aggregation.aggregate]; !ok {
+			return df.withError(errors.Errorf("invalid aggregate with index %d: %s", i, aggregation.aggregate))
+		}
+
+		field := octosql.NewVariableName(octosql.StarExpressionName)
+		if aggregation.argument != nil {
+			named, err := aggregation.argument.named()
+			if err != nil {
+				return df.withError(errors.Wrapf(err, "invalid argument of aggregate with index %d", i))
+			}
+			mapped = append(mapped, named)
+			field = named.Name()
+		}
+
+		name := aggregation.name
+		if name.Empty() {
+			name = octosql.NewVariableName(fmt.Sprintf("%v_%v", field, aggregation.aggregate))
+		}
+
+		fields = append(fields, field)
+		aggregates = append(aggregates, aggregation.aggregate)
+		as = append(as, name)
+		outputs = append(outputs, logical.NewVariable(name))
+	}
+
+	var node logical.Node = logical.NewMap(mapped, df.node, true)
+	node = logical.NewGroupBy(node, key, fields, aggregates, as, nil)
+	node = logical.NewMap(outputs, node, false)
+	return df.with(node)
+}
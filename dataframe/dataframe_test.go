@@ -0,0 +1,147 @@
+package dataframe
+
+import (
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/logical"
+)
+
+func TestDataFrame_Plan(t *testing.T) {
+	tests := []struct {
+		name    string
+		df      *DataFrame
+		want    logical.Node
+		wantErr bool
+	}{
+		{
+			name: "filter and select",
+			df: Table("people", "p").
+				Filter(Col("p.age").Gt(Lit(3)).And(Not(Col("p.city").IsNull()))).
+				Select(Col("p.name"), Fn("upper", Col("p.city")).As("city")),
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+					logical.NewAliasedExpression("city", logical.NewFunctionExpression("upper", []logical.Expression{logical.NewVariable("p.city")})),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewPredicate(logical.NewVariable("p.age"), logical.MoreThan, logical.NewConstant(3)),
+						logical.NewPrefixOperator(logical.NewIsNull(logical.NewVariable("p.city")), "NOT"),
+						"AND",
+					),
+					logical.NewDataSource("people", "p"),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "join",
+			df: Table("people", "p").
+				Join(Table("cities", "c"), Col("p.city").Eq(Col("c.name"))),
+			want: logical.NewJoin(
+				logical.NewDataSource("people", "p"),
+				logical.NewFilter(
+					logical.NewPredicate(logical.NewVariable("p.city"), logical.Equal, logical.NewVariable("c.name")),
+					logical.NewDataSource("cities", "c"),
+				),
+				execution.INNER_JOIN,
+			),
+			wantErr: false,
+		},
+		{
+			name: "group by",
+			df: Table("people", "p").
+				GroupBy(Col("p.city")).
+				Agg(CountAll().As("count"), Max(Col("p.age"))),
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.city"),
+					logical.NewVariable("count"),
+					logical.NewVariable("p.age_max"),
+				},
+				logical.NewGroupBy(
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.city"),
+							logical.NewVariable("p.age"),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+					[]logical.Expression{logical.NewVariable("p.city")},
+					[]octosql.VariableName{"p.city", octosql.StarExpressionName, "p.age"},
+					[]logical.Aggregate{logical.Key, logical.Count, logical.Max},
+					[]octosql.VariableName{"p.city", "count", "p.age_max"},
+					nil,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name:    "unnamed selected expression",
+			df:      Table("people", "p").Select(Fn("upper", Col("p.city"))),
+			wantErr: true,
+		},
+		{
+			name: "filter after limit",
+			df: Table("people", "p").
+				Limit(3).
+				Filter(Col("p.age").Gt(Lit(3))),
+			wantErr: true,
+		},
+		{
+			name: "invalid aggregate",
+			df: Table("people", "p").
+				GroupBy(Col("p.city")).
+				Agg(Agg("median", Col("p.age"))),
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, _, err := tt.df.Plan()
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Plan() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+
+			if err := logical.EqualNodes(got, tt.want); err != nil {
+				t.Errorf("Plan() = %v, want %v: %v", got, tt.want, err)
+			}
+		})
+	}
+}
+
+func TestDataFrame_OutputOptions(t *testing.T) {
+	_, outputOptions, err := Table("people", "p").
+		OrderBy(Col("p.age").Desc(), Col("p.name").Asc()).
+		Limit(10).
+		Offset(5).
+		Plan()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if len(outputOptions.OrderByExpressions) != 2 {
+		t.Fatalf("Plan() order by expressions = %v, want 2 expressions", outputOptions.OrderByExpressions)
+	}
+	if err := logical.EqualExpressions(outputOptions.OrderByExpressions[0], logical.NewVariable("p.age")); err != nil {
+		t.Errorf("Plan() first order by expression: %v", err)
+	}
+	if outputOptions.OrderByDirections[0] != "desc" || outputOptions.OrderByDirections[1] != "asc" {
+		t.Errorf("Plan() order by directions = %v, want [desc asc]", outputOptions.OrderByDirections)
+	}
+	if err := logical.EqualExpressions(outputOptions.Limit, logical.NewConstant(10)); err != nil {
+		t.Errorf("Plan() limit: %v", err)
+	}
+	if err := logical.EqualExpressions(outputOptions.Offset, logical.NewConstant(5)); err != nil {
+		t.Errorf("Plan() offset: %v", err)
+	}
+}
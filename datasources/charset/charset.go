@@ -0,0 +1,223 @@
+package charset
+
+import (
+	"bufio"
+	"io"
+	"strings"
+	"unicode/utf16"
+	"unicode/utf8"
+
+	"github.com/pkg/errors"
+	"golang.org/x/text/encoding/charmap"
+	"golang.org/x/text/encoding/unicode"
+	"golang.org/x/text/transform"
+)
+
+// Encoding is the character encoding of a text file.
+type Encoding int
+
+const (
+	UTF8 Encoding = iota
+	UTF16LE
+	UTF16BE
+	Latin1
+)
+
+// ParseEncoding returns the encoding with the given name.
+// UTF-16 without the byte order given is big endian, unless the file starts with a byte order mark.
+func ParseEncoding(name string) (Encoding, error) {
+	switch strings.ToLower(name) {
+	case "utf-8", "utf8":
+		return UTF8, nil
+	case "utf-16", "utf16", "utf-16be", "utf16be":
+		return UTF16BE, nil
+	case "utf-16le", "utf16le":
+		return UTF16LE, nil
+	case "latin-1", "latin1", "iso-8859-1":
+		return Latin1, nil
+	default:
+		return UTF8, errors.Errorf("unknown encoding %s, available encodings: utf-8, utf-16, utf-16le, utf-16be, latin-1", name)
+	}
+}
+
+// ErrInvalidEncoding is returned by strict readers when the input isn't valid in its encoding.
+var ErrInvalidEncoding = errors.New("invalid encoding")
+
+// NewReader returns a reader decoding the input in the given encoding to UTF-8.
+// A byte order mark at the beginning of a UTF-8 or UTF-16 input is skipped, and decides between those encodings.
+// Strict readers fail on invalid input, others replace it with the unicode replacement character,
+// apart from UTF-8 input, which is passed through as is.
+func NewReader(r io.Reader, encoding Encoding, strict bool) io.Reader {
+	if strict {
+		// The decoders of golang.org/x/text always replace invalid input, so strict readers decode it by hand,
+		// to be able to tell where it's invalid.
+		return &reader{
+			source:   bufio.NewReader(r),
+			encoding: encoding,
+			strict:   strict,
+		}
+	}
+
+	switch encoding {
+	case UTF16LE:
+		return transform.NewReader(r, unicode.BOMOverride(unicode.UTF16(unicode.LittleEndian, unicode.IgnoreBOM).NewDecoder()))
+	case UTF16BE:
+		return transform.NewReader(r, unicode.BOMOverride(unicode.UTF16(unicode.BigEndian, unicode.IgnoreBOM).NewDecoder()))
+	case Latin1:
+		// Any bytes are valid Latin-1 characters, so there's no telling a byte order mark apart.
+		return transform.NewReader(r, charmap.ISO8859_1.NewDecoder())
+	default:
+		return transform.NewReader(r, unicode.BOMOverride(transform.Nop))
+	}
+}
+
+type reader struct {
+	source   *bufio.Reader
+	encoding Encoding
+	strict   bool
+	started  bool
+	offset   int
+	pending  []byte
+	err      error
+}
+
+func (r *reader) Read(p []byte) (int, error) {
+	if !r.started {
+		r.started = true
+		r.err = r.readByteOrderMark()
+	}
+
+	n := copy(p, r.pending)
+	r.pending = r.pending[n:]
+	for len(r.pending) == 0 && n < len(p) && r.err == nil {
+		var ch rune
+		ch, r.err = r.readRune()
+		if r.err != nil {
+			break
+		}
+
+		var buf [utf8.UTFMax]byte
+		size := utf8.EncodeRune(buf[:], ch)
+		copied := copy(p[n:], buf[:size])
+		n += copied
+		r.pending = append(r.pending, buf[copied:size]...)
+	}
+
+	if n > 0 {
+		return n, nil
+	}
+	return 0, r.err
+}
+
+func (r *reader) readByteOrderMark() error {
+	if r.encoding == Latin1 {
+		// Any bytes are valid Latin-1 characters, so there's no telling a byte order mark apart.
+		return nil
+	}
+
+	prefix, err := r.source.Peek(3)
+	if err != nil && err != io.EOF {
+		return err
+	}
+
+	var size int
+	switch {
+	case len(prefix) >= 3 && prefix[0] == 0xEF && prefix[1] == 0xBB && prefix[2] == 0xBF:
+		r.encoding, size = UTF8, 3
+	case len(prefix) >= 2 && prefix[0] == 0xFF && prefix[1] == 0xFE:
+		r.encoding, size = UTF16LE, 2
+	case len(prefix) >= 2 && prefix[0] == 0xFE && prefix[1] == 0xFF:
+		r.encoding, size = UTF16BE, 2
+	}
+
+	r.offset += size
+	_, err = r.source.Discard(size)
+	return err
+}
+
+func (r *reader) readRune() (rune, error) {
+	switch r.encoding {
+	case UTF8:
+		ch, size, err := r.source.ReadRune()
+		if err != nil {
+			return 0, err
+		}
+		r.offset += size
+		if ch == utf8.RuneError && size == 1 {
+			return r.invalid("utf-8 sequence", size)
+		}
+		return ch, nil
+
+	case UTF16LE, UTF16BE:
+		unit, err := r.peekUTF16Unit()
+		if err == io.EOF {
+			// There may be a single byte left.
+			if _, err := r.source.ReadByte(); err != nil {
+				return 0, err
+			}
+			r.offset++
+			return r.invalid("utf-16 code unit cut off by the end of input", 1)
+		} else if err != nil {
+			return 0, err
+		}
+		r.offset += 2
+		if _, err := r.source.Discard(2); err != nil {
+			return 0, err
+		}
+		if !utf16.IsSurrogate(unit) {
+			return unit, nil
+		}
+		if unit >= 0xDC00 {
+			return r.invalid("utf-16 low surrogate without a high one", 2)
+		}
+
+		next, err := r.peekUTF16Unit()
+		if err != nil && err != io.EOF {
+			return 0, err
+		}
+		ch := utf8.RuneError
+		if err == nil {
+			ch = utf16.DecodeRune(unit, next)
+		}
+		if ch == utf8.RuneError {
+			return r.invalid("utf-16 high surrogate without a low one", 2)
+		}
+		r.offset += 2
+		_, err = r.source.Discard(2)
+		return ch, err
+
+	case Latin1:
+		b, err := r.source.ReadByte()
+		if err != nil {
+			return 0, err
+		}
+		r.offset++
+		return rune(b), nil
+	}
+
+	panic("unreachable")
+}
+
+// peekUTF16Unit returns the next code unit without reading it, or io.EOF if there's no complete one left.
+func (r *reader) peekUTF16Unit() (rune, error) {
+	b, err := r.source.Peek(2)
+	if len(b) < 2 {
+		if err == nil {
+			err = io.EOF
+		}
+		return 0, err
+	}
+
+	if r.encoding == UTF16LE {
+		return rune(b[0]) | rune(b[1])<<8, nil
+	}
+	return rune(b[0])<<8 | rune(b[1]), nil
+}
+
+// invalid handles an invalid character ending at the current offset and having the given size.
+func (r *reader) invalid(description string, size int) (rune, error) {
+	if r.strict {
+		return 0, errors.Wrapf(ErrInvalidEncoding, "invalid %s at byte %d", description, r.offset-size)
+	}
+	return utf8.RuneError, nil
+}
@@ -0,0 +1,152 @@
+package charset
+
+import (
+	"bytes"
+	"io/ioutil"
+	"testing"
+
+	"github.com/pkg/errors"
+)
+
+func TestReader(t *testing.T) {
+	tests := []struct {
+		name     string
+		input    []byte
+		encoding Encoding
+		strict   bool
+		want     string
+		wantErr  bool
+	}{
+		{
+			name:     "utf-8",
+			input:    []byte("zażółć,1\n"),
+			encoding: UTF8,
+			want:     "zażółć,1\n",
+		},
+		{
+			name:     "utf-8 with byte order mark",
+			input:    []byte("\xEF\xBB\xBFname,age\n"),
+			encoding: UTF8,
+			want:     "name,age\n",
+		},
+		{
+			name:     "invalid utf-8 passed through",
+			input:    []byte("a\xFFb"),
+			encoding: UTF8,
+			want:     "a\xFFb",
+		},
+		{
+			name:     "invalid utf-8 in strict mode",
+			input:    []byte("a\xFFb"),
+			encoding: UTF8,
+			strict:   true,
+			wantErr:  true,
+		},
+		{
+			name:     "utf-16 little endian",
+			input:    []byte{'a', 0, 0x7C, 0x01, '\n', 0},
+			encoding: UTF16LE,
+			want:     "aż\n",
+		},
+		{
+			name:     "utf-16 big endian with surrogate pair",
+			input:    []byte{0, 'a', 0xD8, 0x3D, 0xDE, 0x00},
+			encoding: UTF16BE,
+			want:     "a😀",
+		},
+		{
+			name:     "utf-16 byte order mark overrides utf-8",
+			input:    []byte{0xFF, 0xFE, 'o', 0, 'k', 0},
+			encoding: UTF8,
+			want:     "ok",
+		},
+		{
+			name:     "utf-16 byte order mark overrides byte order",
+			input:    []byte{0xFE, 0xFF, 0, 'o', 0, 'k'},
+			encoding: UTF16LE,
+			want:     "ok",
+		},
+		{
+			name:     "unpaired utf-16 surrogate replaced",
+			input:    []byte{0xD8, 0x3D, 0, 'a'},
+			encoding: UTF16BE,
+			want:     "\uFFFDa",
+		},
+		{
+			name:     "unpaired utf-16 surrogate in strict mode",
+			input:    []byte{0xD8, 0x3D, 0, 'a'},
+			encoding: UTF16BE,
+			strict:   true,
+			wantErr:  true,
+		},
+		{
+			name:     "utf-16 byte order mark in strict mode",
+			input:    []byte{0xFF, 0xFE, 'o', 0, 'k', 0},
+			encoding: UTF8,
+			strict:   true,
+			want:     "ok",
+		},
+		{
+			name:     "latin-1 not strict",
+			input:    []byte("caf\xE9 \xFF\xFE"),
+			encoding: Latin1,
+			want:     "café ÿþ",
+		},
+		{
+			name:     "utf-16 cut off in strict mode",
+			input:    []byte{0, 'a', 0},
+			encoding: UTF16BE,
+			strict:   true,
+			wantErr:  true,
+		},
+		{
+			name:     "latin-1",
+			input:    []byte("caf\xE9 \xFF\xFE"),
+			encoding: Latin1,
+			strict:   true,
+			want:     "café ÿþ",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ioutil.ReadAll(NewReader(bytes.NewReader(tt.input), tt.encoding, tt.strict))
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ReadAll() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				if errors.Cause(err) != ErrInvalidEncoding {
+					t.Errorf("ReadAll() error = %v, want invalid encoding", err)
+				}
+				return
+			}
+			if string(got) != tt.want {
+				t.Errorf("ReadAll() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestParseEncoding(t *testing.T) {
+	tests := []struct {
+		name    string
+		want    Encoding
+		wantErr bool
+	}{
+		{name: "UTF-8", want: UTF8},
+		{name: "utf-16", want: UTF16BE},
+		{name: "utf-16le", want: UTF16LE},
+		{name: "ISO-8859-1", want: Latin1},
+		{name: "ebcdic", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ParseEncoding(tt.name)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseEncoding() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err == nil && got != tt.want {
+				t.Errorf("ParseEncoding() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
@@ -10,12 +10,15 @@ import (
 	"io"
 	"log"
 	"os"
+	"reflect"
 	"unicode/utf8"
 
 	"github.com/pkg/errors"
 
 	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/charset"
+	"github.com/cube2222/octosql/datasources/invalidrecords"
 	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/physical"
 	"github.com/cube2222/octosql/physical/metadata"
@@ -28,12 +31,16 @@ var availableFilters = map[physical.FieldType]map[physical.Relation]struct{}{
 }
 
 type DataSource struct {
-	path           string
-	alias          string
-	hasColumnNames bool
-	separator      rune
-	batchSize      int
-	stateStorage   storage.Storage
+	path                string
+	alias               string
+	hasColumnNames      bool
+	separator           rune
+	columnTypes         map[octosql.VariableName]string
+	encoding            charset.Encoding
+	strictEncoding      bool
+	invalidRecordPolicy *invalidrecords.Policy
+	batchSize           int
+	stateStorage        storage.Storage
 }
 
 func NewDataSourceBuilderFactory() physical.DataSourceBuilderFactory {
@@ -55,18 +62,53 @@ func NewDataSourceBuilderFactory() physical.DataSourceBuilderFactory {
 			if r == utf8.RuneError {
 				return nil, errors.Errorf("couldn't decode separator %s to rune", separator)
 			}
+			columnTypesConfig, err := config.GetMap(dbConfig, "columnTypes", config.WithDefault(map[string]interface{}{}))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't get column types")
+			}
+			columnTypes := make(map[octosql.VariableName]string, len(columnTypesConfig))
+			for column, value := range columnTypesConfig {
+				datatype, ok := value.(string)
+				if !ok {
+					return nil, errors.Errorf("expected string type of column %s, got %v", column, reflect.TypeOf(value))
+				}
+				if _, err := execution.ParseTypeAs("", datatype); err != nil {
+					return nil, errors.Wrapf(err, "invalid type of column %s", column)
+				}
+				columnTypes[octosql.NewVariableName(fmt.Sprintf("%s.%s", alias, column))] = datatype
+			}
+			encodingName, err := config.GetString(dbConfig, "encoding", config.WithDefault("utf-8"))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't get encoding")
+			}
+			encoding, err := charset.ParseEncoding(encodingName)
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't parse encoding")
+			}
+			strictEncoding, err := config.GetBool(dbConfig, "strictEncoding", config.WithDefault(false))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't get strictEncoding")
+			}
+			invalidRecordPolicy, err := invalidrecords.GetPolicy(dbConfig)
+			if err != nil {
+				return nil, err
+			}
 			batchSize, err := config.GetInt(dbConfig, "batchSize", config.WithDefault(1000))
 			if err != nil {
 				return nil, errors.Wrap(err, "couldn't get batch size")
 			}
 
 			return &DataSource{
-				path:           path,
-				alias:          alias,
-				hasColumnNames: hasColumns,
-				separator:      r,
-				batchSize:      batchSize,
-				stateStorage:   matCtx.Storage,
+				path:                path,
+				alias:               alias,
+				hasColumnNames:      hasColumns,
+				separator:           r,
+				columnTypes:         columnTypes,
+				encoding:            encoding,
+				strictEncoding:      strictEncoding,
+				invalidRecordPolicy: invalidRecordPolicy,
+				batchSize:           batchSize,
+				stateStorage:        matCtx.Storage,
 			}, nil
 		},
 		nil,
@@ -87,6 +129,10 @@ func (ds *DataSource) Get(ctx context.Context, variables octosql.Variables, stre
 		streamID:        streamID,
 		filePath:        ds.path,
 		separator:       ds.separator,
+		columnTypes:     ds.columnTypes,
+		encoding:        ds.encoding,
+		strictEncoding:  ds.strictEncoding,
+		invalidRecords:  ds.invalidRecordPolicy.NewHandler(ds.path),
 		isDone:          false,
 		alias:           ds.alias,
 		first:           true,
@@ -94,6 +140,13 @@ func (ds *DataSource) Get(ctx context.Context, variables octosql.Variables, stre
 		batchSize:       ds.batchSize,
 	}
 
+	// The total size is only used for progress reporting, a missing file is reported when opening it.
+	var total int64
+	if info, err := os.Stat(ds.path); err == nil {
+		total = info.Size()
+	}
+	rs.progress = execution.GetProgressFromContext(ctx).NewSource(total)
+
 	ctx, cancel := context.WithCancel(ctx)
 	rs.workerCtxCancel = cancel
 	rs.workerCloseErrChan = make(chan error, 1)
@@ -122,6 +175,10 @@ type RecordStream struct {
 	streamID        *execution.StreamID
 	filePath        string
 	separator       rune
+	columnTypes     map[octosql.VariableName]string
+	encoding        charset.Encoding
+	strictEncoding  bool
+	invalidRecords  *invalidrecords.Handler
 	file            *os.File
 	r               *csv.Reader
 	isDone          bool
@@ -131,6 +188,7 @@ type RecordStream struct {
 	hasColumnHeader bool
 	offset          int
 	batchSize       int
+	progress        *execution.SourceProgress
 
 	workerCtxCancel    func()
 	workerCloseErrChan chan error
@@ -176,7 +234,8 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 		if err != nil {
 			return errors.Wrap(err, "couldn't open file")
 		}
-		r := csv.NewReader(file)
+		rs.progress.SetRead(0)
+		r := csv.NewReader(charset.NewReader(rs.progress.Reader(file), rs.encoding, rs.strictEncoding))
 		r.Comma = rs.separator
 		r.TrimLeadingSpace = true
 
@@ -188,6 +247,9 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 			_, err := rs.readRecordFromFileWithInitialize()
 			if err == execution.ErrEndOfStream {
 				return ctx.Err()
+			} else if errors.Cause(err) == errInvalidRecord {
+				// Skipped invalid records are counted in the offset too.
+				continue
 			} else if err != nil {
 				return errors.Wrapf(err, "couldn't move csv file iterator by %d offset", rs.offset)
 			}
@@ -253,15 +315,33 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		if err != nil {
 			return errors.Wrapf(err, "couldn't push csv EndOfStream to output record queue")
 		}
+		if err := rs.invalidRecords.Close(); err != nil {
+			return err
+		}
 
 		return execution.ErrEndOfStream
 	}
 
 	batch := make([]*execution.Record, 0)
-	for i := 0; i < rs.batchSize; i++ {
+	read := 0
+	var invalidRecordErr error
+	for read < rs.batchSize {
 		aliasedRecord, err := rs.readRecordFromFileWithInitialize()
 		if err == execution.ErrEndOfStream {
 			break
+		} else if cause := errors.Cause(err); cause == errInvalidRecord || cause == charset.ErrInvalidEncoding {
+			if cause == errInvalidRecord {
+				skipped, handleErr := rs.invalidRecords.Handle(rs.offset+read, err)
+				if handleErr != nil {
+					return errors.Wrap(handleErr, "couldn't handle invalid record")
+				}
+				if skipped {
+					read++
+					continue
+				}
+			}
+			invalidRecordErr = err
+			break
 		} else if err != nil {
 			return errors.Wrap(err, "couldn't read record from csv file")
 		}
@@ -269,7 +349,8 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		batch = append(batch, execution.NewRecord(
 			rs.aliasedFields,
 			aliasedRecord,
-			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(rs.streamID, rs.offset+i))))
+			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(rs.streamID, rs.offset+read))))
+		read++
 	}
 
 	for i := range batch {
@@ -283,11 +364,26 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		}
 	}
 
-	rs.offset = rs.offset + len(batch)
+	if invalidRecordErr != nil {
+		err := outputQueue.Push(ctx, &QueueElement{
+			Type: &QueueElement_Error{
+				Error: invalidRecordErr.Error(),
+			},
+		})
+		if err != nil {
+			return errors.Wrap(err, "couldn't push csv error to output record queue")
+		}
+	}
+
+	rs.offset = rs.offset + read
 	if err := rs.saveOffset(tx); err != nil {
 		return errors.Wrap(err, "couldn't save csv offset")
 	}
 
+	if invalidRecordErr != nil {
+		return execution.ErrEndOfStream
+	}
+
 	return nil
 }
 
@@ -318,13 +414,19 @@ func (rs *RecordStream) readRecordFromFileWithInitialize() (map[octosql.Variable
 	if err == io.EOF {
 		rs.isDone = true
 		return nil, execution.ErrEndOfStream
+	} else if parseErr, ok := err.(*csv.ParseError); ok {
+		return nil, errors.Wrapf(errInvalidRecord, "couldn't parse record: %s", parseErr)
 	} else if err != nil {
 		return nil, errors.Wrap(err, "couldn't read record")
 	}
 
 	aliasedRecord := make(map[octosql.VariableName]octosql.Value)
 	for i, v := range line {
-		aliasedRecord[rs.aliasedFields[i]] = execution.ParseType(v)
+		value, err := rs.parseValue(rs.aliasedFields[i], v)
+		if err != nil {
+			return nil, err
+		}
+		aliasedRecord[rs.aliasedFields[i]] = value
 	}
 
 	return aliasedRecord, nil
@@ -367,12 +469,32 @@ func (rs *RecordStream) initializeColumnsWithoutHeaderRow() (map[octosql.Variabl
 
 	aliasedRecord := make(map[octosql.VariableName]octosql.Value)
 	for i, v := range line {
-		aliasedRecord[rs.aliasedFields[i]] = execution.ParseType(v)
+		value, err := rs.parseValue(rs.aliasedFields[i], v)
+		if err != nil {
+			return nil, err
+		}
+		aliasedRecord[rs.aliasedFields[i]] = value
 	}
 
 	return aliasedRecord, nil
 }
 
+var errInvalidRecord = errors.New("invalid record")
+
+// parseValue parses the value using the type declared for its column, or infers the type if there's none.
+func (rs *RecordStream) parseValue(field octosql.VariableName, value string) (octosql.Value, error) {
+	datatype, ok := rs.columnTypes[field]
+	if !ok {
+		return execution.ParseType(value), nil
+	}
+
+	parsed, err := execution.ParseTypeAs(value, datatype)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrapf(errInvalidRecord, "couldn't parse value of column %s: %s", field, err)
+	}
+	return parsed, nil
+}
+
 func parseDataTypes(row []string) []octosql.Value {
 	resultRow := make([]octosql.Value, len(row))
 	for i, v := range row {
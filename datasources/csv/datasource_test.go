@@ -111,6 +111,8 @@ func TestCSVRecordStream_Next(t *testing.T) {
 		csvName         string
 		hasColumnHeader bool
 		separator       string
+		columnTypes     map[string]interface{}
+		onInvalidRecord string
 		fields          []string
 		want            []*execution.Record
 	}{
@@ -147,6 +149,20 @@ func TestCSVRecordStream_Next(t *testing.T) {
 		//	fields:          []string{"name", "surname"},
 		//	want:            []*execution.Record{},
 		//},
+		{
+			name:            "wrong numbers of columns in a row - skipping invalid records",
+			csvName:         "wrongCount",
+			hasColumnHeader: true,
+			separator:       ",",
+			onInvalidRecord: "skip",
+			fields:          []string{"name", "surname"},
+			want: []*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"wc.name", "wc.surname"},
+					[]interface{}{"test", "test"},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 0))),
+			},
+		},
 		//{ Infinite loop, because error in reading line occurs
 		//	name:            "not unique columns",
 		//	csvName:         "notUnique",
@@ -244,6 +260,32 @@ func TestCSVRecordStream_Next(t *testing.T) {
 					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 3))),
 			},
 		},
+		{
+			name:            "reading people.csv - declared column types",
+			csvName:         "people",
+			hasColumnHeader: true,
+			separator:       ",",
+			columnTypes:     map[string]interface{}{"age": "string", "city": "string"},
+			fields:          []string{"name", "surname", "age", "city"},
+			want: []*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.name", "p.surname", "p.age", "p.city"},
+					[]interface{}{"jan", "chomiak", "3", "warsaw"},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 0))),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.name", "p.surname", "p.age", "p.city"},
+					[]interface{}{"wojtek", "kuzminski", "4", "warsaw"},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 1))),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.name", "p.surname", "p.age", "p.city"},
+					[]interface{}{"adam", "cz", "5", "ciechanowo"},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 2))),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.name", "p.surname", "p.age", "p.city"},
+					[]interface{}{"kuba", "m", "2", "warsaw"},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 3))),
+			},
+		},
 		{
 			name:            "reading peopleSemicolon.csv - wrong separator",
 			csvName:         "people",
@@ -275,17 +317,23 @@ func TestCSVRecordStream_Next(t *testing.T) {
 		t.Run(tt.name, func(t *testing.T) {
 			stateStorage := storage.GetTestStorage(t)
 
+			dsConfig := map[string]interface{}{
+				"path":        csvDbs[tt.csvName].path,
+				"headerRow":   tt.hasColumnHeader,
+				"separator":   tt.separator,
+				"columnTypes": tt.columnTypes,
+				"batchSize":   2,
+			}
+			if tt.onInvalidRecord != "" {
+				dsConfig["onInvalidRecord"] = tt.onInvalidRecord
+			}
+
 			ds, err := NewDataSourceBuilderFactory()("test", csvDbs[tt.csvName].alias)[0].Materialize(context.Background(), &physical.MaterializationContext{
 				Config: &config.Config{
 					DataSources: []config.DataSourceConfig{
 						{
-							Name: "test",
-							Config: map[string]interface{}{
-								"path":      csvDbs[tt.csvName].path,
-								"headerRow": tt.hasColumnHeader,
-								"separator": tt.separator,
-								"batchSize": 2,
-							},
+							Name:   "test",
+							Config: dsConfig,
 						},
 					},
 				},
@@ -0,0 +1,122 @@
+package invalidrecords
+
+import (
+	"encoding/json"
+	"log"
+	"os"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/config"
+)
+
+// Policy is what a file data source does with the records it can't parse, set by its onInvalidRecord option.
+type Policy struct {
+	onInvalidRecord string
+	rejectedPath    string
+}
+
+const (
+	// Fail fails the query on the first invalid record.
+	Fail = "fail"
+	// Skip skips invalid records, and logs their number at the end of the file.
+	Skip = "skip"
+	// Reject skips invalid records like Skip, and also appends them to the file given by the rejectedPath option,
+	// one JSON object per line, so that they can be queried later as a table of the json data source.
+	Reject = "reject"
+)
+
+// GetPolicy reads the policy from the configuration of a data source.
+func GetPolicy(dbConfig map[string]interface{}) (*Policy, error) {
+	onInvalidRecord, err := config.GetString(dbConfig, "onInvalidRecord", config.WithDefault(Fail))
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get invalid record policy")
+	}
+
+	policy := &Policy{onInvalidRecord: onInvalidRecord}
+	switch onInvalidRecord {
+	case Fail, Skip:
+	case Reject:
+		policy.rejectedPath, err = config.GetString(dbConfig, "rejectedPath")
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't get path of the file for rejected records")
+		}
+	default:
+		return nil, errors.Errorf("invalid record policy must be fail, skip or reject, got %s", onInvalidRecord)
+	}
+
+	return policy, nil
+}
+
+// NewHandler creates a handler for the invalid records of a single stream reading the given file.
+func (policy *Policy) NewHandler(path string) *Handler {
+	return &Handler{
+		policy: policy,
+		path:   path,
+	}
+}
+
+// Handler counts the invalid records skipped in a single stream, and writes the rejected ones.
+type Handler struct {
+	policy   *Policy
+	path     string
+	skipped  int
+	rejected *os.File
+}
+
+type rejectedRecord struct {
+	File   string `json:"file"`
+	Record int    `json:"record"`
+	Error  string `json:"error"`
+}
+
+// Handle is called with each invalid record, and its position in the file.
+// It returns false if the query has to fail with the error instead.
+// Reading the file again won't help in that case, so the error should be passed on after the records read before it.
+func (h *Handler) Handle(position int, invalidRecordErr error) (bool, error) {
+	switch h.policy.onInvalidRecord {
+	case Skip:
+	case Reject:
+		if h.rejected == nil {
+			file, err := os.OpenFile(h.policy.rejectedPath, os.O_CREATE|os.O_APPEND|os.O_WRONLY, 0644)
+			if err != nil {
+				return false, errors.Wrap(err, "couldn't open file for rejected records")
+			}
+			h.rejected = file
+		}
+
+		data, err := json.Marshal(rejectedRecord{
+			File:   h.path,
+			Record: position,
+			Error:  invalidRecordErr.Error(),
+		})
+		if err != nil {
+			return false, errors.Wrap(err, "couldn't encode rejected record")
+		}
+		if _, err := h.rejected.Write(append(data, '\n')); err != nil {
+			return false, errors.Wrap(err, "couldn't write rejected record")
+		}
+	default:
+		return false, nil
+	}
+
+	h.skipped++
+	return true, nil
+}
+
+// Close logs the number of skipped records, if there were any, and closes the file for rejected records.
+// It may be called more than once.
+func (h *Handler) Close() error {
+	if h.skipped > 0 {
+		log.Printf("skipped %d invalid records of %s", h.skipped, h.path)
+		h.skipped = 0
+	}
+	if h.rejected != nil {
+		err := h.rejected.Close()
+		h.rejected = nil
+		if err != nil {
+			return errors.Wrap(err, "couldn't close file for rejected records")
+		}
+	}
+	return nil
+}
@@ -0,0 +1,115 @@
+package invalidrecords
+
+import (
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/pkg/errors"
+)
+
+func TestGetPolicy(t *testing.T) {
+	tests := []struct {
+		name    string
+		config  map[string]interface{}
+		wantErr bool
+	}{
+		{
+			name:   "default",
+			config: map[string]interface{}{},
+		},
+		{
+			name:   "skip",
+			config: map[string]interface{}{"onInvalidRecord": "skip"},
+		},
+		{
+			name:   "reject",
+			config: map[string]interface{}{"onInvalidRecord": "reject", "rejectedPath": "rejected.json"},
+		},
+		{
+			name:    "reject without path",
+			config:  map[string]interface{}{"onInvalidRecord": "reject"},
+			wantErr: true,
+		},
+		{
+			name:    "unknown policy",
+			config:  map[string]interface{}{"onInvalidRecord": "ignore"},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := GetPolicy(tt.config); (err != nil) != tt.wantErr {
+				t.Errorf("GetPolicy() error = %v, wantErr %v", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestHandler_Handle(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-invalid-records")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+	rejectedPath := filepath.Join(dir, "rejected.json")
+
+	invalidRecordErr := errors.New("wrong number of fields")
+
+	tests := []struct {
+		name         string
+		config       map[string]interface{}
+		wantSkipped  bool
+		wantRejected string
+	}{
+		{
+			name:        "fail",
+			config:      map[string]interface{}{},
+			wantSkipped: false,
+		},
+		{
+			name:        "skip",
+			config:      map[string]interface{}{"onInvalidRecord": "skip"},
+			wantSkipped: true,
+		},
+		{
+			name:         "reject",
+			config:       map[string]interface{}{"onInvalidRecord": "reject", "rejectedPath": rejectedPath},
+			wantSkipped:  true,
+			wantRejected: `{"file":"people.csv","record":3,"error":"wrong number of fields"}` + "\n" + `{"file":"people.csv","record":7,"error":"wrong number of fields"}` + "\n",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			policy, err := GetPolicy(tt.config)
+			if err != nil {
+				t.Fatal(err)
+			}
+			handler := policy.NewHandler("people.csv")
+
+			for _, position := range []int{3, 7} {
+				skipped, err := handler.Handle(position, invalidRecordErr)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if skipped != tt.wantSkipped {
+					t.Errorf("Handle() = %v, want %v", skipped, tt.wantSkipped)
+				}
+			}
+			if err := handler.Close(); err != nil {
+				t.Fatal(err)
+			}
+
+			if tt.wantRejected != "" {
+				data, err := ioutil.ReadFile(rejectedPath)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if string(data) != tt.wantRejected {
+					t.Errorf("rejected records = %s, want %s", data, tt.wantRejected)
+				}
+			}
+		})
+	}
+}
@@ -1,18 +1,21 @@
 package json
 
 import (
+	"bufio"
 	"context"
 	"encoding/json"
 	"fmt"
+	"io"
 	"log"
 	"os"
 	"sort"
-	"time"
 
 	"github.com/pkg/errors"
 
 	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/charset"
+	"github.com/cube2222/octosql/datasources/invalidrecords"
 	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/physical"
 	"github.com/cube2222/octosql/physical/metadata"
@@ -25,11 +28,14 @@ var availableFilters = map[physical.FieldType]map[physical.Relation]struct{}{
 }
 
 type DataSource struct {
-	path         string
-	alias        string
-	arrayFormat  bool
-	batchSize    int
-	stateStorage storage.Storage
+	path                string
+	alias               string
+	arrayFormat         bool
+	encoding            charset.Encoding
+	strictEncoding      bool
+	invalidRecordPolicy *invalidrecords.Policy
+	batchSize           int
+	stateStorage        storage.Storage
 }
 
 func NewDataSourceBuilderFactory() physical.DataSourceBuilderFactory {
@@ -43,17 +49,36 @@ func NewDataSourceBuilderFactory() physical.DataSourceBuilderFactory {
 			if err != nil {
 				return nil, errors.Wrap(err, "couldn't get if json in array form")
 			}
+			encodingName, err := config.GetString(dbConfig, "encoding", config.WithDefault("utf-8"))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't get encoding")
+			}
+			encoding, err := charset.ParseEncoding(encodingName)
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't parse encoding")
+			}
+			strictEncoding, err := config.GetBool(dbConfig, "strictEncoding", config.WithDefault(false))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't get strictEncoding")
+			}
+			invalidRecordPolicy, err := invalidrecords.GetPolicy(dbConfig)
+			if err != nil {
+				return nil, err
+			}
 			batchSize, err := config.GetInt(dbConfig, "batchSize", config.WithDefault(1000))
 			if err != nil {
 				return nil, errors.Wrap(err, "couldn't get batch size")
 			}
 
 			return &DataSource{
-				path:         path,
-				arrayFormat:  arrayFormat,
-				alias:        alias,
-				batchSize:    batchSize,
-				stateStorage: matCtx.Storage,
+				path:                path,
+				arrayFormat:         arrayFormat,
+				encoding:            encoding,
+				strictEncoding:      strictEncoding,
+				invalidRecordPolicy: invalidRecordPolicy,
+				alias:               alias,
+				batchSize:           batchSize,
+				stateStorage:        matCtx.Storage,
 			}, nil
 		},
 		nil,
@@ -74,12 +99,22 @@ func (ds *DataSource) Get(ctx context.Context, variables octosql.Variables, stre
 		streamID:                      streamID,
 		arrayFormat:                   ds.arrayFormat,
 		arrayFormatOpeningBracketRead: false,
+		encoding:                      ds.encoding,
+		strictEncoding:                ds.strictEncoding,
+		invalidRecords:                ds.invalidRecordPolicy.NewHandler(ds.path),
 		filePath:                      ds.path,
 		isDone:                        false,
 		alias:                         ds.alias,
 		batchSize:                     ds.batchSize,
 	}
 
+	// The total size is only used for progress reporting, a missing file is reported when opening it.
+	var total int64
+	if info, err := os.Stat(ds.path); err == nil {
+		total = info.Size()
+	}
+	rs.progress = execution.GetProgressFromContext(ctx).NewSource(total)
+
 	ctx, cancel := context.WithCancel(ctx)
 	rs.workerCtxCancel = cancel
 	rs.workerCloseErrChan = make(chan error, 1)
@@ -108,13 +143,18 @@ type RecordStream struct {
 	streamID                      *execution.StreamID
 	arrayFormat                   bool
 	arrayFormatOpeningBracketRead bool
+	encoding                      charset.Encoding
+	strictEncoding                bool
+	invalidRecords                *invalidrecords.Handler
 	filePath                      string
 	file                          *os.File
+	reader                        io.Reader
 	decoder                       *json.Decoder
 	isDone                        bool
 	alias                         string
 	offset                        int
 	batchSize                     int
+	progress                      *execution.SourceProgress
 
 	workerCtxCancel    func()
 	workerCloseErrChan chan error
@@ -162,13 +202,18 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 		}
 
 		rs.file = file
-		rs.decoder = json.NewDecoder(file)
+		rs.progress.SetRead(0)
+		rs.reader = charset.NewReader(rs.progress.Reader(file), rs.encoding, rs.strictEncoding)
+		rs.decoder = json.NewDecoder(rs.reader)
 
 		// Moving file iterator by `rs.offset`
 		for i := 0; i < rs.offset; i++ {
 			_, err := rs.readRecordFromFile()
 			if err == execution.ErrEndOfStream {
 				return ctx.Err()
+			} else if errors.Cause(err) == errInvalidRecord {
+				// Skipped invalid records are counted in the offset too.
+				continue
 			} else if err != nil {
 				return errors.Wrapf(err, "couldn't move json file iterator by %d offset", rs.offset)
 			}
@@ -234,15 +279,33 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		if err != nil {
 			return errors.Wrapf(err, "couldn't push json EndOfStream to output record queue")
 		}
+		if err := rs.invalidRecords.Close(); err != nil {
+			return err
+		}
 
 		return execution.ErrEndOfStream
 	}
 
 	batch := make([]*execution.Record, 0)
-	for i := 0; i < rs.batchSize; i++ {
+	read := 0
+	var invalidRecordErr error
+	for read < rs.batchSize {
 		record, err := rs.readRecordFromFile()
 		if err == execution.ErrEndOfStream {
 			break
+		} else if cause := errors.Cause(err); cause == errInvalidRecord || cause == errInvalidFile || cause == charset.ErrInvalidEncoding {
+			if cause == errInvalidRecord {
+				skipped, handleErr := rs.invalidRecords.Handle(rs.offset+read, err)
+				if handleErr != nil {
+					return errors.Wrap(handleErr, "couldn't handle invalid record")
+				}
+				if skipped {
+					read++
+					continue
+				}
+			}
+			invalidRecordErr = err
+			break
 		} else if err != nil {
 			return errors.Wrap(err, "couldn't read record from json file")
 		}
@@ -250,12 +313,16 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		aliasedRecord := make(map[octosql.VariableName]octosql.Value)
 		for k, v := range record {
 			if str, ok := v.(string); ok {
-				parsed, err := time.Parse(time.RFC3339, str)
+				parsed, err := execution.ParseTime(str)
 				if err == nil {
 					v = parsed
 				}
 			}
-			aliasedRecord[octosql.NewVariableName(fmt.Sprintf("%s.%s", rs.alias, k))] = octosql.NormalizeType(v)
+			value, err := octosql.TryNormalizeType(v)
+			if err != nil {
+				return errors.Wrapf(err, "couldn't normalize value of field %s", k)
+			}
+			aliasedRecord[octosql.NewVariableName(fmt.Sprintf("%s.%s", rs.alias, k))] = value
 		}
 
 		fields := make([]octosql.VariableName, 0)
@@ -270,7 +337,8 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		batch = append(batch, execution.NewRecord(
 			fields,
 			aliasedRecord,
-			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(rs.streamID, rs.offset+i))))
+			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(rs.streamID, rs.offset+read))))
+		read++
 	}
 
 	for i := range batch {
@@ -284,11 +352,26 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		}
 	}
 
-	rs.offset = rs.offset + len(batch)
+	if invalidRecordErr != nil {
+		err := outputQueue.Push(ctx, &QueueElement{
+			Type: &QueueElement_Error{
+				Error: invalidRecordErr.Error(),
+			},
+		})
+		if err != nil {
+			return errors.Wrap(err, "couldn't push json error to output record queue")
+		}
+	}
+
+	rs.offset = rs.offset + read
 	if err := rs.saveOffset(tx); err != nil {
 		return errors.Wrap(err, "couldn't save json offset")
 	}
 
+	if invalidRecordErr != nil {
+		return execution.ErrEndOfStream
+	}
+
 	return nil
 }
 
@@ -311,13 +394,53 @@ func (rs *RecordStream) readRecordFromFile() (map[octosql.VariableName]interface
 
 	var record map[octosql.VariableName]interface{}
 	err := rs.decoder.Decode(&record)
-	if err != nil {
+	if _, ok := err.(*json.UnmarshalTypeError); ok {
+		// The decoder has already moved past the value which isn't an object.
+		return nil, errors.Wrapf(errInvalidRecord, "couldn't decode json record: %s", err)
+	} else if _, ok := err.(*json.SyntaxError); ok || err == io.ErrUnexpectedEOF {
+		if rs.arrayFormat {
+			// There's no way to tell where the next record of the list starts.
+			return nil, errors.Wrapf(errInvalidFile, "couldn't decode json record: %s", err)
+		}
+		if err := rs.skipLine(); err != nil {
+			return nil, errors.Wrap(err, "couldn't skip line with invalid json record")
+		}
+		return nil, errors.Wrapf(errInvalidRecord, "couldn't decode json record: %s", err)
+	} else if err != nil {
 		return nil, errors.Wrap(err, "couldn't decode json record")
 	}
 
 	return record, nil
 }
 
+var errInvalidRecord = errors.New("invalid record")
+var errInvalidFile = errors.New("invalid file")
+
+// skipLine moves past the line of the value the decoder has failed on and resets the decoder,
+// which can't be used anymore after a syntax error.
+func (rs *RecordStream) skipLine() error {
+	reader := bufio.NewReader(io.MultiReader(rs.decoder.Buffered(), rs.reader))
+	for {
+		// The decoder stops before the whitespace preceding the invalid value.
+		b, err := reader.ReadByte()
+		if err == io.EOF {
+			break
+		} else if err != nil {
+			return err
+		}
+		if b != ' ' && b != '\t' && b != '\r' && b != '\n' {
+			if _, err := reader.ReadString('\n'); err != nil && err != io.EOF {
+				return err
+			}
+			break
+		}
+	}
+
+	rs.reader = reader
+	rs.decoder = json.NewDecoder(reader)
+	return nil
+}
+
 var offsetPrefix = []byte("json_offset")
 
 func (rs *RecordStream) loadOffset(tx storage.StateTransaction) error {
@@ -16,17 +16,18 @@ func TestJSONRecordStream_Get(t *testing.T) {
 	streamId := execution.GetRawStreamID()
 
 	tests := []struct {
-		name        string
-		path        string
-		arrayFormat bool
-		alias       string
-		want        []*execution.Record
+		name            string
+		path            string
+		arrayFormat     bool
+		onInvalidRecord string
+		alias           string
+		want            []*execution.Record
 	}{
 		{
-			name:        "reading bikes.json - happy path",
-			path:        "fixtures/bikes.json",
-			arrayFormat: false,
-			alias:       "b",
+			name:            "reading bikes.json - happy path",
+			path:            "fixtures/bikes.json",
+			arrayFormat:     false,
+			alias:           "b",
 			want: []*execution.Record{
 				execution.NewRecordFromSliceWithNormalize(
 					[]octosql.VariableName{"b.color", "b.id", "b.ownerid", "b.wheels", "b.year"},
@@ -56,10 +57,10 @@ func TestJSONRecordStream_Get(t *testing.T) {
 			},
 		},
 		{
-			name:        "reading bikes.json in array format - happy path",
-			path:        "fixtures/bikes_array.json",
-			arrayFormat: true,
-			alias:       "b",
+			name:            "reading bikes.json in array format - happy path",
+			path:            "fixtures/bikes_array.json",
+			arrayFormat:     true,
+			alias:           "b",
 			want: []*execution.Record{
 				execution.NewRecordFromSliceWithNormalize(
 					[]octosql.VariableName{"b.color", "b.id", "b.ownerid", "b.wheels", "b.year"},
@@ -88,22 +89,51 @@ func TestJSONRecordStream_Get(t *testing.T) {
 				),
 			},
 		},
+		{
+			name:            "reading bikes_invalid.json - skipping invalid records",
+			path:            "fixtures/bikes_invalid.json",
+			arrayFormat:     false,
+			onInvalidRecord: "skip",
+			alias:           "b",
+			want: []*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"b.color", "b.id", "b.ownerid", "b.wheels", "b.year"},
+					[]interface{}{"green", 1.0, 152849.0, 3.0, 2014.0},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 0)),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"b.color", "b.id", "b.ownerid", "b.wheels", "b.year"},
+					[]interface{}{"purple", 3.0, 99148.0, 2.0, 2009.0},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 3)),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"b.color", "b.id", "b.ownerid", "b.wheels", "b.year"},
+					[]interface{}{"yellow", 5.0, 123466.0, 3.0, 1989.0},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 4)),
+				),
+			},
+		},
 	}
 
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
 			stateStorage := storage.GetTestStorage(t)
 
+			dsConfig := map[string]interface{}{
+				"path":        tt.path,
+				"arrayFormat": tt.arrayFormat,
+				"batchSize":   2,
+			}
+			if tt.onInvalidRecord != "" {
+				dsConfig["onInvalidRecord"] = tt.onInvalidRecord
+			}
+
 			ds, err := NewDataSourceBuilderFactory()("test", tt.alias)[0].Materialize(context.Background(), &physical.MaterializationContext{
 				Config: &config.Config{
 					DataSources: []config.DataSourceConfig{
 						{
-							Name: "test",
-							Config: map[string]interface{}{
-								"path":        tt.path,
-								"arrayFormat": tt.arrayFormat,
-								"batchSize":   2,
-							},
+							Name:   "test",
+							Config: dsConfig,
 						},
 					},
 				},
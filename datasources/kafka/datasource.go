@@ -192,6 +192,10 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 		err := rs.RunWorkerInternal(ctx, tx)
 		if errors.Cause(err) == execution.ErrNewTransactionRequired {
 			tx.Abort()
+			// Messages may have already been read in the aborted transaction.
+			if err := rs.reloadOffset(); err != nil {
+				return errors.Wrap(err, "couldn't reinitialize offset for kafka read messages worker")
+			}
 			continue
 		} else if waitableError := execution.GetErrWaitForChanges(err); waitableError != nil {
 			tx.Abort()
@@ -207,22 +211,32 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 		} else if err != nil {
 			tx.Abort()
 			log.Printf("kafka worker: error running kafka read messages worker: %s, reinitializing from storage", err)
-			tx := rs.stateStorage.BeginTransaction().WithPrefix(rs.streamID.AsPrefix())
-			if err := rs.loadOffset(tx); err != nil {
+			if err := rs.reloadOffset(); err != nil {
 				return errors.Wrap(err, "couldn't reinitialize offset for kafka read messages worker")
 			}
-			tx.Abort() // We only read data above, no need to risk failing now.
 			continue
 		}
 
 		err = tx.Commit()
 		if err != nil {
-			log.Println("kafka worker: couldn't commit transaction: ", err)
+			// The batch wasn't saved, so it has to be read again from the last saved offset.
+			log.Printf("kafka worker: couldn't commit transaction: %s, reinitializing from storage", err)
+			if err := rs.reloadOffset(); err != nil {
+				return errors.Wrap(err, "couldn't reinitialize offset for kafka read messages worker")
+			}
 			continue
 		}
 	}
 }
 
+// reloadOffset sets the kafka reader offset back to the one saved in storage.
+func (rs *RecordStream) reloadOffset() error {
+	tx := rs.stateStorage.BeginTransaction().WithPrefix(rs.streamID.AsPrefix())
+	defer tx.Abort() // We only read data here, no need to risk failing now.
+
+	return rs.loadOffset(tx)
+}
+
 func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateTransaction) error {
 	outputQueue := execution.NewOutputQueue(tx.WithPrefix(outputQueuePrefix))
 	tokenQueue := execution.NewOutputQueue(tx.WithPrefix(tokenQueuePrefix))
@@ -270,7 +284,11 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 				})
 				for _, k := range keys {
 					fields = append(fields, octosql.NewVariableName(fmt.Sprintf("%s.%s", rs.alias, k)))
-					values = append(values, octosql.NormalizeType(object[k]))
+					value, err := octosql.TryNormalizeType(object[k])
+					if err != nil {
+						return errors.Wrapf(err, "couldn't normalize value of field %s", k)
+					}
+					values = append(values, value)
 				}
 			}
 		}
@@ -0,0 +1,80 @@
+package memory
+
+import (
+	"context"
+	"fmt"
+	"sort"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+var availableFilters = map[physical.FieldType]map[physical.Relation]struct{}{
+	physical.Primary:   make(map[physical.Relation]struct{}),
+	physical.Secondary: make(map[physical.Relation]struct{}),
+}
+
+// DataSource reads records given by the application embedding OctoSQL,
+// so they can be queried together with other data sources.
+type DataSource struct {
+	records []map[string]interface{}
+	alias   string
+}
+
+// NewDataSourceBuilderFactory creates a data source builder factory reading the given records.
+// The values of the records are converted the same way as the values of decoded JSON objects.
+// It can be registered in a physical.DataSourceRepository and doesn't need any configuration.
+func NewDataSourceBuilderFactory(records []map[string]interface{}) physical.DataSourceBuilderFactory {
+	return physical.WithOptionalConfig(physical.NewDataSourceBuilderFactory(
+		func(ctx context.Context, matCtx *physical.MaterializationContext, dbConfig map[string]interface{}, filter physical.Formula, alias string, partition int) (execution.Node, error) {
+			return &DataSource{
+				records: records,
+				alias:   alias,
+			}, nil
+		},
+		nil,
+		availableFilters,
+		metadata.BoundedFitsInLocalStorage,
+		1,
+	))
+}
+
+func (ds *DataSource) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	records := make([]*execution.Record, len(ds.records))
+	for i, object := range ds.records {
+		keys := make([]string, 0, len(object))
+		for k := range object {
+			keys = append(keys, k)
+		}
+		sort.Strings(keys)
+
+		fields := make([]octosql.VariableName, len(keys))
+		values := make([]octosql.Value, len(keys))
+		for j, k := range keys {
+			fields[j] = octosql.NewVariableName(fmt.Sprintf("%s.%s", ds.alias, k))
+			value, err := octosql.TryNormalizeType(object[k])
+			if err != nil {
+				return nil, nil, errors.Wrapf(err, "couldn't normalize field %s of record with index %d", k, i)
+			}
+			values[j] = value
+		}
+
+		records[i] = execution.NewRecordFromSlice(
+			fields,
+			values,
+			execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamID, i)),
+		)
+	}
+
+	return execution.NewInMemoryStream(ctx, records),
+		execution.NewExecutionOutput(
+			execution.NewZeroWatermarkGenerator(),
+			map[string]execution.ShuffleData{},
+			nil,
+		),
+		nil
+}
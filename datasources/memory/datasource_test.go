@@ -0,0 +1,87 @@
+package memory
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestDataSource_Get(t *testing.T) {
+	ctx := context.Background()
+	streamId := execution.GetRawStreamID()
+
+	tests := []struct {
+		name    string
+		records []map[string]interface{}
+		alias   string
+		want    []*execution.Record
+	}{
+		{
+			name: "simple records",
+			records: []map[string]interface{}{
+				{"id": 1, "name": "wojtek", "tags": []interface{}{"a", "b"}},
+				{"id": 2, "name": "janek", "tags": []interface{}{}},
+			},
+			alias: "p",
+			want: []*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.id", "p.name", "p.tags"},
+					[]interface{}{1, "wojtek", []interface{}{"a", "b"}},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 0)),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"p.id", "p.name", "p.tags"},
+					[]interface{}{2, "janek", []interface{}{}},
+					execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(streamId, 1)),
+				),
+			},
+		},
+		{
+			name:    "no records",
+			records: []map[string]interface{}{},
+			alias:   "p",
+			want:    []*execution.Record{},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+
+			ds, err := NewDataSourceBuilderFactory(tt.records)("test", tt.alias)[0].Materialize(context.Background(), &physical.MaterializationContext{
+				Config:  &config.Config{},
+				Storage: stateStorage,
+			})
+			if err != nil {
+				t.Errorf("Error creating data source: %v", err)
+			}
+
+			got := execution.GetTestStream(t, stateStorage, octosql.NoVariables(), ds, execution.GetTestStreamWithStreamID(streamId))
+
+			tx := stateStorage.BeginTransaction()
+			want, _, err := execution.NewDummyNode(tt.want).Get(storage.InjectStateTransaction(ctx, tx), octosql.NoVariables(), streamId)
+			if err := tx.Commit(); err != nil {
+				t.Fatal(err)
+			}
+
+			if err := execution.AreStreamsEqualNoOrdering(storage.InjectStateTransaction(ctx, tx), stateStorage, want, got); err != nil {
+				t.Errorf("Streams aren't equal: %v", err)
+				return
+			}
+
+			if err := got.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close memory stream: %v", err)
+				return
+			}
+			if err := want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+		})
+	}
+}
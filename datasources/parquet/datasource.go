@@ -6,7 +6,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"log"
-	"math"
+	"math/big"
 	"time"
 
 	"github.com/kostya-sh/parquet-go/parquet"
@@ -459,24 +459,21 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 					}
 				case logicalType.DECIMAL != nil:
 					convert = func(v interface{}) interface{} {
+						scale := int(logicalType.DECIMAL.Scale)
 						switch v := v.(type) {
 						case int32:
-							return float64(v) * math.Pow10(int(-logicalType.DECIMAL.Scale))
+							return octosql.NewDecimal(big.NewInt(int64(v)), scale)
 						case int64:
-							return float64(v) * math.Pow10(int(-logicalType.DECIMAL.Scale))
+							return octosql.NewDecimal(big.NewInt(v), scale)
 						case []byte:
 							// The byte slice represents a big endian two's complement signed integer
-							var value float64
 							// This reads the unsigned value.
-							for i := range v {
-								j := len(v) - i - 1
-								value += float64(v[j]) * math.Pow(2, float64(8*i))
-							}
+							value := new(big.Int).SetBytes(v)
 							// This applies two's complement to get a signed one.
-							if v[0]&0b10000000 == 0b10000000 {
-								value -= math.Pow(2, float64(8*len(v)))
+							if len(v) > 0 && v[0]&0b10000000 == 0b10000000 {
+								value.Sub(value, new(big.Int).Lsh(big.NewInt(1), uint(8*len(v))))
 							}
-							return value * math.Pow10(int(-logicalType.DECIMAL.Scale))
+							return octosql.NewDecimal(value, scale)
 						default:
 							return v
 						}
@@ -564,9 +561,9 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 				case logicalType.BSON != nil:
 				case logicalType.UUID != nil:
 					convert = func(v interface{}) interface{} {
-						data := v.([]byte)
-						// This is how we readably format uuid bytes
-						return fmt.Sprintf("%.8x-%.4x-%.4x-%.4x-%.12x", data[:4], data[4:6], data[6:8], data[8:10], data[10:16])
+						var u octosql.UUID
+						copy(u[:], v.([]byte))
+						return u
 					}
 				}
 
@@ -582,7 +579,10 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 					}
 				}
 			}
-			octoValues[i] = octosql.NormalizeType(v)
+			octoValues[i], err = octosql.TryNormalizeType(v)
+			if err != nil {
+				return errors.Wrapf(err, "couldn't normalize value of column %s", rs.columnNames[i])
+			}
 		}
 
 		batch = append(batch, execution.NewRecordFromSlice(
@@ -2,7 +2,6 @@ package parquet
 
 import (
 	"context"
-	"encoding/base32"
 	"testing"
 	"time"
 
@@ -43,28 +42,28 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"b.wheels":  3,
 					"b.year":    2014,
 					"b.ownerid": 152849,
-					"b.color":   base32.StdEncoding.EncodeToString([]byte("green")),
+					"b.color":   []byte("green"),
 				},
 				{
 					"b.id":      2,
 					"b.wheels":  2,
 					"b.year":    1988,
 					"b.ownerid": 106332,
-					"b.color":   base32.StdEncoding.EncodeToString([]byte("black")),
+					"b.color":   []byte("black"),
 				},
 				{
 					"b.id":      3,
 					"b.wheels":  2,
 					"b.year":    2009,
 					"b.ownerid": 99148,
-					"b.color":   base32.StdEncoding.EncodeToString([]byte("purple")),
+					"b.color":   []byte("purple"),
 				},
 				{
 					"b.id":      4,
 					"b.wheels":  2,
 					"b.year":    1979,
 					"b.ownerid": 97521,
-					"b.color":   base32.StdEncoding.EncodeToString([]byte("orange")),
+					"b.color":   []byte("orange"),
 				},
 			},
 		},
@@ -111,16 +110,16 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"10_byte_array_lt":                      []byte{255, 255, 255, 255, 255, 255, 255, 255, 255, 255},
 					"date_ct":                               time.Date(1969, 12, 31, 0, 0, 0, 0, time.UTC),
 					"date_lt":                               time.Date(1969, 12, 31, 0, 0, 0, 0, time.UTC),
-					"decimal_int32_ct":                      -1e-05,
-					"decimal_int32_lt":                      -1e-05,
-					"decimal_int64_ct":                      -1e-10,
-					"decimal_int64_lt":                      -1e-10,
-					"decimal_byte_array_ct":                 -1e-2,
-					"decimal_byte_array_lt":                 -1e-2,
-					"decimal_flba_ct":                       -1e-5,
-					"decimal_flba_lt":                       -1e-5,
-					"enum_ct":                               base32.StdEncoding.EncodeToString([]byte("ENUM   000")),
-					"enum_lt":                               base32.StdEncoding.EncodeToString([]byte("ENUM   000")),
+					"decimal_int32_ct":                      mustParseDecimal("-0.00001"),
+					"decimal_int32_lt":                      mustParseDecimal("-0.00001"),
+					"decimal_int64_ct":                      mustParseDecimal("-0.0000000001"),
+					"decimal_int64_lt":                      mustParseDecimal("-0.0000000001"),
+					"decimal_byte_array_ct":                 mustParseDecimal("-0.01"),
+					"decimal_byte_array_lt":                 mustParseDecimal("-0.01"),
+					"decimal_flba_ct":                       mustParseDecimal("-0.00001"),
+					"decimal_flba_lt":                       mustParseDecimal("-0.00001"),
+					"enum_ct":                               []byte("ENUM   000"),
+					"enum_lt":                               []byte("ENUM   000"),
 					"time_millis_ct":                        time.Duration(0),
 					"time_utc_millis_lt":                    time.Duration(0),
 					"time_nonutc_millis_lt":                 time.Duration(0),
@@ -141,9 +140,9 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"interval_lt":                           []byte{0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0},
 					"json_ct":                               map[string]interface{}{"key": "value"},
 					"json_lt":                               map[string]interface{}{"key": "value"},
-					"bson_ct":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"bson_lt":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"uuid":                                  "ffffffff-ffff-ffff-ffff-ffffffffffff",
+					"bson_ct":                               []byte("BSON"),
+					"bson_lt":                               []byte("BSON"),
+					"uuid":                                  mustParseUUID("ffffffff-ffff-ffff-ffff-ffffffffffff"),
 					"uint64_dictionary":                     -1,
 					"optional_uint32":                       4294967295,
 					"twice_repeated_uint16":                 []interface{}{0},
@@ -187,16 +186,16 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"10_byte_array_lt":                      []byte{0, 0, 0, 0, 0, 0, 0, 0, 0, 0},
 					"date_ct":                               time.Date(1970, 1, 1, 0, 0, 0, 0, time.UTC),
 					"date_lt":                               time.Date(1970, 1, 1, 0, 0, 0, 0, time.UTC),
-					"decimal_int32_ct":                      0.0,
-					"decimal_int32_lt":                      0.0,
-					"decimal_int64_ct":                      0.0,
-					"decimal_int64_lt":                      0.0,
-					"decimal_byte_array_ct":                 0.0,
-					"decimal_byte_array_lt":                 0.0,
-					"decimal_flba_ct":                       0.0,
-					"decimal_flba_lt":                       0.0,
-					"enum_ct":                               base32.StdEncoding.EncodeToString([]byte("ENUM   001")),
-					"enum_lt":                               base32.StdEncoding.EncodeToString([]byte("ENUM   001")),
+					"decimal_int32_ct":                      mustParseDecimal("0.00000"),
+					"decimal_int32_lt":                      mustParseDecimal("0.00000"),
+					"decimal_int64_ct":                      mustParseDecimal("0.0000000000"),
+					"decimal_int64_lt":                      mustParseDecimal("0.0000000000"),
+					"decimal_byte_array_ct":                 mustParseDecimal("0.00"),
+					"decimal_byte_array_lt":                 mustParseDecimal("0.00"),
+					"decimal_flba_ct":                       mustParseDecimal("0.00000"),
+					"decimal_flba_lt":                       mustParseDecimal("0.00000"),
+					"enum_ct":                               []byte("ENUM   001"),
+					"enum_lt":                               []byte("ENUM   001"),
 					"time_millis_ct":                        time.Hour + time.Minute + time.Second,
 					"time_utc_millis_lt":                    time.Hour + time.Minute + time.Second,
 					"time_nonutc_millis_lt":                 time.Hour + time.Minute + time.Second,
@@ -217,9 +216,9 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"interval_lt":                           []byte{1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0},
 					"json_ct":                               map[string]interface{}{"key": "value"},
 					"json_lt":                               map[string]interface{}{"key": "value"},
-					"bson_ct":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"bson_lt":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"uuid":                                  "00000000-0000-0000-0000-000000000000",
+					"bson_ct":                               []byte("BSON"),
+					"bson_lt":                               []byte("BSON"),
+					"uuid":                                  mustParseUUID("00000000-0000-0000-0000-000000000000"),
 					"uint64_dictionary":                     0,
 					"optional_uint32":                       nil,
 					"twice_repeated_uint16":                 []interface{}{1, 2},
@@ -263,16 +262,16 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"10_byte_array_lt":                      []byte{1, 1, 1, 1, 1, 1, 1, 1, 1, 1},
 					"date_ct":                               time.Date(1970, 1, 2, 0, 0, 0, 0, time.UTC),
 					"date_lt":                               time.Date(1970, 1, 2, 0, 0, 0, 0, time.UTC),
-					"decimal_int32_ct":                      1e-05,
-					"decimal_int32_lt":                      1e-05,
-					"decimal_int64_ct":                      1e-10,
-					"decimal_int64_lt":                      1e-10,
-					"decimal_byte_array_ct":                 1e-2,
-					"decimal_byte_array_lt":                 1e-2,
-					"decimal_flba_ct":                       1e-05,
-					"decimal_flba_lt":                       1e-05,
-					"enum_ct":                               base32.StdEncoding.EncodeToString([]byte("ENUM   002")),
-					"enum_lt":                               base32.StdEncoding.EncodeToString([]byte("ENUM   002")),
+					"decimal_int32_ct":                      mustParseDecimal("0.00001"),
+					"decimal_int32_lt":                      mustParseDecimal("0.00001"),
+					"decimal_int64_ct":                      mustParseDecimal("0.0000000001"),
+					"decimal_int64_lt":                      mustParseDecimal("0.0000000001"),
+					"decimal_byte_array_ct":                 mustParseDecimal("0.01"),
+					"decimal_byte_array_lt":                 mustParseDecimal("0.01"),
+					"decimal_flba_ct":                       mustParseDecimal("0.00001"),
+					"decimal_flba_lt":                       mustParseDecimal("0.00001"),
+					"enum_ct":                               []byte("ENUM   002"),
+					"enum_lt":                               []byte("ENUM   002"),
 					"time_millis_ct":                        time.Hour*2 + time.Minute*2 + time.Second*2,
 					"time_utc_millis_lt":                    time.Hour*2 + time.Minute*2 + time.Second*2,
 					"time_nonutc_millis_lt":                 time.Hour*2 + time.Minute*2 + time.Second*2,
@@ -293,9 +292,9 @@ func TestParquetRecordStream_Get(t *testing.T) {
 					"interval_lt":                           []byte{2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0},
 					"json_ct":                               map[string]interface{}{"key": "value"},
 					"json_lt":                               map[string]interface{}{"key": "value"},
-					"bson_ct":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"bson_lt":                               base32.StdEncoding.EncodeToString([]byte("BSON")),
-					"uuid":                                  "01000000-0100-0000-0100-000001000000",
+					"bson_ct":                               []byte("BSON"),
+					"bson_lt":                               []byte("BSON"),
+					"uuid":                                  mustParseUUID("01000000-0100-0000-0100-000001000000"),
 					"uint64_dictionary":                     1,
 					"optional_uint32":                       1,
 					"twice_repeated_uint16":                 []interface{}{3, 4},
@@ -449,3 +448,19 @@ func TestParquetRecordStream_Get(t *testing.T) {
 		})
 	}
 }
+
+func mustParseDecimal(s string) octosql.Decimal {
+	d, err := octosql.ParseDecimal(s)
+	if err != nil {
+		panic(err)
+	}
+	return d
+}
+
+func mustParseUUID(s string) octosql.UUID {
+	u, err := octosql.ParseUUID(s)
+	if err != nil {
+		panic(err)
+	}
+	return u
+}
@@ -313,7 +313,11 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 		aliasedRecord := make(map[octosql.VariableName]octosql.Value)
 		for k, v := range recordValues {
 			fieldName := octosql.NewVariableName(fmt.Sprintf("%s.%s", rs.alias, k))
-			aliasedRecord[fieldName] = octosql.NormalizeType(v)
+			value, err := octosql.TryNormalizeType(v)
+			if err != nil {
+				return errors.Wrapf(err, "couldn't normalize value of field %s", k)
+			}
+			aliasedRecord[fieldName] = value
 		}
 
 		fieldNames := make([]octosql.VariableName, 0)
@@ -322,7 +326,10 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 			fieldNames = append(fieldNames, k)
 		}
 
-		aliasedRecord[keyVariableName] = octosql.NormalizeType(key)
+		aliasedRecord[keyVariableName], err = octosql.TryNormalizeType(key)
+		if err != nil {
+			return errors.Wrapf(err, "couldn't normalize key %s", key)
+		}
 
 		// The key is always the first record field
 		sort.Slice(fieldNames[1:], func(i, j int) bool {
@@ -25,6 +25,7 @@ func (t *PostgresTemplate) GetAvailableFilters() map[physical.FieldType]map[phys
 			physical.GreaterEqual: {},
 			physical.LessEqual:    {},
 			physical.Like:         {},
+			physical.ILike:        {},
 		},
 		physical.Secondary: {
 			physical.Equal:        {},
@@ -32,6 +33,7 @@ func (t *PostgresTemplate) GetAvailableFilters() map[physical.FieldType]map[phys
 			physical.MoreThan:     {},
 			physical.LessThan:     {},
 			physical.Like:         {},
+			physical.ILike:        {},
 			physical.GreaterEqual: {},
 			physical.LessEqual:    {},
 		},
@@ -5,6 +5,7 @@ import (
 	"database/sql"
 	"fmt"
 	"log"
+	"strings"
 
 	"github.com/pkg/errors"
 
@@ -163,6 +164,8 @@ type RecordStream struct {
 	streamID     *execution.StreamID
 	rows         *sql.Rows
 	columns      []string
+	decimals     []bool
+	uuids        []bool
 	isDone       bool
 	alias        string
 
@@ -254,6 +257,21 @@ func (rs *RecordStream) RunWorker(ctx context.Context) error {
 		}
 		rs.columns = columns
 
+		columnTypes, err := rows.ColumnTypes()
+		if err != nil {
+			return errors.Wrap(err, "couldn't get column types from rows")
+		}
+		rs.decimals = make([]bool, len(columnTypes))
+		rs.uuids = make([]bool, len(columnTypes))
+		for i := range columnTypes {
+			switch strings.ToUpper(columnTypes[i].DatabaseTypeName()) {
+			case "DECIMAL", "NUMERIC":
+				rs.decimals[i] = true
+			case "UUID":
+				rs.uuids[i] = true
+			}
+		}
+
 		for { // inner for is calling RunWorkerInternal
 			select {
 			case <-ctx.Done():
@@ -351,8 +369,27 @@ func (rs *RecordStream) RunWorkerInternal(ctx context.Context, tx storage.StateT
 			if data, ok := cols[i].([]byte); ok {
 				cols[i] = string(data)
 			}
+			// Both drivers return decimal columns as text, which we parse so that no digits are lost.
+			if data, ok := cols[i].(string); ok && rs.decimals[i] {
+				decimal, err := octosql.ParseDecimal(data)
+				if err != nil {
+					return errors.Wrapf(err, "couldn't parse decimal value of column %s", columnName)
+				}
+				cols[i] = decimal
+			}
+			if data, ok := cols[i].(string); ok && rs.uuids[i] {
+				uuid, err := octosql.ParseUUID(data)
+				if err != nil {
+					return errors.Wrapf(err, "couldn't parse uuid value of column %s", columnName)
+				}
+				cols[i] = uuid
+			}
 
-			resultMap[newName] = octosql.NormalizeType(cols[i])
+			value, err := octosql.TryNormalizeType(cols[i])
+			if err != nil {
+				return errors.Wrapf(err, "couldn't normalize value of column %s", columnName)
+			}
+			resultMap[newName] = value
 		}
 
 		batch = append(batch, execution.NewRecord(
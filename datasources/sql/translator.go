@@ -85,6 +85,8 @@ func relationToSQL(rel physical.Relation) string {
 		return "IN"
 	case physical.Like:
 		return "LIKE"
+	case physical.ILike:
+		return "ILIKE"
 	case physical.GreaterEqual:
 		return ">="
 	case physical.LessEqual:
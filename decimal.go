@@ -0,0 +1,270 @@
+package octosql
+
+import (
+	"database/sql/driver"
+	"math/big"
+	"strconv"
+	"strings"
+
+	"github.com/pkg/errors"
+)
+
+// DecimalDivisionScaleIncrement is the number of digits after the decimal point a quotient has more than its dividend.
+const DecimalDivisionScaleIncrement = 4
+
+// DecimalMaxPrecision is the greatest number of digits a decimal can have, the same as for a 128-bit decimal.
+const DecimalMaxPrecision = 38
+
+// Decimal is an exact decimal number, the unscaled integer divided by 10 to the power of scale.
+// Its scale, the number of digits after the decimal point, is kept, so 1.50 stays 1.50.
+type Decimal struct {
+	unscaled *big.Int
+	scale    int
+}
+
+// NewDecimal creates a decimal equal to unscaled / 10^scale.
+func NewDecimal(unscaled *big.Int, scale int) Decimal {
+	if scale < 0 {
+		unscaled = new(big.Int).Mul(unscaled, pow10(-scale))
+		scale = 0
+	}
+	return Decimal{unscaled: new(big.Int).Set(unscaled), scale: scale}
+}
+
+func DecimalFromInt(v int) Decimal {
+	return Decimal{unscaled: big.NewInt(int64(v))}
+}
+
+// DecimalFromFloat converts the float to the shortest decimal which parses back to it.
+// Digits after the decimal point past DecimalMaxPrecision digits get rounded.
+func DecimalFromFloat(v float64) (Decimal, error) {
+	d, err := parseDecimal(strconv.FormatFloat(v, 'g', -1, 64))
+	if err != nil {
+		return Decimal{}, err
+	}
+	return fitPrecision(d)
+}
+
+// ParseDecimal parses a decimal like -12.340 or 1.5e3.
+// The scale of the decimal is the number of digits after the decimal point, less the exponent.
+// Decimals with more than DecimalMaxPrecision digits are rejected.
+func ParseDecimal(s string) (Decimal, error) {
+	d, err := parseDecimal(s)
+	if err != nil {
+		return Decimal{}, err
+	}
+	if d.Precision() > DecimalMaxPrecision {
+		return Decimal{}, errors.Errorf("decimal %s has more than %d digits", s, DecimalMaxPrecision)
+	}
+	return d, nil
+}
+
+func parseDecimal(s string) (Decimal, error) {
+	text := strings.TrimSpace(s)
+	exponent := 0
+	if i := strings.IndexAny(text, "eE"); i != -1 {
+		var err error
+		exponent, err = strconv.Atoi(text[i+1:])
+		if err != nil {
+			return Decimal{}, errors.Errorf("invalid decimal %s", s)
+		}
+		text = text[:i]
+	}
+
+	sign := ""
+	if len(text) > 0 && (text[0] == '-' || text[0] == '+') {
+		sign, text = text[:1], text[1:]
+	}
+	scale := 0
+	if i := strings.IndexByte(text, '.'); i != -1 {
+		scale = len(text) - i - 1
+		text = text[:i] + text[i+1:]
+	}
+	if len(text) == 0 || strings.IndexFunc(text, func(r rune) bool { return r < '0' || r > '9' }) != -1 {
+		return Decimal{}, errors.Errorf("invalid decimal %s", s)
+	}
+
+	// An exponent shifting all the digits past DecimalMaxPrecision digits from the decimal point can't give a valid decimal,
+	// so it's rejected before the digits get shifted, which would take a huge amount of memory for a huge exponent.
+	if exponent > len(text)+DecimalMaxPrecision || exponent < -(len(text)+DecimalMaxPrecision) {
+		return Decimal{}, errors.Errorf("exponent of decimal %s is out of range", s)
+	}
+
+	unscaled, ok := new(big.Int).SetString(sign+text, 10)
+	if !ok {
+		return Decimal{}, errors.Errorf("invalid decimal %s", s)
+	}
+	return NewDecimal(unscaled, scale-exponent), nil
+}
+
+// fitPrecision rounds the decimal to fewer digits after the decimal point, so that it has at most DecimalMaxPrecision digits.
+// It returns an error if the decimal has more digits than that before the decimal point.
+func fitPrecision(d Decimal) (Decimal, error) {
+	// Rounding may carry into another digit before the decimal point, so it's repeated until the decimal fits.
+	for d.Precision() > DecimalMaxPrecision {
+		integerDigits := d.Precision() - d.scale
+		if integerDigits > DecimalMaxPrecision {
+			return Decimal{}, errors.Errorf("decimal out of range, it has %d digits before the decimal point, at most %d are supported", integerDigits, DecimalMaxPrecision)
+		}
+		d = d.Rescale(DecimalMaxPrecision - integerDigits)
+	}
+	return d, nil
+}
+
+func (d Decimal) value() *big.Int {
+	if d.unscaled == nil {
+		return new(big.Int)
+	}
+	return d.unscaled
+}
+
+// Unscaled returns the decimal without its decimal point.
+func (d Decimal) Unscaled() *big.Int {
+	return new(big.Int).Set(d.value())
+}
+
+// Scale returns the number of digits after the decimal point.
+func (d Decimal) Scale() int {
+	return d.scale
+}
+
+// Precision returns the number of digits.
+func (d Decimal) Precision() int {
+	digits := len(new(big.Int).Abs(d.value()).String())
+	if digits < d.scale {
+		return d.scale
+	}
+	return digits
+}
+
+func (d Decimal) String() string {
+	digits := new(big.Int).Abs(d.value()).String()
+	if d.scale > 0 {
+		if len(digits) <= d.scale {
+			digits = strings.Repeat("0", d.scale-len(digits)+1) + digits
+		}
+		digits = digits[:len(digits)-d.scale] + "." + digits[len(digits)-d.scale:]
+	}
+	if d.Sign() < 0 {
+		return "-" + digits
+	}
+	return digits
+}
+
+// MarshalJSON writes the decimal as a JSON number, without losing any digits.
+func (d Decimal) MarshalJSON() ([]byte, error) {
+	return []byte(d.String()), nil
+}
+
+// Value passes the decimal to SQL databases as text, without losing any digits.
+func (d Decimal) Value() (driver.Value, error) {
+	return d.String(), nil
+}
+
+func (d Decimal) Float() float64 {
+	f, _ := new(big.Rat).SetFrac(d.value(), pow10(d.scale)).Float64()
+	return f
+}
+
+// Int returns the integer part of the decimal, or an error if it's out of the range of an Int.
+func (d Decimal) Int() (int, error) {
+	integer := new(big.Int).Quo(d.value(), pow10(d.scale))
+	if !integer.IsInt64() || int64(int(integer.Int64())) != integer.Int64() {
+		return 0, errors.Errorf("decimal %s is out of the range of Int", d)
+	}
+	return int(integer.Int64()), nil
+}
+
+func (d Decimal) Sign() int {
+	return d.value().Sign()
+}
+
+// Rescale returns the decimal with the given number of digits after the decimal point, rounding half away from zero.
+func (d Decimal) Rescale(scale int) Decimal {
+	if scale >= d.scale {
+		return Decimal{unscaled: new(big.Int).Mul(d.value(), pow10(scale-d.scale)), scale: scale}
+	}
+
+	quotient, remainder := new(big.Int).QuoRem(d.value(), pow10(d.scale-scale), new(big.Int))
+	remainder.Abs(remainder)
+	if remainder.Mul(remainder, big.NewInt(2)).Cmp(pow10(d.scale-scale)) >= 0 {
+		quotient.Add(quotient, big.NewInt(int64(d.Sign())))
+	}
+	return Decimal{unscaled: quotient, scale: scale}
+}
+
+// The results of arithmetic are rounded to fewer digits after the decimal point than their scale would be,
+// if they'd otherwise have more than DecimalMaxPrecision digits, and fail if they have more digits than that before it.
+
+// Add returns the sum, with the greater scale of the two.
+func (d Decimal) Add(other Decimal) (Decimal, error) {
+	left, right := alignScales(d, other)
+	return fitPrecision(Decimal{unscaled: new(big.Int).Add(left.value(), right.value()), scale: left.scale})
+}
+
+// Sub returns the difference, with the greater scale of the two.
+func (d Decimal) Sub(other Decimal) (Decimal, error) {
+	left, right := alignScales(d, other)
+	return fitPrecision(Decimal{unscaled: new(big.Int).Sub(left.value(), right.value()), scale: left.scale})
+}
+
+// Mul returns the product, with the sum of the scales.
+func (d Decimal) Mul(other Decimal) (Decimal, error) {
+	return fitPrecision(Decimal{unscaled: new(big.Int).Mul(d.value(), other.value()), scale: d.scale + other.scale})
+}
+
+// Div returns the quotient with the given scale, rounding half away from zero.
+func (d Decimal) Div(other Decimal, scale int) (Decimal, error) {
+	if other.Sign() == 0 {
+		return Decimal{}, errors.New("division by zero")
+	}
+
+	// The quotient is computed with one more digit, which is then rounded.
+	numerator := new(big.Int).Mul(d.value(), pow10(scale+1+other.scale))
+	denominator := new(big.Int).Mul(other.value(), pow10(d.scale))
+	quotient := Decimal{unscaled: numerator.Quo(numerator, denominator), scale: scale + 1}
+	return fitPrecision(quotient.Rescale(scale))
+}
+
+// Mod returns the remainder of the division truncated towards zero, with the greater scale of the two.
+func (d Decimal) Mod(other Decimal) (Decimal, error) {
+	if other.Sign() == 0 {
+		return Decimal{}, errors.New("division by zero")
+	}
+
+	left, right := alignScales(d, other)
+	return Decimal{unscaled: new(big.Int).Rem(left.value(), right.value()), scale: left.scale}, nil
+}
+
+func (d Decimal) Neg() Decimal {
+	return Decimal{unscaled: new(big.Int).Neg(d.value()), scale: d.scale}
+}
+
+func (d Decimal) Abs() Decimal {
+	return Decimal{unscaled: new(big.Int).Abs(d.value()), scale: d.scale}
+}
+
+// Cmp compares the values of the decimals, regardless of their scales.
+func (d Decimal) Cmp(other Decimal) int {
+	left, right := alignScales(d, other)
+	return left.value().Cmp(right.value())
+}
+
+// normalize returns the digits of the decimal without leading and trailing zeros,
+// and the exponent making the decimal equal to 0.digits * 10^exponent.
+func (d Decimal) normalize() (string, int) {
+	digits := new(big.Int).Abs(d.value()).String()
+	trimmed := strings.TrimRight(digits, "0")
+	return trimmed, len(digits) - d.scale
+}
+
+func alignScales(left, right Decimal) (Decimal, Decimal) {
+	if left.scale < right.scale {
+		return left.Rescale(right.scale), right
+	}
+	return left, right.Rescale(left.scale)
+}
+
+func pow10(n int) *big.Int {
+	return new(big.Int).Exp(big.NewInt(10), big.NewInt(int64(n)), nil)
+}
@@ -0,0 +1,245 @@
+package octosql
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/golang/protobuf/proto"
+)
+
+func mustParseDecimal(s string) Decimal {
+	d, err := ParseDecimal(s)
+	if err != nil {
+		panic(err)
+	}
+	return d
+}
+
+func TestParseDecimal(t *testing.T) {
+	tests := []struct {
+		input   string
+		want    string
+		scale   int
+		wantErr bool
+	}{
+		{input: "12.340", want: "12.340", scale: 3},
+		{input: "-0.05", want: "-0.05", scale: 2},
+		{input: "+7", want: "7", scale: 0},
+		{input: ".5", want: "0.5", scale: 1},
+		{input: "1.5e3", want: "1500", scale: 0},
+		{input: "1.5e-3", want: "0.0015", scale: 4},
+		{input: "1e+21", want: "1000000000000000000000", scale: 0},
+		{input: "1e37", want: "1" + strings.Repeat("0", 37), scale: 0},
+		{input: "0.1e-37", want: "0." + strings.Repeat("0", 37) + "1", scale: 38},
+		{input: "1e38", wantErr: true},
+		{input: "0.1e-38", wantErr: true},
+		{input: "1e999999999", wantErr: true},
+		{input: "1e-999999999", wantErr: true},
+		{input: "1" + strings.Repeat("0", 38), wantErr: true},
+		{input: "", wantErr: true},
+		{input: "-", wantErr: true},
+		{input: "1.2.3", wantErr: true},
+		{input: "12a", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			got, err := ParseDecimal(tt.input)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseDecimal() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				return
+			}
+			if got.String() != tt.want || got.Scale() != tt.scale {
+				t.Errorf("ParseDecimal() = %v with scale %v, want %v with scale %v", got, got.Scale(), tt.want, tt.scale)
+			}
+		})
+	}
+}
+
+func TestDecimalArithmetic(t *testing.T) {
+	tests := []struct {
+		name string
+		got  func() (Decimal, error)
+		want string
+	}{
+		{
+			name: "add keeps the greater scale",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("0.1").Add(mustParseDecimal("0.20"))
+			},
+			want: "0.30",
+		},
+		{
+			name: "subtract",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("1").Sub(mustParseDecimal("1.005"))
+			},
+			want: "-0.005",
+		},
+		{
+			name: "multiply adds the scales",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("1.10").Mul(mustParseDecimal("-0.3"))
+			},
+			want: "-0.330",
+		},
+		{
+			name: "multiply rounds past the maximum precision",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("1.2345678901234567890").Mul(mustParseDecimal("1.2345678901234567890"))
+			},
+			want: "1.5241578753238836750190519987501905210",
+		},
+		{
+			name: "divide rounds half away from zero",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("-2").Div(mustParseDecimal("3"), 4)
+			},
+			want: "-0.6667",
+		},
+		{
+			name: "divide by a decimal",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("10.5").Div(mustParseDecimal("0.25"), 2)
+			},
+			want: "42.00",
+		},
+		{
+			name: "modulo",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("-7.5").Mod(mustParseDecimal("2"))
+			},
+			want: "-1.5",
+		},
+		{
+			name: "rescale rounds",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("2.345").Rescale(2), nil
+			},
+			want: "2.35",
+		},
+		{
+			name: "rescale pads with zeros",
+			got: func() (Decimal, error) {
+				return mustParseDecimal("-2.3").Rescale(3), nil
+			},
+			want: "-2.300",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := tt.got()
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if got.String() != tt.want {
+				t.Errorf("got %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestDecimal_Overflow(t *testing.T) {
+	nines := mustParseDecimal(strings.Repeat("9", DecimalMaxPrecision))
+	if _, err := nines.Add(mustParseDecimal("1")); err == nil {
+		t.Errorf("Add() error = nil, want overflow")
+	}
+	if _, err := nines.Neg().Sub(mustParseDecimal("1")); err == nil {
+		t.Errorf("Sub() error = nil, want overflow")
+	}
+	if _, err := mustParseDecimal("10000000000000000000").Mul(mustParseDecimal("10000000000000000000")); err == nil {
+		t.Errorf("Mul() error = nil, want overflow")
+	}
+	if _, err := mustParseDecimal("1").Div(mustParseDecimal("0."+strings.Repeat("0", 37)+"1"), 0); err == nil {
+		t.Errorf("Div() error = nil, want overflow")
+	}
+}
+
+func TestDecimalFromFloat(t *testing.T) {
+	tests := []struct {
+		input   float64
+		want    string
+		wantErr bool
+	}{
+		{input: 1.5, want: "1.5"},
+		{input: 1e21, want: "1000000000000000000000"},
+		// Digits past the maximum precision get rounded.
+		{input: 1.2345678901234567e-30, want: "0.00000000000000000000000000000123456789"},
+		{input: 1e300, wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(fmt.Sprint(tt.input), func(t *testing.T) {
+			got, err := DecimalFromFloat(tt.input)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("DecimalFromFloat() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err == nil && got.String() != tt.want {
+				t.Errorf("DecimalFromFloat() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestDecimal_DivideByZero(t *testing.T) {
+	if _, err := mustParseDecimal("1").Div(mustParseDecimal("0.00"), 2); err == nil {
+		t.Errorf("Div() error = nil, want division by zero")
+	}
+	if _, err := mustParseDecimal("1").Mod(mustParseDecimal("0")); err == nil {
+		t.Errorf("Mod() error = nil, want division by zero")
+	}
+}
+
+func TestAreEqual_Decimals(t *testing.T) {
+	if !AreEqual(MakeDecimal(mustParseDecimal("1.50")), MakeDecimal(mustParseDecimal("1.5"))) {
+		t.Errorf("AreEqual() = false for decimals with different scales")
+	}
+	if AreEqual(MakeDecimal(mustParseDecimal("1.5")), MakeFloat(1.5)) {
+		t.Errorf("AreEqual() = true for a decimal and a float")
+	}
+}
+
+func TestMakeDecimal_RoundTrip(t *testing.T) {
+	for _, s := range []string{"0", "0.00", "-0.05", "12.340", "-123456789012345678901234567890.5"} {
+		t.Run(s, func(t *testing.T) {
+			value := MakeDecimal(mustParseDecimal(s))
+			data, err := proto.Marshal(&value)
+			if err != nil {
+				t.Fatal(err)
+			}
+			var got Value
+			if err := proto.Unmarshal(data, &got); err != nil {
+				t.Fatal(err)
+			}
+			if got.AsDecimal().String() != s {
+				t.Errorf("got %s, want %s", got.AsDecimal(), s)
+			}
+		})
+	}
+}
+
+func TestDecimal_Int(t *testing.T) {
+	tests := []struct {
+		input   string
+		want    int
+		wantErr bool
+	}{
+		{input: "12.75", want: 12},
+		{input: "-12.75", want: -12},
+		{input: "9223372036854775807.9", want: 9223372036854775807},
+		{input: "9223372036854775808", wantErr: true},
+		{input: "-123456789012345678901234567890", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			got, err := mustParseDecimal(tt.input).Int()
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("Int() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("Int() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
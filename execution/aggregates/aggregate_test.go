@@ -27,6 +27,8 @@ func ExpectValue(t *testing.T, ctx context.Context, aggr execution.Aggregate, tx
 		assert.Greater(t, expected.AsFloat(), val.AsFloat()-eps)
 		assert.Less(t, expected.AsFloat(), val.AsFloat()+eps)
 		//assert.Equal(t, expected.AsFloat(), val.AsFloat())
+	case octosql.TypeDecimal:
+		assert.Equal(t, expected.AsDecimal().String(), val.AsDecimal().String())
 	case octosql.TypeBool:
 		assert.Equal(t, expected.AsBool(), val.AsBool())
 	case octosql.TypeString:
@@ -0,0 +1,93 @@
+package aggregates
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/storage"
+)
+
+var currentRegistersPrefix = []byte("$current_registers$")
+
+// ApproxCountDistinct estimates the number of distinct values using a HyperLogLog sketch,
+// so its state is bounded by the register count, regardless of the cardinality.
+type ApproxCountDistinct struct {
+}
+
+func NewApproxCountDistinctAggregate() *ApproxCountDistinct {
+	return &ApproxCountDistinct{}
+}
+
+func (agg *ApproxCountDistinct) AddValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	registersStorage := storage.NewMap(tx.WithPrefix(currentRegistersPrefix))
+
+	registerIndex, rank := octosql.HyperLogLogPosition(value)
+	register := octosql.MakeInt(registerIndex)
+
+	var currentRank octosql.Value
+	err := registersStorage.Get(&register, &currentRank)
+	if err == storage.ErrNotFound {
+		currentRank = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get current register value from storage")
+	}
+
+	if int(rank) <= currentRank.AsInt() {
+		return nil
+	}
+
+	newRank := octosql.MakeInt(int(rank))
+	err = registersStorage.Set(&register, &newRank)
+	if err != nil {
+		return errors.Wrap(err, "couldn't set register value in storage")
+	}
+
+	return nil
+}
+
+func (agg *ApproxCountDistinct) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	return errors.New("approx_count_distinct doesn't support retractions, use count_distinct instead")
+}
+
+func (agg *ApproxCountDistinct) GetValue(ctx context.Context, tx storage.StateTransaction) (octosql.Value, error) {
+	registersStorage := storage.NewMap(tx.WithPrefix(currentRegistersPrefix))
+
+	it := registersStorage.GetIterator()
+	defer func() {
+		_ = it.Close()
+	}()
+
+	// Registers which aren't in storage are zero.
+	hll := octosql.NewHyperLogLog()
+
+	var register octosql.Value
+	var rank octosql.Value
+	for {
+		err := it.Next(&register, &rank)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't get register value from storage")
+		}
+
+		hll.SetRegister(register.AsInt(), uint8(rank.AsInt()))
+	}
+
+	return octosql.MakeInt(hll.Estimate()), nil
+}
+
+func (agg *ApproxCountDistinct) String() string {
+	return "approx_count_distinct"
+}
+
+func (agg *ApproxCountDistinct) Document() docs.Documentation {
+	return docs.Section(
+		agg.String(),
+		docs.Body(
+			docs.Section("Description", docs.Text("Estimates the count of distinct elements in the group using a HyperLogLog sketch, with a standard error of about 1.6%. Uses constant memory per group, but doesn't support retractions.")),
+		),
+	)
+}
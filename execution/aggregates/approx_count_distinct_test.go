@@ -0,0 +1,45 @@
+package aggregates
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestApproxCountDistinct(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("approx_count_distinct")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewApproxCountDistinctAggregate()
+
+	// Empty storage
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeInt(0))
+
+	// Small cardinalities are counted exactly, unless there's a hash collision
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(1))
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(1))
+	AddValue(t, ctx, aggr, tx, octosql.MakeString("1"))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeInt(2))
+
+	// Large cardinalities are estimated
+	for i := 0; i < 20000; i++ {
+		AddValue(t, ctx, aggr, tx, octosql.MakeString(fmt.Sprintf("value_%d", i%10000)))
+	}
+
+	val, err := aggr.GetValue(ctx, tx)
+	assert.Nil(t, err)
+	assert.InEpsilon(t, 10002, val.AsInt(), 0.05)
+
+	// Retractions aren't supported
+	RetractValueError(t, ctx, aggr, tx, octosql.MakeInt(1))
+}
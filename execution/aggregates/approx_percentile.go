@@ -0,0 +1,239 @@
+package aggregates
+
+import (
+	"context"
+	"math"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/storage"
+)
+
+var currentCentroidsPrefix = []byte("$current_centroids$")
+var currentCentroidCountPrefix = []byte("$current_centroid_count$")
+var currentPercentileTotalPrefix = []byte("$current_percentile_total$")
+var currentPercentilePrefix = []byte("$current_percentile$")
+
+// The compression of the t-digest, which bounds the number of centroids after merging them.
+// Centroids are merged once there are twice as many of them.
+const tDigestCompression = 100
+
+// tDigestScale maps a quantile to the scale on which each merged centroid may span at most 1.
+// It's steepest at the ends, so the centroids there stay small, and it spans half the compression in total.
+func tDigestScale(q float64) float64 {
+	return tDigestCompression / (2 * math.Pi) * math.Asin(2*math.Min(q, 1)-1)
+}
+
+// ApproxPercentile estimates a percentile of the values using a t-digest sketch.
+// The sketch keeps centroids, each being the mean of a number of adjacent values, which are smaller near the ends,
+// so that the estimates of extreme percentiles stay accurate, while the state is bounded by the compression.
+// The values passed in are tuples of the value and the percentile, as aggregates take a single argument.
+type ApproxPercentile struct {
+}
+
+func NewApproxPercentileAggregate() *ApproxPercentile {
+	return &ApproxPercentile{}
+}
+
+type centroid struct {
+	mean   float64
+	weight float64
+}
+
+func (agg *ApproxPercentile) AddValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	if value.GetType() != octosql.TypeTuple || len(value.AsSlice()) != 2 {
+		return errors.Errorf("approx_percentile expects a value and a percentile, got %s", value.Show())
+	}
+	floatValue, err := numericFloatValue(value.AsSlice()[0], "approx_percentile")
+	if err != nil {
+		return err
+	}
+	percentile, err := numericFloatValue(value.AsSlice()[1], "approx_percentile")
+	if err != nil {
+		return errors.Wrap(err, "invalid percentile")
+	}
+	if percentile < 0 || percentile > 1 {
+		return errors.Errorf("percentile must be between 0 and 1, got %v", percentile)
+	}
+
+	percentileState := storage.NewValueState(tx.WithPrefix(currentPercentilePrefix))
+	percentileValue := octosql.MakeFloat(percentile)
+	if err := percentileState.Set(&percentileValue); err != nil {
+		return errors.Wrap(err, "couldn't set percentile in storage")
+	}
+
+	if err := addToFloatState(tx.WithPrefix(currentPercentileTotalPrefix), 1); err != nil {
+		return errors.Wrap(err, "couldn't update count of values")
+	}
+
+	centroidsStorage := storage.NewMap(tx.WithPrefix(currentCentroidsPrefix))
+	isNew, err := addCentroid(centroidsStorage, centroid{mean: floatValue, weight: 1})
+	if err != nil {
+		return err
+	}
+	if !isNew {
+		return nil
+	}
+
+	centroidCountState := tx.WithPrefix(currentCentroidCountPrefix)
+	if err := addToFloatState(centroidCountState, 1); err != nil {
+		return errors.Wrap(err, "couldn't update count of centroids")
+	}
+	centroidCount, err := getFloatState(centroidCountState)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get count of centroids")
+	}
+	if centroidCount <= 2*tDigestCompression {
+		return nil
+	}
+
+	return agg.compress(tx)
+}
+
+// addCentroid adds the centroid to storage, merging it with the one with an equal mean, if there is one.
+// It returns true if a new centroid has been created.
+func addCentroid(centroidsStorage *storage.Map, c centroid) (bool, error) {
+	key := octosql.MakeFloat(c.mean)
+
+	var current octosql.Value
+	err := centroidsStorage.Get(&key, &current)
+	isNew := err == storage.ErrNotFound
+	if err != nil && !isNew {
+		return false, errors.Wrap(err, "couldn't get centroid from storage")
+	}
+	if !isNew {
+		c.weight += current.AsFloat()
+	}
+
+	weight := octosql.MakeFloat(c.weight)
+	if err := centroidsStorage.Set(&key, &weight); err != nil {
+		return false, errors.Wrap(err, "couldn't set centroid in storage")
+	}
+
+	return isNew, nil
+}
+
+func getCentroids(centroidsStorage *storage.Map) ([]centroid, error) {
+	it := centroidsStorage.GetIterator()
+	defer func() {
+		_ = it.Close()
+	}()
+
+	var centroids []centroid
+	var mean octosql.Value
+	var weight octosql.Value
+	for {
+		err := it.Next(&mean, &weight)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get centroid from storage")
+		}
+
+		centroids = append(centroids, centroid{mean: mean.AsFloat(), weight: weight.AsFloat()})
+	}
+
+	return centroids, nil
+}
+
+// compress merges adjacent centroids, as long as the merged centroid spans at most 1 on the t-digest scale.
+func (agg *ApproxPercentile) compress(tx storage.StateTransaction) error {
+	centroidsStorage := storage.NewMap(tx.WithPrefix(currentCentroidsPrefix))
+
+	centroids, err := getCentroids(centroidsStorage)
+	if err != nil {
+		return err
+	}
+	total, err := getFloatState(tx.WithPrefix(currentPercentileTotalPrefix))
+	if err != nil {
+		return errors.Wrap(err, "couldn't get count of values")
+	}
+
+	merged := []centroid{centroids[0]}
+	weightBefore := 0.0
+	for _, c := range centroids[1:] {
+		last := &merged[len(merged)-1]
+
+		if tDigestScale((weightBefore+last.weight+c.weight)/total)-tDigestScale(weightBefore/total) <= 1 {
+			last.mean = (last.mean*last.weight + c.mean*c.weight) / (last.weight + c.weight)
+			last.weight += c.weight
+			continue
+		}
+
+		weightBefore += last.weight
+		merged = append(merged, c)
+	}
+
+	if err := centroidsStorage.Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear centroids")
+	}
+	for i := range merged {
+		if _, err := addCentroid(centroidsStorage, merged[i]); err != nil {
+			return err
+		}
+	}
+
+	centroidCount := octosql.MakeFloat(float64(len(merged)))
+	if err := storage.NewValueState(tx.WithPrefix(currentCentroidCountPrefix)).Set(&centroidCount); err != nil {
+		return errors.Wrap(err, "couldn't set count of centroids")
+	}
+
+	return nil
+}
+
+func (agg *ApproxPercentile) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	return errors.New("approx_percentile doesn't support retractions")
+}
+
+func (agg *ApproxPercentile) GetValue(ctx context.Context, tx storage.StateTransaction) (octosql.Value, error) {
+	centroids, err := getCentroids(storage.NewMap(tx.WithPrefix(currentCentroidsPrefix)))
+	if err != nil {
+		return octosql.ZeroValue(), err
+	}
+	if len(centroids) == 0 {
+		return octosql.MakeNull(), nil
+	}
+
+	var percentile octosql.Value
+	if err := storage.NewValueState(tx.WithPrefix(currentPercentilePrefix)).Get(&percentile); err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get percentile from storage")
+	}
+	total, err := getFloatState(tx.WithPrefix(currentPercentileTotalPrefix))
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get count of values")
+	}
+
+	// Each centroid is treated as if its values were centered around its position,
+	// and the estimate is interpolated between the two centroids closest to the target position.
+	target := percentile.AsFloat() * total
+	weightBefore := 0.0
+	for i, c := range centroids {
+		center := weightBefore + c.weight/2
+		if target < center {
+			if i == 0 {
+				return octosql.MakeFloat(c.mean), nil
+			}
+			previous := centroids[i-1]
+			previousCenter := weightBefore - previous.weight/2
+			return octosql.MakeFloat(previous.mean + (c.mean-previous.mean)*(target-previousCenter)/(center-previousCenter)), nil
+		}
+		weightBefore += c.weight
+	}
+
+	return octosql.MakeFloat(centroids[len(centroids)-1].mean), nil
+}
+
+func (agg *ApproxPercentile) String() string {
+	return "approx_percentile"
+}
+
+func (agg *ApproxPercentile) Document() docs.Documentation {
+	return docs.Section(
+		agg.String(),
+		docs.Body(
+			docs.Section("Description", docs.Text("Estimates the given percentile, between 0 and 1, of ints or floats in the group using a t-digest sketch, as in approx_percentile(x, 0.99). Uses bounded memory per group, but doesn't support retractions.")),
+		),
+	)
+}
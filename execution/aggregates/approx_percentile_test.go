@@ -0,0 +1,61 @@
+package aggregates
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestApproxPercentile(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("approx_percentile")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewApproxPercentileAggregate()
+
+	withPercentile := func(value octosql.Value, percentile float64) octosql.Value {
+		return octosql.MakeTuple([]octosql.Value{value, octosql.MakeFloat(percentile)})
+	}
+
+	// Empty storage
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	// Small counts are interpolated exactly
+	AddValue(t, ctx, aggr, tx, withPercentile(octosql.MakeInt(1), 0.5)) // Values: 1
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(1))
+
+	AddValue(t, ctx, aggr, tx, withPercentile(octosql.MakeInt(3), 0.5)) // Values: 1, 3
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(2))
+
+	AddValue(t, ctx, aggr, tx, withPercentile(octosql.MakeFloat(8), 0.5)) // Values: 1, 3, 8
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(3))
+
+	// Large counts are estimated
+	for i := 0; i < 10000; i++ {
+		AddValue(t, ctx, aggr, tx, withPercentile(octosql.MakeInt((i*7919)%10000), 0.99))
+	}
+
+	val, err := aggr.GetValue(ctx, tx)
+	assert.Nil(t, err)
+	assert.InDelta(t, 9900, val.AsFloat(), 50)
+
+	// Wrong arguments
+	AddValueError(t, ctx, aggr, tx, octosql.MakeInt(1))
+
+	AddValueError(t, ctx, aggr, tx, withPercentile(octosql.MakeString("1"), 0.5))
+
+	AddValueError(t, ctx, aggr, tx, withPercentile(octosql.MakeInt(1), 1.5))
+
+	// Retractions aren't supported
+	RetractValueError(t, ctx, aggr, tx, withPercentile(octosql.MakeInt(1), 0.5))
+}
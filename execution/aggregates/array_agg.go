@@ -0,0 +1,206 @@
+package aggregates
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/storage"
+)
+
+var currentArrayAggPrefix = []byte("$current_array_agg$")
+var currentArrayAggCountsPrefix = []byte("$current_array_agg_counts$")
+var currentArrayAggRetractedPrefix = []byte("$current_array_agg_retracted$")
+
+type ArrayAgg struct {
+}
+
+func NewArrayAggAggregate() *ArrayAgg {
+	return &ArrayAgg{}
+}
+
+// ArrayAgg storage contains Deque for the order of elements added and Map for storing counts of every element.
+// Once the element is added, we PushBack it into the Deque and increment its count in Map.
+func (agg *ArrayAgg) AddValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	currentArrayAggStorage := storage.NewDeque(tx.WithPrefix(currentArrayAggPrefix))
+	currentArrayAggCountsStorage := storage.NewMap(tx.WithPrefix(currentArrayAggCountsPrefix))
+
+	var currentValueCount octosql.Value
+	err := currentArrayAggCountsStorage.Get(&value, &currentValueCount)
+	if err == storage.ErrNotFound {
+		currentValueCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get current value count from array_agg storage")
+	}
+
+	currentValueCount = octosql.MakeInt(currentValueCount.AsInt() + 1)
+
+	err = currentArrayAggStorage.PushBack(&value)
+	if err != nil {
+		return errors.Wrap(err, "couldn't push back current value to array_agg deque")
+	}
+
+	err = currentArrayAggCountsStorage.Set(&value, &currentValueCount)
+	if err != nil {
+		return errors.Wrap(err, "couldn't set current value count in array_agg storage")
+	}
+
+	return nil
+}
+
+// Same as in Last, once the element is retracted we don't know its position in Deque, so we only decrement its count in Map.
+// The retracted instances stay in the Deque, until they make up half of it, and it gets compacted.
+func (agg *ArrayAgg) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	currentArrayAggCountsStorage := storage.NewMap(tx.WithPrefix(currentArrayAggCountsPrefix))
+
+	var currentValueCount octosql.Value
+	err := currentArrayAggCountsStorage.Get(&value, &currentValueCount)
+	if err == storage.ErrNotFound {
+		return errors.New("retracted value which wasn't added to array_agg")
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get current value count from array_agg storage")
+	}
+
+	currentValueCount = octosql.MakeInt(currentValueCount.AsInt() - 1)
+
+	if currentValueCount.AsInt() == 0 {
+		err = currentArrayAggCountsStorage.Delete(&value)
+		if err != nil {
+			return errors.Wrap(err, "couldn't delete current value from array_agg storage")
+		}
+	} else {
+		err = currentArrayAggCountsStorage.Set(&value, &currentValueCount)
+		if err != nil {
+			return errors.Wrap(err, "couldn't set current value count in array_agg storage")
+		}
+	}
+
+	retractedCountState := storage.NewValueState(tx.WithPrefix(currentArrayAggRetractedPrefix))
+
+	var retractedCount octosql.Value
+	err = retractedCountState.Get(&retractedCount)
+	if err == storage.ErrNotFound {
+		retractedCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get retracted value count from array_agg storage")
+	}
+	retractedCount = octosql.MakeInt(retractedCount.AsInt() + 1)
+
+	length, err := storage.NewDeque(tx.WithPrefix(currentArrayAggPrefix)).Length()
+	if err != nil {
+		return errors.Wrap(err, "couldn't get length of array_agg deque")
+	}
+
+	if 2*retractedCount.AsInt() < length {
+		err = retractedCountState.Set(&retractedCount)
+		if err != nil {
+			return errors.Wrap(err, "couldn't set retracted value count in array_agg storage")
+		}
+		return nil
+	}
+
+	return agg.compact(tx)
+}
+
+// compact replaces the contents of the Deque with the current values, dropping the retracted instances.
+func (agg *ArrayAgg) compact(tx storage.StateTransaction) error {
+	currentArrayAggStorage := storage.NewDeque(tx.WithPrefix(currentArrayAggPrefix))
+
+	values, err := agg.getCurrentValues(tx)
+	if err != nil {
+		return err
+	}
+
+	if err := currentArrayAggStorage.Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear array_agg deque")
+	}
+	for i := range values {
+		if err := currentArrayAggStorage.PushBack(&values[i]); err != nil {
+			return errors.Wrap(err, "couldn't push back current value to array_agg deque")
+		}
+	}
+
+	if err := storage.NewValueState(tx.WithPrefix(currentArrayAggRetractedPrefix)).Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear retracted value count in array_agg storage")
+	}
+
+	return nil
+}
+
+func (agg *ArrayAgg) GetValue(ctx context.Context, tx storage.StateTransaction) (octosql.Value, error) {
+	values, err := agg.getCurrentValues(tx)
+	if err != nil {
+		return octosql.ZeroValue(), err
+	}
+
+	if len(values) == 0 {
+		return octosql.ZeroValue(), nil
+	}
+
+	return octosql.MakeTuple(values), nil
+}
+
+// getCurrentValues goes through the Deque in order and takes the last instances of each element, as many as its count says,
+// so the retracted instances of an element are the ones added first.
+func (agg *ArrayAgg) getCurrentValues(tx storage.StateTransaction) ([]octosql.Value, error) {
+	currentArrayAggStorage := storage.NewDeque(tx.WithPrefix(currentArrayAggPrefix))
+	currentArrayAggCountsStorage := storage.NewMap(tx.WithPrefix(currentArrayAggCountsPrefix))
+
+	it := currentArrayAggStorage.GetIterator()
+
+	values := make([]octosql.Value, 0)
+	added := make(map[string]int)
+	for {
+		var value octosql.Value
+		err := it.Next(&value)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			_ = it.Close()
+			return nil, errors.Wrap(err, "couldn't get next value from array_agg deque")
+		}
+
+		values = append(values, value)
+		added[string(value.MonotonicMarshal())]++
+	}
+
+	if err := it.Close(); err != nil {
+		return nil, errors.Wrap(err, "couldn't close array_agg deque iterator")
+	}
+
+	out := make([]octosql.Value, 0)
+	for i := range values {
+		key := string(values[i].MonotonicMarshal())
+
+		var valueCount octosql.Value
+		err := currentArrayAggCountsStorage.Get(&values[i], &valueCount)
+		if err == storage.ErrNotFound {
+			valueCount = octosql.MakeInt(0)
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get current value count from array_agg storage")
+		}
+
+		// The first instances of the element are skipped, until there are only as many left as its count says.
+		if added[key] <= valueCount.AsInt() {
+			out = append(out, values[i])
+		}
+		added[key]--
+	}
+
+	return out, nil
+}
+
+func (agg *ArrayAgg) String() string {
+	return "array_agg"
+}
+
+func (agg *ArrayAgg) Document() docs.Documentation {
+	return docs.Section(
+		agg.String(),
+		docs.Body(
+			docs.Section("Description", docs.Text("Collects all elements in the group into a tuple, in the order they were received.")),
+		),
+	)
+}
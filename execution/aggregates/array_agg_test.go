@@ -0,0 +1,79 @@
+package aggregates
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestArrayAgg(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("array_agg")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewArrayAggAggregate()
+
+	// Empty storage
+	ExpectZeroValue(t, ctx, aggr, tx)
+
+	// AddValue
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(12))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeTuple([]octosql.Value{octosql.MakeInt(12)}))
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeString("test"))
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(11))
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(12))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeTuple([]octosql.Value{
+		octosql.MakeInt(12),
+		octosql.MakeString("test"),
+		octosql.MakeInt(11),
+		octosql.MakeInt(12),
+	}))
+
+	// RetractValue
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(12))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeTuple([]octosql.Value{
+		octosql.MakeString("test"),
+		octosql.MakeInt(11),
+		octosql.MakeInt(12),
+	}))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeString("test"))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeTuple([]octosql.Value{
+		octosql.MakeInt(11),
+		octosql.MakeInt(12),
+	}))
+
+	RetractValueError(t, ctx, aggr, tx, octosql.MakeInt(13))
+
+	// Mixed
+	AddValue(t, ctx, aggr, tx, octosql.MakeString("test"))
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeTuple([]octosql.Value{
+		octosql.MakeInt(11),
+		octosql.MakeInt(12),
+		octosql.MakeString("test"),
+	}))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(12))
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(11))
+	RetractValue(t, ctx, aggr, tx, octosql.MakeString("test"))
+
+	ExpectZeroValue(t, ctx, aggr, tx)
+
+	// Retracted instances get compacted out of the deque
+	length, err := storage.NewDeque(tx.WithPrefix(currentArrayAggPrefix)).Length()
+	assert.Nil(t, err)
+	assert.Equal(t, 0, length)
+}
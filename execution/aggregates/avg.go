@@ -45,7 +45,7 @@ func (agg *Average) AddValue(ctx context.Context, tx storage.StateTransaction, v
 }
 
 func isAppropriateType(valueType octosql.Type) bool {
-	return valueType == octosql.TypeInt || valueType == octosql.TypeFloat || valueType == octosql.TypeDuration
+	return valueType == octosql.TypeInt || valueType == octosql.TypeFloat || valueType == octosql.TypeDecimal || valueType == octosql.TypeDuration
 }
 
 func (agg *Average) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
@@ -90,6 +90,13 @@ func (agg *Average) GetValue(ctx context.Context, tx storage.StateTransaction) (
 		currentAvg = octosql.MakeFloat(float64(currentSum.AsInt()) / float64(currentCount.AsInt()))
 	case octosql.TypeFloat:
 		currentAvg = octosql.MakeFloat(currentSum.AsFloat() / float64(currentCount.AsInt()))
+	case octosql.TypeDecimal:
+		sum := currentSum.AsDecimal()
+		avg, err := sum.Div(octosql.DecimalFromInt(currentCount.AsInt()), sum.Scale()+octosql.DecimalDivisionScaleIncrement)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't divide sum by elements count for avg")
+		}
+		currentAvg = octosql.MakeDecimal(avg)
 	case octosql.TypeDuration:
 		currentAvg = octosql.MakeDuration(currentSum.AsDuration() / time.Duration(currentCount.AsInt()))
 	default:
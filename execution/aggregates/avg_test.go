@@ -366,3 +366,32 @@ func TestAvgDuration(t *testing.T) {
 
 	ExpectValue(t, ctx, aggr, tx, octosql.MakeInt(0))
 }
+
+func TestAvgDecimal(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("avg")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewAverageAggregate()
+
+	// AddValue
+	AddValue(t, ctx, aggr, tx, makeDecimal("1.00")) // Val: 1.00	Num: 1
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("1.000000"))
+
+	AddValue(t, ctx, aggr, tx, makeDecimal("0.5")) // Val: 1.50	Num: 2
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.750000"))
+
+	AddValue(t, ctx, aggr, tx, makeDecimal("0.5")) // Val: 2.00	Num: 3
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.666667"))
+
+	// RetractValue
+	RetractValue(t, ctx, aggr, tx, makeDecimal("1")) // Val: 1.00	Num: 2
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.500000"))
+}
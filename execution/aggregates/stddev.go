@@ -0,0 +1,65 @@
+package aggregates
+
+import (
+	"context"
+	"math"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/storage"
+)
+
+type StandardDeviation struct {
+	underlyingVariance *Variance
+}
+
+func NewStandardDeviationAggregate() *StandardDeviation {
+	return &StandardDeviation{
+		underlyingVariance: NewVarianceAggregate(),
+	}
+}
+
+func (agg *StandardDeviation) AddValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	err := agg.underlyingVariance.AddValue(ctx, tx, value)
+	if err != nil {
+		return errors.Wrap(err, "couldn't add value to variance for stddev")
+	}
+
+	return nil
+}
+
+func (agg *StandardDeviation) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	err := agg.underlyingVariance.RetractValue(ctx, tx, value)
+	if err != nil {
+		return errors.Wrap(err, "couldn't retract value from variance for stddev")
+	}
+
+	return nil
+}
+
+func (agg *StandardDeviation) GetValue(ctx context.Context, tx storage.StateTransaction) (octosql.Value, error) {
+	variance, err := agg.underlyingVariance.GetValue(ctx, tx)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get current variance for stddev")
+	}
+	if variance.GetType() == octosql.TypeNull {
+		return variance, nil
+	}
+
+	return octosql.MakeFloat(math.Sqrt(variance.AsFloat())), nil
+}
+
+func (agg *StandardDeviation) String() string {
+	return "stddev"
+}
+
+func (agg *StandardDeviation) Document() docs.Documentation {
+	return docs.Section(
+		agg.String(),
+		docs.Body(
+			docs.Section("Description", docs.Text("Calculates the sample standard deviation of ints or floats in the group. Returns null if there are fewer than two values.")),
+		),
+	)
+}
@@ -0,0 +1,41 @@
+package aggregates
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestStandardDeviation(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("stddev")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewStandardDeviationAggregate()
+
+	// Empty storage
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	// AddValue
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(1)) // Values: 1
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(4)) // Values: 1, 4
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(7)) // Values: 1, 4, 7
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(3))
+
+	AddValueError(t, ctx, aggr, tx, octosql.MakeBool(true))
+
+	// RetractValue
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(1)) // Values: 4, 7
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(2.1213203435596424))
+}
@@ -58,6 +58,12 @@ func (agg *Sum) AddValue(ctx context.Context, tx storage.StateTransaction, value
 		currentSum = octosql.MakeInt(currentSum.AsInt() + value.AsInt())
 	case octosql.TypeFloat:
 		currentSum = octosql.MakeFloat(currentSum.AsFloat() + value.AsFloat())
+	case octosql.TypeDecimal:
+		sum, err := currentSum.AsDecimal().Add(value.AsDecimal())
+		if err != nil {
+			return errors.Wrap(err, "couldn't add decimal")
+		}
+		currentSum = octosql.MakeDecimal(sum)
 	case octosql.TypeDuration:
 		currentSum = octosql.MakeDuration(currentSum.AsDuration() + value.AsDuration())
 	default:
@@ -85,6 +91,8 @@ func isNeutralElement(currentSum octosql.Value, currentCount octosql.Value) bool
 		return currentSum.AsInt() == 0 && currentCount.AsInt() == 0
 	case octosql.TypeFloat:
 		return currentSum.AsFloat() == 0.0 && currentCount.AsInt() == 0
+	case octosql.TypeDecimal:
+		return currentSum.AsDecimal().Sign() == 0 && currentCount.AsInt() == 0
 	case octosql.TypeDuration:
 		return currentSum.AsDuration() == 0 && currentCount.AsInt() == 0
 	default:
@@ -98,6 +106,8 @@ func getAppropriateNeutralElement(valueType octosql.Type) (octosql.Value, error)
 		return octosql.MakeInt(0), nil
 	case octosql.TypeFloat:
 		return octosql.MakeFloat(0.0), nil
+	case octosql.TypeDecimal:
+		return octosql.ZeroDecimal(), nil
 	case octosql.TypeDuration:
 		return octosql.MakeDuration(0), nil
 	default:
@@ -140,6 +150,12 @@ func (agg *Sum) RetractValue(ctx context.Context, tx storage.StateTransaction, v
 		currentSum = octosql.MakeInt(currentSum.AsInt() - value.AsInt())
 	case octosql.TypeFloat:
 		currentSum = octosql.MakeFloat(currentSum.AsFloat() - value.AsFloat())
+	case octosql.TypeDecimal:
+		sum, err := currentSum.AsDecimal().Sub(value.AsDecimal())
+		if err != nil {
+			return errors.Wrap(err, "couldn't subtract decimal")
+		}
+		currentSum = octosql.MakeDecimal(sum)
 	case octosql.TypeDuration:
 		currentSum = octosql.MakeDuration(currentSum.AsDuration() - value.AsDuration())
 	default:
@@ -190,7 +206,7 @@ func (agg *Sum) Document() docs.Documentation {
 	return docs.Section(
 		agg.String(),
 		docs.Body(
-			docs.Section("Description", docs.Text("Sums Floats, Ints or Durations in the group. You may not mix types.")),
+			docs.Section("Description", docs.Text("Sums Floats, Ints, Decimals or Durations in the group. You may not mix types.")),
 		),
 	)
 }
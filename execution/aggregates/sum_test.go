@@ -187,6 +187,49 @@ func TestSumDuration(t *testing.T) {
 	ExpectValue(t, ctx, aggr, tx, octosql.MakeDuration(0))
 }
 
+func TestSumDecimal(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("sum")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewSumAggregate()
+
+	// Empty storage
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeInt(0))
+
+	// AddValue
+	AddValue(t, ctx, aggr, tx, makeDecimal("0.1"))
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.1"))
+
+	AddValue(t, ctx, aggr, tx, makeDecimal("0.20"))
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.30"))
+
+	// Wrong type passed
+	AddValueError(t, ctx, aggr, tx, octosql.MakeFloat(0.3))
+
+	// RetractValue
+	RetractValue(t, ctx, aggr, tx, makeDecimal("0.1"))
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.20"))
+
+	RetractValue(t, ctx, aggr, tx, makeDecimal("0.2"))
+
+	ExpectValue(t, ctx, aggr, tx, makeDecimal("0.00"))
+}
+
+func makeDecimal(s string) octosql.Value {
+	d, err := octosql.ParseDecimal(s)
+	if err != nil {
+		panic(err)
+	}
+	return octosql.MakeDecimal(d)
+}
+
 func TestSumNasty(t *testing.T) {
 	ctx := context.Background()
 
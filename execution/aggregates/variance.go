@@ -0,0 +1,185 @@
+package aggregates
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/storage"
+)
+
+var currentVarianceMeanPrefix = []byte("$current_variance_mean$")
+var currentVarianceSquaredDistancesPrefix = []byte("$current_variance_squared_distances$")
+
+type Variance struct {
+	underlyingCount *Count
+}
+
+func NewVarianceAggregate() *Variance {
+	return &Variance{
+		underlyingCount: NewCountAggregate(),
+	}
+}
+
+func (agg *Variance) AddValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	floatValue, err := numericFloatValue(value, "variance")
+	if err != nil {
+		return err
+	}
+
+	if err := agg.update(ctx, tx, floatValue, 1); err != nil {
+		return errors.Wrap(err, "couldn't add value to state for variance")
+	}
+
+	err = agg.underlyingCount.AddValue(ctx, tx, value)
+	if err != nil {
+		return errors.Wrap(err, "couldn't add element to elements count for variance")
+	}
+
+	return nil
+}
+
+func (agg *Variance) RetractValue(ctx context.Context, tx storage.StateTransaction, value octosql.Value) error {
+	floatValue, err := numericFloatValue(value, "variance")
+	if err != nil {
+		return err
+	}
+
+	if err := agg.update(ctx, tx, floatValue, -1); err != nil {
+		return errors.Wrap(err, "couldn't retract value from state for variance")
+	}
+
+	err = agg.underlyingCount.RetractValue(ctx, tx, value)
+	if err != nil {
+		return errors.Wrap(err, "couldn't retract element from elements count for variance")
+	}
+
+	return nil
+}
+
+// numericFloatValue converts a numeric value to a float, for aggregates which calculate in floats.
+func numericFloatValue(value octosql.Value, aggregate string) (float64, error) {
+	switch value.GetType() {
+	case octosql.TypeInt:
+		return float64(value.AsInt()), nil
+	case octosql.TypeFloat:
+		return value.AsFloat(), nil
+	case octosql.TypeDecimal:
+		return value.AsDecimal().Float(), nil
+	default:
+		return 0, errors.Errorf("type of value passed (%s) isn't appropriate for calculating %s", value.GetType(), aggregate)
+	}
+}
+
+// update adds the value to the mean and the sum of squared distances from the mean using Welford's algorithm,
+// which, unlike a sum of squares, doesn't lose precision for large values with a small variance.
+// A sign of -1 means a retraction, which applies the inverse update. It has to be called before the count is updated.
+func (agg *Variance) update(ctx context.Context, tx storage.StateTransaction, value float64, sign int) error {
+	currentCount, err := agg.underlyingCount.GetValue(ctx, tx)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get current elements count")
+	}
+	meanState := tx.WithPrefix(currentVarianceMeanPrefix)
+	distancesState := tx.WithPrefix(currentVarianceSquaredDistancesPrefix)
+
+	mean, err := getFloatState(meanState)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get mean")
+	}
+	distances, err := getFloatState(distancesState)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get sum of squared distances")
+	}
+
+	count := currentCount.AsInt() + sign
+	var newMean, newDistances float64
+	switch {
+	case count <= 0:
+		// Starting over avoids accumulating rounding errors once the group is empty.
+	case sign > 0:
+		newMean = mean + (value-mean)/float64(count)
+		newDistances = distances + (value-mean)*(value-newMean)
+	default:
+		newMean = mean - (value-mean)/float64(count)
+		newDistances = distances - (value-newMean)*(value-mean)
+	}
+	if newDistances < 0 { // floating point rounding errors
+		newDistances = 0
+	}
+
+	if err := setFloatState(meanState, newMean); err != nil {
+		return errors.Wrap(err, "couldn't set mean")
+	}
+	if err := setFloatState(distancesState, newDistances); err != nil {
+		return errors.Wrap(err, "couldn't set sum of squared distances")
+	}
+
+	return nil
+}
+
+func addToFloatState(tx storage.StateTransaction, delta float64) error {
+	current, err := getFloatState(tx)
+	if err != nil {
+		return err
+	}
+
+	return setFloatState(tx, current+delta)
+}
+
+func setFloatState(tx storage.StateTransaction, value float64) error {
+	state := storage.NewValueState(tx)
+
+	newValue := octosql.MakeFloat(value)
+	if err := state.Set(&newValue); err != nil {
+		return errors.Wrap(err, "couldn't set value in storage")
+	}
+
+	return nil
+}
+
+func getFloatState(tx storage.StateTransaction) (float64, error) {
+	state := storage.NewValueState(tx)
+
+	var current octosql.Value
+	err := state.Get(&current)
+	if err == storage.ErrNotFound {
+		return 0, nil
+	} else if err != nil {
+		return 0, errors.Wrap(err, "couldn't get value from storage")
+	}
+
+	return current.AsFloat(), nil
+}
+
+func (agg *Variance) GetValue(ctx context.Context, tx storage.StateTransaction) (octosql.Value, error) {
+	currentCount, err := agg.underlyingCount.GetValue(ctx, tx)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get current elements count for variance")
+	}
+
+	if currentCount.AsInt() <= 1 { // the sample variance is undefined for fewer than two values
+		return octosql.MakeNull(), nil
+	}
+
+	distances, err := getFloatState(tx.WithPrefix(currentVarianceSquaredDistancesPrefix))
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get current sum of squared distances for variance")
+	}
+
+	return octosql.MakeFloat(distances / float64(currentCount.AsInt()-1)), nil
+}
+
+func (agg *Variance) String() string {
+	return "variance"
+}
+
+func (agg *Variance) Document() docs.Documentation {
+	return docs.Section(
+		agg.String(),
+		docs.Body(
+			docs.Section("Description", docs.Text("Calculates the sample variance of ints or floats in the group. Returns null if there are fewer than two values.")),
+		),
+	)
+}
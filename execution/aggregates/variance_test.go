@@ -0,0 +1,98 @@
+package aggregates
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestVariance(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("variance")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewVarianceAggregate()
+
+	// Empty storage
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	// AddValue
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(1)) // Values: 1
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(3)) // Values: 1, 3
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(2))
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(5)) // Values: 1, 3, 5
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(4))
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(-5)) // Values: 1, 3, 5, -5
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(56.0/3))
+
+	// Wrong type passed
+	AddValueError(t, ctx, aggr, tx, octosql.MakeTime(time.Now()))
+
+	AddValueError(t, ctx, aggr, tx, octosql.MakeString("1"))
+
+	// RetractValue
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(-5)) // Values: 1, 3, 5
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(4))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(3)) // Values: 1, 5
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(8))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeInt(5)) // Values: 1
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeNull())
+
+	RetractValueError(t, ctx, aggr, tx, octosql.MakeString("1"))
+}
+
+func TestVariance_LargeValues(t *testing.T) {
+	ctx := context.Background()
+
+	prefix := []byte("variance")
+
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction().WithPrefix(prefix)
+
+	aggr := NewVarianceAggregate()
+
+	// A sum of squares of these values, around 4e18, would lose all of the digits the variance depends on.
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+4))
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+7))
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+13))
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+16)) // Values: 1e9 + 4, 7, 13, 16
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(30))
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+1.5))
+	RetractValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+1.5)) // Values: 1e9 + 4, 7, 13, 16
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(30))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+16)) // Values: 1e9 + 4, 7, 13
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(21))
+
+	RetractValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+4))
+	RetractValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+7))
+	RetractValue(t, ctx, aggr, tx, octosql.MakeFloat(1e9+13)) // Values:
+
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(1))
+	AddValue(t, ctx, aggr, tx, octosql.MakeInt(3)) // Values: 1, 3
+
+	ExpectValue(t, ctx, aggr, tx, octosql.MakeFloat(2))
+}
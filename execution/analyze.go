@@ -0,0 +1,105 @@
+package execution
+
+import (
+	"context"
+	"sync/atomic"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// NodeStats are execution statistics of a single node, collected for EXPLAIN ANALYZE.
+// They're shared by all the streams of the node, which may run concurrently, so they're updated atomically.
+type NodeStats struct {
+	records     int64
+	retractions int64
+	duration    int64
+}
+
+func NewNodeStats() *NodeStats {
+	return &NodeStats{}
+}
+
+// Records returns the number of records produced by the node, not counting retractions.
+func (stats *NodeStats) Records() int64 {
+	return atomic.LoadInt64(&stats.records)
+}
+
+// Retractions returns the number of retractions produced by the node.
+func (stats *NodeStats) Retractions() int64 {
+	return atomic.LoadInt64(&stats.retractions)
+}
+
+// Duration returns the wall time spent in the node, including the time spent in its sources.
+func (stats *NodeStats) Duration() time.Duration {
+	return time.Duration(atomic.LoadInt64(&stats.duration))
+}
+
+func (stats *NodeStats) addDuration(start time.Time) {
+	atomic.AddInt64(&stats.duration, int64(time.Since(start)))
+}
+
+// Analyze passes through the records of its source, collecting execution statistics about them.
+type Analyze struct {
+	stats  *NodeStats
+	source Node
+}
+
+func NewAnalyze(stats *NodeStats, child Node) *Analyze {
+	return &Analyze{stats: stats, source: child}
+}
+
+func (node *Analyze) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	start := time.Now()
+	defer node.stats.addDuration(start)
+
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	recordStream, execOutput, err := node.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get record stream")
+	}
+
+	return &AnalyzedStream{
+		stats:  node.stats,
+		source: recordStream,
+	}, execOutput, nil
+}
+
+type AnalyzedStream struct {
+	stats  *NodeStats
+	source RecordStream
+}
+
+func (stream *AnalyzedStream) Next(ctx context.Context) (*Record, error) {
+	start := time.Now()
+	defer stream.stats.addDuration(start)
+
+	record, err := stream.source.Next(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	if record.IsUndo() {
+		atomic.AddInt64(&stream.stats.retractions, 1)
+	} else {
+		atomic.AddInt64(&stream.stats.records, 1)
+	}
+
+	return record, nil
+}
+
+func (stream *AnalyzedStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := stream.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+
+	return nil
+}
@@ -0,0 +1,44 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestAnalyzedStream_Next(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	ctx := storage.InjectStateTransaction(context.Background(), tx)
+
+	fieldNames := []octosql.VariableName{
+		octosql.NewVariableName("e.id"),
+	}
+
+	records := []*Record{
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{1}, WithID(NewRecordID("1"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{2}, WithID(NewRecordID("2"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{1}, WithID(NewRecordID("1")), WithUndo()),
+	}
+
+	stats := NewNodeStats()
+	stream := &AnalyzedStream{
+		stats:  stats,
+		source: NewInMemoryStream(ctx, records),
+	}
+
+	if err := AreStreamsEqual(ctx, stream, NewInMemoryStream(ctx, records)); err != nil {
+		t.Errorf("AnalyzedStream.Next() AreStreamsEqual error = %v", err)
+	}
+
+	if stats.Records() != 2 {
+		t.Errorf("NodeStats.Records() = %v, want %v", stats.Records(), 2)
+	}
+	if stats.Retractions() != 1 {
+		t.Errorf("NodeStats.Retractions() = %v, want %v", stats.Retractions(), 1)
+	}
+}
@@ -0,0 +1,84 @@
+package execution
+
+import (
+	"net"
+
+	"github.com/cube2222/octosql"
+)
+
+// CoerceToCommonType implicitly converts the operands of an operator or function to a common type.
+// Ints are converted to Floats or Decimals, and Decimals to Floats, made if there are operands of both types.
+// Strings in the canonical UUID form are converted to UUIDs, if there are UUID operands, and IP addresses to IPs, if there are IP operands.
+// Operands of other types are returned unchanged, so that the operator can report the type mismatch.
+func CoerceToCommonType(values ...octosql.Value) []octosql.Value {
+	var hasInt, hasFloat, hasDecimal, hasUUID, hasIP, hasString bool
+	for i := range values {
+		switch values[i].GetType() {
+		case octosql.TypeInt:
+			hasInt = true
+		case octosql.TypeFloat:
+			hasFloat = true
+		case octosql.TypeDecimal:
+			hasDecimal = true
+		case octosql.TypeUUID:
+			hasUUID = true
+		case octosql.TypeIP:
+			hasIP = true
+		case octosql.TypeString:
+			hasString = true
+		}
+	}
+
+	switch {
+	case hasFloat && (hasInt || hasDecimal):
+		out := make([]octosql.Value, len(values))
+		for i := range values {
+			switch values[i].GetType() {
+			case octosql.TypeInt:
+				out[i] = octosql.MakeFloat(float64(values[i].AsInt()))
+			case octosql.TypeDecimal:
+				out[i] = octosql.MakeFloat(values[i].AsDecimal().Float())
+			default:
+				out[i] = values[i]
+			}
+		}
+		return out
+
+	case hasDecimal && hasInt:
+		out := make([]octosql.Value, len(values))
+		for i := range values {
+			if values[i].GetType() == octosql.TypeInt {
+				out[i] = octosql.MakeDecimal(octosql.DecimalFromInt(values[i].AsInt()))
+			} else {
+				out[i] = values[i]
+			}
+		}
+		return out
+
+	case hasUUID && hasString:
+		out := make([]octosql.Value, len(values))
+		for i := range values {
+			out[i] = values[i]
+			if values[i].GetType() == octosql.TypeString {
+				if u, err := octosql.ParseUUID(values[i].AsString()); err == nil {
+					out[i] = octosql.MakeUUID(u)
+				}
+			}
+		}
+		return out
+
+	case hasIP && hasString:
+		out := make([]octosql.Value, len(values))
+		for i := range values {
+			out[i] = values[i]
+			if values[i].GetType() == octosql.TypeString {
+				if ip := net.ParseIP(values[i].AsString()); ip != nil {
+					out[i] = octosql.MakeIP(ip)
+				}
+			}
+		}
+		return out
+	}
+
+	return values
+}
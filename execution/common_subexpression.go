@@ -0,0 +1,69 @@
+package execution
+
+import (
+	"context"
+
+	"github.com/cube2222/octosql"
+)
+
+// CommonSubexpression is an expression which occurs multiple times in a Map or Filter.
+// It's evaluated once per record, with the value being reused by the other occurrences.
+// Outside of a subexpression cache it's simply evaluated each time.
+type CommonSubexpression struct {
+	expr Expression
+}
+
+func NewCommonSubexpression() *CommonSubexpression {
+	return &CommonSubexpression{}
+}
+
+// SetExpression sets the underlying expression, as all the occurrences get created before it's materialized.
+func (cs *CommonSubexpression) SetExpression(expr Expression) {
+	cs.expr = expr
+}
+
+// IsSet checks if the underlying expression has already been set.
+func (cs *CommonSubexpression) IsSet() bool {
+	return cs.expr != nil
+}
+
+func (cs *CommonSubexpression) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	cache := getSubexpressionCache(ctx)
+	if cache == nil {
+		return cs.expr.ExpressionValue(ctx, variables)
+	}
+
+	if value, ok := cache.values[cs]; ok {
+		return value, nil
+	}
+
+	value, err := cs.expr.ExpressionValue(ctx, variables)
+	if err != nil {
+		return octosql.ZeroValue(), err
+	}
+
+	if cache.values == nil {
+		cache.values = make(map[*CommonSubexpression]octosql.Value)
+	}
+	cache.values[cs] = value
+
+	return value, nil
+}
+
+// subexpressionCache keeps the values of common subexpressions for a single record.
+type subexpressionCache struct {
+	values map[*CommonSubexpression]octosql.Value
+}
+
+type subexpressionCacheCtxKey struct{}
+
+// withSubexpressionCache returns a context in which common subexpressions are evaluated only once.
+// It should be created for each record.
+func withSubexpressionCache(ctx context.Context) context.Context {
+	return context.WithValue(ctx, subexpressionCacheCtxKey{}, &subexpressionCache{})
+}
+
+func getSubexpressionCache(ctx context.Context) *subexpressionCache {
+	cache, _ := ctx.Value(subexpressionCacheCtxKey{}).(*subexpressionCache)
+	return cache
+}
@@ -5,9 +5,9 @@ import (
 	"fmt"
 	"log"
 	"math/rand"
+	"runtime/trace"
 	"time"
 
-	"github.com/dgraph-io/badger/v2"
 	"github.com/pkg/errors"
 
 	"github.com/cube2222/octosql/storage"
@@ -114,7 +114,11 @@ func (engine *PullEngine) Run() {
 			tx = engine.refreshTransaction(tx)
 		}
 
-		triggeredCount, err := engine.irs.TriggerKeys(engine.ctx, engine.getPrefixedTx(tx), engine.batchSizeManager.RecordsLeftToTake())
+		var triggeredCount int
+		var err error
+		trace.WithRegion(engine.ctx, "trigger keys", func() {
+			triggeredCount, err = engine.irs.TriggerKeys(engine.ctx, engine.getPrefixedTx(tx), engine.batchSizeManager.RecordsLeftToTake())
+		})
 		if triggeredCount == 0 && err == nil {
 			// Nothing triggered, just go forth, if the stream hasn't ended yet.
 			if endOfStreamReached {
@@ -149,7 +153,9 @@ func (engine *PullEngine) Run() {
 			return
 		}
 
-		err = engine.loop(engine.ctx, tx)
+		trace.WithRegion(engine.ctx, "process record", func() {
+			err = engine.loop(engine.ctx, tx)
+		})
 		if err == ErrEndOfStream {
 			err := tx.Commit()
 			if err == nil {
@@ -173,7 +179,9 @@ func (engine *PullEngine) Run() {
 				log.Println("engine: couldn't commit: ", err)
 			}
 			engine.batchSizeManager.CommitSuccessful()
-			err = waitableError.ListenForChanges(engine.ctx)
+			trace.WithRegion(engine.ctx, "wait for changes", func() {
+				err = waitableError.ListenForChanges(engine.ctx)
+			})
 			if err != nil {
 				log.Println("engine: couldn't listen for changes: ", err)
 			}
@@ -207,9 +215,12 @@ func (engine *PullEngine) Run() {
 
 func (engine *PullEngine) refreshTransaction(tx storage.StateTransaction) storage.StateTransaction {
 	if !engine.batchSizeManager.ShouldTakeNextRecord() {
-		err := tx.Commit()
+		var err error
+		trace.WithRegion(engine.ctx, "commit", func() {
+			err = tx.Commit()
+		})
 		if err != nil {
-			if errors.Cause(err) == badger.ErrTxnTooBig {
+			if errors.Cause(err) == storage.ErrTransactionTooBig {
 				engine.batchSizeManager.CommitTooBig()
 			}
 			log.Println("engine: couldn't commit: ", err)
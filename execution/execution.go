@@ -95,6 +95,7 @@ type OutputOptions struct {
 	OrderByDirections  []OrderDirection
 	Limit              Expression
 	Offset             Expression
+	OutputFile         string
 }
 
 func NewOutputOptions(
@@ -102,12 +103,14 @@ func NewOutputOptions(
 	orderByDirections []OrderDirection,
 	limit Expression,
 	offset Expression,
+	outputFile string,
 ) *OutputOptions {
 	return &OutputOptions{
 		OrderByExpressions: orderByExpressions,
 		OrderByDirections:  orderByDirections,
 		Limit:              limit,
 		Offset:             offset,
+		OutputFile:         outputFile,
 	}
 }
 
@@ -126,18 +129,26 @@ type NamedExpression interface {
 
 type StarExpression struct {
 	qualifier string
+	exclude   []octosql.VariableName
+	replace   []NamedExpression
 }
 
 func NewStarExpression(qualifier string) *StarExpression {
 	return &StarExpression{qualifier: qualifier}
 }
 
+// NewStarExpressionWithModifiers creates a star expression which skips the excluded columns
+// and takes the values of the replaced ones from the replacement expressions, named after the columns they replace.
+func NewStarExpressionWithModifiers(qualifier string, exclude []octosql.VariableName, replace []NamedExpression) *StarExpression {
+	return &StarExpression{qualifier: qualifier, exclude: exclude, replace: replace}
+}
+
 func (se *StarExpression) Fields(variables octosql.Variables) []octosql.VariableName {
 	keys := variables.DeterministicOrder()
 	fields := make([]octosql.VariableName, 0)
 
 	for _, key := range keys {
-		if se.doesVariableMatch(key) {
+		if se.doesVariableMatch(key) && !se.isExcluded(key) {
 			fields = append(fields, key)
 		}
 	}
@@ -145,14 +156,29 @@ func (se *StarExpression) Fields(variables octosql.Variables) []octosql.Variable
 	return fields
 }
 
+// FieldValue returns the value of a field returned by Fields, which may be replaced.
+func (se *StarExpression) FieldValue(ctx context.Context, variables octosql.Variables, field octosql.VariableName) (octosql.Value, error) {
+	for _, replacement := range se.replace {
+		if field.MatchesColumn(replacement.Name()) {
+			value, err := replacement.ExpressionValue(ctx, variables)
+			if err != nil {
+				return octosql.ZeroValue(), errors.Wrapf(err, "couldn't get replacement of %v", field)
+			}
+			return value, nil
+		}
+	}
+
+	return variables.Get(field)
+}
+
 func (se *StarExpression) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
 	fields := se.Fields(variables)
 	values := make([]octosql.Value, len(fields))
 
 	for i := range fields {
-		value, err := variables.Get(fields[i])
+		value, err := se.FieldValue(ctx, variables, fields[i])
 		if err != nil {
-			panic("failed to read star expression")
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't get star expression field value")
 		}
 		values[i] = value
 	}
@@ -172,6 +198,15 @@ func (se *StarExpression) doesVariableMatch(vname octosql.VariableName) bool {
 	return se.qualifier == "" || se.qualifier == vname.Source()
 }
 
+func (se *StarExpression) isExcluded(vname octosql.VariableName) bool {
+	for _, column := range se.exclude {
+		if vname.MatchesColumn(column) {
+			return true
+		}
+	}
+	return false
+}
+
 type Variable struct {
 	name octosql.VariableName
 }
@@ -216,54 +251,149 @@ func (tup *TupleExpression) ExpressionValue(ctx context.Context, variables octos
 type NodeExpression struct {
 	node         Node
 	stateStorage storage.Storage
+
+	// If memoized, the records of the subquery are cached by the values of the variables it references,
+	// so that it's only executed once for each distinct combination of them during a run of the query.
+	memoized            bool
+	referencedVariables []octosql.VariableName
 }
 
 func NewNodeExpression(node Node, stateStorage storage.Storage) *NodeExpression {
 	return &NodeExpression{node: node, stateStorage: stateStorage}
 }
 
+func NewMemoizedNodeExpression(node Node, stateStorage storage.Storage, referencedVariables []octosql.VariableName) *NodeExpression {
+	return &NodeExpression{
+		node:                node,
+		stateStorage:        stateStorage,
+		memoized:            true,
+		referencedVariables: referencedVariables,
+	}
+}
+
+// ExpressionValue returns the value of the subquery used as a scalar.
+// It's null if there are no records, and an error if there is more than one.
 func (ne *NodeExpression) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
-	tx := ne.stateStorage.BeginTransaction()
-	// TODO: All of this has to be rewritten to be multithreaded using background jobs for the subqueries. Think about this.
-	recordStream, _, err := ne.node.Get(storage.InjectStateTransaction(ctx, tx), variables, GetRawStreamID())
+	records, err := ne.getRecords(ctx, variables)
 	if err != nil {
-		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get record stream")
+		return octosql.ZeroValue(), err
 	}
-	if err := tx.Commit(); err != nil {
-		return octosql.ZeroValue(), errors.Wrap(err, "couldn't commit transaction")
+	records = withoutRetractions(records)
+
+	switch len(records) {
+	case 0:
+		return octosql.MakeNull(), nil
+	case 1:
+		return subqueryRecordValue(records[0]), nil
+	default:
+		return octosql.ZeroValue(), errors.Errorf("subquery used as a value returned %d records, expected at most one", len(records))
 	}
+}
 
-	records, err := ReadAll(ctx, ne.stateStorage, recordStream)
+// SetValue returns the values of all records of the subquery as a tuple, so it can be used on the right side of IN.
+func (ne *NodeExpression) SetValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	records, err := ne.getRecords(ctx, variables)
 	if err != nil {
-		return octosql.ZeroValue(), errors.Wrap(err, "couldn't read whole subquery stream")
+		return octosql.ZeroValue(), err
+	}
+	records = withoutRetractions(records)
+
+	values := make([]octosql.Value, len(records))
+	for i := range records {
+		values[i] = subqueryRecordValue(records[i])
 	}
 
-	var firstRecord octosql.Value
-	outRecords := make([]octosql.Value, 0)
-	for _, curRecord := range records {
-		if octosql.AreEqual(firstRecord, octosql.ZeroValue()) {
-			firstRecord = curRecord.AsTuple()
+	return octosql.MakeTuple(values), nil
+}
+
+// getRecords returns the records of the subquery, from the cache of the run if it's memoized and has already been run with the same referenced variable values.
+func (ne *NodeExpression) getRecords(ctx context.Context, variables octosql.Variables) ([]*Record, error) {
+	cache := getSubqueryCache(ctx)
+	if !ne.memoized || cache == nil {
+		return readSubquery(ctx, ne.node, ne.stateStorage, variables)
+	}
+
+	keyValues := make([]octosql.Value, len(ne.referencedVariables))
+	for i, name := range ne.referencedVariables {
+		value, err := variables.Get(name)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't get variable %v", name)
 		}
-		outRecords = append(outRecords, curRecord.AsTuple())
+		keyValues[i] = value
+	}
+	key := subqueryCacheKey{
+		expression: ne,
+		variables:  string(octosql.MakeTuple(keyValues).MonotonicMarshal()),
 	}
 
-	if len(outRecords) > 1 {
-		return octosql.MakeTuple(outRecords), nil
+	if records, ok := cache.get(key); ok {
+		return records, nil
 	}
-	if len(outRecords) == 0 {
-		return octosql.ZeroValue(), nil
+
+	records, err := readSubquery(ctx, ne.node, ne.stateStorage, variables)
+	if err != nil {
+		return nil, err
 	}
+	cache.put(key, records)
 
-	// There is exactly one record
-	if len(firstRecord.AsSlice()) > 1 {
-		return firstRecord, nil
+	return records, nil
+}
+
+// withoutRetractions skips the retractions, together with the records they retract.
+func withoutRetractions(records []*Record) []*Record {
+	retracted := make(map[string]int)
+	for i := range records {
+		if records[i].IsUndo() {
+			retracted[string(records[i].AsTuple().MonotonicMarshal())]++
+		}
 	}
-	if len(firstRecord.AsSlice()) == 0 {
-		return octosql.ZeroValue(), nil
+
+	out := make([]*Record, 0, len(records))
+	for i := range records {
+		if records[i].IsUndo() {
+			continue
+		}
+		key := string(records[i].AsTuple().MonotonicMarshal())
+		if retracted[key] > 0 {
+			retracted[key]--
+			continue
+		}
+		out = append(out, records[i])
+	}
+
+	return out
+}
+
+// subqueryRecordValue returns the only field of the record, or all its fields as a tuple if there are more.
+func subqueryRecordValue(record *Record) octosql.Value {
+	switch len(record.Data) {
+	case 0:
+		return octosql.MakeNull()
+	case 1:
+		return *record.Data[0]
+	default:
+		return record.AsTuple()
+	}
+}
+
+// readSubquery runs the node with the given variables and reads all its records.
+func readSubquery(ctx context.Context, node Node, stateStorage storage.Storage, variables octosql.Variables) ([]*Record, error) {
+	tx := stateStorage.BeginTransaction()
+	// TODO: All of this has to be rewritten to be multithreaded using background jobs for the subqueries. Think about this.
+	recordStream, _, err := node.Get(storage.InjectStateTransaction(ctx, tx), variables, GetRawStreamID())
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get record stream")
+	}
+	if err := tx.Commit(); err != nil {
+		return nil, errors.Wrap(err, "couldn't commit transaction")
+	}
+
+	records, err := ReadAll(ctx, stateStorage, recordStream)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read whole subquery stream")
 	}
 
-	// There is exactly one field
-	return firstRecord.AsSlice()[0], nil
+	return records, nil
 }
 
 type LogicExpression struct {
@@ -277,8 +407,7 @@ func NewLogicExpression(formula Formula) *LogicExpression {
 }
 
 func (le *LogicExpression) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
-	out, err := le.formula.Evaluate(ctx, variables)
-	return octosql.MakeBool(out), err
+	return le.formula.Evaluate(ctx, variables)
 }
 
 type AliasedExpression struct {
@@ -66,12 +66,12 @@ func (stream *FilteredStream) Next(ctx context.Context) (*Record, error) {
 			return nil, errors.Wrap(err, "couldn't merge given variables with record variables")
 		}
 
-		predicate, err := stream.formula.Evaluate(ctx, variables)
+		predicate, err := stream.formula.Evaluate(withSubexpressionCache(ctx), variables)
 		if err != nil {
 			return nil, errors.Wrap(err, "couldn't evaluate formula")
 		}
 
-		if predicate {
+		if isTrue(predicate) {
 			return record, nil
 		}
 	}
@@ -16,6 +16,14 @@ type Function struct {
 	Description   docs.Documentation
 	Validator     Validator
 	Logic         func(...octosql.Value) (octosql.Value, error)
+
+	// ContextLogic is used instead of Logic by functions depending on the query they're run in,
+	// like the ones using the session time zone.
+	ContextLogic func(ctx context.Context, args ...octosql.Value) (octosql.Value, error)
+
+	// Nondeterministic functions may return different values for the same arguments, like randint.
+	// Subqueries using them are executed for each record, instead of being memoized.
+	Nondeterministic bool
 }
 
 func (f *Function) Document() docs.Documentation {
@@ -60,13 +68,19 @@ func (fe *FunctionExpression) ExpressionValue(ctx context.Context, variables oct
 
 		values = append(values, value)
 	}
+	values = CoerceToCommonType(values...)
 
 	err := fe.function.Validator.Validate(values...)
 	if err != nil {
 		return octosql.ZeroValue(), errors.Wrapf(err, "invalid arguments to function %v", fe.function.Name)
 	}
 
-	finalValue, err := fe.function.Logic(values...)
+	var finalValue octosql.Value
+	if fe.function.ContextLogic != nil {
+		finalValue, err = fe.function.ContextLogic(ctx, values...)
+	} else {
+		finalValue, err = fe.function.Logic(values...)
+	}
 	if err != nil {
 		return octosql.ZeroValue(), errors.Wrapf(err, "couldn't get function %v value", fe.function.Name)
 	}
@@ -9,10 +9,7 @@ import (
 )
 
 func main() {
-	var functionNames []string
-	for k := range functions.FunctionTable {
-		functionNames = append(functionNames, k)
-	}
+	functionNames := functions.Names()
 	sort.Strings(functionNames)
 
 	// These names have different hashes than name, so we need to create separate 'hashes' slice
@@ -25,7 +22,8 @@ func main() {
 	hashes := make([]string, len(functionNames))
 	functionDocs := make([]docs.Documentation, len(functionNames))
 	for i, name := range functionNames {
-		functionDocs[i] = functions.FunctionTable[name].Document()
+		function, _ := functions.Lookup(name)
+		functionDocs[i] = function.Document()
 		hashes[i] = name
 		if hash, ok := hashesMap[name]; ok {
 			hashes[i] = hash
@@ -0,0 +1,260 @@
+package functions
+
+import (
+	"fmt"
+	"strings"
+	"unicode"
+)
+
+// tokenize splits the text into lowercase terms, made of letters and digits.
+func tokenize(text string) []string {
+	terms := strings.FieldsFunc(text, func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsDigit(r)
+	})
+	for i := range terms {
+		terms[i] = strings.ToLower(terms[i])
+	}
+	return terms
+}
+
+// textQuery is a parsed full-text search query, matched against the terms of a text.
+type textQuery interface {
+	matches(terms []string) bool
+}
+
+type textQueryTerm struct {
+	term   string
+	prefix bool
+}
+
+func (q *textQueryTerm) matches(terms []string) bool {
+	for _, term := range terms {
+		if term == q.term || q.prefix && strings.HasPrefix(term, q.term) {
+			return true
+		}
+	}
+	return false
+}
+
+type textQueryPhrase struct {
+	terms []string
+}
+
+func (q *textQueryPhrase) matches(terms []string) bool {
+	for i := 0; i+len(q.terms) <= len(terms); i++ {
+		found := true
+		for j := range q.terms {
+			if terms[i+j] != q.terms[j] {
+				found = false
+				break
+			}
+		}
+		if found {
+			return true
+		}
+	}
+	return false
+}
+
+type textQueryAnd struct {
+	queries []textQuery
+}
+
+func (q *textQueryAnd) matches(terms []string) bool {
+	for _, query := range q.queries {
+		if !query.matches(terms) {
+			return false
+		}
+	}
+	return true
+}
+
+type textQueryOr struct {
+	queries []textQuery
+}
+
+func (q *textQueryOr) matches(terms []string) bool {
+	for _, query := range q.queries {
+		if query.matches(terms) {
+			return true
+		}
+	}
+	return false
+}
+
+type textQueryNot struct {
+	query textQuery
+}
+
+func (q *textQueryNot) matches(terms []string) bool {
+	return !q.query.matches(terms)
+}
+
+// parseTextQuery parses a query made of words, "quoted phrases" and word* prefixes,
+// combined using AND, OR, NOT and parentheses. Words next to each other are implicitly combined using AND.
+func parseTextQuery(query string) (textQuery, error) {
+	tokens, err := lexTextQuery(query)
+	if err != nil {
+		return nil, err
+	}
+	if len(tokens) == 0 {
+		return nil, fmt.Errorf("empty text query")
+	}
+
+	p := &textQueryParser{tokens: tokens}
+	out, err := p.parseOr()
+	if err != nil {
+		return nil, err
+	}
+	if p.pos < len(p.tokens) {
+		return nil, fmt.Errorf("unexpected %s in text query", p.tokens[p.pos])
+	}
+	return out, nil
+}
+
+// lexTextQuery splits the query into parentheses, quoted phrases (with their quotes) and words.
+func lexTextQuery(query string) ([]string, error) {
+	var tokens []string
+	runes := []rune(query)
+	for i := 0; i < len(runes); {
+		switch {
+		case unicode.IsSpace(runes[i]):
+			i++
+		case runes[i] == '(' || runes[i] == ')':
+			tokens = append(tokens, string(runes[i]))
+			i++
+		case runes[i] == '"':
+			end := i + 1
+			for end < len(runes) && runes[end] != '"' {
+				end++
+			}
+			if end == len(runes) {
+				return nil, fmt.Errorf("unterminated phrase in text query")
+			}
+			tokens = append(tokens, string(runes[i:end+1]))
+			i = end + 1
+		default:
+			end := i
+			for end < len(runes) && !unicode.IsSpace(runes[end]) && runes[end] != '(' && runes[end] != ')' && runes[end] != '"' {
+				end++
+			}
+			tokens = append(tokens, string(runes[i:end]))
+			i = end
+		}
+	}
+	return tokens, nil
+}
+
+type textQueryParser struct {
+	tokens []string
+	pos    int
+}
+
+func (p *textQueryParser) peek() string {
+	if p.pos < len(p.tokens) {
+		return p.tokens[p.pos]
+	}
+	return ""
+}
+
+func (p *textQueryParser) parseOr() (textQuery, error) {
+	first, err := p.parseAnd()
+	if err != nil {
+		return nil, err
+	}
+	queries := []textQuery{first}
+	for p.peek() == "OR" {
+		p.pos++
+		next, err := p.parseAnd()
+		if err != nil {
+			return nil, err
+		}
+		queries = append(queries, next)
+	}
+	if len(queries) == 1 {
+		return first, nil
+	}
+	return &textQueryOr{queries: queries}, nil
+}
+
+func (p *textQueryParser) parseAnd() (textQuery, error) {
+	first, err := p.parseNot()
+	if err != nil {
+		return nil, err
+	}
+	queries := []textQuery{first}
+	for {
+		next := p.peek()
+		if next == "AND" {
+			p.pos++
+		} else if next == "" || next == "OR" || next == ")" {
+			break
+		}
+		query, err := p.parseNot()
+		if err != nil {
+			return nil, err
+		}
+		queries = append(queries, query)
+	}
+	if len(queries) == 1 {
+		return first, nil
+	}
+	return &textQueryAnd{queries: queries}, nil
+}
+
+func (p *textQueryParser) parseNot() (textQuery, error) {
+	if p.peek() == "NOT" {
+		p.pos++
+		query, err := p.parseNot()
+		if err != nil {
+			return nil, err
+		}
+		return &textQueryNot{query: query}, nil
+	}
+	return p.parsePrimary()
+}
+
+func (p *textQueryParser) parsePrimary() (textQuery, error) {
+	token := p.peek()
+	switch {
+	case token == "":
+		return nil, fmt.Errorf("unexpected end of text query")
+	case token == "AND" || token == "OR" || token == ")":
+		return nil, fmt.Errorf("unexpected %s in text query", token)
+	case token == "(":
+		p.pos++
+		query, err := p.parseOr()
+		if err != nil {
+			return nil, err
+		}
+		if p.peek() != ")" {
+			return nil, fmt.Errorf("unclosed parenthesis in text query")
+		}
+		p.pos++
+		return query, nil
+	case strings.HasPrefix(token, `"`):
+		p.pos++
+		return makeTextQueryPhrase(tokenize(token))
+	default:
+		p.pos++
+		prefix := strings.HasSuffix(token, "*")
+		terms := tokenize(token)
+		if prefix && len(terms) == 1 {
+			return &textQueryTerm{term: terms[0], prefix: true}, nil
+		}
+		return makeTextQueryPhrase(terms)
+	}
+}
+
+// makeTextQueryPhrase returns a query matching the terms next to each other,
+// as words like "user-agent" are made of multiple terms too.
+func makeTextQueryPhrase(terms []string) (textQuery, error) {
+	switch len(terms) {
+	case 0:
+		return nil, fmt.Errorf("text query phrase without any terms")
+	case 1:
+		return &textQueryTerm{term: terms[0]}, nil
+	default:
+		return &textQueryPhrase{terms: terms}, nil
+	}
+}
@@ -1,13 +1,20 @@
 package functions
 
 import (
+	"context"
+	"crypto/md5"
+	"crypto/sha256"
 	"encoding/base32"
+	"encoding/base64"
+	"encoding/binary"
+	"encoding/hex"
+	"encoding/json"
 	"fmt"
 	"log"
 	"math"
 	"math/rand"
+	"net"
 	"reflect"
-	"regexp"
 	"strconv"
 	"strings"
 	"time"
@@ -20,11 +27,18 @@ import (
 )
 
 func execute(fun execution.Function, args ...Value) (Value, error) {
+	return executeWithContext(context.Background(), fun, args...)
+}
+
+func executeWithContext(ctx context.Context, fun execution.Function, args ...Value) (Value, error) {
 	err := fun.Validator.Validate(args...)
 	if err != nil {
 		return ZeroValue(), err
 	}
 
+	if fun.ContextLogic != nil {
+		return fun.ContextLogic(ctx, args...)
+	}
 	return fun.Logic(args...)
 }
 
@@ -35,7 +49,7 @@ var FuncInt = execution.Function{
 	ArgumentNames: [][]string{
 		{"x"},
 	},
-	Description: docs.Text("Converts x to an Integer."),
+	Description: docs.Text("Converts x to an Integer. Floats and Decimals are truncated."),
 	Validator: All(
 		ExactlyNArgs(1),
 		Arg(0,
@@ -43,6 +57,7 @@ var FuncInt = execution.Function{
 				TypeOf(ZeroBool()),
 				TypeOf(ZeroInt()),
 				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
 				TypeOf(ZeroString()),
 			),
 		),
@@ -59,13 +74,19 @@ var FuncInt = execution.Function{
 			return arg, nil
 		case TypeFloat:
 			return MakeInt(int(arg.AsFloat())), nil
+		case TypeDecimal:
+			integer, err := arg.AsDecimal().Int()
+			if err != nil {
+				return ZeroValue(), err
+			}
+			return MakeInt(integer), nil
 		case TypeString:
 			number, err := strconv.Atoi(arg.AsString())
 			if err != nil {
 				return ZeroValue(), err
 			}
 			return MakeInt(number), nil
-		case TypeNull, TypePhantom, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -85,6 +106,7 @@ var FuncFloat = execution.Function{
 				TypeOf(ZeroBool()),
 				TypeOf(ZeroInt()),
 				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
 				TypeOf(ZeroString()),
 			),
 		),
@@ -96,6 +118,8 @@ var FuncFloat = execution.Function{
 			return MakeFloat(float64(arg.AsInt())), nil
 		case TypeFloat:
 			return arg, nil
+		case TypeDecimal:
+			return MakeFloat(arg.AsDecimal().Float()), nil
 		case TypeBool:
 			if arg.AsBool() {
 				return MakeFloat(1.0), nil
@@ -107,7 +131,174 @@ var FuncFloat = execution.Function{
 				return ZeroValue(), err
 			}
 			return MakeFloat(number), nil
-		case TypeNull, TypePhantom, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
+		}
+		panic("unreachable")
+	},
+}
+
+var FuncDecimal = execution.Function{
+	Name: "decimal",
+	ArgumentNames: [][]string{
+		{"x"},
+		{"x", "precision", "scale"},
+	},
+	Description: docs.List(
+		docs.Text("Provided one argument, converts x to a Decimal exactly. A Float becomes the shortest Decimal which converts back to it."),
+		docs.Text(fmt.Sprintf("Provided three arguments, rounds x to scale digits after the decimal point, failing if it has more than precision digits in total. The precision can be at most %d.", DecimalMaxPrecision)),
+	),
+	Validator: All(
+		OneOf(
+			ExactlyNArgs(1),
+			ExactlyNArgs(3),
+		),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroInt()),
+				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
+				TypeOf(ZeroString()),
+			),
+		),
+		// The precision and scale may have been converted to the type of x.
+		IfArgPresent(1, Arg(1, SingleOneOf(TypeOf(ZeroInt()), TypeOf(ZeroFloat()), TypeOf(ZeroDecimal())))),
+		IfArgPresent(2, Arg(2, SingleOneOf(TypeOf(ZeroInt()), TypeOf(ZeroFloat()), TypeOf(ZeroDecimal())))),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		var out Decimal
+		arg := args[0]
+		switch arg.GetType() {
+		case TypeInt:
+			out = DecimalFromInt(arg.AsInt())
+		case TypeFloat:
+			d, err := DecimalFromFloat(arg.AsFloat())
+			if err != nil {
+				return ZeroValue(), err
+			}
+			out = d
+		case TypeDecimal:
+			out = arg.AsDecimal()
+		case TypeString:
+			d, err := ParseDecimal(arg.AsString())
+			if err != nil {
+				return ZeroValue(), err
+			}
+			out = d
+		case TypeNull, TypePhantom, TypeBool, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
+		}
+
+		if len(args) == 1 {
+			return MakeDecimal(out), nil
+		}
+
+		precision, err := getIntegralArgument(args[1])
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "invalid precision")
+		}
+		scale, err := getIntegralArgument(args[2])
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "invalid scale")
+		}
+		if precision < 1 || precision > DecimalMaxPrecision {
+			return ZeroValue(), fmt.Errorf("precision must be between 1 and %d, got %d", DecimalMaxPrecision, precision)
+		}
+		if scale < 0 || scale > precision {
+			return ZeroValue(), fmt.Errorf("scale must be between 0 and the precision %d, got %d", precision, scale)
+		}
+
+		out = out.Rescale(scale)
+		if out.Precision() > precision {
+			return ZeroValue(), fmt.Errorf("decimal %s doesn't fit in precision %d", out, precision)
+		}
+		return MakeDecimal(out), nil
+	},
+}
+
+var FuncString = execution.Function{
+	Name: "string",
+	ArgumentNames: [][]string{
+		{"x"},
+	},
+	Description: docs.Text("Converts x to a String. Times are formatted using RFC3339, UUIDs in their canonical form, and Bytes are read as UTF-8 text."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroBool()),
+				TypeOf(ZeroInt()),
+				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
+				TypeOf(ZeroString()),
+				TypeOf(ZeroTime()),
+				TypeOf(ZeroDuration()),
+				TypeOf(ZeroUUID()),
+				TypeOf(ZeroIP()),
+				TypeOf(ZeroBytes()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		arg := args[0]
+		switch arg.GetType() {
+		case TypeBool:
+			return MakeString(strconv.FormatBool(arg.AsBool())), nil
+		case TypeInt:
+			return MakeString(strconv.Itoa(arg.AsInt())), nil
+		case TypeFloat:
+			return MakeString(strconv.FormatFloat(arg.AsFloat(), 'f', -1, 64)), nil
+		case TypeDecimal:
+			return MakeString(arg.AsDecimal().String()), nil
+		case TypeString:
+			return arg, nil
+		case TypeTime:
+			return MakeString(arg.AsTime().Format(time.RFC3339Nano)), nil
+		case TypeDuration:
+			return MakeString(arg.AsDuration().String()), nil
+		case TypeUUID:
+			return MakeString(arg.AsUUID().String()), nil
+		case TypeIP:
+			return MakeString(arg.AsIP().String()), nil
+		case TypeBytes:
+			return MakeString(string(arg.AsBytes())), nil
+		case TypeNull, TypePhantom, TypeTuple, TypeObject:
+			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
+		}
+		panic("unreachable")
+	},
+}
+
+var FuncBool = execution.Function{
+	Name: "bool",
+	ArgumentNames: [][]string{
+		{"x"},
+	},
+	Description: docs.Text("Converts x to a Boolean. Integers are true if they are non-zero."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroBool()),
+				TypeOf(ZeroInt()),
+				TypeOf(ZeroString()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		arg := args[0]
+		switch arg.GetType() {
+		case TypeBool:
+			return arg, nil
+		case TypeInt:
+			return MakeBool(arg.AsInt() != 0), nil
+		case TypeString:
+			b, err := strconv.ParseBool(arg.AsString())
+			if err != nil {
+				return ZeroValue(), err
+			}
+			return MakeBool(b), nil
+		case TypeNull, TypePhantom, TypeFloat, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -126,6 +317,7 @@ var FuncNegate = execution.Function{
 			SingleOneOf(
 				TypeOf(ZeroInt()),
 				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
 			),
 		),
 	),
@@ -136,7 +328,9 @@ var FuncNegate = execution.Function{
 			return MakeInt(-1 * arg.AsInt()), nil
 		case TypeFloat:
 			return MakeFloat(-1.0 * arg.AsFloat()), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeDecimal:
+			return MakeDecimal(arg.AsDecimal().Neg()), nil
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -155,6 +349,7 @@ var FuncAbs = execution.Function{
 			SingleOneOf(
 				TypeOf(ZeroInt()),
 				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
 			),
 		),
 	),
@@ -173,7 +368,9 @@ var FuncAbs = execution.Function{
 				return MakeFloat(-1.0 * asFloat), nil
 			}
 			return arg, nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeDecimal:
+			return MakeDecimal(arg.AsDecimal().Abs()), nil
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -210,7 +407,7 @@ var FuncSqrt = execution.Function{
 				return ZeroValue(), fmt.Errorf("can't take square root of value %v", arg)
 			}
 			return MakeFloat(math.Sqrt(asFloat)), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -239,7 +436,7 @@ var FuncFloor = execution.Function{
 			return MakeFloat(float64(arg.AsInt())), nil
 		case TypeFloat:
 			return MakeFloat(math.Floor(arg.AsFloat())), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -268,7 +465,7 @@ var FuncCeil = execution.Function{
 			return MakeFloat(float64(arg.AsInt())), nil
 		case TypeFloat:
 			return MakeFloat(math.Ceil(arg.AsFloat())), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(arg).String())
 		}
 		panic("unreachable")
@@ -305,7 +502,7 @@ var FuncLog2 = execution.Function{
 				return ZeroValue(), fmt.Errorf("can't take log of value %v", arg)
 			}
 			return MakeFloat(math.Log2(asFloat)), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -342,7 +539,7 @@ var FuncLn = execution.Function{
 				return ZeroValue(), fmt.Errorf("can't take ln of value %v", arg)
 			}
 			return MakeFloat(math.Log1p(asFloat) - 1), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -361,6 +558,7 @@ var FuncLeast = execution.Function{
 		OneOf(
 			AllArgs(TypeOf(ZeroInt())),
 			AllArgs(TypeOf(ZeroFloat())),
+			AllArgs(TypeOf(ZeroDecimal())),
 		),
 	),
 	Logic: func(args ...Value) (Value, error) {
@@ -379,7 +577,16 @@ var FuncLeast = execution.Function{
 			}
 
 			return MakeFloat(min), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeDecimal:
+			min := args[0].AsDecimal()
+			for _, arg := range args[1:] {
+				if arg.AsDecimal().Cmp(min) < 0 {
+					min = arg.AsDecimal()
+				}
+			}
+
+			return MakeDecimal(min), nil
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -397,6 +604,7 @@ var FuncGreatest = execution.Function{
 		OneOf(
 			AllArgs(TypeOf(ZeroInt())),
 			AllArgs(TypeOf(ZeroFloat())),
+			AllArgs(TypeOf(ZeroDecimal())),
 		),
 	),
 	Logic: func(args ...Value) (Value, error) {
@@ -415,7 +623,16 @@ var FuncGreatest = execution.Function{
 			}
 
 			return MakeFloat(max), nil
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+		case TypeDecimal:
+			max := args[0].AsDecimal()
+			for _, arg := range args[1:] {
+				if arg.AsDecimal().Cmp(max) > 0 {
+					max = arg.AsDecimal()
+				}
+			}
+
+			return MakeDecimal(max), nil
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -453,6 +670,7 @@ var FuncRandFloat = execution.Function{
 			return MakeFloat(lower + (upper-lower)*rand.Float64()), nil
 		}
 	},
+	Nondeterministic: true,
 }
 
 var FuncRandInt = execution.Function{
@@ -493,6 +711,7 @@ var FuncRandInt = execution.Function{
 			return MakeInt(lower + rand.Intn(upper-lower)), nil
 		}
 	},
+	Nondeterministic: true,
 }
 
 var FuncPower = execution.Function{
@@ -593,7 +812,7 @@ var FuncReverse = execution.Function{
 				out[len(out)-i-1] = el
 			}
 			return MakeTuple(out), nil
-		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject:
+		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -641,9 +860,9 @@ var FuncRegexpFind = execution.Function{
 		AllArgs(TypeOf(ZeroString())),
 	),
 	Logic: func(args ...Value) (Value, error) {
-		re, err := regexp.Compile(args[0].AsString())
+		re, err := compileRegexp(args[0].AsString())
 		if err != nil {
-			return ZeroValue(), fmt.Errorf("couldn't compile regular expression")
+			return ZeroValue(), err
 		}
 
 		match := re.FindString(args[1].AsString())
@@ -666,15 +885,100 @@ var FuncRegexpMatches = execution.Function{
 		AllArgs(TypeOf(ZeroString())),
 	),
 	Logic: func(args ...Value) (Value, error) {
-		re, err := regexp.Compile(args[0].AsString())
+		re, err := compileRegexp(args[0].AsString())
 		if err != nil {
-			return ZeroValue(), fmt.Errorf("couldn't compile regular expression")
+			return ZeroValue(), err
 		}
 
 		return MakeBool(re.MatchString(args[1].AsString())), nil
 	},
 }
 
+var FuncRegexpExtract = execution.Function{
+	Name: "regexp_extract",
+	ArgumentNames: [][]string{
+		{"text", "regexp"},
+		{"text", "regexp", "group"},
+	},
+	Description: docs.List(
+		docs.Text("Provided two arguments, returns the first match of regexp in the text."),
+		docs.Text("Provided three arguments, returns the given capturing group of the first match, counting from 1."),
+		docs.Text("Returns null if there's no match."),
+	),
+	Validator: All(
+		AtLeastNArgs(2),
+		AtMostNArgs(3),
+		Arg(0, TypeOf(ZeroString())),
+		Arg(1, TypeOf(ZeroString())),
+		IfArgPresent(2, Arg(2, TypeOf(ZeroInt()))),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		re, err := compileRegexp(args[1].AsString())
+		if err != nil {
+			return ZeroValue(), err
+		}
+		group := 0
+		if len(args) == 3 {
+			group = args[2].AsInt()
+		}
+		if group < 0 || group > re.NumSubexp() {
+			return ZeroValue(), fmt.Errorf("invalid group %d, the regular expression has %d groups", group, re.NumSubexp())
+		}
+
+		match := re.FindStringSubmatchIndex(args[0].AsString())
+		if match == nil || match[2*group] == -1 {
+			return MakeNull(), nil
+		}
+
+		return MakeString(args[0].AsString()[match[2*group]:match[2*group+1]]), nil
+	},
+}
+
+var FuncRegexpReplace = execution.Function{
+	Name: "regexp_replace",
+	ArgumentNames: [][]string{
+		{"text", "regexp", "replacement"},
+	},
+	Description: docs.Text("Replaces all the matches of regexp in the text with the replacement, in which $1 or ${1} stands for the first capturing group."),
+	Validator: All(
+		ExactlyNArgs(3),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		re, err := compileRegexp(args[1].AsString())
+		if err != nil {
+			return ZeroValue(), err
+		}
+
+		return MakeString(re.ReplaceAllString(args[0].AsString(), args[2].AsString())), nil
+	},
+}
+
+var FuncRegexpSplitToArray = execution.Function{
+	Name: "regexp_split_to_array",
+	ArgumentNames: [][]string{
+		{"text", "regexp"},
+	},
+	Description: docs.Text("Splits the text around the matches of regexp, returning a Tuple of Strings."),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		re, err := compileRegexp(args[1].AsString())
+		if err != nil {
+			return ZeroValue(), err
+		}
+
+		parts := re.Split(args[0].AsString(), -1)
+		out := make([]Value, len(parts))
+		for i := range parts {
+			out[i] = MakeString(parts[i])
+		}
+		return MakeTuple(out), nil
+	},
+}
+
 var FuncReplace = execution.Function{
 	Name: "replace",
 	ArgumentNames: [][]string{
@@ -770,7 +1074,7 @@ var FuncContains = execution.Function{
 				}
 			}
 			return MakeBool(false), nil
-		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject:
+		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -811,7 +1115,7 @@ var FuncIndex = execution.Function{
 				}
 			}
 			return MakeInt(-1), nil
-		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject:
+		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -846,13 +1150,14 @@ var FuncLength = execution.Function{
 	ArgumentNames: [][]string{
 		{"seq"},
 	},
-	Description: docs.Text("Returns the length of the given Tuple or String."),
+	Description: docs.Text("Returns the length of the given Tuple, String or Bytes."),
 	Validator: All(
 		ExactlyNArgs(1),
 		Arg(0,
 			SingleOneOf(
 				TypeOf(ZeroString()),
 				TypeOf(ZeroTuple()),
+				TypeOf(ZeroBytes()),
 			),
 		),
 	),
@@ -863,7 +1168,9 @@ var FuncLength = execution.Function{
 			return MakeInt(len(arg.AsString())), nil
 		case TypeTuple:
 			return MakeInt(len(arg.AsSlice())), nil
-		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject:
+		case TypeBytes:
+			return MakeInt(len(arg.AsBytes())), nil
+		case TypeNull, TypePhantom, TypeInt, TypeFloat, TypeBool, TypeTime, TypeDuration, TypeObject, TypeDecimal, TypeUUID, TypeIP:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -880,6 +1187,7 @@ var FuncNow = execution.Function{
 	Logic: func(args ...Value) (Value, error) {
 		return MakeTime(time.Now()), nil
 	},
+	Nondeterministic: true,
 }
 
 var FuncStringJoin = execution.Function{
@@ -906,111 +1214,338 @@ var FuncStringJoin = execution.Function{
 	},
 }
 
-/* Operators */
-
-var FuncAdd = execution.Function{
-	Name: "+",
+var FuncElementAt = execution.Function{
+	Name: "element_at",
 	ArgumentNames: [][]string{
-		{"left", "right"},
+		{"tuple", "n"},
+		{"object", "key"},
 	},
-	Description: docs.Text("Returns the sum of the two arguments."),
-	Validator: OneOf(
-		All(
-			AtLeastNArgs(1),
-			AtMostNArgs(2),
-			OneOf(
-				AllArgs(TypeOf(ZeroInt())),
-				AllArgs(TypeOf(ZeroFloat())),
-				AllArgs(TypeOf(ZeroDuration())),
+	Description: docs.List(
+		docs.Text("Provided a Tuple, returns its n-th element, counting from 1, or NULL if there is no such element."),
+		docs.Text("Provided an Object, returns the value of the given key, or NULL if there is no such key."),
+	),
+	Validator: All(
+		ExactlyNArgs(2),
+		OneOf(
+			All(
+				Arg(0, TypeOf(ZeroTuple())),
+				Arg(1, TypeOf(ZeroInt())),
+			),
+			All(
+				Arg(0, TypeOf(ZeroObject())),
+				Arg(1, TypeOf(ZeroString())),
 			),
-		),
-		All(
-			ExactlyNArgs(2),
-			Arg(0, TypeOf(ZeroTime())),
-			Arg(1, TypeOf(ZeroDuration())),
 		),
 	),
 	Logic: func(args ...Value) (Value, error) {
-		switch len(args) {
-		case 1:
-			switch args[0].GetType() {
-			case TypeInt, TypeFloat, TypeDuration:
-				return args[0], nil
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject:
-				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+		switch args[0].GetType() {
+		case TypeTuple:
+			tup := args[0].AsSlice()
+			n := args[1].AsInt()
+			if n < 1 || n > len(tup) {
+				return MakeNull(), nil
 			}
-			panic("unreachable")
-
-		case 2:
-			switch args[0].GetType() {
-			case TypeInt:
-				return MakeInt(args[0].AsInt() + args[1].AsInt()), nil
-			case TypeFloat:
-				return MakeFloat(args[0].AsFloat() + args[1].AsFloat()), nil
-			case TypeDuration:
-				return MakeDuration(args[0].AsDuration() + args[1].AsDuration()), nil
-			case TypeTime:
-				return MakeTime(args[0].AsTime().Add(args[1].AsDuration())), nil
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTuple, TypeObject:
-				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+			return tup[n-1], nil
+		case TypeObject:
+			value, ok := args[0].AsMap()[args[1].AsString()]
+			if !ok {
+				return MakeNull(), nil
 			}
-			panic("unreachable")
-
-		default:
-			log.Fatalf("unexpected argument count in function: %v", len(args))
-			panic("unreachable")
+			return value, nil
 		}
+		panic("unreachable")
 	},
 }
 
-var FuncSubtract = execution.Function{
-	Name: "-",
+var FuncArrayLength = execution.Function{
+	Name: "array_length",
 	ArgumentNames: [][]string{
-		{"left", "right"},
+		{"tuple"},
 	},
-	Description: docs.Text("Returns the difference between the two arguments."),
-	Validator: OneOf(
-		All(
-			AtLeastNArgs(1),
-			AtMostNArgs(2),
-			OneOf(
-				AllArgs(TypeOf(ZeroInt())),
-				AllArgs(TypeOf(ZeroFloat())),
-				AllArgs(TypeOf(ZeroDuration())),
-			),
-		),
-		All(
-			ExactlyNArgs(2),
-			Arg(0, TypeOf(ZeroTime())),
-			Arg(1, TypeOf(ZeroDuration())),
-		),
+	Description: docs.Text("Returns the number of elements in the tuple."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0, TypeOf(ZeroTuple())),
 	),
 	Logic: func(args ...Value) (Value, error) {
-		switch len(args) {
-		case 1:
-			switch args[0].GetType() {
-			case TypeInt:
-				return MakeInt(args[0].AsInt() * -1), nil
-			case TypeFloat:
-				return MakeFloat(args[0].AsFloat() * -1), nil
-			case TypeDuration:
-				return MakeDuration(args[0].AsDuration() * -1), nil
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject:
-				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
-			}
-			panic("unreachable")
+		return MakeInt(len(args[0].AsSlice())), nil
+	},
+}
 
-		case 2:
-			switch args[0].GetType() {
-			case TypeInt:
+var FuncArrayContains = execution.Function{
+	Name: "array_contains",
+	ArgumentNames: [][]string{
+		{"tuple", "element"},
+	},
+	Description: docs.Text("Returns whether the tuple contains the given element."),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroTuple())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		for _, el := range args[0].AsSlice() {
+			if AreEqual(el, args[1]) {
+				return MakeBool(true), nil
+			}
+		}
+		return MakeBool(false), nil
+	},
+}
+
+/* JSON functions */
+
+var FuncJSONExtract = execution.Function{
+	Name: "json_extract",
+	ArgumentNames: [][]string{
+		{"json", "path"},
+	},
+	Description: docs.List(
+		docs.Text("Parses the JSON text and returns the value under the given path, or NULL if there is no such value."),
+		docs.Text("The path is either of the form $.field[index], a single object key, or an Integer array index."),
+	),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroString())),
+		Arg(1, SingleOneOf(
+			TypeOf(ZeroString()),
+			TypeOf(ZeroInt()),
+		)),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		value, ok, err := extractJSON(args[0], args[1])
+		if err != nil || !ok {
+			return MakeNull(), err
+		}
+		return TryNormalizeType(value)
+	},
+}
+
+var FuncJSONExtractOperator = execution.Function{
+	Name: "->",
+	ArgumentNames: [][]string{
+		{"json", "path"},
+	},
+	Description: docs.Text("Same as json_extract."),
+	Validator:   FuncJSONExtract.Validator,
+	Logic:       FuncJSONExtract.Logic,
+}
+
+var FuncJSONExtractTextOperator = execution.Function{
+	Name: "->>",
+	ArgumentNames: [][]string{
+		{"json", "path"},
+	},
+	Description: docs.Text("Same as json_extract, but returns the value as text. Strings are returned without quotes, other values as JSON."),
+	Validator:   FuncJSONExtract.Validator,
+	Logic: func(args ...Value) (Value, error) {
+		value, ok, err := extractJSON(args[0], args[1])
+		if err != nil || !ok || value == nil {
+			return MakeNull(), err
+		}
+		if str, ok := value.(string); ok {
+			return MakeString(str), nil
+		}
+
+		data, err := json.Marshal(value)
+		if err != nil {
+			return MakeNull(), errors.Wrap(err, "couldn't encode JSON value")
+		}
+		return MakeString(string(data)), nil
+	},
+}
+
+var FuncJSONArrayLength = execution.Function{
+	Name: "json_array_length",
+	ArgumentNames: [][]string{
+		{"json"},
+	},
+	Description: docs.Text("Parses the JSON text and returns the length of the array in it, or NULL if it isn't an array."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0, TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		value, err := parseJSON(args[0].AsString())
+		if err != nil {
+			return MakeNull(), err
+		}
+		array, ok := value.([]interface{})
+		if !ok {
+			return MakeNull(), nil
+		}
+		return MakeInt(len(array)), nil
+	},
+}
+
+var FuncJSONType = execution.Function{
+	Name: "json_type",
+	ArgumentNames: [][]string{
+		{"json"},
+	},
+	Description: docs.Text("Parses the JSON text and returns the type of the value in it: object, array, string, number, boolean or null."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0, TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		value, err := parseJSON(args[0].AsString())
+		if err != nil {
+			return MakeNull(), err
+		}
+		switch value.(type) {
+		case map[string]interface{}:
+			return MakeString("object"), nil
+		case []interface{}:
+			return MakeString("array"), nil
+		case string:
+			return MakeString("string"), nil
+		case float64:
+			return MakeString("number"), nil
+		case bool:
+			return MakeString("boolean"), nil
+		case nil:
+			return MakeString("null"), nil
+		}
+		panic("unreachable")
+	},
+}
+
+/* Operators */
+
+var FuncAdd = execution.Function{
+	Name: "+",
+	ArgumentNames: [][]string{
+		{"left", "right"},
+	},
+	Description: docs.Text("Returns the sum of the two arguments."),
+	Validator: OneOf(
+		All(
+			AtLeastNArgs(1),
+			AtMostNArgs(2),
+			OneOf(
+				AllArgs(TypeOf(ZeroInt())),
+				AllArgs(TypeOf(ZeroFloat())),
+				AllArgs(TypeOf(ZeroDecimal())),
+				AllArgs(TypeOf(ZeroDuration())),
+			),
+		),
+		All(
+			ExactlyNArgs(2),
+			Arg(0, TypeOf(ZeroTime())),
+			Arg(1, TypeOf(ZeroDuration())),
+		),
+		All(
+			ExactlyNArgs(2),
+			Arg(0, TypeOf(ZeroDuration())),
+			Arg(1, TypeOf(ZeroTime())),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		switch len(args) {
+		case 1:
+			switch args[0].GetType() {
+			case TypeInt, TypeFloat, TypeDecimal, TypeDuration:
+				return args[0], nil
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+			}
+			panic("unreachable")
+
+		case 2:
+			switch args[0].GetType() {
+			case TypeInt:
+				return MakeInt(args[0].AsInt() + args[1].AsInt()), nil
+			case TypeFloat:
+				return MakeFloat(args[0].AsFloat() + args[1].AsFloat()), nil
+			case TypeDecimal:
+				sum, err := args[0].AsDecimal().Add(args[1].AsDecimal())
+				if err != nil {
+					return ZeroValue(), err
+				}
+				return MakeDecimal(sum), nil
+			case TypeDuration:
+				if args[1].GetType() == TypeTime {
+					return MakeTime(args[1].AsTime().Add(args[0].AsDuration())), nil
+				}
+				return MakeDuration(args[0].AsDuration() + args[1].AsDuration()), nil
+			case TypeTime:
+				return MakeTime(args[0].AsTime().Add(args[1].AsDuration())), nil
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+			}
+			panic("unreachable")
+
+		default:
+			log.Fatalf("unexpected argument count in function: %v", len(args))
+			panic("unreachable")
+		}
+	},
+}
+
+var FuncSubtract = execution.Function{
+	Name: "-",
+	ArgumentNames: [][]string{
+		{"left", "right"},
+	},
+	Description: docs.Text("Returns the difference between the two arguments. The difference between two times is a duration."),
+	Validator: OneOf(
+		All(
+			AtLeastNArgs(1),
+			AtMostNArgs(2),
+			OneOf(
+				AllArgs(TypeOf(ZeroInt())),
+				AllArgs(TypeOf(ZeroFloat())),
+				AllArgs(TypeOf(ZeroDecimal())),
+				AllArgs(TypeOf(ZeroDuration())),
+			),
+		),
+		All(
+			ExactlyNArgs(2),
+			Arg(0, TypeOf(ZeroTime())),
+			Arg(1, TypeOf(ZeroDuration())),
+		),
+		All(
+			ExactlyNArgs(2),
+			AllArgs(TypeOf(ZeroTime())),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		switch len(args) {
+		case 1:
+			switch args[0].GetType() {
+			case TypeInt:
+				return MakeInt(args[0].AsInt() * -1), nil
+			case TypeFloat:
+				return MakeFloat(args[0].AsFloat() * -1), nil
+			case TypeDecimal:
+				return MakeDecimal(args[0].AsDecimal().Neg()), nil
+			case TypeDuration:
+				return MakeDuration(args[0].AsDuration() * -1), nil
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+			}
+			panic("unreachable")
+
+		case 2:
+			switch args[0].GetType() {
+			case TypeInt:
 				return MakeInt(args[0].AsInt() - args[1].AsInt()), nil
 			case TypeFloat:
 				return MakeFloat(args[0].AsFloat() - args[1].AsFloat()), nil
+			case TypeDecimal:
+				difference, err := args[0].AsDecimal().Sub(args[1].AsDecimal())
+				if err != nil {
+					return ZeroValue(), err
+				}
+				return MakeDecimal(difference), nil
 			case TypeDuration:
 				return MakeDuration(args[0].AsDuration() - args[1].AsDuration()), nil
 			case TypeTime:
+				if args[1].GetType() == TypeTime {
+					return MakeDuration(args[0].AsTime().Sub(args[1].AsTime())), nil
+				}
 				return MakeTime(args[0].AsTime().Add(-1 * args[1].AsDuration())), nil
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTuple, TypeObject:
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 			}
 			panic("unreachable")
@@ -1033,6 +1568,7 @@ var FuncMultiply = execution.Function{
 		OneOf(
 			AllArgs(TypeOf(ZeroInt())),
 			AllArgs(TypeOf(ZeroFloat())),
+			AllArgs(TypeOf(ZeroDecimal())),
 			All(
 				Arg(0, TypeOf(ZeroDuration())),
 				Arg(1, TypeOf(ZeroInt())),
@@ -1041,25 +1577,44 @@ var FuncMultiply = execution.Function{
 				Arg(0, TypeOf(ZeroDuration())),
 				Arg(1, TypeOf(ZeroFloat())),
 			),
+			All(
+				Arg(0, TypeOf(ZeroInt())),
+				Arg(1, TypeOf(ZeroDuration())),
+			),
+			All(
+				Arg(0, TypeOf(ZeroFloat())),
+				Arg(1, TypeOf(ZeroDuration())),
+			),
 		),
 	),
 	Logic: func(args ...Value) (Value, error) {
+		// Multiplication is commutative, so the duration is always moved to the left.
+		if args[1].GetType() == TypeDuration {
+			args = []Value{args[1], args[0]}
+		}
+
 		switch args[0].GetType() {
 		case TypeInt:
 			return MakeInt(args[0].AsInt() * args[1].AsInt()), nil
 		case TypeFloat:
 			return MakeFloat(args[0].AsFloat() * args[1].AsFloat()), nil
+		case TypeDecimal:
+			product, err := args[0].AsDecimal().Mul(args[1].AsDecimal())
+			if err != nil {
+				return ZeroValue(), err
+			}
+			return MakeDecimal(product), nil
 		case TypeDuration:
 			switch args[1].GetType() {
 			case TypeInt:
 				return MakeDuration(args[0].AsDuration() * time.Duration(args[1].AsInt())), nil
 			case TypeFloat:
 				return MakeDuration(time.Duration(float64(args[0].AsDuration()) * args[1].AsFloat())), nil
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[1]).String())
 			}
 			panic("unreachable")
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -1071,12 +1626,13 @@ var FuncDivide = execution.Function{
 	ArgumentNames: [][]string{
 		{"left", "right"},
 	},
-	Description: docs.Text("Returns the division of the two arguments."),
+	Description: docs.Text("Returns the division of the two arguments. The quotient of Decimals has 4 more digits after the decimal point than the dividend."),
 	Validator: All(
 		ExactlyNArgs(2),
 		OneOf(
 			AllArgs(TypeOf(ZeroInt())),
 			AllArgs(TypeOf(ZeroFloat())),
+			AllArgs(TypeOf(ZeroDecimal())),
 			All(
 				Arg(0, TypeOf(ZeroDuration())),
 				Arg(1, TypeOf(ZeroInt())),
@@ -1100,6 +1656,13 @@ var FuncDivide = execution.Function{
 			return MakeInt(args[0].AsInt() / args[1].AsInt()), nil
 		case TypeFloat:
 			return MakeFloat(args[0].AsFloat() / args[1].AsFloat()), nil
+		case TypeDecimal:
+			left := args[0].AsDecimal()
+			quotient, err := left.Div(args[1].AsDecimal(), left.Scale()+DecimalDivisionScaleIncrement)
+			if err != nil {
+				return ZeroValue(), err
+			}
+			return MakeDecimal(quotient), nil
 		case TypeDuration:
 			switch args[1].GetType() {
 			case TypeInt:
@@ -1109,17 +1672,62 @@ var FuncDivide = execution.Function{
 				return MakeDuration(args[0].AsDuration() / time.Duration(args[1].AsInt())), nil
 
 			case TypeFloat:
-				return MakeDuration(args[0].AsDuration() / time.Duration(args[1].AsFloat())), nil
+				if args[1].AsFloat() == 0 {
+					return ZeroValue(), fmt.Errorf("division by zero")
+				}
+				return MakeDuration(time.Duration(float64(args[0].AsDuration()) / args[1].AsFloat())), nil
 
 			case TypeDuration:
 				return MakeFloat(float64(args[0].AsDuration()) / float64(args[1].AsDuration())), nil
 
-			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject:
+			case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 				log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[1]).String())
 			}
 			panic("unreachable")
 
-		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject:
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
+			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
+		}
+		panic("unreachable")
+	},
+}
+
+var FuncModulo = execution.Function{
+	Name: "%",
+	ArgumentNames: [][]string{
+		{"left", "right"},
+	},
+	Description: docs.Text("Returns the remainder of the division of the two arguments."),
+	Validator: All(
+		ExactlyNArgs(2),
+		OneOf(
+			AllArgs(TypeOf(ZeroInt())),
+			AllArgs(TypeOf(ZeroFloat())),
+			AllArgs(TypeOf(ZeroDecimal())),
+			AllArgs(TypeOf(ZeroDuration())),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		switch args[0].GetType() {
+		case TypeInt:
+			if args[1].AsInt() == 0 {
+				return ZeroValue(), fmt.Errorf("division by zero")
+			}
+			return MakeInt(args[0].AsInt() % args[1].AsInt()), nil
+		case TypeFloat:
+			return MakeFloat(math.Mod(args[0].AsFloat(), args[1].AsFloat())), nil
+		case TypeDecimal:
+			remainder, err := args[0].AsDecimal().Mod(args[1].AsDecimal())
+			if err != nil {
+				return ZeroValue(), err
+			}
+			return MakeDecimal(remainder), nil
+		case TypeDuration:
+			if args[1].AsDuration() == 0 {
+				return ZeroValue(), fmt.Errorf("division by zero")
+			}
+			return MakeDuration(args[0].AsDuration() % args[1].AsDuration()), nil
+		case TypeNull, TypePhantom, TypeBool, TypeString, TypeTime, TypeTuple, TypeObject, TypeUUID, TypeIP, TypeBytes:
 			log.Fatalf("unexpected type in function: %v", reflect.TypeOf(args[0]).String())
 		}
 		panic("unreachable")
@@ -1134,7 +1742,7 @@ var FuncDuration = execution.Function{
 	},
 	Description: docs.List(
 		docs.Text("Provided one argument, parses the duration as in https://golang.org/pkg/time/#ParseDuration"),
-		docs.Text("Provided two arguments, returns the duration equal to count of unit."),
+		docs.Text("Provided two arguments, returns the duration equal to count of unit. The count may be fractional."),
 	),
 	Validator: OneOf(
 		All(
@@ -1143,7 +1751,12 @@ var FuncDuration = execution.Function{
 		),
 		All(
 			ExactlyNArgs(2),
-			Arg(0, TypeOf(ZeroInt())),
+			Arg(0,
+				SingleOneOf(
+					TypeOf(ZeroInt()),
+					TypeOf(ZeroFloat()),
+				),
+			),
 			Arg(1,
 				SingleAll(
 					TypeOf(ZeroString()),
@@ -1155,7 +1768,7 @@ var FuncDuration = execution.Function{
 						MakeString("minute"),
 						MakeString("hour"),
 						MakeString("day"),
-						MakeString("month"),
+						MakeString("week"),
 					),
 				),
 			),
@@ -1171,27 +1784,34 @@ var FuncDuration = execution.Function{
 			return MakeDuration(dur), nil
 
 		case 2:
-			count := time.Duration(args[0].AsInt())
+			var unit time.Duration
 			switch args[1].AsString() {
 			case "nanosecond":
-				return MakeDuration(count), nil
+				unit = time.Nanosecond
 			case "microsecond":
-				return MakeDuration(count * time.Microsecond), nil
+				unit = time.Microsecond
 			case "millisecond":
-				return MakeDuration(count * time.Millisecond), nil
+				unit = time.Millisecond
 			case "second":
-				return MakeDuration(count * time.Second), nil
+				unit = time.Second
 			case "minute":
-				return MakeDuration(count * time.Minute), nil
+				unit = time.Minute
 			case "hour":
-				return MakeDuration(count * time.Hour), nil
+				unit = time.Hour
 			case "day":
-				return MakeDuration(count * time.Hour * 24), nil
+				unit = time.Hour * 24
+			case "week":
+				unit = time.Hour * 24 * 7
 			default:
 				log.Fatalf("unexpected value in function: %v", args[1])
 				panic("unreachable")
 			}
 
+			if args[0].GetType() == TypeFloat {
+				return MakeDuration(time.Duration(args[0].AsFloat() * float64(unit))), nil
+			}
+			return MakeDuration(time.Duration(args[0].AsInt()) * unit), nil
+
 		default:
 			log.Fatalf("unexpected argument count in function: %v", len(args))
 			panic("unreachable")
@@ -1209,13 +1829,14 @@ var FuncCoalesce = execution.Function{
 	),
 	Validator: All(
 		AtLeastNArgs(1),
+		SameTypeArgs(),
 	),
 	Logic: func(args ...Value) (Value, error) {
 		for i := range args {
 			switch args[i].GetType() {
 			case TypeNull:
 				continue
-			case TypePhantom, TypeInt, TypeFloat, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject:
+			case TypePhantom, TypeInt, TypeFloat, TypeBool, TypeString, TypeTime, TypeDuration, TypeTuple, TypeObject, TypeDecimal, TypeUUID, TypeIP, TypeBytes:
 				return args[i], nil
 			}
 			panic("unreachable")
@@ -1267,39 +1888,774 @@ var FuncParseTime = execution.Function{
 	},
 }
 
-var FuncDecodeBase32 = execution.Function{
-	Name: "decode_base32",
+var FuncAtTimeZone = execution.Function{
+	Name: "at_time_zone",
 	ArgumentNames: [][]string{
-		{"text"},
+		{"time", "zone"},
 	},
 	Description: docs.List(
-		docs.Text("Decodes the text from Base32. Especially useful when using a datasource which stores text as byte fields."),
+		docs.Text("Returns the same instant of time in the given time zone, which is either an IANA name like 'Europe/Warsaw', or an offset from UTC like '+02:00'."),
+		docs.Text("The time is shown in this time zone, and date_trunc and date_part use it instead of the session time zone."),
+		docs.Text("Usually written as 'time AT TIME ZONE zone'."),
 	),
 	Validator: All(
-		ExactlyNArgs(1),
-		AllArgs(TypeOf(ZeroString())),
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroTime())),
+		Arg(1, TypeOf(ZeroString())),
 	),
 	Logic: func(args ...Value) (Value, error) {
-		data, err := base32.StdEncoding.DecodeString(args[0].AsString())
+		loc, err := LoadTimeZone(args[1].AsString())
 		if err != nil {
-			return ZeroValue(), errors.Wrap(err, "couldn't decode base32")
+			return ZeroValue(), err
 		}
 
-		return MakeString(string(data)), nil
+		return MakeTime(args[0].AsTime().In(loc)), nil
 	},
 }
 
-/* Auxiliary functions */
-func intMin(x, y int) int {
-	if x <= y {
-		return x
-	}
-	return y
+var FuncDateTrunc = execution.Function{
+	Name: "date_trunc",
+	ArgumentNames: [][]string{
+		{"unit", "time"},
+	},
+	Description: docs.List(
+		docs.Text("Truncates the time to the given unit, one of microsecond, millisecond, second, minute, hour, day, week (starting on Monday), month, quarter or year."),
+		docs.Text("The time is truncated in its time zone, or in the session time zone if it doesn't have one."),
+	),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroString())),
+		Arg(1, TypeOf(ZeroTime())),
+	),
+	ContextLogic: func(ctx context.Context, args ...Value) (Value, error) {
+		t := localTime(ctx, args[1])
+		loc := t.Location()
+		year, month, day := t.Date()
+		hour, minute, second := t.Clock()
+
+		var out time.Time
+		switch strings.ToLower(args[0].AsString()) {
+		case "microsecond":
+			out = time.Date(year, month, day, hour, minute, second, t.Nanosecond()/1000*1000, loc)
+		case "millisecond":
+			out = time.Date(year, month, day, hour, minute, second, t.Nanosecond()/1000000*1000000, loc)
+		case "second":
+			out = time.Date(year, month, day, hour, minute, second, 0, loc)
+		case "minute":
+			out = time.Date(year, month, day, hour, minute, 0, 0, loc)
+		case "hour":
+			out = time.Date(year, month, day, hour, 0, 0, 0, loc)
+		case "day":
+			out = time.Date(year, month, day, 0, 0, 0, 0, loc)
+		case "week":
+			out = time.Date(year, month, day-(int(t.Weekday())+6)%7, 0, 0, 0, 0, loc)
+		case "month":
+			out = time.Date(year, month, 1, 0, 0, 0, 0, loc)
+		case "quarter":
+			out = time.Date(year, month-(month-1)%3, 1, 0, 0, 0, 0, loc)
+		case "year":
+			out = time.Date(year, 1, 1, 0, 0, 0, 0, loc)
+		default:
+			return ZeroValue(), fmt.Errorf("invalid unit %s, available units: microsecond, millisecond, second, minute, hour, day, week, month, quarter, year", args[0].AsString())
+		}
+
+		if args[1].GetTimeZone() == "" {
+			out = out.UTC()
+		}
+		return MakeTime(out), nil
+	},
 }
 
-func intMax(x, y int) int {
-	if x <= y {
-		return y
-	}
-	return x
+var FuncDatePart = execution.Function{
+	Name: "date_part",
+	ArgumentNames: [][]string{
+		{"field", "time"},
+	},
+	Description: docs.List(
+		docs.Text("Returns the given field of the time as an Int, one of year, quarter, month, week (of the ISO 8601 year), day, dow (day of the week, 0 being Sunday), isodow (1 being Monday), doy (day of the year), hour, minute, second, millisecond and microsecond (including the seconds), epoch (seconds since 1970-01-01 UTC) or timezone (offset from UTC in seconds)."),
+		docs.Text("The field is taken in the time zone of the time, or in the session time zone if it doesn't have one."),
+		docs.Text("Usually written as 'EXTRACT(field FROM time)'."),
+	),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroString())),
+		Arg(1, TypeOf(ZeroTime())),
+	),
+	ContextLogic: func(ctx context.Context, args ...Value) (Value, error) {
+		t := localTime(ctx, args[1])
+
+		switch strings.ToLower(args[0].AsString()) {
+		case "year":
+			return MakeInt(t.Year()), nil
+		case "quarter":
+			return MakeInt((int(t.Month())-1)/3 + 1), nil
+		case "month":
+			return MakeInt(int(t.Month())), nil
+		case "week":
+			_, week := t.ISOWeek()
+			return MakeInt(week), nil
+		case "day":
+			return MakeInt(t.Day()), nil
+		case "dow":
+			return MakeInt(int(t.Weekday())), nil
+		case "isodow":
+			return MakeInt((int(t.Weekday())+6)%7 + 1), nil
+		case "doy":
+			return MakeInt(t.YearDay()), nil
+		case "hour":
+			return MakeInt(t.Hour()), nil
+		case "minute":
+			return MakeInt(t.Minute()), nil
+		case "second":
+			return MakeInt(t.Second()), nil
+		case "millisecond":
+			return MakeInt(t.Second()*1000 + t.Nanosecond()/1000000), nil
+		case "microsecond":
+			return MakeInt(t.Second()*1000000 + t.Nanosecond()/1000), nil
+		case "epoch":
+			return MakeInt(int(t.Unix())), nil
+		case "timezone":
+			_, offset := t.Zone()
+			return MakeInt(offset), nil
+		default:
+			return ZeroValue(), fmt.Errorf("invalid field %s, available fields: year, quarter, month, week, day, dow, isodow, doy, hour, minute, second, millisecond, microsecond, epoch, timezone", args[0].AsString())
+		}
+	},
+}
+
+var FuncDecodeBase32 = execution.Function{
+	Name: "decode_base32",
+	ArgumentNames: [][]string{
+		{"text"},
+	},
+	Description: docs.List(
+		docs.Text("Decodes the text from Base32."),
+	),
+	Validator: All(
+		ExactlyNArgs(1),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		data, err := base32.StdEncoding.DecodeString(args[0].AsString())
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "couldn't decode base32")
+		}
+
+		return MakeString(string(data)), nil
+	},
+}
+
+var FuncEncode = execution.Function{
+	Name: "encode",
+	ArgumentNames: [][]string{
+		{"data", "format"},
+	},
+	Description: docs.Text("Encodes the Bytes as text in the given format, one of hex, base64 or base32."),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0, TypeOf(ZeroBytes())),
+		Arg(1, TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		data := args[0].AsBytes()
+		switch strings.ToLower(args[1].AsString()) {
+		case "hex":
+			return MakeString(hex.EncodeToString(data)), nil
+		case "base64":
+			return MakeString(base64.StdEncoding.EncodeToString(data)), nil
+		case "base32":
+			return MakeString(base32.StdEncoding.EncodeToString(data)), nil
+		default:
+			return ZeroValue(), fmt.Errorf("invalid format %s, available formats: hex, base64, base32", args[1].AsString())
+		}
+	},
+}
+
+var FuncDecode = execution.Function{
+	Name: "decode",
+	ArgumentNames: [][]string{
+		{"text", "format"},
+	},
+	Description: docs.Text("Decodes Bytes from the text in the given format, one of hex, base64 or base32."),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		var data []byte
+		var err error
+		switch strings.ToLower(args[1].AsString()) {
+		case "hex":
+			data, err = hex.DecodeString(args[0].AsString())
+		case "base64":
+			data, err = base64.StdEncoding.DecodeString(args[0].AsString())
+		case "base32":
+			data, err = base32.StdEncoding.DecodeString(args[0].AsString())
+		default:
+			return ZeroValue(), fmt.Errorf("invalid format %s, available formats: hex, base64, base32", args[1].AsString())
+		}
+		if err != nil {
+			return ZeroValue(), errors.Wrapf(err, "couldn't decode %s", args[1].AsString())
+		}
+
+		return MakeBytes(data), nil
+	},
+}
+
+var FuncMD5 = execution.Function{
+	Name: "md5",
+	ArgumentNames: [][]string{
+		{"data"},
+	},
+	Description: docs.Text("Returns the MD5 checksum of the String or Bytes, as hex text."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroString()),
+				TypeOf(ZeroBytes()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		sum := md5.Sum(getBinaryArgument(args[0]))
+		return MakeString(hex.EncodeToString(sum[:])), nil
+	},
+}
+
+var FuncSHA256 = execution.Function{
+	Name: "sha256",
+	ArgumentNames: [][]string{
+		{"data"},
+	},
+	Description: docs.Text("Returns the SHA-256 checksum of the String or Bytes, as hex text."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroString()),
+				TypeOf(ZeroBytes()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		sum := sha256.Sum256(getBinaryArgument(args[0]))
+		return MakeString(hex.EncodeToString(sum[:])), nil
+	},
+}
+
+var FuncGenUUID = execution.Function{
+	Name: "gen_uuid",
+	ArgumentNames: [][]string{
+		{},
+	},
+	Description: docs.Text("Returns a random (version 4) UUID."),
+	Validator:   ExactlyNArgs(0),
+	Logic: func(args ...Value) (Value, error) {
+		u, err := NewRandomUUID()
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "couldn't generate uuid")
+		}
+
+		return MakeUUID(u), nil
+	},
+	Nondeterministic: true,
+}
+
+var FuncUUIDToString = execution.Function{
+	Name: "uuid_to_string",
+	ArgumentNames: [][]string{
+		{"uuid"},
+	},
+	Description: docs.Text("Returns the canonical form of the UUID, like 123e4567-e89b-12d3-a456-426614174000."),
+	Validator: All(
+		ExactlyNArgs(1),
+		AllArgs(TypeOf(ZeroUUID())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		return MakeString(args[0].AsUUID().String()), nil
+	},
+}
+
+var FuncStringToUUID = execution.Function{
+	Name: "string_to_uuid",
+	ArgumentNames: [][]string{
+		{"text"},
+	},
+	Description: docs.Text("Parses a UUID in the canonical form, like 123e4567-e89b-12d3-a456-426614174000."),
+	Validator: All(
+		ExactlyNArgs(1),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		u, err := ParseUUID(args[0].AsString())
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "couldn't parse uuid")
+		}
+
+		return MakeUUID(u), nil
+	},
+}
+
+var FuncIP = execution.Function{
+	Name: "ip",
+	ArgumentNames: [][]string{
+		{"text"},
+	},
+	Description: docs.Text("Parses an IPv4 or IPv6 address, like 192.168.0.1 or 2001:db8::1. IPs are sorted by their numeric value, IPv4 addresses before IPv6 ones."),
+	Validator: All(
+		ExactlyNArgs(1),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		ip, err := parseIP(args[0])
+		if err != nil {
+			return ZeroValue(), err
+		}
+
+		return MakeIP(ip), nil
+	},
+}
+
+var FuncInetAton = execution.Function{
+	Name: "inet_aton",
+	ArgumentNames: [][]string{
+		{"ip"},
+	},
+	Description: docs.Text("Returns the IPv4 address as an Int, like 167772161 for 10.0.0.1."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroString()),
+				TypeOf(ZeroIP()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		ip, err := parseIP(args[0])
+		if err != nil {
+			return ZeroValue(), err
+		}
+		ipv4 := ip.To4()
+		if ipv4 == nil {
+			return ZeroValue(), fmt.Errorf("%s isn't an IPv4 address", ip)
+		}
+
+		return MakeInt(int(binary.BigEndian.Uint32(ipv4))), nil
+	},
+}
+
+var FuncInetNtoa = execution.Function{
+	Name: "inet_ntoa",
+	ArgumentNames: [][]string{
+		{"n"},
+	},
+	Description: docs.Text("Returns the IPv4 address given as an Int in its dotted form, like 10.0.0.1 for 167772161."),
+	Validator: All(
+		ExactlyNArgs(1),
+		AllArgs(TypeOf(ZeroInt())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		n := args[0].AsInt()
+		if n < 0 || int64(n) > math.MaxUint32 {
+			return ZeroValue(), fmt.Errorf("%d is out of the IPv4 address range", n)
+		}
+
+		ip := make(net.IP, net.IPv4len)
+		binary.BigEndian.PutUint32(ip, uint32(n))
+		return MakeString(ip.String()), nil
+	},
+}
+
+var FuncIPInSubnet = execution.Function{
+	Name: "ip_in_subnet",
+	ArgumentNames: [][]string{
+		{"ip", "subnet"},
+	},
+	Description: docs.Text("Checks whether the IP address is in the subnet given in CIDR notation, like 10.0.0.0/8."),
+	Validator: All(
+		ExactlyNArgs(2),
+		Arg(0,
+			SingleOneOf(
+				TypeOf(ZeroString()),
+				TypeOf(ZeroIP()),
+			),
+		),
+		Arg(1, TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		ip, err := parseIP(args[0])
+		if err != nil {
+			return ZeroValue(), err
+		}
+		_, subnet, err := net.ParseCIDR(args[1].AsString())
+		if err != nil {
+			return ZeroValue(), errors.Wrap(err, "couldn't parse subnet")
+		}
+
+		return MakeBool(subnet.Contains(ip)), nil
+	},
+}
+
+var FuncSTPoint = execution.Function{
+	Name: "st_point",
+	ArgumentNames: [][]string{
+		{"longitude", "latitude"},
+	},
+	Description: docs.Text("Returns the point with the given coordinates in degrees, as an Object with lon and lat fields. Objects like that, for example from JSON files, can be used as points too."),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(
+			SingleOneOf(
+				TypeOf(ZeroInt()),
+				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		lon, lat := getFloatArgument(args[0]), getFloatArgument(args[1])
+		if err := checkCoordinates(lon, lat); err != nil {
+			return ZeroValue(), err
+		}
+
+		return MakeObject(map[string]Value{
+			"lon": MakeFloat(lon),
+			"lat": MakeFloat(lat),
+		}), nil
+	},
+}
+
+var FuncSTDistance = execution.Function{
+	Name: "st_distance",
+	ArgumentNames: [][]string{
+		{"point1", "point2"},
+	},
+	Description: docs.Text("Returns the great-circle distance between the points in meters, using the haversine formula."),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(TypeOf(ZeroObject())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		lon1, lat1, err := getPoint(args[0])
+		if err != nil {
+			return ZeroValue(), err
+		}
+		lon2, lat2, err := getPoint(args[1])
+		if err != nil {
+			return ZeroValue(), err
+		}
+
+		return MakeFloat(haversineDistance(lon1, lat1, lon2, lat2)), nil
+	},
+}
+
+var FuncSTEnvelope = execution.Function{
+	Name: "st_envelope",
+	ArgumentNames: [][]string{
+		{"min_longitude", "min_latitude", "max_longitude", "max_latitude"},
+	},
+	Description: docs.Text("Returns the bounding box with the given coordinates in degrees, as an Object with min_lon, min_lat, max_lon and max_lat fields."),
+	Validator: All(
+		ExactlyNArgs(4),
+		AllArgs(
+			SingleOneOf(
+				TypeOf(ZeroInt()),
+				TypeOf(ZeroFloat()),
+				TypeOf(ZeroDecimal()),
+			),
+		),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		minLon, minLat := getFloatArgument(args[0]), getFloatArgument(args[1])
+		maxLon, maxLat := getFloatArgument(args[2]), getFloatArgument(args[3])
+		if err := checkCoordinates(minLon, minLat); err != nil {
+			return ZeroValue(), err
+		}
+		if err := checkCoordinates(maxLon, maxLat); err != nil {
+			return ZeroValue(), err
+		}
+		if minLon > maxLon || minLat > maxLat {
+			return ZeroValue(), fmt.Errorf("minimum coordinates of bounding box greater than the maximum ones")
+		}
+
+		return MakeObject(map[string]Value{
+			"min_lon": MakeFloat(minLon),
+			"min_lat": MakeFloat(minLat),
+			"max_lon": MakeFloat(maxLon),
+			"max_lat": MakeFloat(maxLat),
+		}), nil
+	},
+}
+
+var FuncSTContains = execution.Function{
+	Name: "st_contains",
+	ArgumentNames: [][]string{
+		{"envelope", "point"},
+	},
+	Description: docs.Text("Checks whether the point is inside the bounding box, including its edges."),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(TypeOf(ZeroObject())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		envelope := args[0].AsMap()
+		bounds := make(map[string]float64)
+		for _, field := range []string{"min_lon", "min_lat", "max_lon", "max_lat"} {
+			value, ok := envelope[field]
+			if !ok || !isNumber(value) {
+				return ZeroValue(), fmt.Errorf("invalid bounding box %s, expected numeric min_lon, min_lat, max_lon and max_lat fields", args[0].Show())
+			}
+			bounds[field] = getFloatArgument(value)
+		}
+		lon, lat, err := getPoint(args[1])
+		if err != nil {
+			return ZeroValue(), err
+		}
+
+		return MakeBool(bounds["min_lon"] <= lon && lon <= bounds["max_lon"] && bounds["min_lat"] <= lat && lat <= bounds["max_lat"]), nil
+	},
+}
+
+var FuncTokenize = execution.Function{
+	Name: "tokenize",
+	ArgumentNames: [][]string{
+		{"text"},
+	},
+	Description: docs.Text("Splits the text into a Tuple of lowercase terms, made of letters and digits."),
+	Validator: All(
+		ExactlyNArgs(1),
+		Arg(0, TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		terms := tokenize(args[0].AsString())
+		out := make([]Value, len(terms))
+		for i := range terms {
+			out[i] = MakeString(terms[i])
+		}
+		return MakeTuple(out), nil
+	},
+}
+
+var FuncMatches = execution.Function{
+	Name: "matches",
+	ArgumentNames: [][]string{
+		{"text", "query"},
+	},
+	Description: docs.List(
+		docs.Text("Checks whether the terms of the text match the full-text search query, ignoring case."),
+		docs.Text("The query is made of words, \"quoted phrases\" and word* prefixes, combined using AND, OR, NOT and parentheses."),
+		docs.Text("Words next to each other have to match all."),
+	),
+	Validator: All(
+		ExactlyNArgs(2),
+		AllArgs(TypeOf(ZeroString())),
+	),
+	Logic: func(args ...Value) (Value, error) {
+		query, err := parseTextQuery(args[1].AsString())
+		if err != nil {
+			return ZeroValue(), errors.Wrapf(err, "couldn't parse text query %s", args[1].AsString())
+		}
+
+		return MakeBool(query.matches(tokenize(args[0].AsString()))), nil
+	},
+}
+
+/* Auxiliary functions */
+func intMin(x, y int) int {
+	if x <= y {
+		return x
+	}
+	return y
+}
+
+func intMax(x, y int) int {
+	if x <= y {
+		return y
+	}
+	return x
+}
+
+// getIntegralArgument returns the value of an argument which has to be a whole number,
+// though it may have been implicitly converted to a Float or Decimal.
+func getIntegralArgument(arg Value) (int, error) {
+	switch arg.GetType() {
+	case TypeInt:
+		return arg.AsInt(), nil
+	case TypeFloat:
+		if arg.AsFloat() == math.Trunc(arg.AsFloat()) {
+			return int(arg.AsFloat()), nil
+		}
+	case TypeDecimal:
+		d := arg.AsDecimal()
+		if integer, err := d.Int(); err == nil && d.Cmp(DecimalFromInt(integer)) == 0 {
+			return integer, nil
+		}
+	}
+	return 0, fmt.Errorf("expected a whole number, got %s", arg.Show())
+}
+
+// earthRadius is the mean radius of the Earth in meters.
+const earthRadius = 6371008.8
+
+// getFloatArgument returns the value of a numeric argument as a float.
+func getFloatArgument(arg Value) float64 {
+	switch arg.GetType() {
+	case TypeInt:
+		return float64(arg.AsInt())
+	case TypeDecimal:
+		return arg.AsDecimal().Float()
+	default:
+		return arg.AsFloat()
+	}
+}
+
+func isNumber(arg Value) bool {
+	switch arg.GetType() {
+	case TypeInt, TypeFloat, TypeDecimal:
+		return true
+	default:
+		return false
+	}
+}
+
+func checkCoordinates(lon, lat float64) error {
+	if lon < -180 || lon > 180 || lat < -90 || lat > 90 {
+		return fmt.Errorf("invalid coordinates, longitude %v has to be between -180 and 180, and latitude %v between -90 and 90", lon, lat)
+	}
+	return nil
+}
+
+// getPoint returns the coordinates of a point, which is an Object with numeric lon and lat fields.
+func getPoint(arg Value) (lon, lat float64, err error) {
+	point := arg.AsMap()
+	lonValue, lonOk := point["lon"]
+	latValue, latOk := point["lat"]
+	if !lonOk || !latOk || !isNumber(lonValue) || !isNumber(latValue) {
+		return 0, 0, fmt.Errorf("invalid point %s, expected numeric lon and lat fields", arg.Show())
+	}
+
+	lon, lat = getFloatArgument(lonValue), getFloatArgument(latValue)
+	return lon, lat, checkCoordinates(lon, lat)
+}
+
+// haversineDistance returns the great-circle distance between two points, given in degrees, in meters.
+func haversineDistance(lon1, lat1, lon2, lat2 float64) float64 {
+	toRadians := func(degrees float64) float64 {
+		return degrees * math.Pi / 180
+	}
+	phi1, phi2 := toRadians(lat1), toRadians(lat2)
+	deltaPhi, deltaLambda := toRadians(lat2-lat1), toRadians(lon2-lon1)
+
+	a := math.Pow(math.Sin(deltaPhi/2), 2) + math.Cos(phi1)*math.Cos(phi2)*math.Pow(math.Sin(deltaLambda/2), 2)
+	return 2 * earthRadius * math.Asin(math.Sqrt(a))
+}
+
+// getBinaryArgument returns the Bytes, or the bytes of the String.
+func getBinaryArgument(arg Value) []byte {
+	if arg.GetType() == TypeBytes {
+		return arg.AsBytes()
+	}
+	return []byte(arg.AsString())
+}
+
+// parseIP returns the IP address, given either as an IP or as text.
+func parseIP(arg Value) (net.IP, error) {
+	if arg.GetType() == TypeIP {
+		return arg.AsIP(), nil
+	}
+	ip := net.ParseIP(arg.AsString())
+	if ip == nil {
+		return nil, fmt.Errorf("invalid ip address %s", arg.AsString())
+	}
+	return ip, nil
+}
+
+func parseJSON(text string) (interface{}, error) {
+	var value interface{}
+	if err := json.Unmarshal([]byte(text), &value); err != nil {
+		return nil, errors.Wrap(err, "couldn't parse JSON")
+	}
+	return value, nil
+}
+
+// extractJSON parses the JSON text and follows the path in it.
+// It returns false if there's nothing under the path.
+func extractJSON(text, path Value) (interface{}, bool, error) {
+	value, err := parseJSON(text.AsString())
+	if err != nil {
+		return nil, false, err
+	}
+
+	var steps []interface{}
+	switch path.GetType() {
+	case TypeInt:
+		steps = []interface{}{path.AsInt()}
+	case TypeString:
+		if !strings.HasPrefix(path.AsString(), "$") {
+			steps = []interface{}{path.AsString()}
+			break
+		}
+		steps, err = parseJSONPath(path.AsString())
+		if err != nil {
+			return nil, false, errors.Wrapf(err, "couldn't parse JSON path %s", path.AsString())
+		}
+	}
+
+	for _, step := range steps {
+		switch step := step.(type) {
+		case string:
+			object, ok := value.(map[string]interface{})
+			if !ok {
+				return nil, false, nil
+			}
+			value, ok = object[step]
+			if !ok {
+				return nil, false, nil
+			}
+		case int:
+			array, ok := value.([]interface{})
+			if !ok || step < 0 || step >= len(array) {
+				return nil, false, nil
+			}
+			value = array[step]
+		}
+	}
+
+	return value, true, nil
+}
+
+// parseJSONPath splits a path of the form $.field[index] into object keys and array indices.
+func parseJSONPath(path string) ([]interface{}, error) {
+	steps := make([]interface{}, 0)
+	rest := strings.TrimPrefix(path, "$")
+	for len(rest) > 0 {
+		switch rest[0] {
+		case '.':
+			end := strings.IndexAny(rest[1:], ".[")
+			if end == -1 {
+				end = len(rest) - 1
+			}
+			if end == 0 {
+				return nil, errors.New("empty field name")
+			}
+			steps = append(steps, rest[1:end+1])
+			rest = rest[end+1:]
+		case '[':
+			end := strings.IndexByte(rest, ']')
+			if end == -1 {
+				return nil, errors.New("unclosed bracket")
+			}
+			index, err := strconv.Atoi(rest[1:end])
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't parse array index")
+			}
+			steps = append(steps, index)
+			rest = rest[end+1:]
+		default:
+			return nil, errors.Errorf("expected . or [, got %c", rest[0])
+		}
+	}
+	return steps, nil
 }
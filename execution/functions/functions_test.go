@@ -1,7 +1,9 @@
 package functions
 
 import (
+	"context"
 	"math"
+	"net"
 	"reflect"
 	"testing"
 	"time"
@@ -346,6 +348,167 @@ func Test_various(t *testing.T) {
 		want    Value
 		wantErr bool
 	}{
+		/* string() */
+		{
+			name: "string(1.5)",
+			args: args{
+				args: []Value{MakeFloat(1.5)},
+				fun:  FuncString,
+			},
+			want:    MakeString("1.5"),
+			wantErr: false,
+		},
+		{
+			name: "string(true)",
+			args: args{
+				args: []Value{MakeBool(true)},
+				fun:  FuncString,
+			},
+			want:    MakeString("true"),
+			wantErr: false,
+		},
+
+		/* bool() */
+		{
+			name: "bool(0)",
+			args: args{
+				args: []Value{MakeInt(0)},
+				fun:  FuncBool,
+			},
+			want:    MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "bool('true')",
+			args: args{
+				args: []Value{MakeString("true")},
+				fun:  FuncBool,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "bool('yes')",
+			args: args{
+				args: []Value{MakeString("yes")},
+				fun:  FuncBool,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+
+		/* decimal() */
+		{
+			name: "decimal('12.345', 5, 2)",
+			args: args{
+				args: []Value{MakeString("12.345"), MakeInt(5), MakeInt(2)},
+				fun:  FuncDecimal,
+			},
+			want:    makeDecimal("12.35"),
+			wantErr: false,
+		},
+		{
+			name: "decimal(0.1)",
+			args: args{
+				args: []Value{MakeFloat(0.1)},
+				fun:  FuncDecimal,
+			},
+			want:    makeDecimal("0.1"),
+			wantErr: false,
+		},
+		{
+			name: "decimal('123.4', 4, 2)",
+			args: args{
+				args: []Value{MakeString("123.4"), MakeInt(4), MakeInt(2)},
+				fun:  FuncDecimal,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "decimal('1.00') / decimal('3')",
+			args: args{
+				args: []Value{makeDecimal("1.00"), makeDecimal("3")},
+				fun:  FuncDivide,
+			},
+			want:    makeDecimal("0.333333"),
+			wantErr: false,
+		},
+		{
+			name: "decimal('0.10') * decimal('0.3')",
+			args: args{
+				args: []Value{makeDecimal("0.10"), makeDecimal("0.3")},
+				fun:  FuncMultiply,
+			},
+			want:    makeDecimal("0.030"),
+			wantErr: false,
+		},
+
+		/* time zones */
+		{
+			name: "at_time_zone(2020-03-29T00:30:00Z, 'Europe/Warsaw')",
+			args: args{
+				args: []Value{MakeTime(time.Date(2020, 3, 29, 0, 30, 0, 0, time.UTC)), MakeString("Europe/Warsaw")},
+				fun:  FuncAtTimeZone,
+			},
+			want:    MakeTime(time.Date(2020, 3, 29, 0, 30, 0, 0, time.UTC).In(mustLoadLocation("Europe/Warsaw"))),
+			wantErr: false,
+		},
+		{
+			name: "at_time_zone(2020-03-29T00:30:00Z, 'Mars/Olympus')",
+			args: args{
+				args: []Value{MakeTime(time.Date(2020, 3, 29, 0, 30, 0, 0, time.UTC)), MakeString("Mars/Olympus")},
+				fun:  FuncAtTimeZone,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "date_trunc('day', 2020-03-29T23:30:00+02:00)",
+			args: args{
+				args: []Value{MakeString("day"), MakeTime(time.Date(2020, 3, 29, 23, 30, 0, 0, time.FixedZone("", 7200)))},
+				fun:  FuncDateTrunc,
+			},
+			want:    MakeTime(time.Date(2020, 3, 29, 0, 0, 0, 0, time.FixedZone("", 7200))),
+			wantErr: false,
+		},
+		{
+			name: "date_trunc('week', 2020-03-29T10:00:00Z)",
+			args: args{
+				args: []Value{MakeString("week"), MakeTime(time.Date(2020, 3, 29, 10, 0, 0, 0, time.UTC))},
+				fun:  FuncDateTrunc,
+			},
+			want:    MakeTime(time.Date(2020, 3, 23, 0, 0, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "date_trunc('fortnight', 2020-03-29T10:00:00Z)",
+			args: args{
+				args: []Value{MakeString("fortnight"), MakeTime(time.Date(2020, 3, 29, 10, 0, 0, 0, time.UTC))},
+				fun:  FuncDateTrunc,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "date_part('hour', 2020-03-29T23:30:00+02:00)",
+			args: args{
+				args: []Value{MakeString("hour"), MakeTime(time.Date(2020, 3, 29, 23, 30, 0, 0, time.FixedZone("", 7200)))},
+				fun:  FuncDatePart,
+			},
+			want:    MakeInt(23),
+			wantErr: false,
+		},
+		{
+			name: "date_part('dow', 2020-03-29T23:30:00+02:00)",
+			args: args{
+				args: []Value{MakeString("dow"), MakeTime(time.Date(2020, 3, 29, 23, 30, 0, 0, time.FixedZone("", 7200)))},
+				fun:  FuncDatePart,
+			},
+			want:    MakeInt(0),
+			wantErr: false,
+		},
+
 		/* sqrt() */
 		{
 			name: "sqrt(4)",
@@ -787,6 +950,15 @@ func Test_various(t *testing.T) {
 			want:    MakeInt(11),
 			wantErr: false,
 		},
+		{
+			name: "length(x'deadbeef')",
+			args: args{
+				args: []Value{MakeBytes([]byte{0xde, 0xad, 0xbe, 0xef})},
+				fun:  FuncLength,
+			},
+			want:    MakeInt(4),
+			wantErr: false,
+		},
 		{
 			name: "strjoin('hello hello')",
 			args: args{
@@ -807,339 +979,1340 @@ func Test_various(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "2s + 3s",
+			name: "element_at((1, 'test'), 2)",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 2),
-					MakeDuration(time.Second * 3),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
+					MakeInt(2),
 				},
-				fun: FuncAdd,
+				fun: FuncElementAt,
 			},
-			want:    MakeDuration(time.Second * 5),
+			want:    MakeString("test"),
 			wantErr: false,
 		},
 		{
-			name: "date + duration",
+			name: "element_at((1, 'test'), 3)",
 			args: args{
 				args: []Value{
-					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
-					MakeDuration(time.Hour * 3),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
+					MakeInt(3),
 				},
-				fun: FuncAdd,
+				fun: FuncElementAt,
 			},
-			want:    MakeTime(time.Date(2018, 11, 21, 13, 0, 0, 0, time.UTC)),
+			want:    MakeNull(),
 			wantErr: false,
 		},
 		{
-			name: "2s - 3s",
+			name: "element_at((1, 'test'), 0)",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 2),
-					MakeDuration(time.Second * 3),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
+					MakeInt(0),
 				},
-				fun: FuncSubtract,
+				fun: FuncElementAt,
 			},
-			want:    MakeDuration(time.Second * -1),
+			want:    MakeNull(),
 			wantErr: false,
 		},
 		{
-			name: "date - duration",
+			name: "element_at({a: 3}, 'a')",
 			args: args{
 				args: []Value{
-					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
-					MakeDuration(time.Hour * 3),
+					MakeObject(map[string]Value{"a": MakeInt(3)}),
+					MakeString("a"),
 				},
-				fun: FuncSubtract,
+				fun: FuncElementAt,
 			},
-			want:    MakeTime(time.Date(2018, 11, 21, 7, 0, 0, 0, time.UTC)),
+			want:    MakeInt(3),
 			wantErr: false,
 		},
 		{
-			name: "2s * 3s",
+			name: "element_at((1, 'test'), 'a')",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 2),
-					MakeDuration(time.Second * 3),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
+					MakeString("a"),
 				},
-				fun: FuncMultiply,
+				fun: FuncElementAt,
 			},
 			want:    ZeroValue(),
 			wantErr: true,
 		},
 		{
-			name: "2s * 3",
+			name: "array_length((1, 'test', 2.5))",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 2),
-					MakeInt(3),
+					MakeTuple([]Value{MakeInt(1), MakeString("test"), MakeFloat(2.5)}),
 				},
-				fun: FuncMultiply,
+				fun: FuncArrayLength,
 			},
-			want:    MakeDuration(time.Second * 6),
+			want:    MakeInt(3),
 			wantErr: false,
 		},
 		{
-			name: "4s / 2s",
+			name: "array_length('test')",
+			args: args{
+				args: []Value{MakeString("test")},
+				fun:  FuncArrayLength,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "array_contains((1, 'test'), 'test')",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 4),
-					MakeDuration(time.Second * 2),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
+					MakeString("test"),
 				},
-				fun: FuncDivide,
+				fun: FuncArrayContains,
 			},
-			want:    MakeFloat(2.0),
+			want:    MakeBool(true),
 			wantErr: false,
 		},
 		{
-			name: "4s / 2s",
+			name: "array_contains((1, 'test'), 2)",
 			args: args{
 				args: []Value{
-					MakeDuration(time.Second * 4),
+					MakeTuple([]Value{MakeInt(1), MakeString("test")}),
 					MakeInt(2),
 				},
-				fun: FuncDivide,
+				fun: FuncArrayContains,
 			},
-			want:    MakeDuration(time.Second * 2),
+			want:    MakeBool(false),
 			wantErr: false,
 		},
 		{
-			name: "4s / 2.0s",
+			name: "json_extract(doc, '$.user.id')",
 			args: args{
-				args: []Value{
-					MakeDuration(time.Second * 4),
-					MakeFloat(2.0),
-				},
-				fun: FuncDivide,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("$.user.id")},
+				fun:  FuncJSONExtract,
 			},
-			want:    MakeDuration(time.Second * 2),
+			want:    MakeFloat(3),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(2, 5)",
+			name: "json_extract(doc, '$.user.tags[1]')",
 			args: args{
-				args: []Value{
-					MakeInt(2),
-					MakeInt(5),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("$.user.tags[1]")},
+				fun:  FuncJSONExtract,
 			},
-			want:    MakeInt(2),
+			want:    MakeString("b"),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(2, <null>)",
+			name: "json_extract(doc, '$.user.missing')",
 			args: args{
-				args: []Value{
-					MakeInt(2),
-					MakeNull(),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("$.user.missing")},
+				fun:  FuncJSONExtract,
 			},
-			want:    MakeInt(2),
+			want:    MakeNull(),
 			wantErr: false,
 		},
 		{
-			name: "coalesce('text', <null>)",
+			name: "json_extract(doc, '$.user[')",
 			args: args{
-				args: []Value{
-					MakeString("text"),
-					MakeNull(),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("$.user[")},
+				fun:  FuncJSONExtract,
 			},
-			want:    MakeString("text"),
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "json_extract('[1, 2]', 0)",
+			args: args{
+				args: []Value{MakeString("[1, 2]"), MakeInt(0)},
+				fun:  FuncJSONExtract,
+			},
+			want:    MakeFloat(1),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(<null>, 2)",
+			name: "doc -> 'name'",
 			args: args{
-				args: []Value{
-					MakeNull(),
-					MakeInt(2),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("name")},
+				fun:  FuncJSONExtractOperator,
 			},
-			want:    MakeInt(2),
+			want:    MakeString("x"),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(<null>, 'text')",
+			name: "doc ->> '$.user.tags'",
 			args: args{
-				args: []Value{
-					MakeNull(),
-					MakeString("text"),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("$.user.tags")},
+				fun:  FuncJSONExtractTextOperator,
 			},
-			want:    MakeString("text"),
+			want:    MakeString(`["a","b"]`),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(<null>, <null>, 1, 2)",
+			name: "doc ->> 'name'",
 			args: args{
-				args: []Value{
-					MakeNull(),
-					MakeNull(),
-					MakeInt(1),
-					MakeInt(2),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`), MakeString("name")},
+				fun:  FuncJSONExtractTextOperator,
 			},
-			want:    MakeInt(1),
+			want:    MakeString("x"),
 			wantErr: false,
 		},
 		{
-			name: "coalesce(<null>, <null>, <null>)",
+			name: "json_array_length('[1, 2, 3]')",
 			args: args{
-				args: []Value{
-					MakeNull(),
-					MakeNull(),
-					MakeNull(),
-				},
-				fun: FuncCoalesce,
+				args: []Value{MakeString("[1, 2, 3]")},
+				fun:  FuncJSONArrayLength,
 			},
-			want:    MakeNull(),
+			want:    MakeInt(3),
 			wantErr: false,
 		},
 		{
-			name: "nullif('test', 'test')",
+			name: "json_array_length('{}')",
 			args: args{
-				args: []Value{
-					MakeString("test"),
-					MakeString("test"),
-				},
-				fun: FuncNullIf,
+				args: []Value{MakeString("{}")},
+				fun:  FuncJSONArrayLength,
 			},
 			want:    MakeNull(),
 			wantErr: false,
 		},
 		{
-			name: "nullif('test', 'test2')",
+			name: "json_array_length('[1, 2')",
 			args: args{
-				args: []Value{
-					MakeString("test"),
-					MakeString("test2"),
-				},
-				fun: FuncNullIf,
+				args: []Value{MakeString("[1, 2")},
+				fun:  FuncJSONArrayLength,
 			},
-			want:    MakeString("test2"),
-			wantErr: false,
+			want:    ZeroValue(),
+			wantErr: true,
 		},
 		{
-			name: "nullif('test', 5)",
+			name: "json_type(doc)",
 			args: args{
-				args: []Value{
-					MakeString("test"),
-					MakeInt(5),
-				},
-				fun: FuncNullIf,
+				args: []Value{MakeString(`{"user": {"id": 3, "tags": ["a", "b"]}, "name": "x"}`)},
+				fun:  FuncJSONType,
 			},
-			want:    MakeInt(5),
+			want:    MakeString("object"),
 			wantErr: false,
 		},
 		{
-			name: "nullif(5, 5)",
+			name: "json_type('true')",
 			args: args{
-				args: []Value{
-					MakeInt(5),
-					MakeInt(5),
-				},
-				fun: FuncNullIf,
+				args: []Value{MakeString("true")},
+				fun:  FuncJSONType,
 			},
-			want:    MakeNull(),
+			want:    MakeString("boolean"),
 			wantErr: false,
 		},
 		{
-			name: "parse_time('2006-01-02', '2019-06-13')",
+			name: "2s + 3s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 2),
+					MakeDuration(time.Second * 3),
+				},
+				fun: FuncAdd,
+			},
+			want:    MakeDuration(time.Second * 5),
+			wantErr: false,
+		},
+		{
+			name: "date + duration",
+			args: args{
+				args: []Value{
+					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
+					MakeDuration(time.Hour * 3),
+				},
+				fun: FuncAdd,
+			},
+			want:    MakeTime(time.Date(2018, 11, 21, 13, 0, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "2s - 3s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 2),
+					MakeDuration(time.Second * 3),
+				},
+				fun: FuncSubtract,
+			},
+			want:    MakeDuration(time.Second * -1),
+			wantErr: false,
+		},
+		{
+			name: "date - duration",
+			args: args{
+				args: []Value{
+					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
+					MakeDuration(time.Hour * 3),
+				},
+				fun: FuncSubtract,
+			},
+			want:    MakeTime(time.Date(2018, 11, 21, 7, 0, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "2s * 3s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 2),
+					MakeDuration(time.Second * 3),
+				},
+				fun: FuncMultiply,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "2s * 3",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 2),
+					MakeInt(3),
+				},
+				fun: FuncMultiply,
+			},
+			want:    MakeDuration(time.Second * 6),
+			wantErr: false,
+		},
+		{
+			name: "4s / 2s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 4),
+					MakeDuration(time.Second * 2),
+				},
+				fun: FuncDivide,
+			},
+			want:    MakeFloat(2.0),
+			wantErr: false,
+		},
+		{
+			name: "4s / 2s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 4),
+					MakeInt(2),
+				},
+				fun: FuncDivide,
+			},
+			want:    MakeDuration(time.Second * 2),
+			wantErr: false,
+		},
+		{
+			name: "4s / 2.0s",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 4),
+					MakeFloat(2.0),
+				},
+				fun: FuncDivide,
+			},
+			want:    MakeDuration(time.Second * 2),
+			wantErr: false,
+		},
+		{
+			name: "4s / 0.5",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Second * 4),
+					MakeFloat(0.5),
+				},
+				fun: FuncDivide,
+			},
+			want:    MakeDuration(time.Second * 8),
+			wantErr: false,
+		},
+		{
+			name: "3 * 2s",
+			args: args{
+				args: []Value{
+					MakeInt(3),
+					MakeDuration(time.Second * 2),
+				},
+				fun: FuncMultiply,
+			},
+			want:    MakeDuration(time.Second * 6),
+			wantErr: false,
+		},
+		{
+			name: "duration + date",
+			args: args{
+				args: []Value{
+					MakeDuration(time.Hour * 3),
+					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
+				},
+				fun: FuncAdd,
+			},
+			want:    MakeTime(time.Date(2018, 11, 21, 13, 0, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "date - date",
+			args: args{
+				args: []Value{
+					MakeTime(time.Date(2018, 11, 21, 10, 0, 0, 0, time.UTC)),
+					MakeTime(time.Date(2018, 11, 20, 9, 0, 0, 0, time.UTC)),
+				},
+				fun: FuncSubtract,
+			},
+			want:    MakeDuration(time.Hour * 25),
+			wantErr: false,
+		},
+		{
+			name: "7 % 3",
+			args: args{
+				args: []Value{
+					MakeInt(7),
+					MakeInt(3),
+				},
+				fun: FuncModulo,
+			},
+			want:    MakeInt(1),
+			wantErr: false,
+		},
+		{
+			name: "7.5 % 2.0",
+			args: args{
+				args: []Value{
+					MakeFloat(7.5),
+					MakeFloat(2.0),
+				},
+				fun: FuncModulo,
+			},
+			want:    MakeFloat(1.5),
+			wantErr: false,
+		},
+		{
+			name: "7 % 0",
+			args: args{
+				args: []Value{
+					MakeInt(7),
+					MakeInt(0),
+				},
+				fun: FuncModulo,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "duration(1.5, 'hour')",
+			args: args{
+				args: []Value{
+					MakeFloat(1.5),
+					MakeString("hour"),
+				},
+				fun: FuncDuration,
+			},
+			want:    MakeDuration(time.Minute * 90),
+			wantErr: false,
+		},
+		{
+			name: "duration(2, 'week')",
+			args: args{
+				args: []Value{
+					MakeInt(2),
+					MakeString("week"),
+				},
+				fun: FuncDuration,
+			},
+			want:    MakeDuration(time.Hour * 24 * 14),
+			wantErr: false,
+		},
+		{
+			name: "duration(1, 'month')",
+			args: args{
+				args: []Value{
+					MakeInt(1),
+					MakeString("month"),
+				},
+				fun: FuncDuration,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "coalesce(2, 5)",
+			args: args{
+				args: []Value{
+					MakeInt(2),
+					MakeInt(5),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeInt(2),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(2, <null>)",
+			args: args{
+				args: []Value{
+					MakeInt(2),
+					MakeNull(),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeInt(2),
+			wantErr: false,
+		},
+		{
+			name: "coalesce('text', <null>)",
+			args: args{
+				args: []Value{
+					MakeString("text"),
+					MakeNull(),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeString("text"),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(<null>, 2)",
+			args: args{
+				args: []Value{
+					MakeNull(),
+					MakeInt(2),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeInt(2),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(<null>, 'text')",
+			args: args{
+				args: []Value{
+					MakeNull(),
+					MakeString("text"),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeString("text"),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(<null>, <null>, 1, 2)",
+			args: args{
+				args: []Value{
+					MakeNull(),
+					MakeNull(),
+					MakeInt(1),
+					MakeInt(2),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeInt(1),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(<null>, <null>, <null>)",
+			args: args{
+				args: []Value{
+					MakeNull(),
+					MakeNull(),
+					MakeNull(),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "coalesce(<null>, 1, 'text')",
+			args: args{
+				args: []Value{
+					MakeNull(),
+					MakeInt(1),
+					MakeString("text"),
+				},
+				fun: FuncCoalesce,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "nullif('test', 'test')",
+			args: args{
+				args: []Value{
+					MakeString("test"),
+					MakeString("test"),
+				},
+				fun: FuncNullIf,
+			},
+			want:    MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "nullif('test', 'test2')",
+			args: args{
+				args: []Value{
+					MakeString("test"),
+					MakeString("test2"),
+				},
+				fun: FuncNullIf,
+			},
+			want:    MakeString("test2"),
+			wantErr: false,
+		},
+		{
+			name: "nullif('test', 5)",
+			args: args{
+				args: []Value{
+					MakeString("test"),
+					MakeInt(5),
+				},
+				fun: FuncNullIf,
+			},
+			want:    MakeInt(5),
+			wantErr: false,
+		},
+		{
+			name: "nullif(5, 5)",
+			args: args{
+				args: []Value{
+					MakeInt(5),
+					MakeInt(5),
+				},
+				fun: FuncNullIf,
+			},
+			want:    MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "parse_time('2006-01-02', '2019-06-13')",
+			args: args{
+				args: []Value{
+					MakeString("2006-01-02"),
+					MakeString("2019-06-13"),
+				},
+				fun: FuncParseTime,
+			},
+			want:    MakeTime(time.Date(2019, 06, 13, 0, 0, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "parse_time('2006-01-02', '2019-06-13')",
+			args: args{
+				args: []Value{
+					MakeString("2006-01-02 15:04"),
+					MakeString("2019-06-13 17:21"),
+				},
+				fun: FuncParseTime,
+			},
+			want:    MakeTime(time.Date(2019, 06, 13, 17, 21, 0, 0, time.UTC)),
+			wantErr: false,
+		},
+		{
+			name: "parse_time('2006-01-02', '2019-06-13')",
+			args: args{
+				args: []Value{
+					MakeString("2006-01-02 15:04"),
+					MakeString("2019-13-06 17:21"),
+				},
+				fun: FuncParseTime,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "decode_base32('M5ZGKZLO')",
+			args: args{
+				args: []Value{
+					MakeString("M5ZGKZLO"),
+				},
+				fun: FuncDecodeBase32,
+			},
+			want:    MakeString("green"),
+			wantErr: false,
+		},
+		{
+			name: "decode_base32('OB2XE4DMMU======')",
+			args: args{
+				args: []Value{
+					MakeString("OB2XE4DMMU======"),
+				},
+				fun: FuncDecodeBase32,
+			},
+			want:    MakeString("purple"),
+			wantErr: false,
+		},
+		{
+			name: "decode_base32('OB2XE=DMMU======')",
+			args: args{
+				args: []Value{
+					MakeString("OB2XE=DMMU======"),
+				},
+				fun: FuncDecodeBase32,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "decode_base32('YO74HPY=')",
+			args: args{
+				args: []Value{
+					MakeString("YO74HPY="),
+				},
+				fun: FuncDecodeBase32,
+			},
+			want:    MakeString("ÿÿ"),
+			wantErr: false,
+		},
+		{
+			name: "decode_base32('IVHFKTJAEAQDAMBQ')",
+			args: args{
+				args: []Value{
+					MakeString("IVHFKTJAEAQDAMBQ"),
+				},
+				fun: FuncDecodeBase32,
+			},
+			want:    MakeString("ENUM   000"),
+			wantErr: false,
+		},
+		{
+			name: "encode(x'deadbeef', 'hex')",
+			args: args{
+				args: []Value{
+					MakeBytes([]byte{0xde, 0xad, 0xbe, 0xef}),
+					MakeString("hex"),
+				},
+				fun: FuncEncode,
+			},
+			want:    MakeString("deadbeef"),
+			wantErr: false,
+		},
+		{
+			name: "encode(x'deadbeef', 'BASE64')",
+			args: args{
+				args: []Value{
+					MakeBytes([]byte{0xde, 0xad, 0xbe, 0xef}),
+					MakeString("BASE64"),
+				},
+				fun: FuncEncode,
+			},
+			want:    MakeString("3q2+7w=="),
+			wantErr: false,
+		},
+		{
+			name: "encode(x'deadbeef', 'base58')",
+			args: args{
+				args: []Value{
+					MakeBytes([]byte{0xde, 0xad, 0xbe, 0xef}),
+					MakeString("base58"),
+				},
+				fun: FuncEncode,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "decode('3q2+7w==', 'base64')",
+			args: args{
+				args: []Value{
+					MakeString("3q2+7w=="),
+					MakeString("base64"),
+				},
+				fun: FuncDecode,
+			},
+			want:    MakeBytes([]byte{0xde, 0xad, 0xbe, 0xef}),
+			wantErr: false,
+		},
+		{
+			name: "decode('DEADBEEG', 'hex')",
+			args: args{
+				args: []Value{
+					MakeString("DEADBEEG"),
+					MakeString("hex"),
+				},
+				fun: FuncDecode,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "md5('')",
+			args: args{
+				args: []Value{
+					MakeString(""),
+				},
+				fun: FuncMD5,
+			},
+			want:    MakeString("d41d8cd98f00b204e9800998ecf8427e"),
+			wantErr: false,
+		},
+		{
+			name: "md5(bytes)",
+			args: args{
+				args: []Value{
+					MakeBytes([]byte("abc")),
+				},
+				fun: FuncMD5,
+			},
+			want:    MakeString("900150983cd24fb0d6963f7d28e17f72"),
+			wantErr: false,
+		},
+		{
+			name: "sha256('abc')",
+			args: args{
+				args: []Value{
+					MakeString("abc"),
+				},
+				fun: FuncSHA256,
+			},
+			want:    MakeString("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+			wantErr: false,
+		},
+		{
+			name: "string(bytes)",
+			args: args{
+				args: []Value{
+					MakeBytes([]byte("green")),
+				},
+				fun: FuncString,
+			},
+			want:    MakeString("green"),
+			wantErr: false,
+		},
+		{
+			name: "string_to_uuid('123E4567-E89B-12D3-A456-426614174000')",
+			args: args{
+				args: []Value{
+					MakeString("123E4567-E89B-12D3-A456-426614174000"),
+				},
+				fun: FuncStringToUUID,
+			},
+			want:    makeUUID("123e4567-e89b-12d3-a456-426614174000"),
+			wantErr: false,
+		},
+		{
+			name: "string_to_uuid('123e4567')",
+			args: args{
+				args: []Value{
+					MakeString("123e4567"),
+				},
+				fun: FuncStringToUUID,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "uuid_to_string(uuid)",
+			args: args{
+				args: []Value{
+					makeUUID("123e4567-e89b-12d3-a456-426614174000"),
+				},
+				fun: FuncUUIDToString,
+			},
+			want:    MakeString("123e4567-e89b-12d3-a456-426614174000"),
+			wantErr: false,
+		},
+		{
+			name: "string(uuid)",
+			args: args{
+				args: []Value{
+					makeUUID("123e4567-e89b-12d3-a456-426614174000"),
+				},
+				fun: FuncString,
+			},
+			want:    MakeString("123e4567-e89b-12d3-a456-426614174000"),
+			wantErr: false,
+		},
+		{
+			name: "string(ip)",
+			args: args{
+				args: []Value{
+					MakeIP(net.ParseIP("10.0.0.1")),
+				},
+				fun: FuncString,
+			},
+			want:    MakeString("10.0.0.1"),
+			wantErr: false,
+		},
+		{
+			name: "ip('2001:DB8::1')",
+			args: args{
+				args: []Value{
+					MakeString("2001:DB8::1"),
+				},
+				fun: FuncIP,
+			},
+			want:    MakeIP(net.ParseIP("2001:db8::1")),
+			wantErr: false,
+		},
+		{
+			name: "ip('10.0.0.256')",
+			args: args{
+				args: []Value{
+					MakeString("10.0.0.256"),
+				},
+				fun: FuncIP,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "inet_aton('10.0.0.1')",
+			args: args{
+				args: []Value{
+					MakeString("10.0.0.1"),
+				},
+				fun: FuncInetAton,
+			},
+			want:    MakeInt(167772161),
+			wantErr: false,
+		},
+		{
+			name: "inet_aton(ip)",
+			args: args{
+				args: []Value{
+					MakeIP(net.ParseIP("255.255.255.255")),
+				},
+				fun: FuncInetAton,
+			},
+			want:    MakeInt(4294967295),
+			wantErr: false,
+		},
+		{
+			name: "inet_aton('::1')",
+			args: args{
+				args: []Value{
+					MakeString("::1"),
+				},
+				fun: FuncInetAton,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "inet_ntoa(167772161)",
+			args: args{
+				args: []Value{
+					MakeInt(167772161),
+				},
+				fun: FuncInetNtoa,
+			},
+			want:    MakeString("10.0.0.1"),
+			wantErr: false,
+		},
+		{
+			name: "inet_ntoa(-1)",
+			args: args{
+				args: []Value{
+					MakeInt(-1),
+				},
+				fun: FuncInetNtoa,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "ip_in_subnet('10.1.2.3', '10.0.0.0/8')",
+			args: args{
+				args: []Value{
+					MakeString("10.1.2.3"),
+					MakeString("10.0.0.0/8"),
+				},
+				fun: FuncIPInSubnet,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "ip_in_subnet(ip, '192.168.0.0/16')",
+			args: args{
+				args: []Value{
+					MakeIP(net.ParseIP("192.169.0.1")),
+					MakeString("192.168.0.0/16"),
+				},
+				fun: FuncIPInSubnet,
+			},
+			want:    MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "ip_in_subnet('2001:db8::1', '2001:db8::/32')",
 			args: args{
 				args: []Value{
-					MakeString("2006-01-02"),
-					MakeString("2019-06-13"),
+					MakeString("2001:db8::1"),
+					MakeString("2001:db8::/32"),
 				},
-				fun: FuncParseTime,
+				fun: FuncIPInSubnet,
 			},
-			want:    MakeTime(time.Date(2019, 06, 13, 0, 0, 0, 0, time.UTC)),
+			want:    MakeBool(true),
 			wantErr: false,
 		},
 		{
-			name: "parse_time('2006-01-02', '2019-06-13')",
+			name: "ip_in_subnet('10.1.2.3', '10.0.0.0')",
 			args: args{
 				args: []Value{
-					MakeString("2006-01-02 15:04"),
-					MakeString("2019-06-13 17:21"),
+					MakeString("10.1.2.3"),
+					MakeString("10.0.0.0"),
 				},
-				fun: FuncParseTime,
+				fun: FuncIPInSubnet,
 			},
-			want:    MakeTime(time.Date(2019, 06, 13, 17, 21, 0, 0, time.UTC)),
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "tokenize('ERROR: Connection refused, retrying in 5s')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection refused, retrying in 5s"),
+				},
+				fun: FuncTokenize,
+			},
+			want:    MakeTuple([]Value{MakeString("error"), MakeString("connection"), MakeString("refused"), MakeString("retrying"), MakeString("in"), MakeString("5s")}),
 			wantErr: false,
 		},
 		{
-			name: "parse_time('2006-01-02', '2019-06-13')",
+			name: "tokenize('')",
 			args: args{
 				args: []Value{
-					MakeString("2006-01-02 15:04"),
-					MakeString("2019-13-06 17:21"),
+					MakeString(""),
 				},
-				fun: FuncParseTime,
+				fun: FuncTokenize,
+			},
+			want:    MakeTuple([]Value{}),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'error AND refused')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("error AND refused"),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'error timeout')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("error timeout"),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'timeout OR refus*')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("timeout OR refus*"),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'error AND NOT (curl OR wget)')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("error AND NOT (curl OR wget)"),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, '\"connection to db\"')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString(`"connection to db"`),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, '\"to connection\"')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString(`"to connection"`),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'user-agent')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("user-agent"),
+				},
+				fun: FuncMatches,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "matches(line, 'error AND (refused')",
+			args: args{
+				args: []Value{
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("error AND (refused"),
+				},
+				fun: FuncMatches,
 			},
 			want:    ZeroValue(),
 			wantErr: true,
 		},
 		{
-			name: "decode_base32('M5ZGKZLO')",
+			name: "matches(line, 'error OR')",
 			args: args{
 				args: []Value{
-					MakeString("M5ZGKZLO"),
+					MakeString("ERROR: Connection to db-1 refused (user-agent: curl)"),
+					MakeString("error OR"),
 				},
-				fun: FuncDecodeBase32,
+				fun: FuncMatches,
 			},
-			want:    MakeString("green"),
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "regexp_extract('GET /index.html 200', '/[^ ]*')",
+			args: args{
+				args: []Value{
+					MakeString("GET /index.html 200"),
+					MakeString("/[^ ]*"),
+				},
+				fun: FuncRegexpExtract,
+			},
+			want:    MakeString("/index.html"),
 			wantErr: false,
 		},
 		{
-			name: "decode_base32('OB2XE4DMMU======')",
+			name: "regexp_extract('GET /index.html 200', '([A-Z]+) (\\S+) (\\d+)', 3)",
 			args: args{
 				args: []Value{
-					MakeString("OB2XE4DMMU======"),
+					MakeString("GET /index.html 200"),
+					MakeString(`([A-Z]+) (\S+) (\d+)`),
+					MakeInt(3),
 				},
-				fun: FuncDecodeBase32,
+				fun: FuncRegexpExtract,
 			},
-			want:    MakeString("purple"),
+			want:    MakeString("200"),
 			wantErr: false,
 		},
 		{
-			name: "decode_base32('OB2XE=DMMU======')",
+			name: "regexp_extract('GET /index.html 200', 'POST (.*)', 1)",
 			args: args{
 				args: []Value{
-					MakeString("OB2XE=DMMU======"),
+					MakeString("GET /index.html 200"),
+					MakeString("POST (.*)"),
+					MakeInt(1),
 				},
-				fun: FuncDecodeBase32,
+				fun: FuncRegexpExtract,
+			},
+			want:    MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "regexp_extract('GET /index.html 200', '(GET)', 2)",
+			args: args{
+				args: []Value{
+					MakeString("GET /index.html 200"),
+					MakeString("(GET)"),
+					MakeInt(2),
+				},
+				fun: FuncRegexpExtract,
 			},
 			want:    ZeroValue(),
 			wantErr: true,
 		},
 		{
-			name: "decode_base32('YO74HPY=')",
+			name: "regexp_extract('GET /index.html 200', '(')",
 			args: args{
 				args: []Value{
-					MakeString("YO74HPY="),
+					MakeString("GET /index.html 200"),
+					MakeString("("),
 				},
-				fun: FuncDecodeBase32,
+				fun: FuncRegexpExtract,
 			},
-			want:    MakeString("ÿÿ"),
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "regexp_replace('2021-03-04', '(\\d+)-(\\d+)-(\\d+)', '$3.$2.$1')",
+			args: args{
+				args: []Value{
+					MakeString("2021-03-04"),
+					MakeString(`(\d+)-(\d+)-(\d+)`),
+					MakeString("$3.$2.$1"),
+				},
+				fun: FuncRegexpReplace,
+			},
+			want:    MakeString("04.03.2021"),
 			wantErr: false,
 		},
 		{
-			name: "decode_base32('IVHFKTJAEAQDAMBQ')",
+			name: "regexp_replace('a  b   c', ' +', ' ')",
 			args: args{
 				args: []Value{
-					MakeString("IVHFKTJAEAQDAMBQ"),
+					MakeString("a  b   c"),
+					MakeString(" +"),
+					MakeString(" "),
 				},
-				fun: FuncDecodeBase32,
+				fun: FuncRegexpReplace,
 			},
-			want:    MakeString("ENUM   000"),
+			want:    MakeString("a b c"),
+			wantErr: false,
+		},
+		{
+			name: "regexp_split_to_array('a, b,c', ', *')",
+			args: args{
+				args: []Value{
+					MakeString("a, b,c"),
+					MakeString(", *"),
+				},
+				fun: FuncRegexpSplitToArray,
+			},
+			want:    MakeTuple([]Value{MakeString("a"), MakeString("b"), MakeString("c")}),
+			wantErr: false,
+		},
+		{
+			name: "regexp_split_to_array('abc', ',')",
+			args: args{
+				args: []Value{
+					MakeString("abc"),
+					MakeString(","),
+				},
+				fun: FuncRegexpSplitToArray,
+			},
+			want:    MakeTuple([]Value{MakeString("abc")}),
+			wantErr: false,
+		},
+		{
+			name: "st_point(21, 52.2297)",
+			args: args{
+				args: []Value{
+					MakeFloat(21),
+					MakeFloat(52.2297),
+				},
+				fun: FuncSTPoint,
+			},
+			want: MakeObject(map[string]Value{
+				"lon": MakeFloat(21),
+				"lat": MakeFloat(52.2297),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "st_point(52.2297, 181)",
+			args: args{
+				args: []Value{
+					MakeFloat(52.2297),
+					MakeInt(181),
+				},
+				fun: FuncSTPoint,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "st_envelope(14, 49, 24, 55)",
+			args: args{
+				args: []Value{
+					MakeInt(14),
+					MakeInt(49),
+					MakeInt(24),
+					MakeInt(55),
+				},
+				fun: FuncSTEnvelope,
+			},
+			want: MakeObject(map[string]Value{
+				"min_lon": MakeFloat(14),
+				"min_lat": MakeFloat(49),
+				"max_lon": MakeFloat(24),
+				"max_lat": MakeFloat(55),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "st_envelope(24, 49, 14, 55)",
+			args: args{
+				args: []Value{
+					MakeInt(24),
+					MakeInt(49),
+					MakeInt(14),
+					MakeInt(55),
+				},
+				fun: FuncSTEnvelope,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
+		{
+			name: "st_contains(envelope, point)",
+			args: args{
+				args: []Value{
+					MakeObject(map[string]Value{
+						"min_lon": MakeFloat(14),
+						"min_lat": MakeFloat(49),
+						"max_lon": MakeFloat(24),
+						"max_lat": MakeFloat(55),
+					}),
+					MakeObject(map[string]Value{
+						"lon": MakeFloat(21.0122),
+						"lat": MakeFloat(52.2297),
+					}),
+				},
+				fun: FuncSTContains,
+			},
+			want:    MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "st_contains(envelope, point outside)",
+			args: args{
+				args: []Value{
+					MakeObject(map[string]Value{
+						"min_lon": MakeFloat(14),
+						"min_lat": MakeFloat(49),
+						"max_lon": MakeFloat(24),
+						"max_lat": MakeFloat(55),
+					}),
+					MakeObject(map[string]Value{
+						"lon": MakeInt(13),
+						"lat": MakeInt(52),
+					}),
+				},
+				fun: FuncSTContains,
+			},
+			want:    MakeBool(false),
 			wantErr: false,
 		},
+		{
+			name: "st_contains(envelope, not a point)",
+			args: args{
+				args: []Value{
+					MakeObject(map[string]Value{
+						"min_lon": MakeFloat(14),
+						"min_lat": MakeFloat(49),
+						"max_lon": MakeFloat(24),
+						"max_lat": MakeFloat(55),
+					}),
+					MakeObject(map[string]Value{
+						"x": MakeFloat(21.0122),
+						"y": MakeFloat(52.2297),
+					}),
+				},
+				fun: FuncSTContains,
+			},
+			want:    ZeroValue(),
+			wantErr: true,
+		},
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
@@ -1154,3 +2327,123 @@ func Test_various(t *testing.T) {
 		})
 	}
 }
+
+func Test_stDistance(t *testing.T) {
+	point := func(lon, lat float64) Value {
+		return MakeObject(map[string]Value{
+			"lon": MakeFloat(lon),
+			"lat": MakeFloat(lat),
+		})
+	}
+
+	tests := []struct {
+		name    string
+		args    []Value
+		want    float64
+		wantErr bool
+	}{
+		{
+			name: "warsaw to cracow",
+			args: []Value{point(21.0122, 52.2297), point(19.9450, 50.0647)},
+			want: 251977,
+		},
+		{
+			name: "across the antimeridian",
+			args: []Value{point(-179.5, 0), point(179.5, 0)},
+			want: 111195,
+		},
+		{
+			name: "same point",
+			args: []Value{point(21.0122, 52.2297), point(21.0122, 52.2297)},
+			want: 0,
+		},
+		{
+			name:    "invalid point",
+			args:    []Value{point(21.0122, 52.2297), MakeObject(map[string]Value{"lon": MakeString("21")})},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := execute(FuncSTDistance, tt.args...)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("st_distance error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				return
+			}
+			if math.Abs(got.AsFloat()-tt.want) > 1 {
+				t.Errorf("st_distance = %v, want %v", got.AsFloat(), tt.want)
+			}
+		})
+	}
+}
+
+func makeDecimal(s string) Value {
+	d, err := ParseDecimal(s)
+	if err != nil {
+		panic(err)
+	}
+	return MakeDecimal(d)
+}
+
+func mustLoadLocation(name string) *time.Location {
+	loc, err := time.LoadLocation(name)
+	if err != nil {
+		panic(err)
+	}
+	return loc
+}
+
+func Test_sessionTimeZone(t *testing.T) {
+	loc, err := LoadTimeZone("+05:30")
+	if err != nil {
+		t.Fatal(err)
+	}
+	ctx := execution.WithSessionTimeZone(context.Background(), loc)
+
+	utcTime := MakeTime(time.Date(2020, 1, 1, 20, 0, 0, 0, time.UTC))
+
+	hour, err := executeWithContext(ctx, FuncDatePart, MakeString("hour"), utcTime)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(hour, MakeInt(1)) {
+		t.Errorf("date_part() = %v, want 1", hour.Show())
+	}
+
+	// The truncated time is still without a time zone.
+	day, err := executeWithContext(ctx, FuncDateTrunc, MakeString("day"), utcTime)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := MakeTime(time.Date(2020, 1, 1, 18, 30, 0, 0, time.UTC)); !reflect.DeepEqual(day, want) {
+		t.Errorf("date_trunc() = %v, want %v", day.Show(), want.Show())
+	}
+
+	// Times with a time zone don't use the session one.
+	zonedHour, err := executeWithContext(ctx, FuncDatePart, MakeString("hour"), MakeTime(time.Date(2020, 1, 1, 20, 0, 0, 0, time.FixedZone("", 3600))))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(zonedHour, MakeInt(20)) {
+		t.Errorf("date_part() = %v, want 20", zonedHour.Show())
+	}
+
+	// Other queries keep using UTC.
+	utcHour, err := execute(FuncDatePart, MakeString("hour"), utcTime)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(utcHour, MakeInt(20)) {
+		t.Errorf("date_part() = %v, want 20", utcHour.Show())
+	}
+}
+
+func makeUUID(s string) Value {
+	u, err := ParseUUID(s)
+	if err != nil {
+		panic(err)
+	}
+	return MakeUUID(u)
+}
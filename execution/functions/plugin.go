@@ -0,0 +1,42 @@
+package functions
+
+import (
+	"plugin"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/execution"
+)
+
+// PluginFunctionsSymbol is the name of the variable a function plugin has to export.
+// It has to be of type []execution.Function.
+const PluginFunctionsSymbol = "Functions"
+
+// LoadPlugin opens a Go plugin built with -buildmode=plugin and registers all the functions it exports.
+// Go plugins are only supported on Linux and macOS, and the plugin has to be built with the same Go version
+// and the same versions of the packages it shares with the binary loading it, OctoSQL included.
+// Otherwise opening it fails.
+func LoadPlugin(path string) error {
+	p, err := plugin.Open(path)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't open function plugin %v", path)
+	}
+
+	symbol, err := p.Lookup(PluginFunctionsSymbol)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't find %v in function plugin %v", PluginFunctionsSymbol, path)
+	}
+
+	pluginFunctions, ok := symbol.(*[]execution.Function)
+	if !ok {
+		return errors.Errorf("invalid %v in function plugin %v, expected []execution.Function, got %T", PluginFunctionsSymbol, path, symbol)
+	}
+
+	for _, function := range *pluginFunctions {
+		if err := Register(function); err != nil {
+			return errors.Wrapf(err, "couldn't register function from plugin %v", path)
+		}
+	}
+
+	return nil
+}
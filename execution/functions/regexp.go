@@ -0,0 +1,40 @@
+package functions
+
+import (
+	"regexp"
+	"sync"
+
+	"github.com/pkg/errors"
+)
+
+// maxCachedRegexps limits the number of compiled regexps kept by compileRegexp.
+const maxCachedRegexps = 64
+
+var regexpCache = struct {
+	mutex    sync.Mutex
+	compiled map[string]*regexp.Regexp
+}{
+	compiled: make(map[string]*regexp.Regexp),
+}
+
+// compileRegexp returns the compiled regexp for the pattern.
+// Patterns are usually constants, so they're cached, to be compiled once per query instead of once per record.
+func compileRegexp(pattern string) (*regexp.Regexp, error) {
+	regexpCache.mutex.Lock()
+	defer regexpCache.mutex.Unlock()
+
+	if compiled, ok := regexpCache.compiled[pattern]; ok {
+		return compiled, nil
+	}
+
+	compiled, err := regexp.Compile(pattern)
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't compile regular expression %s", pattern)
+	}
+
+	if len(regexpCache.compiled) >= maxCachedRegexps {
+		regexpCache.compiled = make(map[string]*regexp.Regexp)
+	}
+	regexpCache.compiled[pattern] = compiled
+	return compiled, nil
+}
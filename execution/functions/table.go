@@ -1,17 +1,31 @@
 package functions
 
-import "github.com/cube2222/octosql/execution"
+import (
+	"strings"
+	"sync"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/execution"
+)
 
 /* The only legal functions are the ones that appear in this
 table. Otherwise the function will be considered undefined
 and will throw an error on physical -> execution conversion.
 IMPORTANT: As of now the lookup is case sensitive, so the functions must
 be stored in lowercase, and the user must input them as lowercase as well.
+Functions can be registered while queries are being planned, so the table should
+only be read using Lookup and Names.
 */
 var FunctionTable = map[string]*execution.Function{}
+var functionTableMutex sync.RWMutex
+
 var usableFunctions = []execution.Function{
 	FuncInt,
 	FuncFloat,
+	FuncDecimal,
+	FuncString,
+	FuncBool,
 	FuncLower,
 	FuncUpper,
 	FuncNegate,
@@ -31,6 +45,9 @@ var usableFunctions = []execution.Function{
 	FuncSubstring,
 	FuncRegexpFind,
 	FuncRegexpMatches,
+	FuncRegexpExtract,
+	FuncRegexpReplace,
+	FuncRegexpSplitToArray,
 	FuncNth,
 	FuncReplace,
 	FuncHasPrefix,
@@ -40,15 +57,44 @@ var usableFunctions = []execution.Function{
 	FuncLength,
 	FuncNow,
 	FuncStringJoin,
+	FuncElementAt,
+	FuncArrayLength,
+	FuncArrayContains,
+	FuncJSONExtract,
+	FuncJSONExtractOperator,
+	FuncJSONExtractTextOperator,
+	FuncJSONArrayLength,
+	FuncJSONType,
 	FuncAdd,
 	FuncSubtract,
 	FuncMultiply,
 	FuncDivide,
+	FuncModulo,
 	FuncDuration,
 	FuncCoalesce,
 	FuncNullIf,
 	FuncParseTime,
+	FuncAtTimeZone,
+	FuncDateTrunc,
+	FuncDatePart,
 	FuncDecodeBase32,
+	FuncEncode,
+	FuncDecode,
+	FuncMD5,
+	FuncSHA256,
+	FuncGenUUID,
+	FuncUUIDToString,
+	FuncStringToUUID,
+	FuncIP,
+	FuncInetAton,
+	FuncInetNtoa,
+	FuncIPInSubnet,
+	FuncSTPoint,
+	FuncSTDistance,
+	FuncSTEnvelope,
+	FuncSTContains,
+	FuncTokenize,
+	FuncMatches,
 }
 
 func init() {
@@ -56,3 +102,55 @@ func init() {
 		FunctionTable[usableFunctions[i].Name] = &usableFunctions[i]
 	}
 }
+
+// Register adds a custom function to the function table, so that it can be used in queries.
+// The name must be lowercase and not already taken by another function.
+// It should be called before any queries using the function are planned, i.e. in an init function,
+// but it's safe to call concurrently with planning other queries.
+// Custom functions may depend on state OctoSQL doesn't know about, so they're treated as nondeterministic.
+func Register(function execution.Function) error {
+	if function.Name == "" {
+		return errors.New("function name can't be empty")
+	}
+	if strings.ToLower(function.Name) != function.Name {
+		return errors.Errorf("function name %v must be lowercase", function.Name)
+	}
+	if function.Validator == nil {
+		return errors.Errorf("function %v has no validator", function.Name)
+	}
+	if function.Logic == nil && function.ContextLogic == nil {
+		return errors.Errorf("function %v has no logic", function.Name)
+	}
+
+	functionTableMutex.Lock()
+	defer functionTableMutex.Unlock()
+
+	if _, ok := FunctionTable[function.Name]; ok {
+		return errors.Errorf("function %v already exists", function.Name)
+	}
+
+	function.Nondeterministic = true
+	FunctionTable[function.Name] = &function
+	return nil
+}
+
+// Lookup returns the function with the given name, if there is one.
+func Lookup(name string) (*execution.Function, bool) {
+	functionTableMutex.RLock()
+	defer functionTableMutex.RUnlock()
+
+	function, ok := FunctionTable[name]
+	return function, ok
+}
+
+// Names returns the names of all the functions, in no particular order.
+func Names() []string {
+	functionTableMutex.RLock()
+	defer functionTableMutex.RUnlock()
+
+	names := make([]string, 0, len(FunctionTable))
+	for name := range FunctionTable {
+		names = append(names, name)
+	}
+	return names
+}
@@ -0,0 +1,109 @@
+package functions
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+)
+
+func TestRegister(t *testing.T) {
+	double := func(name string) execution.Function {
+		return execution.Function{
+			Name:          name,
+			ArgumentNames: [][]string{{"x"}},
+			Description:   docs.Text("Doubles the given integer."),
+			Validator:     All(ExactlyNArgs(1), AllArgs(TypeOf(octosql.ZeroInt()))),
+			Logic: func(args ...octosql.Value) (octosql.Value, error) {
+				return octosql.MakeInt(args[0].AsInt() * 2), nil
+			},
+		}
+	}
+
+	tests := []struct {
+		name     string
+		function execution.Function
+		wantErr  bool
+	}{
+		{
+			name:     "new function",
+			function: double("test_double"),
+			wantErr:  false,
+		},
+		{
+			name:     "existing function",
+			function: double("lower"),
+			wantErr:  true,
+		},
+		{
+			name:     "uppercase name",
+			function: double("TEST_DOUBLE_UPPER"),
+			wantErr:  true,
+		},
+		{
+			name: "no logic",
+			function: execution.Function{
+				Name:      "test_no_logic",
+				Validator: ExactlyNArgs(1),
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := Register(tt.function)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Register() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+
+			function, ok := Lookup(tt.function.Name)
+			if !ok {
+				t.Errorf("Register() didn't add function %v to the function table", tt.function.Name)
+				return
+			}
+			got, err := function.Logic(octosql.MakeInt(21))
+			if err != nil {
+				t.Errorf("registered function error = %v", err)
+				return
+			}
+			if !octosql.AreEqual(got, octosql.MakeInt(42)) {
+				t.Errorf("registered function = %v, want %v", got, octosql.MakeInt(42))
+			}
+		})
+	}
+}
+
+func TestRegister_ConcurrentLookups(t *testing.T) {
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		for i := 0; i < 100; i++ {
+			Lookup("lower")
+			Names()
+		}
+	}()
+
+	for i := 0; i < 100; i++ {
+		err := Register(execution.Function{
+			Name:      fmt.Sprintf("test_concurrent_%d", i),
+			Validator: ExactlyNArgs(0),
+			Logic: func(args ...octosql.Value) (octosql.Value, error) {
+				return octosql.MakeNull(), nil
+			},
+		})
+		if err != nil {
+			t.Fatalf("Register() error = %v", err)
+		}
+	}
+	<-done
+
+	if _, ok := Lookup("test_concurrent_99"); !ok {
+		t.Errorf("Lookup() didn't find a registered function")
+	}
+}
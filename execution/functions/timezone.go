@@ -0,0 +1,17 @@
+package functions
+
+import (
+	"context"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+)
+
+// localTime returns the time in its time zone, or in the session time zone if it doesn't have one.
+func localTime(ctx context.Context, v octosql.Value) time.Time {
+	if v.GetTimeZone() != "" {
+		return v.AsTime()
+	}
+	return v.AsTime().In(execution.GetSessionTimeZone(ctx))
+}
@@ -312,6 +312,37 @@ func (v *allArgs) Document() docs.Documentation {
 	)
 }
 
+type sameTypeArgs struct {
+}
+
+func SameTypeArgs() *sameTypeArgs {
+	return &sameTypeArgs{}
+}
+
+func (v *sameTypeArgs) Validate(args ...octosql.Value) error {
+	firstNonNull := -1
+	for i := range args {
+		if args[i].GetType() == octosql.TypeNull {
+			continue
+		}
+		if firstNonNull == -1 {
+			firstNonNull = i
+			continue
+		}
+		if args[i].GetType() != args[firstNonNull].GetType() {
+			return fmt.Errorf(
+				"bad argument at index %v: expected type %v like the argument at index %v, but got %v",
+				i, args[firstNonNull].GetType(), firstNonNull, args[i].GetType(),
+			)
+		}
+	}
+	return nil
+}
+
+func (v *sameTypeArgs) Document() docs.Documentation {
+	return docs.Text("all arguments which aren't null must be of the same type")
+}
+
 func argumentCount(n int) string {
 	switch n {
 	case 1:
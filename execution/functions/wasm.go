@@ -0,0 +1,455 @@
+package functions
+
+import (
+	"encoding/binary"
+	"fmt"
+	"math"
+	"os"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/go-interpreter/wagon/exec"
+	"github.com/go-interpreter/wagon/wasm"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	batcharrow "github.com/cube2222/octosql/output/batch/arrow"
+)
+
+// WasmFunctionPrefix is the prefix of the names of the functions a WebAssembly module exports as user defined functions.
+const WasmFunctionPrefix = "octosql_udf_"
+
+// The layout of the structs of the Arrow C data interface in the 32-bit memory of a WebAssembly module.
+const (
+	wasmSchemaFormat    = 0
+	wasmSchemaName      = 4
+	wasmSchemaFlags     = 16
+	wasmSchemaNChildren = 24
+	wasmSchemaChildren  = 32
+	wasmSchemaSize      = 48
+
+	wasmArrayLength    = 0
+	wasmArrayNullCount = 8
+	wasmArrayOffset    = 16
+	wasmArrayNBuffers  = 24
+	wasmArrayNChildren = 32
+	wasmArrayBuffers   = 40
+	wasmArrayChildren  = 44
+	wasmArraySize      = 64
+
+	arrowFlagNullable = 2
+)
+
+// LoadWasm loads a WebAssembly module and registers the functions it exports with names starting with octosql_udf_,
+// without the prefix. The module can't have imports, so it has to be built for a target like wasm32-unknown-unknown.
+//
+// Values are passed using the Arrow C data interface, laid out in the memory of the module with 32-bit pointers.
+// Each function has the signature (i32 argsSchema, i32 argsArray, i32 resultSchema, i32 resultArray) -> i32.
+// The arguments are a struct array with a child array for each argument, typed like in the arrow output format.
+// They're owned by OctoSQL and have no release callback, so the function mustn't release them.
+// The function fills the zeroed result structs with an array of one value, and returns 0, or another status on error.
+// Results can be nulls, booleans, signed and unsigned integers, floats, strings, binaries, timestamps and durations.
+//
+// The module also has to export its memory as memory, and the functions:
+//   octosql_alloc(i32 size) -> i32, allocating memory aligned to 8 bytes, used for the arguments and the result structs,
+//   octosql_free(i32 ptr), freeing memory allocated by octosql_alloc,
+//   octosql_release(i32 resultSchema, i32 resultArray), releasing the result once it's read.
+// It can export octosql_last_error() -> i32 too, returning a null-terminated message of the last error.
+//
+// Each call currently passes a single row. The functions are nondeterministic, as the module may keep state between calls.
+func LoadWasm(path string) error {
+	f, err := os.Open(path)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't open WebAssembly module %v", path)
+	}
+	defer f.Close()
+
+	module, err := wasm.ReadModule(f, func(name string) (*wasm.Module, error) {
+		return nil, errors.Errorf("imports aren't supported, but module %v is imported", name)
+	})
+	if err != nil {
+		return errors.Wrapf(err, "couldn't read WebAssembly module %v", path)
+	}
+	if module.Export == nil {
+		return errors.Errorf("WebAssembly module %v doesn't export anything", path)
+	}
+	vm, err := exec.NewVM(module)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't instantiate WebAssembly module %v", path)
+	}
+	vm.RecoverPanic = true
+
+	instance := &wasmInstance{
+		vm:             vm,
+		lastErrorIndex: -1,
+	}
+	if instance.allocIndex, err = getWasmFunction(module, "octosql_alloc", 1, 1); err != nil {
+		return errors.Wrapf(err, "invalid WebAssembly module %v", path)
+	}
+	if instance.freeIndex, err = getWasmFunction(module, "octosql_free", 1, 0); err != nil {
+		return errors.Wrapf(err, "invalid WebAssembly module %v", path)
+	}
+	if instance.releaseIndex, err = getWasmFunction(module, "octosql_release", 2, 0); err != nil {
+		return errors.Wrapf(err, "invalid WebAssembly module %v", path)
+	}
+	if entry, ok := module.Export.Entries["octosql_last_error"]; ok {
+		if instance.lastErrorIndex, err = getWasmFunction(module, entry.FieldStr, 0, 1); err != nil {
+			return errors.Wrapf(err, "invalid WebAssembly module %v", path)
+		}
+	}
+
+	var names []string
+	for name := range module.Export.Entries {
+		if strings.HasPrefix(name, WasmFunctionPrefix) {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+
+	for _, name := range names {
+		index, err := getWasmFunction(module, name, 4, 1)
+		if err != nil {
+			return errors.Wrapf(err, "invalid WebAssembly module %v", path)
+		}
+		function := execution.Function{
+			Name: strings.TrimPrefix(name, WasmFunctionPrefix),
+			ArgumentNames: [][]string{
+				{"args..."},
+			},
+			Description:      docs.Text(fmt.Sprintf("User defined function loaded from the WebAssembly module %v.", path)),
+			Validator:        AtLeastNArgs(0),
+			Logic:            instance.function(index),
+			Nondeterministic: true,
+		}
+		if err := Register(function); err != nil {
+			return errors.Wrapf(err, "couldn't register function from WebAssembly module %v", path)
+		}
+	}
+
+	return nil
+}
+
+// getWasmFunction returns the index of the exported function with the given name,
+// checking that it has the given numbers of parameters and results, all of type i32.
+func getWasmFunction(module *wasm.Module, name string, params, results int) (int64, error) {
+	entry, ok := module.Export.Entries[name]
+	if !ok || entry.Kind != wasm.ExternalFunction {
+		return 0, errors.Errorf("function %v isn't exported", name)
+	}
+	function := module.GetFunction(int(entry.Index))
+	if function == nil {
+		return 0, errors.Errorf("function %v doesn't exist", name)
+	}
+	if !allI32(function.Sig.ParamTypes, params) || !allI32(function.Sig.ReturnTypes, results) {
+		return 0, errors.Errorf("function %v has to take %d i32 parameters and return %d i32 results", name, params, results)
+	}
+	return int64(entry.Index), nil
+}
+
+func allI32(types []wasm.ValueType, n int) bool {
+	if len(types) != n {
+		return false
+	}
+	for _, t := range types {
+		if t != wasm.ValueTypeI32 {
+			return false
+		}
+	}
+	return true
+}
+
+// wasmInstance is an instance of a WebAssembly module, which only runs one call at a time.
+type wasmInstance struct {
+	mutex          sync.Mutex
+	vm             *exec.VM
+	allocIndex     int64
+	freeIndex      int64
+	releaseIndex   int64
+	lastErrorIndex int64
+}
+
+func (instance *wasmInstance) function(index int64) func(...octosql.Value) (octosql.Value, error) {
+	return func(args ...octosql.Value) (octosql.Value, error) {
+		instance.mutex.Lock()
+		defer instance.mutex.Unlock()
+
+		columns := make([]batcharrow.Column, len(args))
+		for i := range args {
+			columns[i] = batcharrow.GetColumn(fmt.Sprintf("arg%d", i), args[i:i+1])
+		}
+
+		// The layout is computed once to get its size, and then again at the address it's allocated at.
+		layout := &wasmLayout{}
+		layout.writeCall(columns)
+		result, err := instance.vm.ExecCode(instance.allocIndex, uint64(len(layout.data)))
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't allocate memory for the arguments")
+		}
+		base, _ := result.(uint32)
+		if base == 0 {
+			return octosql.ZeroValue(), errors.New("couldn't allocate memory for the arguments")
+		}
+		defer instance.vm.ExecCode(instance.freeIndex, uint64(base))
+
+		layout = &wasmLayout{base: base}
+		argsSchema, argsArray, resultSchema, resultArray := layout.writeCall(columns)
+		memory := instance.vm.Memory()
+		if uint64(base)+uint64(len(layout.data)) > uint64(len(memory)) {
+			return octosql.ZeroValue(), errors.New("memory allocated for the arguments is out of bounds")
+		}
+		copy(memory[base:], layout.data)
+
+		result, err = instance.vm.ExecCode(index, uint64(argsSchema), uint64(argsArray), uint64(resultSchema), uint64(resultArray))
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't call function")
+		}
+		if status, _ := result.(uint32); status != 0 {
+			return octosql.ZeroValue(), instance.lastError(status)
+		}
+		defer instance.vm.ExecCode(instance.releaseIndex, uint64(resultSchema), uint64(resultArray))
+
+		// The memory may have grown during the call.
+		value, err := readWasmResult(&wasmMemoryReader{memory: instance.vm.Memory()}, resultSchema, resultArray)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't read result")
+		}
+		return value, nil
+	}
+}
+
+func (instance *wasmInstance) lastError(status uint32) error {
+	if instance.lastErrorIndex == -1 {
+		return errors.Errorf("function returned status %d", int32(status))
+	}
+	result, err := instance.vm.ExecCode(instance.lastErrorIndex)
+	if err != nil {
+		return errors.Wrapf(err, "function returned status %d, couldn't get its error", int32(status))
+	}
+	address, _ := result.(uint32)
+	reader := &wasmMemoryReader{memory: instance.vm.Memory()}
+	message := reader.cString(uint64(address))
+	if reader.err != nil {
+		return errors.Wrapf(reader.err, "function returned status %d, couldn't read its error", int32(status))
+	}
+	return errors.New(message)
+}
+
+// wasmLayout lays out data to be copied into the memory of a module at the given base address.
+type wasmLayout struct {
+	base uint32
+	data []byte
+}
+
+// alloc returns the address of size zeroed bytes, aligned to 8 bytes.
+func (layout *wasmLayout) alloc(size int) uint32 {
+	for len(layout.data)%8 != 0 {
+		layout.data = append(layout.data, 0)
+	}
+	address := layout.base + uint32(len(layout.data))
+	layout.data = append(layout.data, make([]byte, size)...)
+	return address
+}
+
+func (layout *wasmLayout) bytes(data []byte) uint32 {
+	address := layout.alloc(len(data))
+	copy(layout.data[address-layout.base:], data)
+	return address
+}
+
+func (layout *wasmLayout) cString(s string) uint32 {
+	return layout.bytes(append([]byte(s), 0))
+}
+
+func (layout *wasmLayout) putUint32(address uint32, v uint32) {
+	binary.LittleEndian.PutUint32(layout.data[address-layout.base:], v)
+}
+
+func (layout *wasmLayout) putUint64(address uint32, v uint64) {
+	binary.LittleEndian.PutUint64(layout.data[address-layout.base:], v)
+}
+
+// writeCall lays out the struct array of the arguments, made of the given columns, and the zeroed structs of the result.
+func (layout *wasmLayout) writeCall(columns []batcharrow.Column) (argsSchema, argsArray, resultSchema, resultArray uint32) {
+	argsSchema = layout.alloc(wasmSchemaSize)
+	argsArray = layout.alloc(wasmArraySize)
+	resultSchema = layout.alloc(wasmSchemaSize)
+	resultArray = layout.alloc(wasmArraySize)
+
+	childSchemas := layout.alloc(4 * len(columns))
+	layout.putUint32(argsSchema+wasmSchemaFormat, layout.cString("+s"))
+	layout.putUint32(argsSchema+wasmSchemaName, layout.cString(""))
+	layout.putUint64(argsSchema+wasmSchemaNChildren, uint64(len(columns)))
+	layout.putUint32(argsSchema+wasmSchemaChildren, childSchemas)
+
+	// The struct array has no validity bitmap, so its buffer is null.
+	childArrays := layout.alloc(4 * len(columns))
+	layout.putUint64(argsArray+wasmArrayLength, 1)
+	layout.putUint64(argsArray+wasmArrayNBuffers, 1)
+	layout.putUint32(argsArray+wasmArrayBuffers, layout.alloc(4))
+	layout.putUint64(argsArray+wasmArrayNChildren, uint64(len(columns)))
+	layout.putUint32(argsArray+wasmArrayChildren, childArrays)
+
+	for i, column := range columns {
+		childSchema := layout.alloc(wasmSchemaSize)
+		layout.putUint32(childSchema+wasmSchemaFormat, layout.cString(column.Format()))
+		layout.putUint32(childSchema+wasmSchemaName, layout.cString(column.Name))
+		layout.putUint64(childSchema+wasmSchemaFlags, arrowFlagNullable)
+		layout.putUint32(childSchemas+4*uint32(i), childSchema)
+
+		childArray := layout.alloc(wasmArraySize)
+		buffers := layout.alloc(4 * len(column.Buffers))
+		for j, buffer := range column.Buffers {
+			layout.putUint32(buffers+4*uint32(j), layout.bytes(buffer))
+		}
+		layout.putUint64(childArray+wasmArrayLength, uint64(column.Length))
+		layout.putUint64(childArray+wasmArrayNullCount, uint64(column.NullCount))
+		layout.putUint64(childArray+wasmArrayNBuffers, uint64(len(column.Buffers)))
+		layout.putUint32(childArray+wasmArrayBuffers, buffers)
+		layout.putUint32(childArrays+4*uint32(i), childArray)
+	}
+
+	return argsSchema, argsArray, resultSchema, resultArray
+}
+
+// wasmMemoryReader reads the memory of a module, keeping the first out of bounds access as its error.
+type wasmMemoryReader struct {
+	memory []byte
+	err    error
+}
+
+func (reader *wasmMemoryReader) bytes(address, size uint64) []byte {
+	if reader.err == nil && (address+size > uint64(len(reader.memory)) || address+size < address) {
+		reader.err = errors.Errorf("%d bytes at address %d are out of bounds", size, address)
+	}
+	if reader.err != nil {
+		// Fixed size values are decoded from the returned bytes anyway, but they're ignored once there's an error.
+		return make([]byte, 8)
+	}
+	return reader.memory[address : address+size]
+}
+
+func (reader *wasmMemoryReader) uint32(address uint64) uint64 {
+	return uint64(binary.LittleEndian.Uint32(reader.bytes(address, 4)))
+}
+
+func (reader *wasmMemoryReader) uint64(address uint64) uint64 {
+	return binary.LittleEndian.Uint64(reader.bytes(address, 8))
+}
+
+func (reader *wasmMemoryReader) cString(address uint64) string {
+	var s []byte
+	for {
+		c := reader.bytes(address+uint64(len(s)), 1)[0]
+		if c == 0 || reader.err != nil {
+			return string(s)
+		}
+		s = append(s, c)
+	}
+}
+
+var wasmTimeUnits = map[byte]time.Duration{
+	's': time.Second,
+	'm': time.Millisecond,
+	'u': time.Microsecond,
+	'n': time.Nanosecond,
+}
+
+// readWasmResult reads the single value of the result array.
+func readWasmResult(reader *wasmMemoryReader, schema, array uint32) (octosql.Value, error) {
+	format := reader.cString(reader.uint32(uint64(schema) + wasmSchemaFormat))
+	length := reader.uint64(uint64(array) + wasmArrayLength)
+	offset := reader.uint64(uint64(array) + wasmArrayOffset)
+	nBuffers := reader.uint64(uint64(array) + wasmArrayNBuffers)
+	buffers := reader.uint32(uint64(array) + wasmArrayBuffers)
+	if reader.err != nil {
+		return octosql.ZeroValue(), reader.err
+	}
+	if length != 1 {
+		return octosql.ZeroValue(), errors.Errorf("expected a single value, got %d", length)
+	}
+	if format == "n" {
+		return octosql.MakeNull(), nil
+	}
+
+	buffer := func(i uint64) uint64 {
+		if i >= nBuffers {
+			if reader.err == nil {
+				reader.err = errors.Errorf("array of format %v has no buffer %d", format, i)
+			}
+			return 0
+		}
+		return reader.uint32(buffers + 4*i)
+	}
+	bit := func(address uint64) bool {
+		return reader.bytes(address+offset/8, 1)[0]&(1<<(offset%8)) != 0
+	}
+	// Arrays of strings and binaries have offsets of the value before it, of the given size.
+	variableLength := func(offsetSize uint64) []byte {
+		offsets := buffer(1) + offsetSize*offset
+		var start, end uint64
+		if offsetSize == 4 {
+			start, end = reader.uint32(offsets), reader.uint32(offsets+4)
+		} else {
+			start, end = reader.uint64(offsets), reader.uint64(offsets+8)
+		}
+		if end < start {
+			if reader.err == nil {
+				reader.err = errors.Errorf("invalid offsets %d and %d", start, end)
+			}
+			return nil
+		}
+		return reader.bytes(buffer(2)+start, end-start)
+	}
+
+	if validity := buffer(0); validity != 0 && !bit(validity) {
+		return octosql.MakeNull(), reader.err
+	}
+
+	var value octosql.Value
+	switch {
+	case format == "b":
+		value = octosql.MakeBool(bit(buffer(1)))
+	case format == "c":
+		value = octosql.MakeInt(int(int8(reader.bytes(buffer(1)+offset, 1)[0])))
+	case format == "C":
+		value = octosql.MakeInt(int(reader.bytes(buffer(1)+offset, 1)[0]))
+	case format == "s":
+		value = octosql.MakeInt(int(int16(binary.LittleEndian.Uint16(reader.bytes(buffer(1)+2*offset, 2)))))
+	case format == "S":
+		value = octosql.MakeInt(int(binary.LittleEndian.Uint16(reader.bytes(buffer(1)+2*offset, 2))))
+	case format == "i":
+		value = octosql.MakeInt(int(int32(reader.uint32(buffer(1) + 4*offset))))
+	case format == "I":
+		value = octosql.MakeInt(int(reader.uint32(buffer(1) + 4*offset)))
+	case format == "l":
+		value = octosql.MakeInt(int(reader.uint64(buffer(1) + 8*offset)))
+	case format == "f":
+		value = octosql.MakeFloat(float64(math.Float32frombits(uint32(reader.uint32(buffer(1) + 4*offset)))))
+	case format == "g":
+		value = octosql.MakeFloat(math.Float64frombits(reader.uint64(buffer(1) + 8*offset)))
+	case format == "u":
+		value = octosql.MakeString(string(variableLength(4)))
+	case format == "U":
+		value = octosql.MakeString(string(variableLength(8)))
+	case format == "z":
+		value = octosql.MakeBytes(append([]byte(nil), variableLength(4)...))
+	case format == "Z":
+		value = octosql.MakeBytes(append([]byte(nil), variableLength(8)...))
+	case strings.HasPrefix(format, "ts") && len(format) >= 4 && format[3] == ':' && wasmTimeUnits[format[2]] != 0:
+		v := int64(reader.uint64(buffer(1) + 8*offset))
+		value = octosql.MakeTime(time.Unix(0, v*int64(wasmTimeUnits[format[2]])).UTC())
+	case strings.HasPrefix(format, "tD") && len(format) == 3 && wasmTimeUnits[format[2]] != 0:
+		v := int64(reader.uint64(buffer(1) + 8*offset))
+		value = octosql.MakeDuration(time.Duration(v) * wasmTimeUnits[format[2]])
+	default:
+		return octosql.ZeroValue(), errors.Errorf("unsupported result format %v", format)
+	}
+	if reader.err != nil {
+		return octosql.ZeroValue(), reader.err
+	}
+	return value, nil
+}
@@ -0,0 +1,98 @@
+package functions
+
+import (
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/cube2222/octosql"
+)
+
+// testWasmModule is the following module, which keeps its results in static memory:
+//   (module
+//     (memory (export "memory") 1)
+//     (global $heap (mut i32) (i32.const 1024))
+//     (func (export "octosql_alloc") (param $size i32) (result i32)
+//       global.get $heap
+//       (global.set $heap (i32.and (i32.add (i32.add (global.get $heap) (local.get $size)) (i32.const 7)) (i32.const -8))))
+//     (func (export "octosql_free") (param i32))
+//     (func (export "octosql_release") (param i32 i32))
+//     ;; Returns its first argument, which has to be an int, plus one.
+//     (func (export "octosql_udf_test_wasm_add_one") (param $args_schema i32) (param $args i32) (param $schema i32) (param $array i32) (result i32)
+//       (i32.store (local.get $schema) (i32.const 16))
+//       (i64.store (local.get $array) (i64.const 1))
+//       (i64.store offset=24 (local.get $array) (i64.const 2))
+//       (i32.store offset=40 (local.get $array) (i32.const 32))
+//       (i64.store (i32.const 48)
+//         (i64.add (i64.load (i32.load offset=4 (i32.load offset=40 (i32.load (i32.load offset=44 (local.get $args)))))) (i64.const 1)))
+//       i32.const 0)
+//     (func (export "octosql_udf_test_wasm_fail") (param i32 i32 i32 i32) (result i32)
+//       i32.const 1)
+//     (func (export "octosql_last_error") (result i32)
+//       i32.const 64)
+//     (data (i32.const 16) "l\00")
+//     (data (i32.const 32) "\00\00\00\00\30\00\00\00")
+//     (data (i32.const 64) "failed\00"))
+var testWasmModule = []byte{
+	0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x1b, 0x05, 0x60, 0x01, 0x7f, 0x01, 0x7f,
+	0x60, 0x01, 0x7f, 0x00, 0x60, 0x02, 0x7f, 0x7f, 0x00, 0x60, 0x04, 0x7f, 0x7f, 0x7f, 0x7f, 0x01,
+	0x7f, 0x60, 0x00, 0x01, 0x7f, 0x03, 0x07, 0x06, 0x00, 0x01, 0x02, 0x03, 0x03, 0x04, 0x05, 0x03,
+	0x01, 0x00, 0x01, 0x06, 0x07, 0x01, 0x7f, 0x01, 0x41, 0x80, 0x08, 0x0b, 0x07, 0x8d, 0x01, 0x07,
+	0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x0d, 0x6f, 0x63, 0x74, 0x6f, 0x73, 0x71,
+	0x6c, 0x5f, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x00, 0x00, 0x0c, 0x6f, 0x63, 0x74, 0x6f, 0x73, 0x71,
+	0x6c, 0x5f, 0x66, 0x72, 0x65, 0x65, 0x00, 0x01, 0x0f, 0x6f, 0x63, 0x74, 0x6f, 0x73, 0x71, 0x6c,
+	0x5f, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x00, 0x02, 0x1d, 0x6f, 0x63, 0x74, 0x6f, 0x73,
+	0x71, 0x6c, 0x5f, 0x75, 0x64, 0x66, 0x5f, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x77, 0x61, 0x73, 0x6d,
+	0x5f, 0x61, 0x64, 0x64, 0x5f, 0x6f, 0x6e, 0x65, 0x00, 0x03, 0x1a, 0x6f, 0x63, 0x74, 0x6f, 0x73,
+	0x71, 0x6c, 0x5f, 0x75, 0x64, 0x66, 0x5f, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x77, 0x61, 0x73, 0x6d,
+	0x5f, 0x66, 0x61, 0x69, 0x6c, 0x00, 0x04, 0x12, 0x6f, 0x63, 0x74, 0x6f, 0x73, 0x71, 0x6c, 0x5f,
+	0x6c, 0x61, 0x73, 0x74, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x00, 0x05, 0x0a, 0x5e, 0x06, 0x11,
+	0x00, 0x23, 0x00, 0x23, 0x00, 0x20, 0x00, 0x6a, 0x41, 0x07, 0x6a, 0x41, 0x78, 0x71, 0x24, 0x00,
+	0x0b, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b, 0x39, 0x00, 0x20, 0x02, 0x41, 0x10, 0x36, 0x02, 0x00,
+	0x20, 0x03, 0x42, 0x01, 0x37, 0x03, 0x00, 0x20, 0x03, 0x42, 0x02, 0x37, 0x03, 0x18, 0x20, 0x03,
+	0x41, 0x20, 0x36, 0x02, 0x28, 0x41, 0x30, 0x20, 0x01, 0x28, 0x02, 0x2c, 0x28, 0x02, 0x00, 0x28,
+	0x02, 0x28, 0x28, 0x02, 0x04, 0x29, 0x03, 0x00, 0x42, 0x01, 0x7c, 0x37, 0x03, 0x00, 0x41, 0x00,
+	0x0b, 0x04, 0x00, 0x41, 0x01, 0x0b, 0x05, 0x00, 0x41, 0xc0, 0x00, 0x0b, 0x0b, 0x22, 0x03, 0x00,
+	0x41, 0x10, 0x0b, 0x02, 0x6c, 0x00, 0x00, 0x41, 0x20, 0x0b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x30,
+	0x00, 0x00, 0x00, 0x00, 0x41, 0xc0, 0x00, 0x0b, 0x07, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x00,
+}
+
+func TestLoadWasm(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-wasm")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+	path := filepath.Join(dir, "test.wasm")
+	if err := ioutil.WriteFile(path, testWasmModule, 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := LoadWasm(path); err != nil {
+		t.Fatal(err)
+	}
+
+	addOne, ok := Lookup("test_wasm_add_one")
+	if !ok {
+		t.Fatal("function test_wasm_add_one isn't registered")
+	}
+	// The module reuses its memory, so calling the function again mustn't change the results.
+	for _, x := range []int{41, -3} {
+		got, err := execute(*addOne, octosql.MakeInt(x))
+		if err != nil {
+			t.Fatal(err)
+		}
+		if want := octosql.MakeInt(x + 1); !octosql.AreEqual(got, want) {
+			t.Errorf("test_wasm_add_one(%v) = %v, want %v", x, got.Show(), want.Show())
+		}
+	}
+
+	fail, ok := Lookup("test_wasm_fail")
+	if !ok {
+		t.Fatal("function test_wasm_fail isn't registered")
+	}
+	if _, err := execute(*fail, octosql.MakeString("a")); err == nil || err.Error() != "failed" {
+		t.Errorf("test_wasm_fail() error = %v, want failed", err)
+	}
+}
@@ -0,0 +1,333 @@
+package execution
+
+import (
+	"context"
+	"log"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution/trigger"
+	"github.com/cube2222/octosql/storage"
+)
+
+// IntervalJoin is an inner join of two streams, matching records with equal keys
+// for which the right record time minus the left record time is between the lower and upper bound, inclusive.
+// Records are matched as soon as they arrive, and are removed from storage
+// once the watermark makes them too old to match any new record of the other stream.
+type IntervalJoin struct {
+	leftSource, rightSource       Node
+	leftKey, rightKey             []Expression
+	leftTimeField, rightTimeField octosql.VariableName
+	lowerBound, upperBound        Expression
+	storage                       storage.Storage
+	eventTimeField                octosql.VariableName
+}
+
+// NewIntervalJoin creates an interval join. Either bound may be nil, in which case it's zero.
+func NewIntervalJoin(leftSource, rightSource Node, leftKey, rightKey []Expression, leftTimeField, rightTimeField octosql.VariableName, lowerBound, upperBound Expression, storage storage.Storage, eventTimeField octosql.VariableName) *IntervalJoin {
+	return &IntervalJoin{
+		leftSource:     leftSource,
+		rightSource:    rightSource,
+		leftKey:        leftKey,
+		rightKey:       rightKey,
+		leftTimeField:  leftTimeField,
+		rightTimeField: rightTimeField,
+		lowerBound:     lowerBound,
+		upperBound:     upperBound,
+		storage:        storage,
+		eventTimeField: eventTimeField,
+	}
+}
+
+func (node *IntervalJoin) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+
+	lowerBound, err := getIntervalJoinBound(ctx, variables, node.lowerBound)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get lower bound of interval join")
+	}
+	upperBound, err := getIntervalJoinBound(ctx, variables, node.upperBound)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get upper bound of interval join")
+	}
+	if lowerBound > upperBound {
+		return nil, nil, errors.Errorf("lower bound of interval join %v is greater than its upper bound %v", lowerBound, upperBound)
+	}
+
+	leftSourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("left"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get left source stream ID")
+	}
+
+	rightSourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("right"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get right source stream ID")
+	}
+
+	leftStream, leftExec, err := node.leftSource.Get(ctx, variables, leftSourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get left source stream in interval join")
+	}
+
+	rightStream, rightExec, err := node.rightSource.Get(ctx, variables, rightSourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get right source stream in interval join")
+	}
+
+	// The source of watermarks for an interval join is the minimum of watermarks from its sources
+	watermarkSource := NewUnionWatermarkGenerator([]WatermarkSource{leftExec.WatermarkSource, rightExec.WatermarkSource})
+
+	mergedNextShuffles, err := mergeNextShuffles(leftExec.NextShuffles, rightExec.NextShuffles)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge next shuffles of sources")
+	}
+
+	stream := &IntervalJoinedStream{
+		streamID:       streamID,
+		leftTimeField:  node.leftTimeField,
+		rightTimeField: node.rightTimeField,
+		lowerBound:     lowerBound,
+		upperBound:     upperBound,
+		eventTimeField: node.eventTimeField,
+	}
+
+	// Matches are created when a record is added, so every key is triggered right after receiving a record.
+	processFunc := &ProcessByKey{
+		trigger:         trigger.NewCountingTrigger(1),
+		keyExpressions:  [][]Expression{node.leftKey, node.rightKey},
+		processFunction: stream,
+		variables:       variables,
+	}
+
+	intervalJoinPullEngine := NewPullEngine(processFunc, node.storage, []RecordStream{leftStream, rightStream}, streamID, watermarkSource, true, ctx)
+
+	return intervalJoinPullEngine,
+		NewExecutionOutput(
+			intervalJoinPullEngine,
+			mergedNextShuffles,
+			append(leftExec.TasksToRun, append(rightExec.TasksToRun, func() error { intervalJoinPullEngine.Run(); return nil })...),
+		), nil
+}
+
+func getIntervalJoinBound(ctx context.Context, variables octosql.Variables, bound Expression) (time.Duration, error) {
+	if bound == nil {
+		return 0, nil
+	}
+	value, err := bound.ExpressionValue(ctx, variables)
+	if err != nil {
+		return 0, errors.Wrap(err, "couldn't evaluate bound expression")
+	}
+	if value.GetType() != octosql.TypeDuration {
+		return 0, errors.Errorf("interval join bound must be a duration, got %v", value.Show())
+	}
+	return value.AsDuration(), nil
+}
+
+type IntervalJoinedStream struct {
+	streamID                      *StreamID
+	leftTimeField, rightTimeField octosql.VariableName
+	lowerBound, upperBound        time.Duration
+	eventTimeField                octosql.VariableName
+}
+
+// The records of each side of a key are sorted by their time. The records of the other side matching a new record are in a time range,
+// and the expiry time of a record is its time moved by one of the bounds, so the records expire in the order they're sorted in.
+var intervalJoinLeftRecordsPrefix = []byte("$interval_join_left_records$")
+var intervalJoinRightRecordsPrefix = []byte("$interval_join_right_records$")
+var intervalJoinPendingRecordsPrefix = []byte("$interval_join_pending_records$")
+var intervalJoinExpiryPrefix = []byte("$interval_join_expiry$")
+var intervalJoinOutputCountPrefix = []byte("$interval_join_output_count$")
+
+// AddRecord matches the record with the stored records of the other stream right away.
+// The matches are kept in storage until the key gets triggered.
+func (js *IntervalJoinedStream) AddRecord(ctx context.Context, tx storage.StateTransaction, inputIndex int, key octosql.Value, record *Record) error {
+	if inputIndex < 0 || inputIndex > 1 {
+		panic("invalid inputIndex for interval join")
+	}
+
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	isLeft := inputIndex == 0
+	isRetraction := record.IsUndo()
+
+	recordTime, err := js.recordTime(record, isLeft)
+	if err != nil {
+		return err
+	}
+
+	myRecords := NewTimeSortedRecords(txByKey.WithPrefix(getRecordsPrefix(isLeft)))
+
+	// Stored records aren't retractions, so that a record and its retraction find each other.
+	storedRecord := NewRecordFromRecord(record, WithNoUndo())
+
+	if isRetraction {
+		present, err := myRecords.Add(recordTime, storedRecord, -1)
+		if err != nil {
+			return errors.Wrap(err, "couldn't remove record from the appropriate record set")
+		}
+		if !present {
+			// The record has already been removed, as it's too old to match any new record, so the retraction is late.
+			log.Printf("interval join: retracted record %v isn't present, ignoring", record.Show())
+			return nil
+		}
+	} else {
+		if _, err := myRecords.Add(recordTime, storedRecord, 1); err != nil {
+			return errors.Wrap(err, "couldn't add record to the appropriate record set")
+		}
+	}
+
+	// The right record time minus the left record time has to be between the bounds.
+	from, to := recordTime.Add(js.lowerBound), recordTime.Add(js.upperBound)
+	if !isLeft {
+		from, to = recordTime.Add(-js.upperBound), recordTime.Add(-js.lowerBound)
+	}
+	otherRecords, err := NewTimeSortedRecords(txByKey.WithPrefix(getRecordsPrefix(!isLeft))).GetRange(from, to)
+	if err != nil {
+		return errors.Wrap(err, "couldn't read records of the other stream")
+	}
+
+	pendingRecords := NewPendingRecords(txByKey.WithPrefix(intervalJoinPendingRecordsPrefix))
+	for _, otherRecord := range otherRecords {
+		left, right := storedRecord, otherRecord
+		if !isLeft {
+			left, right = otherRecord, storedRecord
+		}
+
+		if err := pendingRecords.Push(concatRecords(left, right, isRetraction, js.eventTimeField)); err != nil {
+			return err
+		}
+	}
+
+	if err := js.updateExpiry(tx, txByKey, key); err != nil {
+		return errors.Wrap(err, "couldn't update expiry time of key")
+	}
+
+	return nil
+}
+
+func getRecordsPrefix(isLeft bool) []byte {
+	if isLeft {
+		return intervalJoinLeftRecordsPrefix
+	}
+	return intervalJoinRightRecordsPrefix
+}
+
+// Trigger returns the matches created since the key was last triggered, with new IDs.
+func (js *IntervalJoinedStream) Trigger(ctx context.Context, tx storage.StateTransaction, key octosql.Value) ([]*Record, error) {
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	pendingRecords := NewPendingRecords(txByKey.WithPrefix(intervalJoinPendingRecordsPrefix))
+	outputCountState := storage.NewValueState(tx.WithPrefix(intervalJoinOutputCountPrefix))
+
+	return pendingRecords.PopAll(outputCountState, js.streamID)
+}
+
+// UpdateWatermark removes the records which can't match any new record of the other stream anymore.
+func (js *IntervalJoinedStream) UpdateWatermark(ctx context.Context, tx storage.StateTransaction, watermark time.Time) error {
+	expiry := trigger.NewTimeSortedKeys(tx.WithPrefix(intervalJoinExpiryPrefix))
+
+	for {
+		keys, _, err := expiry.GetUntil(watermark.Add(-time.Nanosecond), 100)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get keys with expired records")
+		}
+		if len(keys) == 0 {
+			return nil
+		}
+
+		for _, key := range keys {
+			keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+			txByKey := tx.WithPrefix(keyPrefix)
+
+			if err := js.removeExpiredRecords(txByKey, watermark, true); err != nil {
+				return errors.Wrap(err, "couldn't remove expired left records")
+			}
+			if err := js.removeExpiredRecords(txByKey, watermark, false); err != nil {
+				return errors.Wrap(err, "couldn't remove expired right records")
+			}
+
+			if err := js.updateExpiry(tx, txByKey, key); err != nil {
+				return errors.Wrap(err, "couldn't update expiry time of key")
+			}
+		}
+	}
+}
+
+// removeExpiredRecords removes the records expiring before the watermark, which are the earliest ones.
+func (js *IntervalJoinedStream) removeExpiredRecords(txByKey storage.StateTransaction, watermark time.Time, isLeft bool) error {
+	records := NewTimeSortedRecords(txByKey.WithPrefix(getRecordsPrefix(isLeft)))
+
+	// A record expires before the watermark if its time is before the watermark moved back by the difference between the two.
+	return records.RemoveBefore(watermark.Add(-js.expiryOffset(isLeft)))
+}
+
+// updateExpiry sets the expiry time of the key to the earliest expiry time of its records,
+// which is the one of the earliest record of either side.
+func (js *IntervalJoinedStream) updateExpiry(tx, txByKey storage.StateTransaction, key octosql.Value) error {
+	expiry := trigger.NewTimeSortedKeys(tx.WithPrefix(intervalJoinExpiryPrefix))
+
+	var earliest time.Time
+	found := false
+	for _, isLeft := range []bool{true, false} {
+		recordTime, ok, err := NewTimeSortedRecords(txByKey.WithPrefix(getRecordsPrefix(isLeft))).GetEarliest()
+		if err != nil {
+			return errors.Wrap(err, "couldn't get earliest record")
+		}
+		if !ok {
+			continue
+		}
+		if expiryTime := recordTime.Add(js.expiryOffset(isLeft)); !found || expiryTime.Before(earliest) {
+			earliest = expiryTime
+			found = true
+		}
+	}
+
+	if !found {
+		if err := expiry.DeleteByKey(key); err != nil && err != storage.ErrNotFound {
+			return errors.Wrap(err, "couldn't delete expiry time of key")
+		}
+		return nil
+	}
+
+	return expiry.Update(key, earliest)
+}
+
+// expiryOffset returns the difference between the expiry time of a record and its time.
+// A left record can only match right records up to its time plus the upper bound,
+// and a right record can only match left records from its time minus the lower bound.
+func (js *IntervalJoinedStream) expiryOffset(isLeft bool) time.Duration {
+	if isLeft {
+		return js.upperBound
+	}
+	return -js.lowerBound
+}
+
+func (js *IntervalJoinedStream) recordTime(record *Record, isLeft bool) (time.Time, error) {
+	timeField := js.rightTimeField
+	if isLeft {
+		timeField = js.leftTimeField
+	}
+
+	value := record.Value(timeField)
+	if value.GetType() != octosql.TypeTime {
+		return time.Time{}, errors.Errorf("couldn't get time field '%v' as time, got: %v", timeField.String(), value.Show())
+	}
+	return value.AsTime(), nil
+}
+
+// concatRecords creates a record with the fields of both records, which is a retraction if isUndo is set.
+func concatRecords(left, right *Record, isUndo bool, eventTimeField octosql.VariableName) *Record {
+	fields := octosql.StringsToVariableNames(append(left.FieldNames, right.FieldNames...))
+	data := append(octosql.GetValuesFromPointers(left.Data), octosql.GetValuesFromPointers(right.Data)...)
+
+	opts := []RecordOption{WithEventTimeField(eventTimeField)}
+	if isUndo {
+		opts = append(opts, WithUndo())
+	}
+
+	return NewRecordFromSlice(fields, data, opts...)
+}
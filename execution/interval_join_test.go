@@ -0,0 +1,149 @@
+package execution
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestIntervalJoin(t *testing.T) {
+	leftFieldNames := []octosql.VariableName{"left.id", "left.time"}
+	rightFieldNames := []octosql.VariableName{"right.id", "right.time"}
+	concatFieldNames := append(leftFieldNames, rightFieldNames...)
+
+	start := time.Date(2020, 7, 1, 12, 0, 0, 0, time.UTC)
+	minutes := func(n int) time.Time {
+		return start.Add(time.Duration(n) * time.Minute)
+	}
+
+	type fields struct {
+		leftSource, rightSource Node
+		leftKey, rightKey       []Expression
+		lowerBound, upperBound  Expression
+	}
+
+	tests := []struct {
+		name    string
+		fields  fields
+		want    Node
+		wantErr bool
+	}{
+		{
+			name: "right time up to 5 minutes before left time",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(10)}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(20)}, WithID(NewRecordID("id2"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"b", minutes(10)}, WithID(NewRecordID("id3"))),
+				}),
+				leftKey: []Expression{NewVariable("left.id")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(5)}, WithID(NewRecordID("id4"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(8)}, WithID(NewRecordID("id5"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(11)}, WithID(NewRecordID("id6"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(16)}, WithID(NewRecordID("id7"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"b", minutes(20)}, WithID(NewRecordID("id8"))),
+				}),
+				rightKey: []Expression{NewVariable("right.id")},
+
+				lowerBound: NewConstantValue(octosql.MakeDuration(-5 * time.Minute)),
+				upperBound: nil,
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(10), "a", minutes(5)}),
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(10), "a", minutes(8)}),
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(20), "a", minutes(16)}),
+			}),
+		},
+		{
+			name: "retractions",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(10)}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(12)}, WithID(NewRecordID("id2"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(10)}, WithID(NewRecordID("id1")), WithUndo()),
+				}),
+				leftKey: []Expression{NewVariable("left.id")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(11)}, WithID(NewRecordID("id3"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(13)}, WithID(NewRecordID("id4"))),
+				}),
+				rightKey: []Expression{NewVariable("right.id")},
+
+				lowerBound: NewConstantValue(octosql.MakeDuration(-time.Minute)),
+				upperBound: NewConstantValue(octosql.MakeDuration(time.Minute)),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(12), "a", minutes(11)}),
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(12), "a", minutes(13)}),
+			}),
+		},
+		{
+			name: "retractions of missing records are ignored",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(10)}, WithID(NewRecordID("id1")), WithUndo()),
+					NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", minutes(12)}, WithID(NewRecordID("id2"))),
+				}),
+				leftKey: []Expression{NewVariable("left.id")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", minutes(11)}, WithID(NewRecordID("id3"))),
+				}),
+				rightKey: []Expression{NewVariable("right.id")},
+
+				lowerBound: NewConstantValue(octosql.MakeDuration(-time.Minute)),
+				upperBound: NewConstantValue(octosql.MakeDuration(time.Minute)),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(12), "a", minutes(11)}),
+			}),
+		},
+		{
+			name: "lower bound greater than upper bound",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{}),
+				leftKey:    []Expression{NewVariable("left.id")},
+
+				rightSource: NewDummyNode([]*Record{}),
+				rightKey:    []Expression{NewVariable("right.id")},
+
+				lowerBound: NewConstantValue(octosql.MakeDuration(time.Minute)),
+				upperBound: NewConstantValue(octosql.MakeDuration(-time.Minute)),
+			},
+			wantErr: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+			intervalJoin := NewIntervalJoin(tt.fields.leftSource, tt.fields.rightSource, tt.fields.leftKey, tt.fields.rightKey, "left.time", "right.time", tt.fields.lowerBound, tt.fields.upperBound, stateStorage, "")
+
+			streamID := GetRawStreamID()
+
+			stream, _, err := GetAndStartAllShuffles(context.Background(), stateStorage, streamID, []Node{intervalJoin}, octosql.NoVariables())
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("IntervalJoin.Get() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if tt.wantErr {
+				return
+			}
+
+			want := GetTestStream(t, stateStorage, octosql.NoVariables(), tt.want)
+
+			err = AreStreamsEqualNoOrderingWithRetractionReductionAndIDChecking(context.Background(), stateStorage, stream[0], want, WithEqualityBasedOn(EqualityOfFieldsAndValues))
+			if err != nil {
+				t.Errorf("Streams aren't equal: %v", err)
+			}
+		})
+	}
+}
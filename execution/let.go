@@ -0,0 +1,67 @@
+package execution
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// Let evaluates the bound expressions once, when the stream is created,
+// and passes their values down to the source as additional variables.
+type Let struct {
+	names       []octosql.VariableName
+	expressions []Expression
+	source      Node
+}
+
+func NewLet(names []octosql.VariableName, expressions []Expression, source Node) *Let {
+	return &Let{names: names, expressions: expressions, source: source}
+}
+
+func (node *Let) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	variables, err = node.Bind(ctx, variables)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	recordStream, execOutput, err := node.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get record stream")
+	}
+
+	return recordStream, execOutput, nil
+}
+
+// Bind evaluates the bound expressions and returns the given variables with their values added.
+func (node *Let) Bind(ctx context.Context, variables octosql.Variables) (octosql.Variables, error) {
+	// Each binding may reference the ones before it, so they're added one by one.
+	for i := range node.expressions {
+		value, err := node.expressions[i].ExpressionValue(ctx, variables)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't evaluate expression bound to %s", node.names[i])
+		}
+
+		variables, err = variables.MergeWith(octosql.NewVariables(map[octosql.VariableName]octosql.Value{
+			node.names[i]: value,
+		}))
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't add %s to variables", node.names[i])
+		}
+	}
+
+	return variables, nil
+}
+
+// Source returns the node which gets the bound values.
+func (node *Let) Source() Node {
+	return node.source
+}
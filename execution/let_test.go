@@ -0,0 +1,77 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+type countingExpression struct {
+	value octosql.Value
+	count int
+}
+
+func (e *countingExpression) ExpressionValue(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	e.count++
+	return e.value, nil
+}
+
+func TestLet_Get(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	ctx := storage.InjectStateTransaction(context.Background(), tx)
+
+	fieldNames := []octosql.VariableName{
+		octosql.NewVariableName("age"),
+	}
+	threshold := &countingExpression{value: octosql.MakeInt(3)}
+
+	node := NewLet(
+		[]octosql.VariableName{
+			octosql.NewVariableName("const_0"),
+			octosql.NewVariableName("const_1"),
+		},
+		[]Expression{
+			threshold,
+			NewVariable(octosql.NewVariableName("const_0")),
+		},
+		NewMap(
+			[]NamedExpression{
+				NewVariable(octosql.NewVariableName("age")),
+				NewAliasedExpression(octosql.NewVariableName("threshold"), NewVariable(octosql.NewVariableName("const_1"))),
+			},
+			NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{1}),
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{2}),
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{3}),
+			}),
+			false,
+		),
+	)
+
+	stream, _, err := node.Get(ctx, octosql.NoVariables(), GetRawStreamID())
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	outFieldNames := []octosql.VariableName{
+		octosql.NewVariableName("age"),
+		octosql.NewVariableName("threshold"),
+	}
+	want := NewInMemoryStream(ctx, []*Record{
+		NewRecordFromSliceWithNormalize(outFieldNames, []interface{}{1, 3}),
+		NewRecordFromSliceWithNormalize(outFieldNames, []interface{}{2, 3}),
+		NewRecordFromSliceWithNormalize(outFieldNames, []interface{}{3, 3}),
+	})
+
+	if err := AreStreamsEqual(ctx, stream, want); err != nil {
+		t.Fatal(err)
+	}
+	if threshold.count != 1 {
+		t.Errorf("bound expression evaluated %d times, want 1", threshold.count)
+	}
+}
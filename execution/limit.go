@@ -0,0 +1,102 @@
+package execution
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// Limit ends the stream once it has passed through the given number of records,
+// so the sources don't get read any further than needed.
+// It only makes sense for streams without retractions.
+type Limit struct {
+	limit  Expression
+	source Node
+}
+
+func NewLimit(limit Expression, source Node) *Limit {
+	return &Limit{limit: limit, source: source}
+}
+
+func (node *Limit) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	limit, err := node.limit.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't evaluate limit expression")
+	}
+	if limit.GetType() != octosql.TypeInt {
+		return nil, nil, errors.Errorf("limit must be an int, got %v", limit.GetType())
+	}
+
+	recordStream, execOutput, err := node.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get record stream")
+	}
+
+	return &LimitedStream{
+		streamID: streamID,
+		limit:    limit.AsInt(),
+		source:   recordStream,
+	}, execOutput, nil
+}
+
+type LimitedStream struct {
+	streamID *StreamID
+	limit    int
+	source   RecordStream
+}
+
+var limitedStreamCountPrefix = []byte("$limited_stream_count$")
+
+func (stream *LimitedStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := stream.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+
+	if err := storage.DropAll(stream.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
+
+func (stream *LimitedStream) Next(ctx context.Context) (*Record, error) {
+	// The count is kept in storage, so that it's consistent with the transaction the record is read in.
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(stream.streamID.AsPrefix())
+	countState := storage.NewValueState(tx.WithPrefix(limitedStreamCountPrefix))
+
+	var count octosql.Value
+	err := countState.Get(&count)
+	if err == storage.ErrNotFound {
+		count = octosql.MakeInt(0)
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't get current record count")
+	}
+
+	if count.AsInt() >= stream.limit {
+		return nil, ErrEndOfStream
+	}
+
+	record, err := stream.source.Next(ctx)
+	if err != nil {
+		if err == ErrEndOfStream {
+			return nil, ErrEndOfStream
+		}
+		return nil, errors.Wrap(err, "couldn't get source record")
+	}
+
+	count = octosql.MakeInt(count.AsInt() + 1)
+	if err := countState.Set(&count); err != nil {
+		return nil, errors.Wrap(err, "couldn't set current record count")
+	}
+
+	return record, nil
+}
@@ -0,0 +1,77 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestLimitedStream_Next(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	ctx := storage.InjectStateTransaction(context.Background(), tx)
+
+	fieldNames := []octosql.VariableName{
+		octosql.NewVariableName("id"),
+	}
+
+	records := func(ids ...int) []*Record {
+		out := make([]*Record, len(ids))
+		for i := range ids {
+			out[i] = NewRecordFromSliceWithNormalize(fieldNames, []interface{}{ids[i]})
+		}
+		return out
+	}
+
+	tests := []struct {
+		name   string
+		limit  int
+		source RecordStream
+		want   RecordStream
+	}{
+		{
+			name:   "limit smaller than the source",
+			limit:  2,
+			source: NewInMemoryStream(ctx, records(1, 2, 3, 4)),
+			want:   NewInMemoryStream(ctx, records(1, 2)),
+		},
+		{
+			name:   "limit bigger than the source",
+			limit:  5,
+			source: NewInMemoryStream(ctx, records(1, 2, 3)),
+			want:   NewInMemoryStream(ctx, records(1, 2, 3)),
+		},
+		{
+			name:   "zero limit",
+			limit:  0,
+			source: NewInMemoryStream(ctx, records(1, 2, 3)),
+			want:   NewInMemoryStream(ctx, records()),
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stream := &LimitedStream{
+				streamID: NewStreamID(tt.name),
+				limit:    tt.limit,
+				source:   tt.source,
+			}
+			if err := AreStreamsEqual(ctx, stream, tt.want); err != nil {
+				t.Errorf("LimitedStream.Next() error = %v", err)
+				return
+			}
+
+			if err := stream.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close limited stream: %v", err)
+				return
+			}
+			if err := tt.want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+		})
+	}
+}
@@ -2,14 +2,26 @@ package execution
 
 import (
 	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
 
 	"context"
 
 	"github.com/pkg/errors"
 )
 
+// Formula is a condition evaluated using SQL's three-valued logic.
+// Its value is a Bool, or Null if it's unknown, because of a comparison with null.
 type Formula interface {
-	Evaluate(ctx context.Context, variables octosql.Variables) (bool, error)
+	Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error)
+}
+
+// isTrue checks if the value of a formula is true. Both false and unknown formulas don't hold.
+func isTrue(value octosql.Value) bool {
+	return value.GetType() == octosql.TypeBool && value.AsBool()
+}
+
+func isFalse(value octosql.Value) bool {
+	return value.GetType() == octosql.TypeBool && !value.AsBool()
 }
 
 type Constant struct {
@@ -20,8 +32,8 @@ func NewConstant(value bool) *Constant {
 	return &Constant{Value: value}
 }
 
-func (f Constant) Evaluate(ctx context.Context, variables octosql.Variables) (bool, error) {
-	return f.Value, nil
+func (f Constant) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	return octosql.MakeBool(f.Value), nil
 }
 
 type And struct {
@@ -32,17 +44,29 @@ func NewAnd(left Formula, right Formula) *And {
 	return &And{Left: left, Right: right}
 }
 
-func (f *And) Evaluate(ctx context.Context, variables octosql.Variables) (bool, error) {
+func (f *And) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
 	left, err := f.Left.Evaluate(ctx, variables)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't evaluate left operand in and")
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't evaluate left operand in and")
+	}
+	// The right operand doesn't change the result, so it isn't evaluated.
+	if isFalse(left) {
+		return octosql.MakeBool(false), nil
 	}
+
 	right, err := f.Right.Evaluate(ctx, variables)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't evaluate right operand in and")
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't evaluate right operand in and")
 	}
 
-	return left && right, nil
+	switch {
+	case isFalse(right):
+		return octosql.MakeBool(false), nil
+	case isTrue(left) && isTrue(right):
+		return octosql.MakeBool(true), nil
+	default:
+		return octosql.MakeNull(), nil
+	}
 }
 
 type Or struct {
@@ -53,18 +77,29 @@ func NewOr(left Formula, right Formula) *Or {
 	return &Or{Left: left, Right: right}
 }
 
-func (f *Or) Evaluate(ctx context.Context, variables octosql.Variables) (bool, error) {
+func (f *Or) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
 	left, err := f.Left.Evaluate(ctx, variables)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't evaluate left operand in or")
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't evaluate left operand in or")
+	}
+	// The right operand doesn't change the result, so it isn't evaluated.
+	if isTrue(left) {
+		return octosql.MakeBool(true), nil
 	}
 
 	right, err := f.Right.Evaluate(ctx, variables)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't evaluate right operand in or")
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't evaluate right operand in or")
 	}
 
-	return left || right, nil
+	switch {
+	case isTrue(right):
+		return octosql.MakeBool(true), nil
+	case isFalse(left) && isFalse(right):
+		return octosql.MakeBool(false), nil
+	default:
+		return octosql.MakeNull(), nil
+	}
 }
 
 type Not struct {
@@ -75,13 +110,16 @@ func NewNot(child Formula) *Not {
 	return &Not{Child: child}
 }
 
-func (f *Not) Evaluate(ctx context.Context, variables octosql.Variables) (bool, error) {
+func (f *Not) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
 	child, err := f.Child.Evaluate(ctx, variables)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't evaluate child formula in not")
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't evaluate child formula in not")
 	}
 
-	return !child, nil
+	if child.GetType() != octosql.TypeBool {
+		return octosql.MakeNull(), nil
+	}
+	return octosql.MakeBool(!child.AsBool()), nil
 }
 
 type Predicate struct {
@@ -94,6 +132,61 @@ func NewPredicate(left Expression, relation Relation, right Expression) *Predica
 	return &Predicate{Left: left, Relation: relation, Right: right}
 }
 
-func (f *Predicate) Evaluate(ctx context.Context, variables octosql.Variables) (bool, error) {
-	return f.Relation.Apply(ctx, variables, f.Left, f.Right)
+func (f *Predicate) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	if relation, ok := f.Relation.(threeValuedRelation); ok {
+		return relation.applyThreeValued(ctx, variables, f.Left, f.Right)
+	}
+
+	out, err := f.Relation.Apply(ctx, variables, f.Left, f.Right)
+	if err != nil {
+		return octosql.ZeroValue(), err
+	}
+	return octosql.MakeBool(out), nil
+}
+
+// IsNull checks if the value of the expression is null.
+type IsNull struct {
+	expression Expression
+}
+
+func NewIsNull(expression Expression) *IsNull {
+	return &IsNull{expression: expression}
+}
+
+func (f *IsNull) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	value, err := f.expression.ExpressionValue(ctx, variables)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't get value of expression in is null")
+	}
+
+	return octosql.MakeBool(value.GetType() == octosql.TypeNull), nil
+}
+
+// Exists checks if the subquery returns any records.
+type Exists struct {
+	subquery     Node
+	stateStorage storage.Storage
+}
+
+func NewExists(subquery Node, stateStorage storage.Storage) *Exists {
+	return &Exists{subquery: subquery, stateStorage: stateStorage}
+}
+
+func (f *Exists) Evaluate(ctx context.Context, variables octosql.Variables) (octosql.Value, error) {
+	records, err := readSubquery(ctx, f.subquery, f.stateStorage, variables)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't read subquery in exists")
+	}
+
+	// Retractions cancel out the records they retract.
+	count := 0
+	for i := range records {
+		if records[i].IsUndo() {
+			count--
+		} else {
+			count++
+		}
+	}
+
+	return octosql.MakeBool(count > 0), nil
 }
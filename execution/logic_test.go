@@ -20,7 +20,7 @@ func TestAnd_Evaluate(t *testing.T) {
 		name    string
 		fields  fields
 		args    args
-		want    bool
+		want    octosql.Value
 		wantErr bool
 	}{
 		{
@@ -32,7 +32,7 @@ func TestAnd_Evaluate(t *testing.T) {
 				Left:  NewConstant(false),
 				Right: NewConstant(false),
 			},
-			want:    false,
+			want:    octosql.MakeBool(false),
 			wantErr: false,
 		},
 		{
@@ -44,7 +44,7 @@ func TestAnd_Evaluate(t *testing.T) {
 				Left:  NewConstant(false),
 				Right: NewConstant(true),
 			},
-			want:    false,
+			want:    octosql.MakeBool(false),
 			wantErr: false,
 		},
 		{
@@ -56,7 +56,7 @@ func TestAnd_Evaluate(t *testing.T) {
 				Left:  NewConstant(true),
 				Right: NewConstant(false),
 			},
-			want:    false,
+			want:    octosql.MakeBool(false),
 			wantErr: false,
 		},
 		{
@@ -68,7 +68,43 @@ func TestAnd_Evaluate(t *testing.T) {
 				Left:  NewConstant(true),
 				Right: NewConstant(true),
 			},
-			want:    true,
+			want:    octosql.MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "false and unknown",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  NewConstant(false),
+				Right: unknownFormula(),
+			},
+			want:    octosql.MakeBool(false),
+			wantErr: false,
+		},
+		{
+			name: "true and unknown",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  NewConstant(true),
+				Right: unknownFormula(),
+			},
+			want:    octosql.MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "false and invalid isn't evaluated",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  NewConstant(false),
+				Right: invalidFormula(),
+			},
+			want:    octosql.MakeBool(false),
 			wantErr: false,
 		},
 	}
@@ -83,7 +119,7 @@ func TestAnd_Evaluate(t *testing.T) {
 				t.Errorf("And.Evaluate() error = %v, wantErr %v", err, tt.wantErr)
 				return
 			}
-			if got != tt.want {
+			if !octosql.AreEqual(got, tt.want) {
 				t.Errorf("And.Evaluate() = %v, want %v", got, tt.want)
 			}
 		})
@@ -103,7 +139,7 @@ func TestOr_Evaluate(t *testing.T) {
 		name    string
 		fields  fields
 		args    args
-		want    bool
+		want    octosql.Value
 		wantErr bool
 	}{
 		{
@@ -115,7 +151,7 @@ func TestOr_Evaluate(t *testing.T) {
 				Left:  NewConstant(false),
 				Right: NewConstant(false),
 			},
-			want:    false,
+			want:    octosql.MakeBool(false),
 			wantErr: false,
 		},
 		{
@@ -127,7 +163,7 @@ func TestOr_Evaluate(t *testing.T) {
 				Left:  NewConstant(false),
 				Right: NewConstant(true),
 			},
-			want:    true,
+			want:    octosql.MakeBool(true),
 			wantErr: false,
 		},
 		{
@@ -139,7 +175,7 @@ func TestOr_Evaluate(t *testing.T) {
 				Left:  NewConstant(true),
 				Right: NewConstant(false),
 			},
-			want:    true,
+			want:    octosql.MakeBool(true),
 			wantErr: false,
 		},
 		{
@@ -151,7 +187,43 @@ func TestOr_Evaluate(t *testing.T) {
 				Left:  NewConstant(true),
 				Right: NewConstant(true),
 			},
-			want:    true,
+			want:    octosql.MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "true or unknown",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  NewConstant(true),
+				Right: unknownFormula(),
+			},
+			want:    octosql.MakeBool(true),
+			wantErr: false,
+		},
+		{
+			name: "unknown or false",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  unknownFormula(),
+				Right: NewConstant(false),
+			},
+			want:    octosql.MakeNull(),
+			wantErr: false,
+		},
+		{
+			name: "true or invalid isn't evaluated",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Left:  NewConstant(true),
+				Right: invalidFormula(),
+			},
+			want:    octosql.MakeBool(true),
 			wantErr: false,
 		},
 	}
@@ -166,7 +238,7 @@ func TestOr_Evaluate(t *testing.T) {
 				t.Errorf("Or.Evaluate() error = %v, wantErr %v", err, tt.wantErr)
 				return
 			}
-			if got != tt.want {
+			if !octosql.AreEqual(got, tt.want) {
 				t.Errorf("Or.Evaluate() = %v, want %v", got, tt.want)
 			}
 		})
@@ -185,7 +257,7 @@ func TestNot_Evaluate(t *testing.T) {
 		name    string
 		fields  fields
 		args    args
-		want    bool
+		want    octosql.Value
 		wantErr bool
 	}{
 		{
@@ -196,7 +268,7 @@ func TestNot_Evaluate(t *testing.T) {
 			fields: fields{
 				Child: NewConstant(false),
 			},
-			want:    true,
+			want:    octosql.MakeBool(true),
 			wantErr: false,
 		},
 
@@ -208,7 +280,19 @@ func TestNot_Evaluate(t *testing.T) {
 			fields: fields{
 				Child: NewConstant(true),
 			},
-			want:    false,
+			want:    octosql.MakeBool(false),
+			wantErr: false,
+		},
+
+		{
+			name: "not unknown",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Child: unknownFormula(),
+			},
+			want:    octosql.MakeNull(),
 			wantErr: false,
 		},
 	}
@@ -222,9 +306,89 @@ func TestNot_Evaluate(t *testing.T) {
 				t.Errorf("Not.Evaluate() error = %v, wantErr %v", err, tt.wantErr)
 				return
 			}
-			if got != tt.want {
+			if !octosql.AreEqual(got, tt.want) {
 				t.Errorf("Not.Evaluate() = %v, want %v", got, tt.want)
 			}
 		})
 	}
 }
+
+func TestIsNull_Evaluate(t *testing.T) {
+	ctx := context.Background()
+	type fields struct {
+		Expression Expression
+	}
+	type args struct {
+		variables octosql.Variables
+	}
+	tests := []struct {
+		name    string
+		fields  fields
+		args    args
+		want    octosql.Value
+		wantErr bool
+	}{
+		{
+			name: "null",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeNull(),
+				},
+			},
+			fields: fields{
+				Expression: NewVariable("a"),
+			},
+			want:    octosql.MakeBool(true),
+			wantErr: false,
+		},
+
+		{
+			name: "not null",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeInt(0),
+				},
+			},
+			fields: fields{
+				Expression: NewVariable("a"),
+			},
+			want:    octosql.MakeBool(false),
+			wantErr: false,
+		},
+
+		{
+			name: "missing variable",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{},
+			},
+			fields: fields{
+				Expression: NewVariable("a"),
+			},
+			want:    octosql.MakeBool(true),
+			wantErr: false,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			f := NewIsNull(tt.fields.Expression)
+			got, err := f.Evaluate(ctx, tt.args.variables)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("IsNull.Evaluate() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if !octosql.AreEqual(got, tt.want) {
+				t.Errorf("IsNull.Evaluate() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+// unknownFormula returns a formula which is unknown, as it checks if null is in a set.
+func unknownFormula() Formula {
+	return NewPredicate(NewConstantValue(octosql.MakeNull()), NewIn(), NewConstantValue(octosql.MakeInt(1)))
+}
+
+// invalidFormula returns a formula which fails to evaluate, as it compares values of different types.
+func invalidFormula() Formula {
+	return NewPredicate(NewConstantValue(octosql.MakeInt(1)), NewEqual(), NewConstantValue(octosql.MakeString("a")))
+}
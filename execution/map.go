@@ -70,6 +70,8 @@ func (stream *MappedStream) Next(ctx context.Context) (*Record, error) {
 		return nil, errors.Wrap(err, "couldn't merge given variables with record variables")
 	}
 
+	ctx = withSubexpressionCache(ctx)
+
 	fieldNames := make([]octosql.VariableName, 0)
 	outValues := make(map[octosql.VariableName]octosql.Value)
 	for _, expr := range stream.expressions {
@@ -80,9 +82,9 @@ func (stream *MappedStream) Next(ctx context.Context) (*Record, error) {
 			fieldNames = append(fieldNames, expressionName...)
 
 			for _, name := range expressionName {
-				value, err := variables.Get(name)
+				value, err := expr.FieldValue(ctx, variables, name)
 				if err != nil {
-					panic(err)
+					return nil, errors.Wrapf(err, "couldn't get star expression field %v", name)
 				}
 
 				outValues[name] = value
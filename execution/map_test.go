@@ -28,6 +28,16 @@ func TestMappedStream_Next(t *testing.T) {
 
 	fieldNamesAll := append(fieldNamesNew, fieldNames...)
 
+	callCount := 0
+	countingCall := NewCommonSubexpression()
+	countingCall.SetExpression(NewFunctionExpression(&Function{
+		Validator: &AnyOk{},
+		Logic: func(args ...octosql.Value) (octosql.Value, error) {
+			callCount++
+			return octosql.MakeInt(callCount), nil
+		},
+	}, []Expression{}))
+
 	type fields struct {
 		expressions []NamedExpression
 		variables   octosql.Variables
@@ -133,6 +143,46 @@ func TestMappedStream_Next(t *testing.T) {
 			}),
 			wantErr: false,
 		},
+		{
+			name: "map with star expression modifiers",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewStarExpressionWithModifiers(
+						"",
+						[]octosql.VariableName{octosql.NewVariableName("something_else")},
+						[]NamedExpression{
+							NewAliasedExpression(
+								octosql.NewVariableName("something"),
+								NewVariable(octosql.NewVariableName("something_from_above")),
+							),
+						},
+					),
+				},
+				variables: map[octosql.VariableName]octosql.Value{
+					octosql.NewVariableName("something_from_above"): octosql.MakeString("yeah"),
+				},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{3, "test1", "test2"},
+					),
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{5, "test2", "test0"},
+					),
+				}),
+				keep: false,
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSliceWithNormalize(fieldNames[:2],
+					[]interface{}{3, "yeah"},
+				),
+				NewRecordFromSliceWithNormalize(fieldNames[:2],
+					[]interface{}{5, "yeah"},
+				),
+			}),
+			wantErr: false,
+		},
 		{
 			name: "map with subquery",
 			fields: fields{
@@ -216,6 +266,211 @@ func TestMappedStream_Next(t *testing.T) {
 			}),
 			wantErr: false,
 		},
+		{
+			name: "map with empty subquery",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewAliasedExpression(
+						octosql.NewVariableName("awesome"),
+						NewNodeExpression(
+							NewDummyNode(
+								[]*Record{},
+							),
+							stateStorage,
+						),
+					),
+				},
+				variables: map[octosql.VariableName]octosql.Value{},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{},
+						[]interface{}{},
+					),
+				}),
+				keep: false,
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSlice(
+					[]octosql.VariableName{"awesome"},
+					[]octosql.Value{
+						octosql.MakeNull(),
+					},
+				),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "map with subquery returning a retracted record",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewAliasedExpression(
+						octosql.NewVariableName("awesome"),
+						NewNodeExpression(
+							NewDummyNode(
+								[]*Record{
+									NewRecordFromSliceWithNormalize(
+										[]octosql.VariableName{octosql.NewVariableName("test")},
+										[]interface{}{5},
+									),
+									NewRecordFromSliceWithNormalize(
+										[]octosql.VariableName{octosql.NewVariableName("test")},
+										[]interface{}{5},
+										WithUndo(),
+									),
+									NewRecordFromSliceWithNormalize(
+										[]octosql.VariableName{octosql.NewVariableName("test")},
+										[]interface{}{3},
+									),
+								},
+							),
+							stateStorage,
+						),
+					),
+				},
+				variables: map[octosql.VariableName]octosql.Value{},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{},
+						[]interface{}{},
+					),
+				}),
+				keep: false,
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSlice(
+					[]octosql.VariableName{"awesome"},
+					[]octosql.Value{
+						octosql.MakeInt(3),
+					},
+				),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "map with subquery returning multiple records",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewAliasedExpression(
+						octosql.NewVariableName("awesome"),
+						NewNodeExpression(
+							NewDummyNode(
+								[]*Record{
+									NewRecordFromSliceWithNormalize(
+										[]octosql.VariableName{octosql.NewVariableName("test")},
+										[]interface{}{5},
+									),
+									NewRecordFromSliceWithNormalize(
+										[]octosql.VariableName{octosql.NewVariableName("test")},
+										[]interface{}{3},
+									),
+								},
+							),
+							stateStorage,
+						),
+					),
+				},
+				variables: map[octosql.VariableName]octosql.Value{},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{},
+						[]interface{}{},
+					),
+				}),
+				keep: false,
+			},
+			want:    NewInMemoryStream(ctx, []*Record{}),
+			wantErr: true,
+		},
+		{
+			name: "map with memoized correlated subquery",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewAliasedExpression(
+						octosql.NewVariableName("name"),
+						NewMemoizedNodeExpression(
+							NewMap(
+								[]NamedExpression{NewVariable(octosql.NewVariableName("t.name"))},
+								NewFilter(
+									NewPredicate(NewVariable(octosql.NewVariableName("t.id")), NewEqual(), NewVariable(octosql.NewVariableName("a.id"))),
+									NewDummyNode(
+										[]*Record{
+											NewRecordFromSliceWithNormalize(
+												[]octosql.VariableName{octosql.NewVariableName("t.id"), octosql.NewVariableName("t.name")},
+												[]interface{}{1, "first"},
+											),
+											NewRecordFromSliceWithNormalize(
+												[]octosql.VariableName{octosql.NewVariableName("t.id"), octosql.NewVariableName("t.name")},
+												[]interface{}{2, "second"},
+											),
+										},
+									),
+								),
+								false,
+							),
+							stateStorage,
+							[]octosql.VariableName{octosql.NewVariableName("t.name"), octosql.NewVariableName("t.id"), octosql.NewVariableName("a.id")},
+						),
+					),
+				},
+				variables: map[octosql.VariableName]octosql.Value{},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{octosql.NewVariableName("a.id")},
+						[]interface{}{1},
+					),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{octosql.NewVariableName("a.id")},
+						[]interface{}{2},
+					),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{octosql.NewVariableName("a.id")},
+						[]interface{}{1},
+					),
+				}),
+				keep: false,
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{octosql.NewVariableName("name")},
+					[]interface{}{"first"},
+				),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{octosql.NewVariableName("name")},
+					[]interface{}{"second"},
+				),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{octosql.NewVariableName("name")},
+					[]interface{}{"first"},
+				),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "map with common subexpression",
+			fields: fields{
+				expressions: []NamedExpression{
+					NewAliasedExpression(octosql.NewVariableName("first"), countingCall),
+					NewAliasedExpression(octosql.NewVariableName("second"), countingCall),
+				},
+				variables: map[octosql.VariableName]octosql.Value{},
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(fieldNames, []interface{}{3, "test1", "test2"}),
+					NewRecordFromSliceWithNormalize(fieldNames, []interface{}{5, "test2", "test0"}),
+				}),
+				keep: false,
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{octosql.NewVariableName("first"), octosql.NewVariableName("second")},
+					[]interface{}{1, 1},
+				),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{octosql.NewVariableName("first"), octosql.NewVariableName("second")},
+					[]interface{}{2, 2},
+				),
+			}),
+			wantErr: false,
+		},
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
@@ -0,0 +1,81 @@
+package execution
+
+import (
+	"context"
+	"sync"
+
+	"github.com/golang/protobuf/proto"
+	"github.com/pkg/errors"
+)
+
+var ErrMemoryLimitExceeded = errors.New("memory limit exceeded")
+
+// MemoryPool accounts for the memory of the records operators keep in memory during a run of a query,
+// so that a query going over its limit either frees some of it or fails with ErrMemoryLimitExceeded,
+// instead of getting killed by the operating system.
+// The state of operators lives in the state storage, which writes it to disk on its own, so it isn't accounted for.
+type MemoryPool struct {
+	mutex sync.Mutex
+	limit int64
+	used  int64
+}
+
+// NewMemoryPool creates a pool of the given number of bytes. A pool with a limit which isn't positive is unlimited.
+func NewMemoryPool(limit int64) *MemoryPool {
+	return &MemoryPool{
+		limit: limit,
+	}
+}
+
+// Reserve registers an allocation of the given number of bytes, if it fits in the limit.
+func (pool *MemoryPool) Reserve(size int64) error {
+	pool.mutex.Lock()
+	defer pool.mutex.Unlock()
+
+	if pool.limit > 0 && pool.used+size > pool.limit {
+		return errors.Wrapf(ErrMemoryLimitExceeded, "couldn't reserve %d bytes with %d of %d bytes in use", size, pool.used, pool.limit)
+	}
+	pool.used += size
+	return nil
+}
+
+// Release frees the given number of bytes reserved before.
+func (pool *MemoryPool) Release(size int64) {
+	pool.mutex.Lock()
+	pool.used -= size
+	pool.mutex.Unlock()
+}
+
+// Used returns the number of bytes currently reserved.
+func (pool *MemoryPool) Used() int64 {
+	pool.mutex.Lock()
+	defer pool.mutex.Unlock()
+
+	return pool.used
+}
+
+// RecordsSize estimates the memory taken up by the records as the size of their serialized form.
+func RecordsSize(records []*Record) int64 {
+	var size int64
+	for _, record := range records {
+		size += int64(proto.Size(record))
+	}
+	return size
+}
+
+type memoryPoolKey struct{}
+
+// WithMemoryPool returns a context in which the query accounts for its memory using the pool.
+// It should be created for each run of a query, so that each query gets its own limit.
+func WithMemoryPool(ctx context.Context, pool *MemoryPool) context.Context {
+	return context.WithValue(ctx, memoryPoolKey{}, pool)
+}
+
+// GetMemoryPool returns the memory pool of the query, or an unlimited one if there's none.
+func GetMemoryPool(ctx context.Context) *MemoryPool {
+	pool, ok := ctx.Value(memoryPoolKey{}).(*MemoryPool)
+	if !ok {
+		return NewMemoryPool(0)
+	}
+	return pool
+}
@@ -0,0 +1,36 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/pkg/errors"
+)
+
+func TestMemoryPool(t *testing.T) {
+	pool := NewMemoryPool(100)
+	ctx := WithMemoryPool(context.Background(), pool)
+
+	if err := GetMemoryPool(ctx).Reserve(60); err != nil {
+		t.Fatal(err)
+	}
+	if err := GetMemoryPool(ctx).Reserve(50); errors.Cause(err) != ErrMemoryLimitExceeded {
+		t.Errorf("Reserve() error = %v, want %v", err, ErrMemoryLimitExceeded)
+	}
+	if got := pool.Used(); got != 60 {
+		t.Errorf("Used() = %v, want %v", got, 60)
+	}
+
+	pool.Release(20)
+	if err := pool.Reserve(50); err != nil {
+		t.Errorf("Reserve() error = %v, want it to fit after a release", err)
+	}
+	if got := pool.Used(); got != 90 {
+		t.Errorf("Used() = %v, want %v", got, 90)
+	}
+
+	unlimited := GetMemoryPool(context.Background())
+	if err := unlimited.Reserve(1 << 40); err != nil {
+		t.Errorf("Reserve() error = %v, want no limit without a pool", err)
+	}
+}
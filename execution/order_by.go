@@ -11,10 +11,33 @@ import (
 type OrderDirection string
 
 const (
-	Ascending  OrderDirection = "asc"
-	Descending OrderDirection = "desc"
+	Ascending            OrderDirection = "asc"
+	Descending           OrderDirection = "desc"
+	AscendingNullsLast   OrderDirection = "asc nulls last"
+	DescendingNullsFirst OrderDirection = "desc nulls first"
 )
 
+// SortKey returns the monotonic encoding of the value, so that encodings compare in the given direction.
+// Nulls are the smallest values, unless the direction says otherwise.
+func SortKey(value octosql.Value, direction OrderDirection) []byte {
+	switch direction {
+	case AscendingNullsLast:
+		if value.GetType() == octosql.TypeNull {
+			return []byte{255} // Greater than any type identifier.
+		}
+		return value.MonotonicMarshal()
+	case Descending:
+		return value.ReversedMonotonicMarshal()
+	case DescendingNullsFirst:
+		if value.GetType() == octosql.TypeNull {
+			return []byte{0} // Less than any reversed type identifier.
+		}
+		return value.ReversedMonotonicMarshal()
+	default:
+		return value.MonotonicMarshal()
+	}
+}
+
 type OrderByKey struct {
 	key []byte
 }
@@ -128,11 +151,7 @@ func (ob *OrderByStream) AddRecord(ctx context.Context, tx storage.StateTransact
 		if err != nil {
 			return errors.Wrapf(err, "couldn't evaluate expression with index %d", i)
 		}
-		if ob.directions[i] == Ascending {
-			recordPrefix = append(recordPrefix, expressionValue.MonotonicMarshal()...)
-		} else {
-			recordPrefix = append(recordPrefix, expressionValue.ReversedMonotonicMarshal()...)
-		}
+		recordPrefix = append(recordPrefix, SortKey(expressionValue, ob.directions[i])...)
 		recordPrefix = append(recordPrefix, '$')
 	}
 
@@ -123,6 +123,68 @@ func TestOrderBy_Get(t *testing.T) {
 			},
 			wantErr: false,
 		},
+		{
+			name: "nulls last - one column int ascending",
+			args: args{
+				source: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{1, 7}),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{2, nil}),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{3, 2}),
+				}),
+				expressions: []Expression{NewVariable(octosql.NewVariableName("age"))},
+				directions:  []OrderDirection{AscendingNullsLast},
+				eventTimeField: octosql.NewVariableName(""),
+			},
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{3, 2}),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{1, 7}),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{2, nil}),
+			},
+			wantErr: false,
+		},
+		{
+			name: "nulls first - one column int descending",
+			args: args{
+				source: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{1, 7}),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{2, nil}),
+					NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "age"},
+						[]interface{}{3, 2}),
+				}),
+				expressions: []Expression{NewVariable(octosql.NewVariableName("age"))},
+				directions:  []OrderDirection{DescendingNullsFirst},
+				eventTimeField: octosql.NewVariableName(""),
+			},
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{2, nil}),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{1, 7}),
+				NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "age"},
+					[]interface{}{3, 2}),
+			},
+			wantErr: false,
+		},
 		{
 			name: "complex order - string ascending then int descending",
 			args: args{
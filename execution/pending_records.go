@@ -0,0 +1,72 @@
+package execution
+
+import (
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// PendingRecords is a queue of records which have already been created, but haven't been returned yet.
+// Records created together are kept in storage until they're all returned,
+// so that none of them get lost if the transaction commits in between.
+type PendingRecords struct {
+	deque *storage.Deque
+}
+
+func NewPendingRecords(tx storage.StateTransaction) *PendingRecords {
+	return &PendingRecords{deque: storage.NewDeque(tx)}
+}
+
+// Push adds the record at the end of the queue.
+func (pr *PendingRecords) Push(record *Record) error {
+	if err := pr.deque.PushBack(record); err != nil {
+		return errors.Wrap(err, "couldn't add pending record")
+	}
+	return nil
+}
+
+// Pop returns the first pending record, or false if there are none.
+func (pr *PendingRecords) Pop() (*Record, bool, error) {
+	var pending Record
+	err := pr.deque.PopFront(&pending)
+	if err == storage.ErrNotFound {
+		return nil, false, nil
+	} else if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't get pending record")
+	}
+	return &pending, true, nil
+}
+
+// PopAll returns all the pending records, with IDs based on the count of records already returned.
+func (pr *PendingRecords) PopAll(outputCountState *storage.ValueState, streamID *StreamID) ([]*Record, error) {
+	var outputCount octosql.Value
+	err := outputCountState.Get(&outputCount)
+	if err == storage.ErrNotFound {
+		outputCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't read count of already triggered records")
+	}
+	offset := outputCount.AsInt()
+
+	var out []*Record
+	for {
+		pending, ok, err := pr.Pop()
+		if err != nil {
+			return nil, err
+		}
+		if !ok {
+			break
+		}
+
+		out = append(out, NewRecordFromRecord(pending, WithID(NewRecordIDFromStreamIDWithOffset(streamID, offset))))
+		offset++
+	}
+
+	newOutputCount := octosql.MakeInt(offset)
+	if err := outputCountState.Set(&newOutputCount); err != nil {
+		return nil, errors.Wrap(err, "couldn't update count of triggered records")
+	}
+
+	return out, nil
+}
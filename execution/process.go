@@ -18,6 +18,11 @@ type ProcessFunction interface {
 	Trigger(ctx context.Context, tx storage.StateTransaction, key octosql.Value) ([]*Record, error) // New Records and Retractions
 }
 
+// WatermarkListener may be implemented by a ProcessFunction which needs to know about watermark updates, i.e. to clear old state.
+type WatermarkListener interface {
+	UpdateWatermark(ctx context.Context, tx storage.StateTransaction, watermark time.Time) error
+}
+
 type Trigger interface {
 	docs.Documented
 	RecordReceived(ctx context.Context, tx storage.StateTransaction, key octosql.Value, eventTime time.Time) error
@@ -127,6 +132,12 @@ func (p *ProcessByKey) Next(ctx context.Context, tx storage.StateTransaction) (*
 }
 
 func (p *ProcessByKey) UpdateWatermark(ctx context.Context, tx storage.StateTransaction, watermark time.Time) error {
+	if listener, ok := p.processFunction.(WatermarkListener); ok {
+		if err := listener.UpdateWatermark(ctx, tx, watermark); err != nil {
+			return errors.Wrap(err, "couldn't update watermark in process function")
+		}
+	}
+
 	err := p.trigger.UpdateWatermark(ctx, tx, watermark)
 	if err != nil {
 		return errors.Wrap(err, "couldn't update watermark in trigger")
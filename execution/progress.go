@@ -0,0 +1,97 @@
+package execution
+
+import (
+	"context"
+	"io"
+	"sync"
+	"sync/atomic"
+)
+
+// Progress collects how much of their input the data sources of a query have already read.
+// It's measured in bytes, for the data sources which know the size of their input up front.
+type Progress struct {
+	mutex   sync.Mutex
+	sources []*SourceProgress
+}
+
+func NewProgress() *Progress {
+	return &Progress{}
+}
+
+// NewSource registers a data source reading an input of the given total size in bytes.
+func (progress *Progress) NewSource(total int64) *SourceProgress {
+	source := &SourceProgress{total: total}
+
+	progress.mutex.Lock()
+	progress.sources = append(progress.sources, source)
+	progress.mutex.Unlock()
+
+	return source
+}
+
+// Read returns the number of bytes read by all the data sources.
+func (progress *Progress) Read() int64 {
+	progress.mutex.Lock()
+	defer progress.mutex.Unlock()
+
+	var read int64
+	for _, source := range progress.sources {
+		read += atomic.LoadInt64(&source.read)
+	}
+	return read
+}
+
+// Total returns the total size of the inputs of all the data sources.
+func (progress *Progress) Total() int64 {
+	progress.mutex.Lock()
+	defer progress.mutex.Unlock()
+
+	var total int64
+	for _, source := range progress.sources {
+		total += source.total
+	}
+	return total
+}
+
+// SourceProgress is the progress of a single data source.
+type SourceProgress struct {
+	read  int64
+	total int64
+}
+
+// SetRead sets the number of bytes read. It's used when the data source starts reading its input anew.
+func (source *SourceProgress) SetRead(read int64) {
+	atomic.StoreInt64(&source.read, read)
+}
+
+// Reader wraps the input of the data source, counting the bytes read from it.
+func (source *SourceProgress) Reader(r io.Reader) io.Reader {
+	return &progressReader{source: source, reader: r}
+}
+
+type progressReader struct {
+	source *SourceProgress
+	reader io.Reader
+}
+
+func (r *progressReader) Read(p []byte) (int, error) {
+	n, err := r.reader.Read(p)
+	atomic.AddInt64(&r.source.read, int64(n))
+	return n, err
+}
+
+type progressKey struct{}
+
+// InjectProgress makes the data sources of a query run with the returned context report their progress.
+func InjectProgress(ctx context.Context, progress *Progress) context.Context {
+	return context.WithValue(ctx, progressKey{}, progress)
+}
+
+// GetProgressFromContext returns the progress to report to, or a new one which nobody listens to if there's none.
+func GetProgressFromContext(ctx context.Context) *Progress {
+	progress, ok := ctx.Value(progressKey{}).(*Progress)
+	if !ok {
+		return NewProgress()
+	}
+	return progress
+}
@@ -0,0 +1,40 @@
+package execution
+
+import (
+	"context"
+	"io/ioutil"
+	"strings"
+	"testing"
+)
+
+func TestProgress(t *testing.T) {
+	progress := NewProgress()
+	ctx := InjectProgress(context.Background(), progress)
+
+	first := GetProgressFromContext(ctx).NewSource(10)
+	second := GetProgressFromContext(ctx).NewSource(20)
+
+	if _, err := ioutil.ReadAll(first.Reader(strings.NewReader("abcdefghij"))); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := ioutil.ReadAll(second.Reader(strings.NewReader("abcde"))); err != nil {
+		t.Fatal(err)
+	}
+
+	if got := progress.Read(); got != 15 {
+		t.Errorf("Progress.Read() = %v, want %v", got, 15)
+	}
+	if got := progress.Total(); got != 30 {
+		t.Errorf("Progress.Total() = %v, want %v", got, 30)
+	}
+
+	first.SetRead(0)
+	if got := progress.Read(); got != 5 {
+		t.Errorf("Progress.Read() after reset = %v, want %v", got, 5)
+	}
+
+	GetProgressFromContext(context.Background()).NewSource(100)
+	if got := progress.Total(); got != 30 {
+		t.Errorf("Progress.Total() after registering a source without progress in context = %v, want %v", got, 30)
+	}
+}
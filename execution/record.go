@@ -110,9 +110,8 @@ func (r *Record) Value(field octosql.VariableName) octosql.Value {
 		case "event_time_field":
 			eventTimeField := r.EventTimeField()
 			return octosql.MakeString(eventTimeField.String())
-		default:
-			return octosql.MakeNull()
 		}
+		// Other system fields, like sys.position, are stored with the data.
 	}
 
 	stringField := field.String()
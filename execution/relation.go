@@ -1,9 +1,11 @@
 package execution
 
 import (
+	"bytes"
 	"context"
 	"regexp"
 	"strings"
+	"sync"
 
 	"github.com/pkg/errors"
 
@@ -14,6 +16,12 @@ type Relation interface {
 	Apply(ctx context.Context, variables octosql.Variables, left, right Expression) (bool, error)
 }
 
+// threeValuedRelation is implemented by relations which can be unknown, instead of just false.
+// Predicates use it to get the unknown value, while Apply treats it as false.
+type threeValuedRelation interface {
+	applyThreeValued(ctx context.Context, variables octosql.Variables, left, right Expression) (octosql.Value, error)
+}
+
 type Equal struct {
 }
 
@@ -30,6 +38,8 @@ func (rel *Equal) Apply(ctx context.Context, variables octosql.Variables, left,
 	if err != nil {
 		return false, errors.Wrap(err, "couldn't get value of right operator in equal")
 	}
+	coerced := CoerceToCommonType(leftValue, rightValue)
+	leftValue, rightValue = coerced[0], coerced[1]
 
 	if leftValue.GetType() != rightValue.GetType() {
 		return false, errors.Errorf(
@@ -71,6 +81,9 @@ func (rel *MoreThan) Apply(ctx context.Context, variables octosql.Variables, lef
 	if err != nil {
 		return false, errors.Wrap(err, "couldn't get value of right operator in more than")
 	}
+	coerced := CoerceToCommonType(leftValue, rightValue)
+	leftValue, rightValue = coerced[0], coerced[1]
+
 	if leftValue.GetType() != rightValue.GetType() {
 		return false, errors.Errorf(
 			"invalid operands to more_than %v and %v with types %v and %v",
@@ -86,9 +99,15 @@ func (rel *MoreThan) Apply(ctx context.Context, variables octosql.Variables, lef
 		return leftValue.AsString() > rightValue.AsString(), nil
 	case octosql.TypeTime:
 		return leftValue.AsTime().After(rightValue.AsTime()), nil
-	case octosql.TypeNull, octosql.TypePhantom, octosql.TypeBool, octosql.TypeDuration, octosql.TypeTuple, octosql.TypeObject:
+	case octosql.TypeDuration:
+		return leftValue.AsDuration() > rightValue.AsDuration(), nil
+	case octosql.TypeDecimal:
+		return leftValue.AsDecimal().Cmp(rightValue.AsDecimal()) > 0, nil
+	case octosql.TypeIP:
+		return bytes.Compare(leftValue.GetIp(), rightValue.GetIp()) > 0, nil
+	case octosql.TypeNull, octosql.TypePhantom, octosql.TypeBool, octosql.TypeTuple, octosql.TypeObject, octosql.TypeUUID, octosql.TypeBytes:
 		return false, errors.Errorf(
-			"invalid operands to more_than %v and %v with types %v and %v, only int, float, string and time allowed",
+			"invalid operands to more_than %v and %v with types %v and %v, only int, float, decimal, string, time, duration and ip allowed",
 			leftValue.Show(), rightValue.Show(), leftValue.GetType(), rightValue.GetType())
 	}
 
@@ -143,12 +162,19 @@ func (rel *LessEqual) Apply(ctx context.Context, variables octosql.Variables, le
 }
 
 type Like struct {
+	caseInsensitive bool
+	patterns        patternCache
 }
 
 func NewLike() Relation {
 	return &Like{}
 }
 
+// NewILike creates a LIKE relation which ignores the case of the matched string and pattern.
+func NewILike() Relation {
+	return &Like{caseInsensitive: true}
+}
+
 const likeEscape = '\\'
 const likeAny = '_'
 const likeAll = '%'
@@ -173,16 +199,57 @@ func (rel *Like) Apply(ctx context.Context, variables octosql.Variables, left, r
 			leftValue.Show(), rightValue.Show(), leftValue.GetType(), rightValue.GetType())
 	}
 
-	patternString, err := likePatternToRegexp(rightValue.AsString())
+	pattern, err := rel.patterns.get(rightValue.AsString(), func(pattern string) (string, error) {
+		patternString, err := likePatternToRegexp(pattern)
+		if err != nil {
+			return "", errors.Wrapf(err, "couldn't transform LIKE pattern %v to regexp", pattern)
+		}
+		if rel.caseInsensitive {
+			patternString = "(?i)" + patternString
+		}
+		return patternString, nil
+	})
 	if err != nil {
-		return false, errors.Wrapf(err, "couldn't transform LIKE pattern %v to regexp", patternString)
+		return false, errors.Wrapf(err, "couldn't compile pattern in like relation %v", rightValue)
+	}
+
+	return pattern.MatchString(leftValue.AsString()), nil
+}
+
+// patternCache holds the last regexp compiled by a relation.
+// The pattern is usually a constant, so this way it's compiled once per query, instead of once per record.
+type patternCache struct {
+	mutex    sync.Mutex
+	pattern  string
+	compiled *regexp.Regexp
+}
+
+// get returns the compiled regexp for the given pattern, which is first transformed to regexp syntax, if needed.
+func (cache *patternCache) get(pattern string, toRegexp func(pattern string) (string, error)) (*regexp.Regexp, error) {
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	if cache.compiled != nil && cache.pattern == pattern {
+		return cache.compiled, nil
+	}
+
+	regexpString := pattern
+	if toRegexp != nil {
+		var err error
+		regexpString, err = toRegexp(pattern)
+		if err != nil {
+			return nil, err
+		}
 	}
 
-	match, err := regexp.MatchString(patternString, leftValue.AsString())
+	compiled, err := regexp.Compile(regexpString)
 	if err != nil {
-		return false, errors.Wrapf(err, "couldn't match string in like relation with pattern %v", rightValue)
+		return nil, errors.Wrapf(err, "couldn't compile regexp %v", regexpString)
 	}
-	return match, nil
+
+	cache.pattern = pattern
+	cache.compiled = compiled
+	return compiled, nil
 }
 
 //we assume that the escape character is '\'
@@ -242,7 +309,9 @@ func needsEscaping(r rune) bool {
 		r == ']' ||
 		r == '^' ||
 		r == '$' ||
-		r == '.'
+		r == '.' ||
+		r == '*' ||
+		r == '|'
 }
 
 type In struct {
@@ -253,28 +322,19 @@ func NewIn() Relation {
 }
 
 func (rel *In) Apply(ctx context.Context, variables octosql.Variables, left, right Expression) (bool, error) {
-	leftValue, err := left.ExpressionValue(ctx, variables)
+	result, err := applyIn(ctx, variables, left, right)
 	if err != nil {
-		return false, errors.Wrap(err, "couldn't get value of left operator in IN")
+		return false, err
 	}
-	rightValue, err := right.ExpressionValue(ctx, variables)
-	if err != nil {
-		return false, errors.Wrap(err, "couldn't get value of right operator in IN")
-	}
-
-	switch rightValue.GetType() {
-	case octosql.TypeTuple:
-		set := rightValue.AsSlice()
-		for i := range set {
-			if octosql.AreEqual(leftValue, set[i]) {
-				return true, nil
-			}
-		}
-		return false, nil
+	return result == inTrue, nil
+}
 
-	default:
-		return octosql.AreEqual(leftValue, rightValue), nil
+func (rel *In) applyThreeValued(ctx context.Context, variables octosql.Variables, left, right Expression) (octosql.Value, error) {
+	result, err := applyIn(ctx, variables, left, right)
+	if err != nil {
+		return octosql.ZeroValue(), err
 	}
+	return result.toValue(false), nil
 }
 
 type NotIn struct {
@@ -285,14 +345,86 @@ func NewNotIn() Relation {
 }
 
 func (rel *NotIn) Apply(ctx context.Context, variables octosql.Variables, left, right Expression) (bool, error) {
-	in, err := (*In).Apply(nil, ctx, variables, left, right)
+	result, err := applyIn(ctx, variables, left, right)
 	if err != nil {
 		return false, errors.Wrap(err, "couldn't check containment")
 	}
-	return !in, nil
+	return result == inFalse, nil
+}
+
+func (rel *NotIn) applyThreeValued(ctx context.Context, variables octosql.Variables, left, right Expression) (octosql.Value, error) {
+	result, err := applyIn(ctx, variables, left, right)
+	if err != nil {
+		return octosql.ZeroValue(), errors.Wrap(err, "couldn't check containment")
+	}
+	return result.toValue(true), nil
+}
+
+type inResult int
+
+const (
+	inFalse inResult = iota
+	inTrue
+	inUnknown
+)
+
+func (result inResult) toValue(negate bool) octosql.Value {
+	switch result {
+	case inTrue:
+		return octosql.MakeBool(!negate)
+	case inFalse:
+		return octosql.MakeBool(negate)
+	default:
+		return octosql.MakeNull()
+	}
+}
+
+// applyIn checks whether the left value is contained in the right value using three-valued logic.
+// If there's no match, but either the left value or any compared element is null, the result is unknown,
+// so neither IN nor NOT IN holds.
+func applyIn(ctx context.Context, variables octosql.Variables, left, right Expression) (inResult, error) {
+	leftValue, err := left.ExpressionValue(ctx, variables)
+	if err != nil {
+		return inFalse, errors.Wrap(err, "couldn't get value of left operator in IN")
+	}
+	// A subquery on the right side returns all its records, instead of a single value.
+	var rightValue octosql.Value
+	if subquery, ok := right.(*NodeExpression); ok {
+		rightValue, err = subquery.SetValue(ctx, variables)
+	} else {
+		rightValue, err = right.ExpressionValue(ctx, variables)
+	}
+	if err != nil {
+		return inFalse, errors.Wrap(err, "couldn't get value of right operator in IN")
+	}
+
+	if leftValue.GetType() == octosql.TypeNull {
+		return inUnknown, nil
+	}
+
+	var set []octosql.Value
+	switch rightValue.GetType() {
+	case octosql.TypeTuple:
+		set = rightValue.AsSlice()
+	default:
+		set = []octosql.Value{rightValue}
+	}
+
+	result := inFalse
+	for i := range set {
+		if set[i].GetType() == octosql.TypeNull {
+			result = inUnknown
+			continue
+		}
+		if octosql.AreEqual(leftValue, set[i]) {
+			return inTrue, nil
+		}
+	}
+	return result, nil
 }
 
 type Regexp struct {
+	patterns patternCache
 }
 
 func NewRegexp() Relation {
@@ -319,9 +451,9 @@ func (rel *Regexp) Apply(ctx context.Context, variables octosql.Variables, left,
 			leftValue.Show(), rightValue.Show(), leftValue.GetType(), rightValue.GetType())
 	}
 
-	match, err := regexp.MatchString(rightValue.AsString(), leftValue.AsString())
+	pattern, err := rel.patterns.get(rightValue.AsString(), nil)
 	if err != nil {
 		return false, errors.Wrapf(err, "couldn't match string in regexp relation with pattern %v", rightValue)
 	}
-	return match, nil
+	return pattern.MatchString(leftValue.AsString()), nil
 }
@@ -2,10 +2,12 @@ package execution
 
 import (
 	"context"
+	"net"
 	"testing"
 	"time"
 
 	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
 )
 
 func TestEqual_Apply(t *testing.T) {
@@ -48,7 +50,7 @@ func TestEqual_Apply(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "simple incompatible variable check",
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeInt(3),
@@ -57,6 +59,19 @@ func TestEqual_Apply(t *testing.T) {
 				left:  NewVariable("a"),
 				right: NewVariable("b"),
 			},
+			want:    true,
+			wantErr: false,
+		},
+		{
+			name: "simple incompatible variable check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeFloat(3.0),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
 			want:    false,
 			wantErr: true,
 		},
@@ -116,7 +131,7 @@ func TestNotEqual_Apply(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "simple incompatible variable check",
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeInt(3),
@@ -126,6 +141,19 @@ func TestNotEqual_Apply(t *testing.T) {
 				right: NewVariable("b"),
 			},
 			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "simple incompatible variable check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeFloat(3.0),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
 			wantErr: true,
 		},
 	}
@@ -210,7 +238,33 @@ func TestMoreThan_Apply(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "simple incompatible variable check",
+			name: "ip greater than ip address check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeIP(net.ParseIP("10.0.0.10")),
+					"b": octosql.MakeString("10.0.0.9"),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    true,
+			wantErr: false,
+		},
+		{
+			name: "duration greater than variable check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeDuration(time.Minute),
+					"b": octosql.MakeDuration(time.Second),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    true,
+			wantErr: false,
+		},
+		{
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeInt(3),
@@ -220,6 +274,19 @@ func TestMoreThan_Apply(t *testing.T) {
 				right: NewVariable("b"),
 			},
 			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "simple incompatible variable check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeFloat(3.0),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
 			wantErr: true,
 		},
 	}
@@ -315,7 +382,7 @@ func TestLessThan_Apply(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "simple incompatible variable check",
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeInt(3),
@@ -325,6 +392,19 @@ func TestLessThan_Apply(t *testing.T) {
 				right: NewVariable("b"),
 			},
 			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "simple incompatible variable check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeFloat(3.0),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
 			wantErr: true,
 		},
 	}
@@ -452,7 +532,7 @@ func TestGreaterEqual_Apply(t *testing.T) {
 		},
 
 		{
-			name: "simple incompatible variables check",
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeFloat(3.0),
@@ -461,6 +541,20 @@ func TestGreaterEqual_Apply(t *testing.T) {
 				left:  NewVariable("a"),
 				right: NewVariable("b"),
 			},
+			want:    true,
+			wantErr: false,
+		},
+
+		{
+			name: "simple incompatible variables check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeInt(3),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
 			want:    false,
 			wantErr: true,
 		},
@@ -579,7 +673,7 @@ func TestLessEqual_Apply(t *testing.T) {
 		},
 
 		{
-			name: "simple incompatible variables check",
+			name: "int and float are coerced",
 			args: args{
 				variables: map[octosql.VariableName]octosql.Value{
 					"a": octosql.MakeFloat(3.0),
@@ -588,6 +682,20 @@ func TestLessEqual_Apply(t *testing.T) {
 				left:  NewVariable("a"),
 				right: NewVariable("b"),
 			},
+			want:    true,
+			wantErr: false,
+		},
+
+		{
+			name: "simple incompatible variables check",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("3"),
+					"b": octosql.MakeInt(3),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
 			want:    false,
 			wantErr: true,
 		},
@@ -760,11 +868,72 @@ func TestLike_Apply(t *testing.T) {
 			want:    false,
 			wantErr: false,
 		},
+
+		{
+			name: "test11 - regexp special characters are literal",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("aab|c"),
+					"b": octosql.MakeString("a*b|c"),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+
+		{
+			name: "test12 - like is case sensitive",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("ABCD"),
+					"b": octosql.MakeString("ab%"),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+
+		{
+			name: "test13 - ilike",
+			rel:  NewILike().(*Like),
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("ABCD"),
+					"b": octosql.MakeString("ab_d"),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    true,
+			wantErr: false,
+		},
+
+		{
+			name: "test14 - ilike with no match",
+			rel:  NewILike().(*Like),
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("ABCD"),
+					"b": octosql.MakeString("b%"),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
 	}
 
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			rel := NewLike()
+			var rel Relation = NewLike()
+			if tt.rel != nil {
+				rel = tt.rel
+			}
 			got, err := rel.Apply(ctx, tt.args.variables, tt.args.left, tt.args.right)
 			if (err != nil) != tt.wantErr {
 				t.Errorf("Like.Apply() error = %v, wantErr %v", err, tt.wantErr)
@@ -779,6 +948,7 @@ func TestLike_Apply(t *testing.T) {
 
 func TestIn_Apply(t *testing.T) {
 	ctx := context.Background()
+	stateStorage := storage.GetTestStorage(t)
 	type args struct {
 		variables octosql.Variables
 		left      Expression
@@ -912,6 +1082,80 @@ func TestIn_Apply(t *testing.T) {
 			want:    true,
 			wantErr: false,
 		},
+		{
+			name: "in with null element",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("123123"),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeNull(),
+							octosql.MakeString("123123"),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    true,
+			wantErr: false,
+		},
+		{
+			name: "not in with null element",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("123123"),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeString("123124"),
+							octosql.MakeNull(),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "null in",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeNull(),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeString("123124"),
+							octosql.MakeNull(),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "in subquery",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeInt(5),
+				},
+				left: NewVariable("a"),
+				right: NewNodeExpression(
+					NewDummyNode(
+						[]*Record{
+							NewRecordFromSliceWithNormalize([]octosql.VariableName{"test"}, []interface{}{3}),
+							NewRecordFromSliceWithNormalize([]octosql.VariableName{"test"}, []interface{}{5}),
+						},
+					),
+					stateStorage,
+				),
+			},
+			want:    true,
+			wantErr: false,
+		},
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
@@ -1063,6 +1307,60 @@ func TestNotIn_Apply(t *testing.T) {
 			want:    false,
 			wantErr: false,
 		},
+		{
+			name: "in with null element",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("123123"),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeNull(),
+							octosql.MakeString("123123"),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "not in with null element",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeString("123123"),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeString("123124"),
+							octosql.MakeNull(),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
+		{
+			name: "null not in",
+			args: args{
+				variables: map[octosql.VariableName]octosql.Value{
+					"a": octosql.MakeNull(),
+					"b": octosql.MakeTuple(
+						[]octosql.Value{
+							octosql.MakeString("123124"),
+							octosql.MakeString("123123"),
+						},
+					),
+				},
+				left:  NewVariable("a"),
+				right: NewVariable("b"),
+			},
+			want:    false,
+			wantErr: false,
+		},
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
@@ -1151,7 +1449,7 @@ func TestRegexp_Apply(t *testing.T) {
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			rel := tt.rel
+			rel := NewRegexp()
 			got, err := rel.Apply(ctx, tt.args.variables, tt.args.left, tt.args.right)
 			if (err != nil) != tt.wantErr {
 				t.Errorf("Like.Apply() error = %v, wantErr %v", err, tt.wantErr)
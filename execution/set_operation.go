@@ -0,0 +1,239 @@
+package execution
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+type SetOperationType string
+
+const (
+	Intersect SetOperationType = "intersect"
+	Except    SetOperationType = "except"
+)
+
+// SetOperation outputs the distinct records of the left source which are (intersect) or aren't (except) present in the right source.
+// Records are matched by all their fields, so both sources have to use the same field names.
+type SetOperation struct {
+	leftSource, rightSource Node
+	operation               SetOperationType
+	storage                 storage.Storage
+	eventTimeField          octosql.VariableName
+}
+
+func NewSetOperation(leftSource, rightSource Node, operation SetOperationType, storage storage.Storage, eventTimeField octosql.VariableName) *SetOperation {
+	return &SetOperation{
+		leftSource:     leftSource,
+		rightSource:    rightSource,
+		operation:      operation,
+		storage:        storage,
+		eventTimeField: eventTimeField,
+	}
+}
+
+func (node *SetOperation) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+
+	leftSourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("left"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get left source stream ID")
+	}
+
+	rightSourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("right"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get right source stream ID")
+	}
+
+	leftStream, leftExec, err := node.leftSource.Get(ctx, variables, leftSourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrapf(err, "couldn't get left source stream in %s", node.operation)
+	}
+
+	rightStream, rightExec, err := node.rightSource.Get(ctx, variables, rightSourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrapf(err, "couldn't get right source stream in %s", node.operation)
+	}
+
+	watermarkSource := NewUnionWatermarkGenerator([]WatermarkSource{leftExec.WatermarkSource, rightExec.WatermarkSource})
+
+	mergedNextShuffles, err := mergeNextShuffles(leftExec.NextShuffles, rightExec.NextShuffles)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge next shuffles of sources")
+	}
+
+	// Same as in distinct, the output of a set operation is strongly related to the triggering of its sources.
+	trigger, err := NewCountingTrigger(NewConstantValue(octosql.MakeInt(1))).Get(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrapf(err, "couldn't create trigger for %s", node.operation)
+	}
+
+	stream := &SetOperationStream{
+		streamID:  streamID,
+		operation: node.operation,
+	}
+
+	processFunc := &ProcessByKey{
+		eventTimeField:  node.eventTimeField,
+		trigger:         trigger,
+		keyExpressions:  [][]Expression{{&RecordExpression{}}, {&RecordExpression{}}},
+		processFunction: stream,
+		variables:       variables,
+	}
+
+	setOperationPullEngine := NewPullEngine(processFunc, node.storage, []RecordStream{leftStream, rightStream}, streamID, watermarkSource, true, ctx)
+
+	return setOperationPullEngine,
+		NewExecutionOutput(
+			setOperationPullEngine,
+			mergedNextShuffles,
+			append(leftExec.TasksToRun, append(rightExec.TasksToRun, func() error { setOperationPullEngine.Run(); return nil })...),
+		), nil
+}
+
+type SetOperationStream struct {
+	streamID  *StreamID
+	operation SetOperationType
+}
+
+var setOperationLeftCountPrefix = []byte("$left_record_count$")
+var setOperationRightCountPrefix = []byte("$right_record_count$")
+var setOperationRecordPrefix = []byte("$record$")
+var setOperationTriggeredRecordPrefix = []byte("$triggered_record$")
+var setOperationTriggeredCountPrefix = []byte("$triggered_count$")
+
+func (s *SetOperationStream) AddRecord(ctx context.Context, tx storage.StateTransaction, inputIndex int, key octosql.Value, record *Record) error {
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	var recordCountState *storage.ValueState
+	switch inputIndex {
+	case 0:
+		recordCountState = storage.NewValueState(txByKey.WithPrefix(setOperationLeftCountPrefix))
+	case 1:
+		recordCountState = storage.NewValueState(txByKey.WithPrefix(setOperationRightCountPrefix))
+	default:
+		panic("only two input streams allowed for set operation")
+	}
+
+	recordCount, err := getSetOperationCount(recordCountState)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get current record count")
+	}
+
+	// Keep track of record vs retraction count
+	if !record.IsUndo() {
+		recordCount++
+	} else {
+		recordCount--
+	}
+
+	if recordCount != 0 {
+		recordCountValue := octosql.MakeInt(recordCount)
+		if err := recordCountState.Set(&recordCountValue); err != nil {
+			return errors.Wrap(err, "couldn't save record count")
+		}
+	} else {
+		if err := recordCountState.Clear(); err != nil {
+			return errors.Wrap(err, "couldn't clear record count")
+		}
+	}
+
+	// Only records of the left source are ever sent, so we keep the first one to send it in Trigger().
+	if inputIndex == 0 && !record.IsUndo() && recordCount == 1 {
+		recordState := storage.NewValueState(txByKey.WithPrefix(setOperationRecordPrefix))
+		if err := recordState.Set(NewRecordFromRecord(record, WithNoUndo())); err != nil {
+			return errors.Wrap(err, "couldn't save record")
+		}
+	}
+
+	return nil
+}
+
+func getSetOperationCount(state *storage.ValueState) (int, error) {
+	var count octosql.Value
+	err := state.Get(&count)
+	if err == storage.ErrNotFound {
+		return 0, nil
+	} else if err != nil {
+		return 0, err
+	}
+	return count.AsInt(), nil
+}
+
+func (s *SetOperationStream) Trigger(ctx context.Context, tx storage.StateTransaction, key octosql.Value) ([]*Record, error) {
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	leftCount, err := getSetOperationCount(storage.NewValueState(txByKey.WithPrefix(setOperationLeftCountPrefix)))
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get left record count")
+	}
+	rightCount, err := getSetOperationCount(storage.NewValueState(txByKey.WithPrefix(setOperationRightCountPrefix)))
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get right record count")
+	}
+
+	var isPresent bool
+	switch s.operation {
+	case Intersect:
+		isPresent = leftCount > 0 && rightCount > 0
+	case Except:
+		isPresent = leftCount > 0 && rightCount <= 0
+	default:
+		return nil, errors.Errorf("invalid set operation: %s", s.operation)
+	}
+
+	triggeredRecordState := storage.NewValueState(txByKey.WithPrefix(setOperationTriggeredRecordPrefix))
+	var triggeredRecord Record
+	err = triggeredRecordState.Get(&triggeredRecord)
+	wasTriggered := true
+	if err == storage.ErrNotFound {
+		wasTriggered = false
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't get previously triggered record")
+	}
+
+	var output *Record
+	if !wasTriggered && isPresent {
+		var record Record
+		recordState := storage.NewValueState(txByKey.WithPrefix(setOperationRecordPrefix))
+		if err := recordState.Get(&record); err != nil {
+			return nil, errors.Wrap(err, "couldn't get record")
+		}
+
+		if err := triggeredRecordState.Set(&record); err != nil {
+			return nil, errors.Wrap(err, "couldn't save triggered record")
+		}
+
+		output = &record
+	} else if wasTriggered && !isPresent {
+		if err := triggeredRecordState.Clear(); err != nil {
+			return nil, errors.Wrap(err, "couldn't clear triggered record")
+		}
+
+		output = NewRecordFromRecord(&triggeredRecord, WithUndo())
+	} else {
+		// The output for this key didn't change.
+		return nil, nil
+	}
+
+	// Assign an ID to the outgoing record
+	triggeredCountState := storage.NewValueState(tx.WithPrefix(setOperationTriggeredCountPrefix))
+	triggeredCount, err := getSetOperationCount(triggeredCountState)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get count of previously triggered records")
+	}
+
+	WithID(NewRecordIDFromStreamIDWithOffset(s.streamID, triggeredCount))(output)
+
+	newTriggeredCount := octosql.MakeInt(triggeredCount + 1)
+	if err := triggeredCountState.Set(&newTriggeredCount); err != nil {
+		return nil, errors.Wrap(err, "couldn't update count of previously triggered records")
+	}
+
+	return []*Record{output}, nil
+}
@@ -0,0 +1,69 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestSetOperation(t *testing.T) {
+	fieldNames := []octosql.VariableName{"t.a", "t.b"}
+
+	left := []*Record{
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"a", 1}, WithID(NewRecordID("id1"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"a", 1}, WithID(NewRecordID("id2"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"b", 2}, WithID(NewRecordID("id3"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"c", 3}, WithID(NewRecordID("id4"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"d", 4}, WithID(NewRecordID("id5"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"d", 4}, WithUndo(), WithID(NewRecordID("id6"))),
+	}
+	right := []*Record{
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"a", 1}, WithID(NewRecordID("id7"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"c", 3}, WithID(NewRecordID("id8"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"d", 4}, WithID(NewRecordID("id9"))),
+		NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"e", 5}, WithID(NewRecordID("id10"))),
+	}
+
+	tests := []struct {
+		name      string
+		operation SetOperationType
+		want      Node
+	}{
+		{
+			name:      "intersect",
+			operation: Intersect,
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"a", 1}),
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"c", 3}),
+			}),
+		},
+		{
+			name:      "except",
+			operation: Except,
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(fieldNames, []interface{}{"b", 2}),
+			}),
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+			setOperation := NewSetOperation(NewDummyNode(left), NewDummyNode(right), tt.operation, stateStorage, "")
+
+			stream, _, err := GetAndStartAllShuffles(context.Background(), stateStorage, GetRawStreamID(), []Node{setOperation}, octosql.NoVariables())
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			want := GetTestStream(t, stateStorage, octosql.NoVariables(), tt.want)
+
+			err = AreStreamsEqualNoOrderingWithRetractionReductionAndIDChecking(context.Background(), stateStorage, stream[0], want, WithEqualityBasedOn(EqualityOfFieldsAndValues))
+			if err != nil {
+				t.Errorf("Streams aren't equal: %v", err)
+			}
+		})
+	}
+}
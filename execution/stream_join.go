@@ -16,6 +16,8 @@ const (
 	INNER_JOIN JoinType = 0
 	LEFT_JOIN  JoinType = 1
 	OUTER_JOIN JoinType = 2
+	SEMI_JOIN  JoinType = 3
+	ANTI_JOIN  JoinType = 4
 )
 
 func (j JoinType) String() string {
@@ -26,6 +28,10 @@ func (j JoinType) String() string {
 		return "Left Join"
 	case OUTER_JOIN:
 		return "Outer Join"
+	case SEMI_JOIN:
+		return "Semi Join"
+	case ANTI_JOIN:
+		return "Anti Join"
 	default:
 		return "Unknown Join"
 	}
@@ -137,6 +143,12 @@ func (js *JoinedStream) AddRecord(ctx context.Context, tx storage.StateTransacti
 	isLeft := inputIndex == 0       // from which input source the record was received
 	isRetraction := record.IsUndo() // is the incoming record a retraction
 
+	// NULL doesn't equal anything, so right records with a NULL in the key can't ever match in a semi or anti join.
+	// Left records with a NULL in the key then never have a match, so they're left out by semi joins and kept by anti joins.
+	if !isLeft && (js.joinType == SEMI_JOIN || js.joinType == ANTI_JOIN) && keyHasNull(key) {
+		return nil
+	}
+
 	// We get the key of the new incoming record
 	WithNoUndo()(record) // reset the potential retraction information, since we want a record and its retraction "to find each other"
 	newRecordValue := recordToValue(record)
@@ -200,6 +212,15 @@ func (js *JoinedStream) AddRecord(ctx context.Context, tx storage.StateTransacti
 	return nil
 }
 
+func keyHasNull(key octosql.Value) bool {
+	for _, value := range key.AsSlice() {
+		if value.GetType() == octosql.TypeNull {
+			return true
+		}
+	}
+	return false
+}
+
 func keepTrackOfCount(tx storage.StateTransaction, isRetraction bool) (int, error) {
 	var recordCount octosql.Value
 	recordCountState := storage.NewValueState(tx)
@@ -240,7 +261,7 @@ func (js *JoinedStream) Trigger(ctx context.Context, tx storage.StateTransaction
 	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
 	txByKey := tx.WithPrefix(keyPrefix)
 
-	addLeftRecords := js.joinType == LEFT_JOIN || js.joinType == OUTER_JOIN
+	addLeftRecords := js.joinType == LEFT_JOIN || js.joinType == OUTER_JOIN || js.joinType == ANTI_JOIN
 	addRightRecords := js.joinType == OUTER_JOIN
 
 	// Read the number of records already triggered from this JoinedStream. These will be used in assigning IDs to new records
@@ -285,29 +306,56 @@ func (js *JoinedStream) Trigger(ctx context.Context, tx storage.StateTransaction
 
 	var allRecordsToTrigger []*Record
 
-	// First we merge new left records with both old and new right records
-	leftMergedWithAllRights := createPairsOfRecords(newLeftRecords, append(oldRightRecords, newRightRecords...), baseOffset, js.streamID, js.eventTimeField)
-	allRecordsToTrigger = append(allRecordsToTrigger, leftMergedWithAllRights...)
-	baseOffset += len(leftMergedWithAllRights)
+	// Semi and anti joins only ever output left records, so there are no pairs to create
+	if js.joinType != SEMI_JOIN && js.joinType != ANTI_JOIN {
+		// First we merge new left records with both old and new right records
+		leftMergedWithAllRights := createPairsOfRecords(newLeftRecords, append(oldRightRecords, newRightRecords...), baseOffset, js.streamID, js.eventTimeField)
+		allRecordsToTrigger = append(allRecordsToTrigger, leftMergedWithAllRights...)
+		baseOffset += len(leftMergedWithAllRights)
+
+		// Now we merge new right records with old left records. In this way we now have oldLeft x newRight, newLeft x oldRight and newLeft x newRight, which
+		// is exactly what we want, since we basically want to get (allLeft x allRight) - (oldLeft x oldRight), since these records were already triggered
+		newRightsMergedWithOldLefts := createPairsOfRecords(oldLeftRecords, newRightRecords, baseOffset, js.streamID, js.eventTimeField)
+		allRecordsToTrigger = append(allRecordsToTrigger, newRightsMergedWithOldLefts...)
+		baseOffset += len(newRightsMergedWithOldLefts)
+	}
+
+	// All records with the same key match each other, so a record is unmatched exactly when the other side is empty for this key
+	leftRecords, err := applyRecords(oldLeftRecords, newLeftRecords)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't apply new records to old records from the left stream")
+	}
+	rightRecords, err := applyRecords(oldRightRecords, newRightRecords)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't apply new records to old records from the right stream")
+	}
+	hadLeftRecords := len(oldLeftRecords) > 0
+	hasLeftRecords := len(leftRecords) > 0
+	hadRightRecords := len(oldRightRecords) > 0
+	hasRightRecords := len(rightRecords) > 0
 
-	// Now we merge new right records with old left records. In this way we now have oldLeft x newRight, newLeft x oldRight and newLeft x newRight, which
-	// is exactly what we want, since we basically want to get (allLeft x allRight) - (oldLeft x oldRight), since these records were already triggered
-	newRightsMergedWithOldLefts := createPairsOfRecords(oldLeftRecords, newRightRecords, baseOffset, js.streamID, js.eventTimeField)
-	allRecordsToTrigger = append(allRecordsToTrigger, newRightsMergedWithOldLefts...)
-	baseOffset += len(newRightsMergedWithOldLefts)
+	// In a semi join we add the matched left records, each of them only once, regardless of the number of matches
+	if js.joinType == SEMI_JOIN {
+		matchedLeftRecords := matchedRecordsToTrigger(oldLeftRecords, newLeftRecords, leftRecords, hadRightRecords, hasRightRecords)
+		leftRecordsRenamed := renameRecords(matchedLeftRecords, baseOffset, js.streamID)
+		allRecordsToTrigger = append(allRecordsToTrigger, leftRecordsRenamed...)
+		baseOffset += len(matchedLeftRecords)
+	}
 
-	// If we are adding single left records (LEFT or OUTER join) we add them and update the offset
+	// If we are adding unmatched left records (LEFT, OUTER or ANTI join) we add them and update the offset
 	if addLeftRecords {
-		leftRecordsRenamed := renameRecords(newLeftRecords, baseOffset, js.streamID)
+		unmatchedLeftRecords := unmatchedRecordsToTrigger(oldLeftRecords, newLeftRecords, leftRecords, hadRightRecords, hasRightRecords)
+		leftRecordsRenamed := renameRecords(unmatchedLeftRecords, baseOffset, js.streamID)
 		allRecordsToTrigger = append(allRecordsToTrigger, leftRecordsRenamed...)
-		baseOffset += len(newLeftRecords)
+		baseOffset += len(unmatchedLeftRecords)
 	}
 
 	// Same goes for right records (in OUTER join)
 	if addRightRecords {
-		rightRecordsRenamed := renameRecords(newRightRecords, baseOffset, js.streamID)
+		unmatchedRightRecords := unmatchedRecordsToTrigger(oldRightRecords, newRightRecords, rightRecords, hadLeftRecords, hasLeftRecords)
+		rightRecordsRenamed := renameRecords(unmatchedRightRecords, baseOffset, js.streamID)
 		allRecordsToTrigger = append(allRecordsToTrigger, rightRecordsRenamed...)
-		baseOffset += len(newRightRecords)
+		baseOffset += len(unmatchedRightRecords)
 	}
 
 	// Now we have to handle merging newRecords and oldRecords. Basically we move records from the new set to the old one,
@@ -435,6 +483,82 @@ func mergeRecords(left, right *Record, ID *RecordID, isUndo bool, eventTimeField
 	return NewRecordFromSlice(mergedFieldNames, mergedData, WithID(ID), WithNoUndo(), WithEventTimeField(eventTimeField))
 }
 
+// applyRecords returns the records present after applying the new records (and retractions) to the old ones.
+// A retraction only cancels a copy of the same record, so the counts are kept for each distinct record.
+func applyRecords(oldRecords, newRecords []*Record) ([]*Record, error) {
+	var keys []string
+	counts := make(map[string]int)
+	records := make(map[string]*Record)
+
+	for _, record := range append(append([]*Record{}, oldRecords...), newRecords...) {
+		isRetraction := record.IsUndo()
+		value := recordToValue(NewRecordFromRecord(record, WithNoUndo()))
+		marshaled, err := proto.Marshal(&value)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't marshal record")
+		}
+		key := string(marshaled)
+
+		if _, ok := counts[key]; !ok {
+			keys = append(keys, key)
+		}
+		if isRetraction {
+			counts[key]--
+		} else {
+			counts[key]++
+			records[key] = record
+		}
+	}
+
+	var out []*Record
+	for _, key := range keys {
+		for i := 0; i < counts[key]; i++ {
+			out = append(out, records[key])
+		}
+	}
+
+	return out, nil
+}
+
+// unmatchedRecordsToTrigger returns the records which have to be triggered on their own in an outer join,
+// given whether the other stream had matching records before this trigger and whether it has them now.
+// Unmatched records which got a match are retracted, and if all matches were retracted the remaining records are triggered again.
+func unmatchedRecordsToTrigger(oldRecords, newRecords, records []*Record, hadMatches, hasMatches bool) []*Record {
+	switch {
+	case !hadMatches && !hasMatches:
+		return newRecords
+	case !hadMatches && hasMatches:
+		retractions := make([]*Record, len(oldRecords))
+		for i := range oldRecords {
+			retractions[i] = NewRecordFromRecord(oldRecords[i], WithUndo())
+		}
+		return retractions
+	case hadMatches && !hasMatches:
+		return records
+	default:
+		return nil
+	}
+}
+
+// matchedRecordsToTrigger is the counterpart of unmatchedRecordsToTrigger for semi joins.
+// Records which got their first match are triggered, and if all matches were retracted the records are retracted.
+func matchedRecordsToTrigger(oldRecords, newRecords, records []*Record, hadMatches, hasMatches bool) []*Record {
+	switch {
+	case !hadMatches && hasMatches:
+		return records
+	case hadMatches && !hasMatches:
+		retractions := make([]*Record, len(oldRecords))
+		for i := range oldRecords {
+			retractions[i] = NewRecordFromRecord(oldRecords[i], WithUndo())
+		}
+		return retractions
+	case hadMatches && hasMatches:
+		return newRecords
+	default:
+		return nil
+	}
+}
+
 func renameRecords(records []*Record, baseOffset int, streamID *StreamID) []*Record {
 	newRecords := make([]*Record, len(records))
 
@@ -100,10 +100,14 @@ func TestStreamJoin(t *testing.T) {
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"a", 1, "a", 10}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"a", 1, "a", 11}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"b", 2, "b", 12}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}),
+				// unmatched records are triggered until a match arrives, and retracted once it does
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}),
-				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}, WithUndo()),
 			}),
 
 			executionCount: 16, // as above
@@ -139,19 +143,144 @@ func TestStreamJoin(t *testing.T) {
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"a", 1, "a", 10}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"a", 1, "a", 11}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"b", 2, "b", 12}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"f", 12}),
+				// unmatched records are triggered until a match arrives, and retracted once it does
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}),
-				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}, WithUndo()),
 				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithUndo()),
 				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 11}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 11}, WithUndo()),
 				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 12}),
-				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"f", 12}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 12}, WithUndo()),
+			}),
+
+			executionCount: 16, // as above
+			triggerValues:  []int{1, 2, 3, 4},
+		},
+		{
+			name: "semi join 1 - no event time field, no retractions",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}, WithID(NewRecordID("id2"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}, WithID(NewRecordID("id3"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}, WithID(NewRecordID("id7"))),
+				}),
+				leftKey: []Expression{NewVariable("left.a")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithID(NewRecordID("id4"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 11}, WithID(NewRecordID("id5"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 12}, WithID(NewRecordID("id6"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"f", 12}, WithID(NewRecordID("id8"))),
+				}),
+				rightKey: []Expression{NewVariable("right.a")},
+
+				joinType:       SEMI_JOIN,
+				eventTimeField: "",
+				variables:      octosql.NoVariables(),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}),
+			}),
+
+			executionCount: 16, // as above
+			triggerValues:  []int{1, 2, 3, 4},
+		},
+		{
+			name: "anti join 1 - no event time field, no retractions",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}, WithID(NewRecordID("id2"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 2}, WithID(NewRecordID("id3"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}, WithID(NewRecordID("id7"))),
+				}),
+				leftKey: []Expression{NewVariable("left.a")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithID(NewRecordID("id4"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 11}, WithID(NewRecordID("id5"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 12}, WithID(NewRecordID("id6"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"f", 12}, WithID(NewRecordID("id8"))),
+				}),
+				rightKey: []Expression{NewVariable("right.a")},
+
+				joinType:       ANTI_JOIN,
+				eventTimeField: "",
+				variables:      octosql.NoVariables(),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"e", 2}),
 			}),
 
 			executionCount: 16, // as above
 			triggerValues:  []int{1, 2, 3, 4},
 		},
+		{
+			name: "semi join 2 - null keys",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{nil, 1}, WithID(NewRecordID("id2"))),
+				}),
+				leftKey: []Expression{NewVariable("left.a")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithID(NewRecordID("id3"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{nil, 11}, WithID(NewRecordID("id4"))),
+				}),
+				rightKey: []Expression{NewVariable("right.a")},
+
+				joinType:       SEMI_JOIN,
+				eventTimeField: "",
+				variables:      octosql.NoVariables(),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}),
+			}),
+
+			executionCount: 8,
+			triggerValues:  []int{1, 2, 3, 4},
+		},
+		{
+			name: "anti join 2 - null keys",
+			fields: fields{
+				leftSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithID(NewRecordID("id1"))),
+					NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{nil, 1}, WithID(NewRecordID("id2"))),
+				}),
+				leftKey: []Expression{NewVariable("left.a")},
+
+				rightSource: NewDummyNode([]*Record{
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithID(NewRecordID("id3"))),
+					NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{nil, 11}, WithID(NewRecordID("id4"))),
+				}),
+				rightKey: []Expression{NewVariable("right.a")},
+
+				joinType:       ANTI_JOIN,
+				eventTimeField: "",
+				variables:      octosql.NoVariables(),
+			},
+
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{nil, 1}),
+			}),
+
+			executionCount: 8,
+			triggerValues:  []int{1, 2, 3, 4},
+		},
 		{
 			name: "inner join 2 - no event time field, retractions",
 			fields: fields{
@@ -227,15 +356,22 @@ func TestStreamJoin(t *testing.T) {
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"a", 1, "a", 10}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"b", 0, "b", 10}),
 				NewRecordFromSliceWithNormalize(concatFieldNames1, []interface{}{"b", 0, "b", 10}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"c", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"c", 0}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"d", 10}),
+				// unmatched records are triggered until a match arrives, and retracted once it does
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 0}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"a", 1}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 0}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 0}),
-				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"c", 0}),
-				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"c", 0}),
+				NewRecordFromSliceWithNormalize(leftFieldNames1, []interface{}{"b", 0}, WithUndo()),
 				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"a", 10}, WithUndo()),
 				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 10}),
-				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"d", 10}),
+				NewRecordFromSliceWithNormalize(rightFieldNames1, []interface{}{"b", 10}, WithUndo()),
 			}),
 
 			executionCount: 96, // there is a TON of possibilities, didn't even bother counting
@@ -289,15 +425,18 @@ func TestStreamJoin(t *testing.T) {
 			},
 
 			want: NewDummyNode([]*Record{
-				// single records
+				// unmatched records
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Warsaw", 22, dogPerson}),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Berlin", 19, dogPerson}),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Berlin", 19, dogPerson}),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Berlin", 24, catPerson}),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Berlin", 24, catPerson}),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Berlin", 24, catPerson}),
+				// unmatched records are triggered until a match arrives, and retracted once it does
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Warsaw", 22, catPerson}),
+				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Warsaw", 22, catPerson}, WithUndo()),
 				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Warsaw", 42, catPerson}),
+				NewRecordFromSliceWithNormalize(leftFieldNames2, []interface{}{"Warsaw", 42, catPerson}, WithUndo()),
 				// matches
 				NewRecordFromSliceWithNormalize(concatFieldNames2, []interface{}{"Warsaw", 22, catPerson, "Warsaw", 20, dogPerson}),
 				NewRecordFromSliceWithNormalize(concatFieldNames2, []interface{}{"Warsaw", 22, catPerson, "Warsaw", 20, dogPerson}),
@@ -341,3 +480,81 @@ func TestStreamJoin(t *testing.T) {
 	}
 
 }
+
+func TestJoinedStream_RetractsNullPaddedRecords(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	ctx := storage.InjectStateTransaction(context.Background(), tx)
+
+	leftFieldNames := []octosql.VariableName{"left.a", "left.b"}
+	rightFieldNames := []octosql.VariableName{"right.a", "right.b"}
+	concatFieldNames := append(leftFieldNames, rightFieldNames...)
+
+	stream := &JoinedStream{
+		streamID: GetRawStreamID(),
+		joinType: OUTER_JOIN,
+	}
+	key := octosql.MakeTuple([]octosql.Value{octosql.MakeString("a")})
+
+	steps := []struct {
+		inputIndex int
+		record     *Record
+		want       []*Record
+	}{
+		{
+			// No match yet, so the left record is triggered padded with nulls.
+			inputIndex: 0,
+			record:     NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}),
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}),
+			},
+		},
+		{
+			// A match appears, so the null-padded record gets retracted.
+			inputIndex: 1,
+			record:     NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", 10}),
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", 0, "a", 10}),
+				NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}, WithUndo()),
+			},
+		},
+		{
+			// The match is retracted, so the left record is unmatched again.
+			inputIndex: 1,
+			record:     NewRecordFromSliceWithNormalize(rightFieldNames, []interface{}{"a", 10}, WithUndo()),
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", 0, "a", 10}, WithUndo()),
+				NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}),
+			},
+		},
+		{
+			// Retracting the left record retracts its null-padded version.
+			inputIndex: 0,
+			record:     NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}, WithUndo()),
+			want: []*Record{
+				NewRecordFromSliceWithNormalize(leftFieldNames, []interface{}{"a", 0}, WithUndo()),
+			},
+		},
+	}
+
+	equality := EqualityOfAll(EqualityOfFieldsAndValues, EqualityOfUndo)
+	for i, step := range steps {
+		if err := stream.AddRecord(ctx, tx, step.inputIndex, key, step.record); err != nil {
+			t.Fatal(err)
+		}
+		got, err := stream.Trigger(ctx, tx, key)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		if len(got) != len(step.want) {
+			t.Fatalf("step %d: got %d records, want %d", i, len(got), len(step.want))
+		}
+		for j := range got {
+			if err := equality(got[j], step.want[j]); err != nil {
+				t.Errorf("step %d: record %d: %v", i, j, err)
+			}
+		}
+	}
+}
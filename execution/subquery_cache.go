@@ -0,0 +1,96 @@
+package execution
+
+import (
+	"container/list"
+	"context"
+	"sync"
+)
+
+// maxSubqueryCacheSize is the maximum number of subquery results kept during a run of a query.
+// The least recently used ones are evicted first.
+const maxSubqueryCacheSize = 1024
+
+type subqueryCacheKey struct {
+	expression *NodeExpression
+	variables  string
+}
+
+type subqueryCacheEntry struct {
+	key     subqueryCacheKey
+	records []*Record
+	size    int64
+}
+
+// subqueryCache keeps the records of memoized subqueries during a single run of a query,
+// so that they're never reused by later runs, which may see different data.
+// The records are reserved in the memory pool of the query, and the least recently used ones
+// are evicted to make room for new ones when it's full.
+type subqueryCache struct {
+	mutex   sync.Mutex
+	entries map[subqueryCacheKey]*list.Element
+	lru     *list.List
+	pool    *MemoryPool
+}
+
+type subqueryCacheCtxKey struct{}
+
+// WithSubqueryCache returns a context in which memoized subqueries cache their results.
+// It should be created for each run of a query, after its memory pool.
+// Without it, subqueries are executed for each record.
+func WithSubqueryCache(ctx context.Context) context.Context {
+	return context.WithValue(ctx, subqueryCacheCtxKey{}, &subqueryCache{
+		entries: make(map[subqueryCacheKey]*list.Element),
+		lru:     list.New(),
+		pool:    GetMemoryPool(ctx),
+	})
+}
+
+func getSubqueryCache(ctx context.Context) *subqueryCache {
+	cache, _ := ctx.Value(subqueryCacheCtxKey{}).(*subqueryCache)
+	return cache
+}
+
+func (cache *subqueryCache) get(key subqueryCacheKey) ([]*Record, bool) {
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	elem, ok := cache.entries[key]
+	if !ok {
+		return nil, false
+	}
+	cache.lru.MoveToFront(elem)
+	return elem.Value.(*subqueryCacheEntry).records, true
+}
+
+func (cache *subqueryCache) put(key subqueryCacheKey, records []*Record) {
+	cache.mutex.Lock()
+	defer cache.mutex.Unlock()
+
+	if elem, ok := cache.entries[key]; ok {
+		cache.lru.MoveToFront(elem)
+		return
+	}
+
+	// Results which don't fit in the memory pool even after evicting all the others aren't cached,
+	// so the subquery gets executed again the next time it's needed.
+	size := RecordsSize(records)
+	for cache.pool.Reserve(size) != nil {
+		if cache.lru.Len() == 0 {
+			return
+		}
+		cache.evictOldest()
+	}
+	cache.entries[key] = cache.lru.PushFront(&subqueryCacheEntry{key: key, records: records, size: size})
+
+	for cache.lru.Len() > maxSubqueryCacheSize {
+		cache.evictOldest()
+	}
+}
+
+func (cache *subqueryCache) evictOldest() {
+	oldest := cache.lru.Back()
+	cache.lru.Remove(oldest)
+	entry := oldest.Value.(*subqueryCacheEntry)
+	delete(cache.entries, entry.key)
+	cache.pool.Release(entry.size)
+}
@@ -0,0 +1,82 @@
+package execution
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/cube2222/octosql"
+)
+
+func TestSubqueryCache(t *testing.T) {
+	if getSubqueryCache(context.Background()) != nil {
+		t.Fatal("expected no subquery cache outside of a run")
+	}
+	cache := getSubqueryCache(WithSubqueryCache(context.Background()))
+
+	expression := &NodeExpression{}
+	key := func(i int) subqueryCacheKey {
+		return subqueryCacheKey{expression: expression, variables: fmt.Sprint(i)}
+	}
+
+	for i := 0; i < maxSubqueryCacheSize; i++ {
+		cache.put(key(i), []*Record{})
+	}
+	// Using the first entry makes the second one the least recently used.
+	if _, ok := cache.get(key(0)); !ok {
+		t.Fatal("expected first entry to be cached")
+	}
+	cache.put(key(maxSubqueryCacheSize), []*Record{})
+
+	if _, ok := cache.get(key(1)); ok {
+		t.Error("expected least recently used entry to be evicted")
+	}
+	for _, i := range []int{0, 2, maxSubqueryCacheSize} {
+		if _, ok := cache.get(key(i)); !ok {
+			t.Errorf("expected entry %d to be cached", i)
+		}
+	}
+	if _, ok := cache.get(subqueryCacheKey{expression: &NodeExpression{}, variables: "0"}); ok {
+		t.Error("expected entries of other expressions not to be shared")
+	}
+}
+
+func TestSubqueryCache_MemoryPool(t *testing.T) {
+	records := []*Record{
+		NewRecordFromSlice([]octosql.VariableName{"a"}, []octosql.Value{octosql.MakeString("ala ma kota")}),
+	}
+	size := RecordsSize(records)
+
+	// The pool fits the results of two keys.
+	pool := NewMemoryPool(2*size + 1)
+	cache := getSubqueryCache(WithSubqueryCache(WithMemoryPool(context.Background(), pool)))
+
+	expression := &NodeExpression{}
+	key := func(i int) subqueryCacheKey {
+		return subqueryCacheKey{expression: expression, variables: fmt.Sprint(i)}
+	}
+
+	cache.put(key(0), records)
+	cache.put(key(1), records)
+	cache.put(key(2), records)
+	if _, ok := cache.get(key(0)); ok {
+		t.Error("expected least recently used entry to be evicted to make room")
+	}
+	for _, i := range []int{1, 2} {
+		if _, ok := cache.get(key(i)); !ok {
+			t.Errorf("expected entry %d to be cached", i)
+		}
+	}
+	if got := pool.Used(); got != 2*size {
+		t.Errorf("Used() = %v, want %v", got, 2*size)
+	}
+
+	// A result larger than the whole pool isn't cached, and the others get evicted trying to make room for it.
+	cache.put(key(3), append(append(append([]*Record{}, records...), records...), records...))
+	if _, ok := cache.get(key(3)); ok {
+		t.Error("expected result larger than the memory pool not to be cached")
+	}
+	if got := pool.Used(); got != 0 {
+		t.Errorf("Used() = %v, want %v", got, 0)
+	}
+}
@@ -0,0 +1,487 @@
+package execution
+
+import (
+	"context"
+	"log"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution/trigger"
+	"github.com/cube2222/octosql/storage"
+)
+
+// TemporalJoin joins a stream with the versions of a table, as sent by the refresh table valued function.
+// A record of the stream is matched with the records of the table with the same key, which were valid at its event time,
+// so from their valid_from, inclusive, until their valid_to, exclusive. A NULL valid_from or valid_to is unbounded.
+// Without an event time field, records of the stream are matched with the current records of the table instead.
+type TemporalJoin struct {
+	source, table                Node
+	sourceKey, tableKey          []Expression
+	validFromField, validToField octosql.VariableName
+	isLeftJoin                   bool
+	storage                      storage.Storage
+	eventTimeField               octosql.VariableName
+}
+
+func NewTemporalJoin(source, table Node, sourceKey, tableKey []Expression, validFromField, validToField octosql.VariableName, isLeftJoin bool, storage storage.Storage, eventTimeField octosql.VariableName) *TemporalJoin {
+	return &TemporalJoin{
+		source:         source,
+		table:          table,
+		sourceKey:      sourceKey,
+		tableKey:       tableKey,
+		validFromField: validFromField,
+		validToField:   validToField,
+		isLeftJoin:     isLeftJoin,
+		storage:        storage,
+		eventTimeField: eventTimeField,
+	}
+}
+
+func (node *TemporalJoin) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+
+	sourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("left"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	tableStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakeString("right"))
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get table stream ID")
+	}
+
+	sourceStream, sourceExec, err := node.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream in temporal join")
+	}
+
+	tableStream, tableExec, err := node.table.Get(ctx, variables, tableStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get table stream in temporal join")
+	}
+
+	// The source of watermarks for a temporal join is the minimum of watermarks from its sources
+	watermarkSource := NewUnionWatermarkGenerator([]WatermarkSource{sourceExec.WatermarkSource, tableExec.WatermarkSource})
+
+	mergedNextShuffles, err := mergeNextShuffles(sourceExec.NextShuffles, tableExec.NextShuffles)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge next shuffles of sources")
+	}
+
+	stream := &TemporalJoinedStream{
+		streamID:       streamID,
+		validFromField: node.validFromField,
+		validToField:   node.validToField,
+		isLeftJoin:     node.isLeftJoin,
+		eventTimeField: node.eventTimeField,
+	}
+
+	// Matches are created when a record is added, so every key is triggered right after receiving a record.
+	processFunc := &ProcessByKey{
+		trigger:         trigger.NewCountingTrigger(1),
+		keyExpressions:  [][]Expression{node.sourceKey, node.tableKey},
+		processFunction: stream,
+		variables:       variables,
+	}
+
+	temporalJoinPullEngine := NewPullEngine(processFunc, node.storage, []RecordStream{sourceStream, tableStream}, streamID, watermarkSource, true, ctx)
+
+	return temporalJoinPullEngine,
+		NewExecutionOutput(
+			temporalJoinPullEngine,
+			mergedNextShuffles,
+			append(sourceExec.TasksToRun, append(tableExec.TasksToRun, func() error { temporalJoinPullEngine.Run(); return nil })...),
+		), nil
+}
+
+type TemporalJoinedStream struct {
+	streamID                     *StreamID
+	validFromField, validToField octosql.VariableName
+	isLeftJoin                   bool
+	eventTimeField               octosql.VariableName
+}
+
+// The records of the stream are sorted by their event time, and the versions of the table which stopped being valid by the end of their validity,
+// so that the records matching a new record are read with a range scan, and the records expire in the order they're sorted in.
+// The current versions of the table are kept separately, as they don't expire.
+var temporalJoinSourceRecordsPrefix = []byte("$temporal_join_source_records$")
+var temporalJoinTableRecordsPrefix = []byte("$temporal_join_table_records$")
+var temporalJoinTableVersionsPrefix = []byte("$temporal_join_table_versions$")
+var temporalJoinPendingRecordsPrefix = []byte("$temporal_join_pending_records$")
+var temporalJoinExpiryPrefix = []byte("$temporal_join_expiry$")
+var temporalJoinOutputCountPrefix = []byte("$temporal_join_output_count$")
+
+// AddRecord matches the record with the stored records of the other input right away.
+// The records of the stream are only stored if they have an event time, as otherwise later versions of the table don't apply to them.
+// The matches are kept in storage until the key gets triggered.
+func (js *TemporalJoinedStream) AddRecord(ctx context.Context, tx storage.StateTransaction, inputIndex int, key octosql.Value, record *Record) error {
+	if inputIndex < 0 || inputIndex > 1 {
+		panic("invalid inputIndex for temporal join")
+	}
+
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	var err error
+	if inputIndex == 0 {
+		err = js.addSourceRecord(txByKey, record)
+	} else {
+		err = js.addTableRecord(txByKey, record)
+	}
+	if err != nil {
+		return err
+	}
+
+	if js.eventTimeField.Empty() {
+		return nil
+	}
+
+	if err := js.updateExpiry(tx, txByKey, key); err != nil {
+		return errors.Wrap(err, "couldn't update expiry time of key")
+	}
+
+	return nil
+}
+
+func (js *TemporalJoinedStream) addSourceRecord(txByKey storage.StateTransaction, record *Record) error {
+	pendingRecords := NewPendingRecords(txByKey.WithPrefix(temporalJoinPendingRecordsPrefix))
+	isRetraction := record.IsUndo()
+
+	// Stored records aren't retractions, so that a record and its retraction find each other.
+	storedRecord := NewRecordFromRecord(record, WithNoUndo())
+
+	var eventTime time.Time
+	if !js.eventTimeField.Empty() {
+		var err error
+		eventTime, err = js.eventTime(storedRecord)
+		if err != nil {
+			return err
+		}
+
+		sourceRecords := NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinSourceRecordsPrefix))
+		if isRetraction {
+			present, err := sourceRecords.Add(eventTime, storedRecord, -1)
+			if err != nil {
+				return errors.Wrap(err, "couldn't remove record from source records")
+			}
+			if !present {
+				// The record has already been removed, as its matches are final, so the retraction is late.
+				log.Printf("temporal join: retracted record %v isn't present, ignoring", record.Show())
+				return nil
+			}
+		} else {
+			if _, err := sourceRecords.Add(eventTime, storedRecord, 1); err != nil {
+				return errors.Wrap(err, "couldn't add record to source records")
+			}
+		}
+	}
+
+	tableRecords, err := js.getTableRecords(txByKey, eventTime)
+	if err != nil {
+		return err
+	}
+
+	matches, err := js.getMatches(storedRecord, tableRecords)
+	if err != nil {
+		return err
+	}
+
+	if len(matches) == 0 && js.isLeftJoin {
+		return pendingRecords.Push(NewRecordFromRecord(storedRecord, WithEventTimeField(js.eventTimeField), withUndoIf(isRetraction)))
+	}
+
+	for _, match := range matches {
+		if err := pendingRecords.Push(concatRecords(storedRecord, match, isRetraction, js.eventTimeField)); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (js *TemporalJoinedStream) addTableRecord(txByKey storage.StateTransaction, record *Record) error {
+	pendingRecords := NewPendingRecords(txByKey.WithPrefix(temporalJoinPendingRecordsPrefix))
+	isRetraction := record.IsUndo()
+
+	storedRecord := NewRecordFromRecord(record, WithNoUndo())
+
+	// Without event time only the current versions of the table are needed.
+	if js.eventTimeField.Empty() && record.Value(js.validToField).GetType() != octosql.TypeNull {
+		return nil
+	}
+
+	if isRetraction {
+		present, err := js.updateTableRecords(txByKey, storedRecord, -1)
+		if err != nil {
+			return errors.Wrap(err, "couldn't remove record from table records")
+		}
+		if !present {
+			// The version has already been removed, as it's too old to match any record, so the retraction is late.
+			log.Printf("temporal join: retracted record %v isn't present, ignoring", record.Show())
+			return nil
+		}
+	}
+
+	sourceRecords, err := js.getSourceRecords(txByKey, storedRecord)
+	if err != nil {
+		return err
+	}
+
+	for _, sourceRecord := range sourceRecords {
+		isValid, err := js.isValidAt(storedRecord, sourceRecord)
+		if err != nil {
+			return err
+		}
+		if !isValid {
+			continue
+		}
+
+		// In a left join, the source record is sent alone as long as there are no matches for it.
+		hasOtherMatches := false
+		if js.isLeftJoin {
+			eventTime, err := js.eventTime(sourceRecord)
+			if err != nil {
+				return err
+			}
+			otherTableRecords, err := js.getTableRecords(txByKey, eventTime)
+			if err != nil {
+				return err
+			}
+			otherMatches, err := js.getMatches(sourceRecord, otherTableRecords)
+			if err != nil {
+				return err
+			}
+			hasOtherMatches = len(otherMatches) > 0
+		}
+
+		if js.isLeftJoin && !hasOtherMatches && !isRetraction {
+			if err := pendingRecords.Push(NewRecordFromRecord(sourceRecord, WithEventTimeField(js.eventTimeField), WithUndo())); err != nil {
+				return err
+			}
+		}
+
+		if err := pendingRecords.Push(concatRecords(sourceRecord, storedRecord, isRetraction, js.eventTimeField)); err != nil {
+			return err
+		}
+
+		if js.isLeftJoin && !hasOtherMatches && isRetraction {
+			if err := pendingRecords.Push(NewRecordFromRecord(sourceRecord, WithEventTimeField(js.eventTimeField), WithNoUndo())); err != nil {
+				return err
+			}
+		}
+	}
+
+	if !isRetraction {
+		if _, err := js.updateTableRecords(txByKey, storedRecord, 1); err != nil {
+			return errors.Wrap(err, "couldn't add record to table records")
+		}
+	}
+
+	return nil
+}
+
+// updateTableRecords changes the count of the version of the table by delta, and returns whether it was present before.
+// Versions which stopped being valid are sorted by the end of their validity, and the current ones are kept separately.
+func (js *TemporalJoinedStream) updateTableRecords(txByKey storage.StateTransaction, record *Record, delta int) (bool, error) {
+	expiryTime, ok := js.tableExpiryTime(record)
+	if ok {
+		return NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinTableVersionsPrefix)).Add(expiryTime, record, delta)
+	}
+
+	tableRecords := storage.NewMultiSet(txByKey.WithPrefix(temporalJoinTableRecordsPrefix))
+	recordValue := recordToValue(record)
+	present, err := tableRecords.Contains(recordValue)
+	if err != nil {
+		return false, errors.Wrap(err, "couldn't check if record is present")
+	}
+	if delta > 0 {
+		return present, tableRecords.Insert(recordValue)
+	}
+	if present {
+		return present, tableRecords.Erase(recordValue)
+	}
+	return false, nil
+}
+
+// getTableRecords returns the versions of the table which may be valid at the given event time:
+// the current ones, and the ones which stopped being valid after it.
+func (js *TemporalJoinedStream) getTableRecords(txByKey storage.StateTransaction, eventTime time.Time) ([]*Record, error) {
+	records, err := readAllAndTransformIntoRecords(txByKey.WithPrefix(temporalJoinTableRecordsPrefix))
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read current table records")
+	}
+	if js.eventTimeField.Empty() {
+		return records, nil
+	}
+
+	versions, err := NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinTableVersionsPrefix)).GetRange(eventTime, time.Time{})
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read table versions")
+	}
+
+	return append(records, versions...), nil
+}
+
+// getSourceRecords returns the stored records of the stream with event times in the validity of the version of the table.
+func (js *TemporalJoinedStream) getSourceRecords(txByKey storage.StateTransaction, tableRecord *Record) ([]*Record, error) {
+	if js.eventTimeField.Empty() {
+		return nil, nil
+	}
+
+	var from, to time.Time
+	if validFrom := tableRecord.Value(js.validFromField); validFrom.GetType() == octosql.TypeTime {
+		from = validFrom.AsTime()
+	}
+	if expiryTime, ok := js.tableExpiryTime(tableRecord); ok {
+		to = expiryTime
+	}
+
+	records, err := NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinSourceRecordsPrefix)).GetRange(from, to)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read source records")
+	}
+	return records, nil
+}
+
+func withUndoIf(isUndo bool) RecordOption {
+	if isUndo {
+		return WithUndo()
+	}
+	return WithNoUndo()
+}
+
+// getMatches returns the table records which were valid at the time of the source record.
+func (js *TemporalJoinedStream) getMatches(sourceRecord *Record, tableRecords []*Record) ([]*Record, error) {
+	var matches []*Record
+	for _, tableRecord := range tableRecords {
+		isValid, err := js.isValidAt(tableRecord, sourceRecord)
+		if err != nil {
+			return nil, err
+		}
+		if isValid {
+			matches = append(matches, tableRecord)
+		}
+	}
+	return matches, nil
+}
+
+// isValidAt checks if the table record was valid at the event time of the source record.
+// Without event time, only the current table records are valid.
+func (js *TemporalJoinedStream) isValidAt(tableRecord, sourceRecord *Record) (bool, error) {
+	validFrom := tableRecord.Value(js.validFromField)
+	validTo := tableRecord.Value(js.validToField)
+
+	if js.eventTimeField.Empty() {
+		return validTo.GetType() == octosql.TypeNull, nil
+	}
+
+	eventTime, err := js.eventTime(sourceRecord)
+	if err != nil {
+		return false, err
+	}
+
+	if validFrom.GetType() == octosql.TypeTime && eventTime.Before(validFrom.AsTime()) {
+		return false, nil
+	}
+	if validTo.GetType() == octosql.TypeTime && !eventTime.Before(validTo.AsTime()) {
+		return false, nil
+	}
+
+	return true, nil
+}
+
+func (js *TemporalJoinedStream) eventTime(sourceRecord *Record) (time.Time, error) {
+	eventTime := sourceRecord.Value(js.eventTimeField)
+	if eventTime.GetType() != octosql.TypeTime {
+		return time.Time{}, errors.Errorf("couldn't get event time field '%v' as time, got: %v", js.eventTimeField.String(), eventTime.Show())
+	}
+	return eventTime.AsTime(), nil
+}
+
+// Trigger returns the matches created since the key was last triggered, with new IDs.
+func (js *TemporalJoinedStream) Trigger(ctx context.Context, tx storage.StateTransaction, key octosql.Value) ([]*Record, error) {
+	keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+	txByKey := tx.WithPrefix(keyPrefix)
+
+	pendingRecords := NewPendingRecords(txByKey.WithPrefix(temporalJoinPendingRecordsPrefix))
+	outputCountState := storage.NewValueState(tx.WithPrefix(temporalJoinOutputCountPrefix))
+
+	return pendingRecords.PopAll(outputCountState, js.streamID)
+}
+
+// UpdateWatermark removes the records of the stream which are earlier than the watermark, as their matches are final now,
+// and the versions of the table which stopped being valid before the watermark.
+func (js *TemporalJoinedStream) UpdateWatermark(ctx context.Context, tx storage.StateTransaction, watermark time.Time) error {
+	if js.eventTimeField.Empty() {
+		return nil
+	}
+
+	expiry := trigger.NewTimeSortedKeys(tx.WithPrefix(temporalJoinExpiryPrefix))
+
+	for {
+		keys, _, err := expiry.GetUntil(watermark.Add(-time.Nanosecond), 100)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get keys with expired records")
+		}
+		if len(keys) == 0 {
+			return nil
+		}
+
+		for _, key := range keys {
+			keyPrefix := append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+			txByKey := tx.WithPrefix(keyPrefix)
+
+			// The records are sorted by their expiry time, so the expired ones are the earliest ones.
+			if err := NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinSourceRecordsPrefix)).RemoveBefore(watermark); err != nil {
+				return errors.Wrap(err, "couldn't remove expired source records")
+			}
+			if err := NewTimeSortedRecords(txByKey.WithPrefix(temporalJoinTableVersionsPrefix)).RemoveBefore(watermark); err != nil {
+				return errors.Wrap(err, "couldn't remove expired table records")
+			}
+
+			if err := js.updateExpiry(tx, txByKey, key); err != nil {
+				return errors.Wrap(err, "couldn't update expiry time of key")
+			}
+		}
+	}
+}
+
+// updateExpiry sets the expiry time of the key to the earliest expiry time of its records,
+// which is the one of the earliest record of the stream or version of the table.
+func (js *TemporalJoinedStream) updateExpiry(tx, txByKey storage.StateTransaction, key octosql.Value) error {
+	expiry := trigger.NewTimeSortedKeys(tx.WithPrefix(temporalJoinExpiryPrefix))
+
+	var earliest time.Time
+	found := false
+	for _, prefix := range [][]byte{temporalJoinSourceRecordsPrefix, temporalJoinTableVersionsPrefix} {
+		expiryTime, ok, err := NewTimeSortedRecords(txByKey.WithPrefix(prefix)).GetEarliest()
+		if err != nil {
+			return errors.Wrap(err, "couldn't get earliest record")
+		}
+		if ok && (!found || expiryTime.Before(earliest)) {
+			earliest = expiryTime
+			found = true
+		}
+	}
+
+	if !found {
+		if err := expiry.DeleteByKey(key); err != nil && err != storage.ErrNotFound {
+			return errors.Wrap(err, "couldn't delete expiry time of key")
+		}
+		return nil
+	}
+
+	return expiry.Update(key, earliest)
+}
+
+// tableExpiryTime returns the time after which the version of the table can be removed, if there is one,
+// as the watermark reaches the end of its validity. A record of the stream can be removed once the watermark passes its event time.
+func (js *TemporalJoinedStream) tableExpiryTime(record *Record) (time.Time, bool) {
+	validTo := record.Value(js.validToField)
+	if validTo.GetType() != octosql.TypeTime {
+		return time.Time{}, false
+	}
+	return validTo.AsTime().Add(-time.Nanosecond), true
+}
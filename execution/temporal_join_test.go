@@ -0,0 +1,99 @@
+package execution
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestTemporalJoin(t *testing.T) {
+	sourceFieldNames := []octosql.VariableName{"s.id", "s.time"}
+	tableFieldNames := []octosql.VariableName{"t.id", "t.value", "t.valid_from", "t.valid_to"}
+	concatFieldNames := append(sourceFieldNames, tableFieldNames...)
+
+	start := time.Date(2020, 7, 1, 12, 0, 0, 0, time.UTC)
+	minutes := func(n int) time.Time {
+		return start.Add(time.Duration(n) * time.Minute)
+	}
+
+	tests := []struct {
+		name          string
+		source, table Node
+		isLeftJoin    bool
+		want          Node
+	}{
+		{
+			name: "records matched with the version valid at their event time",
+			source: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(5)}, WithID(NewRecordID("id1"))),
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(15)}, WithID(NewRecordID("id2"))),
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"b", minutes(15)}, WithID(NewRecordID("id3"))),
+			}),
+			table: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 1, nil, nil}, WithID(NewRecordID("id4"))),
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 1, nil, nil}, WithID(NewRecordID("id4")), WithUndo()),
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 1, nil, minutes(10)}, WithID(NewRecordID("id5"))),
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 2, minutes(10), nil}, WithID(NewRecordID("id6"))),
+			}),
+			isLeftJoin: false,
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(5), "a", 1, nil, minutes(10)}),
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(15), "a", 2, minutes(10), nil}),
+			}),
+		},
+		{
+			name: "left join",
+			source: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(5)}, WithID(NewRecordID("id1"))),
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(15)}, WithID(NewRecordID("id2"))),
+			}),
+			table: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 2, minutes(10), nil}, WithID(NewRecordID("id3"))),
+			}),
+			isLeftJoin: true,
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(5)}),
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(15), "a", 2, minutes(10), nil}),
+			}),
+		},
+		{
+			name: "retractions of missing records are ignored",
+			source: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(5)}, WithID(NewRecordID("id1")), WithUndo()),
+				NewRecordFromSliceWithNormalize(sourceFieldNames, []interface{}{"a", minutes(15)}, WithID(NewRecordID("id2"))),
+			}),
+			table: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 1, nil, nil}, WithID(NewRecordID("id3"))),
+				NewRecordFromSliceWithNormalize(tableFieldNames, []interface{}{"a", 2, nil, minutes(10)}, WithID(NewRecordID("id4")), WithUndo()),
+			}),
+			isLeftJoin: false,
+			want: NewDummyNode([]*Record{
+				NewRecordFromSliceWithNormalize(concatFieldNames, []interface{}{"a", minutes(15), "a", 1, nil, nil}),
+			}),
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+			temporalJoin := NewTemporalJoin(tt.source, tt.table, []Expression{NewVariable("s.id")}, []Expression{NewVariable("t.id")}, "t.valid_from", "t.valid_to", tt.isLeftJoin, stateStorage, "s.time")
+
+			streamID := GetRawStreamID()
+
+			stream, _, err := GetAndStartAllShuffles(context.Background(), stateStorage, streamID, []Node{temporalJoin}, octosql.NoVariables())
+			if err != nil {
+				t.Fatalf("TemporalJoin.Get() error = %v", err)
+			}
+
+			want := GetTestStream(t, stateStorage, octosql.NoVariables(), tt.want)
+
+			err = AreStreamsEqualNoOrderingWithRetractionReductionAndIDChecking(context.Background(), stateStorage, stream[0], want, WithEqualityBasedOn(EqualityOfFieldsAndValues))
+			if err != nil {
+				t.Errorf("Streams aren't equal: %v", err)
+			}
+		})
+	}
+}
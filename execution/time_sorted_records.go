@@ -0,0 +1,138 @@
+package execution
+
+import (
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// TimeSortedRecords is a multiset of records sorted by a time given with each of them,
+// so that the records in a time range are read without reading all of them, and the earliest ones are removed first.
+// Its keys are the time followed by the record, and its values hold both of them with the count of the record.
+type TimeSortedRecords struct {
+	records *storage.Map
+}
+
+func NewTimeSortedRecords(tx storage.StateTransaction) *TimeSortedRecords {
+	return &TimeSortedRecords{
+		records: storage.NewMap(tx),
+	}
+}
+
+// Add changes the count of the record with the given time by delta, removing it once the count drops to zero.
+// It returns whether the record was present before.
+func (tsr *TimeSortedRecords) Add(t time.Time, record *Record, delta int) (bool, error) {
+	recordValue := recordToValue(record)
+	key := NewOrderByKey(append(octosql.MonotonicMarshalTime(t), recordValue.MonotonicMarshal()...))
+
+	count := 0
+	var entry octosql.Value
+	err := tsr.records.Get(key, &entry)
+	if err == nil {
+		count = entry.AsSlice()[2].AsInt()
+	} else if err != storage.ErrNotFound {
+		return false, errors.Wrap(err, "couldn't get record entry")
+	}
+	present := count > 0
+
+	count += delta
+	if count <= 0 {
+		if err := tsr.records.Delete(key); err != nil {
+			return false, errors.Wrap(err, "couldn't delete record entry")
+		}
+		return present, nil
+	}
+
+	entry = octosql.MakeTuple([]octosql.Value{octosql.MakeTime(t), recordValue, octosql.MakeInt(count)})
+	if err := tsr.records.Set(key, &entry); err != nil {
+		return false, errors.Wrap(err, "couldn't set record entry")
+	}
+	return present, nil
+}
+
+// GetRange returns the records with times from the given one up to the given one, inclusive, each repeated as many times as it's present.
+// A zero from or to leaves that end of the range unbounded.
+func (tsr *TimeSortedRecords) GetRange(from, to time.Time) ([]*Record, error) {
+	var opts []storage.IteratorOption
+	if !from.IsZero() {
+		opts = append(opts, storage.WithSeek(octosql.MonotonicMarshalTime(from)))
+	}
+	it := tsr.records.GetIterator(opts...)
+	defer it.Close()
+
+	var records []*Record
+	for {
+		var key OrderByKey
+		var entry octosql.Value
+		err := it.Next(&key, &entry)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get next record entry")
+		}
+
+		fields := entry.AsSlice()
+		if !to.IsZero() && fields[0].AsTime().After(to) {
+			break
+		}
+		for i := 0; i < fields[2].AsInt(); i++ {
+			records = append(records, valueToRecord(fields[1]))
+		}
+	}
+
+	return records, nil
+}
+
+// GetEarliest returns the earliest time of the records, if there are any.
+func (tsr *TimeSortedRecords) GetEarliest() (time.Time, bool, error) {
+	it := tsr.records.GetIterator()
+	defer it.Close()
+
+	var key OrderByKey
+	var entry octosql.Value
+	err := it.Next(&key, &entry)
+	if err == storage.ErrEndOfIterator {
+		return time.Time{}, false, nil
+	} else if err != nil {
+		return time.Time{}, false, errors.Wrap(err, "couldn't get first record entry")
+	}
+
+	return entry.AsSlice()[0].AsTime(), true, nil
+}
+
+// RemoveBefore removes the records with times before the given one.
+func (tsr *TimeSortedRecords) RemoveBefore(t time.Time) error {
+	var keys []*OrderByKey
+
+	it := tsr.records.GetIterator()
+	for {
+		var key OrderByKey
+		var entry octosql.Value
+		err := it.Next(&key, &entry)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			it.Close()
+			return errors.Wrap(err, "couldn't get next record entry")
+		}
+		if !entry.AsSlice()[0].AsTime().Before(t) {
+			break
+		}
+
+		keys = append(keys, &key)
+	}
+	if err := it.Close(); err != nil {
+		return errors.Wrap(err, "couldn't close record iterator")
+	}
+
+	for _, key := range keys {
+		if err := tsr.records.Delete(key); err != nil {
+			return errors.Wrap(err, "couldn't delete record entry")
+		}
+	}
+
+	return nil
+}
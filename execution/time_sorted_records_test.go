@@ -0,0 +1,102 @@
+package execution
+
+import (
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestTimeSortedRecords(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+
+	fields := []octosql.VariableName{"id"}
+	record := func(id int) *Record {
+		return NewRecordFromSliceWithNormalize(fields, []interface{}{id})
+	}
+	minutes := func(n int) time.Time {
+		return time.Date(2020, 1, 1, 0, n, 0, 0, time.UTC)
+	}
+	ids := func(records []*Record) []int {
+		out := make([]int, len(records))
+		for i := range records {
+			out[i] = records[i].Value("id").AsInt()
+		}
+		return out
+	}
+
+	tsr := NewTimeSortedRecords(tx.WithPrefix([]byte("$records$")))
+
+	if _, ok, err := tsr.GetEarliest(); err != nil {
+		t.Fatal(err)
+	} else if ok {
+		t.Fatal("earliest time of no records found")
+	}
+
+	adds := []struct {
+		t       time.Time
+		id      int
+		delta   int
+		present bool
+	}{
+		{minutes(20), 3, 1, false},
+		{minutes(10), 1, 1, false},
+		{minutes(15), 2, 1, false},
+		{minutes(15), 2, 1, true},
+		{minutes(30), 4, 1, false},
+		{minutes(30), 4, -1, true},
+		{minutes(25), 5, -1, false},
+	}
+	for _, add := range adds {
+		present, err := tsr.Add(add.t, record(add.id), add.delta)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if present != add.present {
+			t.Errorf("present of record %d = %v, want %v", add.id, present, add.present)
+		}
+	}
+
+	ranges := []struct {
+		from, to time.Time
+		want     []int
+	}{
+		{time.Time{}, time.Time{}, []int{1, 2, 2, 3}},
+		{minutes(10), minutes(15), []int{1, 2, 2}},
+		{minutes(11), minutes(20), []int{2, 2, 3}},
+		{minutes(16), time.Time{}, []int{3}},
+		{time.Time{}, minutes(14), []int{1}},
+		{minutes(21), minutes(40), []int{}},
+	}
+	for _, r := range ranges {
+		records, err := tsr.GetRange(r.from, r.to)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if got := ids(records); !reflect.DeepEqual(got, r.want) {
+			t.Errorf("records from %v to %v = %v, want %v", r.from, r.to, got, r.want)
+		}
+	}
+
+	if err := tsr.RemoveBefore(minutes(20)); err != nil {
+		t.Fatal(err)
+	}
+	earliest, ok, err := tsr.GetEarliest()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !ok || !earliest.Equal(minutes(20)) {
+		t.Errorf("earliest time = %v, %v, want %v", earliest, ok, minutes(20))
+	}
+	records, err := tsr.GetRange(time.Time{}, time.Time{})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := ids(records); !reflect.DeepEqual(got, []int{3}) {
+		t.Errorf("records after removal = %v, want [3]", got)
+	}
+}
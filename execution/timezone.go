@@ -0,0 +1,22 @@
+package execution
+
+import (
+	"context"
+	"time"
+)
+
+type sessionTimeZoneCtxKey struct{}
+
+// WithSessionTimeZone returns a context in which functions like date_trunc and date_part
+// use the given time zone for times which don't have their own.
+func WithSessionTimeZone(ctx context.Context, loc *time.Location) context.Context {
+	return context.WithValue(ctx, sessionTimeZoneCtxKey{}, loc)
+}
+
+// GetSessionTimeZone returns the session time zone of the query run with the context, which is UTC if none was set.
+func GetSessionTimeZone(ctx context.Context) *time.Location {
+	if loc, ok := ctx.Value(sessionTimeZoneCtxKey{}).(*time.Location); ok {
+		return loc
+	}
+	return time.UTC
+}
@@ -55,14 +55,29 @@ func (c *DelayTrigger) Get(ctx context.Context, variables octosql.Variables) (Tr
 }
 
 type WatermarkTrigger struct {
+	allowedLateness Expression
 }
 
 func NewWatermarkTrigger() *WatermarkTrigger {
 	return &WatermarkTrigger{}
 }
 
+func (c *WatermarkTrigger) WithAllowedLateness(allowedLateness Expression) *WatermarkTrigger {
+	return &WatermarkTrigger{allowedLateness: allowedLateness}
+}
+
 func (c *WatermarkTrigger) Get(ctx context.Context, variables octosql.Variables) (Trigger, error) {
-	return trigger.NewWatermarkTrigger(), nil
+	if c.allowedLateness == nil {
+		return trigger.NewWatermarkTrigger(), nil
+	}
+	allowedLateness, err := c.allowedLateness.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get allowed lateness expression value")
+	}
+	if t := allowedLateness.GetType(); t != octosql.TypeDuration {
+		return nil, errors.Errorf("watermark trigger allowed lateness must be duration, got %v", t)
+	}
+	return trigger.NewWatermarkTrigger().WithAllowedLateness(allowedLateness.AsDuration()), nil
 }
 
 type MultiTrigger struct {
@@ -16,18 +16,27 @@ import (
 var watermarkPrefix = []byte("$watermark$")
 
 type WatermarkTrigger struct {
+	allowedLateness time.Duration
 }
 
 func NewWatermarkTrigger() *WatermarkTrigger {
 	return &WatermarkTrigger{}
 }
 
+// WithAllowedLateness makes the trigger fire keys again when they receive records
+// which are late by at most the given duration, so that the values already sent get corrected.
+func (wt *WatermarkTrigger) WithAllowedLateness(allowedLateness time.Duration) *WatermarkTrigger {
+	return &WatermarkTrigger{allowedLateness: allowedLateness}
+}
+
 func (wt *WatermarkTrigger) Document() docs.Documentation {
 	return docs.Section(
 		"Watermark Trigger",
 		docs.Body(
-			docs.Section("Description", docs.Text("Triggers every record that has event time smaller than watermark value.")),
-			docs.Section("Arguments", docs.List()),
+			docs.Section("Description", docs.Text("Triggers every record that has event time smaller than watermark value.\nA record which arrives after the watermark passed its event time, by at most the allowed lateness, triggers its key again right away.")),
+			docs.Section("Arguments", docs.List(
+				docs.Text("`allowed_lateness`: how late records can be to still trigger their key, zero by default")),
+			),
 		),
 	)
 }
@@ -46,8 +55,25 @@ func (wt *WatermarkTrigger) RecordReceived(ctx context.Context, tx storage.State
 	}
 
 	if watermark.After(eventTime) {
-		// TODO: Handling late data
-		log.Printf("late data...? watermark: %v key: %v event_time: %v", watermark, key.Show(), eventTime)
+		if watermark.Sub(eventTime) > wt.allowedLateness {
+			// TODO: Handling late data
+			log.Printf("late data...? watermark: %v key: %v event_time: %v", watermark, key.Show(), eventTime)
+			return nil
+		}
+
+		// The record is late, but within the allowed lateness, so its key is ready to fire again.
+		err = timeKeys.Update(key, eventTime)
+		if err != nil {
+			return errors.Wrap(err, "couldn't update Trigger time for key")
+		}
+
+		readyToFire := storage.NewValueState(tx.WithPrefix(readyToFirePrefix))
+		octoReady := octosql.MakeBool(true)
+		err = readyToFire.Set(&octoReady)
+		if err != nil {
+			return errors.Wrap(err, "couldn't set ready to fire")
+		}
+
 		return nil
 	}
 
@@ -174,3 +174,40 @@ func TestWatermarkTrigger(t *testing.T) {
 
 	ExpectFire(t, ctx, wt, stateStorage, octosql.MakeInt(3))
 }
+
+func TestWatermarkTriggerAllowedLateness(t *testing.T) {
+	ctx := context.Background()
+	stateStorage := storage.GetTestStorage(t)
+	watermark := time.Now()
+	wt := NewWatermarkTrigger().WithAllowedLateness(time.Minute)
+
+	RecordReceived(t, ctx, wt, stateStorage, octosql.MakeInt(2), watermark.Add(time.Second*30))
+
+	ExpectNoFire(t, ctx, wt, stateStorage)
+
+	watermark = watermark.Add(time.Minute)
+	UpdateWatermark(t, ctx, wt, stateStorage, watermark)
+
+	ExpectFire(t, ctx, wt, stateStorage, octosql.MakeInt(2))
+
+	ExpectNoFire(t, ctx, wt, stateStorage)
+
+	// Late, but within the allowed lateness
+
+	RecordReceived(t, ctx, wt, stateStorage, octosql.MakeInt(2), watermark.Add(-time.Second*30))
+
+	ExpectFire(t, ctx, wt, stateStorage, octosql.MakeInt(2))
+
+	ExpectNoFire(t, ctx, wt, stateStorage)
+
+	// Later than the allowed lateness
+
+	RecordReceived(t, ctx, wt, stateStorage, octosql.MakeInt(3), watermark.Add(-time.Minute*2))
+
+	ExpectNoFire(t, ctx, wt, stateStorage)
+
+	watermark = watermark.Add(time.Minute)
+	UpdateWatermark(t, ctx, wt, stateStorage, watermark)
+
+	ExpectNoFire(t, ctx, wt, stateStorage)
+}
@@ -11,12 +11,12 @@ func main() {
 	var body []docs.Documentation
 
 	body = append(body, docs.TableOfContents(
-		[]string{"range", "tumble", "watermark generator: maximal difference", "watermark generator: percentile"},
-		[]string{"range", "tumble", "watermark-generator-maximal-difference", "watermark-generator-percentile"},
+		[]string{"range", "generate_series", "tumble", "hop", "session", "refresh", "watermark generator: maximal difference", "watermark generator: monotonic", "watermark generator: percentile"},
+		[]string{"range", "generate_series", "tumble", "hop", "session", "refresh", "watermark-generator-maximal-difference", "watermark-generator-monotonic", "watermark-generator-percentile"},
 	))
 	body = append(body, docs.Divider())
 
-	tvfs := []docs.Documented{&tvf.Range{}, &tvf.Tumble{}, &tvf.MaximumDifferenceWatermarkGenerator{}, &tvf.PercentileWatermarkGenerator{}}
+	tvfs := []docs.Documented{&tvf.Range{}, &tvf.GenerateSeries{}, &tvf.Tumble{}, &tvf.Hop{}, &tvf.Session{}, &tvf.Refresh{}, &tvf.MaximumDifferenceWatermarkGenerator{}, &tvf.MonotonicWatermarkGenerator{}, &tvf.PercentileWatermarkGenerator{}}
 	for i, el := range tvfs {
 		body = append(body, el.Document())
 		if i != len(tvfs)-1 {
@@ -0,0 +1,128 @@
+package tvf
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type GenerateSeries struct {
+	start, stop, step execution.Expression
+}
+
+// NewGenerateSeries creates a generate_series table valued function. The step may be nil, in which case it defaults to 1.
+func NewGenerateSeries(start, stop, step execution.Expression) *GenerateSeries {
+	return &GenerateSeries{
+		start: start,
+		stop:  stop,
+		step:  step,
+	}
+}
+
+func (gs *GenerateSeries) Document() docs.Documentation {
+	return docs.Section(
+		"generate_series",
+		docs.Body(
+			docs.Section("Calling", docs.Text("generate_series(\\<start\\>, \\<stop\\>[, \\<step\\>])")),
+			docs.Section("Description", docs.Text("Returns the values from start to stop inclusive, incremented by step. Start and stop can be integers, in which case step is an integer which defaults to 1, or times, in which case step is a required interval. The step can be negative. The field name of the value will be i.")),
+			docs.Section("Example", docs.Text("`SELECT * FROM generate_series(now(), now() + INTERVAL 6 DAY, INTERVAL 1 DAY) d`")),
+		),
+	)
+}
+
+var currentSeriesValuePrefix = []byte("$generate_series_current_value$")
+
+func (gs *GenerateSeries) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	start, err := gs.start.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get series start")
+	}
+	stop, err := gs.stop.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get series stop")
+	}
+	step := octosql.MakeInt(1)
+	if gs.step != nil {
+		step, err = gs.step.ExpressionValue(ctx, variables)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't get series step")
+		}
+	}
+
+	switch {
+	case start.GetType() == octosql.TypeInt && stop.GetType() == octosql.TypeInt && step.GetType() == octosql.TypeInt:
+		if step.AsInt() == 0 {
+			return nil, nil, errors.New("series step can't be zero")
+		}
+	case start.GetType() == octosql.TypeTime && stop.GetType() == octosql.TypeTime && step.GetType() == octosql.TypeDuration:
+		if step.AsDuration() == 0 {
+			return nil, nil, errors.New("series step can't be zero")
+		}
+	default:
+		return nil, nil, errors.Errorf("invalid series, start and stop have to be both ints with an int step, or both times with an interval step, got %v, %v and %v", start, stop, step)
+	}
+
+	currentValueState := storage.NewValueState(storage.GetStateTransactionFromContext(ctx).WithPrefix(streamID.AsPrefix()).WithPrefix(currentSeriesValuePrefix))
+	if err := currentValueState.Set(&start); err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't set current value in generate_series table valued function")
+	}
+
+	return &GenerateSeriesStream{
+		streamID: streamID,
+		stop:     stop,
+		step:     step,
+	}, execution.NewExecutionOutput(execution.NewZeroWatermarkGenerator(), map[string]execution.ShuffleData{}, nil), nil
+}
+
+type GenerateSeriesStream struct {
+	streamID   *execution.StreamID
+	stop, step octosql.Value
+}
+
+func (s *GenerateSeriesStream) Next(ctx context.Context) (*execution.Record, error) {
+	currentValueState := storage.NewValueState(storage.GetStateTransactionFromContext(ctx).WithPrefix(s.streamID.AsPrefix()).WithPrefix(currentSeriesValuePrefix))
+
+	var currentValue octosql.Value
+	if err := currentValueState.Get(&currentValue); err != nil {
+		return nil, errors.Wrap(err, "couldn't get current value in generate_series table valued function")
+	}
+
+	var next octosql.Value
+	switch currentValue.GetType() {
+	case octosql.TypeInt:
+		if s.step.AsInt() > 0 && currentValue.AsInt() > s.stop.AsInt() ||
+			s.step.AsInt() < 0 && currentValue.AsInt() < s.stop.AsInt() {
+			return nil, execution.ErrEndOfStream
+		}
+		next = octosql.MakeInt(currentValue.AsInt() + s.step.AsInt())
+	case octosql.TypeTime:
+		if s.step.AsDuration() > 0 && currentValue.AsTime().After(s.stop.AsTime()) ||
+			s.step.AsDuration() < 0 && currentValue.AsTime().Before(s.stop.AsTime()) {
+			return nil, execution.ErrEndOfStream
+		}
+		next = octosql.MakeTime(currentValue.AsTime().Add(s.step.AsDuration()))
+	default:
+		return nil, errors.Errorf("invalid current value in generate_series table valued function: %v", currentValue)
+	}
+
+	out := execution.NewRecordFromSlice([]octosql.VariableName{"i"}, []octosql.Value{currentValue})
+
+	if err := currentValueState.Set(&next); err != nil {
+		return nil, errors.Wrap(err, "couldn't save next value in generate_series table valued function")
+	}
+
+	return out, nil
+}
+
+func (s *GenerateSeriesStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := storage.DropAll(s.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
@@ -0,0 +1,138 @@
+package tvf
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestGenerateSeries_Get(t *testing.T) {
+	ctx := context.Background()
+	day := time.Date(2020, 1, 1, 0, 0, 0, 0, time.UTC)
+
+	type fields struct {
+		start execution.Expression
+		stop  execution.Expression
+		step  execution.Expression
+	}
+	tests := []struct {
+		name    string
+		fields  fields
+		want    execution.Node
+		wantErr bool
+	}{
+		{
+			name: "default step",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeInt(1)),
+				stop:  execution.NewConstantValue(octosql.MakeInt(3)),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{1}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{2}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{3}),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "negative step",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeInt(5)),
+				stop:  execution.NewConstantValue(octosql.MakeInt(0)),
+				step:  execution.NewConstantValue(octosql.MakeInt(-2)),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{5}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{3}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{1}),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "empty series",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeInt(4)),
+				stop:  execution.NewConstantValue(octosql.MakeInt(3)),
+			},
+			want:    execution.NewDummyNode([]*execution.Record{}),
+			wantErr: false,
+		},
+		{
+			name: "days",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeTime(day)),
+				stop:  execution.NewConstantValue(octosql.MakeTime(day.Add(48 * time.Hour))),
+				step:  execution.NewConstantValue(octosql.MakeDuration(24 * time.Hour)),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{day}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{day.Add(24 * time.Hour)}),
+				execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"i"}, []interface{}{day.Add(48 * time.Hour)}),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "times without step",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeTime(day)),
+				stop:  execution.NewConstantValue(octosql.MakeTime(day.Add(48 * time.Hour))),
+			},
+			wantErr: true,
+		},
+		{
+			name: "zero step",
+			fields: fields{
+				start: execution.NewConstantValue(octosql.MakeInt(1)),
+				stop:  execution.NewConstantValue(octosql.MakeInt(3)),
+				step:  execution.NewConstantValue(octosql.MakeInt(0)),
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			gs := NewGenerateSeries(tt.fields.start, tt.fields.stop, tt.fields.step)
+
+			stateStorage := storage.GetTestStorage(t)
+
+			tx := stateStorage.BeginTransaction()
+			ctx := storage.InjectStateTransaction(ctx, tx)
+
+			got, _, err := gs.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if (err != nil) != tt.wantErr {
+				t.Errorf("GenerateSeries.Get() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+			want, _, err := tt.want.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if err != nil {
+				t.Errorf("GenerateSeries.Get() error = %v", err)
+				return
+			}
+
+			err = execution.AreStreamsEqual(ctx, got, want)
+			if err != nil {
+				t.Errorf("GenerateSeries.Get() AreStreamsEqual error = %v", err)
+			}
+
+			if err := got.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close generate_series stream: %v", err)
+				return
+			}
+			if err := want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+
+			if err := tx.Commit(); err != nil {
+				t.Fatal(err)
+			}
+		})
+	}
+}
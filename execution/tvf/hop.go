@@ -0,0 +1,175 @@
+package tvf
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type Hop struct {
+	source       execution.Node
+	timeField    octosql.VariableName
+	slide        execution.Expression
+	windowLength execution.Expression
+	offset       execution.Expression
+}
+
+// NewHop creates a hop table valued function. The offset may be nil, in which case it defaults to zero.
+func NewHop(source execution.Node, timeField octosql.VariableName, slide, windowLength, offset execution.Expression) *Hop {
+	return &Hop{
+		source:       source,
+		timeField:    timeField,
+		slide:        slide,
+		windowLength: windowLength,
+		offset:       offset,
+	}
+}
+
+func (r *Hop) Document() docs.Documentation {
+	return docs.Section(
+		"hop",
+		docs.Body(
+			docs.Section("Calling", docs.Text("hop(source => \\<Source\\>, time_field => \\<Descriptor\\>, slide => \\<interval\\>, window_length => \\<interval\\>[, offset => \\<interval\\>])")),
+			docs.Section("Description", docs.Text("Outputs the record once for each window the time_field value falls into, with the window_start and window_end of that window added. A new window starts every slide, so windows overlap if the window length is greater than the slide. The offset defaults to zero.")),
+			docs.Section("Example", docs.Text("```\nWITH"+
+				"\n     with_hop AS (SELECT * FROM hop("+
+				"\n                     TABLE(events),"+
+				"\n                     DESCRIPTOR(e.time),"+
+				"\n                     INTERVAL 1 MINUTE,"+
+				"\n                     INTERVAL 5 MINUTES) e)"+
+				"\nSELECT e.window_end, e.team, COUNT(*) as goals\nFROM with_hop e\nGROUP BY e.window_end, e.team\nTRIGGER ON WATERMARK"+
+				"\n```")),
+		),
+	)
+}
+
+func (r *Hop) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := execution.GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	source, execOutput, err := r.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source")
+	}
+
+	slide, err := r.slide.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get window slide")
+	}
+	if slide.GetType() != octosql.TypeDuration || slide.AsDuration() <= 0 {
+		return nil, nil, errors.Errorf("invalid hop slide: %v", slide)
+	}
+
+	duration, err := r.windowLength.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get window length")
+	}
+	if duration.GetType() != octosql.TypeDuration || duration.AsDuration() <= 0 {
+		return nil, nil, errors.Errorf("invalid hop duration: %v", duration)
+	}
+
+	offset := octosql.MakeDuration(0)
+	if r.offset != nil {
+		offset, err = r.offset.ExpressionValue(ctx, variables)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't get window offset")
+		}
+		if offset.GetType() != octosql.TypeDuration {
+			return nil, nil, errors.Errorf("invalid hop offset: %v", offset)
+		}
+	}
+
+	return &HopStream{
+		source:       source,
+		timeField:    r.timeField,
+		slide:        slide.AsDuration(),
+		windowLength: duration.AsDuration(),
+		offset:       offset.AsDuration(),
+		streamID:     streamID,
+	}, execOutput, nil
+}
+
+var hopPendingRecordsPrefix = []byte("$hop_pending_records$")
+
+type HopStream struct {
+	source       execution.RecordStream
+	timeField    octosql.VariableName
+	slide        time.Duration
+	windowLength time.Duration
+	offset       time.Duration
+	streamID     *execution.StreamID
+}
+
+// The records created from a single source record are kept in storage until they're all returned,
+// so that none of them get lost if the transaction commits in between.
+func (s *HopStream) Next(ctx context.Context) (*execution.Record, error) {
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(s.streamID.AsPrefix())
+	pendingRecords := storage.NewDeque(tx.WithPrefix(hopPendingRecordsPrefix))
+
+	for {
+		var pending execution.Record
+		err := pendingRecords.PopFront(&pending)
+		if err == nil {
+			return &pending, nil
+		} else if err != storage.ErrNotFound {
+			return nil, errors.Wrap(err, "couldn't get pending record")
+		}
+
+		srcRecord, err := s.source.Next(ctx)
+		if err != nil {
+			if err == execution.ErrEndOfStream {
+				return nil, execution.ErrEndOfStream
+			}
+			return nil, errors.Wrap(err, "couldn't get source record")
+		}
+
+		timeValue := srcRecord.Value(s.timeField)
+		if timeValue.GetType() != octosql.TypeTime {
+			return nil, fmt.Errorf("couldn't get time field '%v' as time, got: %v", s.timeField.String(), srcRecord.Value(s.timeField).Show())
+		}
+		t := timeValue.AsTime()
+
+		// The starts of the windows containing the time, from the latest one to the earliest one.
+		var windowStarts []time.Time
+		for start := t.Add(-1 * s.offset).Truncate(s.slide).Add(s.offset); start.Add(s.windowLength).After(t); start = start.Add(-1 * s.slide) {
+			windowStarts = append(windowStarts, start)
+		}
+
+		fields := append(srcRecord.GetVariableNames(), octosql.NewVariableName("window_start"), octosql.NewVariableName("window_end"))
+
+		for i := len(windowStarts) - 1; i >= 0; i-- {
+			windowStart := windowStarts[i]
+			values := append(octosql.GetValuesFromPointers(srcRecord.Data), octosql.MakeTime(windowStart), octosql.MakeTime(windowStart.Add(s.windowLength)))
+
+			// Each window gets a record ID based on the one of the source record, so that retractions match.
+			out := execution.NewRecordFromRecord(
+				execution.NewRecordFromSlice(fields, values, execution.WithMetadataFrom(srcRecord), execution.WithEventTimeField("window_end")),
+				execution.WithID(execution.NewRecordID(fmt.Sprintf("%s.%d", srcRecord.ID().ID, len(windowStarts)-1-i))),
+			)
+			if err := pendingRecords.PushBack(out); err != nil {
+				return nil, errors.Wrap(err, "couldn't add pending record")
+			}
+		}
+	}
+}
+
+func (s *HopStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := s.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+	if err := storage.DropAll(s.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
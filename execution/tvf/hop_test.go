@@ -0,0 +1,153 @@
+package tvf
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestHop_Get(t *testing.T) {
+	ctx := context.Background()
+	baseTime := time.Date(2019, 9, 3, 12, 0, 0, 0, time.UTC)
+
+	sourceFields := []octosql.VariableName{"id", "time"}
+	outputFields := []octosql.VariableName{"id", "time", "window_start", "window_end"}
+
+	type fields struct {
+		source       execution.Node
+		timeField    octosql.VariableName
+		slide        execution.Expression
+		windowLength execution.Expression
+		offset       execution.Expression
+	}
+	tests := []struct {
+		name    string
+		fields  fields
+		want    execution.Node
+		wantErr bool
+	}{
+		{
+			name: "overlapping windows",
+			fields: fields{
+				source: execution.NewDummyNode([]*execution.Record{
+					execution.NewRecordFromSliceWithNormalize(
+						sourceFields,
+						[]interface{}{1, baseTime.Add(time.Second * 7)},
+						execution.WithID(execution.NewRecordID("1")),
+					),
+					execution.NewRecordFromSliceWithNormalize(
+						sourceFields,
+						[]interface{}{2, baseTime.Add(time.Second * 10)},
+						execution.WithID(execution.NewRecordID("2")),
+					),
+					execution.NewRecordFromSliceWithNormalize(
+						sourceFields,
+						[]interface{}{1, baseTime.Add(time.Second * 7)},
+						execution.WithID(execution.NewRecordID("1")),
+						execution.WithUndo(),
+					),
+				}),
+				timeField:    "time",
+				slide:        execution.NewConstantValue(octosql.MakeDuration(time.Second * 5)),
+				windowLength: execution.NewConstantValue(octosql.MakeDuration(time.Second * 10)),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{1, baseTime.Add(time.Second * 7), baseTime, baseTime.Add(time.Second * 10)},
+					execution.WithID(execution.NewRecordID("1.0")),
+					execution.WithEventTimeField("window_end"),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{1, baseTime.Add(time.Second * 7), baseTime.Add(time.Second * 5), baseTime.Add(time.Second * 15)},
+					execution.WithID(execution.NewRecordID("1.1")),
+					execution.WithEventTimeField("window_end"),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{2, baseTime.Add(time.Second * 10), baseTime.Add(time.Second * 5), baseTime.Add(time.Second * 15)},
+					execution.WithID(execution.NewRecordID("2.0")),
+					execution.WithEventTimeField("window_end"),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{2, baseTime.Add(time.Second * 10), baseTime.Add(time.Second * 10), baseTime.Add(time.Second * 20)},
+					execution.WithID(execution.NewRecordID("2.1")),
+					execution.WithEventTimeField("window_end"),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{1, baseTime.Add(time.Second * 7), baseTime, baseTime.Add(time.Second * 10)},
+					execution.WithID(execution.NewRecordID("1.0")),
+					execution.WithEventTimeField("window_end"),
+					execution.WithUndo(),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					outputFields,
+					[]interface{}{1, baseTime.Add(time.Second * 7), baseTime.Add(time.Second * 5), baseTime.Add(time.Second * 15)},
+					execution.WithID(execution.NewRecordID("1.1")),
+					execution.WithEventTimeField("window_end"),
+					execution.WithUndo(),
+				),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "zero slide",
+			fields: fields{
+				source:       execution.NewDummyNode([]*execution.Record{}),
+				timeField:    "time",
+				slide:        execution.NewConstantValue(octosql.MakeDuration(0)),
+				windowLength: execution.NewConstantValue(octosql.MakeDuration(time.Second * 10)),
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			r := NewHop(tt.fields.source, tt.fields.timeField, tt.fields.slide, tt.fields.windowLength, tt.fields.offset)
+
+			stateStorage := storage.GetTestStorage(t)
+
+			tx := stateStorage.BeginTransaction()
+			ctx := storage.InjectStateTransaction(ctx, tx)
+
+			got, _, err := r.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Hop.Get() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+			want, _, err := tt.want.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if err != nil {
+				t.Errorf("Hop.Get() error = %v", err)
+				return
+			}
+
+			err = execution.AreStreamsEqual(ctx, got, want)
+			if err != nil {
+				t.Errorf("Hop.Get() AreStreamsEqual error = %v", err)
+			}
+
+			if err := got.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close hop stream: %v", err)
+				return
+			}
+			if err := want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+
+			if err := tx.Commit(); err != nil {
+				t.Fatal(err)
+			}
+		})
+	}
+}
@@ -0,0 +1,142 @@
+package tvf
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type MonotonicWatermarkGenerator struct {
+	source    execution.Node
+	timeField octosql.VariableName
+}
+
+func NewMonotonicWatermarkGenerator(source execution.Node, timeField octosql.VariableName) *MonotonicWatermarkGenerator {
+	return &MonotonicWatermarkGenerator{
+		source:    source,
+		timeField: timeField,
+	}
+}
+
+func (r *MonotonicWatermarkGenerator) Document() docs.Documentation {
+	return docs.Section(
+		"watermark generator: monotonic",
+		docs.Body(
+			docs.Section("Calling", docs.Text("monotonic_watermark(source => \\<Source\\>, time_field => \\<Descriptor\\>)")),
+			docs.Section("Description", docs.Text("Creating watermark for sources which are ordered by event time, which stores watermark value of `<latest record event time>`. A record with an event time earlier than the current watermark is an error. Retractions aren't checked, as they refer to records sent before.")),
+			docs.Section("Example", docs.Text("```\nWITH"+
+				"\n     with_watermark AS (SELECT * FROM monotonic_watermark("+
+				"\n                        source=>TABLE(events),"+
+				"\n                        time_field=>DESCRIPTOR(time)) e),"+
+				"\nSELECT e.team, COUNT(*) as goals\nFROM with_watermark e\nGROUP BY e.team\nTRIGGER ON WATERMARK"+
+				"\n```")),
+		),
+	)
+}
+
+func (w *MonotonicWatermarkGenerator) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := execution.GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	source, execOutput, err := w.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source")
+	}
+
+	ws := &MonotonicWatermarkGeneratorStream{
+		streamID:  streamID,
+		source:    source,
+		timeField: w.timeField,
+	}
+
+	return ws, execution.NewExecutionOutput(ws, execOutput.NextShuffles, execOutput.TasksToRun), nil // watermark generator stream now indicates new watermark source
+}
+
+type MonotonicWatermarkGeneratorStream struct {
+	streamID  *execution.StreamID
+	source    execution.RecordStream
+	timeField octosql.VariableName
+}
+
+func (s *MonotonicWatermarkGeneratorStream) GetWatermark(ctx context.Context, tx storage.StateTransaction) (time.Time, error) {
+	return s.getWatermark(tx.WithPrefix(s.streamID.AsPrefix()))
+}
+
+func (s *MonotonicWatermarkGeneratorStream) getWatermark(tx storage.StateTransaction) (time.Time, error) {
+	watermarkStorage := storage.NewValueState(tx.WithPrefix(watermarkPrefix))
+
+	var currentWatermark octosql.Value
+	err := watermarkStorage.Get(&currentWatermark)
+	if err == storage.ErrNotFound {
+		currentWatermark = octosql.MakeTime(time.Time{})
+	} else if err != nil {
+		return time.Time{}, errors.Wrap(err, "couldn't get current watermark from storage")
+	}
+
+	return currentWatermark.AsTime(), nil
+}
+
+func (s *MonotonicWatermarkGeneratorStream) Next(ctx context.Context) (*execution.Record, error) {
+	srcRecord, err := s.source.Next(ctx)
+	if err != nil {
+		if err == execution.ErrEndOfStream {
+			return nil, execution.ErrEndOfStream
+		}
+		return nil, errors.Wrap(err, "couldn't get source record")
+	}
+
+	timeValue := srcRecord.Value(s.timeField)
+	if timeValue.GetType() != octosql.TypeTime {
+		return nil, fmt.Errorf("couldn't get time field '%v' as time, got: %v", s.timeField.String(), srcRecord.Value(s.timeField))
+	}
+
+	srcRecord = execution.NewRecordFromRecord(srcRecord, execution.WithEventTimeField(s.timeField))
+
+	// Retractions refer to records which have already been sent, so they can be earlier than the watermark.
+	if srcRecord.IsUndo() {
+		return srcRecord, nil
+	}
+
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(s.streamID.AsPrefix())
+
+	currentWatermark, err := s.getWatermark(tx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't get current watermark value")
+	}
+
+	if timeValue.AsTime().Before(currentWatermark) {
+		return nil, errors.Errorf("record event time %v is earlier than the watermark %v of a monotonic watermark generator", timeValue.AsTime(), currentWatermark)
+	}
+
+	if timeValue.AsTime().After(currentWatermark) {
+		watermarkStorage := storage.NewValueState(tx.WithPrefix(watermarkPrefix))
+
+		if err := watermarkStorage.Set(&timeValue); err != nil {
+			return nil, errors.Wrap(err, "couldn't set new watermark value in storage")
+		}
+	}
+
+	return srcRecord, nil
+}
+
+func (s *MonotonicWatermarkGeneratorStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := s.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+
+	if err := storage.DropAll(s.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
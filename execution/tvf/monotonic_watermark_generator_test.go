@@ -0,0 +1,95 @@
+package tvf
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestMonotonicWatermarkGeneratorStream_GetWatermark(t *testing.T) {
+	ctx := context.Background()
+	baseTime := time.Date(2019, 9, 3, 12, 0, 0, 0, time.UTC)
+
+	source := execution.NewDummyNode([]*execution.Record{
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{1, baseTime},
+		),
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{2, baseTime.Add(time.Second * 10)},
+		),
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{2, baseTime.Add(time.Second * 10)},
+		),
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{1, baseTime},
+			execution.WithUndo(),
+		),
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{3, baseTime.Add(time.Second * 13)},
+		),
+		execution.NewRecordFromSliceWithNormalize(
+			[]octosql.VariableName{"id", "time"},
+			[]interface{}{4, baseTime.Add(time.Second * 8)},
+		),
+	})
+	timeField := octosql.NewVariableName("time")
+
+	wg := NewMonotonicWatermarkGenerator(source, timeField)
+
+	stateStorage := storage.GetTestStorage(t)
+	streamID := execution.GetRawStreamID()
+
+	tx := stateStorage.BeginTransaction()
+	ctx = storage.InjectStateTransaction(ctx, tx)
+
+	src, execOutput, err := wg.Get(ctx, octosql.NoVariables(), streamID)
+	if err != nil {
+		t.Errorf("MonotonicWatermarkGenerator.Get() error = %v", err)
+		return
+	}
+
+	assert.Equal(t, src, execOutput.WatermarkSource)
+
+	ws := execOutput.WatermarkSource
+
+	ExpectWatermarkValue(t, ctx, ws, tx, time.Time{})
+
+	NextRecord(t, ctx, src) // curTime = 0
+
+	ExpectWatermarkValue(t, ctx, ws, tx, baseTime)
+
+	NextRecord(t, ctx, src) // curTime = 10
+
+	ExpectWatermarkValue(t, ctx, ws, tx, baseTime.Add(time.Second*10))
+
+	NextRecord(t, ctx, src) // curTime = 10, equal times are fine
+
+	ExpectWatermarkValue(t, ctx, ws, tx, baseTime.Add(time.Second*10))
+
+	NextRecord(t, ctx, src) // retraction with curTime = 0, not checked
+
+	ExpectWatermarkValue(t, ctx, ws, tx, baseTime.Add(time.Second*10))
+
+	NextRecord(t, ctx, src) // curTime = 13
+
+	ExpectWatermarkValue(t, ctx, ws, tx, baseTime.Add(time.Second*13))
+
+	_, err = src.Next(ctx) // curTime = 8, out of order
+	assert.NotNil(t, err)
+
+	if err := src.Close(ctx, stateStorage); err != nil {
+		t.Errorf("Couldn't close watermark generator stream: %v", err)
+		return
+	}
+}
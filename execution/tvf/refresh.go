@@ -0,0 +1,394 @@
+package tvf
+
+import (
+	"context"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type Refresh struct {
+	source       execution.Node
+	interval     execution.Expression
+	stateStorage storage.Storage
+}
+
+// NewRefresh creates a refresh table valued function. The storage is used to close the source streams of finished reads.
+func NewRefresh(source execution.Node, interval execution.Expression, stateStorage storage.Storage) *Refresh {
+	return &Refresh{
+		source:       source,
+		interval:     interval,
+		stateStorage: stateStorage,
+	}
+}
+
+func (r *Refresh) Document() docs.Documentation {
+	return docs.Section(
+		"refresh",
+		docs.Body(
+			docs.Section("Calling", docs.Text("refresh(source => \\<Source\\>, interval => \\<interval\\>)")),
+			docs.Section("Description", docs.Text("Reads the whole source again every interval and sends the changes since the previous read. Adds valid_from and valid_to, the times of the reads in which the record has appeared and disappeared. valid_from is NULL for the records of the first read, and valid_to is NULL as long as the record is present. When a record disappears, it's retracted and sent again with valid_to set. The watermark is the time of the latest finished read. Joining a stream with it matches each record with the version of the table which was valid at the record's event time.")),
+			docs.Section("Example", docs.Text("```\nSELECT e.id, c.name"+
+				"\nFROM events e JOIN refresh(TABLE(customers), INTERVAL 1 MINUTE) c ON e.customer_id = c.id"+
+				"\n```")),
+		),
+	)
+}
+
+func (r *Refresh) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	interval, err := r.interval.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get refresh interval")
+	}
+	if interval.GetType() != octosql.TypeDuration || interval.AsDuration() <= 0 {
+		return nil, nil, errors.Errorf("invalid refresh interval: %v", interval)
+	}
+
+	rs := &RefreshStream{
+		source:       r.source,
+		variables:    variables,
+		interval:     interval.AsDuration(),
+		stateStorage: r.stateStorage,
+		streamID:     streamID,
+	}
+
+	return rs, execution.NewExecutionOutput(rs, map[string]execution.ShuffleData{}, nil), nil
+}
+
+var refreshPendingRecordsPrefix = []byte("$refresh_pending_records$")
+var refreshCurrentRecordsPrefix = []byte("$refresh_current_records$")
+var refreshReadRecordsPrefix = []byte("$refresh_read_records$")
+var refreshReadStartPrefix = []byte("$refresh_read_start$")
+var refreshReadCountPrefix = []byte("$refresh_read_count$")
+var refreshFinishedReadCountPrefix = []byte("$refresh_finished_read_count$")
+var refreshNextReadPrefix = []byte("$refresh_next_read$")
+var refreshOutputCountPrefix = []byte("$refresh_output_count$")
+
+type RefreshStream struct {
+	source       execution.Node
+	variables    octosql.Variables
+	interval     time.Duration
+	stateStorage storage.Storage
+	streamID     *execution.StreamID
+
+	// read is the source stream of the read in progress, nil if it hasn't been opened by this process yet.
+	read execution.RecordStream
+}
+
+func (s *RefreshStream) GetWatermark(ctx context.Context, tx storage.StateTransaction) (time.Time, error) {
+	watermarkStorage := storage.NewValueState(tx.WithPrefix(s.streamID.AsPrefix()).WithPrefix(watermarkPrefix))
+
+	var currentWatermark octosql.Value
+	err := watermarkStorage.Get(&currentWatermark)
+	if err == storage.ErrNotFound {
+		return time.Time{}, nil
+	} else if err != nil {
+		return time.Time{}, errors.Wrap(err, "couldn't get current watermark from storage")
+	}
+
+	return currentWatermark.AsTime(), nil
+}
+
+// The records of the read in progress are gathered in a multiset, and compared with the current records once the read is finished.
+// The current records are stored together with their valid_from, as tuples of field names, values and valid_from.
+func (s *RefreshStream) Next(ctx context.Context) (*execution.Record, error) {
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(s.streamID.AsPrefix())
+	pendingRecords := execution.NewPendingRecords(tx.WithPrefix(refreshPendingRecordsPrefix))
+	readStartState := storage.NewValueState(tx.WithPrefix(refreshReadStartPrefix))
+	readRecords := storage.NewMultiSet(tx.WithPrefix(refreshReadRecordsPrefix))
+
+	for {
+		pending, ok, err := pendingRecords.Pop()
+		if err != nil {
+			return nil, err
+		}
+		if ok {
+			return pending, nil
+		}
+
+		var readStart octosql.Value
+		err = readStartState.Get(&readStart)
+		if err == storage.ErrNotFound {
+			if err := s.startRead(ctx, tx); err != nil {
+				return nil, err
+			}
+			continue
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get start time of current read")
+		}
+
+		// The read may have been started by a previous run, in which case we start it over.
+		if s.read == nil {
+			if err := s.openRead(ctx, tx); err != nil {
+				return nil, errors.Wrap(err, "couldn't open source for reading")
+			}
+		}
+
+		srcRecord, err := s.read.Next(ctx)
+		if err == execution.ErrEndOfStream {
+			if err := s.finishRead(ctx, tx, readStart.AsTime()); err != nil {
+				return nil, errors.Wrap(err, "couldn't finish read of source")
+			}
+			continue
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get source record")
+		}
+
+		value := snapshotValue(srcRecord)
+		if srcRecord.IsUndo() {
+			err = readRecords.Erase(value)
+		} else {
+			err = readRecords.Insert(value)
+		}
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't update records of current read")
+		}
+	}
+}
+
+// startRead starts a new read if the interval since the previous one has passed, otherwise it waits until it does.
+func (s *RefreshStream) startRead(ctx context.Context, tx storage.StateTransaction) error {
+	nextReadState := storage.NewValueState(tx.WithPrefix(refreshNextReadPrefix))
+
+	now := time.Now()
+
+	var nextRead octosql.Value
+	err := nextReadState.Get(&nextRead)
+	if err == nil && now.Before(nextRead.AsTime()) {
+		return execution.NewErrWaitForChanges(waitFor(ctx, nextRead.AsTime().Sub(now)))
+	} else if err != nil && err != storage.ErrNotFound {
+		return errors.Wrap(err, "couldn't get time of next read")
+	}
+
+	readStart := octosql.MakeTime(now)
+	readStartState := storage.NewValueState(tx.WithPrefix(refreshReadStartPrefix))
+	if err := readStartState.Set(&readStart); err != nil {
+		return errors.Wrap(err, "couldn't set start time of current read")
+	}
+
+	return nil
+}
+
+// waitFor returns a subscription which fires after the given duration.
+func waitFor(ctx context.Context, d time.Duration) *storage.Subscription {
+	return storage.NewSubscription(ctx, func(ctx context.Context, changes chan<- struct{}) error {
+		select {
+		case <-time.After(d):
+			return storage.ErrChangeSent
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	})
+}
+
+// openRead gets a new source stream, each read uses a new stream ID.
+func (s *RefreshStream) openRead(ctx context.Context, tx storage.StateTransaction) error {
+	readRecords := storage.NewMultiSet(tx.WithPrefix(refreshReadRecordsPrefix))
+	if err := readRecords.Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear records of previous read")
+	}
+
+	readCountState := storage.NewValueState(tx.WithPrefix(refreshReadCountPrefix))
+	var readCount octosql.Value
+	err := readCountState.Get(&readCount)
+	if err == storage.ErrNotFound {
+		readCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get count of reads")
+	}
+
+	newReadCount := octosql.MakeInt(readCount.AsInt() + 1)
+	if err := readCountState.Set(&newReadCount); err != nil {
+		return errors.Wrap(err, "couldn't set count of reads")
+	}
+
+	readStreamID, err := execution.GetSourceStreamID(tx, readCount)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	read, execOutput, err := s.source.Get(ctx, s.variables, readStreamID)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get source")
+	}
+	if len(execOutput.NextShuffles) > 0 {
+		return errors.New("refreshed source can't contain a shuffle")
+	}
+
+	for _, task := range execOutput.TasksToRun {
+		go task()
+	}
+
+	s.read = read
+	return nil
+}
+
+// finishRead compares the records of the finished read with the current ones.
+// Records which have disappeared are retracted and sent again with valid_to set to the time of the read,
+// new records are sent with valid_from set to the time of the read, or NULL if it's the first one.
+func (s *RefreshStream) finishRead(ctx context.Context, tx storage.StateTransaction, readTime time.Time) error {
+	if err := s.read.Close(ctx, s.stateStorage); err != nil {
+		return errors.Wrap(err, "couldn't close source stream")
+	}
+	s.read = nil
+
+	pendingRecords := execution.NewPendingRecords(tx.WithPrefix(refreshPendingRecordsPrefix))
+	currentRecords := storage.NewMultiSet(tx.WithPrefix(refreshCurrentRecordsPrefix))
+	readRecords := storage.NewMultiSet(tx.WithPrefix(refreshReadRecordsPrefix))
+
+	finishedReadCountState := storage.NewValueState(tx.WithPrefix(refreshFinishedReadCountPrefix))
+	var finishedReadCount octosql.Value
+	err := finishedReadCountState.Get(&finishedReadCount)
+	if err == storage.ErrNotFound {
+		finishedReadCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get count of finished reads")
+	}
+
+	validFrom := octosql.MakeTime(readTime)
+	if finishedReadCount.AsInt() == 0 {
+		validFrom = octosql.MakeNull()
+	}
+
+	current, err := currentRecords.ReadAll()
+	if err != nil {
+		return errors.Wrap(err, "couldn't read current records")
+	}
+
+	for i := range current {
+		tuple := current[i].AsSlice()
+		value := octosql.MakeTuple(tuple[:2])
+
+		stillPresent, err := readRecords.Contains(value)
+		if err != nil {
+			return errors.Wrap(err, "couldn't check if record is still present")
+		}
+		if stillPresent {
+			if err := readRecords.Erase(value); err != nil {
+				return errors.Wrap(err, "couldn't erase record from records of current read")
+			}
+			continue
+		}
+
+		if err := currentRecords.Erase(current[i]); err != nil {
+			return errors.Wrap(err, "couldn't erase record from current records")
+		}
+		if err := s.pushRecord(tx, pendingRecords, value, tuple[2], octosql.MakeNull(), true); err != nil {
+			return err
+		}
+		if err := s.pushRecord(tx, pendingRecords, value, tuple[2], octosql.MakeTime(readTime), false); err != nil {
+			return err
+		}
+	}
+
+	added, err := readRecords.ReadAll()
+	if err != nil {
+		return errors.Wrap(err, "couldn't read records of current read")
+	}
+
+	for i := range added {
+		if err := currentRecords.Insert(octosql.MakeTuple(append(added[i].AsSlice(), validFrom))); err != nil {
+			return errors.Wrap(err, "couldn't insert record into current records")
+		}
+		if err := s.pushRecord(tx, pendingRecords, added[i], validFrom, octosql.MakeNull(), false); err != nil {
+			return err
+		}
+	}
+
+	if err := readRecords.Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear records of current read")
+	}
+
+	readStartState := storage.NewValueState(tx.WithPrefix(refreshReadStartPrefix))
+	if err := readStartState.Clear(); err != nil {
+		return errors.Wrap(err, "couldn't clear start time of current read")
+	}
+
+	nextRead := octosql.MakeTime(readTime.Add(s.interval))
+	nextReadState := storage.NewValueState(tx.WithPrefix(refreshNextReadPrefix))
+	if err := nextReadState.Set(&nextRead); err != nil {
+		return errors.Wrap(err, "couldn't set time of next read")
+	}
+
+	newFinishedReadCount := octosql.MakeInt(finishedReadCount.AsInt() + 1)
+	if err := finishedReadCountState.Set(&newFinishedReadCount); err != nil {
+		return errors.Wrap(err, "couldn't set count of finished reads")
+	}
+
+	watermark := octosql.MakeTime(readTime)
+	watermarkStorage := storage.NewValueState(tx.WithPrefix(watermarkPrefix))
+	if err := watermarkStorage.Set(&watermark); err != nil {
+		return errors.Wrap(err, "couldn't set new watermark value in storage")
+	}
+
+	return nil
+}
+
+// snapshotValue is the tuple of field names and values of the record, which is compared between reads.
+func snapshotValue(record *execution.Record) octosql.Value {
+	fields := make([]octosql.Value, len(record.FieldNames))
+	for i := range record.FieldNames {
+		fields[i] = octosql.MakeString(record.FieldNames[i])
+	}
+
+	return octosql.MakeTuple([]octosql.Value{
+		octosql.MakeTuple(fields),
+		octosql.MakeTuple(octosql.GetValuesFromPointers(record.Data)),
+	})
+}
+
+func (s *RefreshStream) pushRecord(tx storage.StateTransaction, pendingRecords *execution.PendingRecords, value, validFrom, validTo octosql.Value, isUndo bool) error {
+	outputCountState := storage.NewValueState(tx.WithPrefix(refreshOutputCountPrefix))
+	var outputCount octosql.Value
+	err := outputCountState.Get(&outputCount)
+	if err == storage.ErrNotFound {
+		outputCount = octosql.MakeInt(0)
+	} else if err != nil {
+		return errors.Wrap(err, "couldn't get count of sent records")
+	}
+
+	tuple := value.AsSlice()
+	fieldValues := tuple[0].AsSlice()
+	fields := make([]octosql.VariableName, len(fieldValues), len(fieldValues)+2)
+	for i := range fieldValues {
+		fields[i] = octosql.NewVariableName(fieldValues[i].AsString())
+	}
+	fields = append(fields, octosql.NewVariableName("valid_from"), octosql.NewVariableName("valid_to"))
+
+	data := append(tuple[1].AsSlice(), validFrom, validTo)
+
+	opts := []execution.RecordOption{execution.WithID(execution.NewRecordIDFromStreamIDWithOffset(s.streamID, outputCount.AsInt()))}
+	if isUndo {
+		opts = append(opts, execution.WithUndo())
+	}
+
+	if err := pendingRecords.Push(execution.NewRecordFromSlice(fields, data, opts...)); err != nil {
+		return err
+	}
+
+	newOutputCount := octosql.MakeInt(outputCount.AsInt() + 1)
+	if err := outputCountState.Set(&newOutputCount); err != nil {
+		return errors.Wrap(err, "couldn't set count of sent records")
+	}
+
+	return nil
+}
+
+func (s *RefreshStream) Close(ctx context.Context, storage storage.Storage) error {
+	if s.read != nil {
+		if err := s.read.Close(ctx, storage); err != nil {
+			return errors.Wrap(err, "couldn't close underlying stream")
+		}
+	}
+
+	if err := storage.DropAll(s.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
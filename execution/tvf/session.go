@@ -0,0 +1,444 @@
+package tvf
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/docs"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type Session struct {
+	source    execution.Node
+	timeField octosql.VariableName
+	gap       execution.Expression
+	key       octosql.VariableName
+}
+
+// NewSession creates a session table valued function. The key may be empty, in which case all records belong to a single key.
+func NewSession(source execution.Node, timeField octosql.VariableName, gap execution.Expression, key octosql.VariableName) *Session {
+	return &Session{
+		source:    source,
+		timeField: timeField,
+		gap:       gap,
+		key:       key,
+	}
+}
+
+func (r *Session) Document() docs.Documentation {
+	return docs.Section(
+		"session",
+		docs.Body(
+			docs.Section("Calling", docs.Text("session(source => \\<Source\\>, time_field => \\<Descriptor\\>, gap => \\<interval\\>[, key => \\<Descriptor\\>])")),
+			docs.Section("Description", docs.Text("Adds window_start and window_end of the session the record belongs to. A session contains records of the same key which are less than gap apart, and ends gap after its last record. When a record joins sessions together, the records already sent with the previous sessions are retracted and sent again with the merged session. Retracted source records are removed from their session, but the session isn't shrunk. Sessions which ended before the watermark are dropped, so records arriving after that start new sessions.")),
+			docs.Section("Example", docs.Text("```\nWITH"+
+				"\n     with_session AS (SELECT * FROM session("+
+				"\n                     TABLE(clicks),"+
+				"\n                     DESCRIPTOR(c.time),"+
+				"\n                     INTERVAL 30 MINUTES,"+
+				"\n                     DESCRIPTOR(c.user)) c)"+
+				"\nSELECT c.user, c.window_start, COUNT(*) as clicks\nFROM with_session c\nGROUP BY c.user, c.window_start"+
+				"\n```")),
+		),
+	)
+}
+
+func (r *Session) Get(ctx context.Context, variables octosql.Variables, streamID *execution.StreamID) (execution.RecordStream, *execution.ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := execution.GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	source, execOutput, err := r.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source")
+	}
+
+	gap, err := r.gap.ExpressionValue(ctx, variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get session gap")
+	}
+	if gap.GetType() != octosql.TypeDuration || gap.AsDuration() <= 0 {
+		return nil, nil, errors.Errorf("invalid session gap: %v", gap)
+	}
+
+	stream := &SessionStream{
+		source:          source,
+		sourceWatermark: execOutput.WatermarkSource,
+		timeField:       r.timeField,
+		gap:             gap.AsDuration(),
+		key:             r.key,
+		streamID:        streamID,
+	}
+
+	return stream, execution.NewExecutionOutput(stream, execOutput.NextShuffles, execOutput.TasksToRun), nil
+}
+
+var sessionPendingRecordsPrefix = []byte("$session_pending_records$")
+var sessionsPrefix = []byte("$sessions$")
+var sessionRecordsPrefix = []byte("$session_records$")
+var sessionExpiryPrefix = []byte("$session_expiry$")
+
+type SessionStream struct {
+	source          execution.RecordStream
+	sourceWatermark execution.WatermarkSource
+	timeField       octosql.VariableName
+	gap             time.Duration
+	key             octosql.VariableName
+	streamID        *execution.StreamID
+}
+
+// session is a single session of a key.
+type session struct {
+	start, end time.Time
+}
+
+// The sessions of each key are stored as a map from their start to their end, ordered by start.
+// The records belonging to a session are stored in a deque under the key and the start of the session.
+// All the sessions are also indexed by their end, so that they can be expired once the watermark passes it.
+func (s *SessionStream) Next(ctx context.Context) (*execution.Record, error) {
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(s.streamID.AsPrefix())
+	pendingRecords := execution.NewPendingRecords(tx.WithPrefix(sessionPendingRecordsPrefix))
+
+	for {
+		pending, ok, err := pendingRecords.Pop()
+		if err != nil {
+			return nil, err
+		}
+		if ok {
+			return pending, nil
+		}
+
+		srcRecord, err := s.source.Next(ctx)
+		if err != nil {
+			if err == execution.ErrEndOfStream {
+				return nil, execution.ErrEndOfStream
+			}
+			return nil, errors.Wrap(err, "couldn't get source record")
+		}
+
+		timeValue := srcRecord.Value(s.timeField)
+		if timeValue.GetType() != octosql.TypeTime {
+			return nil, fmt.Errorf("couldn't get time field '%v' as time, got: %v", s.timeField.String(), srcRecord.Value(s.timeField).Show())
+		}
+
+		key := octosql.MakeNull()
+		if !s.key.Empty() {
+			key = srcRecord.Value(s.key)
+		}
+
+		if srcRecord.IsUndo() {
+			err = s.retractRecord(tx, key, pendingRecords, srcRecord, timeValue.AsTime())
+		} else {
+			err = s.addRecord(tx, key, pendingRecords, srcRecord, timeValue.AsTime())
+		}
+		if err != nil {
+			return nil, err
+		}
+	}
+}
+
+// GetWatermark returns the watermark of the source. Sessions which ended before it can't be extended by any record
+// which isn't late anymore, so they're expired, and their state is dropped.
+func (s *SessionStream) GetWatermark(ctx context.Context, tx storage.StateTransaction) (time.Time, error) {
+	watermark, err := s.sourceWatermark.GetWatermark(ctx, tx)
+	if err != nil {
+		return time.Time{}, errors.Wrap(err, "couldn't get source watermark")
+	}
+
+	if err := s.expireSessions(tx.WithPrefix(s.streamID.AsPrefix()), watermark); err != nil {
+		return time.Time{}, errors.Wrap(err, "couldn't expire sessions")
+	}
+
+	return watermark, nil
+}
+
+func (s *SessionStream) expireSessions(tx storage.StateTransaction, watermark time.Time) error {
+	expiry := storage.NewMap(tx.WithPrefix(sessionExpiryPrefix))
+
+	var expired []octosql.Value
+	iter := expiry.GetIterator()
+	for {
+		var entry octosql.Value
+		var phantom octosql.Value
+		err := iter.Next(&entry, &phantom)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			_ = iter.Close()
+			return errors.Wrap(err, "couldn't get session expiry entry")
+		}
+		if !entry.AsSlice()[0].AsTime().Before(watermark) {
+			break
+		}
+		expired = append(expired, entry)
+	}
+	if err := iter.Close(); err != nil {
+		return errors.Wrap(err, "couldn't close session expiry iterator")
+	}
+
+	for i := range expired {
+		entry := expired[i].AsSlice()
+		sess := session{start: entry[2].AsTime(), end: entry[0].AsTime()}
+		txByKey := tx.WithPrefix(sessionKeyPrefix(entry[1]))
+
+		sessionStart := octosql.MakeTime(sess.start)
+		if err := storage.NewMap(txByKey.WithPrefix(sessionsPrefix)).Delete(&sessionStart); err != nil {
+			return errors.Wrap(err, "couldn't delete expired session")
+		}
+		if err := storage.NewDeque(s.sessionRecordsTx(txByKey, sess)).Clear(); err != nil {
+			return errors.Wrap(err, "couldn't clear records of expired session")
+		}
+		if err := expiry.Delete(&expired[i]); err != nil {
+			return errors.Wrap(err, "couldn't delete session expiry entry")
+		}
+	}
+
+	return nil
+}
+
+func (s *SessionStream) addRecord(tx storage.StateTransaction, key octosql.Value, pendingRecords *execution.PendingRecords, record *execution.Record, t time.Time) error {
+	txByKey := tx.WithPrefix(sessionKeyPrefix(key))
+	merged := session{start: t, end: t.Add(s.gap)}
+
+	// All the sessions overlapping with the one of the new record get merged into a single one.
+	overlapping, err := s.getOverlappingSessions(txByKey, merged)
+	if err != nil {
+		return err
+	}
+	for _, sess := range overlapping {
+		if sess.start.Before(merged.start) {
+			merged.start = sess.start
+		}
+		if sess.end.After(merged.end) {
+			merged.end = sess.end
+		}
+	}
+
+	sessions := storage.NewMap(txByKey.WithPrefix(sessionsPrefix))
+	expiry := storage.NewMap(tx.WithPrefix(sessionExpiryPrefix))
+
+	var records []*execution.Record
+	for _, sess := range overlapping {
+		sessionRecords, err := s.popSessionRecords(txByKey, sess)
+		if err != nil {
+			return err
+		}
+		sessionStart := octosql.MakeTime(sess.start)
+		if err := sessions.Delete(&sessionStart); err != nil {
+			return errors.Wrap(err, "couldn't delete merged session")
+		}
+		expiryEntry := sessionExpiryEntry(key, sess)
+		if err := expiry.Delete(&expiryEntry); err != nil {
+			return errors.Wrap(err, "couldn't delete expiry entry of merged session")
+		}
+
+		if !sess.start.Equal(merged.start) || !sess.end.Equal(merged.end) {
+			for _, sessionRecord := range sessionRecords {
+				if err := pendingRecords.Push(withSessionWindow(sessionRecord, sess, true)); err != nil {
+					return err
+				}
+				if err := pendingRecords.Push(withSessionWindow(sessionRecord, merged, false)); err != nil {
+					return err
+				}
+			}
+		}
+		records = append(records, sessionRecords...)
+	}
+	records = append(records, record)
+
+	if err := pendingRecords.Push(withSessionWindow(record, merged, false)); err != nil {
+		return err
+	}
+
+	mergedRecords := storage.NewDeque(s.sessionRecordsTx(txByKey, merged))
+	for _, sessionRecord := range records {
+		if err := mergedRecords.PushBack(sessionRecord); err != nil {
+			return errors.Wrap(err, "couldn't add record to session")
+		}
+	}
+
+	mergedStart := octosql.MakeTime(merged.start)
+	mergedEnd := octosql.MakeTime(merged.end)
+	if err := sessions.Set(&mergedStart, &mergedEnd); err != nil {
+		return errors.Wrap(err, "couldn't set session")
+	}
+	expiryEntry := sessionExpiryEntry(key, merged)
+	phantom := octosql.MakePhantom()
+	if err := expiry.Set(&expiryEntry, &phantom); err != nil {
+		return errors.Wrap(err, "couldn't set session expiry entry")
+	}
+
+	return nil
+}
+
+func (s *SessionStream) retractRecord(tx storage.StateTransaction, key octosql.Value, pendingRecords *execution.PendingRecords, record *execution.Record, t time.Time) error {
+	txByKey := tx.WithPrefix(sessionKeyPrefix(key))
+
+	sess, ok, err := s.getLastSessionStartingAt(txByKey, t)
+	if err != nil {
+		return err
+	}
+	if !ok || !t.Before(sess.end) {
+		return errors.Errorf("couldn't find session of retracted record %v, it may have already expired", record.Show())
+	}
+
+	sessionRecords, err := s.popSessionRecords(txByKey, sess)
+	if err != nil {
+		return err
+	}
+
+	// The retraction is matched against the stored record, which isn't a retraction.
+	wanted := execution.NewRecordFromRecord(record, execution.WithNoUndo())
+
+	found := false
+	remaining := storage.NewDeque(s.sessionRecordsTx(txByKey, sess))
+	for _, sessionRecord := range sessionRecords {
+		if !found && sessionRecord.Equal(wanted) {
+			found = true
+			continue
+		}
+		if err := remaining.PushBack(sessionRecord); err != nil {
+			return errors.Wrap(err, "couldn't add record to session")
+		}
+	}
+	if !found {
+		return errors.Errorf("couldn't find retracted record %v in its session", record.Show())
+	}
+
+	if err := pendingRecords.Push(withSessionWindow(wanted, sess, true)); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+// getOverlappingSessions returns the sessions overlapping with the given one.
+// Sessions of a key never overlap each other, so only the last session starting before the given one can overlap it,
+// apart from the sessions starting inside of it.
+func (s *SessionStream) getOverlappingSessions(tx storage.StateTransaction, sess session) ([]session, error) {
+	var out []session
+
+	previous, ok, err := s.getLastSessionStartingAt(tx, sess.start)
+	if err != nil {
+		return nil, err
+	}
+	if ok && !previous.end.Before(sess.start) {
+		out = append(out, previous)
+	}
+
+	sessions := storage.NewMap(tx.WithPrefix(sessionsPrefix))
+	sessionStart := octosql.MakeTime(sess.start)
+	iter := sessions.GetIterator(storage.WithSeek(sessionStart.MonotonicMarshal()))
+	defer func() {
+		_ = iter.Close()
+	}()
+
+	for {
+		var start octosql.Value
+		var end octosql.Value
+		err := iter.Next(&start, &end)
+		if err == storage.ErrEndOfIterator {
+			break
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get session")
+		}
+
+		next := session{start: start.AsTime(), end: end.AsTime()}
+		if next.start.After(sess.end) {
+			break
+		}
+		if ok && next.start.Equal(previous.start) {
+			continue
+		}
+		out = append(out, next)
+	}
+
+	return out, nil
+}
+
+// getLastSessionStartingAt returns the last session which starts at or before the given time, if there is one.
+func (s *SessionStream) getLastSessionStartingAt(tx storage.StateTransaction, t time.Time) (session, bool, error) {
+	sessions := storage.NewMap(tx.WithPrefix(sessionsPrefix))
+	seekStart := octosql.MakeTime(t)
+	iter := sessions.GetIterator(storage.WithReverse(), storage.WithSeek(seekStart.MonotonicMarshal()))
+	defer func() {
+		_ = iter.Close()
+	}()
+
+	var start octosql.Value
+	var end octosql.Value
+	err := iter.Next(&start, &end)
+	if err == storage.ErrEndOfIterator {
+		return session{}, false, nil
+	} else if err != nil {
+		return session{}, false, errors.Wrap(err, "couldn't get session")
+	}
+
+	return session{start: start.AsTime(), end: end.AsTime()}, true, nil
+}
+
+func sessionKeyPrefix(key octosql.Value) []byte {
+	return append(append([]byte("$"), key.MonotonicMarshal()...), '$')
+}
+
+// sessionExpiryEntry is the key of the session in the expiry index, which is ordered by the end of the session.
+func sessionExpiryEntry(key octosql.Value, sess session) octosql.Value {
+	return octosql.MakeTuple([]octosql.Value{octosql.MakeTime(sess.end), key, octosql.MakeTime(sess.start)})
+}
+
+// popSessionRecords returns the records of the session and removes them from storage.
+func (s *SessionStream) popSessionRecords(tx storage.StateTransaction, sess session) ([]*execution.Record, error) {
+	sessionRecords := storage.NewDeque(s.sessionRecordsTx(tx, sess))
+
+	var out []*execution.Record
+	for {
+		var record execution.Record
+		err := sessionRecords.PopFront(&record)
+		if err == storage.ErrNotFound {
+			break
+		} else if err != nil {
+			return nil, errors.Wrap(err, "couldn't get session record")
+		}
+		out = append(out, &record)
+	}
+
+	return out, nil
+}
+
+func (s *SessionStream) sessionRecordsTx(tx storage.StateTransaction, sess session) storage.StateTransaction {
+	sessionStart := octosql.MakeTime(sess.start)
+	return tx.WithPrefix(sessionRecordsPrefix).WithPrefix(append(sessionStart.MonotonicMarshal(), '$'))
+}
+
+func withSessionWindow(record *execution.Record, sess session, undo bool) *execution.Record {
+	fields := append(record.GetVariableNames(), octosql.NewVariableName("window_start"), octosql.NewVariableName("window_end"))
+	values := append(octosql.GetValuesFromPointers(record.Data), octosql.MakeTime(sess.start), octosql.MakeTime(sess.end))
+
+	opts := []execution.RecordOption{
+		execution.WithID(record.ID()),
+		execution.WithEventTimeField("window_end"),
+	}
+	if undo {
+		opts = append(opts, execution.WithUndo())
+	}
+
+	return execution.NewRecordFromSlice(fields, values, opts...)
+}
+
+func (s *SessionStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := s.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+	if err := storage.DropAll(s.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
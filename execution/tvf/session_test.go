@@ -0,0 +1,206 @@
+package tvf
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestSession_Get(t *testing.T) {
+	ctx := context.Background()
+	baseTime := time.Date(2019, 9, 3, 12, 0, 0, 0, time.UTC)
+
+	sourceFields := []octosql.VariableName{"id", "user", "time"}
+	outputFields := []octosql.VariableName{"id", "user", "time", "window_start", "window_end"}
+
+	sourceRecord := func(id int, user string, t time.Duration, opts ...execution.RecordOption) *execution.Record {
+		opts = append(opts, execution.WithID(execution.NewRecordID(fmt.Sprint(id))))
+		return execution.NewRecordFromSliceWithNormalize(sourceFields, []interface{}{id, user, baseTime.Add(t)}, opts...)
+	}
+	outputRecord := func(id int, user string, t, start, end time.Duration, opts ...execution.RecordOption) *execution.Record {
+		opts = append(opts, execution.WithID(execution.NewRecordID(fmt.Sprint(id))), execution.WithEventTimeField("window_end"))
+		return execution.NewRecordFromSliceWithNormalize(outputFields, []interface{}{id, user, baseTime.Add(t), baseTime.Add(start), baseTime.Add(end)}, opts...)
+	}
+
+	type fields struct {
+		source    execution.Node
+		timeField octosql.VariableName
+		gap       execution.Expression
+		key       octosql.VariableName
+	}
+	tests := []struct {
+		name    string
+		fields  fields
+		want    execution.Node
+		wantErr bool
+	}{
+		{
+			name: "separate sessions",
+			fields: fields{
+				source: execution.NewDummyNode([]*execution.Record{
+					sourceRecord(1, "a", 0),
+					sourceRecord(2, "a", time.Second*30),
+					sourceRecord(3, "a", time.Second*35),
+				}),
+				timeField: "time",
+				gap:       execution.NewConstantValue(octosql.MakeDuration(time.Second * 10)),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				outputRecord(1, "a", 0, 0, time.Second*10),
+				outputRecord(2, "a", time.Second*30, time.Second*30, time.Second*40),
+				outputRecord(2, "a", time.Second*30, time.Second*30, time.Second*40, execution.WithUndo()),
+				outputRecord(2, "a", time.Second*30, time.Second*30, time.Second*45),
+				outputRecord(3, "a", time.Second*35, time.Second*30, time.Second*45),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "merged sessions with keys and retractions",
+			fields: fields{
+				source: execution.NewDummyNode([]*execution.Record{
+					sourceRecord(1, "a", 0),
+					sourceRecord(2, "b", time.Second*5),
+					sourceRecord(3, "a", time.Second*25),
+					sourceRecord(4, "a", time.Second*8),
+					sourceRecord(5, "a", time.Second*17),
+					sourceRecord(4, "a", time.Second*8, execution.WithUndo()),
+				}),
+				timeField: "time",
+				gap:       execution.NewConstantValue(octosql.MakeDuration(time.Second * 10)),
+				key:       "user",
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				outputRecord(1, "a", 0, 0, time.Second*10),
+				outputRecord(2, "b", time.Second*5, time.Second*5, time.Second*15),
+				outputRecord(3, "a", time.Second*25, time.Second*25, time.Second*35),
+
+				outputRecord(1, "a", 0, 0, time.Second*10, execution.WithUndo()),
+				outputRecord(1, "a", 0, 0, time.Second*18),
+				outputRecord(4, "a", time.Second*8, 0, time.Second*18),
+
+				outputRecord(1, "a", 0, 0, time.Second*18, execution.WithUndo()),
+				outputRecord(1, "a", 0, 0, time.Second*35),
+				outputRecord(4, "a", time.Second*8, 0, time.Second*18, execution.WithUndo()),
+				outputRecord(4, "a", time.Second*8, 0, time.Second*35),
+				outputRecord(3, "a", time.Second*25, time.Second*25, time.Second*35, execution.WithUndo()),
+				outputRecord(3, "a", time.Second*25, 0, time.Second*35),
+				outputRecord(5, "a", time.Second*17, 0, time.Second*35),
+
+				outputRecord(4, "a", time.Second*8, 0, time.Second*35, execution.WithUndo()),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "zero gap",
+			fields: fields{
+				source:    execution.NewDummyNode([]*execution.Record{}),
+				timeField: "time",
+				gap:       execution.NewConstantValue(octosql.MakeDuration(0)),
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			r := NewSession(tt.fields.source, tt.fields.timeField, tt.fields.gap, tt.fields.key)
+
+			stateStorage := storage.GetTestStorage(t)
+
+			tx := stateStorage.BeginTransaction()
+			ctx := storage.InjectStateTransaction(ctx, tx)
+
+			got, _, err := r.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Session.Get() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+			want, _, err := tt.want.Get(ctx, octosql.NoVariables(), execution.GetRawStreamID())
+			if err != nil {
+				t.Errorf("Session.Get() error = %v", err)
+				return
+			}
+
+			err = execution.AreStreamsEqual(ctx, got, want)
+			if err != nil {
+				t.Errorf("Session.Get() AreStreamsEqual error = %v", err)
+			}
+
+			if err := got.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close session stream: %v", err)
+				return
+			}
+			if err := want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+
+			if err := tx.Commit(); err != nil {
+				t.Fatal(err)
+			}
+		})
+	}
+}
+
+func TestSessionStream_GetWatermark(t *testing.T) {
+	ctx := context.Background()
+	baseTime := time.Date(2019, 9, 3, 12, 0, 0, 0, time.UTC)
+
+	sourceFields := []octosql.VariableName{"id", "user", "time"}
+	source := NewMonotonicWatermarkGenerator(execution.NewDummyNode([]*execution.Record{
+		execution.NewRecordFromSliceWithNormalize(sourceFields, []interface{}{1, "a", baseTime}),
+		execution.NewRecordFromSliceWithNormalize(sourceFields, []interface{}{2, "b", baseTime.Add(time.Second * 30)}),
+	}), "time")
+
+	r := NewSession(source, "time", execution.NewConstantValue(octosql.MakeDuration(time.Second*10)), "user")
+
+	stateStorage := storage.GetTestStorage(t)
+	streamID := execution.GetRawStreamID()
+
+	tx := stateStorage.BeginTransaction()
+	ctx = storage.InjectStateTransaction(ctx, tx)
+
+	got, execOutput, err := r.Get(ctx, octosql.NoVariables(), streamID)
+	if err != nil {
+		t.Fatalf("Session.Get() error = %v", err)
+	}
+
+	NextRecord(t, ctx, got)
+	ExpectWatermarkValue(t, ctx, execOutput.WatermarkSource, tx, baseTime)
+	NextRecord(t, ctx, got)
+	ExpectWatermarkValue(t, ctx, execOutput.WatermarkSource, tx, baseTime.Add(time.Second*30))
+
+	sessionCount := func(user string) int {
+		sessions := storage.NewMap(tx.WithPrefix(streamID.AsPrefix()).WithPrefix(sessionKeyPrefix(octosql.MakeString(user))).WithPrefix(sessionsPrefix))
+		iter := sessions.GetIterator()
+		defer iter.Close()
+
+		count := 0
+		var start octosql.Value
+		var end octosql.Value
+		for err := iter.Next(&start, &end); err != storage.ErrEndOfIterator; err = iter.Next(&start, &end) {
+			if err != nil {
+				t.Fatal(err)
+			}
+			count++
+		}
+		return count
+	}
+
+	// The session of a ended before the watermark, so it's expired, the one of b is still open.
+	assert.Equal(t, 0, sessionCount("a"))
+	assert.Equal(t, 1, sessionCount("b"))
+
+	if err := got.Close(ctx, stateStorage); err != nil {
+		t.Errorf("Couldn't close session stream: %v", err)
+	}
+}
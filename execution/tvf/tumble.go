@@ -20,6 +20,7 @@ type Tumble struct {
 	offset       execution.Expression
 }
 
+// NewTumble creates a tumble table valued function. The offset may be nil, in which case it defaults to zero.
 func NewTumble(source execution.Node, timeField octosql.VariableName, windowLength, offset execution.Expression) *Tumble {
 	return &Tumble{
 		source:       source,
@@ -33,8 +34,8 @@ func (r *Tumble) Document() docs.Documentation {
 	return docs.Section(
 		"tumble",
 		docs.Body(
-			docs.Section("Calling", docs.Text("tumble(source => \\<Source\\>, time_field => \\<Descriptor\\>, window_length => \\<interval\\>, offset => \\<interval\\>)")),
-			docs.Section("Description", docs.Text("Adds window_start and window_end of the record, based on which window the time_field value falls into.")),
+			docs.Section("Calling", docs.Text("tumble(source => \\<Source\\>, time_field => \\<Descriptor\\>, window_length => \\<interval\\>[, offset => \\<interval\\>])")),
+			docs.Section("Description", docs.Text("Adds window_start and window_end of the record, based on which window the time_field value falls into. The offset defaults to zero. The arguments can also be passed by position, as in tumble(TABLE(events), DESCRIPTOR(e.time), INTERVAL 1 MINUTE).")),
 			docs.Section("Example", docs.Text("```\nWITH"+
 				"\n     with_tumble AS (SELECT * FROM tumble("+
 				"\n                     source=>TABLE(events),"+
@@ -63,16 +64,19 @@ func (r *Tumble) Get(ctx context.Context, variables octosql.Variables, streamID
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get window length")
 	}
-	if duration.GetType() != octosql.TypeDuration {
+	if duration.GetType() != octosql.TypeDuration || duration.AsDuration() <= 0 {
 		return nil, nil, errors.Errorf("invalid tumble duration: %v", duration)
 	}
 
-	offset, err := r.offset.ExpressionValue(ctx, variables)
-	if err != nil {
-		return nil, nil, errors.Wrap(err, "couldn't get window offset")
-	}
-	if offset.GetType() != octosql.TypeDuration {
-		return nil, nil, errors.Errorf("invalid tumble offset: %v", offset)
+	offset := octosql.MakeDuration(0)
+	if r.offset != nil {
+		offset, err = r.offset.ExpressionValue(ctx, variables)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't get window offset")
+		}
+		if offset.GetType() != octosql.TypeDuration {
+			return nil, nil, errors.Errorf("invalid tumble offset: %v", offset)
+		}
 	}
 
 	return &TumbleStream{
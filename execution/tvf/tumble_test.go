@@ -140,6 +140,41 @@ func TestTumble_Get(t *testing.T) {
 			}),
 			wantErr: false,
 		},
+		{
+			name: "tumble without offset",
+			fields: fields{
+				source: execution.NewDummyNode([]*execution.Record{
+					execution.NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "time"},
+						[]interface{}{1, baseTime.Add(time.Second * 70)},
+					),
+					execution.NewRecordFromSliceWithNormalize(
+						[]octosql.VariableName{"id", "time"},
+						[]interface{}{2, baseTime.Add(time.Second * 130)},
+					),
+				}),
+				timeField:    "time",
+				windowLength: execution.NewVariable(octosql.NewVariableName("window_length")),
+			},
+			args: args{
+				variables: octosql.NewVariables(map[octosql.VariableName]octosql.Value{
+					"window_length": octosql.MakeDuration(time.Minute),
+				}),
+			},
+			want: execution.NewDummyNode([]*execution.Record{
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "time", "window_start", "window_end"},
+					[]interface{}{1, baseTime.Add(time.Second * 70), baseTime.Add(time.Minute), baseTime.Add(time.Minute * 2)},
+					execution.WithEventTimeField("window_end"),
+				),
+				execution.NewRecordFromSliceWithNormalize(
+					[]octosql.VariableName{"id", "time", "window_start", "window_end"},
+					[]interface{}{2, baseTime.Add(time.Second * 130), baseTime.Add(time.Minute * 2), baseTime.Add(time.Minute * 3)},
+					execution.WithEventTimeField("window_end"),
+				),
+			}),
+			wantErr: false,
+		},
 	}
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
@@ -2,9 +2,12 @@ package execution
 
 import (
 	"encoding/json"
+	"net"
 	"strconv"
 	"time"
 
+	"github.com/pkg/errors"
+
 	"github.com/cube2222/octosql"
 )
 
@@ -38,13 +41,96 @@ func ParseType(str string) octosql.Value {
 	var jsonObject map[string]interface{}
 	err = json.Unmarshal([]byte(str), &jsonObject)
 	if err == nil {
-		return octosql.NormalizeType(jsonObject)
+		if normalized, err := octosql.TryNormalizeType(jsonObject); err == nil {
+			return normalized
+		}
 	}
 
-	t, err := time.Parse(time.RFC3339Nano, str)
+	t, err := ParseTime(str)
 	if err == nil {
 		return octosql.MakeTime(t)
 	}
 
 	return octosql.MakeString(str)
 }
+
+// timeLayouts are the ISO 8601 time formats with an offset from UTC, like 2019-03-17T15:44:16.5+01:00.
+var timeLayouts = []string{
+	"2006-01-02T15:04:05Z07:00",
+	"2006-01-02T15:04:05Z0700",
+	"2006-01-02T15:04:05Z07",
+	"2006-01-02 15:04:05Z07:00",
+	"2006-01-02 15:04:05Z0700",
+	"2006-01-02 15:04:05Z07",
+}
+
+// ParseTime parses an ISO 8601 time with an offset from UTC, with any fraction of a second.
+// The time keeps the offset as its time zone, unless it's UTC.
+func ParseTime(str string) (time.Time, error) {
+	var err error
+	for _, layout := range timeLayouts {
+		var t time.Time
+		t, err = time.ParseInLocation(layout, str, time.UTC)
+		if err == nil {
+			return t, nil
+		}
+	}
+	return time.Time{}, err
+}
+
+// ParseTypeAs parses the given string as a value of the given type, one of int, float, bool, string, time, uuid or ip.
+// Empty strings are parsed as null for all types but string.
+func ParseTypeAs(str string, datatype string) (octosql.Value, error) {
+	switch datatype {
+	case "int", "float", "bool", "time", "uuid", "ip":
+		if str == "" {
+			return octosql.MakeNull(), nil
+		}
+	case "string":
+	default:
+		return octosql.ZeroValue(), errors.Errorf("invalid type %s, available types: int, float, bool, string, time, uuid, ip", datatype)
+	}
+
+	switch datatype {
+	case "int":
+		integer, err := strconv.ParseInt(str, 10, 64)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrapf(err, "couldn't parse %s as int", str)
+		}
+		return octosql.MakeInt(int(integer)), nil
+	case "float":
+		float, err := strconv.ParseFloat(str, 64)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrapf(err, "couldn't parse %s as float", str)
+		}
+		return octosql.MakeFloat(float), nil
+	case "bool":
+		boolean, err := strconv.ParseBool(str)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrapf(err, "couldn't parse %s as bool", str)
+		}
+		return octosql.MakeBool(boolean), nil
+	case "string":
+		return octosql.MakeString(str), nil
+	case "time":
+		t, err := ParseTime(str)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrapf(err, "couldn't parse %s as time", str)
+		}
+		return octosql.MakeTime(t), nil
+	case "uuid":
+		u, err := octosql.ParseUUID(str)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrapf(err, "couldn't parse %s as uuid", str)
+		}
+		return octosql.MakeUUID(u), nil
+	case "ip":
+		ip := net.ParseIP(str)
+		if ip == nil {
+			return octosql.ZeroValue(), errors.Errorf("couldn't parse %s as ip", str)
+		}
+		return octosql.MakeIP(ip), nil
+	default:
+		panic("unreachable")
+	}
+}
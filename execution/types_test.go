@@ -1,6 +1,7 @@
 package execution
 
 import (
+	"net"
 	"reflect"
 	"testing"
 	"time"
@@ -62,7 +63,22 @@ func TestParseType(t *testing.T) {
 		{
 			name: "parse date",
 			str:  "2019-03-17T15:44:16+01:00",
-			want: octosql.MakeTime(time.Date(2019, 03, 17, 14, 44, 16, 0, time.UTC)),
+			want: octosql.MakeTime(time.Date(2019, 03, 17, 15, 44, 16, 0, time.FixedZone("", 3600))),
+		},
+		{
+			name: "parse date with a space and an offset without a colon",
+			str:  "2019-03-17 15:44:16.25-0530",
+			want: octosql.MakeTime(time.Date(2019, 03, 17, 15, 44, 16, 250000000, time.FixedZone("", -19800))),
+		},
+		{
+			name: "parse date with an hour offset",
+			str:  "2019-03-17T15:44:16+02",
+			want: octosql.MakeTime(time.Date(2019, 03, 17, 15, 44, 16, 0, time.FixedZone("", 7200))),
+		},
+		{
+			name: "parse date in UTC",
+			str:  "2019-03-17T15:44:16Z",
+			want: octosql.MakeTime(time.Date(2019, 03, 17, 15, 44, 16, 0, time.UTC)),
 		},
 		{
 			name: "parse json",
@@ -80,6 +96,11 @@ func TestParseType(t *testing.T) {
 				}),
 			}),
 		},
+		{
+			name: "uuids stay strings",
+			str:  "123E4567-E89B-12D3-A456-426614174000",
+			want: octosql.MakeString("123E4567-E89B-12D3-A456-426614174000"),
+		},
 		{
 			name: "unparsable",
 			str:  "Lorem ipsum.",
@@ -94,7 +115,7 @@ func TestParseType(t *testing.T) {
 					reflect.TypeOf(got), reflect.TypeOf(tt.want))
 			}
 			if tt.want.GetType() == octosql.TypeTime {
-				if !tt.want.AsTime().Equal(got.AsTime()) {
+				if !tt.want.AsTime().Equal(got.AsTime()) || tt.want.GetTimeZone() != got.GetTimeZone() {
 					t.Fatalf("ParseType() = %+v, want %+v", got, tt.want)
 				}
 				return
@@ -105,3 +126,73 @@ func TestParseType(t *testing.T) {
 		})
 	}
 }
+
+func TestParseTypeAs(t *testing.T) {
+	tests := []struct {
+		name     string
+		str      string
+		datatype string
+		want     octosql.Value
+		wantErr  bool
+	}{
+		{
+			name:     "number as string",
+			str:      "01234",
+			datatype: "string",
+			want:     octosql.MakeString("01234"),
+		},
+		{
+			name:     "number as float",
+			str:      "3",
+			datatype: "float",
+			want:     octosql.MakeFloat(3),
+		},
+		{
+			name:     "empty int",
+			str:      "",
+			datatype: "int",
+			want:     octosql.MakeNull(),
+		},
+		{
+			name:     "invalid int",
+			str:      "abc",
+			datatype: "int",
+			wantErr:  true,
+		},
+		{
+			name:     "uuid",
+			str:      "123e4567-e89b-12d3-a456-426614174000",
+			datatype: "uuid",
+			want:     octosql.MakeUUID(octosql.UUID{0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17, 0x40, 0x00}),
+		},
+		{
+			name:     "ip",
+			str:      "192.168.0.1",
+			datatype: "ip",
+			want:     octosql.MakeIP(net.IPv4(192, 168, 0, 1)),
+		},
+		{
+			name:     "invalid uuid",
+			str:      "123e4567",
+			datatype: "uuid",
+			wantErr:  true,
+		},
+		{
+			name:     "invalid type",
+			str:      "abc",
+			datatype: "text",
+			wantErr:  true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ParseTypeAs(tt.str, tt.datatype)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseTypeAs() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if !tt.wantErr && !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ParseTypeAs() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+}
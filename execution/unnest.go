@@ -0,0 +1,126 @@
+package execution
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+// Unnest outputs a record for each element of the tuple the expression evaluates to for a source record.
+// The element is added to the fields of the source record, under the given name.
+type Unnest struct {
+	expression Expression
+	field      octosql.VariableName
+	source     Node
+}
+
+func NewUnnest(expression Expression, field octosql.VariableName, child Node) *Unnest {
+	return &Unnest{expression: expression, field: field, source: child}
+}
+
+func (node *Unnest) Get(ctx context.Context, variables octosql.Variables, streamID *StreamID) (RecordStream, *ExecutionOutput, error) {
+	tx := storage.GetStateTransactionFromContext(ctx)
+	sourceStreamID, err := GetSourceStreamID(tx.WithPrefix(streamID.AsPrefix()), octosql.MakePhantom())
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get source stream ID")
+	}
+
+	recordStream, execOutput, err := node.source.Get(ctx, variables, sourceStreamID)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get record stream")
+	}
+
+	return &UnnestedStream{
+		expression: node.expression,
+		field:      node.field,
+		variables:  variables,
+		source:     recordStream,
+		streamID:   streamID,
+	}, execOutput, nil
+}
+
+var unnestPendingRecordsPrefix = []byte("$unnest_pending_records$")
+
+type UnnestedStream struct {
+	expression Expression
+	field      octosql.VariableName
+	variables  octosql.Variables
+	source     RecordStream
+	streamID   *StreamID
+}
+
+func (stream *UnnestedStream) Close(ctx context.Context, storage storage.Storage) error {
+	if err := stream.source.Close(ctx, storage); err != nil {
+		return errors.Wrap(err, "couldn't close underlying stream")
+	}
+	if err := storage.DropAll(stream.streamID.AsPrefix()); err != nil {
+		return errors.Wrap(err, "couldn't clear storage with streamID prefix")
+	}
+
+	return nil
+}
+
+// The records created from a single source record are kept in storage until they're all returned,
+// so that none of them get lost if the transaction commits in between.
+func (stream *UnnestedStream) Next(ctx context.Context) (*Record, error) {
+	tx := storage.GetStateTransactionFromContext(ctx).WithPrefix(stream.streamID.AsPrefix())
+	pendingRecords := storage.NewDeque(tx.WithPrefix(unnestPendingRecordsPrefix))
+
+	for {
+		var pending Record
+		err := pendingRecords.PopFront(&pending)
+		if err == nil {
+			return &pending, nil
+		} else if err != storage.ErrNotFound {
+			return nil, errors.Wrap(err, "couldn't get pending record")
+		}
+
+		srcRecord, err := stream.source.Next(ctx)
+		if err != nil {
+			if err == ErrEndOfStream {
+				return nil, ErrEndOfStream
+			}
+			return nil, errors.Wrap(err, "couldn't get source record")
+		}
+
+		variables, err := stream.variables.MergeWith(srcRecord.AsVariables())
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't merge given variables with record variables")
+		}
+
+		value, err := stream.expression.ExpressionValue(ctx, variables)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't evaluate unnested expression")
+		}
+
+		var elements []octosql.Value
+		switch value.GetType() {
+		case octosql.TypeTuple:
+			elements = value.AsSlice()
+		case octosql.TypeNull:
+			// NULL has no elements, so there are no records for it.
+			continue
+		default:
+			return nil, errors.Errorf("couldn't unnest value of type %v, expected tuple", value.GetType())
+		}
+
+		fields := append(srcRecord.GetVariableNames(), stream.field)
+
+		for i := range elements {
+			values := append(octosql.GetValuesFromPointers(srcRecord.Data), elements[i])
+
+			// Each element gets a record ID based on the one of the source record, so that retractions match.
+			out := NewRecordFromRecord(
+				NewRecordFromSlice(fields, values, WithMetadataFrom(srcRecord)),
+				WithID(NewRecordID(fmt.Sprintf("%s.%d", srcRecord.ID().ID, i))),
+			)
+			if err := pendingRecords.PushBack(out); err != nil {
+				return nil, errors.Wrap(err, "couldn't add pending record")
+			}
+		}
+	}
+}
@@ -0,0 +1,132 @@
+package execution
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestUnnestedStream_Next(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+	ctx := storage.InjectStateTransaction(context.Background(), tx)
+
+	fieldNames := []octosql.VariableName{
+		octosql.NewVariableName("e.id"),
+		octosql.NewVariableName("e.tags"),
+	}
+	unnestedFieldNames := []octosql.VariableName{
+		octosql.NewVariableName("e.id"),
+		octosql.NewVariableName("e.tags"),
+		octosql.NewVariableName("t.element"),
+	}
+
+	type fields struct {
+		expression Expression
+		source     RecordStream
+	}
+	tests := []struct {
+		name    string
+		fields  fields
+		want    RecordStream
+		wantErr bool
+	}{
+		{
+			name: "simple unnest",
+			fields: fields{
+				expression: NewVariable("e.tags"),
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{1, []interface{}{"a", "b"}},
+						WithID(NewRecordID("1")),
+					),
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{2, []interface{}{}},
+						WithID(NewRecordID("2")),
+					),
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{3, nil},
+						WithID(NewRecordID("3")),
+					),
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{1, []interface{}{"a", "b"}},
+						WithID(NewRecordID("1")),
+						WithUndo(),
+					),
+				}),
+			},
+			want: NewInMemoryStream(ctx, []*Record{
+				NewRecordFromSliceWithNormalize(
+					unnestedFieldNames,
+					[]interface{}{1, []interface{}{"a", "b"}, "a"},
+					WithID(NewRecordID("1.0")),
+				),
+				NewRecordFromSliceWithNormalize(
+					unnestedFieldNames,
+					[]interface{}{1, []interface{}{"a", "b"}, "b"},
+					WithID(NewRecordID("1.1")),
+				),
+				NewRecordFromSliceWithNormalize(
+					unnestedFieldNames,
+					[]interface{}{1, []interface{}{"a", "b"}, "a"},
+					WithID(NewRecordID("1.0")),
+					WithUndo(),
+				),
+				NewRecordFromSliceWithNormalize(
+					unnestedFieldNames,
+					[]interface{}{1, []interface{}{"a", "b"}, "b"},
+					WithID(NewRecordID("1.1")),
+					WithUndo(),
+				),
+			}),
+			wantErr: false,
+		},
+		{
+			name: "unnest of a value which isn't a tuple",
+			fields: fields{
+				expression: NewVariable("e.id"),
+				source: NewInMemoryStream(ctx, []*Record{
+					NewRecordFromSliceWithNormalize(
+						fieldNames,
+						[]interface{}{1, []interface{}{"a", "b"}},
+					),
+				}),
+			},
+			want:    NewInMemoryStream(ctx, []*Record{}),
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stream := &UnnestedStream{
+				expression: tt.fields.expression,
+				field:      octosql.NewVariableName("t.element"),
+				variables:  octosql.NoVariables(),
+				source:     tt.fields.source,
+				streamID:   GetRawStreamID(),
+			}
+			err := AreStreamsEqual(ctx, stream, tt.want)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("UnnestedStream.Next() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+
+			if err := stream.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close unnested stream: %v", err)
+				return
+			}
+			if err := tt.want.Close(ctx, stateStorage); err != nil {
+				t.Errorf("Couldn't close wanted in_memory stream: %v", err)
+				return
+			}
+		})
+	}
+}
@@ -8,10 +8,13 @@ require (
 	github.com/bradleyjkemp/memviz v0.2.2
 	github.com/dgraph-io/badger/v2 v2.0.3
 	github.com/go-chi/chi v4.0.3+incompatible
+	github.com/go-interpreter/wagon v0.6.0
 	github.com/go-redis/redis v6.15.2+incompatible
 	github.com/go-sql-driver/mysql v1.4.1
 	github.com/golang/protobuf v1.3.1
+	github.com/golang/snappy v0.0.1
 	github.com/gosuri/uilive v0.0.4
+	github.com/klauspost/compress v1.10.5
 	github.com/kostya-sh/parquet-go v0.0.0-20180827163605-06b7130dc45c
 	github.com/lib/pq v1.0.0
 	github.com/mattn/go-isatty v0.0.12 // indirect
@@ -22,12 +25,14 @@ require (
 	github.com/olekukonko/tablewriter v0.0.1
 	github.com/onsi/ginkgo v1.8.0 // indirect
 	github.com/onsi/gomega v1.5.0 // indirect
+	github.com/pierrec/lz4 v2.0.5+incompatible
 	github.com/pkg/errors v0.8.1
 	github.com/segmentio/kafka-go v0.3.5
 	github.com/spf13/cobra v0.0.5
 	github.com/stretchr/testify v1.4.0
 	github.com/twmb/murmur3 v1.1.3
 	golang.org/x/net v0.0.0-20190620200207-3b0461eec859
+	golang.org/x/text v0.3.0
 	google.golang.org/appengine v1.5.0 // indirect
 	gopkg.in/yaml.v2 v2.2.2
 )
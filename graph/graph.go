@@ -118,3 +118,21 @@ func getGraphNode(gb *graphBuilder, node *Node) string {
 	}
 	return id
 }
+
+// ShowTree renders the node as an indented text tree.
+func ShowTree(node *Node) string {
+	var sb strings.Builder
+	writeTree(&sb, node, "")
+	return sb.String()
+}
+
+func writeTree(sb *strings.Builder, node *Node, indent string) {
+	fmt.Fprintf(sb, "%s%s\n", indent, node.Name)
+	for _, field := range node.Fields {
+		fmt.Fprintf(sb, "%s  %s: %s\n", indent, field.Name, field.Value)
+	}
+	for _, child := range node.Children {
+		fmt.Fprintf(sb, "%s  %s:\n", indent, child.Name)
+		writeTree(sb, child.Node, indent+"    ")
+	}
+}
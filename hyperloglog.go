@@ -0,0 +1,68 @@
+package octosql
+
+import (
+	"math"
+	"math/bits"
+
+	"github.com/twmb/murmur3"
+)
+
+// HyperLogLogPrecision is the number of bits of the hash used to choose the register.
+// 2^12 registers give a standard error of about 1.6%.
+const HyperLogLogPrecision = 12
+const HyperLogLogRegisterCount = 1 << HyperLogLogPrecision
+
+// HyperLogLog is a sketch estimating the number of distinct values added to it, using a fixed amount of memory.
+// Its registers can also be kept elsewhere, like in state storage, using HyperLogLogPosition and SetRegister.
+type HyperLogLog struct {
+	registers []uint8
+}
+
+func NewHyperLogLog() *HyperLogLog {
+	return &HyperLogLog{
+		registers: make([]uint8, HyperLogLogRegisterCount),
+	}
+}
+
+// HyperLogLogPosition returns the register of the value, and its rank, which is kept in the register if it's greater.
+func HyperLogLogPosition(value Value) (int, uint8) {
+	hash := murmur3.Sum64(value.MonotonicMarshal())
+	register := int(hash >> (64 - HyperLogLogPrecision))
+	// The position of the first set bit in the remaining bits, the guard bit caps it.
+	rank := uint8(bits.LeadingZeros64(hash<<HyperLogLogPrecision|1<<(HyperLogLogPrecision-1))) + 1
+	return register, rank
+}
+
+func (hll *HyperLogLog) Add(value Value) {
+	hll.SetRegister(HyperLogLogPosition(value))
+}
+
+// SetRegister sets the register to the rank, if it's greater than the current one.
+func (hll *HyperLogLog) SetRegister(register int, rank uint8) {
+	if rank > hll.registers[register] {
+		hll.registers[register] = rank
+	}
+}
+
+// Estimate returns the estimated number of distinct values added.
+func (hll *HyperLogLog) Estimate() int {
+	m := float64(HyperLogLogRegisterCount)
+	sum := 0.0
+	zeroRegisters := 0
+	for _, rank := range hll.registers {
+		sum += math.Ldexp(1, -int(rank))
+		if rank == 0 {
+			zeroRegisters++
+		}
+	}
+
+	alpha := 0.7213 / (1 + 1.079/m)
+	estimate := alpha * m * m / sum
+
+	// For small cardinalities linear counting is more accurate.
+	if estimate <= 2.5*m && zeroRegisters > 0 {
+		estimate = m * math.Log(m/float64(zeroRegisters))
+	}
+
+	return int(math.Round(estimate))
+}
@@ -0,0 +1,36 @@
+package octosql
+
+import (
+	"testing"
+)
+
+func TestHyperLogLog(t *testing.T) {
+	tests := []struct {
+		name     string
+		distinct int
+	}{
+		{
+			name:     "few values",
+			distinct: 100,
+		},
+		{
+			name:     "many values",
+			distinct: 100000,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			hll := NewHyperLogLog()
+			for i := 0; i < tt.distinct; i++ {
+				// Every value is added twice, which mustn't change the estimate.
+				hll.Add(MakeInt(i))
+				hll.Add(MakeInt(i))
+			}
+
+			got := hll.Estimate()
+			if errorRate := float64(got-tt.distinct) / float64(tt.distinct); errorRate > 0.05 || errorRate < -0.05 {
+				t.Errorf("Estimate() = %v, want about %v", got, tt.distinct)
+			}
+		})
+	}
+}
@@ -20,6 +20,14 @@ func NewFilter(formula Formula, child Node) *Filter {
 }
 
 func (node *Filter) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	outputNodes, variables, ok, err := node.physicalSemiJoin(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for filter as semi join")
+	}
+	if ok {
+		return outputNodes, variables, nil
+	}
+
 	formula, formulaVariables, err := node.formula.Physical(ctx, physicalCreator)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for formula")
@@ -29,12 +37,12 @@ func (node *Filter) Physical(ctx context.Context, physicalCreator *PhysicalPlanC
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for filter source node")
 	}
 
-	variables, err := sourceVariables.MergeWith(formulaVariables)
+	variables, err = sourceVariables.MergeWith(formulaVariables)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't merge variables for filter source")
 	}
 
-	outputNodes := make([]physical.Node, len(sourceNodes))
+	outputNodes = make([]physical.Node, len(sourceNodes))
 	for i := range outputNodes {
 		outputNodes[i] = physical.NewFilter(formula, sourceNodes[i])
 	}
@@ -18,31 +18,41 @@ import (
 type Aggregate string
 
 const (
-	Avg           Aggregate = "avg"
-	AvgDistinct   Aggregate = "avg_distinct"
-	Count         Aggregate = "count"
-	CountDistinct Aggregate = "count_distinct"
-	First         Aggregate = "first"
-	Key           Aggregate = "key"
-	Last          Aggregate = "last"
-	Max           Aggregate = "max"
-	Min           Aggregate = "min"
-	Sum           Aggregate = "sum"
-	SumDistinct   Aggregate = "sum_distinct"
+	ApproxCountDistinct Aggregate = "approx_count_distinct"
+	ApproxPercentile    Aggregate = "approx_percentile"
+	ArrayAgg            Aggregate = "array_agg"
+	Avg                 Aggregate = "avg"
+	AvgDistinct         Aggregate = "avg_distinct"
+	Count               Aggregate = "count"
+	CountDistinct       Aggregate = "count_distinct"
+	First               Aggregate = "first"
+	Key                 Aggregate = "key"
+	Last                Aggregate = "last"
+	Max                 Aggregate = "max"
+	Min                 Aggregate = "min"
+	Stddev              Aggregate = "stddev"
+	Sum                 Aggregate = "sum"
+	SumDistinct         Aggregate = "sum_distinct"
+	Variance            Aggregate = "variance"
 )
 
 var AggregateFunctions = map[Aggregate]struct{}{
-	Avg:           {},
-	AvgDistinct:   {},
-	Count:         {},
-	CountDistinct: {},
-	First:         {},
-	Last:          {},
-	Max:           {},
-	Key:           {},
-	Min:           {},
-	Sum:           {},
-	SumDistinct:   {},
+	ApproxCountDistinct: {},
+	ApproxPercentile:    {},
+	ArrayAgg:            {},
+	Avg:                 {},
+	AvgDistinct:         {},
+	Count:               {},
+	CountDistinct:       {},
+	First:               {},
+	Last:                {},
+	Max:                 {},
+	Key:                 {},
+	Min:                 {},
+	Stddev:              {},
+	Sum:                 {},
+	SumDistinct:         {},
+	Variance:            {},
 }
 
 type Trigger interface {
@@ -95,18 +105,34 @@ func (w *DelayTrigger) Visualize() *graph.Node {
 }
 
 type WatermarkTrigger struct {
+	AllowedLateness Expression
 }
 
 func NewWatermarkTrigger() *WatermarkTrigger {
 	return &WatermarkTrigger{}
 }
 
+// WithAllowedLateness makes the trigger fire again for records which are late by at most the given duration.
+func (w *WatermarkTrigger) WithAllowedLateness(allowedLateness Expression) *WatermarkTrigger {
+	return &WatermarkTrigger{AllowedLateness: allowedLateness}
+}
+
 func (w *WatermarkTrigger) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.Trigger, octosql.Variables, error) {
-	return physical.NewWatermarkTrigger(), octosql.NoVariables(), nil
+	if w.AllowedLateness == nil {
+		return physical.NewWatermarkTrigger(), octosql.NoVariables(), nil
+	}
+	allowedLatenessExpr, vars, err := w.AllowedLateness.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for allowed lateness expression")
+	}
+	return physical.NewWatermarkTrigger().WithAllowedLateness(allowedLatenessExpr), vars, nil
 }
 
 func (w *WatermarkTrigger) Visualize() *graph.Node {
 	n := graph.NewNode("Watermark Trigger")
+	if w.AllowedLateness != nil {
+		n.AddChild("allowed_lateness", w.AllowedLateness.Visualize())
+	}
 	return n
 }
 
@@ -155,6 +181,10 @@ func (node *GroupBy) Physical(ctx context.Context, physicalCreator *PhysicalPlan
 	aggregates := make([]physical.Aggregate, len(node.aggregates))
 	for i := range node.aggregates {
 		switch Aggregate(strings.ToLower(string(node.aggregates[i]))) {
+		case ApproxCountDistinct:
+			aggregates[i] = physical.ApproxCountDistinct
+		case ArrayAgg:
+			aggregates[i] = physical.ArrayAgg
 		case Avg:
 			aggregates[i] = physical.Avg
 		case AvgDistinct:
@@ -173,10 +203,14 @@ func (node *GroupBy) Physical(ctx context.Context, physicalCreator *PhysicalPlan
 			aggregates[i] = physical.Max
 		case Min:
 			aggregates[i] = physical.Min
+		case Stddev:
+			aggregates[i] = physical.Stddev
 		case Sum:
 			aggregates[i] = physical.Sum
 		case SumDistinct:
 			aggregates[i] = physical.SumDistinct
+		case Variance:
+			aggregates[i] = physical.Variance
 		default:
 			return nil, nil, errors.Errorf("invalid aggregate: %s", node.aggregates[i])
 		}
@@ -47,6 +47,17 @@ func (node *Join) Visualize() *graph.Node {
 }
 
 func (node *Join) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	if err := physicalCreator.registerJoin(node); err != nil {
+		return nil, nil, err
+	}
+
+	// With statistics available, a chain of inner joins gets planned at once, so that its order can be chosen.
+	if physicalCreator.statistics != nil {
+		if chain, ok := node.getJoinChain(physicalCreator); ok {
+			return node.physicalJoinChain(ctx, physicalCreator, chain)
+		}
+	}
+
 	sourceNodes, sourceVariables, err := node.source.Physical(ctx, physicalCreator)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for join source nodes")
@@ -57,11 +68,23 @@ func (node *Join) Physical(ctx context.Context, physicalCreator *PhysicalPlanCre
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for left join joined nodes")
 	}
 
+	return node.physicalFromNodes(ctx, physicalCreator, sourceNodes, sourceVariables, joinedNodes, joinedVariables)
+}
+
+// physicalFromNodes plans the join of the already planned source and joined nodes.
+func (node *Join) physicalFromNodes(ctx context.Context, physicalCreator *PhysicalPlanCreator, sourceNodes []physical.Node, sourceVariables octosql.Variables, joinedNodes []physical.Node, joinedVariables octosql.Variables) ([]physical.Node, octosql.Variables, error) {
 	variables, err := sourceVariables.MergeWith(joinedVariables)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't merge variables for source and joined nodes")
 	}
 
+	// Semi and anti joins never output the joined records, so only the other joins could mix up fields of the same alias.
+	if node.joinType != execution.SEMI_JOIN && node.joinType != execution.ANTI_JOIN {
+		if alias, ok := getSharedAlias(sourceNodes[0].Metadata().Namespace(), joinedNodes[0].Metadata().Namespace()); ok {
+			return nil, nil, errors.Errorf("ambiguous alias %s, both joined tables use it, so their fields can't be told apart", alias)
+		}
+	}
+
 	// Based on the cardinality of sources we decide whether we will create a stream_join or a lookup_join
 	// Stream joins support only equity conjunctions (i.e a.x = b.y AND a.v + 17 = b.something * 2)
 	sourceCardinality := sourceNodes[0].Metadata().Cardinality()
@@ -83,11 +106,21 @@ func (node *Join) Physical(ctx context.Context, physicalCreator *PhysicalPlanCre
 		canBeLookupJoin = false
 	}
 
+	// Semi and anti joins come from decorrelated subqueries, which have to be evaluated per record if a stream join isn't possible
+	isSemiJoin := node.joinType == execution.SEMI_JOIN || node.joinType == execution.ANTI_JOIN
+	if isSemiJoin {
+		isStreamJoin = true
+		canBeLookupJoin = false
+	}
+
 	if isStreamJoin {
 		outNodes, variables, err := node.physicalStreamJoin(ctx, physicalCreator, variables, sourceNodes, joinedNodes)
 
 		// If the ON part of join isn't supported by stream join we can fallback to lookup join if we can (so no OUTER join and no two unbounded sources)
 		if err == ErrFallbackToLookupJoin {
+			if isSemiJoin {
+				return nil, nil, ErrCannotDecorrelate
+			}
 			if !canBeLookupJoin {
 				return nil, nil, errors.New("provided join statement can't be made into a stream join nor a lookup join")
 			}
@@ -118,33 +151,98 @@ func (node *Join) physicalStreamJoin(ctx context.Context, physicalCreator *Physi
 	// (otherwise it's just two nodes). We take the formula from the filter to create the key, and set the joined node
 	// as the source of the filter (basically we get rid of the formula node-wise, create key expressions for it,
 	// and the sources are the source and the source of the filter)
+	// We create a new slice, so the filter is still there in case we fall back to a lookup join.
 	if filter, ok := joinedNodes[0].(*physical.Filter); ok {
 		formula = filter.Formula
+		unfilteredJoinedNodes := make([]physical.Node, len(joinedNodes))
 		for i := range joinedNodes {
-			joinedNodes[i] = joinedNodes[i].(*physical.Filter).Source
+			unfilteredJoinedNodes[i] = joinedNodes[i].(*physical.Filter).Source
 		}
+		joinedNodes = unfilteredJoinedNodes
 	} else {
 		formula = physical.NewConstant(true)
 	}
 
-	// We check if the ON part of the join is legal for a stream join
-
-	if !isConjunctionOfEqualities(formula) {
-		return nil, nil, ErrFallbackToLookupJoin
-	}
-
 	// Create necessary namespaces of source and joined
 	sourceNamespace := sourceNodes[0].Metadata().Namespace() // TODO: should these be merged with variables
 	joinedNamespace := joinedNodes[0].Metadata().Namespace()
 	eventTimeField := sourceNodes[0].Metadata().EventTimeField()
 
+	// We split the ON part of the join into equalities between the source and joined, which make up the key,
+	// and the remaining predicates, which can be checked on the joined records afterwards.
+	keyFormula, residualFormula := splitJoinFormula(formula, sourceNamespace, joinedNamespace)
+
+	// We check if the ON part of the join is legal for a stream join
+	if !isConjunctionOfEqualities(keyFormula) {
+		return nil, nil, ErrFallbackToLookupJoin
+	}
+
 	// Create the appropriate keys from the formula. Basically how it works is it goes through predicates i.e a.x = b.y
 	// and then decides whether a.x forms part of source or joined and then adds it to the appropriate key.
-	sourceKey, joinedKey, err := getKeysFromFormula(formula, sourceNamespace, joinedNamespace)
+	sourceKey, joinedKey, err := getKeysFromFormula(keyFormula, sourceNamespace, joinedNamespace)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't create keys from join formula")
 	}
 
+	// Semi and anti joins never output the joined records, so predicates using only them can filter the joined records before the join.
+	if residualFormula != nil && (node.joinType == execution.SEMI_JOIN || node.joinType == execution.ANTI_JOIN) {
+		var joinedFormula physical.Formula
+		joinedFormula, residualFormula = splitJoinedFormula(residualFormula, joinedNamespace)
+		if joinedFormula != nil {
+			filteredJoinedNodes := make([]physical.Node, len(joinedNodes))
+			for i := range joinedNodes {
+				filteredJoinedNodes[i] = physical.NewFilter(joinedFormula, joinedNodes[i])
+			}
+			joinedNodes = filteredJoinedNodes
+		}
+	}
+
+	// A stream joined with a refreshed table is matched with the versions of the table which were valid at the event time
+	// of its records, instead of with every version the table has ever had.
+	if qualifier, ok := getRefreshedTableQualifier(joinedNodes[0]); ok &&
+		(node.joinType == execution.INNER_JOIN || (node.joinType == execution.LEFT_JOIN && residualFormula == nil)) &&
+		sourceNodes[0].Metadata().Cardinality() == metadata.Unbounded {
+		return node.physicalTemporalJoin(physicalCreator, variables, sourceNodes, joinedNodes, sourceKey, joinedKey, qualifier, residualFormula, eventTimeField)
+	}
+
+	// A condition restricting the difference between the times of the joined and source record lets us match records
+	// as they come and clear the ones which can't get any new matches, instead of keeping both streams forever.
+	if residualFormula != nil &&
+		node.joinType == execution.INNER_JOIN &&
+		sourceNodes[0].Metadata().Cardinality() == metadata.Unbounded &&
+		joinedNodes[0].Metadata().Cardinality() == metadata.Unbounded {
+		if bounds, ok := getIntervalJoinBounds(residualFormula, sourceNamespace, joinedNamespace); ok {
+			return node.physicalIntervalJoin(physicalCreator, variables, sourceNodes, joinedNodes, sourceKey, joinedKey, bounds, residualFormula, eventTimeField)
+		}
+	}
+
+	// Without any keys this is a cross join, which ends up in a single partition. Between two unbounded sources
+	// it would keep every record forever and produce an ever growing number of pairs, so it has to be explicitly allowed.
+	// This includes joins whose only conditions, from ON or WHERE, aren't equalities.
+	unboundedCrossJoin := len(sourceKey) == 0 &&
+		sourceNodes[0].Metadata().Cardinality() == metadata.Unbounded &&
+		joinedNodes[0].Metadata().Cardinality() == metadata.Unbounded
+	if unboundedCrossJoin {
+		allowUnboundedCrossJoin, err := config.GetBool(
+			physicalCreator.physicalConfig,
+			"allowUnboundedCrossJoin",
+			config.WithDefault(false),
+		)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't get allowUnboundedCrossJoin configuration")
+		}
+		if !allowUnboundedCrossJoin {
+			return nil, nil, errors.New("cross join between two unbounded sources, add an equality join condition or set allowUnboundedCrossJoin in the physical configuration")
+		}
+	}
+
+	// Filtering the joined records is only correct for inner joins, as outer joins would have to emit
+	// the records without a match instead. Without any key it's a nested loop anyway, which the lookup join does,
+	// unless both sources are unbounded, in which case the allowed cross join gets filtered.
+	if residualFormula != nil && (node.joinType != execution.INNER_JOIN || (len(sourceKey) == 0 && !unboundedCrossJoin)) {
+		return nil, nil, ErrFallbackToLookupJoin
+	}
+
 	// Get the number of partitions into which the stream join will be split
 	streamJoinParallelism, err := config.GetInt(
 		physicalCreator.physicalConfig,
@@ -186,11 +284,339 @@ func (node *Join) physicalStreamJoin(ctx context.Context, physicalCreator *Physi
 
 	for i := range outNodes {
 		outNodes[i] = physical.NewStreamJoin(sourceShuffled[i], joinedShuffled[i], sourceKey, joinedKey, eventTimeField, node.joinType, triggers)
+		if residualFormula != nil {
+			outNodes[i] = physical.NewFilter(residualFormula, outNodes[i])
+		}
+	}
+
+	return outNodes, variables, nil
+}
+
+// physicalIntervalJoin creates an interval join partitioned by the join key. The whole residual formula is still checked
+// on the joined records, as the bounds only describe the range of times which is kept in storage.
+func (node *Join) physicalIntervalJoin(physicalCreator *PhysicalPlanCreator, variables octosql.Variables, sourceNodes, joinedNodes []physical.Node, sourceKey, joinedKey []physical.Expression, bounds *intervalJoinBounds, residualFormula physical.Formula, eventTimeField octosql.VariableName) ([]physical.Node, octosql.Variables, error) {
+	streamJoinParallelism, err := config.GetInt(
+		physicalCreator.physicalConfig,
+		"streamJoinParallelism",
+		config.WithDefault(runtime.GOMAXPROCS(0)),
+	)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get streamJoinParallelism configuration")
+	}
+
+	sourceShuffled := physical.NewShuffle(streamJoinParallelism, physical.NewKeyHashingStrategy(sourceKey), sourceNodes)
+	joinedShuffled := physical.NewShuffle(streamJoinParallelism, physical.NewKeyHashingStrategy(joinedKey), joinedNodes)
+
+	for i := range sourceShuffled {
+		sourceShuffled[i] = physical.NewNextShuffleMetadataChange("_left", i, sourceShuffled[i])
+	}
+
+	for i := range joinedShuffled {
+		joinedShuffled[i] = physical.NewNextShuffleMetadataChange("_right", i, joinedShuffled[i])
+	}
+
+	outNodes := make([]physical.Node, len(sourceShuffled))
+
+	for i := range outNodes {
+		outNodes[i] = physical.NewIntervalJoin(
+			sourceShuffled[i],
+			joinedShuffled[i],
+			sourceKey,
+			joinedKey,
+			bounds.sourceTimeField,
+			bounds.joinedTimeField,
+			bounds.lowerBound,
+			bounds.upperBound,
+			eventTimeField,
+		)
+		outNodes[i] = physical.NewFilter(residualFormula, outNodes[i])
+	}
+
+	return outNodes, variables, nil
+}
+
+// physicalTemporalJoin creates a temporal join partitioned by the join key. The residual formula is checked on the joined records.
+func (node *Join) physicalTemporalJoin(physicalCreator *PhysicalPlanCreator, variables octosql.Variables, sourceNodes, joinedNodes []physical.Node, sourceKey, joinedKey []physical.Expression, qualifier string, residualFormula physical.Formula, eventTimeField octosql.VariableName) ([]physical.Node, octosql.Variables, error) {
+	streamJoinParallelism, err := config.GetInt(
+		physicalCreator.physicalConfig,
+		"streamJoinParallelism",
+		config.WithDefault(runtime.GOMAXPROCS(0)),
+	)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get streamJoinParallelism configuration")
+	}
+
+	sourceShuffled := physical.NewShuffle(streamJoinParallelism, physical.NewKeyHashingStrategy(sourceKey), sourceNodes)
+	joinedShuffled := physical.NewShuffle(streamJoinParallelism, physical.NewKeyHashingStrategy(joinedKey), joinedNodes)
+
+	for i := range sourceShuffled {
+		sourceShuffled[i] = physical.NewNextShuffleMetadataChange("_left", i, sourceShuffled[i])
+	}
+
+	for i := range joinedShuffled {
+		joinedShuffled[i] = physical.NewNextShuffleMetadataChange("_right", i, joinedShuffled[i])
+	}
+
+	validFromField := octosql.NewVariableName(fmt.Sprintf("%s.valid_from", qualifier))
+	validToField := octosql.NewVariableName(fmt.Sprintf("%s.valid_to", qualifier))
+
+	outNodes := make([]physical.Node, len(sourceShuffled))
+
+	for i := range outNodes {
+		outNodes[i] = physical.NewTemporalJoin(
+			sourceShuffled[i],
+			joinedShuffled[i],
+			sourceKey,
+			joinedKey,
+			validFromField,
+			validToField,
+			node.joinType == execution.LEFT_JOIN,
+			eventTimeField,
+		)
+		if residualFormula != nil {
+			outNodes[i] = physical.NewFilter(residualFormula, outNodes[i])
+		}
 	}
 
 	return outNodes, variables, nil
 }
 
+// getRefreshedTableQualifier returns the qualifier of the node, if it's an aliased refresh table valued function.
+func getRefreshedTableQualifier(node physical.Node) (string, bool) {
+	requalifier, ok := node.(*physical.Requalifier)
+	if !ok {
+		return "", false
+	}
+	tvf, ok := requalifier.Source.(*physical.TableValuedFunction)
+	if !ok || tvf.Name != "refresh" {
+		return "", false
+	}
+	return requalifier.Qualifier, true
+}
+
+// intervalJoinBounds describes the range the joined record time minus the source record time has to be in.
+// A nil bound is zero.
+type intervalJoinBounds struct {
+	sourceTimeField, joinedTimeField octosql.VariableName
+	lowerBound, upperBound           physical.Expression
+}
+
+// getIntervalJoinBounds looks for comparisons between a field of the source and a field of the joined node,
+// each optionally offset by an expression which doesn't depend on the records, i.e. a.t >= b.t - INTERVAL 5 MINUTE.
+// It succeeds if there is both a lower and an upper bound on the difference between the same two fields.
+// Strict comparisons are treated as non-strict, the formula itself has to be checked on the joined records anyway.
+func getIntervalJoinBounds(formula physical.Formula, sourceNamespace, joinedNamespace *metadata.Namespace) (*intervalJoinBounds, bool) {
+	var bounds *intervalJoinBounds
+	hasLowerBound, hasUpperBound := false, false
+
+	for _, element := range formula.SplitByAnd() {
+		predicate, ok := element.(*physical.Predicate)
+		if !ok {
+			continue
+		}
+
+		// We rewrite the predicate to the form greater >= lesser.
+		var greater, lesser physical.Expression
+		switch predicate.Relation {
+		case physical.GreaterEqual, physical.MoreThan:
+			greater, lesser = predicate.Left, predicate.Right
+		case physical.LessEqual, physical.LessThan:
+			greater, lesser = predicate.Right, predicate.Left
+		default:
+			continue
+		}
+
+		greaterField, greaterOffset, ok := splitTimeFieldAndOffset(greater)
+		if !ok {
+			continue
+		}
+		lesserField, lesserOffset, ok := splitTimeFieldAndOffset(lesser)
+		if !ok {
+			continue
+		}
+
+		var sourceField, joinedField octosql.VariableName
+		var isUpperBound bool
+		switch {
+		case isOnlyInNamespace(greaterField, sourceNamespace, joinedNamespace) && isOnlyInNamespace(lesserField, joinedNamespace, sourceNamespace):
+			// source + greaterOffset >= joined + lesserOffset, so joined - source <= greaterOffset - lesserOffset
+			sourceField, joinedField = greaterField, lesserField
+			isUpperBound = true
+		case isOnlyInNamespace(greaterField, joinedNamespace, sourceNamespace) && isOnlyInNamespace(lesserField, sourceNamespace, joinedNamespace):
+			// joined + greaterOffset >= source + lesserOffset, so joined - source >= lesserOffset - greaterOffset
+			sourceField, joinedField = lesserField, greaterField
+			isUpperBound = false
+		default:
+			continue
+		}
+
+		if bounds == nil {
+			bounds = &intervalJoinBounds{
+				sourceTimeField: sourceField,
+				joinedTimeField: joinedField,
+			}
+		} else if !bounds.sourceTimeField.Equal(sourceField) || !bounds.joinedTimeField.Equal(joinedField) {
+			continue
+		}
+
+		if isUpperBound && !hasUpperBound {
+			bounds.upperBound = subtractOffsets(greaterOffset, lesserOffset)
+			hasUpperBound = true
+		} else if !isUpperBound && !hasLowerBound {
+			bounds.lowerBound = subtractOffsets(lesserOffset, greaterOffset)
+			hasLowerBound = true
+		}
+	}
+
+	if !hasLowerBound || !hasUpperBound {
+		return nil, false
+	}
+	return bounds, true
+}
+
+// splitTimeFieldAndOffset splits an expression of the form field, field + offset, offset + field or field - offset.
+// The field has to be qualified by a source, while the offset can't depend on the record. A nil offset is zero.
+func splitTimeFieldAndOffset(expr physical.Expression) (octosql.VariableName, physical.Expression, bool) {
+	switch expr := expr.(type) {
+	case *physical.Variable:
+		if expr.Name.Source() == "" {
+			return "", nil, false
+		}
+		return expr.Name, nil, true
+	case *physical.FunctionExpression:
+		if len(expr.Arguments) != 2 {
+			return "", nil, false
+		}
+		left, right := expr.Arguments[0], expr.Arguments[1]
+
+		switch expr.Name {
+		case "+":
+			if field, offset, ok := splitTimeFieldAndOffset(left); ok && offset == nil && isRecordIndependent(right) {
+				return field, right, true
+			}
+			if field, offset, ok := splitTimeFieldAndOffset(right); ok && offset == nil && isRecordIndependent(left) {
+				return field, left, true
+			}
+		case "-":
+			if field, offset, ok := splitTimeFieldAndOffset(left); ok && offset == nil && isRecordIndependent(right) {
+				return field, physical.NewFunctionExpression("-", []physical.Expression{right}), true
+			}
+		}
+	}
+
+	return "", nil, false
+}
+
+// isRecordIndependent checks if the expression only uses constants and variables which don't come from a source.
+func isRecordIndependent(expr physical.Expression) bool {
+	switch expr := expr.(type) {
+	case *physical.Variable:
+		return expr.Name.Source() == ""
+	case *physical.FunctionExpression:
+		for _, arg := range expr.Arguments {
+			if !isRecordIndependent(arg) {
+				return false
+			}
+		}
+		return true
+	default:
+		return false
+	}
+}
+
+// getSharedAlias returns an alias qualifying the fields of both namespaces, if there is any.
+func getSharedAlias(namespace, otherNamespace *metadata.Namespace) (string, bool) {
+	for _, prefix := range otherNamespace.Prefixes() {
+		if prefix != "" && namespace.DoesContainPrefix(prefix) {
+			return prefix, true
+		}
+	}
+	return "", false
+}
+
+func isOnlyInNamespace(name octosql.VariableName, namespace, otherNamespace *metadata.Namespace) bool {
+	return namespace.DoesContainPrefix(name.Source()) && !otherNamespace.DoesContainPrefix(name.Source())
+}
+
+// subtractOffsets returns an expression for left - right, where a nil offset is zero.
+func subtractOffsets(left, right physical.Expression) physical.Expression {
+	switch {
+	case right == nil:
+		return left
+	case left == nil:
+		return physical.NewFunctionExpression("-", []physical.Expression{right})
+	default:
+		return physical.NewFunctionExpression("-", []physical.Expression{left, right})
+	}
+}
+
+// splitJoinFormula splits the formula into a conjunction of equalities which can be used as join keys,
+// and a conjunction of the remaining predicates, which is nil if there are none.
+func splitJoinFormula(formula physical.Formula, sourceNamespace, joinedNamespace *metadata.Namespace) (physical.Formula, physical.Formula) {
+	var keyFormula physical.Formula = physical.NewConstant(true)
+	var residualFormula physical.Formula
+
+	for _, element := range formula.SplitByAnd() {
+		if isJoinKeyPredicate(element, sourceNamespace, joinedNamespace) {
+			keyFormula = physical.NewAnd(keyFormula, element)
+		} else if residualFormula == nil {
+			residualFormula = element
+		} else {
+			residualFormula = physical.NewAnd(residualFormula, element)
+		}
+	}
+
+	return keyFormula, residualFormula
+}
+
+// splitJoinedFormula splits the formula into a conjunction of the predicates which only use the joined namespace,
+// and a conjunction of the remaining predicates. Either of them is nil if there are no such predicates.
+func splitJoinedFormula(formula physical.Formula, joinedNamespace *metadata.Namespace) (physical.Formula, physical.Formula) {
+	var joinedFormula physical.Formula
+	var residualFormula physical.Formula
+
+	for _, element := range formula.SplitByAnd() {
+		if element.DoesMatchNamespace(joinedNamespace) {
+			if joinedFormula == nil {
+				joinedFormula = element
+			} else {
+				joinedFormula = physical.NewAnd(joinedFormula, element)
+			}
+		} else if residualFormula == nil {
+			residualFormula = element
+		} else {
+			residualFormula = physical.NewAnd(residualFormula, element)
+		}
+	}
+
+	return joinedFormula, residualFormula
+}
+
+// isJoinKeyPredicate checks if the formula is a constant true, or an equality between an expression of the source and an expression of the joined node.
+func isJoinKeyPredicate(f physical.Formula, sourceNamespace, joinedNamespace *metadata.Namespace) bool {
+	switch f := f.(type) {
+	case *physical.Constant:
+		return f.Value
+	case *physical.Predicate:
+		if f.Relation != physical.Equal {
+			return false
+		}
+
+		doesLeftMatchSource := f.Left.DoesMatchNamespace(sourceNamespace)
+		doesLeftMatchJoined := f.Left.DoesMatchNamespace(joinedNamespace)
+		doesRightMatchSource := f.Right.DoesMatchNamespace(sourceNamespace)
+		doesRightMatchJoined := f.Right.DoesMatchNamespace(joinedNamespace)
+
+		// These are the same conditions that getKeysFromFormula checks
+		return (doesLeftMatchSource || doesLeftMatchJoined) &&
+			(doesRightMatchSource || doesRightMatchJoined) &&
+			(doesLeftMatchSource || doesRightMatchSource) &&
+			(doesLeftMatchJoined || doesRightMatchJoined)
+	default:
+		return false
+	}
+}
+
 func isConjunctionOfEqualities(f physical.Formula) bool {
 	switch f := f.(type) {
 	case *physical.And:
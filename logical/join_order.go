@@ -0,0 +1,335 @@
+package logical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+// joinChainStep is a table of a chain of inner joins, together with the ON condition it's joined with.
+// The first step of a chain has neither a join nor a condition.
+type joinChainStep struct {
+	join      *Join
+	table     Node
+	condition Formula
+}
+
+// plannedJoinChainStep is a step of a join chain with its table and condition already planned.
+type plannedJoinChainStep struct {
+	join               *Join
+	nodes              []physical.Node
+	variables          octosql.Variables
+	condition          physical.Formula
+	conditionVariables octosql.Variables
+}
+
+// getJoinChain returns the tables of the chain of inner joins ending with this join, in the order they're joined in.
+// Joins using triggers, or conditions with subqueries, end the chain, as their order matters.
+func (node *Join) getJoinChain(physicalCreator *PhysicalPlanCreator) ([]joinChainStep, bool) {
+	if node.joinType != execution.INNER_JOIN || len(node.triggers) > 0 {
+		return nil, false
+	}
+
+	step := joinChainStep{
+		join:  node,
+		table: node.joined,
+	}
+	if filter, ok := node.joined.(*Filter); ok {
+		if !isReorderableFormula(physicalCreator, filter.formula) {
+			return nil, false
+		}
+		step.table = filter.source
+		step.condition = filter.formula
+	}
+
+	if source, ok := node.source.(*Join); ok {
+		if chain, ok := source.getJoinChain(physicalCreator); ok {
+			return append(chain, step), true
+		}
+	}
+	return []joinChainStep{{table: node.source}, step}, true
+}
+
+// isReorderableFormula checks if the formula can be moved between joins,
+// which isn't the case for subqueries, and expressions bound using LET, which may contain them.
+func isReorderableFormula(physicalCreator *PhysicalPlanCreator, formula Formula) bool {
+	switch formula := formula.(type) {
+	case *BooleanConstant:
+		return true
+	case *InfixOperator:
+		return isReorderableFormula(physicalCreator, formula.Left) && isReorderableFormula(physicalCreator, formula.Right)
+	case *PrefixOperator:
+		return isReorderableFormula(physicalCreator, formula.Child)
+	case *Predicate:
+		return isReorderableExpression(physicalCreator, formula.Left) && isReorderableExpression(physicalCreator, formula.Right)
+	case *IsNull:
+		return isReorderableExpression(physicalCreator, formula.Expression)
+	default:
+		return false
+	}
+}
+
+func isReorderableExpression(physicalCreator *PhysicalPlanCreator, expr Expression) bool {
+	switch expr := expr.(type) {
+	case *Variable:
+		_, isBound := physicalCreator.bindings[expr.name]
+		return !isBound
+	case *Constant:
+		return true
+	case *FunctionExpression:
+		for i := range expr.arguments {
+			if !isReorderableExpression(physicalCreator, expr.arguments[i]) {
+				return false
+			}
+		}
+		return true
+	case *Tuple:
+		for i := range expr.expressions {
+			if !isReorderableExpression(physicalCreator, expr.expressions[i]) {
+				return false
+			}
+		}
+		return true
+	case *Interval:
+		return isReorderableExpression(physicalCreator, expr.count) && isReorderableExpression(physicalCreator, expr.unit)
+	case *AliasedExpression:
+		return isReorderableExpression(physicalCreator, expr.expr)
+	case *LogicExpression:
+		return isReorderableFormula(physicalCreator, expr.formula)
+	default:
+		return false
+	}
+}
+
+// physicalJoinChain plans a chain of inner joins. If all the tables are bounded and their sizes can be estimated,
+// the tables get joined in the order which keeps the estimated intermediate results small, with each condition
+// checked by the first join which has all the tables it uses. Otherwise they're joined in the order of the query.
+func (node *Join) physicalJoinChain(ctx context.Context, physicalCreator *PhysicalPlanCreator, chain []joinChainStep) ([]physical.Node, octosql.Variables, error) {
+	planned := make([]plannedJoinChainStep, len(chain))
+	for i := range chain {
+		if chain[i].join != nil && chain[i].join != node {
+			if err := physicalCreator.registerJoin(chain[i].join); err != nil {
+				return nil, nil, err
+			}
+		}
+
+		nodes, variables, err := chain[i].table.Physical(ctx, physicalCreator)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't get physical plan for joined table with index %d", i)
+		}
+		planned[i] = plannedJoinChainStep{
+			join:      chain[i].join,
+			nodes:     nodes,
+			variables: variables,
+		}
+
+		if chain[i].condition != nil {
+			condition, conditionVariables, err := chain[i].condition.Physical(ctx, physicalCreator)
+			if err != nil {
+				return nil, nil, errors.Wrapf(err, "couldn't get physical plan for condition of joined table with index %d", i)
+			}
+			planned[i].condition = condition
+			planned[i].conditionVariables = conditionVariables
+		}
+	}
+
+	rowCounts, ok, err := estimateJoinChainRowCounts(physicalCreator, planned)
+	if err != nil {
+		return nil, nil, err
+	}
+	if !ok {
+		return joinInOrder(ctx, physicalCreator, planned)
+	}
+
+	return joinInEstimatedOrder(ctx, physicalCreator, planned, rowCounts)
+}
+
+// estimateJoinChainRowCounts returns the estimated sizes of the tables of the join chain,
+// if joins may be reordered, the tables are bounded and the sizes of all of them are known.
+func estimateJoinChainRowCounts(physicalCreator *PhysicalPlanCreator, planned []plannedJoinChainStep) ([]int64, bool, error) {
+	if physicalCreator.statistics == nil {
+		return nil, false, nil
+	}
+	joinReordering, err := config.GetBool(
+		physicalCreator.physicalConfig,
+		"joinReordering",
+		config.WithDefault(true),
+	)
+	if err != nil {
+		return nil, false, errors.Wrap(err, "couldn't get joinReordering configuration")
+	}
+	if !joinReordering {
+		return nil, false, nil
+	}
+
+	rowCounts := make([]int64, len(planned))
+	for i := range planned {
+		if planned[i].nodes[0].Metadata().Cardinality() == metadata.Unbounded {
+			return nil, false, nil
+		}
+		rowCount, ok := physicalCreator.statistics.EstimateRowCount(planned[i].nodes[0])
+		if !ok {
+			return nil, false, nil
+		}
+		rowCounts[i] = rowCount
+	}
+	return rowCounts, true, nil
+}
+
+// joinInOrder joins the tables in the order of the query, which is the same as planning the joins one by one.
+func joinInOrder(ctx context.Context, physicalCreator *PhysicalPlanCreator, planned []plannedJoinChainStep) ([]physical.Node, octosql.Variables, error) {
+	nodes, variables := planned[0].nodes, planned[0].variables
+	for i := 1; i < len(planned); i++ {
+		joinedNodes, joinedVariables := planned[i].nodes, planned[i].variables
+		if planned[i].condition != nil {
+			joinedNodes = filterAll(planned[i].condition, joinedNodes)
+
+			var err error
+			joinedVariables, err = joinedVariables.MergeWith(planned[i].conditionVariables)
+			if err != nil {
+				return nil, nil, errors.Wrap(err, "couldn't merge variables for joined table and its condition")
+			}
+		}
+
+		var err error
+		nodes, variables, err = planned[i].join.physicalFromNodes(ctx, physicalCreator, nodes, variables, joinedNodes, joinedVariables)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't get physical plan for join of table with index %d", i)
+		}
+	}
+	return nodes, variables, nil
+}
+
+// joinInEstimatedOrder greedily starts with the smallest table, and then joins the table which leads to the smallest
+// estimated result. Joining on an equality is assumed to result in as many records as the bigger side has,
+// while a join without one results in all the pairs of records. The smaller side of each join is its source.
+// Each table is joined using its own join of the chain, and the first table of the query, which has none,
+// using the join it was joined with in the query.
+func joinInEstimatedOrder(ctx context.Context, physicalCreator *PhysicalPlanCreator, planned []plannedJoinChainStep, rowCounts []int64) ([]physical.Node, octosql.Variables, error) {
+	var predicates []physical.Formula
+	variables := octosql.NoVariables()
+	for i := range planned {
+		if planned[i].condition != nil {
+			predicates = append(predicates, planned[i].condition.SplitByAnd()...)
+		}
+
+		var err error
+		variables, err = variables.MergeWith(planned[i].conditionVariables)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't merge variables of join conditions")
+		}
+	}
+
+	first := 0
+	for i := range rowCounts {
+		if rowCounts[i] < rowCounts[first] {
+			first = i
+		}
+	}
+
+	joined := make([]bool, len(planned))
+	joined[first] = true
+	nodes, nodesVariables := planned[first].nodes, planned[first].variables
+	estimate := float64(rowCounts[first])
+
+	for joinedCount := 1; joinedCount < len(planned); joinedCount++ {
+		namespace := nodes[0].Metadata().Namespace()
+
+		next := -1
+		var nextEstimate float64
+		for i := range planned {
+			if joined[i] {
+				continue
+			}
+			candidateEstimate := estimate * float64(rowCounts[i])
+			if hasJoinKeyPredicate(predicates, namespace, planned[i].nodes[0].Metadata().Namespace()) {
+				candidateEstimate = estimate
+				if float64(rowCounts[i]) > candidateEstimate {
+					candidateEstimate = float64(rowCounts[i])
+				}
+			}
+			if next == -1 || candidateEstimate < nextEstimate {
+				next = i
+				nextEstimate = candidateEstimate
+			}
+		}
+		joined[next] = true
+
+		tableNodes, tableVariables := planned[next].nodes, planned[next].variables
+
+		// All the remaining predicates are checked by the last join, including the ones using variables from outside.
+		mergedNamespace := metadata.EmptyNamespace()
+		mergedNamespace.MergeWith(namespace)
+		mergedNamespace.MergeWith(tableNodes[0].Metadata().Namespace())
+		var condition physical.Formula
+		var remaining []physical.Formula
+		for _, predicate := range predicates {
+			if !predicate.DoesMatchNamespace(mergedNamespace) && joinedCount < len(planned)-1 {
+				remaining = append(remaining, predicate)
+			} else if condition == nil {
+				condition = predicate
+			} else {
+				condition = physical.NewAnd(condition, predicate)
+			}
+		}
+		predicates = remaining
+
+		sourceNodes, sourceVariables := nodes, nodesVariables
+		joinedNodes, joinedVariables := tableNodes, tableVariables
+		if float64(rowCounts[next]) < estimate {
+			sourceNodes, sourceVariables = tableNodes, tableVariables
+			joinedNodes, joinedVariables = nodes, nodesVariables
+		}
+		if condition != nil {
+			joinedNodes = filterAll(condition, joinedNodes)
+		}
+
+		join := planned[next].join
+		if join == nil {
+			join = planned[1].join
+		}
+
+		var err error
+		nodes, nodesVariables, err = join.physicalFromNodes(ctx, physicalCreator, sourceNodes, sourceVariables, joinedNodes, joinedVariables)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't get physical plan for join of table with index %d", next)
+		}
+		estimate = nextEstimate
+	}
+
+	variables, err := nodesVariables.MergeWith(variables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge variables of joined tables and join conditions")
+	}
+	return nodes, variables, nil
+}
+
+// hasJoinKeyPredicate checks if any of the predicates is an equality between the two namespaces.
+func hasJoinKeyPredicate(predicates []physical.Formula, namespace, otherNamespace *metadata.Namespace) bool {
+	for _, predicate := range predicates {
+		if _, ok := predicate.(*physical.Predicate); !ok {
+			continue
+		}
+		if predicate.DoesMatchNamespace(namespace) || predicate.DoesMatchNamespace(otherNamespace) {
+			continue
+		}
+		if isJoinKeyPredicate(predicate, namespace, otherNamespace) {
+			return true
+		}
+	}
+	return false
+}
+
+func filterAll(formula physical.Formula, nodes []physical.Node) []physical.Node {
+	filtered := make([]physical.Node, len(nodes))
+	for i := range nodes {
+		filtered[i] = physical.NewFilter(formula, nodes[i])
+	}
+	return filtered
+}
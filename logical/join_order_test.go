@@ -0,0 +1,117 @@
+package logical
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+func TestJoin_Physical_JoinOrder(t *testing.T) {
+	equal := func(left, right string) Formula {
+		return NewPredicate(NewVariable(octosql.NewVariableName(left)), Equal, NewVariable(octosql.NewVariableName(right)))
+	}
+	and := func(left, right Formula) Formula {
+		return NewInfixOperator(left, right, "and")
+	}
+
+	tests := []struct {
+		name        string
+		rowCounts   map[string]int64
+		cardinality metadata.Cardinality
+		join        Node
+		want        []string
+	}{
+		{
+			name:        "smallest tables first",
+			rowCounts:   map[string]int64{"big": 1000000, "medium": 1000, "small": 10},
+			cardinality: metadata.BoundedFitsInLocalStorage,
+			join: NewJoin(
+				NewJoin(
+					NewDataSource("big", "b"),
+					NewFilter(equal("b.id", "m.id"), NewDataSource("medium", "m")),
+					execution.INNER_JOIN,
+				),
+				NewFilter(equal("m.id", "s.id"), NewDataSource("small", "s")),
+				execution.INNER_JOIN,
+			),
+			want: []string{"s", "m", "b"},
+		},
+		{
+			name:        "smaller side as source",
+			rowCounts:   map[string]int64{"big": 2000, "medium": 1000, "small": 10},
+			cardinality: metadata.BoundedFitsInLocalStorage,
+			join: NewJoin(
+				NewJoin(
+					NewDataSource("medium", "m"),
+					NewFilter(equal("m.id", "b.id"), NewDataSource("big", "b")),
+					execution.INNER_JOIN,
+				),
+				NewFilter(equal("b.id", "s.id"), NewDataSource("small", "s")),
+				execution.INNER_JOIN,
+			),
+			// The small table is joined with the big one first, as joining it with the medium one would be a cross join.
+			// The medium table is then smaller than the result, so it becomes the source of the second join.
+			want: []string{"m", "s", "b"},
+		},
+		{
+			name:        "unknown sizes",
+			rowCounts:   map[string]int64{"big": 1000000},
+			cardinality: metadata.BoundedFitsInLocalStorage,
+			join: NewJoin(
+				NewDataSource("big", "b"),
+				NewFilter(equal("b.id", "s.id"), NewDataSource("small", "s")),
+				execution.INNER_JOIN,
+			),
+			want: []string{"b", "s"},
+		},
+		{
+			name:        "unbounded tables",
+			rowCounts:   map[string]int64{"big": 1000000, "small": 10},
+			cardinality: metadata.Unbounded,
+			join: NewJoin(
+				NewDataSource("big", "b"),
+				NewFilter(equal("b.id", "s.id"), NewDataSource("small", "s")),
+				execution.INNER_JOIN,
+			),
+			want: []string{"b", "s"},
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			repo := physical.NewDataSourceRepository()
+			statistics := physical.NewStatistics()
+			for _, name := range []string{"big", "medium", "small"} {
+				if err := repo.Register(name, physical.NewDataSourceBuilderFactory(nil, nil, nil, tt.cardinality, 1)); err != nil {
+					t.Fatal(err)
+				}
+				if rowCount, ok := tt.rowCounts[name]; ok {
+					statistics.SetRowCount(name, rowCount)
+				}
+			}
+
+			creator := NewPhysicalPlanCreator(repo, map[string]interface{}{"streamJoinParallelism": 1}).WithStatistics(statistics)
+			nodes, _, err := tt.join.Physical(context.Background(), creator)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			var got []string
+			nodes[0].Transform(context.Background(), &physical.Transformers{
+				NodeT: func(node physical.Node) physical.Node {
+					if dsb, ok := node.(*physical.DataSourceBuilder); ok {
+						got = append(got, dsb.Alias)
+					}
+					return node
+				},
+			})
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("tables joined in order %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
@@ -218,6 +218,103 @@ func TestJoin_Physical(t *testing.T) {
 
 			wantErr: false,
 		},
+		{
+			name: "two unbounded streams, no predicate - cross join not allowed",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.Unbounded,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.Unbounded,
+						"",
+						metadata.NewNamespace(
+							[]string{"b"},
+						),
+					),
+				},
+
+				joinType: execution.INNER_JOIN,
+			},
+
+			wantNode: nil,
+			wantErr:  true,
+		},
+		{
+			name: "two unbounded streams, no equality - cross join not allowed",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.Unbounded,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &Filter{
+					formula: &Predicate{ // ON a.field1 > b.field2
+						Left:     &Variable{"a.field1"},
+						Relation: MoreThan,
+						Right:    &Variable{"b.field2"},
+					},
+					source: &StubNode{
+						metadata: metadata.NewNodeMetadata(
+							metadata.Unbounded,
+							"",
+							metadata.NewNamespace(
+								[]string{"b"},
+							),
+						),
+					},
+				},
+
+				joinType: execution.INNER_JOIN,
+			},
+
+			wantNode: nil,
+			wantErr:  true,
+		},
+		{
+			name: "both sides using the same alias - ambiguous",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.BoundedFitsInLocalStorage,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.BoundedFitsInLocalStorage,
+						"",
+						metadata.NewNamespace(
+							[]string{"b", "a"},
+						),
+					),
+				},
+
+				joinType: execution.INNER_JOIN,
+			},
+
+			wantNode: nil,
+			wantErr:  true,
+		},
 		{
 			name: "unbounded + bounded doesn't fit left join - lookup join",
 			fields: fields{
@@ -311,6 +408,59 @@ func TestJoin_Physical(t *testing.T) {
 			},
 			wantErr: false,
 		},
+		{
+			name: "two unbounded streams, inner join with a non-equality predicate - stream join with filter",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.Unbounded,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &Filter{
+					formula: &InfixOperator{ // ON a.field1 = b.field1 AND a.field2 > b.field2
+						Left: &Predicate{
+							Left:     &Variable{"a.field1"},
+							Relation: Equal,
+							Right:    &Variable{"b.field1"},
+						},
+						Operator: "and",
+						Right: &Predicate{
+							Left:     &Variable{"a.field2"},
+							Relation: MoreThan,
+							Right:    &Variable{"b.field2"},
+						},
+					},
+					source: &StubNode{
+						metadata: metadata.NewNodeMetadata(
+							metadata.Unbounded,
+							"",
+							metadata.NewNamespace(
+								[]string{"b"},
+							),
+						),
+					},
+				},
+
+				joinType: execution.INNER_JOIN,
+			},
+
+			wantNode: physical.NewFilter(
+				physical.NewPredicate(physical.NewVariable("a.field2"), physical.MoreThan, physical.NewVariable("b.field2")),
+				&physical.StreamJoin{
+					SourceKey:      []physical.Expression{physical.NewVariable("a.field1")},
+					JoinedKey:      []physical.Expression{physical.NewVariable("b.field1")},
+					EventTimeField: "",
+				},
+			),
+
+			wantErr: false,
+		},
 		{
 			name: "invalid predicate 1 - both sides of predicate match the same namespace",
 			fields: fields{
@@ -385,6 +535,93 @@ func TestJoin_Physical(t *testing.T) {
 			wantNode: physical.NewLookupJoin(nil, nil, true),
 			wantErr:  false,
 		},
+		{
+			name: "semi join - predicates using only the joined node don't prevent a stream join",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.BoundedFitsInLocalStorage,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &Filter{
+					formula: &InfixOperator{ // WHERE a.field1 = b.field1 AND b.field2 > b.field3
+						Left: &Predicate{
+							Left:     &Variable{"a.field1"},
+							Relation: Equal,
+							Right:    &Variable{"b.field1"},
+						},
+						Operator: "and",
+						Right: &Predicate{
+							Left:     &Variable{"b.field2"},
+							Relation: MoreThan,
+							Right:    &Variable{"b.field3"},
+						},
+					},
+					source: &StubNode{
+						metadata: metadata.NewNodeMetadata(
+							metadata.BoundedFitsInLocalStorage,
+							"",
+							metadata.NewNamespace(
+								[]string{"b"},
+							),
+						),
+					},
+				},
+
+				joinType: execution.SEMI_JOIN,
+			},
+
+			wantNode: &physical.StreamJoin{
+				SourceKey:      []physical.Expression{physical.NewVariable("a.field1")},
+				JoinedKey:      []physical.Expression{physical.NewVariable("b.field1")},
+				EventTimeField: "",
+			},
+
+			wantErr: false,
+		},
+		{
+			name: "anti join - a formula not supported by stream join can't be decorrelated",
+			fields: fields{
+				source: &StubNode{
+					metadata: metadata.NewNodeMetadata(
+						metadata.BoundedFitsInLocalStorage,
+						"",
+						metadata.NewNamespace(
+							[]string{"a"},
+						),
+					),
+					variables: octosql.NoVariables(),
+				},
+
+				joined: &Filter{
+					formula: &Predicate{ // WHERE a.field1 > b.field1
+						Left:     &Variable{"a.field1"},
+						Relation: MoreThan,
+						Right:    &Variable{"b.field1"},
+					},
+					source: &StubNode{
+						metadata: metadata.NewNodeMetadata(
+							metadata.BoundedFitsInLocalStorage,
+							"",
+							metadata.NewNamespace(
+								[]string{"b"},
+							),
+						),
+					},
+				},
+
+				joinType: execution.ANTI_JOIN,
+			},
+
+			wantNode: nil,
+			wantErr:  true,
+		},
 	}
 
 	for _, tt := range tests {
@@ -403,9 +640,27 @@ func TestJoin_Physical(t *testing.T) {
 				return
 			}
 
-			switch gotNode := gotNodes[0].(type) {
+			gotNode := gotNodes[0]
+			wantNode := tt.wantNode
+
+			if gotFilter, ok := gotNode.(*physical.Filter); ok {
+				wantFilter, ok := wantNode.(*physical.Filter)
+				if !ok {
+					t.Errorf("Expected no filter, got a filter")
+					return
+				}
+
+				if !reflect.DeepEqual(gotFilter.Formula, wantFilter.Formula) {
+					t.Errorf("Different filter formula")
+				}
+
+				gotNode = gotFilter.Source
+				wantNode = wantFilter.Source
+			}
+
+			switch gotNode := gotNode.(type) {
 			case *physical.StreamJoin:
-				wantNode, ok := tt.wantNode.(*physical.StreamJoin)
+				wantNode, ok := wantNode.(*physical.StreamJoin)
 
 				if !ok {
 					t.Errorf("Expected a lookup join else, got stream join")
@@ -428,7 +683,7 @@ func TestJoin_Physical(t *testing.T) {
 				}
 
 			case *physical.LookupJoin:
-				_, ok := tt.wantNode.(*physical.LookupJoin)
+				_, ok := wantNode.(*physical.LookupJoin)
 				if !ok {
 					t.Errorf("Expected a stream join, got a lookup join")
 				}
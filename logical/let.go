@@ -0,0 +1,77 @@
+package logical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical"
+)
+
+type Let struct {
+	names       []octosql.VariableName
+	expressions []Expression
+	source      Node
+}
+
+func NewLet(names []octosql.VariableName, expressions []Expression, source Node) *Let {
+	return &Let{
+		names:       names,
+		expressions: expressions,
+		source:      source,
+	}
+}
+
+// Physical plans the source with the bound names referencing the values of the bound expressions.
+// The expressions aren't a part of the returned plan, they get added to the LetBindings of the creator instead,
+// as they're evaluated once for the whole query, before it's started.
+func (node *Let) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	letBindings := physicalCreator.LetBindings()
+	sourceCreator := physicalCreator
+
+	variables := octosql.NoVariables()
+	for i := range node.expressions {
+		expr, exprVariables, err := node.expressions[i].Physical(ctx, sourceCreator)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't get physical plan for expression bound to %s", node.names[i])
+		}
+		variables, err = variables.MergeWith(exprVariables)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't merge variables of expression bound to %s", node.names[i])
+		}
+		boundName := sourceCreator.GetVariableName()
+		letBindings.Names = append(letBindings.Names, boundName)
+		letBindings.Expressions = append(letBindings.Expressions, expr)
+
+		// Add this binding to the available variables so further bindings and the source can access this one.
+		sourceCreator = sourceCreator.WithBinding(node.names[i], &binding{
+			variable: boundName,
+		})
+	}
+	letBindings.bindings = sourceCreator.bindings
+
+	source, sourceVariables, err := node.source.Physical(ctx, sourceCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for let source node")
+	}
+	variables, err = variables.MergeWith(sourceVariables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge variables for let source")
+	}
+
+	// The names of the variables have to stay unique in the rest of the plan.
+	physicalCreator.variableCounter = sourceCreator.variableCounter
+
+	return source, variables, nil
+}
+
+func (node *Let) Visualize() *graph.Node {
+	n := graph.NewNode("Let")
+	for i := range node.expressions {
+		n.AddChild(node.names[i].String(), node.expressions[i].Visualize())
+	}
+	n.AddChild("Source", node.source.Visualize())
+	return n
+}
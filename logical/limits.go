@@ -0,0 +1,91 @@
+package logical
+
+import (
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/config"
+)
+
+// planStats keeps track of the shape of the plan being created,
+// so that pathological queries get rejected at plan time, instead of running away on memory and CPU.
+// It's shared between all creators derived from the same root creator.
+type planStats struct {
+	subqueryDepth int
+
+	// A join may get planned more than once, so joins are kept in a set, to count each one once.
+	// The order of registration is kept as well, so that joins of a plan which gets abandoned can be dropped.
+	joins     map[*Join]struct{}
+	joinOrder []*Join
+}
+
+func (creator *PhysicalPlanCreator) getPlanStats() *planStats {
+	if creator.planStats == nil {
+		creator.planStats = &planStats{}
+	}
+	if creator.planStats.joins == nil {
+		creator.planStats.joins = make(map[*Join]struct{})
+	}
+	return creator.planStats
+}
+
+// enterSubquery has to be called before creating the physical plan of a subquery, and paired with exitSubquery.
+func (creator *PhysicalPlanCreator) enterSubquery() error {
+	maxSubqueryDepth, err := config.GetInt(
+		creator.physicalConfig,
+		"maxSubqueryDepth",
+		config.WithDefault(16),
+	)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get maxSubqueryDepth configuration")
+	}
+
+	stats := creator.getPlanStats()
+	if stats.subqueryDepth >= maxSubqueryDepth {
+		return errors.Errorf("subqueries are nested more than %d levels deep, flatten the query or raise maxSubqueryDepth in the physical configuration", maxSubqueryDepth)
+	}
+	stats.subqueryDepth++
+
+	return nil
+}
+
+func (creator *PhysicalPlanCreator) exitSubquery() {
+	creator.getPlanStats().subqueryDepth--
+}
+
+// registerJoin has to be called for each join in the plan, it counts repeated calls for the same join once.
+func (creator *PhysicalPlanCreator) registerJoin(join *Join) error {
+	maxJoinCount, err := config.GetInt(
+		creator.physicalConfig,
+		"maxJoinCount",
+		config.WithDefault(32),
+	)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get maxJoinCount configuration")
+	}
+
+	stats := creator.getPlanStats()
+	if _, ok := stats.joins[join]; ok {
+		return nil
+	}
+	if len(stats.joinOrder) >= maxJoinCount {
+		return errors.Errorf("query contains more than %d joins, split it into smaller queries or raise maxJoinCount in the physical configuration", maxJoinCount)
+	}
+	stats.joins[join] = struct{}{}
+	stats.joinOrder = append(stats.joinOrder, join)
+
+	return nil
+}
+
+// registeredJoins returns a checkpoint which unregisterJoinsSince can go back to.
+func (creator *PhysicalPlanCreator) registeredJoins() int {
+	return len(creator.getPlanStats().joinOrder)
+}
+
+// unregisterJoinsSince drops the joins registered after the checkpoint, when the plan they're part of gets abandoned.
+func (creator *PhysicalPlanCreator) unregisterJoinsSince(checkpoint int) {
+	stats := creator.getPlanStats()
+	for _, join := range stats.joinOrder[checkpoint:] {
+		delete(stats.joins, join)
+	}
+	stats.joinOrder = stats.joinOrder[:checkpoint]
+}
@@ -0,0 +1,48 @@
+package logical
+
+import (
+	"testing"
+)
+
+func TestPhysicalPlanCreator_Limits(t *testing.T) {
+	creator := NewPhysicalPlanCreator(nil, map[string]interface{}{
+		"maxJoinCount":     2,
+		"maxSubqueryDepth": 2,
+	})
+
+	joins := []*Join{{}, {}, {}}
+	for i := 0; i < 2; i++ {
+		if err := creator.registerJoin(joins[i]); err != nil {
+			t.Fatalf("unexpected error registering join %d: %v", i, err)
+		}
+	}
+	// A join planned again, like one in a bound expression referenced twice, is counted once.
+	if err := creator.registerJoin(joins[0]); err != nil {
+		t.Errorf("unexpected error registering join again: %v", err)
+	}
+	// Joins in bound expressions count towards the same limit.
+	if err := creator.WithBinding("x", nil).registerJoin(joins[2]); err == nil {
+		t.Errorf("expected an error after exceeding the join count limit")
+	}
+
+	// Joins of an abandoned plan don't count.
+	creator.unregisterJoinsSince(1)
+	if err := creator.registerJoin(joins[2]); err != nil {
+		t.Errorf("unexpected error registering join after unregistering one: %v", err)
+	}
+
+	for i := 0; i < 2; i++ {
+		if err := creator.enterSubquery(); err != nil {
+			t.Fatalf("unexpected error entering subquery %d: %v", i, err)
+		}
+	}
+	if err := creator.enterSubquery(); err == nil {
+		t.Errorf("expected an error after exceeding the subquery depth limit")
+	}
+
+	// Sibling subqueries don't add up.
+	creator.exitSubquery()
+	if err := creator.enterSubquery(); err != nil {
+		t.Errorf("unexpected error entering sibling subquery: %v", err)
+	}
+}
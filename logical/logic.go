@@ -161,3 +161,59 @@ func (f *Predicate) Visualize() *graph.Node {
 	}
 	return n
 }
+
+type IsNull struct {
+	Expression Expression
+}
+
+func NewIsNull(expression Expression) *IsNull {
+	return &IsNull{Expression: expression}
+}
+
+func (f *IsNull) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.Formula, octosql.Variables, error) {
+	expression, variables, err := f.Expression.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for operand")
+	}
+
+	return physical.NewIsNull(expression), variables, nil
+}
+
+func (f *IsNull) Visualize() *graph.Node {
+	n := graph.NewNode("Is Null")
+	if f.Expression != nil {
+		n.AddChild("Expression", f.Expression.Visualize())
+	}
+	return n
+}
+
+type Exists struct {
+	Subquery Node
+}
+
+func NewExists(subquery Node) *Exists {
+	return &Exists{Subquery: subquery}
+}
+
+func (f *Exists) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.Formula, octosql.Variables, error) {
+	if err := physicalCreator.enterSubquery(); err != nil {
+		return nil, nil, err
+	}
+	sourceNodes, variables, err := f.Subquery.Physical(ctx, physicalCreator)
+	physicalCreator.exitSubquery()
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for exists subquery")
+	}
+
+	outNodes := physical.NewShuffle(1, physical.NewConstantStrategy(0), sourceNodes)
+
+	return physical.NewExists(outNodes[0]), variables, nil
+}
+
+func (f *Exists) Visualize() *graph.Node {
+	n := graph.NewNode("Exists")
+	if f.Subquery != nil {
+		n.AddChild("Subquery", f.Subquery.Visualize())
+	}
+	return n
+}
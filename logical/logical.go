@@ -5,10 +5,12 @@ import (
 	"fmt"
 	"log"
 	"strconv"
+	"strings"
 
 	"github.com/pkg/errors"
 
 	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/graph"
 	"github.com/cube2222/octosql/physical"
 )
@@ -17,6 +19,28 @@ type PhysicalPlanCreator struct {
 	variableCounter int
 	dataSourceRepo  *physical.DataSourceRepository
 	physicalConfig  map[string]interface{}
+	bindings        map[octosql.VariableName]*binding
+	letBindings     *LetBindings
+	planStats       *planStats
+	statistics      *physical.Statistics
+}
+
+// binding is an expression bound to a name using LET or SET.
+// The expression is evaluated once, and references read its value from variable.
+type binding struct {
+	variable octosql.VariableName
+}
+
+// LetBindings are the expressions bound using LET or SET preceding the query.
+// They're evaluated in order once for each run, before the query is started,
+// and their values are available to the query and its output options under Names.
+// They're shared between all creators derived from the same root creator.
+type LetBindings struct {
+	Names       []octosql.VariableName
+	Expressions []physical.Expression
+
+	// bindings are the bound names visible after all of the bindings, which the output options are planned with.
+	bindings map[octosql.VariableName]*binding
 }
 
 func NewPhysicalPlanCreator(repo *physical.DataSourceRepository, physicalConfig map[string]interface{}) *PhysicalPlanCreator {
@@ -24,7 +48,24 @@ func NewPhysicalPlanCreator(repo *physical.DataSourceRepository, physicalConfig
 		variableCounter: 0,
 		dataSourceRepo:  repo,
 		physicalConfig:  physicalConfig,
+		letBindings:     &LetBindings{},
+		planStats:       &planStats{},
+	}
+}
+
+// WithStatistics makes the creator use the given estimates of the sizes of data sources to choose the order of joins.
+func (creator *PhysicalPlanCreator) WithStatistics(statistics *physical.Statistics) *PhysicalPlanCreator {
+	newCreator := &PhysicalPlanCreator{
+		variableCounter: creator.variableCounter,
+		dataSourceRepo:  creator.dataSourceRepo,
+		physicalConfig:  creator.physicalConfig,
+		bindings:        creator.bindings,
+		letBindings:     creator.LetBindings(),
+		planStats:       creator.getPlanStats(),
+		statistics:      statistics,
 	}
+
+	return newCreator
 }
 
 func (creator *PhysicalPlanCreator) GetVariableName() (out octosql.VariableName) {
@@ -38,7 +79,10 @@ func (creator *PhysicalPlanCreator) WithCommonTableExpression(name string, nodes
 	newDataSourceRepo := creator.dataSourceRepo.WithFactory(
 		name,
 		func(name, alias string) []physical.Node {
-			out := nodes
+			// The CTE is planned once, but each reference gets its own slice,
+			// so that requalifying one reference doesn't affect the others.
+			out := make([]physical.Node, len(nodes))
+			copy(out, nodes)
 			if len(alias) > 0 {
 				for i := range out {
 					out[i] = physical.NewRequalifier(alias, out[i])
@@ -51,19 +95,74 @@ func (creator *PhysicalPlanCreator) WithCommonTableExpression(name string, nodes
 	newCreator := &PhysicalPlanCreator{
 		variableCounter: creator.variableCounter,
 		dataSourceRepo:  newDataSourceRepo,
+		physicalConfig:  creator.physicalConfig,
+		bindings:        creator.bindings,
+		letBindings:     creator.LetBindings(),
+		planStats:       creator.getPlanStats(),
+		statistics:      creator.statistics,
 	}
 
 	return newCreator
 }
 
+func (creator *PhysicalPlanCreator) WithBinding(name octosql.VariableName, bound *binding) *PhysicalPlanCreator {
+	newCreator := &PhysicalPlanCreator{
+		variableCounter: creator.variableCounter,
+		dataSourceRepo:  creator.dataSourceRepo,
+		physicalConfig:  creator.physicalConfig,
+		bindings:        withBinding(creator.bindings, name, bound),
+		letBindings:     creator.LetBindings(),
+		planStats:       creator.getPlanStats(),
+		statistics:      creator.statistics,
+	}
+
+	return newCreator
+}
+
+func withBinding(bindings map[octosql.VariableName]*binding, name octosql.VariableName, bound *binding) map[octosql.VariableName]*binding {
+	newBindings := make(map[octosql.VariableName]*binding, len(bindings)+1)
+	for k, v := range bindings {
+		newBindings[k] = v
+	}
+	newBindings[name] = bound
+	return newBindings
+}
+
+// LetBindings returns the expressions bound using LET or SET preceding the planned query.
+// They have to be evaluated before the query is started, as the plan only references their values.
+func (creator *PhysicalPlanCreator) LetBindings() *LetBindings {
+	if creator.letBindings == nil {
+		creator.letBindings = &LetBindings{}
+	}
+	return creator.letBindings
+}
+
 type OutputOptions struct {
 	OrderByExpressions []Expression
 	OrderByDirections  []OrderDirection
 	Limit              Expression
 	Offset             Expression
+	OutputFile         string
+
+	// Explain makes the query print its plan instead of the results.
+	// With ExplainAnalyze the query is also executed, and the plan is annotated with execution statistics.
+	Explain        bool
+	ExplainAnalyze bool
+
+	// AnalyzeFile makes the statistics of the file under this path get collected, instead of running a query.
+	AnalyzeFile string
 }
 
 func (opts *OutputOptions) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (*physical.OutputOptions, octosql.Variables, error) {
+	// The names bound preceding the query are visible to its output options too.
+	if letBindings := physicalCreator.LetBindings(); len(letBindings.Names) > 0 {
+		bindings := physicalCreator.bindings
+		physicalCreator.bindings = letBindings.bindings
+		defer func() {
+			physicalCreator.bindings = bindings
+		}()
+	}
+
 	orderByExpressions := make([]physical.Expression, len(opts.OrderByExpressions))
 	variables := octosql.NoVariables()
 	for i := range opts.OrderByExpressions {
@@ -116,7 +215,7 @@ func (opts *OutputOptions) Physical(ctx context.Context, physicalCreator *Physic
 		offset = offsetExpression
 	}
 
-	return physical.NewOutputOptions(orderByExpressions, orderByDirections, limit, offset), variables, nil
+	return physical.NewOutputOptions(orderByExpressions, orderByDirections, limit, offset, opts.OutputFile), variables, nil
 }
 
 type Node interface {
@@ -135,6 +234,10 @@ func NewDataSource(name string, alias string) *DataSource {
 }
 
 func (ds *DataSource) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	if err := checkAlias(ds.alias); err != nil {
+		return nil, nil, err
+	}
+
 	outDs, err := physicalCreator.dataSourceRepo.Get(ds.name, ds.alias)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get data source")
@@ -149,6 +252,15 @@ func (ds *DataSource) Visualize() *graph.Node {
 	return n
 }
 
+// checkAlias makes sure the alias doesn't collide with the system fields, like sys.undo of retractions.
+// The fields of a table aliased that way would be hidden by them.
+func checkAlias(alias string) error {
+	if strings.EqualFold(alias, execution.SystemSource) {
+		return errors.Errorf("alias %s is reserved for system fields like %s, use a different one", alias, execution.SystemField("undo"))
+	}
+	return nil
+}
+
 type Expression interface {
 	graph.Visualizer
 
@@ -163,12 +275,20 @@ type NamedExpression interface {
 
 type StarExpression struct {
 	qualifier string
+	exclude   []octosql.VariableName
+	replace   []NamedExpression
 }
 
 func NewStarExpression(qualifier string) *StarExpression {
 	return &StarExpression{qualifier: qualifier}
 }
 
+// NewStarExpressionWithModifiers creates a star expression without the excluded columns,
+// and with the replaced ones computed by the replacement expressions named after them.
+func NewStarExpressionWithModifiers(qualifier string, exclude []octosql.VariableName, replace []NamedExpression) *StarExpression {
+	return &StarExpression{qualifier: qualifier, exclude: exclude, replace: replace}
+}
+
 func (se *StarExpression) Name() octosql.VariableName {
 	if se.qualifier == "" {
 		return octosql.StarExpressionName
@@ -182,12 +302,37 @@ func (se *StarExpression) Physical(ctx context.Context, physicalCreator *Physica
 }
 
 func (se *StarExpression) PhysicalNamed(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.NamedExpression, octosql.Variables, error) {
-	return physical.NewStarExpression(se.qualifier), octosql.NoVariables(), nil
+	if len(se.exclude) == 0 && len(se.replace) == 0 {
+		return physical.NewStarExpression(se.qualifier), octosql.NoVariables(), nil
+	}
+
+	replace := make([]physical.NamedExpression, len(se.replace))
+	variables := octosql.NoVariables()
+	for i := range se.replace {
+		physicalExpr, exprVariables, err := se.replace[i].PhysicalNamed(ctx, physicalCreator)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't get physical plan for replacement of %v", se.replace[i].Name())
+		}
+		variables, err = variables.MergeWith(exprVariables)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't merge variables with those of replacement of %v", se.replace[i].Name())
+		}
+
+		replace[i] = physicalExpr
+	}
+
+	return physical.NewStarExpressionWithModifiers(se.qualifier, se.exclude, replace), variables, nil
 }
 
 func (se *StarExpression) Visualize() *graph.Node {
 	n := graph.NewNode("Star Expression")
 	n.AddField("qualifier", se.qualifier)
+	for i, column := range se.exclude {
+		n.AddField(fmt.Sprintf("exclude_%d", i), column.String())
+	}
+	for i, replacement := range se.replace {
+		n.AddChild(fmt.Sprintf("replace_%d", i), replacement.Visualize())
+	}
 	return n
 }
 
@@ -208,6 +353,10 @@ func (v *Variable) Physical(ctx context.Context, physicalCreator *PhysicalPlanCr
 }
 
 func (v *Variable) PhysicalNamed(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.NamedExpression, octosql.Variables, error) {
+	if bound, ok := physicalCreator.bindings[v.name]; ok {
+		return physical.NewAliasedExpression(v.name, physical.NewVariable(bound.variable)), octosql.NoVariables(), nil
+	}
+
 	return physical.NewVariable(v.name), octosql.NoVariables(), nil
 }
 
@@ -226,9 +375,13 @@ func NewConstant(value interface{}) *Constant {
 }
 
 func (c *Constant) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.Expression, octosql.Variables, error) {
+	value, err := octosql.TryNormalizeType(c.value)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't normalize constant")
+	}
 	name := physicalCreator.GetVariableName()
 	return physical.NewVariable(name), octosql.NewVariables(map[octosql.VariableName]octosql.Value{
-		name: octosql.NormalizeType(c.value),
+		name: value,
 	}), nil
 }
 
@@ -289,7 +442,11 @@ func NewNodeExpression(node Node) *NodeExpression {
 }
 
 func (ne *NodeExpression) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) (physical.Expression, octosql.Variables, error) {
+	if err := physicalCreator.enterSubquery(); err != nil {
+		return nil, nil, err
+	}
 	sourceNodes, variables, err := ne.node.Physical(ctx, physicalCreator)
+	physicalCreator.exitSubquery()
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for node expression")
 	}
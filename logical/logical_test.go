@@ -0,0 +1,142 @@
+package logical
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+func TestPhysicalPlanCreator_WithCommonTableExpression(t *testing.T) {
+	cteNodes := []physical.Node{
+		physical.NewStubNode(metadata.NewNodeMetadata(metadata.Unbounded, "", metadata.EmptyNamespace())),
+		physical.NewStubNode(metadata.NewNodeMetadata(metadata.Unbounded, "", metadata.EmptyNamespace())),
+	}
+
+	creator := NewPhysicalPlanCreator(physical.NewDataSourceRepository(), nil).
+		WithCommonTableExpression("cte", cteNodes)
+
+	first, err := creator.dataSourceRepo.Get("cte", "a")
+	if err != nil {
+		t.Fatal(err)
+	}
+	second, err := creator.dataSourceRepo.Get("cte", "b")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	for i := range cteNodes {
+		if requalifier, ok := first[i].(*physical.Requalifier); !ok || requalifier.Qualifier != "a" || requalifier.Source != cteNodes[i] {
+			t.Errorf("invalid node %d of first reference: %+v", i, first[i])
+		}
+		if requalifier, ok := second[i].(*physical.Requalifier); !ok || requalifier.Qualifier != "b" || requalifier.Source != cteNodes[i] {
+			t.Errorf("invalid node %d of second reference: %+v", i, second[i])
+		}
+		if _, ok := cteNodes[i].(*physical.StubNode); !ok {
+			t.Errorf("planned common table expression node %d got modified: %+v", i, cteNodes[i])
+		}
+	}
+}
+
+func TestDataSource_PhysicalReservedAlias(t *testing.T) {
+	creator := NewPhysicalPlanCreator(physical.NewDataSourceRepository(), nil)
+
+	for _, node := range []Node{
+		NewDataSource("people", "sys"),
+		NewRequalifier("SYS", NewDataSource("people", "p")),
+	} {
+		if _, _, err := node.Physical(context.Background(), creator); err == nil {
+			t.Errorf("expected error for node %+v aliased as system source", node)
+		}
+	}
+}
+
+func TestLet_Physical(t *testing.T) {
+	ctx := context.Background()
+	cteNodes := []physical.Node{
+		physical.NewStubNode(metadata.NewNodeMetadata(metadata.BoundedFitsInLocalStorage, "", metadata.EmptyNamespace())),
+		physical.NewStubNode(metadata.NewNodeMetadata(metadata.BoundedFitsInLocalStorage, "", metadata.EmptyNamespace())),
+	}
+	creator := NewPhysicalPlanCreator(physical.NewDataSourceRepository(), nil).
+		WithCommonTableExpression("cte", cteNodes)
+
+	node := NewLet(
+		[]octosql.VariableName{"threshold", "doubled"},
+		[]Expression{
+			NewConstant(3),
+			NewVariable("threshold"),
+		},
+		NewMap([]NamedExpression{NewVariable("doubled"), NewVariable("threshold")}, NewDataSource("cte", "c"), false),
+	)
+
+	nodes, variables, err := node.Physical(ctx, creator)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(variables, octosql.NewVariables(map[octosql.VariableName]octosql.Value{"const_0": octosql.MakeInt(3)})) {
+		t.Errorf("got variables %+v", variables)
+	}
+
+	// The bound expressions are evaluated once for the whole query, so they're only added to the creator.
+	letBindings := creator.LetBindings()
+	wantNames := []octosql.VariableName{"const_1", "const_2"}
+	if !reflect.DeepEqual(letBindings.Names, wantNames) {
+		t.Errorf("got bound names %v, want %v", letBindings.Names, wantNames)
+	}
+	wantExpressions := []physical.Expression{
+		physical.NewVariable("const_0"),
+		physical.NewAliasedExpression("threshold", physical.NewVariable("const_1")),
+	}
+	if !reflect.DeepEqual(letBindings.Expressions, wantExpressions) {
+		t.Errorf("got bound expressions %+v, want %+v", letBindings.Expressions, wantExpressions)
+	}
+
+	// The partitions of the source are left as they are.
+	if len(nodes) != len(cteNodes) {
+		t.Fatalf("got %d partitions, want %d", len(nodes), len(cteNodes))
+	}
+	wantMapExpressions := []physical.NamedExpression{
+		physical.NewAliasedExpression("doubled", physical.NewVariable("const_2")),
+		physical.NewAliasedExpression("threshold", physical.NewVariable("const_1")),
+	}
+	for i := range nodes {
+		if !reflect.DeepEqual(nodes[i].(*physical.Map).Expressions, wantMapExpressions) {
+			t.Errorf("got map expressions %+v in partition %d, want %+v", nodes[i].(*physical.Map).Expressions, i, wantMapExpressions)
+		}
+	}
+
+	// The bound names aren't visible to nodes planned with the creator afterwards, but they are to the output options,
+	// which reference the same values as the source.
+	if _, ok := creator.bindings["doubled"]; ok {
+		t.Errorf("binding of doubled leaked into the creator")
+	}
+	outputOptions := &OutputOptions{
+		OrderByExpressions: []Expression{NewVariable("threshold")},
+		OrderByDirections:  []OrderDirection{"asc"},
+		Limit:              NewVariable("doubled"),
+	}
+	physicalOutputOptions, variables, err := outputOptions.Physical(ctx, creator)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := physical.NewAliasedExpression("threshold", physical.NewVariable("const_1")); !reflect.DeepEqual(physicalOutputOptions.OrderByExpressions[0], want) {
+		t.Errorf("got order by expression %+v, want %+v", physicalOutputOptions.OrderByExpressions[0], want)
+	}
+	if want := physical.NewAliasedExpression("doubled", physical.NewVariable("const_2")); !reflect.DeepEqual(physicalOutputOptions.Limit, want) {
+		t.Errorf("got limit expression %+v, want %+v", physicalOutputOptions.Limit, want)
+	}
+	if len(variables) != 0 {
+		t.Errorf("got variables %+v for output options", variables)
+	}
+	if _, ok := creator.bindings["doubled"]; ok {
+		t.Errorf("binding of doubled leaked into the creator after planning output options")
+	}
+
+	// Variable names given out later don't collide with the ones used inside of the source.
+	if name := creator.GetVariableName(); name != "const_3" {
+		t.Errorf("got variable name %s after planning, want const_3", name)
+	}
+}
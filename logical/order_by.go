@@ -53,6 +53,10 @@ func (node *OrderBy) Physical(ctx context.Context, physicalCreator *PhysicalPlan
 			directions[i] = physical.Ascending
 		case "desc":
 			directions[i] = physical.Descending
+		case "asc nulls last":
+			directions[i] = physical.AscendingNullsLast
+		case "desc nulls first":
+			directions[i] = physical.DescendingNullsFirst
 		default:
 			return nil, nil, errors.Errorf("invalid order by direction: %v", direction)
 		}
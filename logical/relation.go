@@ -16,6 +16,7 @@ const (
 	MoreThan     Relation = ">"
 	LessThan     Relation = "<"
 	Like         Relation = "like"
+	ILike        Relation = "ilike"
 	In           Relation = "in"
 	NotIn        Relation = "not in"
 	GreaterEqual Relation = ">="
@@ -39,6 +40,8 @@ func (rel Relation) Physical(ctx context.Context) (physical.Relation, error) {
 		return physical.LessThan, nil
 	case Like:
 		return physical.Like, nil
+	case ILike:
+		return physical.ILike, nil
 	case In:
 		return physical.In, nil
 	case NotIn:
@@ -20,6 +20,10 @@ func NewRequalifier(qualifier string, child Node) *Requalifier {
 }
 
 func (node *Requalifier) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	if err := checkAlias(node.qualifier); err != nil {
+		return nil, nil, err
+	}
+
 	sourceNodes, variables, err := node.source.Physical(ctx, physicalCreator)
 	if err != nil {
 		return nil, nil, errors.Wrap(err, "couldn't get physical plan for requalifier source nodes")
@@ -0,0 +1,163 @@
+package logical
+
+import (
+	"context"
+	"strings"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical"
+)
+
+// ErrCannotDecorrelate is returned when a semi or anti join can't be planned as a stream join.
+// The subquery it was created from is then executed for each record instead.
+var ErrCannotDecorrelate = errors.New("subquery can't be decorrelated into a join")
+
+// physicalSemiJoin plans the filter as a semi join with the subquery of an EXISTS or IN predicate,
+// or as an anti join with the subquery of a NOT EXISTS predicate, if there is one in the top-level conjunction of the formula.
+// This way the subquery is executed once, instead of once per record. The remaining predicates filter the output of the join.
+// It returns false if there is no such predicate, or none of the subqueries can be decorrelated.
+func (node *Filter) physicalSemiJoin(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, bool, error) {
+	conjuncts := splitByAnd(node.formula)
+
+	for i := range conjuncts {
+		joined, joinType, ok := decorrelateSubquery(conjuncts[i])
+		if !ok {
+			continue
+		}
+
+		var root Node = NewJoin(node.source, joined, joinType)
+
+		remaining := make([]Formula, 0, len(conjuncts)-1)
+		remaining = append(remaining, conjuncts[:i]...)
+		remaining = append(remaining, conjuncts[i+1:]...)
+		if len(remaining) > 0 {
+			root = NewFilter(joinByAnd(remaining), root)
+		}
+
+		checkpoint := physicalCreator.registeredJoins()
+		outputNodes, variables, err := root.Physical(ctx, physicalCreator)
+		if errors.Cause(err) == ErrCannotDecorrelate {
+			// The subquery gets planned again when it's executed for each record, so the joins of this attempt don't count.
+			physicalCreator.unregisterJoinsSince(checkpoint)
+			continue
+		} else if err != nil {
+			return nil, nil, false, errors.Wrap(err, "couldn't get physical plan for decorrelated subquery")
+		}
+
+		return outputNodes, variables, true, nil
+	}
+
+	return nil, nil, false, nil
+}
+
+// decorrelateSubquery returns the node which the source of the filter has to be semi or anti joined with,
+// so that only the records satisfying the formula are left.
+func decorrelateSubquery(formula Formula) (Node, execution.JoinType, bool) {
+	switch formula := formula.(type) {
+	case *Exists:
+		_, source, ok := getSimpleSubquery(formula.Subquery)
+		return source, execution.SEMI_JOIN, ok
+
+	case *PrefixOperator:
+		exists, ok := formula.Child.(*Exists)
+		if !ok || strings.ToLower(formula.Operator) != "not" {
+			return nil, 0, false
+		}
+		_, source, ok := getSimpleSubquery(exists.Subquery)
+		return source, execution.ANTI_JOIN, ok
+
+	case *Predicate:
+		// NOT IN isn't decorrelated, as a NULL in the subquery makes it false for all records, which an anti join wouldn't do.
+		if Relation(strings.ToLower(string(formula.Relation))) != In {
+			return nil, 0, false
+		}
+		subquery, ok := formula.Right.(*NodeExpression)
+		if !ok {
+			return nil, 0, false
+		}
+		projection, source, ok := getSimpleSubquery(subquery.node)
+		if !ok || len(projection) != 1 {
+			return nil, 0, false
+		}
+		selected, ok := projection[0].(*Variable)
+		if !ok {
+			return nil, 0, false
+		}
+
+		// x IN (SELECT y FROM t WHERE ...) is the same as EXISTS (SELECT * FROM t WHERE ... AND y = x).
+		// The selected expression goes on the left, as it's then used as the joined key even if neither side is qualified.
+		keyPredicate := NewPredicate(selected, Equal, formula.Left)
+		if filter, ok := source.(*Filter); ok {
+			return NewFilter(NewInfixOperator(filter.formula, keyPredicate, "AND"), filter.source), execution.SEMI_JOIN, true
+		}
+		return NewFilter(keyPredicate, source), execution.SEMI_JOIN, true
+
+	default:
+		return nil, 0, false
+	}
+}
+
+// getSimpleSubquery matches subqueries of the form SELECT ... FROM table [WHERE ...], with an optional DISTINCT,
+// which doesn't change the result of a semi join. It returns the selected expressions and the source of the selection,
+// including the filter if there is one. Other subqueries could reference the outer query in places a join can't handle.
+func getSimpleSubquery(node Node) ([]NamedExpression, Node, bool) {
+	if distinct, ok := node.(*Distinct); ok {
+		node = distinct.child
+	}
+
+	projection, ok := node.(*Map)
+	if !ok || projection.keep {
+		return nil, nil, false
+	}
+
+	source := projection.source
+	selection := source
+	if filter, ok := selection.(*Filter); ok {
+		selection = filter.source
+	}
+
+	selectionMap, ok := selection.(*Map)
+	if !ok || !selectionMap.keep {
+		return nil, nil, false
+	}
+	dataSource, ok := selectionMap.source.(*DataSource)
+	if !ok {
+		return nil, nil, false
+	}
+
+	// The selected expressions are evaluated before the join, so they mustn't reference the outer query.
+	for _, expr := range selectionMap.expressions {
+		switch expr := expr.(type) {
+		case *Variable:
+			if expr.name.Source() != dataSource.alias {
+				return nil, nil, false
+			}
+		case *AliasedExpression:
+			if _, ok := expr.expr.(*Constant); !ok {
+				return nil, nil, false
+			}
+		default:
+			return nil, nil, false
+		}
+	}
+
+	return projection.expressions, source, true
+}
+
+func splitByAnd(formula Formula) []Formula {
+	if infix, ok := formula.(*InfixOperator); ok && strings.ToLower(infix.Operator) == "and" {
+		return append(splitByAnd(infix.Left), splitByAnd(infix.Right)...)
+	}
+	return []Formula{formula}
+}
+
+func joinByAnd(formulas []Formula) Formula {
+	out := formulas[0]
+	for _, formula := range formulas[1:] {
+		out = NewInfixOperator(out, formula, "AND")
+	}
+	return out
+}
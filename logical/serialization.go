@@ -0,0 +1,682 @@
+package logical
+
+import (
+	"encoding/json"
+	"reflect"
+
+	"github.com/golang/protobuf/jsonpb"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+)
+
+// planSerializationVersion is increased with changes to the serialized form of plans which older versions can't read.
+const planSerializationVersion = 1
+
+// jsonPlan is the serialized form of a logical plan with its output options.
+type jsonPlan struct {
+	Version       int               `json:"version"`
+	Plan          *jsonNode         `json:"plan"`
+	OutputOptions jsonOutputOptions `json:"output_options"`
+}
+
+type jsonOutputOptions struct {
+	OrderByExpressions []*jsonNode `json:"order_by_expressions,omitempty"`
+	OrderByDirections  []string    `json:"order_by_directions,omitempty"`
+	Limit              *jsonNode   `json:"limit,omitempty"`
+	Offset             *jsonNode   `json:"offset,omitempty"`
+	OutputFile         string      `json:"output_file,omitempty"`
+	Explain            bool        `json:"explain,omitempty"`
+	ExplainAnalyze     bool        `json:"explain_analyze,omitempty"`
+}
+
+// jsonNode is the serialized form of nodes, expressions, formulas, triggers and table valued function arguments,
+// which are told apart by their type. Only the fields used by the type are set.
+type jsonNode struct {
+	Type string `json:"type"`
+
+	Name      string          `json:"name,omitempty"`
+	Alias     string          `json:"alias,omitempty"`
+	Qualifier string          `json:"qualifier,omitempty"`
+	Operator  string          `json:"operator,omitempty"`
+	Relation  string          `json:"relation,omitempty"`
+	Operation string          `json:"operation,omitempty"`
+	JoinType  string          `json:"join_type,omitempty"`
+	Keep      bool            `json:"keep,omitempty"`
+	Value     json.RawMessage `json:"value,omitempty"`
+
+	Names      []string `json:"names,omitempty"`
+	Aggregates []string `json:"aggregates,omitempty"`
+	As         []string `json:"as,omitempty"`
+	Directions []string `json:"directions,omitempty"`
+
+	Source      *jsonNode            `json:"source,omitempty"`
+	Joined      *jsonNode            `json:"joined,omitempty"`
+	Left        *jsonNode            `json:"left,omitempty"`
+	Right       *jsonNode            `json:"right,omitempty"`
+	Formula     *jsonNode            `json:"formula,omitempty"`
+	Expression  *jsonNode            `json:"expression,omitempty"`
+	Expressions []*jsonNode          `json:"expressions,omitempty"`
+	Key         []*jsonNode          `json:"key,omitempty"`
+	Nodes       []*jsonNode          `json:"nodes,omitempty"`
+	Triggers    []*jsonNode          `json:"triggers,omitempty"`
+	Arguments   map[string]*jsonNode `json:"arguments,omitempty"`
+}
+
+var joinTypes = map[execution.JoinType]string{
+	execution.INNER_JOIN: "inner",
+	execution.LEFT_JOIN:  "left",
+	execution.OUTER_JOIN: "outer",
+	execution.SEMI_JOIN:  "semi",
+	execution.ANTI_JOIN:  "anti",
+}
+
+// MarshalPlan serializes the logical plan with its output options to JSON,
+// so that it can be cached, sent to another process or inspected, and later read back with UnmarshalPlan.
+func MarshalPlan(node Node, outputOptions *OutputOptions) ([]byte, error) {
+	plan, err := marshalNode(node)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't serialize plan")
+	}
+
+	out := jsonPlan{
+		Version: planSerializationVersion,
+		Plan:    plan,
+	}
+	if outputOptions != nil {
+		if out.OutputOptions.OrderByExpressions, err = marshalNodes(outputOptions.OrderByExpressions); err != nil {
+			return nil, errors.Wrap(err, "couldn't serialize order by expressions")
+		}
+		out.OutputOptions.OrderByDirections = make([]string, len(outputOptions.OrderByDirections))
+		for i := range outputOptions.OrderByDirections {
+			out.OutputOptions.OrderByDirections[i] = string(outputOptions.OrderByDirections[i])
+		}
+		if out.OutputOptions.Limit, err = marshalNode(outputOptions.Limit); err != nil {
+			return nil, errors.Wrap(err, "couldn't serialize limit")
+		}
+		if out.OutputOptions.Offset, err = marshalNode(outputOptions.Offset); err != nil {
+			return nil, errors.Wrap(err, "couldn't serialize offset")
+		}
+		out.OutputOptions.OutputFile = outputOptions.OutputFile
+		out.OutputOptions.Explain = outputOptions.Explain
+		out.OutputOptions.ExplainAnalyze = outputOptions.ExplainAnalyze
+	}
+
+	return json.Marshal(out)
+}
+
+// UnmarshalPlan reads back a logical plan with its output options serialized by MarshalPlan.
+func UnmarshalPlan(data []byte) (Node, *OutputOptions, error) {
+	var in jsonPlan
+	if err := json.Unmarshal(data, &in); err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't decode plan")
+	}
+	if in.Version != planSerializationVersion {
+		return nil, nil, errors.Errorf("unsupported plan version %d, expected %d", in.Version, planSerializationVersion)
+	}
+	if in.Plan == nil {
+		return nil, nil, errors.New("no plan given")
+	}
+
+	node, err := unmarshalNode(in.Plan)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't deserialize plan")
+	}
+
+	outputOptions := &OutputOptions{
+		OutputFile:     in.OutputOptions.OutputFile,
+		Explain:        in.OutputOptions.Explain,
+		ExplainAnalyze: in.OutputOptions.ExplainAnalyze,
+	}
+	if outputOptions.OrderByExpressions, err = unmarshalExpressions(in.OutputOptions.OrderByExpressions); err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't deserialize order by expressions")
+	}
+	if len(in.OutputOptions.OrderByDirections) != len(outputOptions.OrderByExpressions) {
+		return nil, nil, errors.Errorf("got %d order by directions for %d expressions", len(in.OutputOptions.OrderByDirections), len(outputOptions.OrderByExpressions))
+	}
+	for _, direction := range in.OutputOptions.OrderByDirections {
+		outputOptions.OrderByDirections = append(outputOptions.OrderByDirections, OrderDirection(direction))
+	}
+	if outputOptions.Limit, err = unmarshalExpression(in.OutputOptions.Limit); err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't deserialize limit")
+	}
+	if outputOptions.Offset, err = unmarshalExpression(in.OutputOptions.Offset); err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't deserialize offset")
+	}
+
+	return node, outputOptions, nil
+}
+
+// marshalNode serializes any part of the plan. Nil parts, like absent optional expressions, are serialized as nil.
+func marshalNode(part interface{}) (*jsonNode, error) {
+	if part == nil {
+		return nil, nil
+	}
+	if value := reflect.ValueOf(part); value.Kind() == reflect.Ptr && value.IsNil() {
+		return nil, nil
+	}
+
+	var out *jsonNode
+	var err error
+	marshalChild := func(field **jsonNode, child interface{}) {
+		if err == nil {
+			*field, err = marshalNode(child)
+		}
+	}
+	marshalChildren := func(field *[]*jsonNode, list interface{}) {
+		if err == nil {
+			*field, err = marshalNodes(list)
+		}
+	}
+
+	switch part := part.(type) {
+	// Nodes.
+	case *DataSource:
+		out = &jsonNode{Type: "data_source", Name: part.name, Alias: part.alias}
+	case *Distinct:
+		out = &jsonNode{Type: "distinct"}
+		marshalChild(&out.Source, part.child)
+	case *Filter:
+		out = &jsonNode{Type: "filter"}
+		marshalChild(&out.Formula, part.formula)
+		marshalChild(&out.Source, part.source)
+	case *GroupBy:
+		out = &jsonNode{
+			Type:       "group_by",
+			Names:      octosql.VariableNamesToStrings(part.fields),
+			Aggregates: make([]string, len(part.aggregates)),
+			As:         octosql.VariableNamesToStrings(part.as),
+		}
+		for i := range part.aggregates {
+			out.Aggregates[i] = string(part.aggregates[i])
+		}
+		marshalChild(&out.Source, part.source)
+		marshalChildren(&out.Key, part.key)
+		marshalChildren(&out.Triggers, part.triggers)
+	case *Join:
+		joinType, ok := joinTypes[part.joinType]
+		if !ok {
+			return nil, errors.Errorf("invalid join type %v", part.joinType)
+		}
+		out = &jsonNode{Type: "join", JoinType: joinType}
+		marshalChild(&out.Source, part.source)
+		marshalChild(&out.Joined, part.joined)
+		marshalChildren(&out.Triggers, part.triggers)
+	case *Let:
+		out = &jsonNode{Type: "let", Names: octosql.VariableNamesToStrings(part.names)}
+		marshalChildren(&out.Expressions, part.expressions)
+		marshalChild(&out.Source, part.source)
+	case *Map:
+		out = &jsonNode{Type: "map", Keep: part.keep}
+		marshalChildren(&out.Expressions, part.expressions)
+		marshalChild(&out.Source, part.source)
+	case *OrderBy:
+		out = &jsonNode{Type: "order_by", Directions: make([]string, len(part.directions))}
+		for i := range part.directions {
+			out.Directions[i] = string(part.directions[i])
+		}
+		marshalChildren(&out.Expressions, part.expressions)
+		marshalChild(&out.Source, part.source)
+	case *Requalifier:
+		out = &jsonNode{Type: "requalifier", Qualifier: part.qualifier}
+		marshalChild(&out.Source, part.source)
+	case *SetOperation:
+		out = &jsonNode{Type: "set_operation", Operation: string(part.operation)}
+		marshalChild(&out.Left, part.first)
+		marshalChild(&out.Right, part.second)
+	case *TableValuedFunction:
+		out = &jsonNode{Type: "table_valued_function", Name: part.name, Arguments: make(map[string]*jsonNode, len(part.arguments))}
+		for name, argument := range part.arguments {
+			var serialized *jsonNode
+			marshalChild(&serialized, argument)
+			out.Arguments[name.String()] = serialized
+		}
+	case *UnionAll:
+		out = &jsonNode{Type: "union_all"}
+		marshalChild(&out.Left, part.first)
+		marshalChild(&out.Right, part.second)
+	case *UnionDistinct:
+		out = &jsonNode{Type: "union_distinct"}
+		marshalChild(&out.Left, part.first)
+		marshalChild(&out.Right, part.second)
+	case *Unnest:
+		out = &jsonNode{Type: "unnest", Alias: part.alias}
+		marshalChild(&out.Expression, part.expression)
+		marshalChild(&out.Source, part.source)
+	case *With:
+		out = &jsonNode{Type: "with", Names: part.cteNames}
+		marshalChildren(&out.Nodes, part.cteNodes)
+		marshalChild(&out.Source, part.source)
+
+	// Expressions.
+	case *FunctionExpression:
+		out = &jsonNode{Type: "function", Name: part.name}
+		marshalChildren(&out.Expressions, part.arguments)
+	case *Interval:
+		out = &jsonNode{Type: "interval"}
+		marshalChildren(&out.Expressions, []Expression{part.count, part.unit})
+	case *StarExpression:
+		out = &jsonNode{Type: "star", Qualifier: part.qualifier, Names: octosql.VariableNamesToStrings(part.exclude)}
+		marshalChildren(&out.Expressions, part.replace)
+	case *Variable:
+		out = &jsonNode{Type: "variable", Name: part.name.String()}
+	case *Constant:
+		out = &jsonNode{Type: "constant"}
+		out.Value, err = marshalConstant(part.value)
+	case *Tuple:
+		out = &jsonNode{Type: "tuple"}
+		marshalChildren(&out.Expressions, part.expressions)
+	case *NodeExpression:
+		out = &jsonNode{Type: "node_expression"}
+		marshalChild(&out.Source, part.node)
+	case *LogicExpression:
+		out = &jsonNode{Type: "logic_expression"}
+		marshalChild(&out.Formula, part.formula)
+	case *AliasedExpression:
+		out = &jsonNode{Type: "aliased", Name: part.name.String()}
+		marshalChild(&out.Expression, part.expr)
+
+	// Formulas.
+	case *BooleanConstant:
+		out = &jsonNode{Type: "boolean_constant"}
+		out.Value, err = json.Marshal(part.Value)
+	case *InfixOperator:
+		out = &jsonNode{Type: "infix_operator", Operator: part.Operator}
+		marshalChild(&out.Left, part.Left)
+		marshalChild(&out.Right, part.Right)
+	case *PrefixOperator:
+		out = &jsonNode{Type: "prefix_operator", Operator: part.Operator}
+		marshalChild(&out.Formula, part.Child)
+	case *Predicate:
+		out = &jsonNode{Type: "predicate", Relation: string(part.Relation)}
+		marshalChild(&out.Left, part.Left)
+		marshalChild(&out.Right, part.Right)
+	case *IsNull:
+		out = &jsonNode{Type: "is_null"}
+		marshalChild(&out.Expression, part.Expression)
+	case *Exists:
+		out = &jsonNode{Type: "exists"}
+		marshalChild(&out.Source, part.Subquery)
+
+	// Triggers.
+	case *CountingTrigger:
+		out = &jsonNode{Type: "counting_trigger"}
+		marshalChild(&out.Expression, part.Count)
+	case *DelayTrigger:
+		out = &jsonNode{Type: "delay_trigger"}
+		marshalChild(&out.Expression, part.Delay)
+	case *WatermarkTrigger:
+		out = &jsonNode{Type: "watermark_trigger"}
+		marshalChild(&out.Expression, part.AllowedLateness)
+
+	// Table valued function arguments.
+	case *TableValuedFunctionArgumentValueExpression:
+		out = &jsonNode{Type: "argument_expression"}
+		marshalChild(&out.Expression, part.expression)
+	case *TableValuedFunctionArgumentValueTable:
+		out = &jsonNode{Type: "argument_table"}
+		marshalChild(&out.Source, part.source)
+	case *TableValuedFunctionArgumentValueDescriptor:
+		out = &jsonNode{Type: "argument_descriptor", Name: part.descriptor.String()}
+
+	default:
+		return nil, errors.Errorf("unsupported plan part %T", part)
+	}
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't serialize %s", out.Type)
+	}
+
+	return out, nil
+}
+
+// marshalNodes serializes a slice of plan parts.
+func marshalNodes(list interface{}) ([]*jsonNode, error) {
+	value := reflect.ValueOf(list)
+	out := make([]*jsonNode, value.Len())
+	for i := range out {
+		var err error
+		out[i], err = marshalNode(value.Index(i).Interface())
+		if err != nil {
+			return nil, err
+		}
+		if out[i] == nil {
+			return nil, errors.Errorf("element with index %d is nil", i)
+		}
+	}
+	return out, nil
+}
+
+// marshalConstant serializes the value of a constant in the JSON form of the protobuf Value, which keeps its type.
+func marshalConstant(value interface{}) (json.RawMessage, error) {
+	normalized, err := octosql.TryNormalizeType(value)
+	if err != nil {
+		// Boolean constants from the parser have their own named type.
+		if reflect.ValueOf(value).Kind() != reflect.Bool {
+			return nil, err
+		}
+		normalized = octosql.MakeBool(reflect.ValueOf(value).Bool())
+	}
+
+	out, err := (&jsonpb.Marshaler{}).MarshalToString(&normalized)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't encode value")
+	}
+	return json.RawMessage(out), nil
+}
+
+func unmarshalNode(in *jsonNode) (Node, error) {
+	part, err := unmarshalPart(in)
+	if err != nil || part == nil {
+		return nil, err
+	}
+	node, ok := part.(Node)
+	if !ok {
+		return nil, errors.Errorf("expected node, got %s", in.Type)
+	}
+	return node, nil
+}
+
+func unmarshalExpression(in *jsonNode) (Expression, error) {
+	part, err := unmarshalPart(in)
+	if err != nil || part == nil {
+		return nil, err
+	}
+	expression, ok := part.(Expression)
+	if !ok {
+		return nil, errors.Errorf("expected expression, got %s", in.Type)
+	}
+	return expression, nil
+}
+
+func unmarshalNamedExpression(in *jsonNode) (NamedExpression, error) {
+	part, err := unmarshalPart(in)
+	if err != nil || part == nil {
+		return nil, err
+	}
+	expression, ok := part.(NamedExpression)
+	if !ok {
+		return nil, errors.Errorf("expected named expression, got %s", in.Type)
+	}
+	return expression, nil
+}
+
+func unmarshalFormula(in *jsonNode) (Formula, error) {
+	part, err := unmarshalPart(in)
+	if err != nil || part == nil {
+		return nil, err
+	}
+	formula, ok := part.(Formula)
+	if !ok {
+		return nil, errors.Errorf("expected formula, got %s", in.Type)
+	}
+	return formula, nil
+}
+
+func unmarshalTrigger(in *jsonNode) (Trigger, error) {
+	part, err := unmarshalPart(in)
+	if err != nil || part == nil {
+		return nil, err
+	}
+	trigger, ok := part.(Trigger)
+	if !ok {
+		return nil, errors.Errorf("expected trigger, got %s", in.Type)
+	}
+	return trigger, nil
+}
+
+func unmarshalExpressions(in []*jsonNode) ([]Expression, error) {
+	var out []Expression
+	for i := range in {
+		expression, err := unmarshalExpression(in[i])
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't deserialize expression with index %d", i)
+		}
+		out = append(out, expression)
+	}
+	return out, nil
+}
+
+func unmarshalTriggers(in []*jsonNode) ([]Trigger, error) {
+	var out []Trigger
+	for i := range in {
+		trigger, err := unmarshalTrigger(in[i])
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't deserialize trigger with index %d", i)
+		}
+		out = append(out, trigger)
+	}
+	return out, nil
+}
+
+func unmarshalNamedExpressions(in []*jsonNode) ([]NamedExpression, error) {
+	var out []NamedExpression
+	for i := range in {
+		expression, err := unmarshalNamedExpression(in[i])
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't deserialize named expression with index %d", i)
+		}
+		out = append(out, expression)
+	}
+	return out, nil
+}
+
+// unmarshalPart deserializes any part of the plan, checking that the parts it's made of are present.
+func unmarshalPart(in *jsonNode) (interface{}, error) {
+	if in == nil {
+		return nil, nil
+	}
+
+	// The helpers below keep the first error, so that the parts can be created without checking each one.
+	var err error
+	node := func(in *jsonNode, name string) Node {
+		if err != nil {
+			return nil
+		}
+		var out Node
+		if out, err = unmarshalNode(in); err == nil && out == nil {
+			err = errors.Errorf("no %s given", name)
+		}
+		return out
+	}
+	expression := func(in *jsonNode, name string) Expression {
+		if err != nil {
+			return nil
+		}
+		var out Expression
+		if out, err = unmarshalExpression(in); err == nil && out == nil {
+			err = errors.Errorf("no %s given", name)
+		}
+		return out
+	}
+	formula := func(in *jsonNode, name string) Formula {
+		if err != nil {
+			return nil
+		}
+		var out Formula
+		if out, err = unmarshalFormula(in); err == nil && out == nil {
+			err = errors.Errorf("no %s given", name)
+		}
+		return out
+	}
+	expressions := func(in []*jsonNode) []Expression {
+		if err != nil {
+			return nil
+		}
+		var out []Expression
+		out, err = unmarshalExpressions(in)
+		return out
+	}
+	namedExpressions := func(in []*jsonNode) []NamedExpression {
+		if err != nil {
+			return nil
+		}
+		var out []NamedExpression
+		out, err = unmarshalNamedExpressions(in)
+		return out
+	}
+	triggers := func(in []*jsonNode) []Trigger {
+		if err != nil {
+			return nil
+		}
+		var out []Trigger
+		out, err = unmarshalTriggers(in)
+		return out
+	}
+
+	var out interface{}
+	switch in.Type {
+	// Nodes.
+	case "data_source":
+		out = NewDataSource(in.Name, in.Alias)
+	case "distinct":
+		out = NewDistinct(node(in.Source, "source"))
+	case "filter":
+		out = NewFilter(formula(in.Formula, "formula"), node(in.Source, "source"))
+	case "group_by":
+		aggregates := make([]Aggregate, len(in.Aggregates))
+		for i := range in.Aggregates {
+			aggregates[i] = Aggregate(in.Aggregates[i])
+		}
+		if len(in.Names) != len(aggregates) || len(in.As) != len(aggregates) {
+			return nil, errors.Errorf("got %d fields and %d aliases for %d aggregates", len(in.Names), len(in.As), len(aggregates))
+		}
+		out = NewGroupBy(node(in.Source, "source"), expressions(in.Key), octosql.StringsToVariableNames(in.Names), aggregates, octosql.StringsToVariableNames(in.As), triggers(in.Triggers))
+	case "join":
+		var joinType execution.JoinType
+		found := false
+		for value, name := range joinTypes {
+			if name == in.JoinType {
+				joinType, found = value, true
+			}
+		}
+		if !found {
+			return nil, errors.Errorf("invalid join type %s", in.JoinType)
+		}
+		out = NewJoin(node(in.Source, "source"), node(in.Joined, "joined"), joinType).WithTriggers(triggers(in.Triggers))
+	case "let":
+		letExpressions := expressions(in.Expressions)
+		if err == nil && len(in.Names) != len(letExpressions) {
+			return nil, errors.Errorf("got %d names for %d expressions", len(in.Names), len(letExpressions))
+		}
+		out = NewLet(octosql.StringsToVariableNames(in.Names), letExpressions, node(in.Source, "source"))
+	case "map":
+		out = NewMap(namedExpressions(in.Expressions), node(in.Source, "source"), in.Keep)
+	case "order_by":
+		orderExpressions := expressions(in.Expressions)
+		if err == nil && len(in.Directions) != len(orderExpressions) {
+			return nil, errors.Errorf("got %d directions for %d expressions", len(in.Directions), len(orderExpressions))
+		}
+		directions := make([]OrderDirection, len(in.Directions))
+		for i := range in.Directions {
+			directions[i] = OrderDirection(in.Directions[i])
+		}
+		out = NewOrderBy(orderExpressions, directions, node(in.Source, "source"))
+	case "requalifier":
+		out = NewRequalifier(in.Qualifier, node(in.Source, "source"))
+	case "set_operation":
+		out = NewSetOperation(node(in.Left, "left"), node(in.Right, "right"), execution.SetOperationType(in.Operation))
+	case "table_valued_function":
+		arguments := make(map[octosql.VariableName]TableValuedFunctionArgumentValue, len(in.Arguments))
+		for name, argument := range in.Arguments {
+			part, err := unmarshalPart(argument)
+			if err != nil {
+				return nil, errors.Wrapf(err, "couldn't deserialize argument %s", name)
+			}
+			value, ok := part.(TableValuedFunctionArgumentValue)
+			if !ok {
+				return nil, errors.Errorf("expected table valued function argument %s", name)
+			}
+			arguments[octosql.NewVariableName(name)] = value
+		}
+		out = NewTableValuedFunction(in.Name, arguments)
+	case "union_all":
+		out = NewUnionAll(node(in.Left, "left"), node(in.Right, "right"))
+	case "union_distinct":
+		out = NewUnionDistinct(node(in.Left, "left"), node(in.Right, "right"))
+	case "unnest":
+		out = NewUnnest(expression(in.Expression, "expression"), in.Alias, node(in.Source, "source"))
+	case "with":
+		var cteNodes []Node
+		for i := range in.Nodes {
+			cteNodes = append(cteNodes, node(in.Nodes[i], "common table expression"))
+		}
+		if len(in.Names) != len(cteNodes) {
+			return nil, errors.Errorf("got %d names for %d common table expressions", len(in.Names), len(cteNodes))
+		}
+		out = NewWith(in.Names, cteNodes, node(in.Source, "source"))
+
+	// Expressions.
+	case "function":
+		out = NewFunctionExpression(in.Name, expressions(in.Expressions))
+	case "interval":
+		if len(in.Expressions) != 2 {
+			return nil, errors.Errorf("expected count and unit of interval, got %d expressions", len(in.Expressions))
+		}
+		out = NewInterval(expression(in.Expressions[0], "count"), expression(in.Expressions[1], "unit"))
+	case "star":
+		out = NewStarExpressionWithModifiers(in.Qualifier, octosql.StringsToVariableNames(in.Names), namedExpressions(in.Expressions))
+	case "variable":
+		out = NewVariable(octosql.NewVariableName(in.Name))
+	case "constant":
+		var value octosql.Value
+		if err := jsonpb.UnmarshalString(string(in.Value), &value); err != nil {
+			return nil, errors.Wrap(err, "couldn't decode constant value")
+		}
+		out = NewConstant(value)
+	case "tuple":
+		out = NewTuple(expressions(in.Expressions))
+	case "node_expression":
+		out = NewNodeExpression(node(in.Source, "source"))
+	case "logic_expression":
+		out = NewLogicExpression(formula(in.Formula, "formula"))
+	case "aliased":
+		out = NewAliasedExpression(octosql.NewVariableName(in.Name), expression(in.Expression, "expression"))
+
+	// Formulas.
+	case "boolean_constant":
+		var value bool
+		if err := json.Unmarshal(in.Value, &value); err != nil {
+			return nil, errors.Wrap(err, "couldn't decode boolean constant value")
+		}
+		out = NewBooleanConstant(value)
+	case "infix_operator":
+		out = NewInfixOperator(formula(in.Left, "left"), formula(in.Right, "right"), in.Operator)
+	case "prefix_operator":
+		out = NewPrefixOperator(formula(in.Formula, "formula"), in.Operator)
+	case "predicate":
+		out = NewPredicate(expression(in.Left, "left"), NewRelation(in.Relation), expression(in.Right, "right"))
+	case "is_null":
+		out = NewIsNull(expression(in.Expression, "expression"))
+	case "exists":
+		out = NewExists(node(in.Source, "source"))
+
+	// Triggers.
+	case "counting_trigger":
+		out = NewCountingTrigger(expression(in.Expression, "count"))
+	case "delay_trigger":
+		out = NewDelayTrigger(expression(in.Expression, "delay"))
+	case "watermark_trigger":
+		trigger := NewWatermarkTrigger()
+		if in.Expression != nil {
+			trigger = trigger.WithAllowedLateness(expression(in.Expression, "allowed lateness"))
+		}
+		out = trigger
+
+	// Table valued function arguments.
+	case "argument_expression":
+		out = NewTableValuedFunctionArgumentValueExpression(expression(in.Expression, "expression"))
+	case "argument_table":
+		out = NewTableValuedFunctionArgumentValueTable(node(in.Source, "source"))
+	case "argument_descriptor":
+		out = NewTableValuedFunctionArgumentValueDescriptor(octosql.NewVariableName(in.Name))
+
+	default:
+		return nil, errors.Errorf("unknown plan part type %s", in.Type)
+	}
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't deserialize %s", in.Type)
+	}
+
+	return out, nil
+}
@@ -0,0 +1,145 @@
+package logical_test
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/cube2222/octosql/logical"
+	"github.com/cube2222/octosql/parser"
+)
+
+func TestMarshalPlan_RoundTrip(t *testing.T) {
+	tests := []struct {
+		name  string
+		query string
+	}{
+		{
+			name:  "star expression with modifiers",
+			query: "SELECT d.* EXCLUDE (age, d.owner) REPLACE (upper(d.name) AS name) FROM dogs d",
+		},
+		{
+			name:  "tuples and subqueries",
+			query: "SELECT * FROM people p WHERE p.age IN (1, 2, 3, 4) AND ('Jacob', 3) IN (SELECT * FROM people p)",
+		},
+		{
+			name:  "group by with triggers",
+			query: "SELECT COUNT(DISTINCT p.name), FIRST(p.age as myage) as firstage, p.surname, p.surname as mysurname FROM people p GROUP BY p.age, p.city TRIGGER AFTER DELAY INTERVAL 3 SECONDS, ON WATERMARK, COUNTING 5",
+		},
+		{
+			name:  "watermark trigger with allowed lateness",
+			query: "SELECT p.city FROM people p GROUP BY p.city TRIGGER ON WATERMARK ALLOWED LATENESS INTERVAL 1 MINUTE",
+		},
+		{
+			name:  "common table expressions",
+			query: "WITH xtab AS (SELECT * FROM tab t), ytab AS (SELECT * FROM xtab x) SELECT * FROM ytab y",
+		},
+		{
+			name:  "join with order by",
+			query: "SELECT p.*, c.name FROM people p JOIN cities c ON p.city = c.name ORDER BY 2",
+		},
+		{
+			name:  "set operations",
+			query: "(SELECT p.name FROM people p WHERE p.age > 3) UNION ALL (SELECT p.name FROM people p WHERE p.age IS NULL OR NOT EXISTS (SELECT * FROM cities c WHERE c.name = p.city))",
+		},
+		{
+			name:  "let with parameters",
+			query: "LET threshold = 3; SELECT a.name FROM anacondas a WHERE a.age > threshold AND a.id = :id AND a.active = true",
+		},
+		{
+			name:  "table valued function",
+			query: `SELECT * FROM func(arg0=>TABLE(test1), arg1=>"test", arg2=>2.5, arg3=> interval 2 hour, arg4=>DESCRIPTOR(test2.test3)) x`,
+		},
+		{
+			name:  "output file",
+			query: "CREATE TABLE 'out.csv' AS SELECT a.name FROM anacondas a",
+		},
+		{
+			name:  "explain",
+			query: "EXPLAIN SELECT p.age + 1 AS older, p.name FROM people p ORDER BY 2, older DESC LIMIT 3 OFFSET 2",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			plan, outputOptions, err := parser.ParseQuery(tt.query)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			data, err := logical.MarshalPlan(plan, outputOptions)
+			if err != nil {
+				t.Fatalf("MarshalPlan() error = %v", err)
+			}
+
+			gotPlan, gotOutputOptions, err := logical.UnmarshalPlan(data)
+			if err != nil {
+				t.Fatalf("UnmarshalPlan() error = %v", err)
+			}
+
+			gotData, err := logical.MarshalPlan(gotPlan, gotOutputOptions)
+			if err != nil {
+				t.Fatalf("MarshalPlan() of deserialized plan error = %v", err)
+			}
+			if string(gotData) != string(data) {
+				t.Errorf("MarshalPlan() of deserialized plan = %s, want %s", gotData, data)
+			}
+
+			if gotOutputOptions.OutputFile != outputOptions.OutputFile ||
+				gotOutputOptions.Explain != outputOptions.Explain ||
+				gotOutputOptions.ExplainAnalyze != outputOptions.ExplainAnalyze ||
+				!reflect.DeepEqual(gotOutputOptions.OrderByDirections, outputOptions.OrderByDirections) {
+				t.Errorf("UnmarshalPlan() output options = %+v, want %+v", gotOutputOptions, outputOptions)
+			}
+		})
+	}
+}
+
+func TestUnmarshalPlan_Invalid(t *testing.T) {
+	tests := []struct {
+		name string
+		data string
+	}{
+		{
+			name: "invalid json",
+			data: `{"version":1,`,
+		},
+		{
+			name: "unsupported version",
+			data: `{"version":2,"plan":{"type":"data_source","name":"people","alias":"p"}}`,
+		},
+		{
+			name: "no plan",
+			data: `{"version":1}`,
+		},
+		{
+			name: "unknown type",
+			data: `{"version":1,"plan":{"type":"sample"}}`,
+		},
+		{
+			name: "missing source",
+			data: `{"version":1,"plan":{"type":"filter","formula":{"type":"boolean_constant","value":true}}}`,
+		},
+		{
+			name: "formula instead of node",
+			data: `{"version":1,"plan":{"type":"distinct","source":{"type":"boolean_constant","value":true}}}`,
+		},
+		{
+			name: "invalid join type",
+			data: `{"version":1,"plan":{"type":"join","join_type":"cross","source":{"type":"data_source","name":"people","alias":"p"},"joined":{"type":"data_source","name":"cities","alias":"c"}}}`,
+		},
+		{
+			name: "directions not matching expressions",
+			data: `{"version":1,"plan":{"type":"order_by","expressions":[{"type":"variable","name":"p.age"}],"source":{"type":"data_source","name":"people","alias":"p"}}}`,
+		},
+		{
+			name: "invalid constant",
+			data: `{"version":1,"plan":{"type":"map","expressions":[{"type":"aliased","name":"x","expression":{"type":"constant","value":3}}],"source":{"type":"data_source","name":"people","alias":"p"}}}`,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, _, err := logical.UnmarshalPlan([]byte(tt.data)); err == nil {
+				t.Errorf("UnmarshalPlan() expected error")
+			}
+		})
+	}
+}
@@ -0,0 +1,89 @@
+package logical
+
+import (
+	"context"
+	"runtime"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical"
+)
+
+type SetOperation struct {
+	first, second Node
+	operation     execution.SetOperationType
+}
+
+func NewSetOperation(first, second Node, operation execution.SetOperationType) *SetOperation {
+	return &SetOperation{first: first, second: second, operation: operation}
+}
+
+func (node *SetOperation) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	groupByParallelism, err := config.GetInt(
+		physicalCreator.physicalConfig,
+		"groupByParallelism",
+		config.WithDefault(runtime.GOMAXPROCS(0)),
+	)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get groupByParallelism configuration")
+	}
+
+	variables := octosql.NoVariables()
+	firstNodes, firstVariables, err := node.first.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for first nodes")
+	}
+	variables, err = variables.MergeWith(firstVariables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get first node variables")
+	}
+
+	secondNodes, secondVariables, err := node.second.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for second nodes")
+	}
+	variables, err = variables.MergeWith(secondVariables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get second node variables")
+	}
+
+	var eventTimeField octosql.VariableName
+	if firstNodes[0].Metadata().EventTimeField() == secondNodes[0].Metadata().EventTimeField() {
+		eventTimeField = firstNodes[0].Metadata().EventTimeField()
+	}
+
+	// Equal records have to end up in the same partition.
+	key := []physical.Expression{physical.NewRecordExpression()}
+	firstShuffled := physical.NewShuffle(groupByParallelism, physical.NewKeyHashingStrategy(key), firstNodes)
+	secondShuffled := physical.NewShuffle(groupByParallelism, physical.NewKeyHashingStrategy(key), secondNodes)
+
+	for i := range firstShuffled {
+		firstShuffled[i] = physical.NewNextShuffleMetadataChange("_left", i, firstShuffled[i])
+	}
+	for i := range secondShuffled {
+		secondShuffled[i] = physical.NewNextShuffleMetadataChange("_right", i, secondShuffled[i])
+	}
+
+	outNodes := make([]physical.Node, len(firstShuffled))
+	for i := range outNodes {
+		outNodes[i] = physical.NewSetOperation(firstShuffled[i], secondShuffled[i], node.operation, eventTimeField)
+	}
+
+	return outNodes, variables, nil
+}
+
+func (node *SetOperation) Visualize() *graph.Node {
+	n := graph.NewNode("Set Operation")
+	if node.first != nil {
+		n.AddChild("first", node.first.Visualize())
+	}
+	if node.second != nil {
+		n.AddChild("second", node.second.Visualize())
+	}
+	n.AddField("operation", string(node.operation))
+	return n
+}
@@ -93,6 +93,21 @@ func (arg *TableValuedFunctionArgumentValueDescriptor) Visualize() *graph.Node {
 	return n
 }
 
+// TableValuedFunctionPositionalArguments holds the names of the arguments of table valued functions
+// which can be passed by position, in order, as in range(1, 10).
+var TableValuedFunctionPositionalArguments = map[string][]octosql.VariableName{
+	"range":           {"range_start", "range_end"},
+	"generate_series": {"start", "stop", "step"},
+	"tumble":          {"source", "time_field", "window_length", "offset"},
+	"hop":             {"source", "time_field", "slide", "window_length", "offset"},
+	"session":         {"source", "time_field", "gap", "key"},
+
+	"max_diff_watermark":  {"source", "time_field", "offset"},
+	"monotonic_watermark": {"source", "time_field"},
+
+	"refresh": {"source", "interval"},
+}
+
 type TableValuedFunction struct {
 	name      string
 	arguments map[octosql.VariableName]TableValuedFunctionArgumentValue
@@ -126,6 +141,13 @@ func (node *TableValuedFunction) Physical(ctx context.Context, physicalCreator *
 		physArguments[k] = physArg
 	}
 
+	// Sessions are assigned per key, so all the records of a key have to end up in the same partition.
+	if node.name == "session" {
+		if err := shuffleSessionSource(physArguments); err != nil {
+			return nil, nil, err
+		}
+	}
+
 	// We only want one source node with multiple partitions for a table valued function, otherwise partitioning gets nasty.
 	// So we find it here if it exists.
 	multipartitionCount := 0
@@ -178,6 +200,43 @@ func (node *TableValuedFunction) Physical(ctx context.Context, physicalCreator *
 	return outNodes, variables, nil
 }
 
+// shuffleSessionSource shuffles a multipartition source by the session key, or into a single partition if there's no key.
+func shuffleSessionSource(physArguments map[octosql.VariableName][]physical.TableValuedFunctionArgumentValue) error {
+	sources := physArguments[octosql.NewVariableName("source")]
+	if len(sources) <= 1 {
+		return nil
+	}
+
+	sourceNodes := make([]physical.Node, len(sources))
+	for i := range sources {
+		table, ok := sources[i].(*physical.TableValuedFunctionArgumentValueTable)
+		if !ok {
+			return errors.Errorf("source argument of session table valued function must be a table")
+		}
+		sourceNodes[i] = table.Source
+	}
+
+	partitions := 1
+	strategy := physical.NewConstantStrategy(0)
+	if keys, ok := physArguments[octosql.NewVariableName("key")]; ok {
+		descriptor, ok := keys[0].(*physical.TableValuedFunctionArgumentValueDescriptor)
+		if !ok {
+			return errors.Errorf("key argument of session table valued function must be a descriptor")
+		}
+		partitions = len(sources)
+		strategy = physical.NewKeyHashingStrategy([]physical.Expression{physical.NewVariable(descriptor.Descriptor)})
+	}
+
+	shuffled := physical.NewShuffle(partitions, strategy, sourceNodes)
+	outputArguments := make([]physical.TableValuedFunctionArgumentValue, len(shuffled))
+	for i := range shuffled {
+		outputArguments[i] = physical.NewTableValuedFunctionArgumentValueTable(shuffled[i])
+	}
+	physArguments[octosql.NewVariableName("source")] = outputArguments
+
+	return nil
+}
+
 func (node *TableValuedFunction) Visualize() *graph.Node {
 	n := graph.NewNode("TableValuedFunction(" + node.name + ")")
 	n.AddField("name", node.name)
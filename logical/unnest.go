@@ -0,0 +1,62 @@
+package logical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical"
+)
+
+// UnnestedField is the name of the field holding the element, which is qualified with the alias of the unnested expression.
+const UnnestedField = "element"
+
+type Unnest struct {
+	expression Expression
+	alias      string
+	source     Node
+}
+
+func NewUnnest(expression Expression, alias string, child Node) *Unnest {
+	return &Unnest{expression: expression, alias: alias, source: child}
+}
+
+func (node *Unnest) Physical(ctx context.Context, physicalCreator *PhysicalPlanCreator) ([]physical.Node, octosql.Variables, error) {
+	expression, expressionVariables, err := node.expression.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for unnested expression")
+	}
+
+	sourceNodes, sourceVariables, err := node.source.Physical(ctx, physicalCreator)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't get physical plan for unnest source nodes")
+	}
+
+	variables, err := sourceVariables.MergeWith(expressionVariables)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't merge variables for unnest source")
+	}
+
+	field := octosql.NewVariableName(node.alias + "." + UnnestedField)
+
+	outputNodes := make([]physical.Node, len(sourceNodes))
+	for i := range outputNodes {
+		outputNodes[i] = physical.NewUnnest(expression, field, sourceNodes[i])
+	}
+
+	return outputNodes, variables, nil
+}
+
+func (node *Unnest) Visualize() *graph.Node {
+	n := graph.NewNode("Unnest")
+	n.AddField("alias", node.alias)
+	if node.expression != nil {
+		n.AddChild("expression", node.expression.Visualize())
+	}
+	if node.source != nil {
+		n.AddChild("source", node.source.Visualize())
+	}
+	return n
+}
@@ -29,6 +29,25 @@ func EqualNodes(node1, node2 Node) error {
 			return nil
 		}
 
+	case *Let:
+		if node2, ok := node2.(*Let); ok {
+			if len(node1.names) != len(node2.names) {
+				return errors.Errorf("counts of bindings not equal: %d vs %d", len(node1.names), len(node2.names))
+			}
+			for i := range node1.names {
+				if node1.names[i] != node2.names[i] {
+					return errors.Errorf("binding names with index %d not equal: %s vs %s", i, node1.names[i], node2.names[i])
+				}
+				if err := EqualExpressions(node1.expressions[i], node2.expressions[i]); err != nil {
+					return errors.Wrapf(err, "binding expressions with index %d not equal", i)
+				}
+			}
+			if err := EqualNodes(node1.source, node2.source); err != nil {
+				return errors.Wrapf(err, "source node not equal: %+v, %+v", node1.source, node2.source)
+			}
+			return nil
+		}
+
 	case *UnionAll:
 		if node2, ok := node2.(*UnionAll); ok {
 			if err := EqualNodes(node1.first, node2.first); err != nil {
@@ -51,6 +70,20 @@ func EqualNodes(node1, node2 Node) error {
 			return nil
 		}
 
+	case *SetOperation:
+		if node2, ok := node2.(*SetOperation); ok {
+			if node1.operation != node2.operation {
+				return errors.Errorf("set operations not equal: %v, %v", node1.operation, node2.operation)
+			}
+			if err := EqualNodes(node1.first, node2.first); err != nil {
+				return errors.Wrapf(err, "first statements not equal: %+v, %+v", node1.first, node2.first)
+			}
+			if err := EqualNodes(node1.second, node2.second); err != nil {
+				return errors.Wrapf(err, "second statements not equal: %+v, %+v", node1.second, node2.second)
+			}
+			return nil
+		}
+
 	case *Map:
 		if node2, ok := node2.(*Map); ok {
 			if len(node1.expressions) != len(node2.expressions) {
@@ -83,6 +116,20 @@ func EqualNodes(node1, node2 Node) error {
 			return nil
 		}
 
+	case *Unnest:
+		if node2, ok := node2.(*Unnest); ok {
+			if node1.alias != node2.alias {
+				return fmt.Errorf("aliases not equal: %v, %v", node1.alias, node2.alias)
+			}
+			if err := EqualExpressions(node1.expression, node2.expression); err != nil {
+				return errors.Wrap(err, "expressions not equal")
+			}
+			if err := EqualNodes(node1.source, node2.source); err != nil {
+				return errors.Wrap(err, "sources not equal")
+			}
+			return nil
+		}
+
 	case *Requalifier:
 		if node2, ok := node2.(*Requalifier); ok {
 			if node1.qualifier != node2.qualifier {
@@ -256,6 +303,22 @@ func EqualFormula(expr1, expr2 Formula) error {
 			return nil
 		}
 
+	case *IsNull:
+		if expr2, ok := expr2.(*IsNull); ok {
+			if err := EqualExpressions(expr1.Expression, expr2.Expression); err != nil {
+				return errors.Wrap(err, "expressions not equal")
+			}
+			return nil
+		}
+
+	case *Exists:
+		if expr2, ok := expr2.(*Exists); ok {
+			if err := EqualNodes(expr1.Subquery, expr2.Subquery); err != nil {
+				return errors.Wrap(err, "subqueries not equal")
+			}
+			return nil
+		}
+
 	default:
 		log.Fatalf("Unsupported equality comparison %v and %v", reflect.TypeOf(expr1), reflect.TypeOf(expr2))
 	}
@@ -270,6 +333,17 @@ func EqualExpressions(expr1, expr2 Expression) error {
 			if expr1.Name() != expr2.Name() {
 				return errors.Errorf("qualifiers not equal: %v %v", expr1.Name().Source(), expr2.Name().Source())
 			}
+			if !reflect.DeepEqual(expr1.exclude, expr2.exclude) {
+				return errors.Errorf("excluded columns not equal: %v %v", expr1.exclude, expr2.exclude)
+			}
+			if len(expr1.replace) != len(expr2.replace) {
+				return errors.Errorf("replaced columns count not equal: %v %v", len(expr1.replace), len(expr2.replace))
+			}
+			for i := range expr1.replace {
+				if err := EqualExpressions(expr1.replace[i], expr2.replace[i]); err != nil {
+					return errors.Wrapf(err, "replaced column with index %v not equal", i)
+				}
+			}
 
 			return nil
 		}
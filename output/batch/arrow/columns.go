@@ -0,0 +1,137 @@
+package arrow
+
+import (
+	"encoding/binary"
+	"math"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output/batch"
+)
+
+// Column is a column of records in the Arrow memory layout.
+type Column struct {
+	Name string
+
+	// Type is one of TypeInt, TypeFloat, TypeBool, TypeBytes, TypeTime, TypeDuration and TypeString.
+	// Columns with values of a single type get that type, and other ones are made of strings.
+	// Times and durations are stored as microseconds.
+	Type octosql.Type
+
+	Length    int
+	NullCount int
+
+	// Buffers are the validity bitmap followed by the values, with the offsets of the values before them for strings and bytes.
+	Buffers [][]byte
+}
+
+// GetColumns returns the columns of all the fields of the records.
+func GetColumns(records []*execution.Record) []Column {
+	fields := batch.GetAllFields(records)
+
+	columns := make([]Column, len(fields))
+	for i, field := range fields {
+		values := make([]octosql.Value, len(records))
+		for j, record := range records {
+			values[j] = record.Value(octosql.NewVariableName(field))
+		}
+		columns[i] = GetColumn(field, values)
+	}
+	return columns
+}
+
+// GetColumn returns the column with the given name and values.
+func GetColumn(name string, values []octosql.Value) Column {
+	nullCount := 0
+	for _, value := range values {
+		if value.GetType() == octosql.TypeNull {
+			nullCount++
+		}
+	}
+
+	columnType := batch.GetColumnType(values)
+	return Column{
+		Name:      name,
+		Type:      columnType,
+		Length:    len(values),
+		NullCount: nullCount,
+		Buffers:   columnBuffers(columnType, values),
+	}
+}
+
+// Format returns the format string of the column type in the Arrow C data interface.
+func (column Column) Format() string {
+	switch column.Type {
+	case octosql.TypeInt:
+		return "l"
+	case octosql.TypeFloat:
+		return "g"
+	case octosql.TypeBool:
+		return "b"
+	case octosql.TypeBytes:
+		return "z"
+	case octosql.TypeTime:
+		return "tsu:UTC"
+	case octosql.TypeDuration:
+		return "tDu"
+	default:
+		return "u"
+	}
+}
+
+// columnBuffers returns the validity bitmap of the column, followed by the buffers of its values.
+func columnBuffers(columnType octosql.Type, values []octosql.Value) [][]byte {
+	validity := make([]byte, (len(values)+7)/8)
+	for i, value := range values {
+		if value.GetType() != octosql.TypeNull {
+			validity[i/8] |= 1 << uint(i%8)
+		}
+	}
+
+	switch columnType {
+	case octosql.TypeInt, octosql.TypeFloat, octosql.TypeTime, octosql.TypeDuration:
+		data := make([]byte, 8*len(values))
+		for i, value := range values {
+			var bits uint64
+			switch value.GetType() {
+			case octosql.TypeInt:
+				bits = uint64(value.AsInt())
+			case octosql.TypeFloat:
+				bits = math.Float64bits(value.AsFloat())
+			case octosql.TypeTime:
+				bits = uint64(value.AsTime().UnixNano() / int64(time.Microsecond))
+			case octosql.TypeDuration:
+				bits = uint64(value.AsDuration() / time.Microsecond)
+			}
+			binary.LittleEndian.PutUint64(data[8*i:], bits)
+		}
+		return [][]byte{validity, data}
+
+	case octosql.TypeBool:
+		data := make([]byte, (len(values)+7)/8)
+		for i, value := range values {
+			if value.GetType() == octosql.TypeBool && value.AsBool() {
+				data[i/8] |= 1 << uint(i%8)
+			}
+		}
+		return [][]byte{validity, data}
+
+	default:
+		offsets := make([]byte, 4*(len(values)+1))
+		var data []byte
+		for i, value := range values {
+			switch value.GetType() {
+			case octosql.TypeNull:
+			case octosql.TypeString:
+				data = append(data, value.AsString()...)
+			case octosql.TypeBytes:
+				data = append(data, value.AsBytes()...)
+			default:
+				data = append(data, value.Show()...)
+			}
+			binary.LittleEndian.PutUint32(offsets[4*(i+1):], uint32(len(data)))
+		}
+		return [][]byte{validity, offsets, data}
+	}
+}
@@ -0,0 +1,167 @@
+package arrow
+
+import (
+	"encoding/binary"
+)
+
+// The Arrow IPC metadata is encoded as FlatBuffers. Only the few kinds of values the schema and record batch messages need are supported,
+// and the buffer is written front to back: each table is preceded by its vtable and followed by the values it references.
+
+// fbTable is a table with its fields indexed by their ids. Absent fields are nil.
+type fbTable []interface{}
+
+// fbScalar is a little-endian scalar stored inline in a table.
+type fbScalar struct {
+	size int
+	bits uint64
+}
+
+func fbInt16(v int16) fbScalar { return fbScalar{size: 2, bits: uint64(uint16(v))} }
+func fbInt32(v int32) fbScalar { return fbScalar{size: 4, bits: uint64(uint32(v))} }
+func fbInt64(v int64) fbScalar { return fbScalar{size: 8, bits: uint64(v)} }
+func fbUint8(v uint8) fbScalar { return fbScalar{size: 1, bits: uint64(v)} }
+
+func fbBool(v bool) fbScalar {
+	if v {
+		return fbUint8(1)
+	}
+	return fbUint8(0)
+}
+
+type fbString string
+
+// fbTables is a vector of tables.
+type fbTables []fbTable
+
+// fbStructs is a vector of structs made of two 8 byte fields, like FieldNode and Buffer, given as the values of all those fields in order.
+type fbStructs []int64
+
+type fbBuilder struct {
+	buf []byte
+}
+
+// encodeFlatbuffer returns the buffer with the given root table, padded to a multiple of 8 bytes.
+func encodeFlatbuffer(root fbTable) []byte {
+	b := &fbBuilder{buf: make([]byte, 4)}
+	tablePos := b.writeTable(root)
+	binary.LittleEndian.PutUint32(b.buf, uint32(tablePos))
+	b.pad(8, 0)
+	return b.buf
+}
+
+// pad appends zeroes until the length of the buffer modulo align equals remainder.
+func (b *fbBuilder) pad(align, remainder int) {
+	for len(b.buf)%align != remainder {
+		b.buf = append(b.buf, 0)
+	}
+}
+
+func (b *fbBuilder) appendUint16(v uint16) {
+	b.buf = append(b.buf, byte(v), byte(v>>8))
+}
+
+func (b *fbBuilder) appendUint32(v uint32) {
+	b.buf = append(b.buf, byte(v), byte(v>>8), byte(v>>16), byte(v>>24))
+}
+
+// setOffset points the offset at the given position to the target position, which has to be after it.
+func (b *fbBuilder) setOffset(pos, target int) {
+	binary.LittleEndian.PutUint32(b.buf[pos:], uint32(target-pos))
+}
+
+func fieldSize(field interface{}) int {
+	if scalar, ok := field.(fbScalar); ok {
+		return scalar.size
+	}
+	return 4 // Offset to the referenced value.
+}
+
+func (b *fbBuilder) writeTable(table fbTable) int {
+	// Fields are laid out after the vtable offset from the largest to the smallest, which keeps them aligned.
+	fieldOffsets := make([]int, len(table))
+	tableSize := 4
+	hasLongFields := false
+	for _, size := range []int{8, 4, 2, 1} {
+		for i, field := range table {
+			if field != nil && fieldSize(field) == size {
+				fieldOffsets[i] = tableSize
+				tableSize += size
+				if size == 8 {
+					hasLongFields = true
+				}
+			}
+		}
+	}
+
+	b.pad(2, 0)
+	vtablePos := len(b.buf)
+	b.appendUint16(uint16(4 + 2*len(table)))
+	b.appendUint16(uint16(tableSize))
+	for _, offset := range fieldOffsets {
+		b.appendUint16(uint16(offset))
+	}
+
+	if hasLongFields {
+		b.pad(8, 4)
+	} else {
+		b.pad(4, 0)
+	}
+	tablePos := len(b.buf)
+	b.appendUint32(uint32(tablePos - vtablePos))
+	b.buf = append(b.buf, make([]byte, tableSize-4)...)
+
+	for i, field := range table {
+		fieldPos := tablePos + fieldOffsets[i]
+		switch field := field.(type) {
+		case nil:
+		case fbScalar:
+			for j := 0; j < field.size; j++ {
+				b.buf[fieldPos+j] = byte(field.bits >> (8 * j))
+			}
+		case fbTable:
+			b.setOffset(fieldPos, b.writeTable(field))
+		case fbString:
+			b.setOffset(fieldPos, b.writeString(field))
+		case fbTables:
+			b.setOffset(fieldPos, b.writeTables(field))
+		case fbStructs:
+			b.setOffset(fieldPos, b.writeStructs(field))
+		default:
+			panic("invalid flatbuffer field")
+		}
+	}
+
+	return tablePos
+}
+
+func (b *fbBuilder) writeString(s fbString) int {
+	b.pad(4, 0)
+	pos := len(b.buf)
+	b.appendUint32(uint32(len(s)))
+	b.buf = append(b.buf, s...)
+	b.buf = append(b.buf, 0)
+	return pos
+}
+
+func (b *fbBuilder) writeTables(tables fbTables) int {
+	b.pad(4, 0)
+	pos := len(b.buf)
+	b.appendUint32(uint32(len(tables)))
+	b.buf = append(b.buf, make([]byte, 4*len(tables))...)
+	for i := range tables {
+		b.setOffset(pos+4+4*i, b.writeTable(tables[i]))
+	}
+	return pos
+}
+
+func (b *fbBuilder) writeStructs(structs fbStructs) int {
+	// The structs start right after the length, aligned to 8 bytes.
+	b.pad(8, 4)
+	pos := len(b.buf)
+	b.appendUint32(uint32(len(structs) / 2))
+	for _, v := range structs {
+		b.appendUint32(uint32(v))
+		b.appendUint32(uint32(uint64(v) >> 32))
+	}
+	return pos
+}
@@ -0,0 +1,145 @@
+package arrow
+
+import (
+	"encoding/binary"
+	"io"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output/batch"
+)
+
+// ContentType is the media type of the Arrow IPC stream format.
+const ContentType = "application/vnd.apache.arrow.stream"
+
+// Values of the Arrow format enums and unions used in the metadata.
+const (
+	metadataVersionV5 = 4
+
+	messageHeaderSchema      = 1
+	messageHeaderRecordBatch = 3
+
+	typeInt           = 2
+	typeFloatingPoint = 3
+	typeBinary        = 4
+	typeUtf8          = 5
+	typeBool          = 6
+	typeTimestamp     = 10
+	typeDuration      = 18
+
+	precisionDouble  = 2
+	unitMicrosecond  = 2
+	endiannessLittle = 0
+)
+
+// TableFormatter writes the records in the Arrow IPC stream format, as a schema followed by a single record batch.
+func TableFormatter() batch.TableFormatter {
+	return func(w io.Writer, records []*execution.Record, watermark time.Time, errToPrint error) error {
+		columns := GetColumns(records)
+
+		schemaFields := make(fbTables, len(columns))
+		var nodes fbStructs
+		var buffers fbStructs
+		var body []byte
+		for i, column := range columns {
+			schemaFields[i] = fbTable{
+				fbString(column.Name),
+				fbBool(true),
+				fbUint8(arrowTypeID(column.Type)),
+				arrowType(column.Type),
+				nil,
+				fbTables{},
+			}
+			nodes = append(nodes, int64(column.Length), int64(column.NullCount))
+
+			for _, buffer := range column.Buffers {
+				buffers = append(buffers, int64(len(body)), int64(len(buffer)))
+				body = append(body, buffer...)
+				for len(body)%8 != 0 {
+					body = append(body, 0)
+				}
+			}
+		}
+
+		schema := fbTable{
+			fbInt16(endiannessLittle),
+			schemaFields,
+		}
+		if err := writeMessage(w, messageHeaderSchema, schema, nil); err != nil {
+			return errors.Wrap(err, "couldn't write schema")
+		}
+
+		recordBatch := fbTable{
+			fbInt64(int64(len(records))),
+			nodes,
+			buffers,
+		}
+		if err := writeMessage(w, messageHeaderRecordBatch, recordBatch, body); err != nil {
+			return errors.Wrap(err, "couldn't write record batch")
+		}
+
+		// End of stream marker.
+		if _, err := w.Write([]byte{0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0}); err != nil {
+			return errors.Wrap(err, "couldn't write end of stream")
+		}
+
+		return nil
+	}
+}
+
+// writeMessage writes the encapsulated message: a continuation marker, the metadata length, the metadata and the body.
+func writeMessage(w io.Writer, headerType uint8, header fbTable, body []byte) error {
+	metadata := encodeFlatbuffer(fbTable{
+		fbInt16(metadataVersionV5),
+		fbUint8(headerType),
+		header,
+		fbInt64(int64(len(body))),
+	})
+
+	prefix := make([]byte, 8)
+	binary.LittleEndian.PutUint32(prefix, 0xffffffff)
+	binary.LittleEndian.PutUint32(prefix[4:], uint32(len(metadata)))
+	for _, data := range [][]byte{prefix, metadata, body} {
+		if _, err := w.Write(data); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func arrowTypeID(columnType octosql.Type) uint8 {
+	switch columnType {
+	case octosql.TypeInt:
+		return typeInt
+	case octosql.TypeFloat:
+		return typeFloatingPoint
+	case octosql.TypeBool:
+		return typeBool
+	case octosql.TypeBytes:
+		return typeBinary
+	case octosql.TypeTime:
+		return typeTimestamp
+	case octosql.TypeDuration:
+		return typeDuration
+	default:
+		return typeUtf8
+	}
+}
+
+func arrowType(columnType octosql.Type) fbTable {
+	switch columnType {
+	case octosql.TypeInt:
+		return fbTable{fbInt32(64), fbBool(true)}
+	case octosql.TypeFloat:
+		return fbTable{fbInt16(precisionDouble)}
+	case octosql.TypeTime:
+		return fbTable{fbInt16(unitMicrosecond), fbString("UTC")}
+	case octosql.TypeDuration:
+		return fbTable{fbInt16(unitMicrosecond)}
+	default:
+		return fbTable{}
+	}
+}
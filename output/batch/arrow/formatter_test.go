@@ -0,0 +1,118 @@
+package arrow
+
+import (
+	"bytes"
+	"encoding/binary"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+)
+
+// fbField returns the position of the field of the table with the given id, or -1 if it's absent.
+func fbField(buf []byte, table, id int) int {
+	vtable := table - int(int32(binary.LittleEndian.Uint32(buf[table:])))
+	if 4+2*id >= int(binary.LittleEndian.Uint16(buf[vtable:])) {
+		return -1
+	}
+	offset := int(binary.LittleEndian.Uint16(buf[vtable+4+2*id:]))
+	if offset == 0 {
+		return -1
+	}
+	return table + offset
+}
+
+func fbDeref(buf []byte, pos int) int {
+	return pos + int(binary.LittleEndian.Uint32(buf[pos:]))
+}
+
+func TestTableFormatter(t *testing.T) {
+	fields := []octosql.VariableName{"id", "name"}
+	records := []*execution.Record{
+		execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeInt(1), octosql.MakeString("a")}, execution.WithID(execution.NewRecordID("s.0"))),
+		execution.NewRecordFromSlice(fields, []octosql.Value{octosql.MakeInt(2), octosql.MakeNull()}, execution.WithID(execution.NewRecordID("s.1"))),
+	}
+
+	var buf bytes.Buffer
+	if err := TableFormatter()(&buf, records, time.Time{}, nil); err != nil {
+		t.Fatal(err)
+	}
+	out := buf.Bytes()
+
+	// The schema message.
+	if got := binary.LittleEndian.Uint32(out); got != 0xffffffff {
+		t.Fatalf("continuation marker = %x", got)
+	}
+	metadataLength := int(binary.LittleEndian.Uint32(out[4:]))
+	if metadataLength%8 != 0 {
+		t.Errorf("metadata length %d isn't a multiple of 8", metadataLength)
+	}
+	metadata := out[8 : 8+metadataLength]
+	message := fbDeref(metadata, 0)
+	if got := metadata[fbField(metadata, message, 1)]; got != messageHeaderSchema {
+		t.Fatalf("header type = %d, want schema", got)
+	}
+	schema := fbDeref(metadata, fbField(metadata, message, 2))
+	schemaFields := fbDeref(metadata, fbField(metadata, schema, 1))
+
+	var names []string
+	var types []byte
+	for i := 0; i < int(binary.LittleEndian.Uint32(metadata[schemaFields:])); i++ {
+		field := fbDeref(metadata, schemaFields+4+4*i)
+		name := fbDeref(metadata, fbField(metadata, field, 0))
+		nameLength := int(binary.LittleEndian.Uint32(metadata[name:]))
+		names = append(names, string(metadata[name+4:name+4+nameLength]))
+		types = append(types, metadata[fbField(metadata, field, 2)])
+	}
+	if want := []string{"id", "name", "sys.id"}; !reflect.DeepEqual(names, want) {
+		t.Errorf("field names = %v, want %v", names, want)
+	}
+	if want := []byte{typeInt, typeUtf8, typeUtf8}; !reflect.DeepEqual(types, want) {
+		t.Errorf("field types = %v, want %v", types, want)
+	}
+
+	// The record batch message, with the validity bitmap, values, and for strings offsets, of each column.
+	batchStart := 8 + metadataLength
+	metadataLength = int(binary.LittleEndian.Uint32(out[batchStart+4:]))
+	metadata = out[batchStart+8 : batchStart+8+metadataLength]
+	message = fbDeref(metadata, 0)
+	if got := metadata[fbField(metadata, message, 1)]; got != messageHeaderRecordBatch {
+		t.Fatalf("header type = %d, want record batch", got)
+	}
+	bodyLength := int(binary.LittleEndian.Uint64(metadata[fbField(metadata, message, 3):]))
+	body := out[batchStart+8+metadataLength : batchStart+8+metadataLength+bodyLength]
+
+	recordBatch := fbDeref(metadata, fbField(metadata, message, 2))
+	if got := binary.LittleEndian.Uint64(metadata[fbField(metadata, recordBatch, 0):]); got != 2 {
+		t.Errorf("record batch length = %d, want 2", got)
+	}
+	buffers := fbDeref(metadata, fbField(metadata, recordBatch, 2))
+	var bufferContents [][]byte
+	for i := 0; i < int(binary.LittleEndian.Uint32(metadata[buffers:])); i++ {
+		offset := binary.LittleEndian.Uint64(metadata[buffers+4+16*i:])
+		length := binary.LittleEndian.Uint64(metadata[buffers+12+16*i:])
+		if offset%8 != 0 {
+			t.Errorf("buffer %d offset %d isn't aligned", i, offset)
+		}
+		bufferContents = append(bufferContents, body[offset:offset+length])
+	}
+	want := [][]byte{
+		{0x03},
+		{1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0},
+		{0x01},
+		{0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0},
+		[]byte("a"),
+		{0x03},
+		{0, 0, 0, 0, 3, 0, 0, 0, 6, 0, 0, 0},
+		[]byte("s.0s.1"),
+	}
+	if !reflect.DeepEqual(bufferContents, want) {
+		t.Errorf("buffers = %v, want %v", bufferContents, want)
+	}
+
+	if got := out[batchStart+8+metadataLength+bodyLength:]; !bytes.Equal(got, []byte{0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0}) {
+		t.Errorf("end of stream = %v", got)
+	}
+}
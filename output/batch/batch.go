@@ -4,6 +4,7 @@ import (
 	"io"
 	"time"
 
+	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/execution"
 )
 
@@ -22,3 +23,28 @@ func GetAllFields(records []*execution.Record) []string {
 	}
 	return fields
 }
+
+// GetColumnType returns the type of the column with the given values, for formats with typed columns.
+// Columns with values of a single type of int, float, bool, bytes, time or duration get that type,
+// other ones, including columns of nulls, are made of strings.
+func GetColumnType(values []octosql.Value) octosql.Type {
+	columnType := octosql.TypeNull
+	for _, value := range values {
+		valueType := value.GetType()
+		if valueType == octosql.TypeNull {
+			continue
+		}
+		if columnType == octosql.TypeNull {
+			columnType = valueType
+		} else if columnType != valueType {
+			return octosql.TypeString
+		}
+	}
+
+	switch columnType {
+	case octosql.TypeInt, octosql.TypeFloat, octosql.TypeBool, octosql.TypeBytes, octosql.TypeTime, octosql.TypeDuration:
+		return columnType
+	default:
+		return octosql.TypeString
+	}
+}
@@ -2,7 +2,6 @@ package csv
 
 import (
 	"encoding/csv"
-	"fmt"
 	"io"
 	"time"
 
@@ -16,7 +15,7 @@ import (
 func TableFormatter(separator rune) batch.TableFormatter {
 	return func(w io.Writer, records []*execution.Record, watermark time.Time, errToPrint error) error {
 		if errToPrint != nil {
-			fmt.Fprintf(w, "Error: %s", errToPrint.Error())
+			return errToPrint
 		}
 		fields := batch.GetAllFields(records)
 
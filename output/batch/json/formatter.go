@@ -0,0 +1,37 @@
+package json
+
+import (
+	"encoding/json"
+	"io"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output/batch"
+)
+
+// TableFormatter writes each record as a separate JSON object, one per line.
+// A query error is returned as is, without writing any records.
+func TableFormatter() batch.TableFormatter {
+	return func(w io.Writer, records []*execution.Record, watermark time.Time, errToPrint error) error {
+		if errToPrint != nil {
+			return errToPrint
+		}
+		fields := batch.GetAllFields(records)
+
+		enc := json.NewEncoder(w)
+		for _, record := range records {
+			kvs := make(map[string]interface{}, len(fields))
+			for _, field := range fields {
+				kvs[field] = record.Value(octosql.NewVariableName(field)).ToRawValue()
+			}
+			if err := enc.Encode(kvs); err != nil {
+				return errors.Wrap(err, "couldn't encode record")
+			}
+		}
+
+		return nil
+	}
+}
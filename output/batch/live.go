@@ -0,0 +1,102 @@
+// +build !js
+
+package batch
+
+import (
+	"bytes"
+	"context"
+	"sort"
+	"time"
+
+	"github.com/gosuri/uilive"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+// The live printer isn't available on js/wasm, as uilive gets the terminal size using syscalls which don't exist there.
+
+type LiveTablePrinter struct {
+	stateStorage   storage.Storage
+	recordsLister  RecordsLister
+	tableFormatter TableFormatter
+	refreshDelay   time.Duration
+}
+
+// NewLiveTablePrinter creates a printer which redraws the current table every refreshDelay,
+// so partial results of slow streams get shown in a timely manner.
+func NewLiveTablePrinter(stateStorage storage.Storage, recordsLister RecordsLister, tableFormatter TableFormatter, refreshDelay time.Duration) *LiveTablePrinter {
+	return &LiveTablePrinter{
+		stateStorage:   stateStorage,
+		recordsLister:  recordsLister,
+		tableFormatter: tableFormatter,
+		refreshDelay:   refreshDelay,
+	}
+}
+
+func (printer *LiveTablePrinter) Run(ctx context.Context) error {
+	liveWriter := uilive.New()
+
+	ticker := time.NewTicker(printer.refreshDelay)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return ctx.Err()
+		case <-ticker.C:
+		}
+
+		tx := printer.stateStorage.BeginTransaction()
+
+		records, err := printer.recordsLister.ListRecords(ctx, tx)
+		if err != nil {
+			return errors.Wrap(err, "couldn't list records")
+		}
+
+		if len(records) > 0 && !records[0].EventTimeField().Empty() {
+			sort.SliceStable(records, func(i, j int) bool {
+				return records[i].EventTime().AsTime().Before(records[j].EventTime().AsTime())
+			})
+		}
+
+		endOfStream, err := printer.recordsLister.GetEndOfStream(ctx, tx)
+		if err != nil {
+			return errors.Wrap(err, "couldn't check if end of stream has been reached")
+		}
+
+		watermark, err := printer.recordsLister.GetWatermark(ctx, tx)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get current watermark")
+		}
+
+		errorMessage, err := printer.recordsLister.GetErrorMessage(ctx, tx)
+		if err != nil {
+			return errors.Wrap(err, "couldn't check if there was an error")
+		}
+
+		var errorToPrint error
+		if len(errorMessage) > 0 {
+			errorToPrint = errors.New(errorMessage)
+		}
+
+		var buf bytes.Buffer
+		err = printer.tableFormatter(&buf, records, watermark, errorToPrint)
+		execution.GetMemoryPool(ctx).Release(execution.RecordsSize(records))
+		if err != nil {
+			return errors.Wrap(err, "couldn't format table")
+		}
+
+		buf.WriteTo(liveWriter)
+		liveWriter.Flush()
+
+		tx.Abort()
+
+		if errorToPrint != nil {
+			return errorToPrint
+		} else if endOfStream {
+			return nil
+		}
+	}
+}
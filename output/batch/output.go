@@ -19,6 +19,7 @@ type TableOutput struct {
 	OrderingDirections  []execution.OrderDirection
 	Limit               *int
 	Offset              *int
+	RetractionFree      bool
 }
 
 func NewTableOutput(
@@ -28,6 +29,7 @@ func NewTableOutput(
 	orderingDirections []execution.OrderDirection,
 	limit *int,
 	offset *int,
+	retractionFree bool,
 ) *TableOutput {
 	return &TableOutput{
 		StreamID:            streamID,
@@ -36,6 +38,7 @@ func NewTableOutput(
 		OrderingDirections:  orderingDirections,
 		Limit:               limit,
 		Offset:              offset,
+		RetractionFree:      retractionFree,
 	}
 }
 
@@ -63,11 +66,7 @@ func (o *TableOutput) AddRecord(ctx context.Context, tx storage.StateTransaction
 		if err != nil {
 			return errors.Wrapf(err, "couldn't evaluate expression with index %d", i)
 		}
-		if o.OrderingDirections[i] == execution.Ascending {
-			orderingPrefix = append(orderingPrefix, expressionValue.MonotonicMarshal()...)
-		} else {
-			orderingPrefix = append(orderingPrefix, expressionValue.ReversedMonotonicMarshal()...)
-		}
+		orderingPrefix = append(orderingPrefix, execution.SortKey(expressionValue, o.OrderingDirections[i])...)
 		orderingPrefix = append(orderingPrefix, '$')
 	}
 
@@ -107,6 +106,49 @@ func (o *TableOutput) AddRecord(ctx context.Context, tx storage.StateTransaction
 		}
 	}
 
+	// If none of the records will get retracted, the ones past the limit will never be listed, so we don't need to keep them.
+	if o.RetractionFree && o.Limit != nil && len(o.OrderingExpressions) > 0 {
+		if err := o.dropRecordsPastLimit(records); err != nil {
+			return errors.Wrap(err, "couldn't drop records past the limit")
+		}
+	}
+
+	return nil
+}
+
+// dropRecordsPastLimit removes the records which are ordered after the first limit + offset records.
+func (o *TableOutput) dropRecordsPastLimit(records *storage.Map) error {
+	keep := *o.Limit
+	if o.Offset != nil {
+		keep += *o.Offset
+	}
+
+	iter := records.GetIterator()
+
+	var toDelete []execution.OrderByKey
+	count := 0
+	var err error
+	var octoKey execution.OrderByKey
+	var recordData RecordData
+	for err = iter.Next(&octoKey, &recordData); err == nil; err = iter.Next(&octoKey, &recordData) {
+		count++
+		if count > keep {
+			toDelete = append(toDelete, octoKey)
+		}
+	}
+	if err != nil && err != storage.ErrEndOfIterator {
+		return errors.Wrap(err, "couldn't iterate over records")
+	}
+	if err := iter.Close(); err != nil {
+		return errors.Wrap(err, "couldn't close iterator")
+	}
+
+	for i := range toDelete {
+		if err := records.Delete(&toDelete[i]); err != nil {
+			return errors.Wrap(err, "couldn't delete record from output records")
+		}
+	}
+
 	return nil
 }
 
@@ -207,9 +249,14 @@ func (o *TableOutput) Close(ctx context.Context, storage storage.Storage) error
 	return nil
 }
 
+// ListRecords returns the records of the table. They're reserved in the memory pool of the query as they're read,
+// so a table which doesn't fit in it fails with execution.ErrMemoryLimitExceeded.
+// The caller has to release them once they're not needed anymore.
 func (o *TableOutput) ListRecords(ctx context.Context, tx storage.StateTransaction) ([]*execution.Record, error) {
 	tx = tx.WithPrefix(o.StreamID.AsPrefix())
 	records := storage.NewMap(tx.WithPrefix(recordsPrefix))
+	pool := execution.GetMemoryPool(ctx)
+	var reserved int64
 
 	iter := records.GetIterator()
 
@@ -254,20 +301,27 @@ func (o *TableOutput) ListRecords(ctx context.Context, tx storage.StateTransacti
 				opts = append(opts, execution.WithUndo())
 			}
 
-			outRecords = append(
-				outRecords,
-				execution.NewRecordFromSlice(
-					variableNames,
-					data,
-					opts...,
-				),
+			record := execution.NewRecordFromSlice(
+				variableNames,
+				data,
+				opts...,
 			)
+			size := execution.RecordsSize([]*execution.Record{record})
+			if err := pool.Reserve(size); err != nil {
+				pool.Release(reserved)
+				iter.Close()
+				return nil, errors.Wrap(err, "couldn't keep records in memory")
+			}
+			reserved += size
+			outRecords = append(outRecords, record)
 		}
 	}
 	if err != nil && err != storage.ErrEndOfIterator {
+		pool.Release(reserved)
 		return nil, errors.Wrap(err, "couldn't iterate over records")
 	}
 	if err := iter.Close(); err != nil {
+		pool.Release(reserved)
 		return nil, errors.Wrap(err, "couldn't close iterator")
 	}
 
@@ -0,0 +1,153 @@
+package batch
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestTableOutput_AddRecord(t *testing.T) {
+	ctx := context.Background()
+	fields := []octosql.VariableName{"a"}
+
+	record := func(a int, opts ...execution.RecordOption) *execution.Record {
+		return execution.NewRecordFromSliceWithNormalize(fields, []interface{}{a}, opts...)
+	}
+	intPtr := func(i int) *int {
+		return &i
+	}
+
+	tests := []struct {
+		name           string
+		limit          *int
+		offset         *int
+		retractionFree bool
+		records        []*execution.Record
+		want           []int
+		wantStored     int
+	}{
+		{
+			name:           "limit and offset without retractions",
+			limit:          intPtr(2),
+			offset:         intPtr(1),
+			retractionFree: true,
+			records: []*execution.Record{
+				record(5),
+				record(3),
+				record(4),
+				record(1),
+				record(6),
+				record(2),
+			},
+			want: []int{2, 3},
+			// Only the top limit + offset records are kept.
+			wantStored: 3,
+		},
+		{
+			name:           "limit without retractions",
+			limit:          intPtr(1),
+			retractionFree: true,
+			records: []*execution.Record{
+				record(2),
+				record(3),
+				record(1),
+			},
+			want:       []int{1},
+			wantStored: 1,
+		},
+		{
+			name:   "limit and offset with retractions",
+			limit:  intPtr(2),
+			offset: intPtr(1),
+			records: []*execution.Record{
+				record(5),
+				record(3),
+				record(4),
+				record(1),
+				record(1, execution.WithUndo()),
+				record(2),
+			},
+			want: []int{3, 4},
+			// The records past the limit may still get into the result, so all of them are kept.
+			wantStored: 4,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+			tx := stateStorage.BeginTransaction()
+			defer tx.Abort()
+			ctx := storage.InjectStateTransaction(ctx, tx)
+
+			streamID := execution.GetRawStreamID()
+			o := NewTableOutput(
+				streamID,
+				octosql.NewVariableName(""),
+				[]execution.Expression{execution.NewVariable("a")},
+				[]execution.OrderDirection{execution.Ascending},
+				tt.limit,
+				tt.offset,
+				tt.retractionFree,
+			)
+
+			for _, rec := range tt.records {
+				if err := o.AddRecord(ctx, tx, 0, rec); err != nil {
+					t.Fatalf("AddRecord() error = %v", err)
+				}
+			}
+
+			stored := 0
+			iter := storage.NewMap(tx.WithPrefix(streamID.AsPrefix()).WithPrefix(recordsPrefix)).GetIterator()
+			var key execution.OrderByKey
+			var recordData RecordData
+			var err error
+			for err = iter.Next(&key, &recordData); err == nil; err = iter.Next(&key, &recordData) {
+				stored++
+			}
+			if err != storage.ErrEndOfIterator {
+				t.Fatalf("couldn't iterate over stored records: %v", err)
+			}
+			if err := iter.Close(); err != nil {
+				t.Fatal(err)
+			}
+			if stored != tt.wantStored {
+				t.Errorf("got %d stored records, want %d", stored, tt.wantStored)
+			}
+
+			records, err := o.ListRecords(ctx, tx)
+			if err != nil {
+				t.Fatalf("ListRecords() error = %v", err)
+			}
+			var got []int
+			for _, rec := range records {
+				got = append(got, rec.Value("a").AsInt())
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ListRecords() = %v, want %v", got, tt.want)
+			}
+
+			// The listed records have to fit in the memory pool of the query.
+			size := execution.RecordsSize(records)
+			pool := execution.NewMemoryPool(size)
+			if _, err := o.ListRecords(execution.WithMemoryPool(ctx, pool), tx); err != nil {
+				t.Errorf("ListRecords() error = %v, want the records to fit", err)
+			}
+			if got := pool.Used(); got != size {
+				t.Errorf("reserved %d bytes, want %d", got, size)
+			}
+			pool = execution.NewMemoryPool(size - 1)
+			if _, err := o.ListRecords(execution.WithMemoryPool(ctx, pool), tx); errors.Cause(err) != execution.ErrMemoryLimitExceeded {
+				t.Errorf("ListRecords() error = %v, want %v", err, execution.ErrMemoryLimitExceeded)
+			}
+			if got := pool.Used(); got != 0 {
+				t.Errorf("reserved %d bytes after failing, want none", got)
+			}
+		})
+	}
+}
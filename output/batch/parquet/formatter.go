@@ -0,0 +1,269 @@
+package parquet
+
+import (
+	"encoding/binary"
+	"io"
+	"math"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output/batch"
+)
+
+const magic = "PAR1"
+
+// Values of the Parquet format enums used in the metadata.
+const (
+	typeBoolean   = 0
+	typeInt64     = 2
+	typeDouble    = 5
+	typeByteArray = 6
+
+	repetitionOptional = 1
+
+	convertedTypeUTF8            = 0
+	convertedTypeTimeMicros      = 8
+	convertedTypeTimestampMicros = 10
+
+	encodingPlain = 0
+	encodingRLE   = 3
+
+	codecUncompressed = 0
+
+	pageTypeDataPage = 0
+)
+
+// TableFormatter writes the records as a Parquet file, with a single row group made of one data page per column.
+// Columns with values of a single type get the matching Parquet type: INT64, DOUBLE, BOOLEAN, BYTE_ARRAY,
+// INT64 timestamps or INT64 times, as Parquet has no duration type, all in microseconds. Other columns are written as UTF8 strings.
+// All columns are optional, and their values are plain encoded and uncompressed.
+func TableFormatter() batch.TableFormatter {
+	return func(w io.Writer, records []*execution.Record, watermark time.Time, errToPrint error) error {
+		if errToPrint != nil {
+			return errToPrint
+		}
+		fields := batch.GetAllFields(records)
+
+		if _, err := io.WriteString(w, magic); err != nil {
+			return errors.Wrap(err, "couldn't write header")
+		}
+		offset := int64(len(magic))
+
+		schema := thriftList{
+			thriftStruct{
+				nil,
+				nil,
+				nil,
+				thriftBinary("schema"),
+				thriftI32(len(fields)),
+			},
+		}
+		var columnChunks thriftList
+		var totalSize int64
+		for _, field := range fields {
+			values := make([]octosql.Value, len(records))
+			for i, record := range records {
+				values[i] = record.Value(octosql.NewVariableName(field))
+			}
+			columnType := batch.GetColumnType(values)
+			schema = append(schema, schemaElement(field, columnType))
+
+			data := appendDefinitionLevels(nil, values)
+			data = appendValues(data, columnType, values)
+			pageHeader := encodeThrift(thriftStruct{
+				thriftI32(pageTypeDataPage),
+				thriftI32(len(data)),
+				thriftI32(len(data)),
+				nil,
+				thriftStruct{
+					thriftI32(len(values)),
+					thriftI32(encodingPlain),
+					thriftI32(encodingRLE),
+					thriftI32(encodingRLE),
+				},
+			})
+			for _, part := range [][]byte{pageHeader, data} {
+				if _, err := w.Write(part); err != nil {
+					return errors.Wrapf(err, "couldn't write column %s", field)
+				}
+			}
+
+			chunkSize := int64(len(pageHeader) + len(data))
+			columnChunks = append(columnChunks, thriftStruct{
+				nil,
+				thriftI64(offset),
+				thriftStruct{
+					thriftI32(physicalType(columnType)),
+					thriftList{thriftI32(encodingPlain), thriftI32(encodingRLE)},
+					thriftList{thriftBinary(field)},
+					thriftI32(codecUncompressed),
+					thriftI64(len(values)),
+					thriftI64(chunkSize),
+					thriftI64(chunkSize),
+					nil,
+					thriftI64(offset),
+				},
+			})
+			offset += chunkSize
+			totalSize += chunkSize
+		}
+
+		var rowGroups thriftList
+		if len(records) > 0 {
+			rowGroups = append(rowGroups, thriftStruct{
+				columnChunks,
+				thriftI64(totalSize),
+				thriftI64(len(records)),
+			})
+		}
+		metadata := encodeThrift(thriftStruct{
+			thriftI32(1),
+			schema,
+			thriftI64(len(records)),
+			rowGroups,
+			nil,
+			thriftBinary("octosql"),
+		})
+
+		footer := make([]byte, 4)
+		binary.LittleEndian.PutUint32(footer, uint32(len(metadata)))
+		for _, part := range [][]byte{metadata, footer, []byte(magic)} {
+			if _, err := w.Write(part); err != nil {
+				return errors.Wrap(err, "couldn't write footer")
+			}
+		}
+
+		return nil
+	}
+}
+
+func physicalType(columnType octosql.Type) int {
+	switch columnType {
+	case octosql.TypeInt, octosql.TypeTime, octosql.TypeDuration:
+		return typeInt64
+	case octosql.TypeFloat:
+		return typeDouble
+	case octosql.TypeBool:
+		return typeBoolean
+	default:
+		return typeByteArray
+	}
+}
+
+func schemaElement(name string, columnType octosql.Type) thriftStruct {
+	element := thriftStruct{
+		thriftI32(physicalType(columnType)),
+		nil,
+		thriftI32(repetitionOptional),
+		thriftBinary(name),
+		nil,
+		nil,
+		nil,
+		nil,
+		nil,
+		nil,
+	}
+
+	// The logical types are unions, of which only the field of the given type is set.
+	microseconds := thriftStruct{nil, thriftStruct{}}
+	switch columnType {
+	case octosql.TypeString:
+		element[5] = thriftI32(convertedTypeUTF8)
+		element[9] = thriftStruct{thriftStruct{}}
+	case octosql.TypeTime:
+		element[5] = thriftI32(convertedTypeTimestampMicros)
+		element[9] = thriftStruct{nil, nil, nil, nil, nil, nil, nil, thriftStruct{thriftBool(true), microseconds}}
+	case octosql.TypeDuration:
+		element[5] = thriftI32(convertedTypeTimeMicros)
+		element[9] = thriftStruct{nil, nil, nil, nil, nil, nil, thriftStruct{thriftBool(true), microseconds}}
+	}
+	return element
+}
+
+// appendDefinitionLevels appends the definition levels of the values, 1 for values and 0 for nulls,
+// as runs of repeated levels in the RLE encoding, prefixed with their length.
+func appendDefinitionLevels(buf []byte, values []octosql.Value) []byte {
+	var levels []byte
+	for i := 0; i < len(values); {
+		level := definitionLevel(values[i])
+		run := 1
+		for i+run < len(values) && definitionLevel(values[i+run]) == level {
+			run++
+		}
+		levels = appendVarint(levels, uint64(run)<<1)
+		levels = append(levels, level)
+		i += run
+	}
+
+	length := make([]byte, 4)
+	binary.LittleEndian.PutUint32(length, uint32(len(levels)))
+	return append(append(buf, length...), levels...)
+}
+
+func definitionLevel(value octosql.Value) byte {
+	if value.GetType() == octosql.TypeNull {
+		return 0
+	}
+	return 1
+}
+
+// appendValues appends the values which aren't null in the plain encoding of the column type.
+func appendValues(buf []byte, columnType octosql.Type, values []octosql.Value) []byte {
+	if columnType == octosql.TypeBool {
+		var bits []byte
+		count := 0
+		for _, value := range values {
+			if value.GetType() == octosql.TypeNull {
+				continue
+			}
+			if count%8 == 0 {
+				bits = append(bits, 0)
+			}
+			if value.AsBool() {
+				bits[count/8] |= 1 << uint(count%8)
+			}
+			count++
+		}
+		return append(buf, bits...)
+	}
+
+	for _, value := range values {
+		if value.GetType() == octosql.TypeNull {
+			continue
+		}
+
+		switch columnType {
+		case octosql.TypeInt:
+			buf = appendUint64(buf, uint64(value.AsInt()))
+		case octosql.TypeFloat:
+			buf = appendUint64(buf, math.Float64bits(value.AsFloat()))
+		case octosql.TypeTime:
+			buf = appendUint64(buf, uint64(value.AsTime().UnixNano()/int64(time.Microsecond)))
+		case octosql.TypeDuration:
+			buf = appendUint64(buf, uint64(value.AsDuration()/time.Microsecond))
+		default:
+			var data []byte
+			switch value.GetType() {
+			case octosql.TypeString:
+				data = []byte(value.AsString())
+			case octosql.TypeBytes:
+				data = value.AsBytes()
+			default:
+				data = []byte(value.Show())
+			}
+			length := make([]byte, 4)
+			binary.LittleEndian.PutUint32(length, uint32(len(data)))
+			buf = append(append(buf, length...), data...)
+		}
+	}
+	return buf
+}
+
+func appendUint64(buf []byte, v uint64) []byte {
+	data := make([]byte, 8)
+	binary.LittleEndian.PutUint64(data, v)
+	return append(buf, data...)
+}
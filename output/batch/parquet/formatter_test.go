@@ -0,0 +1,126 @@
+package parquet
+
+import (
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+	"time"
+
+	parquetgo "github.com/kostya-sh/parquet-go/parquet"
+
+	"github.com/cube2222/octosql"
+	parquetsource "github.com/cube2222/octosql/datasources/parquet"
+	"github.com/cube2222/octosql/execution"
+)
+
+func TestTableFormatter(t *testing.T) {
+	created := time.Date(2020, 5, 17, 12, 30, 0, 123000, time.UTC)
+	fields := []octosql.VariableName{"id", "name", "score", "active", "created", "mixed"}
+	records := []*execution.Record{
+		execution.NewRecordFromSlice(fields, []octosql.Value{
+			octosql.MakeInt(1), octosql.MakeString("a"), octosql.MakeFloat(1.5), octosql.MakeBool(true), octosql.MakeTime(created), octosql.MakeInt(1),
+		}, execution.WithID(execution.NewRecordID("s.0"))),
+		execution.NewRecordFromSlice(fields, []octosql.Value{
+			octosql.MakeInt(2), octosql.MakeNull(), octosql.MakeFloat(2.5), octosql.MakeBool(false), octosql.MakeNull(), octosql.MakeString("x"),
+		}, execution.WithID(execution.NewRecordID("s.1"))),
+		execution.NewRecordFromSlice(fields, []octosql.Value{
+			octosql.MakeInt(3), octosql.MakeString("c"), octosql.MakeNull(), octosql.MakeBool(true), octosql.MakeTime(created), octosql.MakeNull(),
+		}, execution.WithID(execution.NewRecordID("s.2"))),
+	}
+
+	dir, err := ioutil.TempDir("", "octosql-parquet-output")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+	path := filepath.Join(dir, "out.parquet")
+
+	f, err := os.Create(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := TableFormatter()(f, records, time.Time{}, nil); err != nil {
+		t.Fatal(err)
+	}
+	if err := f.Close(); err != nil {
+		t.Fatal(err)
+	}
+
+	// The file is read back using the library the Parquet data source uses.
+	file, err := parquetgo.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer file.Close()
+
+	if file.MetaData.NumRows != 3 {
+		t.Errorf("number of rows = %d, want 3", file.MetaData.NumRows)
+	}
+	if logicalType := file.MetaData.Schema[2].LogicalType; logicalType == nil || logicalType.STRING == nil {
+		t.Errorf("logical type of name = %v, want string", logicalType)
+	}
+	if logicalType := file.MetaData.Schema[5].LogicalType; logicalType == nil || logicalType.TIMESTAMP == nil || !logicalType.TIMESTAMP.Unit.IsSetMICROS() {
+		t.Errorf("logical type of created = %v, want timestamp in microseconds", logicalType)
+	}
+
+	want := map[string][]interface{}{
+		"id":      {int64(1), int64(2), int64(3)},
+		"name":    {[]byte("a"), nil, []byte("c")},
+		"score":   {1.5, 2.5, nil},
+		"active":  {true, false, true},
+		"created": {created.UnixNano() / 1000, nil, created.UnixNano() / 1000},
+		"mixed":   {[]byte("1"), []byte("x"), nil},
+		"sys.id":  {[]byte("s.0"), []byte("s.1"), []byte("s.2")},
+	}
+	columns := file.Schema.Columns()
+	if len(columns) != len(want) {
+		t.Fatalf("got %d columns, want %d", len(columns), len(want))
+	}
+	for _, column := range columns {
+		it := parquetsource.NewColStrIter(file, column)
+		var got []interface{}
+		for {
+			value, err := it.Next()
+			if err == parquetsource.ErrNoNewElement {
+				break
+			} else if err != nil {
+				t.Fatalf("couldn't read column %s: %v", column, err)
+			}
+			got = append(got, value)
+		}
+		if !reflect.DeepEqual(got, want[column.String()]) {
+			t.Errorf("values of column %s = %v, want %v", column, got, want[column.String()])
+		}
+	}
+}
+
+func TestTableFormatter_NoRecords(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-parquet-output")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+	path := filepath.Join(dir, "out.parquet")
+
+	f, err := os.Create(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := TableFormatter()(f, nil, time.Time{}, nil); err != nil {
+		t.Fatal(err)
+	}
+	if err := f.Close(); err != nil {
+		t.Fatal(err)
+	}
+
+	file, err := parquetgo.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer file.Close()
+	if file.MetaData.NumRows != 0 || len(file.MetaData.RowGroups) != 0 {
+		t.Errorf("got %d rows in %d row groups, want an empty file", file.MetaData.NumRows, len(file.MetaData.RowGroups))
+	}
+}
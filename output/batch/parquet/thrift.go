@@ -0,0 +1,123 @@
+package parquet
+
+// The Parquet metadata is encoded using the Thrift compact protocol. Only the kinds of values the file metadata and page headers need are supported.
+
+// thriftStruct is a struct with its fields indexed by their ids minus one. Absent fields are nil.
+type thriftStruct []interface{}
+
+type thriftBool bool
+type thriftI32 int32
+type thriftI64 int64
+type thriftBinary []byte
+
+// thriftList is a list of values of a single type.
+type thriftList []interface{}
+
+// Types of the compact protocol.
+const (
+	thriftTypeTrue   = 1
+	thriftTypeFalse  = 2
+	thriftTypeI32    = 5
+	thriftTypeI64    = 6
+	thriftTypeBinary = 8
+	thriftTypeList   = 9
+	thriftTypeStruct = 12
+)
+
+func encodeThrift(root thriftStruct) []byte {
+	return appendThriftStruct(nil, root)
+}
+
+func thriftType(value interface{}) byte {
+	switch value := value.(type) {
+	case thriftBool:
+		if value {
+			return thriftTypeTrue
+		}
+		return thriftTypeFalse
+	case thriftI32:
+		return thriftTypeI32
+	case thriftI64:
+		return thriftTypeI64
+	case thriftBinary:
+		return thriftTypeBinary
+	case thriftList:
+		return thriftTypeList
+	case thriftStruct:
+		return thriftTypeStruct
+	default:
+		panic("unsupported thrift value")
+	}
+}
+
+func appendThriftStruct(buf []byte, s thriftStruct) []byte {
+	lastID := 0
+	for i, field := range s {
+		if field == nil {
+			continue
+		}
+		id := i + 1
+		// The field header is the difference from the previous field id together with the type, booleans are stored in the type.
+		if delta := id - lastID; delta <= 15 {
+			buf = append(buf, byte(delta<<4)|thriftType(field))
+		} else {
+			buf = append(buf, thriftType(field))
+			buf = appendVarint(buf, zigzag(int64(id)))
+		}
+		lastID = id
+
+		if _, ok := field.(thriftBool); !ok {
+			buf = appendThriftValue(buf, field)
+		}
+	}
+	return append(buf, 0)
+}
+
+func appendThriftValue(buf []byte, value interface{}) []byte {
+	switch value := value.(type) {
+	case thriftBool:
+		return append(buf, thriftType(value))
+	case thriftI32:
+		return appendVarint(buf, zigzag(int64(value)))
+	case thriftI64:
+		return appendVarint(buf, zigzag(int64(value)))
+	case thriftBinary:
+		buf = appendVarint(buf, uint64(len(value)))
+		return append(buf, value...)
+	case thriftList:
+		// The element type of empty lists doesn't matter.
+		elementType := byte(thriftTypeStruct)
+		if len(value) > 0 {
+			elementType = thriftType(value[0])
+			if elementType == thriftTypeFalse {
+				elementType = thriftTypeTrue
+			}
+		}
+		if len(value) < 15 {
+			buf = append(buf, byte(len(value)<<4)|elementType)
+		} else {
+			buf = append(buf, 0xf0|elementType)
+			buf = appendVarint(buf, uint64(len(value)))
+		}
+		for _, element := range value {
+			buf = appendThriftValue(buf, element)
+		}
+		return buf
+	case thriftStruct:
+		return appendThriftStruct(buf, value)
+	default:
+		panic("unsupported thrift value")
+	}
+}
+
+func zigzag(v int64) uint64 {
+	return uint64(v<<1) ^ uint64(v>>63)
+}
+
+func appendVarint(buf []byte, v uint64) []byte {
+	for v >= 0x80 {
+		buf = append(buf, byte(v)|0x80)
+		v >>= 7
+	}
+	return append(buf, byte(v))
+}
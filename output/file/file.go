@@ -0,0 +1,106 @@
+package file
+
+import (
+	"context"
+	"fmt"
+	"io/ioutil"
+	"log"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	batchcsv "github.com/cube2222/octosql/output/batch/csv"
+	batchjson "github.com/cube2222/octosql/output/batch/json"
+	batchparquet "github.com/cube2222/octosql/output/batch/parquet"
+	"github.com/cube2222/octosql/storage"
+)
+
+// FormatterForPath infers the output format from the extension of the given file path.
+func FormatterForPath(path string) (batch.TableFormatter, error) {
+	switch ext := strings.ToLower(filepath.Ext(path)); ext {
+	case ".csv":
+		return batchcsv.TableFormatter(','), nil
+	case ".tsv":
+		return batchcsv.TableFormatter('\t'), nil
+	case ".json", ".jsonl", ".ndjson":
+		return batchjson.TableFormatter(), nil
+	case ".parquet":
+		return batchparquet.TableFormatter(), nil
+	default:
+		return nil, errors.Errorf("unsupported output file extension '%s', available extensions: .csv, .tsv, .json, .jsonl, .ndjson, .parquet", ext)
+	}
+}
+
+// NewOutput creates an output which writes the whole result table to the file under the given path,
+// once the end of stream has been reached.
+func NewOutput(
+	stateStorage storage.Storage,
+	streamID *execution.StreamID,
+	eventTimeField octosql.VariableName,
+	orderingExpressions []execution.Expression,
+	orderingDirections []execution.OrderDirection,
+	limit *int,
+	offset *int,
+	retractionFree bool,
+	path string,
+) (execution.IntermediateRecordStore, output.Printer, error) {
+	formatter, err := FormatterForPath(path)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't infer output file format")
+	}
+
+	sink := batch.NewTableOutput(streamID, eventTimeField, orderingExpressions, orderingDirections, limit, offset, retractionFree)
+
+	return sink, &Printer{
+		stateStorage:   stateStorage,
+		recordsLister:  sink,
+		tableFormatter: formatter,
+		path:           path,
+	}, nil
+}
+
+type Printer struct {
+	stateStorage   storage.Storage
+	recordsLister  batch.RecordsLister
+	tableFormatter batch.TableFormatter
+	path           string
+}
+
+// Run writes the result to a temporary file next to the destination, and renames it once the whole result has been written,
+// so that a failed query doesn't leave a partial result or overwrite an existing file.
+func (printer *Printer) Run(ctx context.Context) error {
+	dir, name := filepath.Split(printer.path)
+	if dir == "" {
+		dir = "."
+	}
+	f, err := ioutil.TempFile(dir, fmt.Sprintf(".%s.*.tmp", name))
+	if err != nil {
+		return errors.Wrapf(err, "couldn't create temporary output file for %s", printer.path)
+	}
+
+	err = batch.NewWholeTableWriterPrinter(printer.stateStorage, printer.recordsLister, printer.tableFormatter, f).Run(ctx)
+	if closeErr := f.Close(); closeErr != nil && err == nil {
+		err = errors.Wrapf(closeErr, "couldn't close temporary output file %s", f.Name())
+	}
+	if err == nil {
+		// Temporary files are only readable by their owner.
+		err = errors.Wrapf(os.Chmod(f.Name(), 0644), "couldn't set permissions of temporary output file %s", f.Name())
+	}
+	if err == nil {
+		err = errors.Wrapf(os.Rename(f.Name(), printer.path), "couldn't move temporary output file to %s", printer.path)
+	}
+	if err != nil {
+		if removeErr := os.Remove(f.Name()); removeErr != nil && !os.IsNotExist(removeErr) {
+			log.Printf("couldn't remove temporary output file %s: %s", f.Name(), removeErr)
+		}
+		return err
+	}
+
+	return nil
+}
@@ -0,0 +1,135 @@
+package file
+
+import (
+	"context"
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+type stubRecordsLister struct {
+	records      []*execution.Record
+	errorMessage string
+}
+
+func (l *stubRecordsLister) ListRecords(ctx context.Context, tx storage.StateTransaction) ([]*execution.Record, error) {
+	return l.records, nil
+}
+
+func (l *stubRecordsLister) GetWatermark(ctx context.Context, tx storage.StateTransaction) (time.Time, error) {
+	return time.Time{}, nil
+}
+
+func (l *stubRecordsLister) GetEndOfStream(ctx context.Context, tx storage.StateTransaction) (bool, error) {
+	return l.errorMessage == "", nil
+}
+
+func (l *stubRecordsLister) GetErrorMessage(ctx context.Context, tx storage.StateTransaction) (string, error) {
+	return l.errorMessage, nil
+}
+
+func TestPrinter_Run(t *testing.T) {
+	records := []*execution.Record{
+		execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"name"}, []interface{}{"wojtek"}, execution.WithID(execution.NewRecordID("id1"))),
+	}
+
+	tests := []struct {
+		name    string
+		ext     string
+		lister  *stubRecordsLister
+		want    string
+		wantErr bool
+	}{
+		{
+			name:   "csv",
+			ext:    ".csv",
+			lister: &stubRecordsLister{records: records},
+			want:   "name,sys.id\nwojtek,id1\n",
+		},
+		{
+			name:   "json",
+			ext:    ".json",
+			lister: &stubRecordsLister{records: records},
+			want:   "{\"name\":\"wojtek\",\"sys.id\":\"id1\"}\n",
+		},
+		{
+			name:    "csv query error",
+			ext:     ".csv",
+			lister:  &stubRecordsLister{records: records, errorMessage: "division by zero"},
+			want:    "previous",
+			wantErr: true,
+		},
+		{
+			name:    "json query error",
+			ext:     ".json",
+			lister:  &stubRecordsLister{records: records, errorMessage: "division by zero"},
+			want:    "previous",
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			dir, err := ioutil.TempDir("", "octosql-file-output")
+			if err != nil {
+				t.Fatal(err)
+			}
+			defer os.RemoveAll(dir)
+
+			path := filepath.Join(dir, "out"+tt.ext)
+			if err := ioutil.WriteFile(path, []byte("previous"), 0644); err != nil {
+				t.Fatal(err)
+			}
+
+			formatter, err := FormatterForPath(path)
+			if err != nil {
+				t.Fatal(err)
+			}
+			printer := &Printer{
+				stateStorage:   storage.GetTestStorage(t),
+				recordsLister:  tt.lister,
+				tableFormatter: formatter,
+				path:           path,
+			}
+
+			if err := printer.Run(context.Background()); (err != nil) != tt.wantErr {
+				t.Fatalf("Run() error = %v, wantErr %v", err, tt.wantErr)
+			}
+
+			got, err := ioutil.ReadFile(path)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if string(got) != tt.want {
+				t.Errorf("got file contents %q, want %q", got, tt.want)
+			}
+
+			// The temporary file mustn't be left behind.
+			files, err := ioutil.ReadDir(dir)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(files) != 1 {
+				t.Errorf("got %d files in output directory, want 1", len(files))
+			}
+		})
+	}
+}
+
+func TestFormatterForPath(t *testing.T) {
+	for _, path := range []string{"out.csv", "out.TSV", "out.jsonl", "out.parquet"} {
+		if _, err := FormatterForPath(path); err != nil {
+			t.Errorf("unexpected error for output file %s: %v", path, err)
+		}
+	}
+	for _, path := range []string{"out.xlsx", "out"} {
+		if _, err := FormatterForPath(path); err == nil {
+			t.Errorf("expected error for unsupported output file %s", path)
+		}
+	}
+}
@@ -3,6 +3,7 @@ package json
 import (
 	"bufio"
 	"encoding/json"
+	"io"
 	"log"
 	"os"
 
@@ -11,6 +12,11 @@ import (
 
 func JSONPrinter() func(rec *execution.Record) {
 	w := bufio.NewWriter(os.Stdout)
+	return JSONWriterPrinter(w, w.Flush)
+}
+
+// JSONWriterPrinter writes each record to w as a JSON object, one per line, calling flush after each one.
+func JSONWriterPrinter(w io.Writer, flush func() error) func(rec *execution.Record) {
 	enc := json.NewEncoder(w)
 
 	return func(rec *execution.Record) {
@@ -22,8 +28,8 @@ func JSONPrinter() func(rec *execution.Record) {
 		if err := enc.Encode(kvs); err != nil {
 			log.Println("error encoding record for output print: ", err)
 		}
-		if err := w.Flush(); err != nil {
-			log.Println("error flushing stdout buffered writer: ", err)
+		if err := flush(); err != nil {
+			log.Println("error flushing buffered writer: ", err)
 		}
 	}
 }
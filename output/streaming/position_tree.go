@@ -0,0 +1,405 @@
+package streaming
+
+import (
+	"bytes"
+
+	"github.com/pkg/errors"
+	"github.com/twmb/murmur3"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+var positionTreeRootPrefix = []byte("$root$")
+var positionTreeNodesPrefix = []byte("$nodes$")
+var positionTreeRecordsPrefix = []byte("$records$")
+
+// positionTree is a multiset of keys, which can tell the position of a key in the ordered result,
+// and the key at a position, in logarithmic time.
+// It's a treap, with the priorities of the nodes being the hashes of their keys, and each node
+// storing the number of records in its subtree. A single record with each key is kept as well,
+// to be sent when the key enters or leaves the part of the result which is shown.
+type positionTree struct {
+	root    *storage.ValueState
+	nodes   *storage.Map
+	records *storage.Map
+}
+
+type positionTreeNode struct {
+	key   []byte
+	left  []byte
+	right []byte
+	count int
+	size  int
+}
+
+func newPositionTree(tx storage.StateTransaction) *positionTree {
+	return &positionTree{
+		root:    storage.NewValueState(tx.WithPrefix(positionTreeRootPrefix)),
+		nodes:   storage.NewMap(tx.WithPrefix(positionTreeNodesPrefix)),
+		records: storage.NewMap(tx.WithPrefix(positionTreeRecordsPrefix)),
+	}
+}
+
+// CountBefore returns the number of records with keys less than the given one.
+func (t *positionTree) CountBefore(key []byte) (int, error) {
+	cur, err := t.getRoot()
+	if err != nil {
+		return 0, err
+	}
+
+	position := 0
+	for cur != nil {
+		node, err := t.getNode(cur)
+		if err != nil {
+			return 0, err
+		}
+		leftSize, err := t.getSize(node.left)
+		if err != nil {
+			return 0, err
+		}
+
+		switch cmp := bytes.Compare(key, node.key); {
+		case cmp == 0:
+			return position + leftSize, nil
+		case cmp < 0:
+			cur = node.left
+		default:
+			position += leftSize + node.count
+			cur = node.right
+		}
+	}
+
+	return position, nil
+}
+
+// Count returns the number of records with the given key.
+func (t *positionTree) Count(key []byte) (int, error) {
+	node, err := t.getNode(key)
+	if err == storage.ErrNotFound {
+		return 0, nil
+	} else if err != nil {
+		return 0, err
+	}
+	return node.count, nil
+}
+
+// RecordAt returns the record at the given position, or false if there are no more records.
+func (t *positionTree) RecordAt(position int) (*execution.Record, bool, error) {
+	cur, err := t.getRoot()
+	if err != nil {
+		return nil, false, err
+	}
+
+	for cur != nil {
+		node, err := t.getNode(cur)
+		if err != nil {
+			return nil, false, err
+		}
+		leftSize, err := t.getSize(node.left)
+		if err != nil {
+			return nil, false, err
+		}
+
+		switch {
+		case position < leftSize:
+			cur = node.left
+		case position < leftSize+node.count:
+			var record execution.Record
+			if err := t.records.Get(execution.NewOrderByKey(node.key), &record); err != nil {
+				return nil, false, errors.Wrap(err, "couldn't get record")
+			}
+			return &record, true, nil
+		default:
+			position -= leftSize + node.count
+			cur = node.right
+		}
+	}
+
+	return nil, false, nil
+}
+
+// Add adds a record with the given key.
+func (t *positionTree) Add(key []byte, record *execution.Record) error {
+	count, err := t.Count(key)
+	if err != nil {
+		return err
+	}
+	if count == 0 {
+		stored := execution.NewRecordFromRecord(record, execution.WithNoUndo())
+		if err := t.records.Set(execution.NewOrderByKey(key), stored); err != nil {
+			return errors.Wrap(err, "couldn't set record")
+		}
+	}
+
+	root, err := t.getRoot()
+	if err != nil {
+		return err
+	}
+	root, err = t.add(root, key)
+	if err != nil {
+		return err
+	}
+	return t.setRoot(root)
+}
+
+// Remove removes a record with the given key, which has to be in the tree.
+func (t *positionTree) Remove(key []byte) error {
+	count, err := t.Count(key)
+	if err != nil {
+		return err
+	}
+	if count == 1 {
+		if err := t.records.Delete(execution.NewOrderByKey(key)); err != nil {
+			return errors.Wrap(err, "couldn't delete record")
+		}
+	}
+
+	root, err := t.getRoot()
+	if err != nil {
+		return err
+	}
+	root, err = t.remove(root, key)
+	if err != nil {
+		return err
+	}
+	return t.setRoot(root)
+}
+
+// add adds the key to the subtree with the given root, and returns the new root of the subtree.
+func (t *positionTree) add(cur []byte, key []byte) ([]byte, error) {
+	if cur == nil {
+		return key, t.setNode(&positionTreeNode{key: key, count: 1, size: 1})
+	}
+
+	node, err := t.getNode(cur)
+	if err != nil {
+		return nil, err
+	}
+	node.size++
+
+	switch cmp := bytes.Compare(key, node.key); {
+	case cmp == 0:
+		node.count++
+		return cur, t.setNode(node)
+	case cmp < 0:
+		if node.left, err = t.add(node.left, key); err != nil {
+			return nil, err
+		}
+		if priority(node.left) > priority(node.key) {
+			return t.rotateRight(node)
+		}
+	default:
+		if node.right, err = t.add(node.right, key); err != nil {
+			return nil, err
+		}
+		if priority(node.right) > priority(node.key) {
+			return t.rotateLeft(node)
+		}
+	}
+
+	return cur, t.setNode(node)
+}
+
+// remove removes the key from the subtree with the given root, and returns the new root of the subtree.
+func (t *positionTree) remove(cur []byte, key []byte) ([]byte, error) {
+	if cur == nil {
+		return nil, errors.New("key not found in position tree")
+	}
+
+	node, err := t.getNode(cur)
+	if err != nil {
+		return nil, err
+	}
+
+	switch cmp := bytes.Compare(key, node.key); {
+	case cmp == 0:
+		if node.count == 1 {
+			return t.removeNode(node)
+		}
+		node.count--
+	case cmp < 0:
+		if node.left, err = t.remove(node.left, key); err != nil {
+			return nil, err
+		}
+	default:
+		if node.right, err = t.remove(node.right, key); err != nil {
+			return nil, err
+		}
+	}
+	node.size--
+
+	return cur, t.setNode(node)
+}
+
+// removeNode rotates the node down until it's got at most one child, and then replaces it with that child.
+func (t *positionTree) removeNode(node *positionTreeNode) ([]byte, error) {
+	if node.left == nil || node.right == nil {
+		if err := t.nodes.Delete(execution.NewOrderByKey(node.key)); err != nil {
+			return nil, errors.Wrap(err, "couldn't delete node")
+		}
+		if node.left == nil {
+			return node.right, nil
+		}
+		return node.left, nil
+	}
+
+	rotate := t.rotateLeft
+	if priority(node.left) > priority(node.right) {
+		rotate = t.rotateRight
+	}
+	top, err := rotate(node)
+	if err != nil {
+		return nil, err
+	}
+
+	// The node is now a child of the new top of the subtree.
+	topNode, err := t.getNode(top)
+	if err != nil {
+		return nil, err
+	}
+	if bytes.Equal(topNode.left, node.key) {
+		topNode.left, err = t.removeNode(node)
+	} else {
+		topNode.right, err = t.removeNode(node)
+	}
+	if err != nil {
+		return nil, err
+	}
+	topNode.size--
+
+	return top, t.setNode(topNode)
+}
+
+func (t *positionTree) rotateRight(node *positionTreeNode) ([]byte, error) {
+	left, err := t.getNode(node.left)
+	if err != nil {
+		return nil, err
+	}
+	node.left = left.right
+	left.right = node.key
+	if err := t.updateSize(node); err != nil {
+		return nil, err
+	}
+	if err := t.updateSize(left); err != nil {
+		return nil, err
+	}
+	return left.key, nil
+}
+
+func (t *positionTree) rotateLeft(node *positionTreeNode) ([]byte, error) {
+	right, err := t.getNode(node.right)
+	if err != nil {
+		return nil, err
+	}
+	node.right = right.left
+	right.left = node.key
+	if err := t.updateSize(node); err != nil {
+		return nil, err
+	}
+	if err := t.updateSize(right); err != nil {
+		return nil, err
+	}
+	return right.key, nil
+}
+
+// updateSize recomputes the size of the node from its children, and saves it.
+func (t *positionTree) updateSize(node *positionTreeNode) error {
+	leftSize, err := t.getSize(node.left)
+	if err != nil {
+		return err
+	}
+	rightSize, err := t.getSize(node.right)
+	if err != nil {
+		return err
+	}
+	node.size = leftSize + node.count + rightSize
+	return t.setNode(node)
+}
+
+func (t *positionTree) getSize(key []byte) (int, error) {
+	if key == nil {
+		return 0, nil
+	}
+	node, err := t.getNode(key)
+	if err != nil {
+		return 0, err
+	}
+	return node.size, nil
+}
+
+func (t *positionTree) getRoot() ([]byte, error) {
+	var root octosql.Value
+	err := t.root.Get(&root)
+	if err == storage.ErrNotFound {
+		return nil, nil
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't get root of position tree")
+	}
+	return root.AsBytes(), nil
+}
+
+func (t *positionTree) setRoot(key []byte) error {
+	if key == nil {
+		if err := t.root.Clear(); err != nil {
+			return errors.Wrap(err, "couldn't clear root of position tree")
+		}
+		return nil
+	}
+
+	root := octosql.MakeBytes(key)
+	if err := t.root.Set(&root); err != nil {
+		return errors.Wrap(err, "couldn't set root of position tree")
+	}
+	return nil
+}
+
+func (t *positionTree) getNode(key []byte) (*positionTreeNode, error) {
+	var value octosql.Value
+	if err := t.nodes.Get(execution.NewOrderByKey(key), &value); err == storage.ErrNotFound {
+		return nil, err
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't get node of position tree")
+	}
+
+	fields := value.AsSlice()
+	return &positionTreeNode{
+		key:   key,
+		left:  childFromValue(fields[0]),
+		right: childFromValue(fields[1]),
+		count: fields[2].AsInt(),
+		size:  fields[3].AsInt(),
+	}, nil
+}
+
+func (t *positionTree) setNode(node *positionTreeNode) error {
+	value := octosql.MakeTuple([]octosql.Value{
+		childToValue(node.left),
+		childToValue(node.right),
+		octosql.MakeInt(node.count),
+		octosql.MakeInt(node.size),
+	})
+	if err := t.nodes.Set(execution.NewOrderByKey(node.key), &value); err != nil {
+		return errors.Wrap(err, "couldn't set node of position tree")
+	}
+	return nil
+}
+
+func childToValue(key []byte) octosql.Value {
+	if key == nil {
+		return octosql.MakeNull()
+	}
+	return octosql.MakeBytes(key)
+}
+
+func childFromValue(value octosql.Value) []byte {
+	if value.GetType() == octosql.TypeNull {
+		return nil
+	}
+	return value.AsBytes()
+}
+
+func priority(key []byte) uint64 {
+	return murmur3.Sum64(key)
+}
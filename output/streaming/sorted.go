@@ -0,0 +1,196 @@
+package streaming
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+var sortedRecordsPrefix = []byte("$sorted_records$")
+
+// PositionField is the field with the position of the record in the sorted result, added by the SortedStreamOutput.
+var PositionField = octosql.NewVariableName("sys.position")
+
+// SortedStreamOutput sends every record as soon as it's available, like the InstantStreamOutput,
+// but keeps the current result ordered by the ordering expressions, with retractions applied,
+// and adds the position of the record in it. A record should be inserted at that position,
+// and a retraction removes the record at that position, so the live result is always in the requested order.
+// With a limit or an offset, only the records in that part of the result are sent, and positions are relative to it.
+// A record shifting others into or out of it causes those to be sent, or retracted, as well.
+type SortedStreamOutput struct {
+	*InstantStreamOutput
+	OrderingExpressions []execution.Expression
+	OrderingDirections  []execution.OrderDirection
+	Limit               *int
+	Offset              *int
+}
+
+func NewSortedStreamOutput(streamID *execution.StreamID, orderingExpressions []execution.Expression, orderingDirections []execution.OrderDirection, limit, offset *int) *SortedStreamOutput {
+	return &SortedStreamOutput{
+		InstantStreamOutput: NewInstantStreamOutput(streamID),
+		OrderingExpressions: orderingExpressions,
+		OrderingDirections:  orderingDirections,
+		Limit:               limit,
+		Offset:              offset,
+	}
+}
+
+func (o *SortedStreamOutput) AddRecord(ctx context.Context, tx storage.StateTransaction, inputIndex int, record *execution.Record) error {
+	if inputIndex != 0 {
+		return errors.Errorf("only one input stream allowed for output, got input index %d", inputIndex)
+	}
+	sortedRecords := newPositionTree(tx.WithPrefix(o.StreamID.AsPrefix()).WithPrefix(sortedRecordsPrefix))
+
+	variables := record.AsVariables()
+
+	orderingPrefix := []byte("$")
+	for i := range o.OrderingExpressions {
+		expressionValue, err := o.OrderingExpressions[i].ExpressionValue(ctx, variables)
+		if err != nil {
+			return errors.Wrapf(err, "couldn't evaluate expression with index %d", i)
+		}
+		orderingPrefix = append(orderingPrefix, execution.SortKey(expressionValue, o.OrderingDirections[i])...)
+		orderingPrefix = append(orderingPrefix, '$')
+	}
+
+	recordKV := map[string]octosql.Value{}
+	for _, field := range record.Fields() {
+		recordKV[field.Name.String()] = record.Value(field.Name)
+	}
+
+	objectKey := octosql.MakeObject(recordKV)
+
+	key := append(orderingPrefix, objectKey.MonotonicMarshal()...)
+
+	position, err := sortedRecords.CountBefore(key)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get position of record")
+	}
+
+	if record.IsUndo() {
+		count, err := sortedRecords.Count(key)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get current count of record")
+		}
+		if count == 0 {
+			return errors.Errorf("retraction of record %s, which isn't in the result", record.Show())
+		}
+		if err := sortedRecords.Remove(key); err != nil {
+			return errors.Wrap(err, "couldn't remove record from sorted records")
+		}
+		return o.recordRemoved(ctx, tx, sortedRecords, record, position)
+	}
+
+	if err := sortedRecords.Add(key, record); err != nil {
+		return errors.Wrap(err, "couldn't add record to sorted records")
+	}
+	return o.recordAdded(ctx, tx, sortedRecords, record, position)
+}
+
+// recordAdded sends the changes to the shown part of the result caused by the record added at the given position.
+func (o *SortedStreamOutput) recordAdded(ctx context.Context, tx storage.StateTransaction, sortedRecords *positionTree, record *execution.Record, position int) error {
+	offset := o.offset()
+	if o.Limit != nil && (*o.Limit == 0 || position >= offset+*o.Limit) {
+		return nil
+	}
+
+	if position < offset {
+		// The record before the shown part gets shifted into it.
+		entering, ok, err := sortedRecords.RecordAt(offset)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get record entering the result")
+		}
+		if !ok {
+			return nil
+		}
+		if err := o.send(ctx, tx, entering, 0, false); err != nil {
+			return err
+		}
+	} else {
+		if err := o.send(ctx, tx, record, position-offset, false); err != nil {
+			return err
+		}
+	}
+
+	if o.Limit == nil {
+		return nil
+	}
+	// The last record gets shifted out of the shown part.
+	leaving, ok, err := sortedRecords.RecordAt(offset + *o.Limit)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get record leaving the result")
+	}
+	if !ok {
+		return nil
+	}
+	return o.send(ctx, tx, leaving, *o.Limit, true)
+}
+
+// recordRemoved sends the changes to the shown part of the result caused by the record removed from the given position.
+func (o *SortedStreamOutput) recordRemoved(ctx context.Context, tx storage.StateTransaction, sortedRecords *positionTree, record *execution.Record, position int) error {
+	offset := o.offset()
+	if o.Limit != nil && (*o.Limit == 0 || position >= offset+*o.Limit) {
+		return nil
+	}
+
+	if position < offset {
+		// The first record gets shifted out of the shown part.
+		leaving, ok, err := sortedRecords.RecordAt(offset - 1)
+		if err != nil {
+			return errors.Wrap(err, "couldn't get record leaving the result")
+		}
+		if !ok {
+			return nil
+		}
+		if err := o.send(ctx, tx, leaving, 0, true); err != nil {
+			return err
+		}
+	} else {
+		if err := o.send(ctx, tx, record, position-offset, true); err != nil {
+			return err
+		}
+	}
+
+	if o.Limit == nil {
+		return nil
+	}
+	// The record after the shown part gets shifted into it.
+	entering, ok, err := sortedRecords.RecordAt(offset + *o.Limit - 1)
+	if err != nil {
+		return errors.Wrap(err, "couldn't get record entering the result")
+	}
+	if !ok {
+		return nil
+	}
+	return o.send(ctx, tx, entering, *o.Limit-1, false)
+}
+
+func (o *SortedStreamOutput) offset() int {
+	if o.Offset == nil {
+		return 0
+	}
+	return *o.Offset
+}
+
+// send sends the record with its position added.
+func (o *SortedStreamOutput) send(ctx context.Context, tx storage.StateTransaction, record *execution.Record, position int, undo bool) error {
+	fields := append(record.GetVariableNames(), PositionField)
+	data := make([]octosql.Value, len(fields))
+	for i := range record.Data {
+		data[i] = *record.Data[i]
+	}
+	data[len(data)-1] = octosql.MakeInt(position)
+
+	undoOption := execution.WithNoUndo()
+	if undo {
+		undoOption = execution.WithUndo()
+	}
+	metadata := execution.NewRecordFromRecord(record, undoOption)
+	positioned := execution.NewRecordFromSlice(fields, data, execution.WithMetadataFrom(metadata))
+
+	return o.InstantStreamOutput.AddRecord(ctx, tx, 0, positioned)
+}
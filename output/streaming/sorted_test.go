@@ -0,0 +1,195 @@
+package streaming
+
+import (
+	"context"
+	"fmt"
+	"math/rand"
+	"sort"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestSortedStreamOutput_AddRecord(t *testing.T) {
+	ctx := context.Background()
+	fields := []octosql.VariableName{"a"}
+	outputFields := []octosql.VariableName{"a", PositionField}
+
+	record := func(a int, opts ...execution.RecordOption) *execution.Record {
+		return execution.NewRecordFromSliceWithNormalize(fields, []interface{}{a}, opts...)
+	}
+	positioned := func(a, position int, opts ...execution.RecordOption) *execution.Record {
+		return execution.NewRecordFromSliceWithNormalize(outputFields, []interface{}{a, position}, opts...)
+	}
+	intPtr := func(i int) *int {
+		return &i
+	}
+
+	tests := []struct {
+		name    string
+		limit   *int
+		offset  *int
+		records []*execution.Record
+		want    []*execution.Record
+		wantErr bool
+	}{
+		{
+			name: "whole result",
+			records: []*execution.Record{
+				record(3),
+				record(1),
+				record(2),
+				record(2),
+				record(1, execution.WithUndo()),
+				record(3, execution.WithUndo()),
+			},
+			want: []*execution.Record{
+				positioned(3, 0),
+				positioned(1, 0),
+				positioned(2, 1),
+				positioned(2, 1),
+				positioned(1, 0, execution.WithUndo()),
+				positioned(3, 2, execution.WithUndo()),
+			},
+		},
+		{
+			name:   "limit and offset",
+			limit:  intPtr(2),
+			offset: intPtr(1),
+			records: []*execution.Record{
+				record(3),
+				record(1),
+				record(2),
+				record(4),
+				record(0),
+				record(2, execution.WithUndo()),
+				record(0, execution.WithUndo()),
+			},
+			want: []*execution.Record{
+				// 3 gets shifted into the result by 1.
+				positioned(3, 0),
+				positioned(2, 0),
+				// 0 shifts 1 into the result, and 3 out of it.
+				positioned(1, 0),
+				positioned(3, 2, execution.WithUndo()),
+				// Retracting 2 shifts 3 back into the result.
+				positioned(2, 1, execution.WithUndo()),
+				positioned(3, 1),
+				// Retracting 0 shifts 1 out of the result, and 4 into it.
+				positioned(1, 0, execution.WithUndo()),
+				positioned(4, 1),
+			},
+		},
+		{
+			name: "retraction of missing record",
+			records: []*execution.Record{
+				record(1),
+				record(2, execution.WithUndo()),
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stateStorage := storage.GetTestStorage(t)
+			tx := stateStorage.BeginTransaction()
+			defer tx.Abort()
+			ctx := storage.InjectStateTransaction(ctx, tx)
+
+			o := NewSortedStreamOutput(
+				execution.GetRawStreamID(),
+				[]execution.Expression{execution.NewVariable("a")},
+				[]execution.OrderDirection{execution.Ascending},
+				tt.limit,
+				tt.offset,
+			)
+
+			var err error
+			for _, rec := range tt.records {
+				if err = o.AddRecord(ctx, tx, 0, rec); err != nil {
+					break
+				}
+			}
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("AddRecord() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if tt.wantErr {
+				return
+			}
+
+			equality := execution.EqualityOfAll(execution.EqualityOfFieldsAndValues, execution.EqualityOfUndo)
+			for i := range tt.want {
+				got, err := o.Next(ctx, tx)
+				if err != nil {
+					t.Fatalf("Next() error = %v", err)
+				}
+				if err := equality(got, tt.want[i]); err != nil {
+					t.Errorf("record %d: got %s, want %s: %v", i, got.Show(), tt.want[i].Show(), err)
+				}
+			}
+
+			if err := o.MarkEndOfStream(ctx, tx); err != nil {
+				t.Fatal(err)
+			}
+			if got, err := o.Next(ctx, tx); err != execution.ErrEndOfStream {
+				t.Errorf("Next() = %v, %v, want end of stream", got, err)
+			}
+		})
+	}
+}
+
+func TestPositionTree(t *testing.T) {
+	stateStorage := storage.GetTestStorage(t)
+	tx := stateStorage.BeginTransaction()
+	defer tx.Abort()
+
+	tree := newPositionTree(tx)
+	rng := rand.New(rand.NewSource(42))
+
+	var keys []string
+	for i := 0; i < 300; i++ {
+		if len(keys) > 0 && rng.Intn(3) == 0 {
+			index := rng.Intn(len(keys))
+			key := keys[index]
+			keys = append(keys[:index], keys[index+1:]...)
+
+			if err := tree.Remove([]byte(key)); err != nil {
+				t.Fatalf("Remove(%s) error = %v", key, err)
+			}
+		} else {
+			key := fmt.Sprintf("%03d", rng.Intn(50))
+			keys = append(keys, key)
+
+			record := execution.NewRecordFromSliceWithNormalize([]octosql.VariableName{"key"}, []interface{}{key})
+			if err := tree.Add([]byte(key), record); err != nil {
+				t.Fatalf("Add(%s) error = %v", key, err)
+			}
+		}
+
+		sorted := append([]string{}, keys...)
+		sort.Strings(sorted)
+
+		for position, key := range sorted {
+			record, ok, err := tree.RecordAt(position)
+			if err != nil {
+				t.Fatalf("RecordAt(%d) error = %v", position, err)
+			}
+			if !ok || record.Value("key").AsString() != key {
+				t.Fatalf("RecordAt(%d) = %v, %v, want %s", position, record, ok, key)
+			}
+
+			before, err := tree.CountBefore([]byte(key))
+			if err != nil {
+				t.Fatalf("CountBefore(%s) error = %v", key, err)
+			}
+			if want := sort.SearchStrings(sorted, key); before != want {
+				t.Fatalf("CountBefore(%s) = %d, want %d", key, before, want)
+			}
+		}
+		if _, ok, err := tree.RecordAt(len(sorted)); err != nil || ok {
+			t.Fatalf("RecordAt(%d) = %v, %v, want no record", len(sorted), ok, err)
+		}
+	}
+}
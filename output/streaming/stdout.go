@@ -26,6 +26,10 @@ func NewStreamPrinter(stateStorage storage.Storage, recordSink execution.Interme
 
 func (sp *StreamPrinter) Run(ctx context.Context) error {
 	for {
+		if err := ctx.Err(); err != nil {
+			return err
+		}
+
 		tx := sp.stateStorage.BeginTransaction()
 		ctx := storage.InjectStateTransaction(ctx, tx)
 
@@ -38,6 +38,12 @@ func ParseUnion(statement *sqlparser.Union) (logical.Node, error) {
 	case sqlparser.UnionDistinctStr, sqlparser.UnionStr:
 		root = logical.NewUnionDistinct(firstNode, secondNode)
 
+	case sqlparser.IntersectStr:
+		root = logical.NewSetOperation(firstNode, secondNode, execution.Intersect)
+
+	case sqlparser.ExceptStr:
+		root = logical.NewSetOperation(firstNode, secondNode, execution.Except)
+
 	default:
 		return nil, errors.Errorf("unsupported union %+v of type %v", statement, statement.Type)
 	}
@@ -50,8 +56,8 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 	var root logical.Node
 	var outputOptions logical.OutputOptions
 
-	if len(statement.From) != 1 {
-		return nil, nil, errors.Errorf("currently only one expression in from supported, got %v", len(statement.From))
+	if len(statement.From) == 0 {
+		return nil, nil, errors.New("currently a from expression is required")
 	}
 
 	root, err = ParseTableExpression(statement.From[0], true)
@@ -59,6 +65,57 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 		return nil, nil, errors.Wrap(err, "couldn't parse from expression")
 	}
 
+	// FROM a, b is a cross join of all the listed table expressions.
+	// Predicates of the WHERE clause comparing the listed tables become the conditions of their joins,
+	// so that equalities between them are used as join keys, instead of filtering the whole cross product.
+	// The statement isn't modified, so the remaining predicates are kept in a separate where expression.
+	var where sqlparser.Expr
+	if statement.Where != nil {
+		where = statement.Where.Expr
+	}
+	var whereConjuncts []sqlparser.Expr
+	if where != nil && len(statement.From) > 1 {
+		whereConjuncts = splitAndExpr(where)
+	}
+	visibleAliases, _ := getTableAliases(statement.From[0])
+
+	for i := 1; i < len(statement.From); i++ {
+		if unnest, ok := statement.From[i].(*sqlparser.UnnestExpr); ok {
+			root, err = ParseUnnest(root, unnest)
+			if err != nil {
+				return nil, nil, errors.Wrapf(err, "couldn't parse from expression with index %d", i)
+			}
+			visibleAliases = append(visibleAliases, parseAlias(unnest.As))
+			continue
+		}
+
+		joined, err := ParseTableExpression(statement.From[i], true)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't parse from expression with index %d", i)
+		}
+
+		joinedAliases, _ := getTableAliases(statement.From[i])
+		var condition []sqlparser.Expr
+		condition, whereConjuncts = takeJoinPredicates(whereConjuncts, visibleAliases, joinedAliases)
+		if len(condition) > 0 {
+			formula, err := ParseLogic(joinAndExpr(condition))
+			if err != nil {
+				return nil, nil, errors.Wrapf(err, "couldn't parse join condition of from expression with index %d", i)
+			}
+			joined = logical.NewFilter(formula, joined)
+		}
+		visibleAliases = append(visibleAliases, joinedAliases...)
+
+		root = logical.NewJoin(root, joined, execution.INNER_JOIN)
+	}
+	if where != nil && len(statement.From) > 1 {
+		if len(whereConjuncts) > 0 {
+			where = joinAndExpr(whereConjuncts)
+		} else {
+			where = nil
+		}
+	}
+
 	// If we get a join we want to parse triggers for it. It is done here, because otherwise passing statement.Triggers
 	// would have to get to like 4 functions, which is a bit of a pain, since the type check here.
 	if joinRoot, ok := root.(*logical.Join); ok {
@@ -86,6 +143,8 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 	}
 
 	// We want to have normal expressions first, star expressions later
+	// The original order is kept, as GROUP BY and ORDER BY may reference select expressions by their position.
+	selectExprs := statement.SelectExprs
 	statement.SelectExprs = append(nonStarExpressions, starExpressions...)
 
 	// A WHERE clause needs to have access to those variables, so this map comes first, keeping the old variables.
@@ -148,8 +207,8 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 		root = logical.NewMap(filteredExpressions, root, true)
 	}
 
-	if statement.Where != nil {
-		filterFormula, err := ParseLogic(statement.Where.Expr)
+	if where != nil {
+		filterFormula, err := ParseLogic(where)
 		if err != nil {
 			return nil, nil, errors.Wrap(err, "couldn't parse where expression")
 		}
@@ -163,6 +222,19 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 	if aggregating {
 		key := make([]logical.Expression, len(statement.GroupBy))
 		for i := range statement.GroupBy {
+			if position, ok := getPosition(statement.GroupBy[i]); ok {
+				index, err := getSelectExpressionIndex(selectExprs, statement.SelectExprs, position)
+				if err != nil {
+					return nil, nil, errors.Wrapf(err, "couldn't get select expression of group key with index %v", i)
+				}
+				if len(aggregates[index]) > 0 {
+					return nil, nil, errors.Errorf("group key with index %v references aggregate at position %d", i, position)
+				}
+				// The select expression has been evaluated by the map before, so its value is available by name.
+				key[i] = logical.NewVariable(expressions[index].Name())
+				continue
+			}
+
 			key[i], err = ParseExpression(statement.GroupBy[i])
 			if err != nil {
 				return nil, nil, errors.Wrapf(err, "couldn't parse group key expression with index %v", i)
@@ -208,19 +280,10 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 		root = logical.NewGroupBy(root, key, fields, aggregates, aggregatesAs, triggers)
 	}
 
-	if statement.OrderBy != nil {
-		orderByExpressions, orderByDirections, err := parseOrderByExpressions(statement.OrderBy)
-		if err != nil {
-			return nil, nil, errors.Wrap(err, "couldn't parse arguments of order by")
-		}
-
-		outputOptions.OrderByDirections = orderByDirections
-		outputOptions.OrderByExpressions = orderByExpressions
-	}
-
 	// Now we only keep the selected variables.
+	var nameExpressions []logical.NamedExpression
 	if len(statement.SelectExprs) >= 1 {
-		nameExpressions := make([]logical.NamedExpression, len(nonStarExpressions))
+		nameExpressions = make([]logical.NamedExpression, len(nonStarExpressions))
 
 		// Handle non star expressions
 		for i := range nonStarExpressions {
@@ -269,6 +332,23 @@ func ParseSelect(statement *sqlparser.Select) (logical.Node, *logical.OutputOpti
 		root = logical.NewMap(nameExpressions, root, false)
 	}
 
+	if statement.OrderBy != nil {
+		// Ordering happens on the output records, so positions reference the names of the output fields.
+		orderByExpressions, orderByDirections, err := parseOrderByExpressions(statement.OrderBy, func(position int) (logical.Expression, error) {
+			index, err := getSelectExpressionIndex(selectExprs, statement.SelectExprs, position)
+			if err != nil {
+				return nil, err
+			}
+			return logical.NewVariable(nameExpressions[index].Name()), nil
+		})
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't parse arguments of order by")
+		}
+
+		outputOptions.OrderByDirections = orderByDirections
+		outputOptions.OrderByExpressions = orderByExpressions
+	}
+
 	if len(statement.Distinct) > 0 {
 		root = logical.NewDistinct(root)
 	}
@@ -310,6 +390,135 @@ func ParseWith(statement *sqlparser.With) (logical.Node, *logical.OutputOptions,
 	return logical.NewWith(names, nodes, source), outputOptions, nil
 }
 
+// ParseQuery parses the given query text into a logical plan with its output options.
+// The query may be preceded by LET or SET statements binding expressions to names, separated by semicolons.
+func ParseQuery(query string) (logical.Node, *logical.OutputOptions, error) {
+	pieces, err := sqlparser.SplitStatementToPieces(query)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't split query into statements")
+	}
+
+	statements := make([]sqlparser.Statement, 0, len(pieces))
+	for _, piece := range pieces {
+		if strings.TrimSpace(piece) == "" {
+			continue
+		}
+
+		stmt, err := sqlparser.Parse(piece)
+		if err != nil {
+			return nil, nil, errors.Wrap(err, "couldn't parse query")
+		}
+		statements = append(statements, stmt)
+	}
+	if len(statements) == 0 {
+		return nil, nil, errors.New("no statement in query")
+	}
+
+	names := make([]octosql.VariableName, 0)
+	expressions := make([]logical.Expression, 0)
+	for i, stmt := range statements[:len(statements)-1] {
+		set, ok := stmt.(*sqlparser.Set)
+		if !ok {
+			return nil, nil, errors.Errorf("only LET and SET statements may precede the query, got %v at index %d", reflect.TypeOf(stmt), i)
+		}
+
+		setNames, setExpressions, err := ParseSet(set)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't parse set statement with index %d", i)
+		}
+		names = append(names, setNames...)
+		expressions = append(expressions, setExpressions...)
+	}
+
+	var root logical.Node
+	var outputOptions *logical.OutputOptions
+
+	switch stmt := statements[len(statements)-1].(type) {
+	case sqlparser.SelectStatement:
+		root, outputOptions, err = ParseNode(stmt)
+	case *sqlparser.CreateTableAs:
+		root, outputOptions, err = ParseCreateTableAs(stmt)
+	case *sqlparser.Explain:
+		root, outputOptions, err = ParseExplain(stmt)
+	case *sqlparser.AnalyzeFile:
+		if len(names) > 0 {
+			return nil, nil, errors.New("LET and SET statements can't precede an ANALYZE statement")
+		}
+		root, outputOptions, err = ParseAnalyzeFile(stmt)
+	default:
+		return nil, nil, errors.Errorf("invalid statement type, wanted sqlparser.SelectStatement, sqlparser.CreateTableAs, sqlparser.Explain or sqlparser.AnalyzeFile got %v", reflect.TypeOf(stmt))
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	if len(names) > 0 {
+		root = logical.NewLet(names, expressions, root)
+	}
+
+	return root, outputOptions, nil
+}
+
+// ParseSet parses the names and expressions bound in a LET or SET statement.
+func ParseSet(statement *sqlparser.Set) ([]octosql.VariableName, []logical.Expression, error) {
+	if statement.Scope != "" {
+		return nil, nil, errors.Errorf("%s variables are unsupported", statement.Scope)
+	}
+
+	names := make([]octosql.VariableName, len(statement.Exprs))
+	expressions := make([]logical.Expression, len(statement.Exprs))
+	for i, setExpr := range statement.Exprs {
+		if setExpr.Name.EqualString(sqlparser.TransactionStr) {
+			return nil, nil, errors.New("transaction characteristics are unsupported")
+		}
+
+		expr, err := ParseExpression(setExpr.Expr)
+		if err != nil {
+			return nil, nil, errors.Wrapf(err, "couldn't parse expression bound to %s", setExpr.Name.String())
+		}
+
+		names[i] = octosql.NewVariableName(setExpr.Name.String())
+		expressions[i] = expr
+	}
+
+	return names, expressions, nil
+}
+
+// ParseCreateTableAs parses the underlying select and makes its output go to the file given in the statement.
+func ParseCreateTableAs(statement *sqlparser.CreateTableAs) (logical.Node, *logical.OutputOptions, error) {
+	if statement.Path == "" {
+		return nil, nil, errors.Errorf("output file path can't be empty")
+	}
+
+	source, outputOptions, err := ParseNode(statement.Select)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't parse underlying select in CREATE TABLE AS statement")
+	}
+	outputOptions.OutputFile = statement.Path
+
+	return source, outputOptions, nil
+}
+
+func ParseExplain(statement *sqlparser.Explain) (logical.Node, *logical.OutputOptions, error) {
+	source, outputOptions, err := ParseNode(statement.Statement)
+	if err != nil {
+		return nil, nil, errors.Wrap(err, "couldn't parse underlying select in EXPLAIN statement")
+	}
+	outputOptions.Explain = true
+	outputOptions.ExplainAnalyze = statement.Analyze
+
+	return source, outputOptions, nil
+}
+
+// ParseAnalyzeFile parses an ANALYZE statement, which has no plan, as the statistics are collected by the app.
+func ParseAnalyzeFile(statement *sqlparser.AnalyzeFile) (logical.Node, *logical.OutputOptions, error) {
+	if statement.Path == "" {
+		return nil, nil, errors.Errorf("analyzed file path can't be empty")
+	}
+
+	return nil, &logical.OutputOptions{AnalyzeFile: statement.Path}, nil
+}
+
 func ParseNode(statement sqlparser.SelectStatement) (logical.Node, *logical.OutputOptions, error) {
 	switch statement := statement.(type) {
 	case *sqlparser.Select:
@@ -340,6 +549,8 @@ func ParseTableExpression(expr sqlparser.TableExpr, mustBeAliased bool) (logical
 		return ParseTableExpression(expr.Exprs[0], mustBeAliased)
 	case *sqlparser.TableValuedFunction:
 		return ParseTableValuedFunction(expr)
+	case *sqlparser.UnnestExpr:
+		return nil, errors.Errorf("unnest has to be joined with the table it's unnesting values of: %v", sqlparser.String(expr))
 	default:
 		return nil, errors.Errorf("invalid table expression %+v of type %v", expr, reflect.TypeOf(expr))
 	}
@@ -351,14 +562,14 @@ func ParseAliasedTableExpression(expr *sqlparser.AliasedTableExpr, mustBeAliased
 		if expr.As.IsEmpty() && mustBeAliased {
 			return nil, errors.Errorf("table \"%v\" must have unique alias", subExpr.Name)
 		}
-		return logical.NewDataSource(subExpr.Name.String(), expr.As.String()), nil
+		return logical.NewDataSource(subExpr.Name.String(), parseAlias(expr.As)), nil
 
 	case *sqlparser.Subquery:
 		subQuery, _, err := ParseNode(subExpr.Select)
 		if err != nil {
 			return nil, errors.Wrap(err, "couldn't parse subquery")
 		}
-		return logical.NewRequalifier(expr.As.String(), subQuery), nil
+		return logical.NewRequalifier(parseAlias(expr.As), subQuery), nil
 
 	default:
 		return nil, errors.Errorf("invalid aliased table expression %+v of type %v", expr.Expr, reflect.TypeOf(expr.Expr))
@@ -370,6 +581,28 @@ func ParseJoinTableExpression(expr *sqlparser.JoinTableExpr) (logical.Node, erro
 	if err != nil {
 		return nil, errors.Wrap(err, "couldn't parse join left table expression")
 	}
+
+	// Joining with UNNEST expands each record of the left table, instead of joining two tables.
+	if unnest, ok := expr.RightExpr.(*sqlparser.UnnestExpr); ok {
+		if expr.Join != sqlparser.JoinStr {
+			return nil, errors.Errorf("unnest can only be used in an inner or cross join, got %v", expr.Join)
+		}
+
+		root, err := ParseUnnest(leftTable, unnest)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse join unnest expression")
+		}
+		if expr.Condition.On != nil {
+			condition, err := ParseLogic(expr.Condition.On)
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't parse ON condition in join")
+			}
+
+			root = logical.NewFilter(condition, root)
+		}
+		return root, nil
+	}
+
 	rightTable, err := ParseTableExpression(expr.RightExpr, true)
 	if err != nil {
 		return nil, errors.Wrap(err, "couldn't parse join right table expression")
@@ -377,7 +610,7 @@ func ParseJoinTableExpression(expr *sqlparser.JoinTableExpr) (logical.Node, erro
 
 	var source, joined logical.Node
 	switch expr.Join {
-	case sqlparser.LeftJoinStr, sqlparser.JoinStr:
+	case sqlparser.LeftJoinStr, sqlparser.JoinStr, sqlparser.FullOuterJoinStr:
 		source = leftTable
 		joined = rightTable
 	case sqlparser.RightJoinStr:
@@ -401,24 +634,51 @@ func ParseJoinTableExpression(expr *sqlparser.JoinTableExpr) (logical.Node, erro
 		return logical.NewJoin(source, joined, execution.LEFT_JOIN), nil
 	case sqlparser.JoinStr:
 		return logical.NewJoin(source, joined, execution.INNER_JOIN), nil
+	case sqlparser.FullOuterJoinStr:
+		return logical.NewJoin(source, joined, execution.OUTER_JOIN), nil
 	default:
 		return nil, errors.Errorf("invalid join expression: %v", expr.Join)
 	}
 }
 
+func ParseUnnest(source logical.Node, expr *sqlparser.UnnestExpr) (logical.Node, error) {
+	if expr.As.IsEmpty() {
+		return nil, errors.Errorf("unnest \"%v\" must have unique alias", sqlparser.String(expr.Expr))
+	}
+
+	unnested, err := ParseExpression(expr.Expr)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't parse unnested expression")
+	}
+
+	return logical.NewUnnest(unnested, parseAlias(expr.As), source), nil
+}
+
 func ParseTableValuedFunction(expr *sqlparser.TableValuedFunction) (logical.Node, error) {
-	name := expr.Name.String()
+	name := strings.ToLower(expr.Name.String())
 	arguments := make(map[octosql.VariableName]logical.TableValuedFunctionArgumentValue)
 	for i := range expr.Args {
 		parsed, err := ParseTableValuedFunctionArgument(expr.Args[i].Value)
 		if err != nil {
 			return nil, errors.Wrapf(err, "couldn't parse table valued function argument \"%v\" with index %v", expr.Args[i].Name.String(), i)
 		}
-		arguments[octosql.NewVariableName(expr.Args[i].Name.String())] = parsed
+
+		argName := octosql.NewVariableName(expr.Args[i].Name.String())
+		if expr.Args[i].Name.IsEmpty() {
+			positional := logical.TableValuedFunctionPositionalArguments[name]
+			if i >= len(positional) {
+				return nil, errors.Errorf("table valued function %v doesn't take a positional argument with index %v", name, i)
+			}
+			if i > 0 && !expr.Args[i-1].Name.IsEmpty() {
+				return nil, errors.Errorf("positional table valued function argument with index %v can't follow a named one", i)
+			}
+			argName = positional[i]
+		}
+		arguments[argName] = parsed
 	}
 
 	return logical.NewRequalifier(
-		expr.As.String(),
+		parseAlias(expr.As),
 		logical.NewTableValuedFunction(name, arguments),
 	), nil
 }
@@ -489,12 +749,46 @@ func ParseAggregate(expr sqlparser.Expr) (logical.Aggregate, logical.NamedExpres
 			)
 		}
 
+		if curAggregate == logical.ApproxPercentile {
+			if parsedArg == nil || len(expr.Exprs) != 2 {
+				return "", nil, errors.Errorf("approx_percentile takes a value and a percentile")
+			}
+			var err error
+			parsedArg, err = parsePercentileArgument(parsedArg, expr.Exprs[1])
+			if err != nil {
+				return "", nil, errors.Wrap(err, "couldn't parse approx_percentile argument")
+			}
+		}
+
 		return curAggregate, parsedArg, nil
 	}
 
 	return "", nil, errors.Wrapf(ErrNotAggregate, "invalid group by select expression type")
 }
 
+// parsePercentileArgument creates the argument of a percentile aggregate, which is a tuple of the value and the percentile,
+// as aggregates take a single argument. It's named after both, so that different percentiles of a value don't collide.
+func parsePercentileArgument(arg logical.NamedExpression, percentileExpr sqlparser.SelectExpr) (logical.NamedExpression, error) {
+	var val *sqlparser.SQLVal
+	if aliased, ok := percentileExpr.(*sqlparser.AliasedExpr); ok {
+		val, _ = aliased.Expr.(*sqlparser.SQLVal)
+	}
+	if val == nil || (val.Type != sqlparser.FloatVal && val.Type != sqlparser.IntVal) {
+		return nil, errors.Errorf("percentile must be a number, got %v", sqlparser.String(percentileExpr))
+	}
+
+	percentile, err := strconv.ParseFloat(string(val.Val), 64)
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't parse percentile %s", val.Val)
+	}
+	if percentile < 0 || percentile > 1 {
+		return nil, errors.Errorf("percentile must be between 0 and 1, got %v", percentile)
+	}
+
+	name := octosql.NewVariableName(fmt.Sprintf("%s_%s", arg.Name(), strings.Replace(string(val.Val), ".", "_", -1)))
+	return logical.NewAliasedExpression(name, logical.NewTuple([]logical.Expression{arg, logical.NewConstant(percentile)})), nil
+}
+
 func ParseTrigger(trigger sqlparser.Trigger) (logical.Trigger, error) {
 	switch trigger := trigger.(type) {
 	case *sqlparser.CountingTrigger:
@@ -512,7 +806,14 @@ func ParseTrigger(trigger sqlparser.Trigger) (logical.Trigger, error) {
 		return logical.NewDelayTrigger(delayExpr), nil
 
 	case *sqlparser.WatermarkTrigger:
-		return logical.NewWatermarkTrigger(), nil
+		if trigger.AllowedLateness == nil {
+			return logical.NewWatermarkTrigger(), nil
+		}
+		allowedLatenessExpr, err := ParseExpression(trigger.AllowedLateness)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse allowed lateness expression")
+		}
+		return logical.NewWatermarkTrigger().WithAllowedLateness(allowedLatenessExpr), nil
 	}
 
 	return nil, errors.Errorf("invalid trigger type: %v", trigger)
@@ -528,13 +829,62 @@ func ParseAliasedExpression(expr *sqlparser.AliasedExpr) (logical.NamedExpressio
 		if named, ok := subExpr.(logical.NamedExpression); ok {
 			return named, nil
 		}
+		// A nested field is named after its whole path.
+		if colName, ok := expr.Expr.(*sqlparser.ColName); ok && len(colName.Path) > 0 {
+			parts := []string{colName.Qualifier.Name.String(), colName.Name.String()}
+			for _, field := range colName.Path {
+				parts = append(parts, field.String())
+			}
+			return logical.NewAliasedExpression(octosql.NewVariableName(strings.Join(parts, ".")), subExpr), nil
+		}
 		return nil, errors.Errorf("expressions in select statement and aggregate expressions must be named")
 	}
 	return logical.NewAliasedExpression(octosql.NewVariableName(expr.As.String()), subExpr), nil
 }
 
 func ParseStarExpression(expr *sqlparser.StarExpr) (logical.NamedExpression, error) {
-	return logical.NewStarExpression(expr.TableName.Name.String()), nil
+	qualifier := parseAlias(expr.TableName.Name)
+	if len(expr.Exclude) == 0 && len(expr.Replace) == 0 {
+		return logical.NewStarExpression(qualifier), nil
+	}
+
+	var exclude []octosql.VariableName
+	for i := range expr.Exclude {
+		colName, ok := expr.Exclude[i].(*sqlparser.ColName)
+		if !ok || len(colName.Path) > 0 {
+			return nil, errors.Errorf("expected column name in star expression exclude list, got %v", sqlparser.String(expr.Exclude[i]))
+		}
+		name := colName.Name.String()
+		if !colName.Qualifier.Name.IsEmpty() {
+			name = fmt.Sprintf("%s.%s", colName.Qualifier.Name.String(), name)
+		}
+		exclude = append(exclude, octosql.NewVariableName(name))
+	}
+
+	var replace []logical.NamedExpression
+	for i := range expr.Replace {
+		aliasedExpression, ok := expr.Replace[i].(*sqlparser.AliasedExpr)
+		if !ok {
+			return nil, errors.Errorf("expected aliased expression in star expression replace list, got %v", sqlparser.String(expr.Replace[i]))
+		}
+		if _, _, err := ParseAggregate(aliasedExpression.Expr); errors.Cause(err) != ErrNotAggregate {
+			return nil, errors.Errorf("aggregates aren't allowed in star expression replace list, got %v", sqlparser.String(aliasedExpression))
+		}
+
+		replacement, err := ParseAliasedExpression(aliasedExpression)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't parse replacement with index %d", i)
+		}
+		replace = append(replace, replacement)
+	}
+
+	return logical.NewStarExpressionWithModifiers(qualifier, exclude, replace), nil
+}
+
+// parseAlias returns the alias of a table. Like variable names, aliases aren't case sensitive,
+// so they're lowercased to match the qualifiers of the variables referencing them.
+func parseAlias(alias sqlparser.TableIdent) string {
+	return strings.ToLower(alias.String())
 }
 
 func ParseFunctionArgument(expr *sqlparser.AliasedExpr) (logical.Expression, error) {
@@ -599,7 +949,16 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 		if !expr.Qualifier.Name.IsEmpty() {
 			name = fmt.Sprintf("%s.%s", expr.Qualifier.Name.String(), name)
 		}
-		return logical.NewVariable(octosql.NewVariableName(name)), nil
+		if len(expr.Path) == 0 {
+			return logical.NewVariable(octosql.NewVariableName(name)), nil
+		}
+
+		// Nested fields are accessed the same way as with an index, so t.col.field is t.col['field'].
+		var out logical.Expression = logical.NewVariable(octosql.NewVariableName(name))
+		for _, field := range expr.Path {
+			out = logical.NewFunctionExpression("element_at", []logical.Expression{out, logical.NewConstant(field.String())})
+		}
+		return out, nil
 
 	case *sqlparser.Subquery:
 		selectExpr, ok := expr.Select.(*sqlparser.Select)
@@ -614,6 +973,11 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 		return logical.NewNodeExpression(subquery), nil
 
 	case *sqlparser.SQLVal:
+		if expr.Type == sqlparser.ValArg {
+			// Parameters get their values only when the query is run, so they're kept as variables.
+			return logical.NewVariable(octosql.NewParameterVariableName(string(expr.Val[1:]))), nil
+		}
+
 		var value interface{}
 		var err error
 		switch expr.Type {
@@ -625,6 +989,8 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 			value, err = strconv.ParseFloat(string(expr.Val), 64)
 		case sqlparser.StrVal:
 			value = string(expr.Val)
+		case sqlparser.HexVal:
+			value, err = expr.HexDecode()
 		default:
 			err = errors.Errorf("constant value type unsupported")
 		}
@@ -654,8 +1020,40 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 		}
 		return logical.NewTuple(expressions), nil
 
-	case *sqlparser.IntervalExpr:
+	case *sqlparser.ArrayExpr:
+		// Unlike a parenthesized tuple, an array with a single element isn't unwrapped.
+		expressions := make([]logical.Expression, len(expr.Exprs))
+		for i := range expr.Exprs {
+			subExpr, err := ParseExpression(expr.Exprs[i])
+			if err != nil {
+				return nil, errors.Wrapf(err, "couldn't parse array element with index %v", i)
+			}
+
+			expressions[i] = subExpr
+		}
+		return logical.NewTuple(expressions), nil
+
+	case *sqlparser.IndexExpr:
 		subExpr, err := ParseExpression(expr.Expr)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse indexed expression")
+		}
+		index, err := ParseExpression(expr.Index)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse index expression")
+		}
+
+		return logical.NewFunctionExpression("element_at", []logical.Expression{subExpr, index}), nil
+
+	case *sqlparser.IntervalExpr:
+		var subExpr logical.Expression
+		var err error
+		if count, ok := expr.Expr.(*sqlparser.SQLVal); ok && count.Type == sqlparser.StrVal {
+			// The count may be quoted, as in INTERVAL '5' MINUTE.
+			subExpr, err = parseIntervalCount(string(count.Val))
+		} else {
+			subExpr, err = ParseExpression(expr.Expr)
+		}
 		if err != nil {
 			return nil, errors.Wrap(err, "couldn't parse expression in interval")
 		}
@@ -665,6 +1063,35 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 			logical.NewConstant(strings.TrimSuffix(strings.ToLower(expr.Unit), "s")),
 		), nil
 
+	case *sqlparser.ConvertExpr:
+		subExpr, err := ParseExpression(expr.Expr)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse expression in cast")
+		}
+
+		functionName, ok := castFunctions[strings.ToLower(expr.Type.Type)]
+		if !ok {
+			return nil, errors.Errorf("unsupported cast to type %v", expr.Type.Type)
+		}
+
+		args := []logical.Expression{subExpr}
+		if functionName == "decimal" && expr.Type.Length != nil {
+			precision, err := strconv.Atoi(string(expr.Type.Length.Val))
+			if err != nil {
+				return nil, errors.Wrap(err, "couldn't parse decimal precision")
+			}
+			scale := 0
+			if expr.Type.Scale != nil {
+				scale, err = strconv.Atoi(string(expr.Type.Scale.Val))
+				if err != nil {
+					return nil, errors.Wrap(err, "couldn't parse decimal scale")
+				}
+			}
+			args = append(args, logical.NewConstant(precision), logical.NewConstant(scale))
+		}
+
+		return logical.NewFunctionExpression(functionName, args), nil
+
 	case *sqlparser.AndExpr:
 		return ParseLogicExpression(expr)
 	case *sqlparser.OrExpr:
@@ -673,6 +1100,12 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 		return ParseLogicExpression(expr)
 	case *sqlparser.ComparisonExpr:
 		return ParseLogicExpression(expr)
+	case *sqlparser.ExistsExpr:
+		return ParseLogicExpression(expr)
+	case *sqlparser.RangeCond:
+		return ParseLogicExpression(expr)
+	case *sqlparser.IsExpr:
+		return ParseLogicExpression(expr)
 	case *sqlparser.ParenExpr:
 		return ParseExpression(expr.Expr)
 
@@ -681,6 +1114,35 @@ func ParseExpression(expr sqlparser.Expr) (logical.Expression, error) {
 	}
 }
 
+func parseIntervalCount(count string) (logical.Expression, error) {
+	if i, err := strconv.Atoi(count); err == nil {
+		return logical.NewConstant(i), nil
+	}
+	f, err := strconv.ParseFloat(count, 64)
+	if err != nil {
+		return nil, errors.Errorf("invalid interval count %v, expected a number", count)
+	}
+	return logical.NewConstant(f), nil
+}
+
+// castFunctions maps the types which can be used in a cast to the functions converting values to them.
+var castFunctions = map[string]string{
+	"signed":   "int",
+	"unsigned": "int",
+	"int":      "int",
+	"integer":  "int",
+	"decimal":  "decimal",
+	"float":    "float",
+	"double":   "float",
+	"real":     "float",
+	"char":     "string",
+	"nchar":    "string",
+	"varchar":  "string",
+	"text":     "string",
+	"bool":     "bool",
+	"boolean":  "bool",
+}
+
 func ParseLogicExpression(expr sqlparser.Expr) (*logical.LogicExpression, error) {
 	formula, err := ParseLogic(expr)
 	if err != nil {
@@ -701,7 +1163,62 @@ func ParseLogic(expr sqlparser.Expr) (logical.Formula, error) {
 	case *sqlparser.NotExpr:
 		return ParsePrefixOperator(expr.Expr, "NOT")
 	case *sqlparser.ComparisonExpr:
+		switch expr.Operator {
+		case sqlparser.NotLikeStr, sqlparser.NotILikeStr, sqlparser.NotRegexpStr:
+			// Negated pattern matching has no relation of its own, so it's the negation of the matching relation.
+			formula, err := ParseInfixComparison(expr.Left, expr.Right, strings.TrimPrefix(expr.Operator, "not "))
+			if err != nil {
+				return nil, err
+			}
+			return logical.NewPrefixOperator(formula, "NOT"), nil
+		}
 		return ParseInfixComparison(expr.Left, expr.Right, expr.Operator)
+	case *sqlparser.ExistsExpr:
+		selectExpr, ok := expr.Subquery.Select.(*sqlparser.Select)
+		if !ok {
+			return nil, errors.Errorf("expected select statement in exists subquery, got %v %v",
+				expr.Subquery.Select, reflect.TypeOf(expr.Subquery.Select))
+		}
+		subquery, _, err := ParseNode(selectExpr)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse exists subquery")
+		}
+		return logical.NewExists(subquery), nil
+	case *sqlparser.RangeCond:
+		left, err := ParseExpression(expr.Left)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse left hand side of between")
+		}
+		from, err := ParseExpression(expr.From)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse lower bound of between")
+		}
+		to, err := ParseExpression(expr.To)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse upper bound of between")
+		}
+		formula := logical.NewInfixOperator(
+			logical.NewPredicate(left, logical.GreaterEqual, from),
+			logical.NewPredicate(left, logical.LessEqual, to),
+			"AND",
+		)
+		if expr.Operator == sqlparser.NotBetweenStr {
+			return logical.NewPrefixOperator(formula, "NOT"), nil
+		}
+		return formula, nil
+	case *sqlparser.IsExpr:
+		child, err := ParseExpression(expr.Expr)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't parse is operand")
+		}
+		switch expr.Operator {
+		case sqlparser.IsNullStr:
+			return logical.NewIsNull(child), nil
+		case sqlparser.IsNotNullStr:
+			return logical.NewPrefixOperator(logical.NewIsNull(child), "NOT"), nil
+		default:
+			return nil, errors.Errorf("unsupported is operator %v", expr.Operator)
+		}
 	case *sqlparser.ParenExpr:
 		return ParseLogic(expr.Expr)
 	default:
@@ -741,23 +1258,172 @@ func ParseInfixComparison(left, right sqlparser.Expr, operator string) (logical.
 	return logical.NewPredicate(leftParsed, logical.NewRelation(operator), rightParsed), nil
 }
 
-func parseOrderByExpressions(orderBy sqlparser.OrderBy) ([]logical.Expression, []logical.OrderDirection, error) {
+// parseOrderByExpressions parses the ORDER BY clause, using the given function to get the expressions of positions, like ORDER BY 1.
+func parseOrderByExpressions(orderBy sqlparser.OrderBy, getPositionExpression func(position int) (logical.Expression, error)) ([]logical.Expression, []logical.OrderDirection, error) {
 	expressions := make([]logical.Expression, len(orderBy))
 	directions := make([]logical.OrderDirection, len(orderBy))
 
 	for i, field := range orderBy {
-		expr, err := ParseExpression(field.Expr)
-		if err != nil {
-			return nil, nil, errors.Errorf("couldn't parse order by expression with index %v", i)
+		if position, ok := getPosition(field.Expr); ok {
+			expr, err := getPositionExpression(position)
+			if err != nil {
+				return nil, nil, errors.Wrapf(err, "couldn't get select expression of order by expression with index %v", i)
+			}
+			expressions[i] = expr
+		} else {
+			expr, err := ParseExpression(field.Expr)
+			if err != nil {
+				return nil, nil, errors.Errorf("couldn't parse order by expression with index %v", i)
+			}
+			expressions[i] = expr
 		}
 
-		expressions[i] = expr
-		directions[i] = logical.OrderDirection(field.Direction)
+		// Nulls are the smallest values, so only the non-default placements need a separate direction.
+		switch {
+		case field.Direction == sqlparser.AscScr && field.Nulls == sqlparser.NullsLastStr:
+			directions[i] = logical.OrderDirection("asc nulls last")
+		case field.Direction == sqlparser.DescScr && field.Nulls == sqlparser.NullsFirstStr:
+			directions[i] = logical.OrderDirection("desc nulls first")
+		default:
+			directions[i] = logical.OrderDirection(field.Direction)
+		}
 	}
 
 	return expressions, directions, nil
 }
 
+// getTableAliases returns the aliases of the tables in the table expression, or false if they aren't all known up front.
+func getTableAliases(expr sqlparser.TableExpr) ([]string, bool) {
+	switch expr := expr.(type) {
+	case *sqlparser.AliasedTableExpr:
+		if expr.As.IsEmpty() {
+			return nil, false
+		}
+		return []string{parseAlias(expr.As)}, true
+
+	case *sqlparser.JoinTableExpr:
+		left, leftOk := getTableAliases(expr.LeftExpr)
+		right, rightOk := getTableAliases(expr.RightExpr)
+		return append(left, right...), leftOk && rightOk
+
+	case *sqlparser.ParenTableExpr:
+		var aliases []string
+		ok := true
+		for i := range expr.Exprs {
+			exprAliases, exprOk := getTableAliases(expr.Exprs[i])
+			aliases = append(aliases, exprAliases...)
+			ok = ok && exprOk
+		}
+		return aliases, ok
+
+	default:
+		return nil, false
+	}
+}
+
+// takeJoinPredicates splits the predicates into the ones comparing the joined tables with the tables joined before,
+// which can be the condition of the join, and the rest. Predicates with unqualified fields, which may reference
+// select expressions, and ones with subqueries, which may get decorrelated from the WHERE clause, are left where they are.
+func takeJoinPredicates(predicates []sqlparser.Expr, sourceAliases, joinedAliases []string) ([]sqlparser.Expr, []sqlparser.Expr) {
+	var condition, rest []sqlparser.Expr
+	for _, predicate := range predicates {
+		qualifiers, ok := getPredicateQualifiers(predicate)
+		usesSource, usesJoined := false, false
+		for qualifier := range qualifiers {
+			switch {
+			case containsString(sourceAliases, qualifier):
+				usesSource = true
+			case containsString(joinedAliases, qualifier):
+				usesJoined = true
+			default:
+				ok = false
+			}
+		}
+
+		if ok && usesSource && usesJoined {
+			condition = append(condition, predicate)
+		} else {
+			rest = append(rest, predicate)
+		}
+	}
+	return condition, rest
+}
+
+// getPredicateQualifiers returns the table aliases of the fields the predicate uses,
+// or false if it uses unqualified fields or subqueries.
+func getPredicateQualifiers(predicate sqlparser.Expr) (map[string]bool, bool) {
+	qualifiers := make(map[string]bool)
+	ok := true
+	_ = sqlparser.Walk(func(node sqlparser.SQLNode) (bool, error) {
+		switch node := node.(type) {
+		case *sqlparser.ColName:
+			if node.Qualifier.IsEmpty() {
+				ok = false
+			} else {
+				qualifiers[parseAlias(node.Qualifier.Name)] = true
+			}
+		case *sqlparser.Subquery, *sqlparser.ExistsExpr:
+			ok = false
+		}
+		return ok, nil
+	}, predicate)
+	return qualifiers, ok
+}
+
+func splitAndExpr(expr sqlparser.Expr) []sqlparser.Expr {
+	if and, ok := expr.(*sqlparser.AndExpr); ok {
+		return append(splitAndExpr(and.Left), splitAndExpr(and.Right)...)
+	}
+	return []sqlparser.Expr{expr}
+}
+
+func joinAndExpr(exprs []sqlparser.Expr) sqlparser.Expr {
+	out := exprs[0]
+	for _, expr := range exprs[1:] {
+		out = &sqlparser.AndExpr{Left: out, Right: expr}
+	}
+	return out
+}
+
+func containsString(strs []string, str string) bool {
+	for i := range strs {
+		if strs[i] == str {
+			return true
+		}
+	}
+	return false
+}
+
+// getPosition returns the position of a select expression referenced by an integer literal in GROUP BY or ORDER BY.
+func getPosition(expr sqlparser.Expr) (int, bool) {
+	val, ok := expr.(*sqlparser.SQLVal)
+	if !ok || val.Type != sqlparser.IntVal {
+		return 0, false
+	}
+	position, err := strconv.Atoi(string(val.Val))
+	if err != nil {
+		return 0, false
+	}
+	return position, true
+}
+
+// getSelectExpressionIndex returns the index of the select expression at the given position, counting from 1,
+// after the star expressions have been moved to the end.
+func getSelectExpressionIndex(original, reordered []sqlparser.SelectExpr, position int) (int, error) {
+	if position < 1 || position > len(original) {
+		return 0, errors.Errorf("position %d is out of range of the %d select expressions", position, len(original))
+	}
+	if _, ok := original[position-1].(*sqlparser.StarExpr); ok {
+		return 0, errors.Errorf("position %d references a star expression", position)
+	}
+	for i := range reordered {
+		if reordered[i] == original[position-1] {
+			return i, nil
+		}
+	}
+	panic("unreachable")
+}
+
 func parseTwoSubexpressions(limit, offset sqlparser.Expr) (logical.Expression, logical.Expression, error) {
 	/* 	to be strict neither LIMIT nor OFFSET is in SQL standard...
 	*	parser doesn't support OFFSET clause without LIMIT clause - Google BigQuery syntax
@@ -104,6 +104,38 @@ func TestParseNode(t *testing.T) {
 			),
 			wantErr: false,
 		},
+		{
+			name: "star expression with exclude and replace",
+			args: args{
+				statement: `SELECT d.* EXCLUDE (age, d.owner) REPLACE (upper(d.name) AS name) FROM dogs d`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpressionWithModifiers(
+						"d",
+						[]octosql.VariableName{"age", "d.owner"},
+						[]logical.NamedExpression{
+							logical.NewAliasedExpression("name", logical.NewFunctionExpression("upper", []logical.Expression{logical.NewVariable("d.name")})),
+						},
+					),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{},
+					logical.NewDataSource("dogs", "d"),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "star expression replacing with an aggregate",
+			args: args{
+				statement: `SELECT * REPLACE (count(d.name) AS name) FROM dogs d`,
+			},
+			want:    nil,
+			wantErr: true,
+		},
 		{
 			name: "simple union",
 			args: args{
@@ -288,6 +320,201 @@ func TestParseNode(t *testing.T) {
 			),
 			wantErr: false,
 		},
+		{
+			name: "where not exists",
+			args: args{
+				"SELECT p.name FROM people p WHERE NOT EXISTS (SELECT * FROM cities c WHERE c.name = p.city)",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+				},
+				logical.NewFilter(
+					logical.NewPrefixOperator(
+						logical.NewExists(
+							logical.NewMap(
+								[]logical.NamedExpression{
+									logical.NewStarExpression(""),
+								},
+								logical.NewFilter(
+									logical.NewPredicate(
+										logical.NewVariable("c.name"),
+										logical.Equal,
+										logical.NewVariable("p.city"),
+									),
+									logical.NewMap(
+										[]logical.NamedExpression{},
+										logical.NewDataSource("cities", "c"),
+										true,
+									),
+								),
+								false,
+							),
+						),
+						"NOT",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.name"),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "where not ilike and regexp operator",
+			args: args{
+				"SELECT p.name FROM people p WHERE p.name NOT ILIKE 'a%' AND p.city ~ '^W'",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewPrefixOperator(
+							logical.NewPredicate(
+								logical.NewVariable("p.name"),
+								logical.ILike,
+								logical.NewConstant("a%"),
+							),
+							"NOT",
+						),
+						logical.NewPredicate(
+							logical.NewVariable("p.city"),
+							logical.Regexp,
+							logical.NewConstant("^W"),
+						),
+						"AND",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.name"),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "simple intersect",
+			args: args{
+				`(SELECT p2.name, p2.age FROM people p2 WHERE p2.age > 3)
+					INTERSECT
+					(SELECT p2.name, p2.age FROM people p2 WHERE p2.age > 4)`,
+			},
+			want: logical.NewSetOperation(
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p2.name"),
+						logical.NewVariable("p2.age"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("p2.age"),
+							logical.MoreThan,
+							logical.NewConstant(3),
+						),
+						logical.NewMap(
+							[]logical.NamedExpression{
+								logical.NewVariable("p2.name"),
+								logical.NewVariable("p2.age"),
+							},
+							logical.NewDataSource("people", "p2"),
+							true,
+						),
+					),
+					false,
+				),
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p2.name"),
+						logical.NewVariable("p2.age"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("p2.age"),
+							logical.MoreThan,
+							logical.NewConstant(4),
+						),
+						logical.NewMap(
+							[]logical.NamedExpression{
+								logical.NewVariable("p2.name"),
+								logical.NewVariable("p2.age"),
+							},
+							logical.NewDataSource("people", "p2"),
+							true,
+						),
+					),
+					false,
+				),
+				execution.Intersect,
+			),
+			wantErr: false,
+		},
+		{
+			name: "simple except",
+			args: args{
+				`(SELECT p2.name, p2.age FROM people p2 WHERE p2.age > 3)
+					EXCEPT
+					(SELECT p2.name, p2.age FROM people p2 WHERE p2.age > 4)`,
+			},
+			want: logical.NewSetOperation(
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p2.name"),
+						logical.NewVariable("p2.age"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("p2.age"),
+							logical.MoreThan,
+							logical.NewConstant(3),
+						),
+						logical.NewMap(
+							[]logical.NamedExpression{
+								logical.NewVariable("p2.name"),
+								logical.NewVariable("p2.age"),
+							},
+							logical.NewDataSource("people", "p2"),
+							true,
+						),
+					),
+					false,
+				),
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p2.name"),
+						logical.NewVariable("p2.age"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("p2.age"),
+							logical.MoreThan,
+							logical.NewConstant(4),
+						),
+						logical.NewMap(
+							[]logical.NamedExpression{
+								logical.NewVariable("p2.name"),
+								logical.NewVariable("p2.age"),
+							},
+							logical.NewDataSource("people", "p2"),
+							true,
+						),
+					),
+					false,
+				),
+				execution.Except,
+			),
+			wantErr: false,
+		},
 		{
 			name: "complex union all",
 			args: args{
@@ -421,9 +648,9 @@ func TestParseNode(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "all operators",
+			name: "cast",
 			args: args{
-				statement: "SELECT * FROM people p2 WHERE TRUE AND FALSE OR TRUE AND NOT TRUE",
+				statement: "SELECT * FROM people p2 WHERE CAST(p2.age AS FLOAT) > 3.5 AND CAST(p2.name AS TEXT) = 'Kowalski'",
 			},
 			want: logical.NewMap(
 				[]logical.NamedExpression{
@@ -431,17 +658,17 @@ func TestParseNode(t *testing.T) {
 				},
 				logical.NewFilter(
 					logical.NewInfixOperator(
-						logical.NewInfixOperator(
-							logical.NewBooleanConstant(true),
-							logical.NewBooleanConstant(false),
-							"AND",
+						logical.NewPredicate(
+							logical.NewFunctionExpression("float", []logical.Expression{logical.NewVariable("p2.age")}),
+							logical.MoreThan,
+							logical.NewConstant(3.5),
 						),
-						logical.NewInfixOperator(
-							logical.NewBooleanConstant(true),
-							logical.NewPrefixOperator(logical.NewBooleanConstant(true), "NOT"),
-							"AND",
+						logical.NewPredicate(
+							logical.NewFunctionExpression("string", []logical.Expression{logical.NewVariable("p2.name")}),
+							logical.Equal,
+							logical.NewConstant("Kowalski"),
 						),
-						"OR",
+						"AND",
 					),
 					logical.NewMap(
 						[]logical.NamedExpression{},
@@ -454,58 +681,435 @@ func TestParseNode(t *testing.T) {
 			wantErr: false,
 		},
 		{
-			name: "all relations",
+			name: "cast to decimal",
 			args: args{
-				statement: `SELECT * FROM people p2 
-							WHERE 	p2.age > 3 AND 
-									p2.age = 3 AND 
-									p2.age < 3 AND 
-									p2.age <> 3 AND 
-									p2.age != 3 AND 
-									p2.age IN (SELECT * FROM people p3)`,
+				statement: "SELECT * FROM accounts a WHERE CAST(a.balance AS DECIMAL(10, 2)) > CAST(a.debt AS DECIMAL)",
 			},
 			want: logical.NewMap(
 				[]logical.NamedExpression{
 					logical.NewStarExpression(""),
 				},
 				logical.NewFilter(
-					logical.NewInfixOperator(
-						logical.NewInfixOperator(
-							logical.NewInfixOperator(
-								logical.NewInfixOperator(
-									logical.NewInfixOperator(
-										logical.NewPredicate(
-											logical.NewVariable("p2.age"),
-											logical.MoreThan,
-											logical.NewConstant(3),
-										),
-										logical.NewPredicate(
-											logical.NewVariable("p2.age"),
-											logical.Equal,
-											logical.NewConstant(3),
-										),
-										"AND",
-									),
-									logical.NewPredicate(
-										logical.NewVariable("p2.age"),
-										logical.LessThan,
-										logical.NewConstant(3),
-									),
-									"AND",
-								),
-								logical.NewPredicate(
-									logical.NewVariable("p2.age"),
-									logical.NotEqual,
-									logical.NewConstant(3),
-								),
-								"AND",
-							),
-							logical.NewPredicate(
-								logical.NewVariable("p2.age"),
-								logical.NotEqual,
-								logical.NewConstant(3),
-							),
-							"AND",
+					logical.NewPredicate(
+						logical.NewFunctionExpression("decimal", []logical.Expression{
+							logical.NewVariable("a.balance"),
+							logical.NewConstant(10),
+							logical.NewConstant(2),
+						}),
+						logical.MoreThan,
+						logical.NewFunctionExpression("decimal", []logical.Expression{logical.NewVariable("a.debt")}),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("accounts", "a"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "hex literal",
+			args: args{
+				statement: "SELECT * FROM files f WHERE md5(f.content) = md5(x'DEADBEEF')",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewFunctionExpression("md5", []logical.Expression{logical.NewVariable("f.content")}),
+						logical.Equal,
+						logical.NewFunctionExpression("md5", []logical.Expression{
+							logical.NewConstant([]byte{0xde, 0xad, 0xbe, 0xef}),
+						}),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("files", "f"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "extract at time zone",
+			args: args{
+				statement: "SELECT * FROM events e WHERE EXTRACT(HOUR FROM e.time AT TIME ZONE 'Europe/Warsaw') > 8",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewFunctionExpression("date_part", []logical.Expression{
+							logical.NewConstant("hour"),
+							logical.NewFunctionExpression("at_time_zone", []logical.Expression{
+								logical.NewVariable("e.time"),
+								logical.NewConstant("Europe/Warsaw"),
+							}),
+						}),
+						logical.MoreThan,
+						logical.NewConstant(8),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("events", "e"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "is null",
+			args: args{
+				statement: "SELECT * FROM people p2 WHERE p2.age IS NULL OR p2.name IS NOT NULL",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewIsNull(logical.NewVariable("p2.age")),
+						logical.NewPrefixOperator(logical.NewIsNull(logical.NewVariable("p2.name")), "NOT"),
+						"OR",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("people", "p2"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "between with interval",
+			args: args{
+				statement: "SELECT * FROM events e WHERE e.time BETWEEN e.start AND e.start + INTERVAL '5' MINUTE",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewPredicate(
+							logical.NewVariable("e.time"),
+							logical.GreaterEqual,
+							logical.NewVariable("e.start"),
+						),
+						logical.NewPredicate(
+							logical.NewVariable("e.time"),
+							logical.LessEqual,
+							logical.NewFunctionExpression("+", []logical.Expression{
+								logical.NewVariable("e.start"),
+								logical.NewInterval(logical.NewConstant(5), logical.NewConstant("minute")),
+							}),
+						),
+						"AND",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("events", "e"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "nested field access",
+			args: args{
+				statement: "SELECT e.payload.user.id FROM events e WHERE e.payload.kind = 'login'",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("e.payload.user.id"),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewFunctionExpression("element_at", []logical.Expression{
+							logical.NewVariable("e.payload"),
+							logical.NewConstant("kind"),
+						}),
+						logical.Equal,
+						logical.NewConstant("login"),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewAliasedExpression(
+								"e.payload.user.id",
+								logical.NewFunctionExpression("element_at", []logical.Expression{
+									logical.NewFunctionExpression("element_at", []logical.Expression{
+										logical.NewVariable("e.payload"),
+										logical.NewConstant("user"),
+									}),
+									logical.NewConstant("id"),
+								}),
+							),
+						},
+						logical.NewDataSource("events", "e"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "json extract operator",
+			args: args{
+				statement: "SELECT * FROM events e WHERE e.payload ->> '$.kind' = 'login'",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewFunctionExpression("->>", []logical.Expression{
+							logical.NewVariable("e.payload"),
+							logical.NewConstant("$.kind"),
+						}),
+						logical.Equal,
+						logical.NewConstant("login"),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("events", "e"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "positional table valued function arguments",
+			args: args{
+				statement: "SELECT * FROM range(1, 10) r",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{},
+					logical.NewRequalifier(
+						"r",
+						logical.NewTableValuedFunction(
+							"range",
+							map[octosql.VariableName]logical.TableValuedFunctionArgumentValue{
+								"range_start": logical.NewTableValuedFunctionArgumentValueExpression(logical.NewConstant(1)),
+								"range_end":   logical.NewTableValuedFunctionArgumentValueExpression(logical.NewConstant(10)),
+							},
+						),
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "positional tumble arguments",
+			args: args{
+				statement: "SELECT * FROM tumble(TABLE(events), DESCRIPTOR(e.time), INTERVAL '1' MINUTE) w",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{},
+					logical.NewRequalifier(
+						"w",
+						logical.NewTableValuedFunction(
+							"tumble",
+							map[octosql.VariableName]logical.TableValuedFunctionArgumentValue{
+								"source":     logical.NewTableValuedFunctionArgumentValueTable(logical.NewDataSource("events", "")),
+								"time_field": logical.NewTableValuedFunctionArgumentValueDescriptor("e.time"),
+								"window_length": logical.NewTableValuedFunctionArgumentValueExpression(logical.NewInterval(
+									logical.NewConstant(1),
+									logical.NewConstant("minute"),
+								)),
+							},
+						),
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "session window",
+			args: args{
+				statement: "SELECT * FROM SESSION(TABLE(clicks), DESCRIPTOR(c.time), INTERVAL '30' MINUTE, DESCRIPTOR(c.user)) c",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{},
+					logical.NewRequalifier(
+						"c",
+						logical.NewTableValuedFunction(
+							"session",
+							map[octosql.VariableName]logical.TableValuedFunctionArgumentValue{
+								"source":     logical.NewTableValuedFunctionArgumentValueTable(logical.NewDataSource("clicks", "")),
+								"time_field": logical.NewTableValuedFunctionArgumentValueDescriptor("c.time"),
+								"gap": logical.NewTableValuedFunctionArgumentValueExpression(logical.NewInterval(
+									logical.NewConstant(30),
+									logical.NewConstant("minute"),
+								)),
+								"key": logical.NewTableValuedFunctionArgumentValueDescriptor("c.user"),
+							},
+						),
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "too many positional table valued function arguments",
+			args: args{
+				statement: "SELECT * FROM range(1, 10, 2) r",
+			},
+			wantErr: true,
+		},
+		{
+			name: "array index and constructor",
+			args: args{
+				statement: "SELECT * FROM events e WHERE e.tags[1] = array_length(ARRAY[e.name])",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewFunctionExpression("element_at", []logical.Expression{
+							logical.NewVariable("e.tags"),
+							logical.NewConstant(1),
+						}),
+						logical.Equal,
+						logical.NewFunctionExpression("array_length", []logical.Expression{
+							logical.NewTuple([]logical.Expression{
+								logical.NewVariable("e.name"),
+							}),
+						}),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("events", "e"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "all operators",
+			args: args{
+				statement: "SELECT * FROM people p2 WHERE TRUE AND FALSE OR TRUE AND NOT TRUE",
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewInfixOperator(
+							logical.NewBooleanConstant(true),
+							logical.NewBooleanConstant(false),
+							"AND",
+						),
+						logical.NewInfixOperator(
+							logical.NewBooleanConstant(true),
+							logical.NewPrefixOperator(logical.NewBooleanConstant(true), "NOT"),
+							"AND",
+						),
+						"OR",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{},
+						logical.NewDataSource("people", "p2"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "all relations",
+			args: args{
+				statement: `SELECT * FROM people p2 
+							WHERE 	p2.age > 3 AND 
+									p2.age = 3 AND 
+									p2.age < 3 AND 
+									p2.age <> 3 AND 
+									p2.age != 3 AND 
+									p2.age IN (SELECT * FROM people p3)`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewStarExpression(""),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewInfixOperator(
+							logical.NewInfixOperator(
+								logical.NewInfixOperator(
+									logical.NewInfixOperator(
+										logical.NewPredicate(
+											logical.NewVariable("p2.age"),
+											logical.MoreThan,
+											logical.NewConstant(3),
+										),
+										logical.NewPredicate(
+											logical.NewVariable("p2.age"),
+											logical.Equal,
+											logical.NewConstant(3),
+										),
+										"AND",
+									),
+									logical.NewPredicate(
+										logical.NewVariable("p2.age"),
+										logical.LessThan,
+										logical.NewConstant(3),
+									),
+									"AND",
+								),
+								logical.NewPredicate(
+									logical.NewVariable("p2.age"),
+									logical.NotEqual,
+									logical.NewConstant(3),
+								),
+								"AND",
+							),
+							logical.NewPredicate(
+								logical.NewVariable("p2.age"),
+								logical.NotEqual,
+								logical.NewConstant(3),
+							),
+							"AND",
 						),
 						logical.NewPredicate(
 							logical.NewVariable("p2.age"),
@@ -674,6 +1278,47 @@ SELECT p.name FROM people p LEFT JOIN cities c ON p.city = c.name AND p.favorite
 			),
 			wantErr: false,
 		},
+		{
+			name: "cross join unnest",
+			args: args{
+				statement: `
+SELECT e.id, t.element FROM events e CROSS JOIN UNNEST(e.tags) t ON t.element <> 'test'`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("e.id"),
+					logical.NewVariable("t.element"),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("e.id"),
+						logical.NewVariable("t.element"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("t.element"),
+							logical.NotEqual,
+							logical.NewConstant("test"),
+						),
+						logical.NewUnnest(
+							logical.NewVariable("e.tags"),
+							"t",
+							logical.NewDataSource("events", "e"),
+						),
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "unnest without table",
+			args: args{
+				statement: `SELECT t.element FROM UNNEST(ARRAY[1, 2]) t`,
+			},
+			wantErr: true,
+		},
 		{
 			name: "right join 1",
 			args: args{
@@ -714,6 +1359,102 @@ SELECT p.name FROM cities c RIGHT JOIN people p ON p.city = c.name AND p.favorit
 			),
 			wantErr: false,
 		},
+		{
+			name: "comma join",
+			args: args{
+				statement: `
+SELECT p.name FROM people p, cities c`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p.name"),
+					},
+					logical.NewJoin(
+						logical.NewDataSource("people", "p"),
+						logical.NewDataSource("cities", "c"),
+						execution.INNER_JOIN,
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "comma join with where",
+			args: args{
+				statement: `
+SELECT p.name FROM people p, cities c WHERE p.city = c.name AND p.age > 3`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+				},
+				logical.NewFilter(
+					logical.NewPredicate(
+						logical.NewVariable("p.age"),
+						logical.MoreThan,
+						logical.NewConstant(3),
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.name"),
+						},
+						logical.NewJoin(
+							logical.NewDataSource("people", "p"),
+							logical.NewFilter(
+								logical.NewPredicate(
+									logical.NewVariable("p.city"),
+									logical.Equal,
+									logical.NewVariable("c.name"),
+								),
+								logical.NewDataSource("cities", "c"),
+							),
+							execution.INNER_JOIN,
+						),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "full outer join",
+			args: args{
+				statement: `
+SELECT p.name FROM people p FULL OUTER JOIN cities c ON p.city = c.name`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name"),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("p.name"),
+					},
+					logical.NewJoin(
+						logical.NewDataSource("people", "p"),
+						logical.NewFilter(
+							logical.NewPredicate(
+								logical.NewVariable("p.city"),
+								logical.Equal,
+								logical.NewVariable("c.name"),
+							),
+							logical.NewDataSource("cities", "c"),
+						),
+						execution.OUTER_JOIN,
+					),
+					true,
+				),
+				false,
+			),
+			wantErr: false,
+		},
 		{
 			name: "select IN",
 			args: args{
@@ -793,52 +1534,168 @@ SELECT p.name FROM cities c RIGHT JOIN people p ON p.city = c.name AND p.favorit
 			),
 			wantErr: false,
 		},
+		{
+			name: "approx percentile",
+			args: args{
+				statement: `SELECT p.city, APPROX_PERCENTILE(p.age, 0.9) FROM people p GROUP BY p.city`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.city"),
+					logical.NewVariable("p.age_0_9_approx_percentile"),
+				},
+				logical.NewGroupBy(
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.city"),
+							logical.NewAliasedExpression(
+								"p.age_0_9",
+								logical.NewTuple([]logical.Expression{
+									logical.NewVariable("p.age"),
+									logical.NewConstant(0.9),
+								}),
+							),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+					[]logical.Expression{
+						logical.NewVariable("p.city"),
+					},
+					[]octosql.VariableName{"p.city", "p.age_0_9"},
+					[]logical.Aggregate{logical.Key, logical.ApproxPercentile},
+					[]octosql.VariableName{"p.city", ""},
+					[]logical.Trigger{},
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "approx percentile out of range",
+			args: args{
+				statement: `SELECT APPROX_PERCENTILE(p.age, 2) FROM people p`,
+			},
+			want:    nil,
+			wantErr: true,
+		},
 		{
 			name: "normal group by",
 			args: args{
-				statement: `SELECT COUNT(DISTINCT p.name), FIRST(p.age as myage) as firstage, p.surname, p.surname as mysurname FROM people p GROUP BY p.age, p.city TRIGGER AFTER DELAY INTERVAL 3 SECONDS, ON WATERMARK, COUNTING 5`,
+				statement: `SELECT COUNT(DISTINCT p.name), FIRST(p.age as myage) as firstage, p.surname, p.surname as mysurname FROM people p GROUP BY p.age, p.city TRIGGER AFTER DELAY INTERVAL 3 SECONDS, ON WATERMARK, COUNTING 5`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.name_count_distinct"),
+					logical.NewVariable("firstage"),
+					logical.NewVariable("p.surname"),
+					logical.NewVariable("mysurname"),
+				},
+				logical.NewGroupBy(
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.name"),
+							logical.NewAliasedExpression(
+								"myage",
+								logical.NewVariable("p.age"),
+							),
+							logical.NewVariable("p.surname"),
+							logical.NewAliasedExpression(
+								"mysurname",
+								logical.NewVariable("p.surname"),
+							),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+					[]logical.Expression{
+						logical.NewVariable("p.age"),
+						logical.NewVariable("p.city"),
+					},
+					[]octosql.VariableName{"p.name", "myage", "p.surname", "mysurname"},
+					[]logical.Aggregate{logical.CountDistinct, logical.First, logical.First, logical.First},
+					[]octosql.VariableName{"", "firstage", "p.surname", "mysurname"},
+					[]logical.Trigger{
+						logical.NewDelayTrigger(logical.NewConstant(time.Second * 3)),
+						logical.NewWatermarkTrigger(),
+						logical.NewCountingTrigger(logical.NewConstant(5)),
+					},
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "watermark trigger with allowed lateness",
+			args: args{
+				statement: `SELECT p.city FROM people p GROUP BY p.city TRIGGER ON WATERMARK ALLOWED LATENESS INTERVAL 1 MINUTE`,
+			},
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("p.city"),
+				},
+				logical.NewGroupBy(
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("p.city"),
+						},
+						logical.NewDataSource("people", "p"),
+						true,
+					),
+					[]logical.Expression{
+						logical.NewVariable("p.city"),
+					},
+					[]octosql.VariableName{"p.city"},
+					[]logical.Aggregate{logical.First},
+					[]octosql.VariableName{"p.city"},
+					[]logical.Trigger{
+						logical.NewWatermarkTrigger().WithAllowedLateness(logical.NewInterval(
+							logical.NewConstant(1),
+							logical.NewConstant("minute"),
+						)),
+					},
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name: "group by position",
+			args: args{
+				statement: `SELECT p.city FROM people p GROUP BY 1`,
 			},
 			want: logical.NewMap(
 				[]logical.NamedExpression{
-					logical.NewVariable("p.name_count_distinct"),
-					logical.NewVariable("firstage"),
-					logical.NewVariable("p.surname"),
-					logical.NewVariable("mysurname"),
+					logical.NewVariable("p.city"),
 				},
 				logical.NewGroupBy(
 					logical.NewMap(
 						[]logical.NamedExpression{
-							logical.NewVariable("p.name"),
-							logical.NewAliasedExpression(
-								"myage",
-								logical.NewVariable("p.age"),
-							),
-							logical.NewVariable("p.surname"),
-							logical.NewAliasedExpression(
-								"mysurname",
-								logical.NewVariable("p.surname"),
-							),
+							logical.NewVariable("p.city"),
 						},
 						logical.NewDataSource("people", "p"),
 						true,
 					),
 					[]logical.Expression{
-						logical.NewVariable("p.age"),
 						logical.NewVariable("p.city"),
 					},
-					[]octosql.VariableName{"p.name", "myage", "p.surname", "mysurname"},
-					[]logical.Aggregate{logical.CountDistinct, logical.First, logical.First, logical.First},
-					[]octosql.VariableName{"", "firstage", "p.surname", "mysurname"},
-					[]logical.Trigger{
-						logical.NewDelayTrigger(logical.NewConstant(time.Second * 3)),
-						logical.NewWatermarkTrigger(),
-						logical.NewCountingTrigger(logical.NewConstant(5)),
-					},
+					[]octosql.VariableName{"p.city"},
+					[]logical.Aggregate{logical.First},
+					[]octosql.VariableName{"p.city"},
+					[]logical.Trigger{},
 				),
 				false,
 			),
 			wantErr: false,
 		},
+		{
+			name: "group by position of star expression",
+			args: args{
+				statement: `SELECT * FROM people p GROUP BY 1`,
+			},
+			want:    nil,
+			wantErr: true,
+		},
 		{
 			name: "table valued function",
 			args: args{
@@ -964,3 +1821,376 @@ SELECT p.name FROM cities c RIGHT JOIN people p ON p.city = c.name AND p.favorit
 		})
 	}
 }
+
+func TestParseNode_DoesntModifyStatement(t *testing.T) {
+	stmt, err := sqlparser.Parse("SELECT p.name FROM people p, cities c WHERE p.city = c.name AND p.age > 3")
+	if err != nil {
+		t.Fatal(err)
+	}
+	before := sqlparser.String(stmt)
+
+	if _, _, err := ParseNode(stmt.(sqlparser.SelectStatement)); err != nil {
+		t.Fatal(err)
+	}
+	if after := sqlparser.String(stmt); after != before {
+		t.Errorf("statement after ParseNode() = %s, want %s", after, before)
+	}
+}
+
+func TestParseNode_OrderByPositions(t *testing.T) {
+	tests := []struct {
+		name      string
+		statement string
+		want      []logical.Expression
+		wantErr   bool
+	}{
+		{
+			name:      "positions and aliases",
+			statement: `SELECT p.age + 1 AS older, p.name FROM people p ORDER BY 2, older DESC`,
+			want: []logical.Expression{
+				logical.NewVariable("p.name"),
+				logical.NewVariable("older"),
+			},
+		},
+		{
+			name:      "position with star expressions moved to the end",
+			statement: `SELECT p.*, c.name FROM people p JOIN cities c ON p.city = c.name ORDER BY 2`,
+			want: []logical.Expression{
+				logical.NewVariable("c.name"),
+			},
+		},
+		{
+			name:      "position of aggregate",
+			statement: `SELECT p.city, COUNT(*) AS n FROM people p GROUP BY p.city ORDER BY 2`,
+			want: []logical.Expression{
+				logical.NewVariable("n"),
+			},
+		},
+		{
+			name:      "position out of range",
+			statement: `SELECT p.name FROM people p ORDER BY 2`,
+			wantErr:   true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stmt, err := sqlparser.Parse(tt.statement)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			_, outputOptions, err := ParseNode(stmt.(sqlparser.SelectStatement))
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseNode() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				return
+			}
+
+			if len(outputOptions.OrderByExpressions) != len(tt.want) {
+				t.Fatalf("ParseNode() order by expressions = %v, want %v", outputOptions.OrderByExpressions, tt.want)
+			}
+			for i := range tt.want {
+				if err := logical.EqualExpressions(outputOptions.OrderByExpressions[i], tt.want[i]); err != nil {
+					t.Errorf("ParseNode() order by expression with index %d not equal: %v", i, err)
+				}
+			}
+		})
+	}
+}
+
+func TestParseCreateTableAs(t *testing.T) {
+	tests := []struct {
+		name           string
+		statement      string
+		want           logical.Node
+		wantOutputFile string
+		wantErr        bool
+	}{
+		{
+			name:      "simple create table as",
+			statement: `CREATE TABLE 'out.csv' AS SELECT a.name FROM anacondas a`,
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("a.name"),
+				},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("a.name"),
+					},
+					logical.NewDataSource("anacondas", "a"),
+					true,
+				),
+				false,
+			),
+			wantOutputFile: "out.csv",
+			wantErr:        false,
+		},
+		{
+			name:      "empty path",
+			statement: `CREATE TABLE '' AS SELECT a.name FROM anacondas a`,
+			wantErr:   true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stmt, err := sqlparser.Parse(tt.statement)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			statement := stmt.(*sqlparser.CreateTableAs)
+
+			got, outputOptions, err := ParseCreateTableAs(statement)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("ParseCreateTableAs() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+
+			if err := logical.EqualNodes(got, tt.want); err != nil {
+				t.Errorf("ParseCreateTableAs() = %v, want %v: %v", got, tt.want, err)
+			}
+			if outputOptions.OutputFile != tt.wantOutputFile {
+				t.Errorf("ParseCreateTableAs() output file = %v, want %v", outputOptions.OutputFile, tt.wantOutputFile)
+			}
+		})
+	}
+}
+
+func TestParseExplain(t *testing.T) {
+	want := logical.NewMap(
+		[]logical.NamedExpression{
+			logical.NewVariable("a.name"),
+		},
+		logical.NewMap(
+			[]logical.NamedExpression{
+				logical.NewVariable("a.name"),
+			},
+			logical.NewDataSource("anacondas", "a"),
+			true,
+		),
+		false,
+	)
+
+	tests := []struct {
+		name        string
+		statement   string
+		want        logical.Node
+		wantAnalyze bool
+	}{
+		{
+			name:        "explain",
+			statement:   `EXPLAIN SELECT a.name FROM anacondas a`,
+			want:        want,
+			wantAnalyze: false,
+		},
+		{
+			name:        "explain analyze",
+			statement:   `EXPLAIN ANALYZE SELECT a.name FROM anacondas a`,
+			want:        want,
+			wantAnalyze: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			stmt, err := sqlparser.Parse(tt.statement)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			statement := stmt.(*sqlparser.Explain)
+
+			got, outputOptions, err := ParseExplain(statement)
+			if err != nil {
+				t.Errorf("ParseExplain() error = %v", err)
+				return
+			}
+
+			if err := logical.EqualNodes(got, tt.want); err != nil {
+				t.Errorf("ParseExplain() = %v, want %v: %v", got, tt.want, err)
+			}
+			if !outputOptions.Explain {
+				t.Errorf("ParseExplain() explain = %v, want %v", outputOptions.Explain, true)
+			}
+			if outputOptions.ExplainAnalyze != tt.wantAnalyze {
+				t.Errorf("ParseExplain() explain analyze = %v, want %v", outputOptions.ExplainAnalyze, tt.wantAnalyze)
+			}
+		})
+	}
+}
+
+func TestParseAnalyzeFile(t *testing.T) {
+	tests := []struct {
+		name            string
+		query           string
+		wantAnalyzeFile string
+		wantErr         bool
+	}{
+		{
+			name:            "analyze file",
+			query:           `ANALYZE 'data/people.parquet'`,
+			wantAnalyzeFile: "data/people.parquet",
+			wantErr:         false,
+		},
+		{
+			name:    "empty path",
+			query:   `ANALYZE ''`,
+			wantErr: true,
+		},
+		{
+			name:    "preceded by let",
+			query:   `LET x = 1; ANALYZE 'data/people.parquet'`,
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, outputOptions, err := ParseQuery(tt.query)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("ParseQuery() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+
+			if got != nil {
+				t.Errorf("ParseQuery() = %v, want no plan", got)
+			}
+			if outputOptions.AnalyzeFile != tt.wantAnalyzeFile {
+				t.Errorf("ParseQuery() analyze file = %v, want %v", outputOptions.AnalyzeFile, tt.wantAnalyzeFile)
+			}
+		})
+	}
+}
+
+func TestParseQuery(t *testing.T) {
+	tests := []struct {
+		name    string
+		query   string
+		want    logical.Node
+		wantErr bool
+	}{
+		{
+			name:  "let binding",
+			query: `LET threshold = 3; SELECT a.name FROM anacondas a WHERE a.age > threshold`,
+			want: logical.NewLet(
+				[]octosql.VariableName{"threshold"},
+				[]logical.Expression{logical.NewConstant(3)},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("a.name"),
+					},
+					logical.NewFilter(
+						logical.NewPredicate(
+							logical.NewVariable("a.age"),
+							logical.MoreThan,
+							logical.NewVariable("threshold"),
+						),
+						logical.NewMap(
+							[]logical.NamedExpression{
+								logical.NewVariable("a.name"),
+							},
+							logical.NewDataSource("anacondas", "a"),
+							true,
+						),
+					),
+					false,
+				),
+			),
+			wantErr: false,
+		},
+		{
+			name:  "set bindings with trailing semicolon",
+			query: `SET x = 1, y = 'a'; SELECT a.name FROM anacondas a;`,
+			want: logical.NewLet(
+				[]octosql.VariableName{"x", "y"},
+				[]logical.Expression{logical.NewConstant(1), logical.NewConstant("a")},
+				logical.NewMap(
+					[]logical.NamedExpression{
+						logical.NewVariable("a.name"),
+					},
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("a.name"),
+						},
+						logical.NewDataSource("anacondas", "a"),
+						true,
+					),
+					false,
+				),
+			),
+			wantErr: false,
+		},
+		{
+			name:  "query parameters",
+			query: `SELECT a.name FROM anacondas a WHERE a.age > :min_age AND a.id = ? AND a.city = $2`,
+			want: logical.NewMap(
+				[]logical.NamedExpression{
+					logical.NewVariable("a.name"),
+				},
+				logical.NewFilter(
+					logical.NewInfixOperator(
+						logical.NewInfixOperator(
+							logical.NewPredicate(
+								logical.NewVariable("a.age"),
+								logical.MoreThan,
+								logical.NewVariable("$min_age"),
+							),
+							logical.NewPredicate(
+								logical.NewVariable("a.id"),
+								logical.Equal,
+								logical.NewVariable("$1"),
+							),
+							"AND",
+						),
+						logical.NewPredicate(
+							logical.NewVariable("a.city"),
+							logical.Equal,
+							logical.NewVariable("$2"),
+						),
+						"AND",
+					),
+					logical.NewMap(
+						[]logical.NamedExpression{
+							logical.NewVariable("a.name"),
+						},
+						logical.NewDataSource("anacondas", "a"),
+						true,
+					),
+				),
+				false,
+			),
+			wantErr: false,
+		},
+		{
+			name:    "query not last",
+			query:   `SELECT a.name FROM anacondas a; LET x = 1`,
+			wantErr: true,
+		},
+		{
+			name:    "session variable",
+			query:   `SET SESSION x = 1; SELECT a.name FROM anacondas a`,
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, _, err := ParseQuery(tt.query)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("ParseQuery() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if tt.wantErr {
+				return
+			}
+
+			if err := logical.EqualNodes(got, tt.want); err != nil {
+				t.Errorf("ParseQuery() = %v, want %v: %v", got, tt.want, err)
+			}
+		})
+	}
+}
@@ -279,23 +279,26 @@ type Statement interface {
 	SQLNode
 }
 
-func (*Union) iStatement()      {}
-func (*Select) iStatement()     {}
-func (*With) iStatement()       {}
-func (*Stream) iStatement()     {}
-func (*Insert) iStatement()     {}
-func (*Update) iStatement()     {}
-func (*Delete) iStatement()     {}
-func (*Set) iStatement()        {}
-func (*DBDDL) iStatement()      {}
-func (*DDL) iStatement()        {}
-func (*Show) iStatement()       {}
-func (*Use) iStatement()        {}
-func (*Begin) iStatement()      {}
-func (*Commit) iStatement()     {}
-func (*Rollback) iStatement()   {}
-func (*OtherRead) iStatement()  {}
-func (*OtherAdmin) iStatement() {}
+func (*Union) iStatement()         {}
+func (*Select) iStatement()        {}
+func (*With) iStatement()          {}
+func (*Stream) iStatement()        {}
+func (*CreateTableAs) iStatement() {}
+func (*Explain) iStatement()       {}
+func (*AnalyzeFile) iStatement()   {}
+func (*Insert) iStatement()        {}
+func (*Update) iStatement()        {}
+func (*Delete) iStatement()        {}
+func (*Set) iStatement()           {}
+func (*DBDDL) iStatement()         {}
+func (*DDL) iStatement()           {}
+func (*Show) iStatement()          {}
+func (*Use) iStatement()           {}
+func (*Begin) iStatement()         {}
+func (*Commit) iStatement()        {}
+func (*Rollback) iStatement()      {}
+func (*OtherRead) iStatement()     {}
+func (*OtherAdmin) iStatement()    {}
 
 // ParenSelect can actually not be a top level statement,
 // but we have to allow it because it's a requirement
@@ -454,6 +457,8 @@ const (
 	UnionStr         = "union"
 	UnionAllStr      = "union all"
 	UnionDistinctStr = "union distinct"
+	IntersectStr     = "intersect"
+	ExceptStr        = "except"
 )
 
 // Format formats the node.
@@ -555,6 +560,65 @@ func (node *Stream) walkSubtree(visit Visit) error {
 	)
 }
 
+// CreateTableAs represents a CREATE TABLE 'path' AS SELECT statement,
+// which materializes the result of the query into the given file.
+type CreateTableAs struct {
+	Path   string
+	Select SelectStatement
+}
+
+// Format formats the node.
+func (node *CreateTableAs) Format(buf *TrackedBuffer) {
+	buf.Myprintf("create table ")
+	sqltypes.MakeTrusted(sqltypes.VarBinary, []byte(node.Path)).EncodeSQL(buf)
+	buf.Myprintf(" as %v", node.Select)
+}
+
+func (node *CreateTableAs) walkSubtree(visit Visit) error {
+	if node == nil {
+		return nil
+	}
+	return Walk(visit, node.Select)
+}
+
+// Explain represents an EXPLAIN or EXPLAIN ANALYZE statement.
+type Explain struct {
+	Analyze   bool
+	Statement SelectStatement
+}
+
+// Format formats the node.
+func (node *Explain) Format(buf *TrackedBuffer) {
+	buf.Myprintf("explain ")
+	if node.Analyze {
+		buf.Myprintf("analyze ")
+	}
+	buf.Myprintf("%v", node.Statement)
+}
+
+func (node *Explain) walkSubtree(visit Visit) error {
+	if node == nil {
+		return nil
+	}
+	return Walk(visit, node.Statement)
+}
+
+// AnalyzeFile represents an ANALYZE 'path' statement,
+// which collects statistics of the file under the given path.
+type AnalyzeFile struct {
+	Path string
+}
+
+// Format formats the node.
+func (node *AnalyzeFile) Format(buf *TrackedBuffer) {
+	buf.Myprintf("analyze ")
+	sqltypes.MakeTrusted(sqltypes.VarBinary, []byte(node.Path)).EncodeSQL(buf)
+}
+
+func (node *AnalyzeFile) walkSubtree(visit Visit) error {
+	return nil
+}
+
 // Insert represents an INSERT or REPLACE statement.
 // Per the MySQL docs, http://dev.mysql.com/doc/refman/5.7/en/replace.html
 // Replace is the counterpart to `INSERT IGNORE`, and works exactly like a
@@ -1682,7 +1746,7 @@ func (node *Rollback) walkSubtree(visit Visit) error {
 	return nil
 }
 
-// OtherRead represents a DESCRIBE, or EXPLAIN statement.
+// OtherRead represents a DESCRIBE statement.
 // It should be used only as an indicator. It does not contain
 // the full AST for the statement.
 type OtherRead struct{}
@@ -1756,9 +1820,12 @@ func (*StarExpr) iSelectExpr()    {}
 func (*AliasedExpr) iSelectExpr() {}
 func (Nextval) iSelectExpr()      {}
 
-// StarExpr defines a '*' or 'table.*' expression.
+// StarExpr defines a '*' or 'table.*' expression,
+// optionally with the columns it EXCLUDEs and REPLACEs.
 type StarExpr struct {
 	TableName TableName
+	Exclude   Exprs
+	Replace   SelectExprs
 }
 
 // Format formats the node.
@@ -1767,6 +1834,12 @@ func (node *StarExpr) Format(buf *TrackedBuffer) {
 		buf.Myprintf("%v.", node.TableName)
 	}
 	buf.Myprintf("*")
+	if len(node.Exclude) > 0 {
+		buf.Myprintf(" exclude (%v)", node.Exclude)
+	}
+	if len(node.Replace) > 0 {
+		buf.Myprintf(" replace (%v)", node.Replace)
+	}
 }
 
 func (node *StarExpr) walkSubtree(visit Visit) error {
@@ -1776,6 +1849,8 @@ func (node *StarExpr) walkSubtree(visit Visit) error {
 	return Walk(
 		visit,
 		node.TableName,
+		node.Exclude,
+		node.Replace,
 	)
 }
 
@@ -1910,6 +1985,7 @@ func (*AliasedTableExpr) iTableExpr()    {}
 func (*ParenTableExpr) iTableExpr()      {}
 func (*JoinTableExpr) iTableExpr()       {}
 func (*TableValuedFunction) iTableExpr() {}
+func (*UnnestExpr) iTableExpr()          {}
 
 // AliasedTableExpr represents a table expression
 // coupled with an optional alias or index hint.
@@ -2085,6 +2161,7 @@ const (
 	StraightJoinStr     = "straight_join"
 	LeftJoinStr         = "left join"
 	RightJoinStr        = "right join"
+	FullOuterJoinStr    = "full outer join"
 	NaturalJoinStr      = "natural join"
 	NaturalLeftJoinStr  = "natural left join"
 	NaturalRightJoinStr = "natural right join"
@@ -2129,6 +2206,28 @@ func (node *TableValuedFunction) walkSubtree(visit Visit) error {
 	)
 }
 
+// UnnestExpr represents an UNNEST(expr) table expression, which outputs a record for each element of expr.
+type UnnestExpr struct {
+	Expr Expr
+	As   TableIdent
+}
+
+// Format formats the node.
+func (node *UnnestExpr) Format(buf *TrackedBuffer) {
+	buf.Myprintf("unnest(%v) as %v", node.Expr, node.As)
+}
+
+func (node *UnnestExpr) walkSubtree(visit Visit) error {
+	if node == nil {
+		return nil
+	}
+	return Walk(
+		visit,
+		node.Expr,
+		node.As,
+	)
+}
+
 // TableValuedFunctionArguments represents SELECT expressions.
 type TableValuedFunctionArguments []*TableValuedFunctionArgument
 
@@ -2151,6 +2250,7 @@ func (node TableValuedFunctionArguments) walkSubtree(visit Visit) error {
 }
 
 // TableValuedFunctionArgument defines an aliased SELECT expression.
+// If Name is empty, the argument is positional.
 type TableValuedFunctionArgument struct {
 	Name  ColIdent
 	Value TableValuedFunctionArgumentValue
@@ -2158,6 +2258,10 @@ type TableValuedFunctionArgument struct {
 
 // Format formats the node.
 func (node *TableValuedFunctionArgument) Format(buf *TrackedBuffer) {
+	if node.Name.IsEmpty() {
+		buf.Myprintf("%v", node.Value)
+		return
+	}
 	buf.Myprintf("%v => %v", node.Name, node.Value)
 }
 
@@ -2338,6 +2442,8 @@ func (ListArg) iExpr()            {}
 func (*BinaryExpr) iExpr()        {}
 func (*UnaryExpr) iExpr()         {}
 func (*IntervalExpr) iExpr()      {}
+func (*IndexExpr) iExpr()         {}
+func (*ArrayExpr) iExpr()         {}
 func (*CollateExpr) iExpr()       {}
 func (*FuncExpr) iExpr()          {}
 func (*TimestampFuncExpr) iExpr() {}
@@ -2520,6 +2626,8 @@ const (
 	NotInStr             = "not in"
 	LikeStr              = "like"
 	NotLikeStr           = "not like"
+	ILikeStr             = "ilike"
+	NotILikeStr          = "not ilike"
 	RegexpStr            = "regexp"
 	NotRegexpStr         = "not regexp"
 	JSONExtractOp        = "->"
@@ -2827,6 +2935,8 @@ type ColName struct {
 	Metadata  interface{}
 	Name      ColIdent
 	Qualifier TableName
+	// Path holds the names of nested fields accessed in the column, as in t.col.field.
+	Path []ColIdent
 }
 
 // Format formats the node.
@@ -2835,6 +2945,9 @@ func (node *ColName) Format(buf *TrackedBuffer) {
 		buf.Myprintf("%v.", node.Qualifier)
 	}
 	buf.Myprintf("%v", node.Name)
+	for _, field := range node.Path {
+		buf.Myprintf(".%v", field)
+	}
 }
 
 func (node *ColName) walkSubtree(visit Visit) error {
@@ -2858,6 +2971,14 @@ func (node *ColName) Equal(c *ColName) bool {
 	if node == nil || c == nil {
 		return false
 	}
+	if len(node.Path) != len(c.Path) {
+		return false
+	}
+	for i := range node.Path {
+		if !node.Path[i].Equal(c.Path[i]) {
+			return false
+		}
+	}
 	return node.Name.Equal(c.Name) && node.Qualifier == c.Qualifier
 }
 
@@ -3039,6 +3160,58 @@ func (node *IntervalExpr) replace(from, to Expr) bool {
 	return replaceExprs(from, to, &node.Expr)
 }
 
+// IndexExpr represents an access to an element of an array, as in expr[index].
+type IndexExpr struct {
+	Expr  Expr
+	Index Expr
+}
+
+// Format formats the node.
+func (node *IndexExpr) Format(buf *TrackedBuffer) {
+	buf.Myprintf("%v[%v]", node.Expr, node.Index)
+}
+
+func (node *IndexExpr) walkSubtree(visit Visit) error {
+	if node == nil {
+		return nil
+	}
+	return Walk(
+		visit,
+		node.Expr,
+		node.Index,
+	)
+}
+
+func (node *IndexExpr) replace(from, to Expr) bool {
+	return replaceExprs(from, to, &node.Expr, &node.Index)
+}
+
+// ArrayExpr represents an array constructor, as in ARRAY[expr, ...].
+type ArrayExpr struct {
+	Exprs Exprs
+}
+
+// Format formats the node.
+func (node *ArrayExpr) Format(buf *TrackedBuffer) {
+	buf.Myprintf("array[%v]", node.Exprs)
+}
+
+func (node *ArrayExpr) walkSubtree(visit Visit) error {
+	if node == nil {
+		return nil
+	}
+	return Walk(visit, node.Exprs)
+}
+
+func (node *ArrayExpr) replace(from, to Expr) bool {
+	for i := range node.Exprs {
+		if replaceExprs(from, to, &node.Exprs[i]) {
+			return true
+		}
+	}
+	return false
+}
+
 // TimestampFuncExpr represents the function and arguments for TIMESTAMP{ADD,DIFF} functions.
 type TimestampFuncExpr struct {
 	Name  string
@@ -3572,6 +3745,7 @@ func (node OrderBy) walkSubtree(visit Visit) error {
 type Order struct {
 	Expr      Expr
 	Direction string
+	Nulls     string
 }
 
 // Order.Direction
@@ -3580,6 +3754,12 @@ const (
 	DescScr = "desc"
 )
 
+// Order.Nulls
+const (
+	NullsFirstStr = "nulls first"
+	NullsLastStr  = "nulls last"
+)
+
 // Format formats the node.
 func (node *Order) Format(buf *TrackedBuffer) {
 	if node, ok := node.Expr.(*NullVal); ok {
@@ -3594,6 +3774,9 @@ func (node *Order) Format(buf *TrackedBuffer) {
 	}
 
 	buf.Myprintf("%v %s", node.Expr, node.Direction)
+	if node.Nulls != "" {
+		buf.Myprintf(" %s", node.Nulls)
+	}
 }
 
 func (node *Order) walkSubtree(visit Visit) error {
@@ -3664,15 +3847,23 @@ func (w *WatermarkTrigger) iTrigger() {}
 func (w *DelayTrigger) iTrigger()     {}
 func (w *CountingTrigger) iTrigger()  {}
 
+// WatermarkTrigger has a nil AllowedLateness if none was given.
 type WatermarkTrigger struct {
+	AllowedLateness Expr
 }
 
 func (w *WatermarkTrigger) Format(buf *TrackedBuffer) {
 	buf.Myprintf("WATERMARK")
+	if w.AllowedLateness != nil {
+		buf.Myprintf(" ALLOWED LATENESS %v", w.AllowedLateness)
+	}
 }
 
 func (w *WatermarkTrigger) walkSubtree(visit Visit) error {
-	return nil
+	if w.AllowedLateness == nil {
+		return nil
+	}
+	return Walk(visit, w.AllowedLateness)
 }
 
 type DelayTrigger struct {
@@ -550,6 +550,10 @@ func TestColNameEqual(t *testing.T) {
 	if !c1.Equal(c2) {
 		t.Error("columns unequal, want equal")
 	}
+	c2.Path = []ColIdent{NewColIdent("cc")}
+	if c1.Equal(c2) {
+		t.Error("columns with different paths equal, want unequal")
+	}
 }
 
 func TestColIdent(t *testing.T) {
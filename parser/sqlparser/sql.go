@@ -117,258 +117,273 @@ type yySymType struct {
 
 const LEX_ERROR = 57346
 const UNION = 57347
-const SELECT = 57348
-const STREAM = 57349
-const INSERT = 57350
-const UPDATE = 57351
-const DELETE = 57352
-const FROM = 57353
-const WHERE = 57354
-const GROUP = 57355
-const HAVING = 57356
-const ORDER = 57357
-const BY = 57358
-const LIMIT = 57359
-const OFFSET = 57360
-const FOR = 57361
-const WATERMARK = 57362
-const DELAY = 57363
-const COUNTING = 57364
-const AFTER = 57365
-const ALL = 57366
-const DISTINCT = 57367
-const AS = 57368
-const EXISTS = 57369
-const ASC = 57370
-const DESC = 57371
-const INTO = 57372
-const DUPLICATE = 57373
-const KEY = 57374
-const DEFAULT = 57375
-const SET = 57376
-const LOCK = 57377
-const UNLOCK = 57378
-const KEYS = 57379
-const VALUES = 57380
-const LAST_INSERT_ID = 57381
-const NEXT = 57382
-const VALUE = 57383
-const SHARE = 57384
-const MODE = 57385
-const SQL_NO_CACHE = 57386
-const SQL_CACHE = 57387
-const JOIN = 57388
-const STRAIGHT_JOIN = 57389
-const LEFT = 57390
-const RIGHT = 57391
-const INNER = 57392
-const OUTER = 57393
-const CROSS = 57394
-const NATURAL = 57395
-const USE = 57396
-const FORCE = 57397
-const ON = 57398
-const USING = 57399
-const ID = 57400
-const HEX = 57401
-const STRING = 57402
-const INTEGRAL = 57403
-const FLOAT = 57404
-const HEXNUM = 57405
-const VALUE_ARG = 57406
-const LIST_ARG = 57407
-const COMMENT = 57408
-const COMMENT_KEYWORD = 57409
-const BIT_LITERAL = 57410
-const NULL = 57411
-const TRUE = 57412
-const FALSE = 57413
-const OFF = 57414
-const OR = 57415
-const AND = 57416
-const NOT = 57417
-const BETWEEN = 57418
-const CASE = 57419
-const WHEN = 57420
-const THEN = 57421
-const ELSE = 57422
-const END = 57423
-const LE = 57424
-const GE = 57425
-const NE = 57426
-const NULL_SAFE_EQUAL = 57427
-const IS = 57428
-const LIKE = 57429
-const REGEXP = 57430
-const IN = 57431
-const RIGHTARROW = 57432
-const SHIFT_LEFT = 57433
-const SHIFT_RIGHT = 57434
-const DIV = 57435
-const MOD = 57436
-const UNARY = 57437
-const COLLATE = 57438
-const BINARY = 57439
-const UNDERSCORE_BINARY = 57440
-const UNDERSCORE_UTF8MB4 = 57441
-const INTERVAL = 57442
-const JSON_EXTRACT_OP = 57443
-const JSON_UNQUOTE_EXTRACT_OP = 57444
-const CREATE = 57445
-const ALTER = 57446
-const DROP = 57447
-const RENAME = 57448
-const ANALYZE = 57449
-const ADD = 57450
-const FLUSH = 57451
-const SCHEMA = 57452
-const TABLE = 57453
-const DESCRIPTOR = 57454
-const INDEX = 57455
-const VIEW = 57456
-const TO = 57457
-const IGNORE = 57458
-const IF = 57459
-const UNIQUE = 57460
-const PRIMARY = 57461
-const COLUMN = 57462
-const SPATIAL = 57463
-const FULLTEXT = 57464
-const KEY_BLOCK_SIZE = 57465
-const ACTION = 57466
-const CASCADE = 57467
-const CONSTRAINT = 57468
-const FOREIGN = 57469
-const NO = 57470
-const REFERENCES = 57471
-const RESTRICT = 57472
-const SHOW = 57473
-const DESCRIBE = 57474
-const EXPLAIN = 57475
-const DATE = 57476
-const ESCAPE = 57477
-const REPAIR = 57478
-const OPTIMIZE = 57479
-const TRUNCATE = 57480
-const MAXVALUE = 57481
-const PARTITION = 57482
-const REORGANIZE = 57483
-const LESS = 57484
-const THAN = 57485
-const PROCEDURE = 57486
-const TRIGGER = 57487
-const VINDEX = 57488
-const VINDEXES = 57489
-const STATUS = 57490
-const VARIABLES = 57491
-const WARNINGS = 57492
-const BEGIN = 57493
-const START = 57494
-const TRANSACTION = 57495
-const COMMIT = 57496
-const ROLLBACK = 57497
-const BIT = 57498
-const TINYINT = 57499
-const SMALLINT = 57500
-const MEDIUMINT = 57501
-const INT = 57502
-const INTEGER = 57503
-const BIGINT = 57504
-const INTNUM = 57505
-const REAL = 57506
-const DOUBLE = 57507
-const FLOAT_TYPE = 57508
-const DECIMAL = 57509
-const NUMERIC = 57510
-const TIME = 57511
-const TIMESTAMP = 57512
-const DATETIME = 57513
-const YEAR = 57514
-const CHAR = 57515
-const VARCHAR = 57516
-const BOOL = 57517
-const CHARACTER = 57518
-const VARBINARY = 57519
-const NCHAR = 57520
-const TEXT = 57521
-const TINYTEXT = 57522
-const MEDIUMTEXT = 57523
-const LONGTEXT = 57524
-const BLOB = 57525
-const TINYBLOB = 57526
-const MEDIUMBLOB = 57527
-const LONGBLOB = 57528
-const JSON = 57529
-const ENUM = 57530
-const GEOMETRY = 57531
-const POINT = 57532
-const LINESTRING = 57533
-const POLYGON = 57534
-const GEOMETRYCOLLECTION = 57535
-const MULTIPOINT = 57536
-const MULTILINESTRING = 57537
-const MULTIPOLYGON = 57538
-const NULLX = 57539
-const AUTO_INCREMENT = 57540
-const APPROXNUM = 57541
-const SIGNED = 57542
-const UNSIGNED = 57543
-const ZEROFILL = 57544
-const COLLATION = 57545
-const DATABASES = 57546
-const SCHEMAS = 57547
-const TABLES = 57548
-const VITESS_KEYSPACES = 57549
-const VITESS_SHARDS = 57550
-const VITESS_TABLETS = 57551
-const VSCHEMA = 57552
-const VSCHEMA_TABLES = 57553
-const VITESS_TARGET = 57554
-const FULL = 57555
-const PROCESSLIST = 57556
-const COLUMNS = 57557
-const FIELDS = 57558
-const ENGINES = 57559
-const PLUGINS = 57560
-const NAMES = 57561
-const CHARSET = 57562
-const GLOBAL = 57563
-const SESSION = 57564
-const ISOLATION = 57565
-const LEVEL = 57566
-const READ = 57567
-const WRITE = 57568
-const ONLY = 57569
-const REPEATABLE = 57570
-const COMMITTED = 57571
-const UNCOMMITTED = 57572
-const SERIALIZABLE = 57573
-const CURRENT_TIMESTAMP = 57574
-const DATABASE = 57575
-const CURRENT_DATE = 57576
-const CURRENT_TIME = 57577
-const LOCALTIME = 57578
-const LOCALTIMESTAMP = 57579
-const UTC_DATE = 57580
-const UTC_TIME = 57581
-const UTC_TIMESTAMP = 57582
-const REPLACE = 57583
-const CONVERT = 57584
-const CAST = 57585
-const SUBSTR = 57586
-const SUBSTRING = 57587
-const GROUP_CONCAT = 57588
-const SEPARATOR = 57589
-const TIMESTAMPADD = 57590
-const TIMESTAMPDIFF = 57591
-const MATCH = 57592
-const AGAINST = 57593
-const BOOLEAN = 57594
-const LANGUAGE = 57595
-const WITH = 57596
-const QUERY = 57597
-const EXPANSION = 57598
-const UNUSED = 57599
+const INTERSECT = 57348
+const EXCEPT = 57349
+const SELECT = 57350
+const STREAM = 57351
+const INSERT = 57352
+const UPDATE = 57353
+const DELETE = 57354
+const FROM = 57355
+const WHERE = 57356
+const GROUP = 57357
+const HAVING = 57358
+const ORDER = 57359
+const BY = 57360
+const LIMIT = 57361
+const OFFSET = 57362
+const FOR = 57363
+const WATERMARK = 57364
+const DELAY = 57365
+const COUNTING = 57366
+const AFTER = 57367
+const ALLOWED = 57368
+const LATENESS = 57369
+const ALL = 57370
+const DISTINCT = 57371
+const AS = 57372
+const EXISTS = 57373
+const ASC = 57374
+const DESC = 57375
+const NULLS = 57376
+const FIRST = 57377
+const LAST = 57378
+const INTO = 57379
+const DUPLICATE = 57380
+const KEY = 57381
+const DEFAULT = 57382
+const SET = 57383
+const LET = 57384
+const LOCK = 57385
+const UNLOCK = 57386
+const KEYS = 57387
+const VALUES = 57388
+const LAST_INSERT_ID = 57389
+const NEXT = 57390
+const VALUE = 57391
+const SHARE = 57392
+const MODE = 57393
+const SQL_NO_CACHE = 57394
+const SQL_CACHE = 57395
+const JOIN = 57396
+const STRAIGHT_JOIN = 57397
+const LEFT = 57398
+const RIGHT = 57399
+const INNER = 57400
+const OUTER = 57401
+const CROSS = 57402
+const NATURAL = 57403
+const USE = 57404
+const FORCE = 57405
+const ON = 57406
+const USING = 57407
+const ID = 57408
+const HEX = 57409
+const STRING = 57410
+const INTEGRAL = 57411
+const FLOAT = 57412
+const HEXNUM = 57413
+const VALUE_ARG = 57414
+const LIST_ARG = 57415
+const COMMENT = 57416
+const COMMENT_KEYWORD = 57417
+const BIT_LITERAL = 57418
+const NULL = 57419
+const TRUE = 57420
+const FALSE = 57421
+const OFF = 57422
+const OR = 57423
+const AND = 57424
+const NOT = 57425
+const BETWEEN = 57426
+const CASE = 57427
+const WHEN = 57428
+const THEN = 57429
+const ELSE = 57430
+const END = 57431
+const LE = 57432
+const GE = 57433
+const NE = 57434
+const NULL_SAFE_EQUAL = 57435
+const IS = 57436
+const LIKE = 57437
+const ILIKE = 57438
+const REGEXP = 57439
+const IN = 57440
+const RIGHTARROW = 57441
+const SHIFT_LEFT = 57442
+const SHIFT_RIGHT = 57443
+const DIV = 57444
+const MOD = 57445
+const AT = 57446
+const UNARY = 57447
+const COLLATE = 57448
+const BINARY = 57449
+const UNDERSCORE_BINARY = 57450
+const UNDERSCORE_UTF8MB4 = 57451
+const INTERVAL = 57452
+const JSON_EXTRACT_OP = 57453
+const JSON_UNQUOTE_EXTRACT_OP = 57454
+const CREATE = 57455
+const ALTER = 57456
+const DROP = 57457
+const RENAME = 57458
+const ANALYZE = 57459
+const ADD = 57460
+const FLUSH = 57461
+const SCHEMA = 57462
+const TABLE = 57463
+const DESCRIPTOR = 57464
+const INDEX = 57465
+const VIEW = 57466
+const TO = 57467
+const IGNORE = 57468
+const IF = 57469
+const UNIQUE = 57470
+const PRIMARY = 57471
+const COLUMN = 57472
+const SPATIAL = 57473
+const FULLTEXT = 57474
+const KEY_BLOCK_SIZE = 57475
+const ACTION = 57476
+const CASCADE = 57477
+const CONSTRAINT = 57478
+const FOREIGN = 57479
+const NO = 57480
+const REFERENCES = 57481
+const RESTRICT = 57482
+const SHOW = 57483
+const DESCRIBE = 57484
+const EXPLAIN = 57485
+const DATE = 57486
+const ESCAPE = 57487
+const REPAIR = 57488
+const OPTIMIZE = 57489
+const TRUNCATE = 57490
+const EXTRACT = 57491
+const MAXVALUE = 57492
+const PARTITION = 57493
+const REORGANIZE = 57494
+const LESS = 57495
+const THAN = 57496
+const PROCEDURE = 57497
+const TRIGGER = 57498
+const VINDEX = 57499
+const VINDEXES = 57500
+const STATUS = 57501
+const VARIABLES = 57502
+const WARNINGS = 57503
+const BEGIN = 57504
+const START = 57505
+const TRANSACTION = 57506
+const COMMIT = 57507
+const ROLLBACK = 57508
+const BIT = 57509
+const TINYINT = 57510
+const SMALLINT = 57511
+const MEDIUMINT = 57512
+const INT = 57513
+const INTEGER = 57514
+const BIGINT = 57515
+const INTNUM = 57516
+const REAL = 57517
+const DOUBLE = 57518
+const FLOAT_TYPE = 57519
+const DECIMAL = 57520
+const NUMERIC = 57521
+const TIME = 57522
+const TIMESTAMP = 57523
+const DATETIME = 57524
+const YEAR = 57525
+const ZONE = 57526
+const CHAR = 57527
+const VARCHAR = 57528
+const BOOL = 57529
+const CHARACTER = 57530
+const VARBINARY = 57531
+const NCHAR = 57532
+const TEXT = 57533
+const TINYTEXT = 57534
+const MEDIUMTEXT = 57535
+const LONGTEXT = 57536
+const BLOB = 57537
+const TINYBLOB = 57538
+const MEDIUMBLOB = 57539
+const LONGBLOB = 57540
+const JSON = 57541
+const ENUM = 57542
+const GEOMETRY = 57543
+const POINT = 57544
+const LINESTRING = 57545
+const POLYGON = 57546
+const GEOMETRYCOLLECTION = 57547
+const MULTIPOINT = 57548
+const MULTILINESTRING = 57549
+const MULTIPOLYGON = 57550
+const NULLX = 57551
+const AUTO_INCREMENT = 57552
+const APPROXNUM = 57553
+const SIGNED = 57554
+const UNSIGNED = 57555
+const ZEROFILL = 57556
+const COLLATION = 57557
+const DATABASES = 57558
+const SCHEMAS = 57559
+const TABLES = 57560
+const VITESS_KEYSPACES = 57561
+const VITESS_SHARDS = 57562
+const VITESS_TABLETS = 57563
+const VSCHEMA = 57564
+const VSCHEMA_TABLES = 57565
+const VITESS_TARGET = 57566
+const FULL = 57567
+const PROCESSLIST = 57568
+const COLUMNS = 57569
+const FIELDS = 57570
+const ENGINES = 57571
+const PLUGINS = 57572
+const NAMES = 57573
+const CHARSET = 57574
+const GLOBAL = 57575
+const SESSION = 57576
+const ISOLATION = 57577
+const LEVEL = 57578
+const READ = 57579
+const WRITE = 57580
+const ONLY = 57581
+const REPEATABLE = 57582
+const COMMITTED = 57583
+const UNCOMMITTED = 57584
+const SERIALIZABLE = 57585
+const CURRENT_TIMESTAMP = 57586
+const DATABASE = 57587
+const CURRENT_DATE = 57588
+const CURRENT_TIME = 57589
+const LOCALTIME = 57590
+const LOCALTIMESTAMP = 57591
+const UTC_DATE = 57592
+const UTC_TIME = 57593
+const UTC_TIMESTAMP = 57594
+const REPLACE = 57595
+const CONVERT = 57596
+const CAST = 57597
+const ARRAY = 57598
+const UNNEST = 57599
+const EXCLUDE = 57600
+const SUBSTR = 57601
+const SUBSTRING = 57602
+const GROUP_CONCAT = 57603
+const SEPARATOR = 57604
+const TIMESTAMPADD = 57605
+const TIMESTAMPDIFF = 57606
+const MATCH = 57607
+const AGAINST = 57608
+const BOOLEAN = 57609
+const LANGUAGE = 57610
+const WITH = 57611
+const QUERY = 57612
+const EXPANSION = 57613
+const UNUSED = 57614
 
 var yyToknames = [...]string{
 	"$end",
@@ -376,6 +391,8 @@ var yyToknames = [...]string{
 	"$unk",
 	"LEX_ERROR",
 	"UNION",
+	"INTERSECT",
+	"EXCEPT",
 	"SELECT",
 	"STREAM",
 	"INSERT",
@@ -394,17 +411,23 @@ var yyToknames = [...]string{
 	"DELAY",
 	"COUNTING",
 	"AFTER",
+	"ALLOWED",
+	"LATENESS",
 	"ALL",
 	"DISTINCT",
 	"AS",
 	"EXISTS",
 	"ASC",
 	"DESC",
+	"NULLS",
+	"FIRST",
+	"LAST",
 	"INTO",
 	"DUPLICATE",
 	"KEY",
 	"DEFAULT",
 	"SET",
+	"LET",
 	"LOCK",
 	"UNLOCK",
 	"KEYS",
@@ -465,9 +488,11 @@ var yyToknames = [...]string{
 	"NULL_SAFE_EQUAL",
 	"IS",
 	"LIKE",
+	"ILIKE",
 	"REGEXP",
 	"IN",
 	"RIGHTARROW",
+	"'~'",
 	"'|'",
 	"'&'",
 	"SHIFT_LEFT",
@@ -480,8 +505,9 @@ var yyToknames = [...]string{
 	"'%'",
 	"MOD",
 	"'^'",
-	"'~'",
+	"AT",
 	"UNARY",
+	"'['",
 	"COLLATE",
 	"BINARY",
 	"UNDERSCORE_BINARY",
@@ -526,6 +552,7 @@ var yyToknames = [...]string{
 	"REPAIR",
 	"OPTIMIZE",
 	"TRUNCATE",
+	"EXTRACT",
 	"MAXVALUE",
 	"PARTITION",
 	"REORGANIZE",
@@ -560,6 +587,7 @@ var yyToknames = [...]string{
 	"TIMESTAMP",
 	"DATETIME",
 	"YEAR",
+	"ZONE",
 	"CHAR",
 	"VARCHAR",
 	"BOOL",
@@ -631,6 +659,9 @@ var yyToknames = [...]string{
 	"REPLACE",
 	"CONVERT",
 	"CAST",
+	"ARRAY",
+	"UNNEST",
+	"EXCLUDE",
 	"SUBSTR",
 	"SUBSTRING",
 	"GROUP_CONCAT",
@@ -646,6 +677,7 @@ var yyToknames = [...]string{
 	"EXPANSION",
 	"UNUSED",
 	"';'",
+	"']'",
 }
 var yyStatenames = [...]string{}
 
@@ -658,1457 +690,1546 @@ var yyExca = [...]int{
 	-1, 1,
 	1, -1,
 	-2, 0,
-	-1, 38,
-	165, 304,
-	166, 304,
-	-2, 294,
-	-1, 281,
-	117, 660,
-	-2, 656,
-	-1, 282,
-	117, 661,
-	-2, 657,
-	-1, 350,
-	86, 840,
-	-2, 68,
-	-1, 351,
-	86, 796,
-	-2, 69,
-	-1, 356,
-	86, 773,
-	-2, 622,
-	-1, 358,
-	86, 817,
-	-2, 624,
-	-1, 633,
-	1, 356,
-	12, 356,
-	13, 356,
-	14, 356,
-	15, 356,
-	17, 356,
-	19, 356,
-	34, 356,
-	35, 356,
-	46, 356,
-	47, 356,
-	48, 356,
-	49, 356,
-	50, 356,
-	52, 356,
-	53, 356,
-	56, 356,
-	57, 356,
-	59, 356,
-	60, 356,
-	162, 356,
-	275, 356,
-	-2, 383,
-	-1, 637,
-	57, 49,
-	59, 49,
-	-2, 53,
-	-1, 783,
-	117, 663,
-	-2, 659,
-	-1, 1010,
-	5, 35,
-	-2, 450,
-	-1, 1289,
-	5, 35,
-	-2, 597,
-	-1, 1431,
-	5, 35,
-	-2, 600,
+	-1, 40,
+	177, 308,
+	178, 308,
+	-2, 298,
+	-1, 296,
+	128, 702,
+	-2, 698,
+	-1, 297,
+	128, 703,
+	-2, 699,
+	-1, 368,
+	94, 894,
+	-2, 70,
+	-1, 369,
+	94, 846,
+	-2, 71,
+	-1, 374,
+	94, 821,
+	-2, 664,
+	-1, 376,
+	94, 870,
+	-2, 666,
+	-1, 668,
+	1, 370,
+	14, 370,
+	15, 370,
+	16, 370,
+	17, 370,
+	19, 370,
+	21, 370,
+	41, 370,
+	43, 370,
+	54, 370,
+	55, 370,
+	56, 370,
+	57, 370,
+	58, 370,
+	60, 370,
+	61, 370,
+	64, 370,
+	65, 370,
+	67, 370,
+	68, 370,
+	174, 370,
+	243, 370,
+	291, 370,
+	-2, 401,
+	-1, 674,
+	65, 50,
+	67, 50,
+	-2, 54,
+	-1, 1072,
+	5, 36,
+	6, 36,
+	7, 36,
+	-2, 473,
+	-1, 1389,
+	5, 36,
+	6, 36,
+	7, 36,
+	-2, 639,
+	-1, 1536,
+	5, 36,
+	6, 36,
+	7, 36,
+	-2, 642,
 }
 
 const yyPrivate = 57344
 
-const yyLast = 13702
+const yyLast = 14559
 
 var yyAct = [...]int{
 
-	282, 1478, 1468, 1252, 1417, 1043, 1138, 1065, 286, 589,
-	1328, 629, 1362, 1443, 1063, 312, 1315, 1189, 299, 257,
-	865, 588, 3, 1044, 66, 1186, 974, 1226, 1071, 1196,
-	1190, 895, 62, 206, 870, 58, 867, 66, 1092, 904,
-	66, 894, 938, 1202, 355, 733, 1161, 818, 1002, 808,
-	248, 815, 747, 1118, 650, 1109, 891, 836, 785, 908,
-	856, 518, 524, 649, 458, 533, 313, 52, 934, 349,
-	849, 284, 541, 344, 256, 630, 269, 341, 346, 639,
-	57, 1471, 1449, 604, 1466, 1429, 1463, 1253, 25, 1448,
-	1428, 25, 1178, 1281, 463, 61, 249, 250, 251, 252,
-	1220, 918, 255, 603, 651, 25, 652, 957, 885, 924,
-	216, 212, 1080, 213, 214, 1079, 1038, 254, 1081, 52,
-	1039, 1221, 1222, 1346, 512, 956, 1390, 253, 554, 553,
-	563, 564, 556, 557, 558, 559, 560, 561, 562, 555,
-	55, 186, 565, 55, 324, 817, 330, 331, 328, 329,
-	327, 326, 325, 961, 1100, 491, 476, 55, 886, 887,
-	332, 333, 955, 917, 1318, 508, 841, 925, 188, 189,
-	190, 191, 192, 509, 506, 507, 208, 207, 210, 22,
-	1334, 1272, 1270, 511, 247, 501, 502, 1141, 722, 1140,
-	720, 1423, 66, 206, 1465, 1460, 1418, 66, 1137, 66,
-	850, 1410, 909, 1363, 1486, 477, 1162, 465, 210, 66,
-	1125, 1142, 66, 952, 949, 950, 1365, 948, 66, 726,
-	1482, 66, 493, 721, 206, 495, 206, 206, 911, 206,
-	206, 713, 206, 215, 206, 464, 273, 1215, 911, 1123,
-	1214, 1213, 1371, 206, 1164, 1066, 1068, 461, 723, 959,
-	962, 265, 468, 220, 1134, 492, 494, 1093, 211, 1397,
-	1136, 968, 66, 881, 967, 577, 578, 1019, 1292, 1148,
-	1076, 1029, 209, 996, 756, 645, 206, 1166, 545, 1170,
-	483, 1165, 526, 1163, 892, 954, 1364, 530, 1168, 565,
-	488, 1238, 488, 488, 1212, 488, 488, 1167, 488, 529,
-	488, 1391, 195, 753, 514, 515, 1427, 953, 1124, 488,
-	1169, 1171, 925, 1129, 1126, 1119, 1127, 1122, 1180, 459,
-	1016, 1120, 1121, 976, 1067, 540, 910, 52, 1480, 528,
-	837, 1481, 52, 1479, 555, 1128, 910, 565, 196, 66,
-	66, 66, 715, 1239, 490, 1372, 1370, 574, 206, 538,
-	576, 958, 457, 23, 206, 473, 23, 527, 1135, 1408,
-	1133, 1380, 539, 538, 264, 540, 960, 338, 339, 275,
-	23, 479, 480, 481, 577, 578, 628, 748, 587, 540,
-	591, 592, 593, 594, 595, 596, 597, 598, 599, 1200,
-	602, 605, 605, 605, 611, 605, 605, 611, 605, 619,
-	620, 621, 622, 623, 624, 975, 634, 911, 531, 607,
-	609, 489, 613, 615, 755, 618, 653, 1487, 638, 470,
-	1098, 471, 643, 516, 472, 1413, 647, 577, 578, 606,
-	608, 610, 612, 614, 616, 617, 459, 466, 467, 837,
-	1014, 1026, 1013, 554, 553, 563, 564, 556, 557, 558,
-	559, 560, 561, 562, 555, 754, 1488, 565, 66, 539,
-	538, 535, 749, 206, 1015, 914, 1435, 792, 66, 66,
-	206, 915, 539, 538, 66, 55, 540, 66, 1324, 288,
-	66, 790, 791, 789, 66, 788, 206, 206, 1437, 540,
-	1323, 206, 206, 206, 66, 206, 206, 1003, 759, 760,
-	539, 538, 206, 206, 809, 910, 810, 1182, 1113, 1112,
-	907, 905, 1101, 906, 539, 538, 1409, 540, 903, 909,
-	556, 557, 558, 559, 560, 561, 562, 555, 735, 488,
-	565, 540, 517, 206, 1341, 1406, 488, 66, 775, 777,
-	778, 1321, 1145, 206, 776, 1110, 539, 538, 1255, 762,
-	761, 1093, 488, 488, 727, 1368, 1464, 488, 488, 488,
-	1088, 488, 488, 540, 786, 993, 994, 995, 488, 488,
-	554, 553, 563, 564, 556, 557, 558, 559, 560, 561,
-	562, 555, 811, 1082, 565, 1083, 732, 206, 1439, 517,
-	783, 781, 1368, 1421, 517, 52, 858, 861, 862, 863,
-	859, 731, 860, 864, 352, 827, 830, 716, 764, 714,
-	517, 838, 711, 822, 485, 779, 1368, 517, 1368, 1398,
-	206, 206, 1368, 1367, 1313, 1312, 1377, 66, 521, 525,
-	1294, 517, 1291, 517, 1376, 66, 478, 66, 1245, 1244,
-	66, 66, 1235, 52, 66, 66, 66, 206, 546, 812,
-	813, 558, 559, 560, 561, 562, 555, 1072, 591, 565,
-	206, 1241, 1242, 1241, 1240, 834, 1008, 517, 912, 872,
-	311, 853, 517, 1072, 846, 59, 876, 820, 517, 1187,
-	878, 1151, 1199, 590, 302, 301, 304, 305, 306, 307,
-	735, 1454, 601, 303, 308, 660, 659, 763, 1199, 820,
-	641, 868, 869, 204, 853, 875, 634, 640, 874, 852,
-	634, 1287, 883, 1379, 66, 206, 882, 206, 879, 1008,
-	1199, 206, 206, 66, 66, 899, 66, 66, 853, 1008,
-	66, 206, 641, 1243, 853, 1211, 1084, 884, 823, 824,
-	1032, 1031, 829, 832, 833, 1008, 642, 66, 644, 66,
-	66, 640, 66, 940, 646, 819, 821, 757, 725, 352,
-	261, 55, 1450, 575, 1330, 919, 1299, 845, 1284, 847,
-	848, 920, 921, 922, 923, 266, 936, 937, 642, 1139,
-	640, 488, 939, 488, 926, 927, 928, 931, 932, 933,
-	1283, 1231, 858, 861, 862, 863, 859, 488, 860, 864,
-	1087, 783, 983, 1203, 1204, 786, 935, 554, 553, 563,
-	564, 556, 557, 558, 559, 560, 561, 562, 555, 633,
-	984, 565, 930, 929, 986, 1446, 1445, 55, 942, 554,
-	553, 563, 564, 556, 557, 558, 559, 560, 561, 562,
-	555, 1473, 1469, 565, 1233, 1206, 1187, 1114, 998, 997,
-	751, 729, 770, 1209, 66, 66, 66, 66, 66, 1444,
-	1208, 1045, 1055, 354, 1052, 1046, 66, 1056, 1049, 66,
-	206, 1040, 1053, 1051, 66, 1458, 66, 1054, 270, 271,
-	750, 858, 861, 862, 863, 859, 1447, 860, 864, 1147,
-	822, 1203, 1204, 1025, 354, 206, 354, 354, 980, 354,
-	354, 279, 354, 534, 354, 1074, 1070, 1075, 772, 773,
-	1452, 991, 1073, 354, 990, 992, 1041, 1042, 532, 1105,
-	634, 634, 634, 634, 634, 1058, 1085, 658, 985, 519,
-	1047, 1048, 486, 1050, 1057, 868, 862, 863, 1069, 1077,
-	1097, 1415, 634, 206, 206, 520, 543, 1094, 1414, 1344,
-	1095, 1089, 1285, 1326, 945, 728, 866, 782, 267, 268,
-	534, 590, 1007, 262, 825, 826, 1090, 1091, 1456, 1455,
-	258, 989, 206, 1384, 259, 1332, 59, 1383, 1005, 988,
-	1023, 1104, 1006, 1106, 1107, 1108, 1111, 1072, 66, 1010,
-	1011, 1012, 510, 1475, 1474, 1475, 1018, 206, 1117, 1021,
-	1022, 1020, 1017, 1130, 746, 1028, 536, 1394, 1319, 1030,
-	488, 752, 1033, 1034, 1035, 1036, 185, 187, 354, 56,
-	1, 1467, 1254, 890, 655, 1327, 1144, 951, 787, 1416,
-	1102, 1103, 1361, 1225, 1060, 902, 893, 194, 488, 456,
-	193, 1407, 206, 206, 1188, 901, 900, 1045, 1155, 1154,
-	1369, 1317, 913, 1099, 916, 1191, 1160, 1232, 352, 1179,
-	1096, 1412, 1173, 1193, 1172, 666, 664, 665, 663, 668,
-	206, 896, 1198, 667, 783, 983, 662, 232, 347, 654,
-	941, 537, 197, 1132, 1131, 206, 947, 206, 206, 504,
-	505, 234, 573, 1207, 987, 1078, 353, 1194, 1442, 1216,
-	1422, 758, 1219, 523, 1382, 1331, 1192, 1217, 52, 1024,
-	600, 835, 287, 774, 633, 66, 300, 297, 1224, 633,
-	298, 765, 1037, 633, 981, 982, 1223, 525, 1228, 1229,
-	1230, 547, 66, 354, 285, 277, 632, 625, 206, 857,
-	354, 206, 206, 66, 855, 854, 342, 1205, 1201, 206,
-	1303, 1061, 66, 1062, 631, 1150, 354, 354, 1280, 1236,
-	1237, 354, 354, 354, 1389, 354, 354, 769, 782, 1247,
-	27, 1259, 354, 354, 1159, 184, 272, 19, 18, 1260,
-	17, 1248, 20, 1250, 16, 15, 14, 474, 1261, 31,
-	1009, 579, 580, 581, 582, 583, 584, 585, 586, 21,
-	13, 12, 11, 766, 10, 9, 8, 1027, 1045, 1268,
-	7, 6, 206, 543, 1286, 5, 354, 4, 634, 60,
-	260, 263, 24, 1296, 206, 2, 1302, 0, 1210, 0,
-	0, 1301, 206, 0, 1295, 0, 0, 1311, 0, 0,
-	0, 0, 0, 0, 0, 1279, 0, 206, 0, 0,
-	0, 0, 0, 0, 206, 1085, 0, 814, 0, 0,
-	0, 0, 0, 0, 1265, 1266, 0, 1267, 0, 787,
-	1269, 0, 1271, 839, 0, 0, 0, 0, 0, 0,
-	1307, 1308, 1309, 0, 0, 0, 0, 0, 0, 0,
-	843, 844, 206, 206, 0, 206, 0, 0, 0, 0,
-	206, 0, 66, 1191, 0, 0, 896, 0, 206, 206,
-	206, 66, 1347, 488, 206, 1352, 1353, 354, 1345, 0,
-	0, 1262, 0, 0, 1357, 1358, 1359, 0, 1264, 1314,
-	354, 206, 0, 633, 633, 633, 633, 633, 1373, 1273,
-	1274, 1366, 0, 872, 0, 1360, 1146, 1381, 633, 0,
-	1320, 0, 1322, 0, 1192, 633, 66, 1348, 0, 1288,
-	1289, 1290, 1191, 1293, 1395, 1400, 0, 1399, 1404, 206,
-	1396, 0, 1355, 1356, 1405, 0, 1333, 1374, 0, 1375,
-	206, 206, 0, 1310, 0, 354, 0, 354, 0, 0,
-	1419, 963, 964, 1378, 1425, 0, 1181, 0, 206, 1278,
-	1430, 354, 0, 1045, 0, 0, 636, 0, 1153, 0,
-	0, 66, 1420, 1192, 0, 52, 0, 0, 0, 206,
-	0, 0, 634, 0, 0, 0, 0, 354, 1441, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 1183, 1218, 218, 1451, 1453, 0, 1340, 0, 784,
-	0, 206, 793, 794, 795, 796, 797, 798, 799, 800,
-	801, 802, 803, 804, 805, 806, 807, 1459, 1461, 0,
-	1472, 0, 0, 0, 0, 0, 0, 1483, 554, 553,
-	563, 564, 556, 557, 558, 559, 560, 561, 562, 555,
-	0, 0, 565, 0, 0, 0, 896, 0, 896, 0,
-	0, 0, 1385, 1386, 1387, 1388, 683, 842, 0, 1392,
-	1393, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 839, 1401, 1402, 1403, 0, 0, 0,
-	0, 0, 1470, 487, 0, 0, 0, 0, 0, 0,
-	1064, 553, 563, 564, 556, 557, 558, 559, 560, 561,
-	562, 555, 1282, 0, 565, 0, 1426, 0, 0, 0,
-	1153, 0, 590, 1431, 0, 354, 1433, 1434, 0, 0,
-	1297, 0, 522, 1298, 0, 0, 1300, 0, 0, 0,
-	0, 0, 1304, 1438, 0, 671, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 63, 0, 343, 0,
-	0, 0, 0, 460, 0, 462, 0, 0, 0, 219,
-	0, 0, 246, 1115, 354, 469, 0, 0, 475, 0,
-	0, 0, 0, 684, 482, 0, 0, 484, 0, 0,
-	0, 633, 0, 0, 0, 896, 0, 0, 0, 0,
-	0, 0, 354, 1484, 1485, 697, 700, 701, 702, 703,
-	704, 705, 0, 706, 707, 708, 709, 710, 685, 686,
-	687, 688, 669, 670, 698, 1329, 672, 354, 673, 674,
-	675, 676, 677, 678, 679, 680, 681, 682, 689, 690,
-	691, 692, 693, 694, 695, 696, 0, 0, 0, 0,
-	0, 0, 999, 1000, 1001, 0, 0, 0, 0, 0,
-	354, 0, 0, 0, 0, 0, 0, 0, 549, 839,
-	552, 0, 1195, 1197, 0, 0, 566, 567, 568, 569,
-	570, 571, 572, 0, 550, 551, 548, 0, 554, 553,
-	563, 564, 556, 557, 558, 559, 560, 561, 562, 555,
-	1197, 699, 565, 0, 0, 627, 0, 637, 0, 0,
-	0, 0, 0, 1424, 590, 354, 0, 354, 1227, 496,
-	497, 276, 498, 499, 345, 500, 1277, 503, 0, 219,
-	0, 219, 0, 0, 1156, 0, 513, 0, 0, 0,
-	0, 219, 0, 0, 219, 0, 0, 0, 0, 0,
-	219, 1329, 896, 219, 0, 554, 553, 563, 564, 556,
-	557, 558, 559, 560, 561, 562, 555, 0, 1251, 565,
-	0, 1256, 1257, 0, 0, 0, 1457, 0, 0, 354,
-	0, 0, 0, 0, 0, 0, 1462, 0, 0, 0,
-	0, 0, 0, 0, 63, 633, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 554, 553, 563, 564, 556,
-	557, 558, 559, 560, 561, 562, 555, 0, 0, 565,
-	0, 0, 0, 0, 661, 1276, 0, 0, 0, 0,
-	839, 0, 0, 0, 717, 718, 0, 0, 0, 0,
-	724, 0, 1064, 343, 0, 0, 730, 0, 0, 0,
+	297, 294, 1584, 1573, 1546, 1346, 1210, 1106, 1521, 623,
+	947, 1467, 1429, 1416, 1137, 1277, 315, 922, 1320, 64,
+	272, 1131, 1107, 1129, 60, 68, 1278, 990, 569, 664,
+	915, 976, 1274, 917, 303, 219, 943, 1027, 329, 68,
+	1143, 956, 68, 946, 1060, 1164, 264, 1284, 861, 1290,
+	810, 673, 1190, 1181, 687, 785, 824, 904, 563, 301,
+	850, 1239, 1127, 883, 544, 550, 686, 362, 622, 3,
+	960, 897, 482, 864, 367, 986, 299, 665, 559, 284,
+	364, 676, 638, 59, 1240, 1577, 1552, 514, 26, 639,
+	231, 863, 867, 373, 26, 265, 266, 267, 359, 1496,
+	270, 26, 584, 583, 593, 594, 586, 587, 588, 589,
+	590, 591, 592, 585, 597, 262, 596, 595, 1571, 1534,
+	1567, 1101, 1242, 271, 1347, 1551, 1102, 1533, 26, 584,
+	583, 593, 594, 586, 587, 588, 589, 590, 591, 592,
+	585, 597, 198, 596, 595, 1265, 57, 1380, 63, 1250,
+	1251, 564, 57, 1254, 1253, 1252, 1244, 811, 1248, 57,
+	1243, 490, 1314, 1241, 1258, 1255, 1449, 937, 1246, 1257,
+	200, 201, 202, 203, 204, 205, 342, 1245, 348, 349,
+	346, 347, 345, 344, 343, 269, 57, 1315, 1316, 1172,
+	1247, 1249, 688, 516, 689, 350, 351, 229, 225, 537,
+	226, 227, 938, 939, 68, 219, 219, 533, 1152, 268,
+	68, 1151, 969, 68, 1153, 534, 531, 532, 1419, 501,
+	977, 263, 68, 1437, 1065, 68, 907, 910, 911, 912,
+	908, 68, 909, 914, 68, 486, 1291, 1292, 219, 854,
+	219, 219, 220, 219, 219, 1256, 219, 221, 219, 223,
+	1370, 1368, 261, 526, 527, 361, 1213, 219, 760, 536,
+	23, 484, 1212, 518, 489, 758, 520, 1010, 1527, 970,
+	1569, 1563, 1522, 494, 288, 1209, 500, 68, 898, 1514,
+	961, 1497, 507, 1468, 1592, 509, 479, 485, 1197, 502,
+	1009, 219, 370, 370, 759, 487, 1470, 517, 519, 1588,
+	223, 1214, 764, 555, 963, 1476, 611, 612, 613, 614,
+	615, 616, 617, 1064, 619, 540, 541, 1067, 1195, 1014,
+	963, 753, 228, 1138, 1140, 1309, 1308, 1307, 1008, 488,
+	1206, 761, 539, 607, 907, 910, 911, 912, 908, 1208,
+	909, 914, 493, 224, 552, 222, 609, 610, 543, 556,
+	1165, 1532, 1021, 234, 1503, 1020, 1392, 68, 68, 68,
+	977, 487, 1148, 1091, 963, 794, 219, 24, 1081, 1469,
+	682, 621, 219, 24, 573, 1078, 508, 596, 595, 944,
+	24, 618, 1005, 1002, 1003, 1332, 1001, 515, 933, 786,
+	1196, 663, 668, 791, 483, 1201, 1198, 1191, 1199, 1194,
+	568, 498, 483, 1192, 1193, 1139, 962, 24, 662, 1512,
+	674, 1586, 1477, 1475, 1587, 913, 1585, 1485, 1200, 1012,
+	1015, 233, 962, 57, 557, 553, 641, 643, 645, 647,
+	649, 651, 652, 642, 644, 481, 648, 650, 566, 653,
+	1333, 1207, 680, 1205, 356, 357, 684, 504, 505, 506,
+	491, 492, 1288, 370, 568, 279, 1007, 675, 907, 910,
+	911, 912, 908, 1302, 909, 914, 962, 1029, 495, 690,
+	496, 959, 957, 497, 958, 1267, 567, 566, 1006, 955,
+	961, 787, 68, 1269, 609, 610, 597, 68, 596, 595,
+	219, 609, 610, 568, 68, 219, 884, 585, 597, 68,
+	596, 595, 68, 208, 966, 68, 1076, 1490, 1075, 68,
+	967, 219, 219, 1170, 750, 832, 219, 219, 219, 68,
+	219, 219, 1011, 913, 1517, 567, 566, 219, 219, 829,
+	830, 831, 828, 698, 884, 543, 1088, 561, 749, 209,
+	1013, 1538, 568, 567, 566, 756, 1050, 1051, 1052, 1540,
+	762, 567, 566, 361, 793, 1028, 768, 1593, 1425, 219,
+	568, 567, 566, 68, 797, 798, 1424, 1185, 568, 57,
+	779, 219, 1358, 1184, 219, 1077, 800, 823, 568, 827,
+	833, 834, 835, 836, 837, 838, 839, 840, 841, 842,
+	843, 844, 845, 846, 847, 848, 849, 1594, 853, 820,
+	765, 792, 773, 1173, 588, 589, 590, 591, 592, 585,
+	597, 826, 596, 595, 809, 825, 567, 566, 567, 566,
+	219, 1513, 219, 799, 1444, 1422, 567, 566, 859, 567,
+	566, 1217, 851, 568, 852, 568, 1182, 802, 219, 816,
+	818, 819, 890, 568, 1510, 817, 568, 913, 318, 317,
+	320, 321, 322, 323, 1349, 219, 219, 319, 324, 1473,
+	1568, 822, 68, 1154, 1165, 1155, 1542, 543, 1473, 1525,
+	68, 1473, 543, 543, 68, 1473, 1504, 68, 68, 1473,
+	1472, 68, 68, 68, 219, 1160, 856, 857, 1414, 1413,
+	874, 877, 855, 924, 869, 770, 885, 219, 1394, 543,
+	1391, 543, 1357, 543, 668, 769, 894, 1339, 1338, 865,
+	928, 668, 881, 899, 930, 668, 1335, 1336, 870, 871,
+	1335, 1334, 876, 879, 880, 1070, 543, 1228, 543, 929,
+	901, 543, 867, 543, 57, 755, 754, 889, 751, 697,
+	696, 1144, 510, 978, 979, 980, 926, 503, 893, 931,
+	895, 896, 935, 1482, 68, 219, 219, 678, 934, 219,
+	219, 68, 68, 1481, 68, 68, 1329, 964, 68, 219,
+	951, 370, 678, 61, 1275, 992, 773, 1287, 1557, 1287,
+	330, 54, 900, 1224, 948, 68, 1144, 68, 68, 927,
+	68, 677, 867, 1387, 901, 219, 586, 587, 588, 589,
+	590, 591, 592, 585, 597, 997, 596, 595, 901, 679,
+	1484, 681, 1018, 1019, 901, 1022, 1023, 993, 1337, 1024,
+	988, 989, 1301, 1070, 679, 1156, 677, 54, 936, 1095,
+	1094, 1056, 1057, 1058, 1059, 54, 1026, 1070, 1070, 1287,
+	677, 1032, 584, 583, 593, 594, 586, 587, 588, 589,
+	590, 591, 592, 585, 597, 683, 596, 595, 795, 763,
+	281, 1036, 276, 826, 1553, 1040, 1431, 825, 1549, 1548,
+	971, 1399, 1049, 991, 1325, 1291, 1292, 1579, 1159, 1062,
+	1053, 987, 822, 982, 981, 1055, 1211, 996, 1574, 1327,
+	1294, 1275, 1186, 789, 767, 1121, 1119, 1061, 808, 1046,
+	1122, 1120, 68, 68, 68, 68, 68, 68, 1547, 1117,
+	1123, 1299, 911, 912, 1118, 1298, 68, 1297, 57, 68,
+	1116, 1115, 285, 286, 1561, 1550, 68, 1219, 68, 1033,
+	560, 1109, 1555, 1045, 1112, 1113, 668, 668, 668, 668,
+	668, 668, 1044, 545, 1177, 1142, 1087, 219, 695, 558,
+	511, 1169, 1519, 668, 1069, 1518, 1447, 1157, 1167, 1161,
+	1108, 1145, 668, 1385, 1427, 546, 1103, 1220, 1221, 1146,
+	1038, 1147, 1124, 1085, 994, 280, 972, 973, 974, 975,
+	1110, 1111, 766, 1135, 1114, 916, 748, 869, 282, 283,
+	560, 1149, 983, 984, 985, 277, 1570, 219, 219, 1565,
+	1559, 1558, 1043, 273, 1174, 1175, 1166, 1488, 1489, 542,
+	1042, 274, 61, 1176, 1433, 1178, 1179, 1180, 513, 1144,
+	513, 513, 535, 513, 513, 219, 513, 1092, 513, 1162,
+	1163, 1581, 1580, 199, 948, 1082, 1183, 513, 1079, 784,
+	562, 1581, 68, 1500, 54, 1420, 790, 1189, 195, 196,
+	197, 58, 1, 1572, 1348, 1202, 54, 1428, 554, 1004,
+	1520, 54, 1466, 1234, 1319, 1235, 954, 1236, 945, 207,
+	480, 206, 1511, 953, 952, 1474, 606, 1418, 965, 608,
+	1261, 1262, 1216, 1263, 1264, 1171, 968, 1326, 1168, 1516,
+	703, 701, 219, 1222, 702, 700, 1272, 1273, 705, 704,
+	699, 1231, 1232, 1230, 1229, 219, 219, 246, 620, 1276,
+	1266, 625, 626, 627, 628, 629, 630, 631, 632, 633,
+	365, 636, 637, 640, 640, 640, 646, 640, 640, 646,
+	640, 654, 655, 656, 657, 658, 659, 1259, 669, 1260,
+	771, 1279, 219, 691, 1238, 995, 565, 1286, 211, 1204,
+	1203, 1000, 529, 530, 248, 605, 1041, 219, 1150, 219,
+	219, 1108, 1295, 1296, 371, 1282, 1545, 1526, 1311, 1318,
+	1037, 1328, 796, 1281, 549, 1487, 1432, 1086, 634, 1270,
+	882, 328, 302, 815, 316, 313, 1313, 68, 314, 1310,
+	803, 1100, 575, 300, 292, 667, 660, 906, 327, 903,
+	1317, 905, 1322, 902, 68, 360, 1293, 1289, 1323, 1324,
+	219, 1330, 1331, 219, 219, 68, 1128, 666, 1223, 1225,
+	1048, 1379, 1495, 807, 28, 68, 194, 287, 19, 18,
+	17, 21, 20, 217, 16, 1361, 15, 1341, 1340, 14,
+	499, 33, 22, 13, 948, 12, 948, 11, 10, 1342,
+	9, 1344, 8, 7, 1353, 1343, 6, 5, 1355, 668,
+	4, 62, 275, 278, 1354, 25, 1352, 2, 0, 0,
+	513, 0, 1366, 0, 0, 513, 583, 593, 594, 586,
+	587, 588, 589, 590, 591, 592, 585, 597, 1359, 596,
+	595, 513, 513, 0, 801, 0, 513, 513, 513, 0,
+	513, 513, 0, 0, 68, 0, 0, 513, 513, 1386,
+	1396, 1395, 0, 0, 0, 0, 0, 0, 219, 0,
+	0, 0, 0, 0, 1403, 1402, 219, 0, 1157, 0,
+	290, 1401, 1412, 1404, 0, 54, 1406, 1407, 668, 0,
+	0, 219, 0, 0, 0, 812, 0, 1108, 219, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 219,
+	866, 868, 0, 0, 1405, 0, 1421, 0, 1423, 0,
+	0, 0, 0, 0, 0, 0, 1438, 1439, 1440, 1441,
+	1442, 0, 0, 0, 0, 1445, 1446, 1435, 0, 54,
+	0, 0, 219, 219, 0, 219, 1436, 0, 0, 0,
+	219, 512, 68, 372, 372, 948, 625, 68, 68, 219,
+	219, 219, 68, 1456, 1448, 219, 1455, 0, 0, 0,
+	0, 0, 1462, 1463, 1464, 0, 1279, 0, 0, 1465,
+	0, 924, 219, 1471, 0, 1430, 372, 1478, 372, 372,
+	0, 372, 372, 0, 372, 1486, 372, 0, 0, 0,
+	918, 919, 920, 921, 0, 372, 0, 669, 1479, 1450,
+	1480, 669, 0, 0, 0, 1501, 0, 0, 0, 0,
+	0, 0, 0, 0, 219, 0, 0, 0, 1509, 0,
+	1508, 0, 0, 0, 0, 219, 219, 0, 1279, 571,
+	0, 219, 0, 0, 0, 0, 1524, 1523, 0, 1529,
+	0, 0, 0, 0, 219, 0, 0, 0, 1535, 0,
+	1363, 1364, 0, 1365, 0, 68, 1367, 0, 1369, 1530,
+	1502, 0, 0, 219, 0, 0, 0, 0, 1371, 54,
+	0, 0, 1544, 0, 0, 513, 513, 1039, 0, 0,
+	0, 243, 0, 0, 0, 0, 0, 0, 0, 513,
+	1556, 1554, 0, 0, 219, 0, 0, 0, 0, 0,
+	1108, 0, 1564, 0, 372, 256, 1539, 1562, 0, 0,
+	692, 0, 1430, 948, 0, 0, 1578, 1582, 0, 0,
+	0, 0, 0, 0, 1589, 0, 0, 0, 0, 0,
+	0, 1415, 1047, 1066, 0, 0, 0, 0, 0, 1068,
+	0, 0, 0, 1054, 547, 551, 0, 1072, 1073, 1074,
+	0, 0, 0, 0, 1080, 0, 0, 1083, 1084, 0,
+	235, 0, 0, 1090, 574, 0, 0, 238, 1093, 0,
+	0, 1096, 1097, 1098, 1099, 247, 0, 242, 0, 0,
+	0, 521, 522, 0, 523, 524, 0, 525, 0, 528,
+	0, 0, 0, 0, 0, 1126, 0, 0, 538, 0,
+	0, 624, 0, 0, 0, 0, 0, 0, 0, 245,
+	635, 0, 0, 0, 0, 255, 0, 0, 1104, 1105,
+	0, 0, 669, 669, 669, 669, 669, 669, 372, 0,
+	0, 0, 0, 372, 0, 0, 0, 0, 0, 918,
+	0, 0, 236, 0, 1141, 0, 0, 0, 669, 372,
+	372, 0, 0, 0, 372, 372, 372, 0, 372, 372,
+	0, 0, 0, 0, 0, 372, 372, 0, 0, 249,
+	239, 240, 0, 250, 251, 252, 254, 0, 253, 259,
+	0, 0, 0, 241, 244, 0, 237, 258, 257, 543,
+	1377, 0, 0, 0, 0, 0, 0, 804, 593, 594,
+	586, 587, 588, 589, 590, 591, 592, 585, 597, 571,
+	596, 595, 372, 0, 0, 0, 0, 0, 513, 0,
+	0, 0, 0, 0, 0, 0, 1227, 0, 0, 584,
+	583, 593, 594, 586, 587, 588, 589, 590, 591, 592,
+	585, 597, 0, 596, 595, 513, 0, 1237, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 860, 0,
+	372, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 886, 888, 584, 583, 593,
+	594, 586, 587, 588, 589, 590, 591, 592, 585, 597,
+	0, 596, 595, 891, 892, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 1300, 788, 0, 0, 0,
+	0, 0, 0, 1305, 1306, 0, 0, 0, 0, 0,
+	0, 720, 372, 1280, 0, 54, 0, 0, 0, 1384,
+	0, 752, 0, 1376, 0, 372, 757, 813, 814, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 620, 774, 775, 1303, 1304, 0, 776, 777, 778,
+	0, 780, 781, 0, 0, 0, 0, 0, 782, 783,
+	584, 583, 593, 594, 586, 587, 588, 589, 590, 591,
+	592, 585, 597, 0, 596, 595, 0, 0, 0, 624,
+	0, 0, 0, 372, 372, 0, 624, 1016, 1017, 872,
+	873, 0, 0, 1356, 708, 0, 0, 372, 1360, 0,
+	0, 0, 0, 0, 0, 0, 0, 1362, 0, 0,
+	584, 583, 593, 594, 586, 587, 588, 589, 590, 591,
+	592, 585, 597, 372, 596, 595, 0, 1372, 1373, 0,
+	0, 0, 721, 0, 0, 669, 0, 0, 0, 1382,
+	0, 886, 0, 1375, 0, 0, 0, 0, 1388, 1389,
+	1390, 942, 1393, 0, 0, 734, 737, 738, 739, 740,
+	741, 742, 0, 743, 744, 745, 746, 747, 722, 723,
+	724, 725, 0, 706, 707, 735, 1378, 709, 1411, 710,
+	711, 712, 713, 714, 715, 716, 717, 718, 719, 726,
+	727, 728, 729, 730, 731, 732, 733, 584, 583, 593,
+	594, 586, 587, 588, 589, 590, 591, 592, 585, 597,
+	0, 596, 595, 0, 669, 0, 0, 0, 1408, 1409,
+	1410, 0, 0, 0, 0, 0, 0, 0, 0, 886,
+	584, 583, 593, 594, 586, 587, 588, 589, 590, 591,
+	592, 585, 597, 0, 596, 595, 0, 1443, 1130, 1130,
+	0, 513, 0, 1034, 1035, 736, 551, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 1458, 1459, 0, 372, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 548, 998, 999, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 1280, 0,
+	1025, 1451, 0, 0, 0, 0, 1491, 1492, 1493, 1494,
+	65, 0, 0, 1498, 1499, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 232, 1187, 372, 260, 1071, 0,
+	1505, 1506, 1507, 0, 0, 0, 0, 0, 1483, 0,
+	0, 0, 0, 0, 0, 1089, 0, 0, 1383, 0,
+	0, 0, 0, 372, 0, 0, 0, 0, 0, 0,
+	1280, 0, 54, 1531, 0, 0, 0, 0, 0, 0,
+	1536, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	1132, 1132, 1136, 0, 0, 0, 0, 0, 1541, 584,
+	583, 593, 594, 586, 587, 588, 589, 590, 591, 592,
+	585, 597, 0, 596, 595, 0, 0, 26, 27, 55,
+	29, 30, 0, 0, 0, 0, 0, 0, 0, 0,
+	372, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	886, 0, 47, 1283, 1285, 0, 0, 0, 0, 0,
+	31, 32, 51, 52, 0, 0, 0, 0, 0, 0,
+	1590, 1591, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 41, 0, 0, 0, 57, 0, 0, 0, 0,
+	1285, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	1575, 0, 0, 0, 0, 372, 291, 372, 1321, 363,
+	1218, 0, 0, 0, 0, 232, 0, 1374, 232, 0,
+	0, 0, 0, 0, 0, 0, 0, 232, 1226, 0,
+	232, 0, 0, 0, 0, 0, 232, 0, 0, 232,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 1188,
+	34, 35, 37, 36, 39, 0, 53, 0, 1345, 0,
+	0, 1350, 1351, 0, 0, 0, 0, 0, 0, 1268,
+	0, 0, 0, 1271, 0, 0, 1215, 886, 40, 48,
+	46, 0, 65, 49, 50, 38, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+	43, 0, 44, 45, 584, 583, 593, 594, 586, 587,
+	588, 589, 590, 591, 592, 585, 597, 0, 596, 595,
+	0, 0, 0, 0, 0, 1312, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 886, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	1130, 0, 0, 886, 0, 0, 0, 0, 0, 0,
+	0, 0, 232, 232, 232, 0, 372, 0, 0, 0,
+	0, 0, 0, 0, 1417, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 372,
+	56, 0, 0, 0, 0, 0, 372, 0, 0, 0,
+	577, 0, 582, 0, 0, 0, 24, 571, 598, 599,
+	600, 601, 602, 603, 604, 0, 578, 579, 581, 576,
+	0, 580, 584, 583, 593, 594, 586, 587, 588, 589,
+	590, 591, 592, 585, 597, 0, 596, 595, 0, 0,
+	1452, 1453, 0, 1454, 0, 0, 0, 0, 1417, 0,
+	1381, 0, 0, 0, 0, 0, 0, 1417, 1417, 1417,
+	0, 624, 0, 1321, 0, 0, 0, 0, 0, 1397,
+	0, 0, 1398, 0, 0, 1400, 0, 0, 0, 0,
+	1417, 0, 1132, 1132, 0, 0, 0, 232, 0, 0,
+	0, 0, 232, 0, 0, 0, 0, 0, 0, 232,
+	0, 0, 0, 0, 232, 0, 0, 232, 0, 0,
+	232, 0, 0, 0, 772, 0, 0, 0, 0, 0,
+	0, 0, 1515, 0, 232, 0, 0, 0, 0, 0,
+	0, 0, 1233, 372, 372, 0, 0, 0, 1434, 571,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 886,
+	1063, 0, 1537, 0, 0, 584, 583, 593, 594, 586,
+	587, 588, 589, 590, 591, 592, 585, 597, 232, 596,
+	595, 1543, 0, 584, 583, 593, 594, 586, 587, 588,
+	589, 590, 591, 592, 585, 597, 0, 596, 595, 0,
+	0, 0, 1426, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 1417, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 291, 0, 0, 0,
+	0, 0, 291, 291, 0, 0, 291, 291, 291, 0,
+	0, 0, 887, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 291, 291, 291, 291, 0, 232, 0, 1528,
+	624, 0, 0, 0, 0, 232, 0, 0, 0, 65,
+	0, 0, 232, 232, 0, 0, 232, 932, 772, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	1560, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	1566, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 1576, 0, 0, 0, 0, 0, 0, 0, 232,
+	0, 0, 0, 0, 0, 0, 232, 232, 0, 232,
+	232, 0, 0, 232, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	232, 0, 1030, 1031, 0, 232, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 291, 0, 0, 0, 0, 887, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 291, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 291, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 887, 232, 232, 232,
+	232, 232, 232, 0, 0, 0, 0, 0, 0, 0,
+	0, 1125, 0, 0, 232, 0, 0, 0, 0, 0,
+	0, 65, 0, 232, 0, 136, 0, 189, 92, 88,
+	69, 72, 117, 0, 0, 0, 0, 0, 0, 134,
+	99, 115, 0, 94, 0, 0, 0, 119, 0, 0,
+	113, 0, 116, 0, 0, 157, 127, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 218, 0, 0, 0, 0, 0,
+	0, 0, 0, 85, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 584, 583, 593, 594, 586, 587, 588,
+	589, 590, 591, 592, 585, 597, 0, 596, 595, 0,
+	0, 0, 0, 0, 0, 0, 0, 232, 0, 0,
+	0, 0, 102, 0, 0, 0, 0, 179, 0, 0,
+	291, 0, 143, 0, 160, 104, 112, 71, 79, 0,
+	103, 133, 148, 152, 0, 0, 291, 89, 0, 150,
+	138, 172, 0, 0, 139, 149, 118, 165, 144, 0,
+	180, 181, 162, 178, 188, 73, 161, 171, 86, 153,
+	75, 169, 159, 125, 108, 109, 74, 887, 147, 93,
+	100, 91, 135, 166, 167, 90, 191, 193, 80, 177,
+	77, 81, 176, 132, 164, 170, 126, 123, 76, 168,
+	124, 122, 111, 96, 105, 141, 121, 142, 106, 129,
+	128, 130, 0, 0, 0, 158, 174, 192, 83, 0,
+	154, 163, 182, 183, 184, 185, 186, 187, 0, 0,
+	84, 101, 95, 140, 131, 82, 107, 155, 110, 120,
+	146, 190, 137, 151, 87, 173, 156, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 97, 232, 0, 0, 0, 0, 0, 0, 70,
+	78, 114, 0, 145, 98, 175, 0, 0, 0, 232,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	232, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	232, 0, 0, 0, 887, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 887, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 232,
+	887, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 1457, 0, 0,
+	0, 0, 1460, 1461, 0, 465, 0, 65, 453, 0,
+	424, 469, 400, 415, 477, 416, 417, 446, 386, 432,
+	136, 413, 189, 92, 88, 69, 72, 117, 0, 403,
+	380, 409, 381, 401, 134, 99, 115, 426, 94, 429,
+	399, 455, 119, 435, 467, 113, 475, 116, 440, 0,
+	157, 127, 0, 0, 428, 457, 430, 451, 423, 447,
+	391, 439, 470, 414, 444, 471, 0, 0, 0, 218,
+	0, 949, 950, 0, 0, 0, 0, 0, 85, 0,
+	442, 464, 412, 443, 445, 378, 441, 0, 384, 387,
+	476, 459, 406, 407, 1158, 0, 887, 0, 0, 0,
+	0, 427, 431, 420, 448, 421, 0, 0, 0, 0,
+	232, 0, 0, 0, 0, 0, 404, 0, 438, 0,
+	382, 0, 0, 388, 385, 0, 0, 425, 0, 0,
+	0, 390, 0, 405, 449, 0, 377, 102, 452, 458,
+	0, 422, 179, 462, 419, 418, 466, 143, 0, 160,
+	104, 112, 71, 79, 0, 103, 133, 148, 152, 456,
+	402, 410, 89, 408, 150, 138, 172, 411, 437, 139,
+	149, 118, 165, 144, 463, 180, 181, 162, 178, 188,
+	73, 161, 171, 86, 153, 75, 169, 159, 125, 108,
+	109, 74, 0, 147, 93, 100, 91, 135, 166, 167,
+	90, 191, 193, 80, 177, 77, 81, 176, 132, 164,
+	170, 126, 123, 76, 168, 124, 122, 111, 96, 105,
+	141, 121, 142, 106, 129, 128, 130, 0, 383, 0,
+	158, 174, 192, 83, 398, 154, 163, 182, 183, 184,
+	185, 186, 187, 0, 0, 84, 101, 95, 140, 131,
+	82, 107, 155, 110, 120, 146, 190, 137, 151, 87,
+	173, 156, 394, 397, 392, 393, 433, 434, 472, 473,
+	474, 450, 389, 0, 379, 468, 97, 395, 396, 0,
+	454, 460, 461, 436, 70, 78, 114, 478, 145, 98,
+	175, 465, 0, 0, 453, 0, 424, 469, 400, 415,
+	477, 416, 417, 446, 386, 432, 136, 413, 189, 92,
+	88, 69, 72, 117, 0, 403, 380, 409, 381, 401,
+	134, 99, 115, 426, 94, 429, 399, 455, 119, 435,
+	467, 113, 475, 116, 440, 0, 157, 127, 0, 0,
+	428, 457, 430, 451, 423, 447, 391, 439, 470, 414,
+	444, 471, 0, 0, 0, 218, 0, 949, 950, 0,
+	0, 0, 0, 0, 85, 0, 442, 464, 412, 443,
+	445, 378, 441, 0, 384, 387, 476, 459, 406, 407,
+	0, 0, 0, 0, 0, 0, 0, 427, 431, 420,
+	448, 421, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 404, 0, 438, 0, 382, 0, 0, 388,
+	385, 0, 0, 425, 0, 0, 0, 390, 0, 405,
+	449, 0, 377, 102, 452, 458, 0, 422, 179, 462,
+	419, 418, 466, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 456, 402, 410, 89, 408,
+	150, 138, 172, 411, 437, 139, 149, 118, 165, 144,
+	463, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 81, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 383, 0, 158, 174, 192, 83,
+	398, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 131, 82, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 394, 397,
+	392, 393, 433, 434, 472, 473, 474, 450, 389, 0,
+	379, 468, 97, 395, 396, 0, 454, 460, 461, 436,
+	70, 78, 114, 478, 145, 98, 175, 465, 0, 0,
+	453, 0, 424, 469, 400, 415, 477, 416, 417, 446,
+	386, 432, 136, 413, 189, 92, 88, 69, 72, 117,
+	0, 403, 380, 409, 381, 401, 134, 99, 115, 426,
+	94, 429, 399, 455, 119, 435, 467, 113, 475, 116,
+	440, 0, 157, 127, 0, 0, 428, 457, 430, 451,
+	423, 447, 391, 439, 470, 414, 444, 471, 57, 0,
+	0, 218, 0, 0, 0, 0, 0, 0, 0, 0,
+	85, 0, 442, 464, 412, 443, 445, 378, 441, 0,
+	384, 387, 476, 459, 406, 407, 0, 0, 0, 0,
+	0, 0, 0, 427, 431, 420, 448, 421, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 404, 0,
+	438, 0, 382, 0, 0, 388, 385, 0, 0, 425,
+	0, 0, 0, 390, 0, 405, 449, 0, 377, 102,
+	452, 458, 0, 422, 179, 462, 419, 418, 466, 143,
+	0, 160, 104, 112, 71, 79, 0, 103, 133, 148,
+	152, 456, 402, 410, 89, 408, 150, 138, 172, 411,
+	437, 139, 149, 118, 165, 144, 463, 180, 181, 162,
+	178, 188, 73, 161, 171, 86, 153, 75, 169, 159,
+	125, 108, 109, 74, 0, 147, 93, 100, 91, 135,
+	166, 167, 90, 191, 193, 80, 177, 77, 81, 176,
+	132, 164, 170, 126, 123, 76, 168, 124, 122, 111,
+	96, 105, 141, 121, 142, 106, 129, 128, 130, 0,
+	383, 0, 158, 174, 192, 83, 398, 154, 163, 182,
+	183, 184, 185, 186, 187, 0, 0, 84, 101, 95,
+	140, 131, 82, 107, 155, 110, 120, 146, 190, 137,
+	151, 87, 173, 156, 394, 397, 392, 393, 433, 434,
+	472, 473, 474, 450, 389, 0, 379, 468, 97, 395,
+	396, 0, 454, 460, 461, 436, 70, 78, 114, 478,
+	145, 98, 175, 465, 0, 0, 453, 0, 424, 469,
+	400, 415, 477, 416, 417, 446, 386, 432, 136, 413,
+	189, 92, 88, 69, 72, 117, 0, 403, 380, 409,
+	381, 401, 134, 99, 115, 426, 94, 429, 399, 455,
+	119, 435, 467, 113, 475, 116, 440, 0, 157, 127,
+	0, 0, 428, 457, 430, 451, 423, 447, 391, 439,
+	470, 414, 444, 471, 0, 0, 0, 67, 0, 0,
+	0, 0, 0, 0, 0, 0, 85, 0, 442, 464,
+	412, 443, 445, 378, 441, 0, 384, 387, 476, 459,
+	406, 407, 0, 0, 0, 0, 0, 0, 0, 427,
+	431, 420, 448, 421, 0, 0, 0, 0, 0, 0,
+	0, 0, 933, 0, 404, 0, 438, 0, 382, 0,
+	0, 388, 385, 0, 0, 425, 0, 0, 0, 390,
+	0, 405, 449, 0, 377, 102, 452, 458, 0, 422,
+	179, 462, 419, 418, 466, 143, 0, 160, 104, 112,
+	71, 79, 0, 103, 133, 148, 152, 456, 402, 410,
+	89, 408, 150, 138, 172, 411, 437, 139, 149, 118,
+	165, 144, 463, 180, 181, 162, 178, 188, 73, 161,
+	171, 86, 153, 75, 169, 159, 125, 108, 109, 74,
+	0, 147, 93, 100, 91, 135, 166, 167, 90, 191,
+	193, 80, 177, 77, 81, 176, 132, 164, 170, 126,
+	123, 76, 168, 124, 122, 111, 96, 105, 141, 121,
+	142, 106, 129, 128, 130, 0, 383, 0, 158, 174,
+	192, 83, 398, 154, 163, 182, 183, 184, 185, 186,
+	187, 0, 0, 84, 101, 95, 140, 131, 82, 107,
+	155, 110, 120, 146, 190, 137, 151, 87, 173, 156,
+	394, 397, 392, 393, 433, 434, 472, 473, 474, 450,
+	389, 0, 379, 468, 97, 395, 396, 0, 454, 460,
+	461, 436, 70, 78, 114, 478, 145, 98, 175, 465,
+	0, 0, 453, 0, 424, 469, 400, 415, 477, 416,
+	417, 446, 386, 432, 136, 413, 189, 92, 88, 69,
+	72, 117, 0, 403, 380, 409, 381, 401, 134, 99,
+	115, 426, 94, 429, 399, 455, 119, 435, 467, 113,
+	475, 116, 440, 0, 157, 127, 0, 0, 428, 457,
+	430, 451, 423, 447, 391, 439, 470, 414, 444, 471,
+	0, 0, 0, 218, 0, 0, 0, 0, 0, 0,
+	0, 0, 85, 0, 442, 464, 412, 443, 445, 378,
+	441, 0, 384, 387, 476, 459, 406, 407, 0, 0,
+	0, 0, 0, 0, 0, 427, 431, 420, 448, 421,
+	0, 0, 0, 0, 0, 0, 0, 0, 821, 0,
+	404, 0, 438, 0, 382, 0, 0, 388, 385, 0,
+	0, 425, 0, 0, 0, 390, 0, 405, 449, 0,
+	377, 102, 452, 458, 0, 422, 179, 462, 419, 418,
+	466, 143, 0, 160, 104, 112, 71, 79, 0, 103,
+	133, 148, 152, 456, 402, 410, 89, 408, 150, 138,
+	172, 411, 437, 139, 149, 118, 165, 144, 463, 180,
+	181, 162, 178, 188, 73, 161, 171, 86, 153, 75,
+	169, 159, 125, 108, 109, 74, 0, 147, 93, 100,
+	91, 135, 166, 167, 90, 191, 193, 80, 177, 77,
+	81, 176, 132, 164, 170, 126, 123, 76, 168, 124,
+	122, 111, 96, 105, 141, 121, 142, 106, 129, 128,
+	130, 0, 383, 0, 158, 174, 192, 83, 398, 154,
+	163, 182, 183, 184, 185, 186, 187, 0, 0, 84,
+	101, 95, 140, 131, 82, 107, 155, 110, 120, 146,
+	190, 137, 151, 87, 173, 156, 394, 397, 392, 393,
+	433, 434, 472, 473, 474, 450, 389, 0, 379, 468,
+	97, 395, 396, 0, 454, 460, 461, 436, 70, 78,
+	114, 478, 145, 98, 175, 465, 0, 0, 453, 0,
+	424, 469, 400, 415, 477, 416, 417, 446, 386, 432,
+	136, 413, 189, 92, 88, 69, 72, 117, 0, 403,
+	380, 409, 381, 401, 134, 99, 115, 426, 94, 429,
+	399, 455, 119, 435, 467, 113, 475, 116, 440, 0,
+	157, 127, 0, 0, 428, 457, 430, 451, 423, 447,
+	391, 439, 470, 414, 444, 471, 0, 0, 0, 218,
+	0, 0, 0, 0, 0, 0, 0, 0, 85, 0,
+	442, 464, 412, 443, 445, 378, 441, 0, 384, 387,
+	476, 459, 406, 407, 0, 0, 0, 0, 0, 0,
+	0, 427, 431, 420, 448, 421, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 404, 0, 438, 0,
+	382, 0, 0, 388, 385, 0, 0, 425, 0, 0,
+	0, 390, 0, 405, 449, 0, 377, 102, 452, 458,
+	0, 422, 179, 462, 419, 418, 466, 143, 0, 160,
+	104, 112, 71, 79, 0, 103, 133, 148, 152, 456,
+	402, 410, 89, 408, 150, 138, 172, 411, 437, 139,
+	149, 118, 165, 144, 463, 180, 181, 162, 178, 188,
+	73, 161, 171, 86, 153, 75, 169, 159, 125, 108,
+	109, 74, 0, 147, 93, 100, 91, 135, 166, 167,
+	90, 191, 193, 80, 177, 77, 81, 176, 132, 164,
+	170, 126, 123, 76, 168, 124, 122, 111, 96, 105,
+	141, 121, 142, 106, 129, 128, 130, 0, 383, 0,
+	158, 174, 192, 83, 398, 154, 163, 182, 183, 184,
+	185, 186, 187, 0, 0, 84, 101, 95, 140, 131,
+	82, 107, 155, 110, 120, 146, 190, 137, 151, 87,
+	173, 156, 394, 397, 392, 393, 433, 434, 472, 473,
+	474, 450, 389, 0, 379, 468, 97, 395, 396, 0,
+	454, 460, 461, 436, 70, 78, 114, 478, 145, 98,
+	175, 465, 0, 0, 453, 0, 424, 469, 400, 415,
+	477, 416, 417, 446, 386, 432, 136, 413, 189, 92,
+	88, 69, 72, 117, 0, 403, 380, 409, 381, 401,
+	134, 99, 115, 426, 94, 429, 399, 455, 119, 435,
+	467, 113, 475, 116, 440, 0, 157, 127, 0, 0,
+	428, 457, 430, 451, 423, 447, 391, 439, 470, 414,
+	444, 471, 0, 0, 0, 218, 0, 0, 0, 0,
+	0, 0, 0, 0, 85, 0, 442, 464, 412, 443,
+	445, 378, 441, 0, 384, 387, 476, 459, 406, 407,
+	0, 0, 0, 0, 0, 0, 0, 427, 431, 420,
+	448, 421, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 404, 0, 438, 0, 382, 0, 0, 388,
+	385, 0, 0, 425, 0, 0, 0, 390, 0, 405,
+	449, 0, 377, 102, 452, 458, 0, 422, 179, 462,
+	419, 418, 466, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 456, 402, 410, 89, 408,
+	150, 138, 172, 411, 437, 139, 149, 118, 165, 144,
+	463, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 375, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 383, 0, 158, 174, 192, 83,
+	398, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 376, 374, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 394, 397,
+	392, 393, 433, 434, 472, 473, 474, 450, 389, 0,
+	379, 468, 97, 395, 396, 0, 454, 460, 461, 436,
+	70, 78, 114, 478, 145, 98, 175, 465, 0, 0,
+	453, 0, 424, 469, 400, 415, 477, 416, 417, 446,
+	386, 432, 136, 413, 189, 92, 88, 69, 72, 117,
+	0, 403, 380, 409, 381, 401, 134, 99, 115, 426,
+	94, 429, 399, 455, 119, 435, 467, 113, 475, 116,
+	440, 0, 157, 127, 0, 0, 428, 457, 430, 451,
+	423, 447, 391, 439, 470, 414, 444, 471, 0, 0,
+	0, 67, 0, 0, 0, 0, 0, 0, 0, 0,
+	85, 0, 442, 464, 412, 443, 445, 378, 441, 0,
+	384, 387, 476, 459, 406, 407, 0, 0, 0, 0,
+	0, 0, 0, 427, 431, 420, 448, 421, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 404, 0,
+	438, 0, 382, 0, 0, 388, 385, 0, 0, 425,
+	0, 0, 0, 390, 0, 405, 449, 0, 377, 102,
+	452, 458, 0, 422, 179, 462, 419, 418, 466, 143,
+	0, 160, 104, 112, 71, 79, 0, 103, 133, 148,
+	152, 456, 402, 410, 89, 408, 150, 138, 172, 411,
+	437, 139, 149, 118, 165, 144, 463, 180, 181, 162,
+	178, 188, 73, 161, 171, 86, 153, 75, 169, 159,
+	125, 108, 109, 74, 0, 147, 93, 100, 91, 135,
+	166, 167, 90, 191, 193, 80, 177, 77, 81, 176,
+	132, 164, 170, 126, 123, 76, 168, 124, 122, 111,
+	96, 105, 141, 121, 142, 106, 129, 128, 130, 0,
+	383, 0, 158, 174, 192, 83, 398, 154, 163, 182,
+	183, 184, 185, 186, 187, 0, 0, 84, 101, 95,
+	140, 131, 82, 107, 155, 110, 120, 146, 190, 137,
+	151, 87, 173, 156, 394, 397, 392, 393, 433, 434,
+	472, 473, 474, 450, 389, 0, 379, 468, 97, 395,
+	396, 0, 454, 460, 461, 436, 70, 78, 114, 478,
+	145, 98, 175, 465, 0, 0, 453, 0, 424, 469,
+	400, 415, 477, 416, 417, 446, 386, 432, 136, 413,
+	189, 92, 88, 69, 72, 117, 0, 403, 380, 409,
+	381, 401, 134, 99, 115, 426, 94, 429, 399, 455,
+	119, 435, 467, 113, 475, 116, 440, 0, 157, 127,
+	0, 0, 428, 457, 430, 451, 423, 447, 391, 439,
+	470, 414, 444, 471, 0, 0, 0, 218, 0, 0,
+	0, 0, 0, 0, 0, 0, 85, 0, 442, 464,
+	412, 443, 445, 378, 441, 0, 384, 387, 476, 459,
+	406, 407, 0, 0, 0, 0, 0, 0, 0, 427,
+	431, 420, 448, 421, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 404, 0, 438, 0, 382, 0,
+	0, 388, 385, 0, 0, 425, 0, 0, 0, 390,
+	0, 405, 449, 0, 377, 102, 452, 458, 0, 422,
+	179, 462, 419, 418, 466, 143, 0, 160, 104, 112,
+	71, 79, 0, 103, 133, 148, 152, 456, 402, 410,
+	89, 408, 150, 138, 172, 411, 437, 139, 149, 118,
+	165, 144, 463, 180, 181, 162, 178, 188, 73, 161,
+	685, 86, 153, 75, 169, 159, 125, 108, 109, 74,
+	0, 147, 93, 100, 91, 135, 166, 167, 90, 191,
+	193, 80, 177, 77, 375, 176, 132, 164, 170, 126,
+	123, 76, 168, 124, 122, 111, 96, 105, 141, 121,
+	142, 106, 129, 128, 130, 0, 383, 0, 158, 174,
+	192, 83, 398, 154, 163, 182, 183, 184, 185, 186,
+	187, 0, 0, 84, 101, 95, 140, 376, 374, 107,
+	155, 110, 120, 146, 190, 137, 151, 87, 173, 156,
+	394, 397, 392, 393, 433, 434, 472, 473, 474, 450,
+	389, 0, 379, 468, 97, 395, 396, 0, 454, 460,
+	461, 436, 70, 78, 114, 478, 145, 98, 175, 465,
+	0, 0, 453, 0, 424, 469, 400, 415, 477, 416,
+	417, 446, 386, 432, 136, 413, 189, 92, 88, 69,
+	72, 117, 0, 403, 380, 409, 381, 401, 134, 99,
+	115, 426, 94, 429, 399, 455, 119, 435, 467, 113,
+	475, 116, 440, 0, 157, 127, 0, 0, 428, 457,
+	430, 451, 423, 447, 391, 439, 470, 414, 444, 471,
+	0, 0, 0, 218, 0, 0, 0, 0, 0, 0,
+	0, 0, 85, 0, 442, 464, 412, 443, 445, 378,
+	441, 0, 384, 387, 476, 459, 406, 407, 0, 0,
+	0, 0, 0, 0, 0, 427, 431, 420, 448, 421,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	404, 0, 438, 0, 382, 0, 0, 388, 385, 0,
+	0, 425, 0, 0, 0, 390, 0, 405, 449, 0,
+	377, 102, 452, 458, 0, 422, 179, 462, 419, 418,
+	466, 143, 0, 160, 104, 112, 71, 79, 0, 103,
+	133, 148, 152, 456, 402, 410, 89, 408, 150, 138,
+	172, 411, 437, 139, 149, 118, 165, 144, 463, 180,
+	181, 162, 178, 188, 73, 161, 366, 86, 153, 75,
+	169, 159, 125, 108, 109, 74, 0, 147, 93, 100,
+	91, 135, 166, 167, 90, 191, 193, 80, 177, 77,
+	375, 176, 132, 164, 170, 126, 123, 76, 168, 124,
+	122, 111, 96, 105, 141, 121, 142, 106, 129, 128,
+	130, 0, 383, 0, 158, 174, 192, 83, 398, 154,
+	163, 182, 183, 184, 185, 186, 187, 0, 0, 84,
+	101, 95, 140, 376, 374, 369, 368, 110, 120, 146,
+	190, 137, 151, 87, 173, 156, 394, 397, 392, 393,
+	433, 434, 472, 473, 474, 450, 389, 0, 379, 468,
+	97, 395, 396, 0, 454, 460, 461, 436, 70, 78,
+	114, 478, 145, 98, 175, 136, 0, 189, 92, 88,
+	69, 72, 117, 0, 0, 0, 298, 0, 0, 134,
+	99, 115, 0, 94, 0, 295, 0, 119, 0, 0,
+	113, 340, 116, 0, 0, 157, 127, 0, 0, 0,
+	0, 331, 332, 0, 0, 0, 0, 0, 0, 0,
+	0, 57, 0, 0, 296, 318, 317, 320, 321, 322,
+	323, 0, 0, 85, 319, 324, 325, 326, 0, 0,
+	0, 293, 310, 0, 339, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 309, 0, 0, 0, 0, 307, 308, 0,
+	0, 0, 0, 354, 0, 0, 0, 0, 0, 304,
+	305, 306, 312, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 102, 0, 0, 0, 0, 179, 0, 0,
+	352, 0, 143, 0, 160, 104, 112, 71, 79, 0,
+	103, 133, 148, 152, 0, 0, 0, 89, 0, 150,
+	138, 172, 341, 0, 139, 149, 118, 165, 144, 0,
+	180, 181, 162, 178, 188, 73, 161, 171, 86, 153,
+	75, 169, 159, 125, 108, 109, 74, 0, 147, 93,
+	100, 91, 135, 166, 167, 90, 191, 193, 80, 177,
+	77, 81, 176, 132, 164, 170, 126, 123, 76, 168,
+	124, 122, 111, 96, 105, 141, 121, 142, 106, 129,
+	128, 130, 0, 0, 0, 158, 174, 192, 83, 0,
+	154, 163, 182, 183, 184, 185, 186, 187, 0, 0,
+	84, 101, 95, 140, 131, 82, 107, 155, 110, 120,
+	146, 190, 137, 151, 87, 173, 156, 342, 353, 348,
+	349, 346, 347, 345, 344, 343, 355, 333, 334, 311,
+	0, 97, 335, 336, 338, 0, 350, 351, 337, 70,
+	78, 114, 0, 145, 98, 175, 136, 858, 189, 92,
+	88, 69, 72, 117, 0, 0, 0, 298, 0, 0,
+	134, 99, 115, 0, 94, 0, 295, 0, 119, 0,
+	0, 113, 340, 116, 0, 0, 157, 127, 0, 0,
+	0, 0, 331, 332, 0, 0, 0, 0, 0, 0,
+	0, 0, 57, 0, 0, 296, 318, 317, 320, 321,
+	322, 323, 0, 0, 85, 319, 324, 325, 326, 0,
+	0, 0, 293, 310, 0, 339, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 309, 0, 0, 0, 0, 307, 308,
 	0, 0, 0, 0, 354, 0, 0, 0, 0, 0,
-	741, 0, 1316, 0, 0, 1157, 1158, 0, 0, 0,
-	0, 219, 219, 219, 0, 0, 0, 354, 1174, 1175,
-	0, 1176, 1177, 0, 354, 1275, 0, 0, 0, 0,
-	0, 0, 0, 1184, 1185, 0, 0, 0, 0, 0,
-	0, 0, 0, 771, 554, 553, 563, 564, 556, 557,
-	558, 559, 560, 561, 562, 555, 0, 0, 565, 0,
-	0, 0, 1349, 1350, 0, 1351, 0, 0, 0, 0,
-	1316, 0, 0, 0, 0, 0, 0, 0, 1316, 1316,
-	1316, 0, 0, 0, 1227, 563, 564, 556, 557, 558,
-	559, 560, 561, 562, 555, 0, 712, 565, 0, 1234,
-	0, 1316, 0, 719, 554, 553, 563, 564, 556, 557,
-	558, 559, 560, 561, 562, 555, 0, 0, 565, 736,
-	737, 0, 0, 0, 738, 739, 740, 839, 742, 743,
-	219, 0, 0, 851, 0, 744, 745, 0, 0, 1411,
-	219, 219, 0, 0, 0, 0, 219, 877, 1004, 219,
-	354, 354, 219, 0, 0, 0, 734, 0, 1263, 0,
-	0, 0, 0, 0, 0, 839, 219, 0, 1432, 554,
-	553, 563, 564, 556, 557, 558, 559, 560, 561, 562,
-	555, 0, 0, 565, 0, 0, 0, 0, 0, 1440,
-	554, 553, 563, 564, 556, 557, 558, 559, 560, 561,
-	562, 555, 0, 0, 565, 0, 0, 0, 0, 219,
-	0, 25, 26, 53, 28, 29, 0, 0, 734, 0,
-	943, 1316, 0, 0, 0, 0, 0, 0, 0, 965,
-	966, 0, 969, 970, 44, 0, 971, 0, 0, 30,
-	49, 50, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 973, 0, 0, 0, 0, 979, 39,
-	276, 0, 0, 55, 0, 276, 276, 0, 0, 276,
-	276, 276, 0, 0, 0, 840, 0, 1335, 1336, 1337,
-	1338, 1339, 0, 0, 0, 1342, 1343, 0, 0, 0,
-	0, 0, 0, 0, 276, 276, 276, 276, 0, 219,
-	0, 0, 0, 0, 0, 0, 0, 219, 0, 63,
-	0, 0, 219, 219, 0, 0, 219, 880, 734, 0,
-	0, 229, 0, 0, 0, 32, 33, 35, 34, 37,
-	0, 51, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 242, 0, 0, 944, 0,
-	946, 0, 0, 38, 45, 46, 0, 0, 47, 48,
-	36, 0, 0, 0, 972, 0, 0, 0, 0, 0,
-	0, 0, 0, 40, 41, 0, 42, 43, 0, 0,
-	0, 0, 0, 0, 0, 0, 219, 0, 0, 0,
-	0, 0, 0, 0, 0, 219, 219, 221, 219, 219,
-	0, 0, 219, 0, 224, 0, 0, 0, 0, 0,
-	0, 0, 233, 0, 228, 0, 0, 0, 0, 219,
-	0, 977, 978, 0, 219, 0, 0, 0, 0, 734,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 276, 0, 0, 231, 0, 0, 0, 0,
-	0, 241, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 54, 0, 0, 0, 0, 0, 0,
-	0, 0, 1476, 0, 0, 0, 23, 222, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 276,
-	0, 0, 0, 0, 1149, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 235, 225, 226, 276, 236, 237,
-	238, 240, 0, 239, 245, 0, 0, 0, 227, 230,
-	0, 223, 244, 243, 0, 840, 219, 219, 219, 219,
-	219, 0, 0, 0, 0, 0, 0, 0, 1059, 0,
-	0, 219, 0, 0, 0, 0, 63, 0, 219, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 1116, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 1143, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 1246, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 1249, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 1258,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	219, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	276, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 276, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 734, 0, 0, 0, 0, 0, 0, 0,
-	0, 840, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 219, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 219, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 219, 0, 0, 0, 0,
-	0, 0, 0, 0, 219, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 840, 0, 0, 0, 0, 0, 0, 0,
-	1325, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 1436, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 1354, 0, 0, 0, 0, 0,
-	0, 0, 0, 63, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 219, 840,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 840, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 443, 431, 219, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 897,
-	898, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 1086, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 897,
-	898, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 55, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	1152, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 65, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	881, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 281, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	780, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 281, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 357,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 358, 356, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 65, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 648, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 357,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 358, 356, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 443, 431, 0, 402, 446, 380, 394, 454, 395,
-	396, 424, 366, 410, 127, 392, 180, 89, 85, 67,
-	0, 383, 361, 389, 362, 381, 404, 91, 407, 379,
-	433, 413, 445, 108, 452, 110, 418, 0, 148, 119,
-	0, 0, 406, 435, 408, 429, 401, 425, 371, 417,
-	447, 393, 422, 448, 0, 0, 0, 205, 0, 0,
-	0, 0, 0, 0, 0, 0, 82, 0, 420, 442,
-	391, 421, 423, 360, 419, 0, 364, 367, 453, 437,
-	386, 387, 0, 0, 0, 0, 0, 0, 0, 405,
-	409, 426, 399, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 384, 0, 416, 0, 0, 0, 368, 365,
-	0, 0, 403, 0, 0, 0, 370, 0, 385, 427,
-	0, 359, 97, 430, 436, 0, 400, 170, 440, 398,
-	397, 444, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 434, 382, 390, 86, 388, 141,
-	129, 163, 415, 130, 140, 111, 156, 135, 441, 171,
-	172, 153, 169, 179, 70, 152, 348, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 357,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 363, 0, 149, 165, 183, 80, 378, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 358, 356, 351, 350, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 374, 377, 372, 373, 411,
-	412, 449, 450, 451, 428, 369, 0, 375, 376, 0,
-	432, 438, 439, 414, 68, 75, 109, 455, 136, 94,
-	166, 127, 0, 180, 89, 85, 67, 0, 0, 0,
-	283, 0, 0, 0, 91, 0, 280, 0, 0, 0,
-	108, 323, 110, 0, 0, 148, 119, 0, 0, 0,
-	0, 314, 315, 0, 0, 0, 0, 0, 0, 0,
-	0, 55, 0, 0, 281, 302, 301, 304, 305, 306,
-	307, 0, 0, 82, 303, 308, 309, 310, 0, 0,
-	0, 278, 295, 0, 322, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 292, 293, 0, 0, 0,
-	0, 336, 0, 294, 0, 0, 289, 290, 291, 296,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 97,
-	0, 1305, 1306, 0, 170, 0, 0, 334, 0, 134,
-	0, 151, 99, 107, 69, 76, 0, 98, 125, 139,
-	143, 0, 0, 0, 86, 0, 141, 129, 163, 0,
-	130, 140, 111, 156, 135, 0, 171, 172, 153, 169,
-	179, 70, 152, 162, 83, 144, 72, 160, 150, 117,
-	103, 104, 71, 0, 138, 90, 95, 88, 126, 157,
-	158, 87, 182, 77, 168, 74, 78, 167, 124, 155,
-	161, 118, 115, 73, 159, 116, 114, 106, 93, 100,
-	132, 113, 133, 101, 121, 120, 122, 0, 0, 0,
-	149, 165, 183, 80, 0, 145, 154, 173, 174, 175,
-	176, 177, 178, 0, 0, 81, 96, 92, 131, 123,
-	79, 102, 146, 105, 112, 137, 181, 128, 142, 84,
-	164, 147, 324, 335, 330, 331, 328, 329, 327, 326,
-	325, 337, 316, 317, 318, 319, 321, 0, 332, 333,
-	320, 68, 75, 109, 0, 136, 94, 166, 127, 0,
-	180, 89, 85, 67, 0, 0, 0, 283, 0, 0,
-	0, 91, 0, 280, 0, 0, 0, 108, 323, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 314, 315,
-	0, 0, 0, 0, 0, 0, 888, 0, 55, 0,
-	0, 281, 302, 301, 304, 305, 306, 307, 0, 0,
-	82, 303, 308, 309, 310, 889, 0, 0, 278, 295,
-	0, 322, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 292, 293, 0, 0, 0, 0, 336, 0,
-	294, 0, 0, 289, 290, 291, 296, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 334, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 324,
-	335, 330, 331, 328, 329, 327, 326, 325, 337, 316,
-	317, 318, 319, 321, 25, 332, 333, 320, 68, 75,
-	109, 0, 136, 94, 166, 0, 127, 0, 180, 89,
-	85, 67, 0, 0, 0, 283, 0, 0, 0, 91,
-	0, 280, 0, 0, 0, 108, 323, 110, 0, 0,
-	148, 119, 0, 0, 0, 0, 314, 315, 0, 0,
-	0, 0, 0, 0, 0, 0, 55, 0, 0, 281,
-	302, 301, 304, 305, 306, 307, 0, 0, 82, 303,
-	308, 309, 310, 0, 0, 0, 278, 295, 0, 322,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	292, 293, 0, 0, 0, 0, 336, 0, 294, 0,
-	0, 289, 290, 291, 296, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 97, 0, 0, 0, 0, 170,
-	0, 0, 334, 0, 134, 0, 151, 99, 107, 69,
-	76, 0, 98, 125, 139, 143, 0, 0, 0, 86,
-	0, 141, 129, 163, 0, 130, 140, 111, 156, 135,
-	0, 171, 172, 153, 169, 179, 70, 152, 162, 83,
-	144, 72, 160, 150, 117, 103, 104, 71, 0, 138,
-	90, 95, 88, 126, 157, 158, 87, 182, 77, 168,
-	74, 78, 167, 124, 155, 161, 118, 115, 73, 159,
-	116, 114, 106, 93, 100, 132, 113, 133, 101, 121,
-	120, 122, 0, 0, 0, 149, 165, 183, 80, 0,
-	145, 154, 173, 174, 175, 176, 177, 178, 0, 0,
-	81, 96, 92, 131, 123, 79, 102, 146, 105, 112,
-	137, 181, 128, 142, 84, 164, 147, 324, 335, 330,
-	331, 328, 329, 327, 326, 325, 337, 316, 317, 318,
-	319, 321, 0, 332, 333, 320, 68, 75, 109, 23,
-	136, 94, 166, 127, 0, 180, 89, 85, 67, 0,
-	816, 0, 283, 0, 0, 0, 91, 0, 280, 0,
-	0, 0, 108, 323, 110, 0, 0, 148, 119, 0,
-	0, 0, 0, 314, 315, 0, 0, 0, 0, 0,
-	0, 0, 0, 55, 0, 0, 281, 302, 301, 304,
-	305, 306, 307, 0, 0, 82, 303, 308, 309, 310,
-	0, 0, 0, 278, 295, 0, 322, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 292, 293, 274,
-	0, 0, 0, 336, 0, 294, 0, 0, 289, 290,
-	291, 296, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 97, 0, 0, 0, 0, 170, 0, 0, 334,
-	0, 134, 0, 151, 99, 107, 69, 76, 0, 98,
-	125, 139, 143, 0, 0, 0, 86, 0, 141, 129,
-	163, 0, 130, 140, 111, 156, 135, 0, 171, 172,
-	153, 169, 179, 70, 152, 162, 83, 144, 72, 160,
-	150, 117, 103, 104, 71, 0, 138, 90, 95, 88,
-	126, 157, 158, 87, 182, 77, 168, 74, 78, 167,
-	124, 155, 161, 118, 115, 73, 159, 116, 114, 106,
-	93, 100, 132, 113, 133, 101, 121, 120, 122, 0,
-	0, 0, 149, 165, 183, 80, 0, 145, 154, 173,
-	174, 175, 176, 177, 178, 0, 0, 81, 96, 92,
-	131, 123, 79, 102, 146, 105, 112, 137, 181, 128,
-	142, 84, 164, 147, 324, 335, 330, 331, 328, 329,
-	327, 326, 325, 337, 316, 317, 318, 319, 321, 0,
-	332, 333, 320, 68, 75, 109, 0, 136, 94, 166,
-	127, 0, 180, 89, 85, 67, 0, 0, 0, 283,
-	0, 0, 0, 91, 0, 280, 0, 0, 0, 108,
-	323, 110, 0, 0, 148, 119, 0, 0, 0, 0,
-	314, 315, 0, 0, 0, 0, 0, 0, 0, 0,
-	55, 0, 517, 281, 302, 301, 304, 305, 306, 307,
-	0, 0, 82, 303, 308, 309, 310, 0, 0, 0,
-	278, 295, 0, 322, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 292, 293, 0, 0, 0, 0,
-	336, 0, 294, 0, 0, 289, 290, 291, 296, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 97, 0,
-	0, 0, 0, 170, 0, 0, 334, 0, 134, 0,
-	151, 99, 107, 69, 76, 0, 98, 125, 139, 143,
-	0, 0, 0, 86, 0, 141, 129, 163, 0, 130,
-	140, 111, 156, 135, 0, 171, 172, 153, 169, 179,
-	70, 152, 162, 83, 144, 72, 160, 150, 117, 103,
-	104, 71, 0, 138, 90, 95, 88, 126, 157, 158,
-	87, 182, 77, 168, 74, 78, 167, 124, 155, 161,
-	118, 115, 73, 159, 116, 114, 106, 93, 100, 132,
-	113, 133, 101, 121, 120, 122, 0, 0, 0, 149,
-	165, 183, 80, 0, 145, 154, 173, 174, 175, 176,
-	177, 178, 0, 0, 81, 96, 92, 131, 123, 79,
-	102, 146, 105, 112, 137, 181, 128, 142, 84, 164,
-	147, 324, 335, 330, 331, 328, 329, 327, 326, 325,
-	337, 316, 317, 318, 319, 321, 0, 332, 333, 320,
-	68, 75, 109, 0, 136, 94, 166, 127, 0, 180,
-	89, 85, 67, 0, 0, 0, 283, 0, 0, 0,
-	91, 0, 280, 0, 0, 0, 108, 323, 110, 0,
-	0, 148, 119, 0, 0, 0, 0, 314, 315, 0,
-	0, 0, 0, 0, 0, 0, 0, 55, 0, 0,
-	281, 302, 301, 304, 305, 306, 307, 0, 0, 82,
-	303, 308, 309, 310, 0, 0, 0, 278, 295, 0,
-	322, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 292, 293, 274, 0, 0, 0, 336, 0, 294,
-	0, 0, 289, 290, 291, 296, 0, 0, 0, 0,
+	304, 305, 306, 312, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 102, 0, 1133, 1134, 0, 179, 0,
+	0, 352, 0, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 0, 0, 0, 89, 0,
+	150, 138, 172, 341, 0, 139, 149, 118, 165, 144,
+	0, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 81, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 0, 0, 158, 174, 192, 83,
+	0, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 131, 82, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 342, 353,
+	348, 349, 346, 347, 345, 344, 343, 355, 333, 334,
+	311, 0, 97, 335, 336, 338, 0, 350, 351, 337,
+	70, 78, 114, 0, 145, 98, 175, 136, 0, 189,
+	92, 88, 69, 72, 117, 0, 0, 0, 298, 0,
+	0, 134, 99, 115, 0, 94, 0, 295, 0, 119,
+	0, 0, 113, 340, 116, 0, 0, 157, 127, 0,
+	0, 0, 0, 331, 332, 0, 0, 0, 0, 0,
+	0, 940, 0, 57, 0, 0, 296, 318, 317, 320,
+	321, 322, 323, 0, 0, 85, 319, 324, 325, 326,
+	941, 0, 0, 293, 310, 0, 339, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 309, 0, 0, 0, 0, 307,
+	308, 0, 0, 0, 0, 354, 0, 0, 0, 0,
+	0, 304, 305, 306, 312, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 102, 0, 0, 0, 0, 179,
+	0, 0, 352, 0, 143, 0, 160, 104, 112, 71,
+	79, 0, 103, 133, 148, 152, 0, 0, 0, 89,
+	0, 150, 138, 172, 341, 0, 139, 149, 118, 165,
+	144, 0, 180, 181, 162, 178, 188, 73, 161, 171,
+	86, 153, 75, 169, 159, 125, 108, 109, 74, 0,
+	147, 93, 100, 91, 135, 166, 167, 90, 191, 193,
+	80, 177, 77, 81, 176, 132, 164, 170, 126, 123,
+	76, 168, 124, 122, 111, 96, 105, 141, 121, 142,
+	106, 129, 128, 130, 0, 0, 0, 158, 174, 192,
+	83, 0, 154, 163, 182, 183, 184, 185, 186, 187,
+	0, 0, 84, 101, 95, 140, 131, 82, 107, 155,
+	110, 120, 146, 190, 137, 151, 87, 173, 156, 342,
+	353, 348, 349, 346, 347, 345, 344, 343, 355, 333,
+	334, 311, 0, 97, 335, 336, 338, 26, 350, 351,
+	337, 70, 78, 114, 0, 145, 98, 175, 0, 136,
+	0, 189, 92, 88, 69, 72, 117, 0, 0, 0,
+	298, 0, 0, 134, 99, 115, 0, 94, 0, 295,
+	0, 119, 0, 0, 113, 340, 116, 0, 0, 157,
+	127, 0, 0, 0, 0, 331, 332, 0, 0, 0,
+	0, 0, 0, 0, 0, 57, 0, 0, 296, 318,
+	317, 320, 321, 322, 323, 0, 0, 85, 319, 324,
+	325, 326, 0, 0, 0, 293, 310, 0, 339, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 309, 0, 0, 0,
+	0, 307, 308, 0, 0, 0, 0, 354, 0, 0,
+	0, 0, 0, 304, 305, 306, 312, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 102, 0, 0, 0,
+	0, 179, 0, 0, 352, 0, 143, 0, 160, 104,
+	112, 71, 79, 0, 103, 133, 148, 152, 0, 0,
+	0, 89, 0, 150, 138, 172, 341, 0, 139, 149,
+	118, 165, 144, 0, 180, 181, 162, 178, 188, 73,
+	161, 171, 86, 153, 75, 169, 159, 125, 108, 109,
+	74, 0, 147, 93, 100, 91, 135, 166, 167, 90,
+	191, 193, 80, 177, 77, 81, 176, 132, 164, 170,
+	126, 123, 76, 168, 124, 122, 111, 96, 105, 141,
+	121, 142, 106, 129, 128, 130, 0, 0, 0, 158,
+	174, 192, 83, 0, 154, 163, 182, 183, 184, 185,
+	186, 187, 0, 0, 84, 101, 95, 140, 131, 82,
+	107, 155, 110, 120, 146, 190, 137, 151, 87, 173,
+	156, 342, 353, 348, 349, 346, 347, 345, 344, 343,
+	355, 333, 334, 311, 0, 97, 335, 336, 338, 0,
+	350, 351, 337, 70, 78, 114, 24, 145, 98, 175,
+	136, 0, 189, 92, 88, 69, 72, 117, 0, 862,
+	0, 298, 0, 0, 134, 99, 115, 0, 94, 0,
+	295, 0, 119, 0, 0, 113, 340, 116, 0, 0,
+	157, 127, 0, 0, 0, 0, 331, 332, 0, 0,
+	0, 0, 0, 0, 0, 0, 57, 0, 0, 296,
+	318, 317, 320, 321, 322, 323, 0, 0, 85, 319,
+	324, 325, 326, 0, 0, 0, 293, 310, 0, 339,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 309, 0, 0,
+	0, 0, 307, 308, 289, 0, 0, 0, 354, 0,
+	0, 0, 0, 0, 304, 305, 306, 312, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 102, 0, 0,
+	0, 0, 179, 0, 0, 352, 0, 143, 0, 160,
+	104, 112, 71, 79, 0, 103, 133, 148, 152, 0,
+	0, 0, 89, 0, 150, 138, 172, 341, 0, 139,
+	149, 118, 165, 144, 0, 180, 181, 162, 178, 188,
+	73, 161, 171, 86, 153, 75, 169, 159, 125, 108,
+	109, 74, 0, 147, 93, 100, 91, 135, 166, 167,
+	90, 191, 193, 80, 177, 77, 81, 176, 132, 164,
+	170, 126, 123, 76, 168, 124, 122, 111, 96, 105,
+	141, 121, 142, 106, 129, 128, 130, 0, 0, 0,
+	158, 174, 192, 83, 0, 154, 163, 182, 183, 184,
+	185, 186, 187, 0, 0, 84, 101, 95, 140, 131,
+	82, 107, 155, 110, 120, 146, 190, 137, 151, 87,
+	173, 156, 342, 353, 348, 349, 346, 347, 345, 344,
+	343, 355, 333, 334, 311, 0, 97, 335, 336, 338,
+	0, 350, 351, 337, 70, 78, 114, 0, 145, 98,
+	175, 136, 0, 189, 92, 88, 69, 72, 117, 0,
+	0, 0, 298, 0, 0, 134, 99, 115, 0, 94,
+	0, 295, 0, 119, 0, 0, 113, 340, 116, 0,
+	0, 157, 127, 0, 0, 0, 0, 331, 332, 0,
+	0, 0, 0, 0, 0, 0, 0, 57, 0, 543,
+	296, 318, 317, 320, 321, 322, 323, 0, 0, 85,
+	319, 324, 325, 326, 0, 0, 0, 293, 310, 0,
+	339, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 309, 0,
+	0, 0, 0, 307, 308, 0, 0, 0, 0, 354,
+	0, 0, 0, 0, 0, 304, 305, 306, 312, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 102, 0,
+	0, 0, 0, 179, 0, 0, 352, 0, 143, 0,
+	160, 104, 112, 71, 79, 0, 103, 133, 148, 152,
+	0, 0, 0, 89, 0, 150, 138, 172, 341, 0,
+	139, 149, 118, 165, 144, 0, 180, 181, 162, 178,
+	188, 73, 161, 171, 86, 153, 75, 169, 159, 125,
+	108, 109, 74, 0, 147, 93, 100, 91, 135, 166,
+	167, 90, 191, 193, 80, 177, 77, 81, 176, 132,
+	164, 170, 126, 123, 76, 168, 124, 122, 111, 96,
+	105, 141, 121, 142, 106, 129, 128, 130, 0, 0,
+	0, 158, 174, 192, 83, 0, 154, 163, 182, 183,
+	184, 185, 186, 187, 0, 0, 84, 101, 95, 140,
+	131, 82, 107, 155, 110, 120, 146, 190, 137, 151,
+	87, 173, 156, 342, 353, 348, 349, 346, 347, 345,
+	344, 343, 355, 333, 334, 311, 0, 97, 335, 336,
+	338, 0, 350, 351, 337, 70, 78, 114, 0, 145,
+	98, 175, 136, 0, 189, 92, 88, 69, 72, 117,
+	0, 0, 0, 298, 0, 0, 134, 99, 115, 0,
+	94, 0, 295, 0, 119, 0, 0, 113, 340, 116,
+	0, 0, 157, 127, 0, 0, 0, 0, 331, 332,
+	0, 0, 0, 0, 0, 0, 0, 0, 57, 0,
+	0, 296, 318, 317, 320, 321, 322, 323, 0, 0,
+	85, 319, 324, 325, 326, 0, 0, 0, 293, 310,
+	0, 339, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 309,
+	0, 0, 0, 0, 307, 308, 289, 0, 0, 0,
+	354, 0, 0, 0, 0, 0, 304, 305, 306, 312,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 102,
+	0, 0, 0, 0, 179, 0, 0, 352, 0, 143,
+	0, 160, 104, 112, 71, 79, 0, 103, 133, 148,
+	152, 0, 0, 0, 89, 0, 150, 138, 172, 341,
+	0, 139, 149, 118, 165, 144, 0, 180, 181, 162,
+	178, 188, 73, 161, 171, 86, 153, 75, 169, 159,
+	125, 108, 109, 74, 0, 147, 93, 100, 91, 135,
+	166, 167, 90, 191, 193, 80, 177, 77, 81, 176,
+	132, 164, 170, 126, 123, 76, 168, 124, 122, 111,
+	96, 105, 141, 121, 142, 106, 129, 128, 130, 0,
+	0, 0, 158, 174, 192, 83, 0, 154, 163, 182,
+	183, 184, 185, 186, 187, 0, 0, 84, 101, 95,
+	140, 131, 82, 107, 155, 110, 120, 146, 190, 137,
+	151, 87, 173, 156, 342, 353, 348, 349, 346, 347,
+	345, 344, 343, 355, 333, 334, 311, 0, 97, 335,
+	336, 338, 0, 350, 351, 337, 70, 78, 114, 0,
+	145, 98, 175, 136, 0, 189, 92, 88, 69, 72,
+	117, 0, 0, 0, 298, 0, 0, 134, 99, 115,
+	0, 94, 0, 295, 0, 119, 0, 0, 113, 340,
+	116, 0, 0, 157, 127, 0, 0, 0, 0, 331,
+	332, 0, 0, 0, 0, 0, 0, 0, 0, 57,
+	0, 0, 296, 318, 878, 320, 321, 322, 323, 0,
+	0, 85, 319, 324, 325, 326, 0, 0, 0, 293,
+	310, 0, 339, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	309, 0, 0, 0, 0, 307, 308, 289, 0, 0,
+	0, 354, 0, 0, 0, 0, 0, 304, 305, 306,
+	312, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	102, 0, 0, 0, 0, 179, 0, 0, 352, 0,
+	143, 0, 160, 104, 112, 71, 79, 0, 103, 133,
+	148, 152, 0, 0, 0, 89, 0, 150, 138, 172,
+	341, 0, 139, 149, 118, 165, 144, 0, 180, 181,
+	162, 178, 188, 73, 161, 171, 86, 153, 75, 169,
+	159, 125, 108, 109, 74, 0, 147, 93, 100, 91,
+	135, 166, 167, 90, 191, 193, 80, 177, 77, 81,
+	176, 132, 164, 170, 126, 123, 76, 168, 124, 122,
+	111, 96, 105, 141, 121, 142, 106, 129, 128, 130,
+	0, 0, 0, 158, 174, 192, 83, 0, 154, 163,
+	182, 183, 184, 185, 186, 187, 0, 0, 84, 101,
+	95, 140, 131, 82, 107, 155, 110, 120, 146, 190,
+	137, 151, 87, 173, 156, 342, 353, 348, 349, 346,
+	347, 345, 344, 343, 355, 333, 334, 311, 0, 97,
+	335, 336, 338, 0, 350, 351, 337, 70, 78, 114,
+	0, 145, 98, 175, 136, 0, 189, 92, 88, 69,
+	72, 117, 0, 0, 0, 298, 0, 0, 134, 99,
+	115, 0, 94, 0, 295, 0, 119, 0, 0, 113,
+	340, 116, 0, 0, 157, 127, 0, 0, 0, 0,
+	331, 332, 0, 0, 0, 0, 0, 0, 0, 0,
+	57, 0, 0, 296, 318, 875, 320, 321, 322, 323,
+	0, 0, 85, 319, 324, 325, 326, 0, 0, 0,
+	293, 310, 0, 339, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 309, 0, 0, 0, 0, 307, 308, 289, 0,
+	0, 0, 354, 0, 0, 0, 0, 0, 304, 305,
+	306, 312, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 102, 0, 0, 0, 0, 179, 0, 0, 352,
+	0, 143, 0, 160, 104, 112, 71, 79, 0, 103,
+	133, 148, 152, 0, 0, 0, 89, 0, 150, 138,
+	172, 341, 0, 139, 149, 118, 165, 144, 0, 180,
+	181, 162, 178, 188, 73, 161, 171, 86, 153, 75,
+	169, 159, 125, 108, 109, 74, 0, 147, 93, 100,
+	91, 135, 166, 167, 90, 191, 193, 80, 177, 77,
+	81, 176, 132, 164, 170, 126, 123, 76, 168, 124,
+	122, 111, 96, 105, 141, 121, 142, 106, 129, 128,
+	130, 0, 0, 0, 158, 174, 192, 83, 0, 154,
+	163, 182, 183, 184, 185, 186, 187, 0, 0, 84,
+	101, 95, 140, 131, 82, 107, 155, 110, 120, 146,
+	190, 137, 151, 87, 173, 156, 342, 353, 348, 349,
+	346, 347, 345, 344, 343, 355, 333, 334, 311, 0,
+	97, 335, 336, 338, 0, 350, 351, 337, 70, 78,
+	114, 0, 145, 98, 175, 136, 0, 189, 92, 88,
+	69, 72, 117, 0, 0, 0, 298, 0, 0, 134,
+	99, 115, 0, 94, 0, 295, 0, 119, 0, 0,
+	113, 340, 116, 0, 0, 157, 127, 0, 0, 0,
+	0, 331, 332, 0, 0, 0, 0, 0, 0, 0,
+	0, 57, 0, 0, 296, 318, 317, 320, 321, 322,
+	323, 0, 0, 85, 319, 324, 325, 326, 0, 0,
+	0, 293, 310, 0, 339, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 309, 0, 0, 0, 0, 307, 308, 0,
+	0, 0, 0, 354, 0, 0, 0, 0, 0, 304,
+	305, 306, 312, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 102, 0, 0, 0, 0, 179, 0, 0,
+	352, 0, 143, 0, 160, 104, 112, 71, 79, 0,
+	103, 133, 148, 152, 0, 0, 0, 89, 0, 150,
+	138, 172, 341, 0, 139, 149, 118, 165, 144, 0,
+	180, 181, 162, 178, 188, 73, 161, 171, 86, 153,
+	75, 169, 159, 125, 108, 109, 74, 0, 147, 93,
+	100, 91, 135, 166, 167, 90, 191, 193, 80, 177,
+	77, 81, 176, 132, 164, 170, 126, 123, 76, 168,
+	124, 122, 111, 96, 105, 141, 121, 142, 106, 129,
+	128, 130, 0, 0, 0, 158, 174, 192, 83, 0,
+	154, 163, 182, 183, 184, 185, 186, 187, 0, 0,
+	84, 101, 95, 140, 131, 82, 107, 155, 110, 120,
+	146, 190, 137, 151, 87, 173, 156, 342, 353, 348,
+	349, 346, 347, 345, 344, 343, 355, 333, 334, 311,
+	0, 97, 335, 336, 338, 0, 350, 351, 337, 70,
+	78, 114, 0, 145, 98, 175, 136, 0, 189, 92,
+	88, 69, 72, 117, 0, 0, 0, 0, 0, 0,
+	134, 99, 115, 0, 94, 0, 0, 0, 119, 0,
+	0, 113, 340, 116, 0, 0, 157, 127, 0, 0,
+	0, 0, 331, 332, 0, 0, 0, 0, 0, 0,
+	0, 0, 57, 0, 0, 296, 318, 317, 320, 321,
+	322, 323, 0, 0, 85, 319, 324, 325, 326, 0,
+	0, 0, 0, 310, 0, 339, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 309, 0, 0, 0, 0, 307, 308,
+	0, 0, 0, 0, 354, 0, 0, 0, 0, 0,
+	304, 305, 306, 312, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 102, 0, 0, 0, 0, 179, 0,
+	0, 352, 0, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 0, 0, 0, 89, 0,
+	150, 138, 172, 341, 1583, 139, 149, 118, 165, 144,
+	0, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 81, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 0, 0, 158, 174, 192, 83,
+	0, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 131, 82, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 342, 353,
+	348, 349, 346, 347, 345, 344, 343, 355, 333, 334,
+	311, 0, 97, 335, 336, 338, 0, 350, 351, 337,
+	70, 78, 114, 0, 145, 98, 175, 136, 0, 189,
+	92, 88, 69, 72, 117, 0, 0, 0, 0, 0,
+	0, 134, 99, 115, 0, 94, 0, 0, 0, 119,
+	0, 0, 113, 340, 116, 0, 0, 157, 127, 0,
+	0, 0, 0, 331, 332, 0, 0, 0, 0, 0,
+	0, 0, 0, 57, 0, 543, 296, 318, 317, 320,
+	321, 322, 323, 0, 0, 85, 319, 324, 325, 326,
+	0, 0, 0, 0, 310, 0, 339, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 309, 0, 0, 0, 0, 307,
+	308, 0, 0, 0, 0, 354, 0, 0, 0, 0,
+	0, 304, 305, 306, 312, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 102, 0, 0, 0, 0, 179,
+	0, 0, 352, 0, 143, 0, 160, 104, 112, 71,
+	79, 0, 103, 133, 148, 152, 0, 0, 0, 89,
+	0, 150, 138, 172, 341, 0, 139, 149, 118, 165,
+	144, 0, 180, 181, 162, 178, 188, 73, 161, 171,
+	86, 153, 75, 169, 159, 125, 108, 109, 74, 0,
+	147, 93, 100, 91, 135, 166, 167, 90, 191, 193,
+	80, 177, 77, 81, 176, 132, 164, 170, 126, 123,
+	76, 168, 124, 122, 111, 96, 105, 141, 121, 142,
+	106, 129, 128, 130, 0, 0, 0, 158, 174, 192,
+	83, 0, 154, 163, 182, 183, 184, 185, 186, 187,
+	0, 0, 84, 101, 95, 140, 131, 82, 107, 155,
+	110, 120, 146, 190, 137, 151, 87, 173, 156, 342,
+	353, 348, 349, 346, 347, 345, 344, 343, 355, 333,
+	334, 311, 0, 97, 335, 336, 338, 0, 350, 351,
+	337, 70, 78, 114, 0, 145, 98, 175, 136, 0,
+	189, 92, 88, 69, 72, 117, 0, 0, 0, 0,
+	0, 0, 134, 99, 115, 0, 94, 0, 0, 0,
+	119, 0, 0, 113, 340, 116, 0, 0, 157, 127,
+	0, 0, 0, 0, 331, 332, 0, 0, 0, 0,
+	0, 0, 0, 0, 57, 0, 0, 296, 318, 317,
+	320, 321, 322, 323, 0, 0, 85, 319, 324, 325,
+	326, 0, 0, 0, 0, 310, 0, 339, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 309, 0, 0, 0, 0,
+	307, 308, 0, 0, 0, 0, 354, 0, 0, 0,
+	0, 0, 304, 305, 306, 312, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 102, 0, 0, 0, 0,
+	179, 0, 0, 352, 0, 143, 0, 160, 104, 112,
+	71, 79, 0, 103, 133, 148, 152, 0, 0, 0,
+	89, 0, 150, 138, 172, 341, 0, 139, 149, 118,
+	165, 144, 0, 180, 181, 162, 178, 188, 73, 161,
+	171, 86, 153, 75, 169, 159, 125, 108, 109, 74,
+	0, 147, 93, 100, 91, 135, 166, 167, 90, 191,
+	193, 80, 177, 77, 81, 176, 132, 164, 170, 126,
+	123, 76, 168, 124, 122, 111, 96, 105, 141, 121,
+	142, 106, 129, 128, 130, 0, 0, 0, 158, 174,
+	192, 83, 0, 154, 163, 182, 183, 184, 185, 186,
+	187, 0, 0, 84, 101, 95, 140, 131, 82, 107,
+	155, 110, 120, 146, 190, 137, 151, 87, 173, 156,
+	342, 353, 348, 349, 346, 347, 345, 344, 343, 355,
+	333, 334, 311, 0, 97, 335, 336, 338, 0, 350,
+	351, 337, 70, 78, 114, 0, 145, 98, 175, 136,
+	0, 189, 92, 88, 69, 72, 117, 0, 0, 570,
+	0, 0, 0, 134, 99, 115, 0, 94, 0, 0,
+	0, 119, 0, 0, 113, 0, 116, 0, 0, 157,
+	127, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 218, 0,
+	572, 0, 0, 0, 0, 0, 0, 85, 0, 0,
+	0, 0, 0, 567, 566, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	568, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 102, 0, 0, 0,
+	0, 179, 0, 0, 0, 0, 143, 0, 160, 104,
+	112, 71, 79, 0, 103, 133, 148, 152, 0, 0,
+	0, 89, 0, 150, 138, 172, 0, 0, 139, 149,
+	118, 165, 144, 0, 180, 181, 162, 178, 188, 73,
+	161, 171, 86, 153, 75, 169, 159, 125, 108, 109,
+	74, 0, 147, 93, 100, 91, 135, 166, 167, 90,
+	191, 193, 80, 177, 77, 81, 176, 132, 164, 170,
+	126, 123, 76, 168, 124, 122, 111, 96, 105, 141,
+	121, 142, 106, 129, 128, 130, 0, 0, 0, 158,
+	174, 192, 83, 0, 154, 163, 182, 183, 184, 185,
+	186, 187, 0, 0, 84, 101, 95, 140, 131, 82,
+	107, 155, 110, 120, 146, 190, 137, 151, 87, 173,
+	156, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 97, 0, 0, 0, 0,
-	170, 0, 0, 334, 0, 134, 0, 151, 99, 107,
-	69, 76, 0, 98, 125, 139, 143, 0, 0, 0,
-	86, 0, 141, 129, 163, 0, 130, 140, 111, 156,
-	135, 0, 171, 172, 153, 169, 179, 70, 152, 162,
-	83, 144, 72, 160, 150, 117, 103, 104, 71, 0,
-	138, 90, 95, 88, 126, 157, 158, 87, 182, 77,
-	168, 74, 78, 167, 124, 155, 161, 118, 115, 73,
-	159, 116, 114, 106, 93, 100, 132, 113, 133, 101,
-	121, 120, 122, 0, 0, 0, 149, 165, 183, 80,
-	0, 145, 154, 173, 174, 175, 176, 177, 178, 0,
-	0, 81, 96, 92, 131, 123, 79, 102, 146, 105,
-	112, 137, 181, 128, 142, 84, 164, 147, 324, 335,
-	330, 331, 328, 329, 327, 326, 325, 337, 316, 317,
-	318, 319, 321, 0, 332, 333, 320, 68, 75, 109,
-	0, 136, 94, 166, 127, 0, 180, 89, 85, 67,
-	0, 0, 0, 283, 0, 0, 0, 91, 0, 280,
-	0, 0, 0, 108, 323, 110, 0, 0, 148, 119,
-	0, 0, 0, 0, 314, 315, 0, 0, 0, 0,
-	0, 0, 0, 0, 55, 0, 0, 281, 302, 831,
-	304, 305, 306, 307, 0, 0, 82, 303, 308, 309,
-	310, 0, 0, 0, 278, 295, 0, 322, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 292, 293,
-	274, 0, 0, 0, 336, 0, 294, 0, 0, 289,
-	290, 291, 296, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 97, 0, 0, 0, 0, 170, 0, 0,
-	334, 0, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 0, 0, 0, 86, 0, 141,
-	129, 163, 0, 130, 140, 111, 156, 135, 0, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 0, 0, 149, 165, 183, 80, 0, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 324, 335, 330, 331, 328,
-	329, 327, 326, 325, 337, 316, 317, 318, 319, 321,
-	0, 332, 333, 320, 68, 75, 109, 0, 136, 94,
-	166, 127, 0, 180, 89, 85, 67, 0, 0, 0,
-	283, 0, 0, 0, 91, 0, 280, 0, 0, 0,
-	108, 323, 110, 0, 0, 148, 119, 0, 0, 0,
-	0, 314, 315, 0, 0, 0, 0, 0, 0, 0,
-	0, 55, 0, 0, 281, 302, 828, 304, 305, 306,
-	307, 0, 0, 82, 303, 308, 309, 310, 0, 0,
-	0, 278, 295, 0, 322, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 292, 293, 274, 0, 0,
-	0, 336, 0, 294, 0, 0, 289, 290, 291, 296,
+	0, 0, 0, 70, 78, 114, 0, 145, 98, 175,
+	136, 0, 189, 92, 88, 69, 72, 117, 0, 0,
+	0, 0, 0, 0, 134, 99, 115, 0, 94, 0,
+	0, 0, 119, 0, 0, 113, 0, 116, 0, 0,
+	157, 127, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 218,
+	0, 0, 0, 0, 0, 0, 0, 0, 85, 0,
+	0, 0, 0, 0, 213, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 102, 215, 210,
+	0, 0, 212, 0, 0, 0, 216, 143, 0, 160,
+	104, 112, 71, 79, 0, 103, 133, 148, 152, 0,
+	0, 0, 89, 0, 150, 138, 172, 0, 0, 139,
+	149, 118, 165, 144, 0, 180, 181, 162, 178, 188,
+	73, 161, 171, 86, 153, 75, 169, 159, 125, 108,
+	109, 74, 0, 147, 93, 100, 91, 135, 166, 167,
+	90, 191, 193, 80, 177, 77, 81, 176, 132, 164,
+	170, 126, 123, 76, 168, 124, 122, 111, 96, 105,
+	141, 121, 142, 106, 129, 128, 130, 0, 0, 0,
+	158, 174, 192, 83, 0, 154, 163, 182, 183, 184,
+	185, 186, 187, 0, 0, 84, 101, 95, 140, 131,
+	82, 107, 155, 110, 120, 146, 190, 137, 151, 87,
+	173, 156, 0, 214, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 97, 0, 0, 26,
+	0, 0, 0, 0, 70, 78, 114, 0, 145, 98,
+	175, 136, 0, 189, 92, 88, 69, 72, 117, 0,
+	0, 0, 0, 0, 0, 134, 99, 115, 0, 94,
+	0, 0, 0, 119, 0, 0, 113, 0, 116, 0,
+	0, 157, 127, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+	670, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 102, 0,
+	0, 0, 0, 179, 0, 0, 0, 0, 143, 0,
+	160, 104, 112, 71, 79, 0, 103, 133, 148, 152,
+	0, 0, 0, 89, 0, 150, 138, 172, 0, 0,
+	139, 149, 118, 165, 144, 0, 180, 181, 162, 178,
+	188, 73, 161, 171, 86, 153, 75, 169, 159, 125,
+	108, 109, 74, 0, 147, 93, 100, 91, 135, 166,
+	167, 90, 191, 193, 80, 177, 77, 81, 176, 132,
+	164, 170, 126, 123, 76, 168, 124, 122, 111, 96,
+	105, 141, 121, 142, 106, 129, 128, 130, 0, 0,
+	0, 158, 174, 192, 83, 0, 154, 163, 182, 183,
+	184, 185, 186, 187, 0, 0, 84, 101, 95, 140,
+	131, 82, 107, 671, 110, 120, 146, 190, 137, 151,
+	87, 173, 156, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 672, 97, 0, 0,
+	26, 0, 0, 0, 0, 70, 78, 114, 24, 145,
+	98, 175, 136, 0, 189, 92, 88, 69, 72, 117,
+	0, 0, 0, 0, 0, 0, 134, 99, 115, 0,
+	94, 0, 0, 0, 119, 0, 0, 113, 0, 116,
+	0, 0, 157, 127, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 57, 0,
+	0, 218, 0, 0, 0, 0, 0, 0, 0, 0,
+	85, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 102,
+	0, 0, 0, 0, 179, 0, 0, 0, 0, 143,
+	0, 160, 104, 112, 71, 79, 0, 103, 133, 148,
+	152, 0, 0, 0, 89, 0, 150, 138, 172, 0,
+	0, 139, 149, 118, 165, 144, 0, 180, 181, 162,
+	178, 188, 73, 161, 171, 86, 153, 75, 169, 159,
+	125, 108, 109, 74, 0, 147, 93, 100, 91, 135,
+	166, 167, 90, 191, 193, 80, 177, 77, 81, 176,
+	132, 164, 170, 126, 123, 76, 168, 124, 122, 111,
+	96, 105, 141, 121, 142, 106, 129, 128, 130, 0,
+	0, 0, 158, 174, 192, 83, 0, 154, 163, 182,
+	183, 184, 185, 186, 187, 0, 0, 84, 101, 95,
+	140, 131, 82, 107, 155, 110, 120, 146, 190, 137,
+	151, 87, 173, 156, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 97, 0,
+	0, 0, 0, 0, 0, 0, 70, 78, 114, 24,
+	145, 98, 175, 136, 0, 189, 92, 88, 69, 72,
+	117, 0, 0, 925, 0, 0, 0, 134, 99, 115,
+	0, 94, 0, 0, 0, 119, 0, 0, 113, 0,
+	116, 0, 0, 157, 127, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 67, 0, 66, 0, 0, 0, 0, 0,
+	0, 85, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	102, 0, 0, 0, 0, 179, 0, 0, 0, 0,
+	143, 0, 160, 104, 112, 71, 79, 0, 103, 133,
+	148, 152, 0, 0, 0, 89, 0, 150, 138, 172,
+	0, 0, 139, 149, 118, 165, 144, 0, 180, 181,
+	162, 178, 188, 73, 161, 171, 86, 153, 75, 169,
+	159, 125, 108, 109, 74, 0, 147, 93, 100, 91,
+	135, 166, 167, 90, 191, 193, 80, 177, 77, 81,
+	176, 132, 164, 170, 126, 123, 76, 168, 124, 122,
+	111, 96, 105, 141, 121, 142, 106, 129, 128, 130,
+	0, 0, 0, 158, 174, 192, 83, 0, 154, 163,
+	182, 183, 184, 185, 186, 187, 0, 0, 84, 101,
+	95, 140, 131, 82, 107, 155, 110, 120, 146, 190,
+	137, 151, 87, 173, 156, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 97,
-	0, 0, 0, 0, 170, 0, 0, 334, 0, 134,
-	0, 151, 99, 107, 69, 76, 0, 98, 125, 139,
-	143, 0, 0, 0, 86, 0, 141, 129, 163, 0,
-	130, 140, 111, 156, 135, 0, 171, 172, 153, 169,
-	179, 70, 152, 162, 83, 144, 72, 160, 150, 117,
-	103, 104, 71, 0, 138, 90, 95, 88, 126, 157,
-	158, 87, 182, 77, 168, 74, 78, 167, 124, 155,
-	161, 118, 115, 73, 159, 116, 114, 106, 93, 100,
-	132, 113, 133, 101, 121, 120, 122, 0, 0, 0,
-	149, 165, 183, 80, 0, 145, 154, 173, 174, 175,
-	176, 177, 178, 0, 0, 81, 96, 92, 131, 123,
-	79, 102, 146, 105, 112, 137, 181, 128, 142, 84,
-	164, 147, 324, 335, 330, 331, 328, 329, 327, 326,
-	325, 337, 316, 317, 318, 319, 321, 0, 332, 333,
-	320, 68, 75, 109, 0, 136, 94, 166, 127, 0,
-	180, 89, 85, 67, 0, 0, 0, 283, 0, 0,
-	0, 91, 0, 280, 0, 0, 0, 108, 323, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 314, 315,
-	0, 0, 0, 0, 0, 0, 0, 0, 55, 0,
-	0, 281, 302, 301, 304, 305, 306, 307, 0, 0,
-	82, 303, 308, 309, 310, 0, 0, 0, 278, 295,
-	0, 322, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 292, 293, 0, 0, 0, 0, 336, 0,
-	294, 0, 0, 289, 290, 291, 296, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 334, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 324,
-	335, 330, 331, 328, 329, 327, 326, 325, 337, 316,
-	317, 318, 319, 321, 0, 332, 333, 320, 68, 75,
-	109, 0, 136, 94, 166, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 0, 0, 0, 91, 0,
-	0, 0, 0, 0, 108, 323, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 314, 315, 0, 0, 0,
-	0, 0, 0, 0, 0, 55, 0, 0, 281, 302,
-	301, 304, 305, 306, 307, 0, 0, 82, 303, 308,
-	309, 310, 0, 0, 0, 0, 295, 0, 322, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 292,
-	293, 0, 0, 0, 0, 336, 0, 294, 0, 0,
-	289, 290, 291, 296, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 334, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 1477, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 324, 335, 330, 331,
-	328, 329, 327, 326, 325, 337, 316, 317, 318, 319,
-	321, 0, 332, 333, 320, 68, 75, 109, 0, 136,
-	94, 166, 127, 0, 180, 89, 85, 67, 0, 0,
-	0, 0, 0, 0, 0, 91, 0, 0, 0, 0,
-	0, 108, 323, 110, 0, 0, 148, 119, 0, 0,
-	0, 0, 314, 315, 0, 0, 0, 0, 0, 0,
-	0, 0, 55, 0, 517, 281, 302, 301, 304, 305,
-	306, 307, 0, 0, 82, 303, 308, 309, 310, 0,
-	0, 0, 0, 295, 0, 322, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 292, 293, 0, 0,
-	0, 0, 336, 0, 294, 0, 0, 289, 290, 291,
-	296, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	97, 0, 0, 0, 0, 170, 0, 0, 334, 0,
-	134, 0, 151, 99, 107, 69, 76, 0, 98, 125,
-	139, 143, 0, 0, 0, 86, 0, 141, 129, 163,
-	0, 130, 140, 111, 156, 135, 0, 171, 172, 153,
-	169, 179, 70, 152, 162, 83, 144, 72, 160, 150,
-	117, 103, 104, 71, 0, 138, 90, 95, 88, 126,
-	157, 158, 87, 182, 77, 168, 74, 78, 167, 124,
-	155, 161, 118, 115, 73, 159, 116, 114, 106, 93,
-	100, 132, 113, 133, 101, 121, 120, 122, 0, 0,
-	0, 149, 165, 183, 80, 0, 145, 154, 173, 174,
-	175, 176, 177, 178, 0, 0, 81, 96, 92, 131,
-	123, 79, 102, 146, 105, 112, 137, 181, 128, 142,
-	84, 164, 147, 324, 335, 330, 331, 328, 329, 327,
-	326, 325, 337, 316, 317, 318, 319, 321, 0, 332,
-	333, 320, 68, 75, 109, 0, 136, 94, 166, 127,
-	0, 180, 89, 85, 67, 0, 0, 0, 0, 0,
-	0, 0, 91, 0, 0, 0, 0, 0, 108, 323,
-	110, 0, 0, 148, 119, 0, 0, 0, 0, 314,
-	315, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-	0, 0, 281, 302, 301, 304, 305, 306, 307, 0,
-	0, 82, 303, 308, 309, 310, 0, 0, 0, 0,
-	295, 0, 322, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 292, 293, 0, 0, 0, 0, 336,
-	0, 294, 0, 0, 289, 290, 291, 296, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 97, 0, 0,
-	0, 0, 170, 0, 0, 334, 0, 134, 0, 151,
-	99, 107, 69, 76, 0, 98, 125, 139, 143, 0,
-	0, 0, 86, 0, 141, 129, 163, 0, 130, 140,
-	111, 156, 135, 0, 171, 172, 153, 169, 179, 70,
-	152, 162, 83, 144, 72, 160, 150, 117, 103, 104,
-	71, 0, 138, 90, 95, 88, 126, 157, 158, 87,
-	182, 77, 168, 74, 78, 167, 124, 155, 161, 118,
-	115, 73, 159, 116, 114, 106, 93, 100, 132, 113,
-	133, 101, 121, 120, 122, 0, 0, 0, 149, 165,
-	183, 80, 0, 145, 154, 173, 174, 175, 176, 177,
-	178, 0, 0, 81, 96, 92, 131, 123, 79, 102,
-	146, 105, 112, 137, 181, 128, 142, 84, 164, 147,
-	324, 335, 330, 331, 328, 329, 327, 326, 325, 337,
-	316, 317, 318, 319, 321, 0, 332, 333, 320, 68,
-	75, 109, 0, 136, 94, 166, 127, 0, 180, 89,
-	85, 67, 0, 0, 0, 0, 0, 0, 0, 91,
-	0, 0, 0, 0, 0, 108, 0, 110, 0, 0,
-	148, 119, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 205,
-	0, 0, 0, 0, 0, 0, 0, 0, 82, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 554, 553, 563, 564,
-	556, 557, 558, 559, 560, 561, 562, 555, 0, 0,
-	565, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 97, 0, 0, 0, 0, 170,
-	0, 0, 0, 0, 134, 0, 151, 99, 107, 69,
-	76, 0, 98, 125, 139, 143, 0, 0, 0, 86,
-	0, 141, 129, 163, 0, 130, 140, 111, 156, 135,
-	0, 171, 172, 153, 169, 179, 70, 152, 162, 83,
-	144, 72, 160, 150, 117, 103, 104, 71, 0, 138,
-	90, 95, 88, 126, 157, 158, 87, 182, 77, 168,
-	74, 78, 167, 124, 155, 161, 118, 115, 73, 159,
-	116, 114, 106, 93, 100, 132, 113, 133, 101, 121,
-	120, 122, 0, 0, 0, 149, 165, 183, 80, 0,
-	145, 154, 173, 174, 175, 176, 177, 178, 0, 0,
-	81, 96, 92, 131, 123, 79, 102, 146, 105, 112,
-	137, 181, 128, 142, 84, 164, 147, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 127, 0, 180, 89,
-	85, 67, 0, 0, 542, 0, 68, 75, 109, 91,
-	136, 94, 166, 0, 0, 108, 0, 110, 0, 0,
-	148, 119, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 205,
-	0, 544, 0, 0, 0, 0, 0, 0, 82, 0,
-	0, 0, 0, 0, 539, 538, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 540, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 97, 0, 0, 0, 0, 170,
-	0, 0, 0, 0, 134, 0, 151, 99, 107, 69,
-	76, 0, 98, 125, 139, 143, 0, 0, 0, 86,
-	0, 141, 129, 163, 0, 130, 140, 111, 156, 135,
-	0, 171, 172, 153, 169, 179, 70, 152, 162, 83,
-	144, 72, 160, 150, 117, 103, 104, 71, 0, 138,
-	90, 95, 88, 126, 157, 158, 87, 182, 77, 168,
-	74, 78, 167, 124, 155, 161, 118, 115, 73, 159,
-	116, 114, 106, 93, 100, 132, 113, 133, 101, 121,
-	120, 122, 0, 0, 0, 149, 165, 183, 80, 0,
-	145, 154, 173, 174, 175, 176, 177, 178, 0, 0,
-	81, 96, 92, 131, 123, 79, 102, 146, 105, 112,
-	137, 181, 128, 142, 84, 164, 147, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 127, 0, 180, 89,
-	85, 67, 0, 0, 0, 0, 68, 75, 109, 91,
-	136, 94, 166, 0, 0, 108, 0, 110, 0, 0,
-	148, 119, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 205,
-	0, 0, 0, 0, 0, 0, 0, 0, 82, 0,
-	0, 0, 0, 0, 199, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 97, 201, 202, 0, 0, 198,
-	0, 0, 0, 203, 134, 0, 151, 99, 107, 69,
-	76, 0, 98, 125, 139, 143, 0, 0, 0, 86,
-	0, 141, 129, 163, 0, 130, 140, 111, 156, 135,
-	0, 171, 172, 153, 169, 179, 70, 152, 162, 83,
-	144, 72, 160, 150, 117, 103, 104, 71, 0, 138,
-	90, 95, 88, 126, 157, 158, 87, 182, 77, 168,
-	74, 78, 167, 124, 155, 161, 118, 115, 73, 159,
-	116, 114, 106, 93, 100, 132, 113, 133, 101, 121,
-	120, 122, 0, 0, 0, 149, 165, 183, 80, 0,
-	145, 154, 173, 174, 175, 176, 177, 178, 0, 0,
-	81, 96, 92, 131, 123, 79, 102, 146, 105, 112,
-	137, 181, 128, 142, 84, 164, 147, 0, 200, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 25, 0, 0, 0, 0, 68, 75, 109, 0,
-	136, 94, 166, 127, 0, 180, 89, 85, 67, 0,
-	0, 0, 0, 0, 0, 0, 91, 0, 0, 0,
-	0, 0, 108, 0, 110, 0, 0, 148, 119, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 55, 0, 0, 205, 0, 0, 0,
-	0, 0, 0, 0, 0, 82, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 97, 0, 0, 0, 0, 170, 0, 0, 0,
-	0, 134, 0, 151, 99, 107, 69, 76, 0, 98,
-	125, 139, 143, 0, 0, 0, 86, 0, 141, 129,
-	163, 0, 130, 140, 111, 156, 135, 0, 171, 172,
-	153, 169, 179, 70, 152, 162, 83, 144, 72, 160,
-	150, 117, 103, 104, 71, 0, 138, 90, 95, 88,
-	126, 157, 158, 87, 182, 77, 168, 74, 78, 167,
-	124, 155, 161, 118, 115, 73, 159, 116, 114, 106,
-	93, 100, 132, 113, 133, 101, 121, 120, 122, 0,
-	0, 0, 149, 165, 183, 80, 0, 145, 154, 173,
-	174, 175, 176, 177, 178, 0, 0, 81, 96, 92,
-	131, 123, 79, 102, 146, 105, 112, 137, 181, 128,
-	142, 84, 164, 147, 0, 25, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 127, 0, 180,
-	89, 85, 67, 68, 75, 109, 23, 136, 94, 166,
-	91, 0, 0, 0, 0, 0, 108, 0, 110, 0,
-	0, 148, 119, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 55, 0, 0,
-	635, 0, 0, 0, 0, 0, 0, 0, 0, 82,
+	0, 0, 0, 0, 0, 0, 0, 70, 78, 114,
+	0, 145, 98, 175, 136, 0, 189, 92, 88, 69,
+	72, 117, 0, 0, 0, 0, 0, 0, 134, 99,
+	115, 0, 94, 0, 0, 0, 119, 0, 0, 113,
+	0, 116, 0, 0, 157, 127, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	57, 0, 0, 670, 0, 0, 0, 0, 0, 0,
+	0, 0, 85, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 97, 0, 0, 0, 0,
-	170, 0, 0, 0, 0, 134, 0, 151, 99, 107,
-	69, 76, 0, 98, 125, 139, 143, 0, 0, 0,
-	86, 0, 141, 129, 163, 0, 130, 140, 111, 156,
-	135, 0, 171, 172, 153, 169, 179, 70, 152, 162,
-	83, 144, 72, 160, 150, 117, 103, 104, 71, 0,
-	138, 90, 95, 88, 126, 157, 158, 87, 182, 77,
-	168, 74, 78, 167, 124, 155, 161, 118, 115, 73,
-	159, 116, 114, 106, 93, 100, 132, 113, 133, 101,
-	121, 120, 122, 0, 0, 0, 149, 165, 183, 80,
-	0, 145, 154, 173, 174, 175, 176, 177, 178, 0,
-	0, 81, 96, 92, 131, 123, 79, 102, 146, 105,
-	112, 137, 181, 128, 142, 84, 164, 147, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 68, 75, 109,
-	23, 136, 94, 166, 127, 0, 180, 89, 85, 67,
-	0, 0, 873, 0, 0, 0, 0, 91, 0, 0,
-	0, 0, 0, 108, 0, 110, 0, 0, 148, 119,
+	0, 102, 0, 0, 0, 0, 179, 0, 0, 0,
+	0, 143, 0, 160, 104, 112, 71, 79, 0, 103,
+	133, 148, 152, 0, 0, 0, 89, 0, 150, 138,
+	172, 0, 0, 139, 149, 118, 165, 144, 0, 180,
+	181, 162, 178, 188, 73, 161, 171, 86, 153, 75,
+	169, 159, 125, 108, 109, 74, 0, 147, 93, 100,
+	91, 135, 166, 167, 90, 191, 193, 80, 177, 77,
+	81, 176, 132, 164, 170, 126, 123, 76, 168, 124,
+	122, 111, 96, 105, 141, 121, 142, 106, 129, 128,
+	130, 0, 0, 0, 158, 174, 192, 83, 0, 154,
+	163, 182, 183, 184, 185, 186, 187, 0, 0, 84,
+	101, 95, 140, 131, 82, 107, 671, 110, 120, 146,
+	190, 137, 151, 87, 173, 156, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 672,
+	97, 0, 0, 0, 0, 0, 0, 0, 70, 78,
+	114, 0, 145, 98, 175, 136, 0, 189, 92, 88,
+	69, 72, 117, 0, 0, 925, 0, 0, 0, 134,
+	99, 115, 0, 94, 0, 0, 0, 119, 0, 0,
+	113, 0, 116, 0, 0, 157, 127, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 67, 0, 66, 0, 0, 0,
+	0, 0, 0, 85, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 65, 0, 64,
-	0, 0, 0, 0, 0, 0, 82, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 102, 0, 0, 0, 0, 179, 0, 0,
+	0, 0, 143, 0, 160, 104, 112, 71, 79, 0,
+	103, 133, 148, 152, 0, 0, 0, 89, 0, 150,
+	138, 172, 0, 0, 923, 149, 118, 165, 144, 0,
+	180, 181, 162, 178, 188, 73, 161, 171, 86, 153,
+	75, 169, 159, 125, 108, 109, 74, 0, 147, 93,
+	100, 91, 135, 166, 167, 90, 191, 193, 80, 177,
+	77, 81, 176, 132, 164, 170, 126, 123, 76, 168,
+	124, 122, 111, 96, 105, 141, 121, 142, 106, 129,
+	128, 130, 0, 0, 0, 158, 174, 192, 83, 0,
+	154, 163, 182, 183, 184, 185, 186, 187, 0, 0,
+	84, 101, 95, 140, 131, 82, 107, 155, 110, 120,
+	146, 190, 137, 151, 87, 173, 156, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 97, 0, 0, 0, 0, 0, 0, 0, 70,
+	78, 114, 0, 145, 98, 175, 136, 0, 189, 92,
+	88, 69, 72, 117, 0, 0, 0, 0, 0, 0,
+	134, 99, 115, 0, 94, 0, 0, 0, 119, 0,
+	0, 113, 0, 116, 0, 0, 157, 127, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 218, 0, 0, 805, 0,
+	0, 806, 0, 0, 85, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 102, 0, 0, 0, 0, 179, 0,
+	0, 0, 0, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 0, 0, 0, 89, 0,
+	150, 138, 172, 0, 0, 139, 149, 118, 165, 144,
+	0, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 81, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 0, 0, 158, 174, 192, 83,
+	0, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 131, 82, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 97, 0, 0, 0, 0, 0, 0, 0,
+	70, 78, 114, 0, 145, 98, 175, 136, 0, 189,
+	92, 88, 69, 72, 117, 0, 0, 0, 0, 0,
+	0, 134, 99, 115, 0, 94, 0, 694, 0, 119,
+	0, 0, 113, 0, 116, 0, 0, 157, 127, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 218, 0, 693, 0,
+	0, 0, 0, 0, 0, 85, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 102, 0, 0, 0, 0, 179,
+	0, 0, 0, 0, 143, 0, 160, 104, 112, 71,
+	79, 0, 103, 133, 148, 152, 0, 0, 0, 89,
+	0, 150, 138, 172, 0, 0, 139, 149, 118, 165,
+	144, 0, 180, 181, 162, 178, 188, 73, 161, 171,
+	86, 153, 75, 169, 159, 125, 108, 109, 74, 0,
+	147, 93, 100, 91, 135, 166, 167, 90, 191, 193,
+	80, 177, 77, 81, 176, 132, 164, 170, 126, 123,
+	76, 168, 124, 122, 111, 96, 105, 141, 121, 142,
+	106, 129, 128, 130, 0, 0, 0, 158, 174, 192,
+	83, 0, 154, 163, 182, 183, 184, 185, 186, 187,
+	0, 0, 84, 101, 95, 140, 131, 82, 107, 155,
+	110, 120, 146, 190, 137, 151, 87, 173, 156, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 97, 0, 0, 0, 0, 0, 0,
+	0, 70, 78, 114, 0, 145, 98, 175, 136, 0,
+	189, 92, 88, 69, 72, 117, 0, 0, 0, 0,
+	0, 0, 134, 99, 115, 0, 94, 0, 0, 0,
+	119, 0, 0, 113, 0, 116, 0, 0, 157, 127,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 218, 0, 572,
+	0, 0, 0, 0, 0, 0, 85, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 102, 0, 0, 0, 0,
+	179, 0, 0, 0, 0, 143, 0, 160, 104, 112,
+	71, 79, 0, 103, 133, 148, 152, 0, 0, 0,
+	89, 0, 150, 138, 172, 0, 0, 139, 149, 118,
+	165, 144, 0, 180, 181, 162, 178, 188, 73, 161,
+	171, 86, 153, 75, 169, 159, 125, 108, 109, 74,
+	0, 147, 93, 100, 91, 135, 166, 167, 90, 191,
+	193, 80, 177, 77, 81, 176, 132, 164, 170, 126,
+	123, 76, 168, 124, 122, 111, 96, 105, 141, 121,
+	142, 106, 129, 128, 130, 0, 0, 0, 158, 174,
+	192, 83, 0, 154, 163, 182, 183, 184, 185, 186,
+	187, 0, 0, 84, 101, 95, 140, 131, 82, 107,
+	155, 110, 120, 146, 190, 137, 151, 87, 173, 156,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 97, 0, 0, 0, 0, 0,
+	0, 0, 70, 78, 114, 0, 145, 98, 175, 136,
+	0, 189, 92, 88, 69, 72, 117, 0, 0, 0,
+	0, 0, 0, 134, 99, 115, 0, 94, 0, 0,
+	0, 119, 0, 0, 113, 0, 116, 0, 0, 157,
+	127, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 67, 0,
+	66, 0, 0, 0, 0, 0, 0, 85, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 102, 0, 0, 0,
+	0, 179, 0, 0, 0, 0, 143, 0, 160, 104,
+	112, 71, 79, 0, 103, 133, 148, 152, 0, 0,
+	0, 89, 0, 150, 138, 172, 0, 0, 139, 149,
+	118, 165, 144, 0, 180, 181, 162, 178, 188, 73,
+	161, 171, 86, 153, 75, 169, 159, 125, 108, 109,
+	74, 0, 147, 93, 100, 91, 135, 166, 167, 90,
+	191, 193, 80, 177, 77, 81, 176, 132, 164, 170,
+	126, 123, 76, 168, 124, 122, 111, 96, 105, 141,
+	121, 142, 106, 129, 128, 130, 0, 0, 0, 158,
+	174, 192, 83, 0, 154, 163, 182, 183, 184, 185,
+	186, 187, 0, 0, 84, 101, 95, 140, 131, 82,
+	107, 155, 110, 120, 146, 190, 137, 151, 87, 173,
+	156, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 97, 0, 0, 0, 0,
+	0, 0, 0, 70, 78, 114, 0, 145, 98, 175,
+	136, 0, 189, 92, 88, 69, 72, 117, 0, 0,
+	0, 0, 0, 0, 134, 99, 115, 661, 94, 0,
+	0, 0, 119, 0, 0, 113, 0, 116, 0, 0,
+	157, 127, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 67,
+	0, 0, 0, 0, 0, 0, 0, 0, 85, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 102, 0, 0,
+	0, 0, 179, 0, 0, 0, 0, 143, 0, 160,
+	104, 112, 71, 79, 0, 103, 133, 148, 152, 0,
+	0, 0, 89, 0, 150, 138, 172, 0, 0, 139,
+	149, 118, 165, 144, 0, 180, 181, 162, 178, 188,
+	73, 161, 171, 86, 153, 75, 169, 159, 125, 108,
+	109, 74, 0, 147, 93, 100, 91, 135, 166, 167,
+	90, 191, 193, 80, 177, 77, 81, 176, 132, 164,
+	170, 126, 123, 76, 168, 124, 122, 111, 96, 105,
+	141, 121, 142, 106, 129, 128, 130, 0, 0, 0,
+	158, 174, 192, 83, 0, 154, 163, 182, 183, 184,
+	185, 186, 187, 0, 0, 84, 101, 95, 140, 131,
+	82, 107, 155, 110, 120, 146, 190, 137, 151, 87,
+	173, 156, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
+	0, 0, 0, 0, 70, 78, 114, 358, 145, 98,
+	175, 0, 0, 0, 136, 0, 189, 92, 88, 69,
+	72, 117, 0, 0, 0, 0, 0, 0, 134, 99,
+	115, 0, 94, 0, 0, 0, 119, 0, 0, 113,
+	0, 116, 0, 0, 157, 127, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 97, 0, 0, 0, 0, 170, 0, 0,
-	0, 0, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 0, 0, 0, 86, 0, 141,
-	129, 163, 0, 130, 140, 111, 156, 135, 0, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 0, 0, 149, 165, 183, 80, 0, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 127, 0, 180, 89, 85, 67,
-	0, 0, 873, 0, 68, 75, 109, 91, 136, 94,
-	166, 0, 0, 108, 0, 110, 0, 0, 148, 119,
+	0, 0, 0, 67, 0, 0, 0, 0, 0, 0,
+	0, 0, 85, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 65, 0, 64,
-	0, 0, 0, 0, 0, 0, 82, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 102, 0, 0, 0, 0, 179, 0, 0, 0,
+	0, 143, 0, 160, 104, 112, 71, 79, 0, 103,
+	133, 148, 152, 0, 0, 0, 89, 0, 150, 138,
+	172, 0, 0, 139, 149, 118, 165, 144, 0, 180,
+	181, 162, 178, 188, 73, 161, 171, 86, 153, 75,
+	169, 159, 125, 108, 109, 74, 0, 147, 93, 100,
+	91, 135, 166, 167, 90, 191, 193, 80, 177, 77,
+	81, 176, 132, 164, 170, 126, 123, 76, 168, 124,
+	122, 111, 96, 105, 141, 121, 142, 106, 129, 128,
+	130, 0, 0, 0, 158, 174, 192, 83, 0, 154,
+	163, 182, 183, 184, 185, 186, 187, 0, 0, 84,
+	101, 95, 140, 131, 82, 107, 155, 110, 120, 146,
+	190, 137, 151, 87, 173, 156, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 97, 0, 0, 0, 0, 170, 0, 0,
-	0, 0, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 0, 0, 0, 86, 0, 141,
-	129, 163, 0, 871, 140, 111, 156, 135, 0, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 0, 0, 149, 165, 183, 80, 0, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 127, 0, 180, 89, 85, 67,
-	0, 0, 0, 0, 68, 75, 109, 91, 136, 94,
-	166, 0, 0, 108, 0, 110, 0, 0, 148, 119,
+	97, 0, 0, 0, 0, 0, 0, 0, 70, 78,
+	114, 0, 145, 98, 175, 136, 0, 189, 92, 88,
+	69, 72, 117, 0, 0, 0, 0, 0, 0, 134,
+	99, 115, 0, 94, 0, 0, 0, 119, 0, 0,
+	113, 0, 116, 0, 0, 157, 127, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 205, 0, 0,
-	767, 0, 0, 768, 0, 0, 82, 0, 0, 0,
+	0, 0, 0, 0, 67, 0, 0, 0, 0, 0,
+	0, 0, 0, 85, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 97, 0, 0, 0, 0, 170, 0, 0,
-	0, 0, 134, 0, 151, 99, 107, 69, 76, 0,
-	98, 125, 139, 143, 0, 0, 0, 86, 0, 141,
-	129, 163, 0, 130, 140, 111, 156, 135, 0, 171,
-	172, 153, 169, 179, 70, 152, 162, 83, 144, 72,
-	160, 150, 117, 103, 104, 71, 0, 138, 90, 95,
-	88, 126, 157, 158, 87, 182, 77, 168, 74, 78,
-	167, 124, 155, 161, 118, 115, 73, 159, 116, 114,
-	106, 93, 100, 132, 113, 133, 101, 121, 120, 122,
-	0, 0, 0, 149, 165, 183, 80, 0, 145, 154,
-	173, 174, 175, 176, 177, 178, 0, 0, 81, 96,
-	92, 131, 123, 79, 102, 146, 105, 112, 137, 181,
-	128, 142, 84, 164, 147, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 127, 0,
-	180, 89, 85, 67, 68, 75, 109, 0, 136, 94,
-	166, 91, 0, 657, 0, 0, 0, 108, 0, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 0, 0,
+	0, 0, 102, 0, 230, 0, 0, 179, 0, 0,
+	0, 0, 143, 0, 160, 104, 112, 71, 79, 0,
+	103, 133, 148, 152, 0, 0, 0, 89, 0, 150,
+	138, 172, 0, 0, 139, 149, 118, 165, 144, 0,
+	180, 181, 162, 178, 188, 73, 161, 171, 86, 153,
+	75, 169, 159, 125, 108, 109, 74, 0, 147, 93,
+	100, 91, 135, 166, 167, 90, 191, 193, 80, 177,
+	77, 81, 176, 132, 164, 170, 126, 123, 76, 168,
+	124, 122, 111, 96, 105, 141, 121, 142, 106, 129,
+	128, 130, 0, 0, 0, 158, 174, 192, 83, 0,
+	154, 163, 182, 183, 184, 185, 186, 187, 0, 0,
+	84, 101, 95, 140, 131, 82, 107, 155, 110, 120,
+	146, 190, 137, 151, 87, 173, 156, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 205, 0, 656, 0, 0, 0, 0, 0, 0,
-	82, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 97, 0, 0, 0, 0, 0, 0, 0, 70,
+	78, 114, 0, 145, 98, 175, 136, 0, 189, 92,
+	88, 69, 72, 117, 0, 0, 0, 0, 0, 0,
+	134, 99, 115, 0, 94, 0, 0, 0, 119, 0,
+	0, 113, 0, 116, 0, 0, 157, 127, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 218, 0, 0, 0, 0,
+	0, 0, 0, 0, 85, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 0, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 127, 0,
-	180, 89, 85, 67, 0, 0, 0, 0, 68, 75,
-	109, 91, 136, 94, 166, 0, 0, 108, 0, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 55, 0,
-	0, 635, 0, 0, 0, 0, 0, 0, 0, 0,
-	82, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 102, 0, 0, 0, 0, 179, 0,
+	0, 0, 0, 143, 0, 160, 104, 112, 71, 79,
+	0, 103, 133, 148, 152, 0, 0, 0, 89, 0,
+	150, 138, 172, 0, 0, 139, 149, 118, 165, 144,
+	0, 180, 181, 162, 178, 188, 73, 161, 171, 86,
+	153, 75, 169, 159, 125, 108, 109, 74, 0, 147,
+	93, 100, 91, 135, 166, 167, 90, 191, 193, 80,
+	177, 77, 81, 176, 132, 164, 170, 126, 123, 76,
+	168, 124, 122, 111, 96, 105, 141, 121, 142, 106,
+	129, 128, 130, 0, 0, 0, 158, 174, 192, 83,
+	0, 154, 163, 182, 183, 184, 185, 186, 187, 0,
+	0, 84, 101, 95, 140, 131, 82, 107, 155, 110,
+	120, 146, 190, 137, 151, 87, 173, 156, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 97, 0, 0, 0, 0, 0, 0, 0,
+	70, 78, 114, 0, 145, 98, 175, 136, 0, 189,
+	92, 88, 69, 72, 117, 0, 0, 0, 0, 0,
+	0, 134, 99, 115, 0, 94, 0, 0, 0, 119,
+	0, 0, 113, 0, 116, 0, 0, 157, 127, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 0, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 127, 0,
-	180, 89, 85, 67, 0, 0, 0, 0, 68, 75,
-	109, 91, 136, 94, 166, 0, 0, 108, 0, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 67, 0, 0, 0,
+	0, 0, 0, 0, 0, 85, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 65, 0, 64, 0, 0, 0, 0, 0, 0,
-	82, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 0, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 127, 0,
-	180, 89, 85, 67, 0, 0, 0, 0, 68, 75,
-	109, 91, 136, 94, 166, 0, 0, 108, 0, 110,
-	0, 0, 148, 119, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 102, 0, 0, 0, 0, 179,
+	0, 0, 0, 0, 143, 0, 160, 104, 112, 71,
+	79, 0, 103, 133, 148, 152, 0, 0, 0, 89,
+	0, 150, 138, 172, 0, 0, 139, 149, 118, 165,
+	144, 0, 180, 181, 162, 178, 188, 73, 161, 171,
+	86, 153, 75, 169, 159, 125, 108, 109, 74, 0,
+	147, 93, 100, 91, 135, 166, 167, 90, 191, 193,
+	80, 177, 77, 81, 176, 132, 164, 170, 126, 123,
+	76, 168, 124, 122, 111, 96, 105, 141, 121, 142,
+	106, 129, 128, 130, 0, 0, 0, 158, 174, 192,
+	83, 0, 154, 163, 182, 183, 184, 185, 186, 187,
+	0, 0, 84, 101, 95, 140, 131, 82, 107, 155,
+	110, 120, 146, 190, 137, 151, 87, 173, 156, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 97, 0, 0, 0, 0, 0, 0,
+	0, 70, 78, 114, 0, 145, 98, 175, 136, 0,
+	189, 92, 88, 69, 72, 117, 0, 0, 0, 0,
+	0, 0, 134, 99, 115, 0, 94, 0, 0, 0,
+	119, 0, 0, 113, 0, 116, 0, 0, 157, 127,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 296, 0, 0,
+	0, 0, 0, 0, 0, 0, 85, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 205, 0, 544, 0, 0, 0, 0, 0, 0,
-	82, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 102, 0, 0, 0, 0,
+	179, 0, 0, 0, 0, 143, 0, 160, 104, 112,
+	71, 79, 0, 103, 133, 148, 152, 0, 0, 0,
+	89, 0, 150, 138, 172, 0, 0, 139, 149, 118,
+	165, 144, 0, 180, 181, 162, 178, 188, 73, 161,
+	171, 86, 153, 75, 169, 159, 125, 108, 109, 74,
+	0, 147, 93, 100, 91, 135, 166, 167, 90, 191,
+	193, 80, 177, 77, 81, 176, 132, 164, 170, 126,
+	123, 76, 168, 124, 122, 111, 96, 105, 141, 121,
+	142, 106, 129, 128, 130, 0, 0, 0, 158, 174,
+	192, 83, 0, 154, 163, 182, 183, 184, 185, 186,
+	187, 0, 0, 84, 101, 95, 140, 131, 82, 107,
+	155, 110, 120, 146, 190, 137, 151, 87, 173, 156,
 	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
-	0, 170, 0, 0, 0, 0, 134, 0, 151, 99,
-	107, 69, 76, 0, 98, 125, 139, 143, 0, 0,
-	0, 86, 0, 141, 129, 163, 0, 130, 140, 111,
-	156, 135, 0, 171, 172, 153, 169, 179, 70, 152,
-	162, 83, 144, 72, 160, 150, 117, 103, 104, 71,
-	0, 138, 90, 95, 88, 126, 157, 158, 87, 182,
-	77, 168, 74, 78, 167, 124, 155, 161, 118, 115,
-	73, 159, 116, 114, 106, 93, 100, 132, 113, 133,
-	101, 121, 120, 122, 0, 0, 0, 149, 165, 183,
-	80, 0, 145, 154, 173, 174, 175, 176, 177, 178,
-	0, 0, 81, 96, 92, 131, 123, 79, 102, 146,
-	105, 112, 137, 181, 128, 142, 84, 164, 147, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 68, 75,
-	109, 0, 136, 94, 166, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 0, 0, 626, 91, 0,
-	0, 0, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 65, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 340, 0,
-	0, 0, 0, 0, 0, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 68, 75, 109, 91, 136,
-	94, 166, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 65, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 68, 75, 109, 91, 136,
-	94, 166, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 65, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 217, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 68, 75, 109, 91, 136,
-	94, 166, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 205, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 68, 75, 109, 91, 136,
-	94, 166, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 65, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 127, 0, 180, 89, 85,
-	67, 0, 0, 0, 0, 68, 75, 109, 91, 136,
-	94, 166, 0, 0, 108, 0, 110, 0, 0, 148,
-	119, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 281, 0,
-	0, 0, 0, 0, 0, 0, 0, 82, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 97, 0, 0, 0, 0, 170, 0,
-	0, 0, 0, 134, 0, 151, 99, 107, 69, 76,
-	0, 98, 125, 139, 143, 0, 0, 0, 86, 0,
-	141, 129, 163, 0, 130, 140, 111, 156, 135, 0,
-	171, 172, 153, 169, 179, 70, 152, 162, 83, 144,
-	72, 160, 150, 117, 103, 104, 71, 0, 138, 90,
-	95, 88, 126, 157, 158, 87, 182, 77, 168, 74,
-	78, 167, 124, 155, 161, 118, 115, 73, 159, 116,
-	114, 106, 93, 100, 132, 113, 133, 101, 121, 120,
-	122, 0, 0, 0, 149, 165, 183, 80, 0, 145,
-	154, 173, 174, 175, 176, 177, 178, 0, 0, 81,
-	96, 92, 131, 123, 79, 102, 146, 105, 112, 137,
-	181, 128, 142, 84, 164, 147, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 68, 75, 109, 0, 136,
-	94, 166,
+	0, 0, 0, 0, 97, 0, 0, 0, 0, 0,
+	0, 0, 70, 78, 114, 0, 145, 98, 175,
 }
 var yyPact = [...]int{
 
-	2105, -1000, -195, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
+	2269, -1000, -208, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, 961, 11730, 1011, -1000, -1000, -1000, -1000, -1000,
-	-1000, 244, 9768, 45, 130, -17, 12707, 125, 2184, 13187,
-	-1000, 14, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -96,
-	-106, -1000, 99, -1000, -1000, -1000, -1000, -1000, 953, 958,
-	701, -1000, 937, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, 995, 12639, 1043, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, 437, 10200, 105, 204, 59, 13455, 282,
+	1496, 13997, -1000, 70, -1000, -1000, 86, -1000, -1000, -1000,
+	-1000, -27, -51, -1000, 93, -1000, -1000, -1000, -1000, -1000,
+	984, 993, 795, -1000, 965, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
@@ -2120,24 +2241,26 @@ var yyPact = [...]int{
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, 769, 934, 834, -1000, 7489, 75,
-	75, 12467, 5946, -1000, -1000, 258, 13187, 117, 13187, -164,
-	73, 73, 73, -1000, -1000, -1000, -1000, 124, 13187, 299,
-	-1000, 13187, 71, 575, 71, 71, 71, 13187, -1000, 163,
-	13187, 553, 898, 3516, 94, 3516, 3516, -1000, 3516, 3516,
-	-1000, 3516, 20, 3516, -58, 980, -1000, -1000, -1000, -1000,
-	-40, -1000, 3516, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, -1000, -1000, 534, 910, 8260, 8260,
-	99, 11730, 703, 961, -1000, 99, -1000, -1000, -1000, 878,
-	-1000, -1000, 392, 995, -1000, 9528, 161, -1000, 8260, 1630,
-	703, -1000, -1000, 703, -1000, -1000, 147, -1000, -1000, 9031,
-	9031, 9031, 9031, 9031, 9031, 9031, 9031, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, 703, -1000, 6718, 703, 703, 703, 703, 703, 703,
-	703, 703, 8260, 703, 703, 703, 703, 703, 703, 703,
-	703, 703, 703, 703, 703, 703, 703, 703, 12227, 11490,
-	13187, 721, 689, -1000, -1000, 158, 695, 5676, -136, -1000,
-	-1000, -1000, 330, 11250, -1000, -1000, -1000, 893, -1000, -1000,
+	-1000, -1000, -1000, -1000, 852, 960, -1000, -1000, 870, -1000,
+	8032, 156, 156, 13184, 6134, 5276, -1000, -1000, 333, 13997,
+	216, 188, 13997, -110, 150, 150, -1000, -1000, -1000, -1000,
+	203, 13997, 337, -1000, 13997, 144, 678, 144, 144, 144,
+	13997, -1000, 248, 13997, -1000, 673, 909, 4132, 124, 4132,
+	4132, -1000, 4132, 4132, -1000, 4132, 76, 4132, -29, 1008,
+	-1000, -1000, -1000, -1000, 23, -1000, 4132, -1000, -1000, -1000,
+	-1000, -1000, -1000, 93, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, 605, 922, 8845, 8845, 93, 12639, 668, 995, -1000,
+	93, -1000, -1000, -1000, 901, -1000, -1000, 460, 1027, -125,
+	9929, 246, -1000, 8845, 2464, 668, -1000, -1000, 668, -1000,
+	-1000, 217, -1000, -1000, 9658, 9658, 9658, 9658, 9658, 9658,
+	9658, 259, 9658, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, -1000, -1000, 668, 243, -1000,
+	7219, 668, 668, 668, 668, 668, 668, 668, 668, 8845,
+	668, 668, 668, 668, 668, 668, 668, 668, 668, 668,
+	668, 668, 668, 668, 668, 668, 12910, 11284, 13997, 759,
+	744, -1000, -1000, 242, 788, 5848, -61, -1000, -1000, -1000,
+	375, 12097, -1000, -1000, -1000, 907, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
@@ -2147,295 +2270,314 @@ var yyPact = [...]int{
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, -1000, -1000, 636, 13187, -1000, 1472,
-	-1000, 551, 3516, 100, 548, 264, 546, 13187, 13187, 3516,
-	27, 60, 120, 13187, 699, 87, 13187, 928, 795, 13187,
-	540, 525, -1000, 5406, -1000, 3516, 3516, -1000, -1000, -1000,
-	3516, 3516, 3516, 13187, 3516, 3516, -1000, -1000, -1000, -1000,
-	-1000, 3516, 3516, -1000, 993, 366, -1000, -1000, -1000, -1000,
-	8260, -1000, 794, -1000, -1000, -1000, -1000, -1000, -1000, 1002,
-	207, 396, 157, 698, -1000, 470, -1000, -1000, 99, 953,
-	534, 834, 11006, 805, -1000, -1000, 13187, -1000, 8260, 8260,
-	466, -1000, 11970, -1000, -1000, 4326, 232, 9031, 417, 387,
-	9031, 9031, 9031, 9031, 9031, 9031, 9031, 9031, 9031, 9031,
-	9031, 9031, 9031, 9031, 9031, 443, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, 521, -1000, 99, 622, 622, 177,
-	177, 177, 177, 177, 177, 177, 9288, 6975, 534, 618,
-	286, 6718, 7489, 7489, 8260, 8260, 8003, 7746, 7489, 935,
-	248, 286, 13427, -1000, -1000, 8774, -1000, -1000, -1000, -1000,
-	-1000, 534, -1000, -1000, -1000, -1000, -1000, -1000, -1000, 12947,
-	12947, 7489, 7489, 7489, 7489, 43, 13187, -1000, 675, 746,
-	-1000, -1000, -1000, 930, 10269, 703, 10766, 43, 648, 11490,
-	13187, -1000, -1000, 11490, 13187, 4056, 5136, 695, -136, 678,
-	-1000, -133, -85, 6460, 172, -1000, -1000, -1000, -1000, 3246,
-	375, 608, 393, -55, -1000, -1000, -1000, 707, -1000, 707,
-	707, 707, 707, -26, -26, -26, -26, -1000, -1000, -1000,
-	-1000, -1000, 765, 764, -1000, 707, 707, 707, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, 788,
+	672, 13997, -1000, 1840, -1000, 956, 13997, 428, 669, 4132,
+	179, 667, 666, 13997, 4132, 90, 119, 192, 13997, 792,
+	159, 13997, 951, 830, 13997, 636, 626, -1000, 5562, -1000,
+	4132, 4132, -1000, -1000, -1000, 4132, 4132, 4132, 13997, 4132,
+	4132, -1000, -1000, -1000, -1000, -1000, 4132, 4132, -1000, 1026,
+	376, -1000, -1000, -1000, -1000, 8845, -1000, 829, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, 1035, 288, 534, 237, 791,
+	-1000, 532, -1000, -1000, 93, 984, 605, 870, 11826, 843,
+	-1000, -1000, 13997, -114, 668, -1000, 8845, 8845, 559, -1000,
+	12368, -1000, -1000, 4704, 299, 9658, 503, 427, 9658, 9658,
+	9658, 9658, 9658, 9658, 9658, 9658, 9658, 9658, 9658, 9658,
+	9658, 9658, 9658, 9658, 9658, 563, 9658, 41, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, -1000, 623, -1000, 93, 578,
+	578, 255, 255, 255, 255, 255, 255, 255, 6405, 3065,
+	7490, 4990, 605, 665, 459, 7219, 8032, 8032, 8845, 8845,
+	8574, 8303, 8032, 961, 406, 459, 14268, 13726, -1000, -1000,
+	9387, -1000, -1000, -1000, -1000, -1000, 605, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, 13726, 13726, 8032, 8032, 8032, 8032,
+	109, 13997, -1000, 741, 404, -1000, -1000, -1000, 955, 10471,
+	668, 668, 668, 11555, 109, 724, 11284, 13997, -1000, -1000,
+	11284, 13997, 4418, 5276, 788, -61, 761, -1000, -87, -54,
+	6947, 256, -1000, -1000, -1000, -1000, 3846, 325, 699, 424,
+	-19, -1000, -1000, -1000, 804, -1000, 804, 804, 804, 804,
+	14, 14, 14, 14, -1000, -1000, -1000, -1000, -1000, 818,
+	817, -1000, 804, 804, 804, -1000, -1000, -1000, -1000, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, -1000, -1000, 748, 748, 748, 724,
-	724, 771, -1000, 13187, 3516, 927, 3516, -1000, 92, -1000,
-	12947, 12947, 13187, 13187, 139, 13187, 13187, 692, -1000, 13187,
-	3516, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, -1000, -1000, 13187, 311, 13187, 13187,
-	286, 13187, -1000, 856, 8260, 8260, 4866, 8260, -1000, -1000,
-	-1000, 534, 910, -1000, 935, 960, -1000, 876, 873, 7489,
-	-1000, -1000, 232, 272, -1000, -1000, 493, -1000, -1000, -1000,
-	-1000, 156, 703, -1000, 1992, -1000, -1000, -1000, -1000, 417,
-	9031, 9031, 9031, 345, 1992, 1971, 1885, 1442, 177, 547,
-	547, 225, 225, 225, 225, 225, 418, 418, -1000, -1000,
-	-1000, 534, -1000, -1000, -1000, 534, 7489, 686, -1000, -1000,
-	8260, -1000, 534, 607, 607, 383, 438, 309, 991, 607,
-	256, 990, 607, 607, 7489, 357, -1000, 8260, 534, -1000,
-	154, -1000, 472, 682, 681, 607, 534, 607, 607, 82,
-	703, -1000, 13427, 11490, 11490, 11490, 11490, 11490, -1000, 827,
-	818, -1000, 826, 816, 888, 13187, -1000, 612, 10269, 12947,
-	191, 703, -1000, 11730, 975, 11490, 645, -1000, 645, -1000,
-	153, -1000, -1000, 678, -136, -130, -1000, -1000, -1000, -1000,
-	286, -1000, 522, 677, 2976, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, 742, 499, -1000, 919, 206, 196, 490, 918,
-	-1000, -1000, -1000, 907, -1000, 348, -65, -1000, -1000, 448,
-	-26, -26, -1000, -1000, 172, 885, 172, 172, 172, 482,
-	482, -1000, -1000, -1000, -1000, 445, -1000, -1000, -1000, 444,
-	-1000, 791, 12947, 3516, -1000, -1000, -1000, -1000, 178, 178,
-	228, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, -1000, 41, 722, -1000, -1000, -1000, 26, 24, 79,
-	-1000, 3516, -1000, 366, -1000, 479, 8260, -1000, -1000, -1000,
-	846, 286, 286, 152, -1000, -1000, -1000, 13187, -1000, -1000,
-	-1000, -1000, 670, -1000, -1000, -1000, 3786, 7489, -1000, 345,
-	1992, 1697, -1000, 9031, 9031, -1000, -1000, 607, 7489, 286,
-	-1000, -1000, -1000, 93, 443, 93, 9031, 9031, -1000, 9031,
-	9031, -1000, -176, 660, 233, -1000, 8260, 424, -1000, 4866,
-	-1000, 9031, 9031, -1000, -1000, -1000, -1000, 790, 13427, 703,
-	-1000, 10025, 12947, 661, -1000, 303, 746, 747, 789, 835,
-	-1000, -1000, -1000, -1000, 814, -1000, 807, -1000, -1000, -1000,
-	-1000, 534, 676, -1000, 197, -1000, 111, 110, 107, 12947,
-	-1000, 961, 8260, 645, -1000, -1000, 159, -1000, -1000, -142,
-	-125, -1000, -1000, -1000, 3246, -1000, 3246, 12947, 58, -1000,
-	490, 490, -1000, -1000, -1000, 733, 788, 9031, -1000, -1000,
-	-1000, 582, 172, 172, -1000, 230, -1000, -1000, -1000, 604,
-	-1000, 602, 674, 579, 13187, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, 815, 815, 815, 807, 807, 93, -1000,
+	943, 822, -1000, 13997, 4132, 4132, 250, -1000, 13726, 13726,
+	13997, 13997, 219, 13997, 13997, 773, -1000, 13997, 4132, -1000,
 	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
-	-1000, 13187, -1000, -1000, -1000, -1000, -1000, 12947, -184, 487,
-	12947, 12947, 13187, -1000, 311, -1000, 286, -1000, 4596, -1000,
-	975, 11490, -1000, -1000, 534, -1000, 9031, 1992, 1992, -1000,
-	-1000, 534, 707, 707, -1000, 707, 724, -1000, 707, 4,
-	707, 3, 534, 534, 1906, 1846, 1747, 1380, 703, -171,
-	-1000, 286, 8260, -1000, 731, 709, -1000, 921, 623, 652,
-	-1000, -1000, 7232, 534, 573, 151, 571, -1000, 961, 13427,
-	8260, -1000, -1000, 8260, 708, -1000, 8260, -1000, -1000, -1000,
-	930, 12947, 6203, 703, 703, 703, 571, 953, 286, -1000,
-	-1000, -1000, -1000, 2976, -1000, 565, -1000, 707, -1000, -1000,
-	-1000, 12947, -51, 999, 1992, -1000, -1000, -1000, -1000, -1000,
-	-26, 478, -26, 426, -1000, 414, 3516, -1000, -1000, -1000,
-	-1000, 923, -1000, 4596, -1000, -1000, 706, -1000, -1000, -1000,
-	962, 669, -1000, 1992, -1000, -1000, 119, -1000, -1000, -1000,
-	-1000, -1000, -1000, -1000, -1000, 9031, 9031, 9031, 9031, 9031,
-	534, 471, 286, 9031, 9031, 917, -1000, 703, -1000, -1000,
-	85, 12947, 12947, -1000, 12947, 953, -1000, 286, 286, 12947,
-	286, 13187, -1000, -1000, 286, 703, 703, 12947, 12947, 12947,
-	10526, -1000, 146, 12947, -1000, 563, -1000, 210, -1000, -105,
-	172, -1000, 172, 574, 566, -1000, 703, 654, -1000, 275,
-	12947, 963, 957, -1000, -1000, 472, 472, 472, 472, 30,
-	-1000, -1000, 472, 472, 998, -1000, 703, -1000, 99, 142,
-	-1000, -1000, -1000, 559, -1000, 11490, 13427, 557, 557, 557,
-	191, 146, -1000, 474, 273, 453, -1000, 55, 12947, 355,
-	916, -1000, 909, -1000, -1000, -1000, -1000, -1000, 39, 4596,
-	3246, 533, 29, 8260, 8260, -1000, -1000, -1000, -1000, 534,
-	37, -187, -1000, -1000, 13427, 652, 534, 12947, -1000, 550,
-	534, -1000, -1000, -1000, -1000, -1000, -1000, 402, -1000, -1000,
-	13187, -1000, -1000, 425, -1000, -1000, 529, -1000, 12947, -1000,
-	-1000, 722, -1000, 803, 286, 640, -1000, 843, -181, -191,
-	639, -1000, -1000, -1000, -1000, -1000, 704, -1000, -1000, 39,
-	872, -184, 632, -1000, 949, 947, 8260, -1000, 832, -1000,
-	12947, -1000, 36, -1000, 803, -1000, 8260, 286, -185, 496,
-	34, -1000, 286, -188, 786, 703, -192, 785, -1000, 984,
-	8517, -1000, -1000, 986, 186, 186, 472, 534, -1000, -1000,
-	-1000, 63, 384, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, 13997, 453, 13997, 13997, 459, 13997,
+	-1000, 879, 8845, 8845, 4990, 8845, 936, -1000, -1000, 605,
+	922, -1000, 961, 989, -1000, 896, 887, 8032, -1000, -1000,
+	-1000, 668, 14268, 299, 353, -1000, -1000, 466, -1000, -1000,
+	-1000, -125, 668, 1959, -1000, -1000, -1000, -1000, 503, 9658,
+	9658, 9658, 9658, 734, 734, 1959, 1959, 2615, 1648, 1167,
+	366, 490, 490, 378, 378, 378, 378, 378, 684, 684,
+	-1000, -1000, -1000, 21, 22, 605, -1000, -1000, -1000, 25,
+	-1000, 605, 8032, 771, -1000, -1000, -1000, 8845, -1000, 605,
+	658, 658, 441, 545, 362, 1025, 658, 355, 1022, 658,
+	658, 8032, 444, -1000, 8845, 605, -1000, 235, 1014, -1000,
+	1681, 763, 762, 658, 605, 658, 658, 80, 668, -1000,
+	14268, 11284, 11284, 11284, 11284, 11284, 11284, -1000, 867, 866,
+	-1000, 855, 842, 841, 856, 13997, -1000, 663, 10471, 6676,
+	6676, 8845, 261, 668, -1000, 12639, 1005, 11284, 727, -1000,
+	727, -1000, 234, -1000, -1000, 761, -61, -47, -1000, -1000,
+	-1000, -1000, 459, -1000, 594, 758, 3560, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, 812, 616, -1000, 920, 265, 281,
+	595, 919, -1000, -1000, -1000, 911, -1000, 433, -43, -1000,
+	-1000, 531, 14, 14, -1000, -1000, 256, 903, 256, 256,
+	256, 565, 565, -1000, -1000, -1000, -1000, 501, -1000, -1000,
+	-1000, 495, -1000, -1000, -1000, 828, 13726, 4132, -1000, -1000,
+	-1000, 249, 249, 300, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, 106, 821, -1000, -1000, -1000,
+	87, 81, 158, -1000, 4132, -1000, 376, -1000, 560, 8845,
+	-1000, -1000, -1000, 876, 459, 459, -1000, -1000, 932, -1000,
+	-1000, 13997, -1000, -1000, -1000, -1000, 770, 8845, 660, -1000,
+	-1000, -1000, -1000, -114, 8032, -1000, 734, 734, 1959, 2597,
+	-1000, 9658, -1000, 9658, -1000, 9658, -1000, -1000, -1000, 658,
+	8032, 459, -1000, -1000, -1000, -40, 563, -40, 9658, 9658,
+	-1000, 9658, 9658, -1000, -139, 756, 382, -1000, 8845, 392,
+	-1000, 4990, 8845, -1000, 9658, 9658, -1000, -1000, -1000, -1000,
+	827, 14268, 668, -1000, 10742, 13726, 772, -1000, 358, 404,
+	811, 826, 172, 172, -1000, -1000, -1000, -1000, 863, -1000,
+	861, -1000, 857, -1000, -1000, -1000, -1000, 605, 755, -1000,
+	357, -1000, 459, 668, 668, 605, 467, -1000, 186, 185,
+	184, 13726, -1000, 995, 8845, 727, -1000, -1000, 274, -1000,
+	-1000, -93, -72, -1000, -1000, -1000, 3846, -1000, 3846, 13726,
+	125, -1000, 595, 595, -1000, -1000, -1000, 808, 825, 9658,
+	-1000, -1000, -1000, 698, 256, 256, -1000, 316, -1000, -1000,
+	-1000, 653, -1000, 649, 751, 640, 13997, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, 13997, -1000, -1000, -1000, -1000, -1000, 13726,
+	-163, 585, 13726, 13726, 13997, -1000, 453, -1000, 459, -1000,
+	-1000, -1000, -1000, 1005, 11284, 635, 542, -1000, 14268, -1000,
+	605, -1000, -1000, 9658, 1959, 1959, 255, -1000, -1000, 605,
+	804, 804, -1000, 804, 807, -1000, 804, 61, 804, 60,
+	-1000, -1000, -1000, -1000, -1000, -1000, -1000, -1000, 804, 605,
+	605, 2346, 1992, 1872, 1729, 668, -133, -1000, 459, 8845,
+	-1000, 467, 2151, 1822, -1000, 925, 710, 726, -1000, -1000,
+	7761, 605, 633, 228, 631, -1000, 995, 14268, 8845, -1000,
+	-1000, 8845, 805, -1000, 8845, -1000, -1000, -1000, -1000, -1000,
+	955, 6676, 6676, 11284, 14268, 955, 955, 668, 668, 668,
+	631, 984, 459, -1000, -1000, -1000, -1000, 3560, -1000, 621,
+	-1000, 804, -1000, -1000, -1000, 13726, -10, 1034, 1959, -1000,
+	-1000, -1000, -1000, -1000, 14, 554, 14, 494, -1000, 486,
+	4132, -1000, -1000, -1000, -1000, 927, -1000, 4990, -1000, -1000,
+	800, -1000, -1000, -1000, 999, 747, -1000, 8845, 12368, -1000,
+	-1000, 1959, -1000, -1000, 154, -1000, -1000, -1000, -1000, -1000,
+	-1000, -1000, -1000, -1000, 9658, 9658, 9658, 9658, 9658, 605,
+	553, 459, -1000, 9658, 9658, 917, -1000, 668, -1000, -1000,
+	120, 13726, 13726, -1000, 13726, 984, -1000, 459, 459, 13726,
+	459, 13997, -1000, -1000, 280, 605, 13997, 13997, 13726, 13726,
+	13726, 11013, -1000, 218, 13726, -1000, 612, -1000, 266, -1000,
+	-86, 256, -1000, 256, 695, 685, -1000, 668, 743, -1000,
+	323, 13726, 991, 990, 477, -1000, -1000, -1000, 1681, 1681,
+	1681, 1681, -6, -1000, -1000, 1681, 1681, 1032, -1000, 668,
+	-1000, 93, 226, -1000, -1000, -1000, 608, -1000, -1000, -1000,
+	-1000, -1000, 604, 604, 604, 261, 218, -1000, 575, 315,
+	550, -1000, 122, 13726, 446, 916, -1000, 913, -1000, -1000,
+	-1000, -1000, -1000, 103, 4990, 3846, 601, 94, 8845, 8845,
+	12368, -1000, -1000, -1000, -1000, 605, 66, -169, -1000, -1000,
+	14268, 726, 605, 13726, -1000, -1000, -1000, -1000, -1000, -1000,
+	-1000, 469, -1000, -1000, 13997, -1000, -1000, 478, -1000, -1000,
+	599, -1000, 13726, -1000, -1000, 821, -1000, 844, 459, 725,
+	-1000, -1000, 874, -161, -203, 712, -1000, -1000, -1000, 798,
+	-1000, -1000, 103, 886, -163, 711, -1000, 979, 977, 8845,
+	-1000, 873, -1000, 13726, -1000, 100, -1000, 844, 973, 8845,
+	459, -167, 592, 98, -1000, 969, 459, -170, 824, 668,
+	8845, -204, 813, -1000, 1020, 9116, 459, -1000, -1000, 1030,
+	258, 258, 1681, 605, -1000, -1000, -1000, 132, 517, -1000,
+	-1000, -1000, -1000, -1000, -1000,
 }
 var yyPgo = [...]int{
 
-	0, 1225, 21, 179, 1222, 1221, 1220, 95, 1219, 1217,
-	1215, 1211, 1210, 1206, 1205, 1204, 1202, 1201, 1200, 1199,
-	1189, 1187, 1186, 1185, 1184, 1182, 1180, 1178, 1177, 141,
-	1176, 1175, 1170, 65, 1167, 76, 1164, 1158, 48, 145,
-	51, 47, 369, 1155, 36, 11, 75, 1154, 1153, 1151,
-	14, 1150, 43, 1148, 1147, 77, 1146, 1145, 60, 1144,
-	1139, 1406, 1137, 73, 1136, 7, 28, 1135, 1134, 1131,
-	1122, 71, 901, 1121, 1120, 18, 1117, 1116, 83, 1113,
-	58, 9, 17, 15, 30, 1112, 479, 8, 1111, 57,
-	1110, 1109, 1105, 1104, 35, 1103, 62, 1101, 19, 61,
-	1100, 1098, 13, 1097, 16, 70, 29, 25, 5, 78,
-	63, 1096, 23, 69, 54, 1095, 1094, 177, 1092, 1091,
-	52, 1090, 1089, 26, 156, 235, 1086, 1084, 1083, 1082,
-	44, 0, 670, 411, 72, 1081, 1080, 1079, 1572, 45,
-	32, 34, 20, 50, 1533, 49, 1078, 1077, 46, 1076,
-	1073, 1069, 1068, 1067, 1066, 1065, 101, 1061, 1060, 1057,
-	109, 56, 1054, 1053, 68, 42, 1052, 1051, 1050, 55,
-	64, 1046, 1045, 59, 38, 1041, 1040, 1039, 1037, 1036,
-	41, 31, 1035, 27, 1033, 12, 1032, 39, 1029, 4,
-	1027, 10, 1025, 3, 1022, 6, 53, 1, 1021, 2,
-	1020, 1019, 66, 166, 79, 1017, 103,
+	0, 1267, 68, 260, 1265, 1263, 1262, 148, 1261, 1260,
+	1257, 1256, 1253, 1252, 1250, 1248, 1247, 1245, 1243, 1242,
+	1241, 1240, 1239, 1236, 1234, 1232, 1231, 1230, 1229, 1228,
+	142, 1227, 1226, 1224, 78, 1223, 79, 1222, 1221, 44,
+	91, 48, 73, 58, 1220, 50, 1219, 1330, 1218, 33,
+	29, 77, 1217, 1216, 62, 23, 21, 49, 1207, 1206,
+	98, 1205, 1203, 57, 1201, 1199, 1197, 51, 1196, 67,
+	1195, 14, 40, 1194, 1193, 1192, 1191, 76, 1, 1190,
+	1188, 16, 1185, 1184, 89, 1183, 56, 9, 15, 38,
+	26, 1182, 34, 59, 1181, 1180, 63, 1178, 1177, 1176,
+	1175, 24, 1174, 65, 1172, 1170, 20, 64, 1167, 1166,
+	4, 1165, 13, 71, 47, 32, 7, 80, 66, 1164,
+	22, 74, 54, 1158, 1156, 242, 1155, 1154, 55, 1153,
+	1152, 37, 219, 235, 1151, 1150, 1149, 1148, 93, 0,
+	1198, 87, 28, 1146, 1145, 1143, 2155, 1140, 19, 17,
+	30, 46, 1401, 60, 1120, 1107, 61, 1100, 1099, 1098,
+	1095, 1094, 1091, 1090, 269, 1089, 1088, 1087, 31, 36,
+	1086, 1085, 75, 27, 1078, 1077, 1075, 53, 72, 1074,
+	1073, 70, 45, 1072, 1071, 1070, 1069, 1068, 43, 10,
+	1066, 18, 1064, 11, 1062, 41, 1060, 8, 1059, 12,
+	1057, 5, 1054, 6, 52, 2, 1053, 3, 1052, 1051,
+	780, 737, 81, 1033, 82,
 }
 var yyR1 = [...]int{
 
-	0, 200, 201, 201, 1, 1, 1, 1, 1, 1,
+	0, 208, 209, 209, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-	1, 1, 1, 1, 2, 2, 2, 2, 6, 6,
-	8, 8, 7, 9, 3, 4, 5, 5, 10, 10,
-	32, 32, 11, 12, 12, 12, 12, 204, 204, 55,
-	55, 56, 56, 105, 105, 13, 13, 13, 13, 110,
-	110, 114, 114, 114, 115, 115, 115, 115, 146, 146,
-	14, 14, 14, 14, 14, 14, 14, 195, 195, 194,
-	193, 193, 192, 192, 191, 20, 176, 178, 178, 177,
-	177, 177, 177, 170, 149, 149, 149, 149, 152, 152,
-	150, 150, 150, 150, 150, 150, 150, 150, 150, 151,
-	151, 151, 151, 151, 153, 153, 153, 153, 153, 154,
-	154, 154, 154, 154, 154, 154, 154, 154, 154, 154,
-	154, 154, 154, 154, 155, 155, 155, 155, 155, 155,
-	155, 155, 169, 169, 156, 156, 164, 164, 165, 165,
-	165, 162, 162, 163, 163, 166, 166, 166, 158, 158,
-	159, 159, 167, 167, 160, 160, 160, 161, 161, 161,
-	168, 168, 168, 168, 168, 157, 157, 171, 171, 186,
-	186, 185, 185, 185, 175, 175, 182, 182, 182, 182,
-	182, 173, 173, 174, 174, 184, 184, 183, 172, 172,
-	187, 187, 187, 187, 198, 199, 197, 197, 197, 197,
-	197, 179, 179, 179, 180, 180, 180, 181, 181, 181,
-	15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
-	15, 15, 15, 196, 196, 196, 196, 196, 196, 196,
-	196, 196, 196, 196, 190, 188, 188, 189, 189, 16,
-	21, 21, 17, 17, 17, 17, 17, 18, 18, 22,
-	23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+	1, 1, 1, 1, 1, 2, 2, 2, 2, 6,
+	6, 8, 8, 7, 9, 3, 4, 5, 5, 10,
+	10, 33, 33, 11, 12, 12, 12, 12, 212, 212,
+	60, 60, 61, 61, 113, 113, 13, 13, 13, 13,
+	13, 118, 118, 122, 122, 122, 123, 123, 123, 123,
+	154, 154, 14, 14, 14, 14, 14, 14, 14, 14,
+	203, 203, 202, 201, 201, 200, 200, 199, 20, 184,
+	186, 186, 185, 185, 185, 185, 178, 157, 157, 157,
+	157, 160, 160, 158, 158, 158, 158, 158, 158, 158,
+	158, 158, 159, 159, 159, 159, 159, 161, 161, 161,
+	161, 161, 162, 162, 162, 162, 162, 162, 162, 162,
+	162, 162, 162, 162, 162, 162, 162, 163, 163, 163,
+	163, 163, 163, 163, 163, 177, 177, 164, 164, 172,
+	172, 173, 173, 173, 170, 170, 171, 171, 174, 174,
+	174, 166, 166, 167, 167, 175, 175, 168, 168, 168,
+	169, 169, 169, 176, 176, 176, 176, 176, 165, 165,
+	179, 179, 194, 194, 193, 193, 193, 183, 183, 190,
+	190, 190, 190, 190, 181, 181, 182, 182, 192, 192,
+	191, 180, 180, 195, 195, 195, 195, 206, 207, 205,
+	205, 205, 205, 205, 187, 187, 187, 188, 188, 188,
+	189, 189, 189, 15, 15, 15, 15, 15, 15, 15,
+	15, 15, 15, 15, 15, 15, 204, 204, 204, 204,
+	204, 204, 204, 204, 204, 204, 204, 198, 196, 196,
+	197, 197, 16, 21, 21, 17, 17, 17, 17, 17,
+	18, 18, 22, 22, 23, 23, 23, 23, 23, 23,
 	23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
 	23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
-	23, 23, 121, 121, 119, 119, 122, 122, 120, 120,
-	120, 123, 123, 123, 147, 147, 147, 24, 24, 26,
-	26, 27, 28, 25, 25, 25, 25, 25, 25, 25,
-	19, 205, 29, 30, 30, 31, 31, 31, 35, 35,
-	35, 33, 33, 34, 34, 40, 40, 39, 39, 41,
-	41, 41, 41, 135, 135, 135, 134, 134, 43, 43,
-	44, 44, 45, 45, 46, 46, 46, 46, 46, 64,
-	64, 49, 49, 48, 48, 50, 51, 51, 51, 104,
-	104, 106, 106, 47, 47, 47, 47, 52, 52, 53,
-	53, 54, 54, 142, 142, 141, 141, 141, 140, 140,
-	57, 57, 57, 59, 58, 58, 58, 58, 60, 60,
-	62, 62, 61, 61, 63, 65, 65, 65, 65, 66,
-	66, 42, 42, 42, 42, 42, 42, 42, 118, 118,
-	68, 68, 67, 67, 67, 67, 67, 67, 67, 67,
-	67, 67, 79, 79, 79, 79, 79, 79, 69, 69,
-	69, 69, 69, 69, 69, 38, 38, 80, 80, 80,
-	86, 81, 81, 72, 72, 72, 72, 72, 72, 72,
-	72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-	72, 72, 72, 72, 72, 72, 72, 72, 72, 72,
-	72, 72, 72, 72, 72, 76, 76, 76, 74, 74,
-	74, 74, 74, 74, 74, 74, 74, 74, 74, 74,
-	74, 75, 75, 75, 75, 75, 75, 75, 75, 75,
-	75, 75, 75, 75, 75, 75, 75, 206, 206, 78,
-	77, 77, 77, 77, 77, 77, 36, 36, 36, 36,
-	36, 145, 145, 148, 148, 148, 148, 148, 148, 148,
-	148, 148, 148, 148, 148, 148, 90, 90, 37, 37,
-	88, 88, 89, 91, 91, 87, 87, 87, 71, 71,
-	71, 71, 71, 71, 71, 71, 73, 73, 73, 92,
-	92, 93, 93, 94, 94, 95, 95, 96, 97, 97,
-	97, 98, 98, 98, 98, 99, 99, 99, 100, 100,
-	101, 101, 102, 102, 102, 70, 70, 70, 70, 70,
-	70, 103, 103, 103, 103, 107, 107, 82, 82, 84,
-	84, 83, 85, 108, 108, 112, 109, 109, 113, 113,
-	113, 113, 111, 111, 111, 137, 137, 137, 116, 116,
-	124, 124, 125, 125, 117, 117, 126, 126, 126, 126,
-	126, 126, 126, 126, 126, 126, 127, 127, 127, 128,
-	128, 129, 129, 129, 136, 136, 132, 132, 133, 133,
-	138, 138, 139, 139, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
-	130, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 131, 131,
-	131, 131, 131, 131, 131, 131, 131, 131, 202, 203,
-	143, 144, 144, 144,
+	23, 23, 23, 23, 23, 23, 129, 129, 127, 127,
+	130, 130, 128, 128, 128, 131, 131, 131, 155, 155,
+	155, 24, 24, 27, 27, 28, 29, 25, 25, 26,
+	26, 26, 26, 26, 26, 19, 213, 30, 31, 31,
+	32, 32, 32, 32, 32, 36, 36, 36, 34, 34,
+	35, 35, 41, 41, 40, 40, 42, 42, 42, 43,
+	43, 44, 44, 45, 45, 46, 46, 143, 143, 143,
+	142, 142, 48, 48, 49, 49, 50, 50, 51, 51,
+	51, 51, 51, 51, 51, 70, 70, 54, 54, 53,
+	53, 55, 55, 56, 56, 56, 112, 112, 114, 114,
+	52, 52, 52, 52, 52, 57, 57, 58, 58, 59,
+	59, 150, 150, 149, 149, 149, 148, 148, 62, 62,
+	62, 65, 63, 63, 63, 63, 64, 64, 66, 66,
+	68, 68, 67, 67, 69, 71, 71, 71, 71, 72,
+	72, 47, 47, 47, 47, 47, 47, 47, 126, 126,
+	74, 74, 73, 73, 73, 73, 73, 73, 73, 73,
+	73, 73, 73, 73, 73, 85, 85, 85, 85, 85,
+	85, 75, 75, 75, 75, 75, 75, 75, 39, 39,
+	86, 86, 86, 92, 87, 87, 78, 78, 78, 78,
+	78, 78, 78, 78, 78, 78, 78, 78, 78, 78,
+	78, 78, 78, 78, 78, 78, 78, 78, 78, 78,
+	78, 78, 78, 78, 78, 78, 78, 78, 78, 78,
+	78, 78, 82, 82, 82, 80, 80, 80, 80, 80,
+	80, 80, 80, 80, 80, 80, 80, 80, 80, 81,
+	81, 81, 81, 81, 81, 81, 81, 81, 81, 81,
+	81, 81, 81, 81, 81, 214, 214, 84, 83, 83,
+	83, 83, 83, 83, 37, 37, 37, 37, 37, 153,
+	153, 156, 156, 156, 156, 156, 156, 156, 156, 156,
+	156, 156, 156, 156, 156, 156, 156, 156, 156, 156,
+	156, 156, 156, 97, 97, 38, 38, 95, 95, 96,
+	98, 98, 93, 93, 94, 94, 77, 77, 77, 77,
+	77, 77, 77, 77, 79, 79, 79, 99, 99, 100,
+	100, 101, 101, 102, 102, 103, 104, 104, 104, 105,
+	105, 105, 106, 106, 106, 106, 107, 107, 107, 108,
+	108, 109, 109, 110, 110, 110, 110, 76, 76, 76,
+	76, 76, 76, 111, 111, 111, 111, 115, 115, 88,
+	88, 90, 90, 89, 91, 116, 116, 120, 117, 117,
+	121, 121, 121, 121, 119, 119, 119, 145, 145, 145,
+	124, 124, 132, 132, 133, 133, 125, 125, 134, 134,
+	134, 134, 134, 134, 134, 134, 134, 134, 135, 135,
+	135, 136, 136, 137, 137, 137, 144, 144, 140, 140,
+	141, 141, 146, 146, 147, 147, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 138, 138,
+	138, 138, 138, 138, 138, 138, 138, 138, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+	139, 139, 139, 210, 211, 151, 152, 152, 152,
 }
 var yyR2 = [...]int{
 
 	0, 2, 0, 1, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-	1, 1, 1, 0, 4, 4, 6, 7, 0, 1,
-	1, 3, 5, 5, 11, 3, 1, 3, 7, 8,
-	1, 1, 9, 8, 7, 6, 6, 1, 1, 1,
-	3, 1, 3, 0, 4, 3, 4, 5, 4, 1,
-	3, 3, 2, 2, 2, 2, 2, 1, 1, 1,
-	2, 2, 8, 4, 6, 5, 5, 0, 2, 1,
-	0, 2, 1, 3, 3, 4, 4, 2, 4, 1,
-	3, 3, 3, 8, 3, 1, 1, 1, 2, 1,
-	1, 1, 1, 1, 1, 1, 1, 1, 1, 2,
-	2, 2, 2, 2, 1, 2, 2, 2, 1, 4,
-	4, 2, 2, 3, 3, 3, 3, 1, 1, 1,
-	1, 1, 6, 6, 1, 1, 1, 1, 1, 1,
-	1, 1, 1, 3, 0, 3, 0, 5, 0, 3,
-	5, 0, 1, 0, 1, 0, 1, 2, 0, 2,
-	0, 3, 0, 1, 0, 3, 3, 0, 2, 2,
-	0, 2, 1, 2, 1, 0, 2, 5, 4, 1,
-	2, 2, 3, 2, 0, 1, 2, 3, 3, 2,
-	2, 1, 1, 0, 1, 1, 3, 2, 3, 1,
-	10, 11, 11, 12, 3, 3, 1, 1, 2, 2,
-	2, 0, 1, 3, 1, 2, 3, 1, 1, 1,
-	6, 7, 7, 7, 7, 4, 5, 7, 5, 5,
-	5, 12, 7, 1, 1, 1, 1, 1, 1, 1,
-	1, 1, 1, 1, 7, 1, 3, 8, 8, 3,
-	3, 5, 4, 6, 5, 4, 4, 3, 2, 3,
-	4, 4, 3, 4, 4, 4, 4, 4, 4, 3,
-	3, 2, 3, 3, 2, 3, 4, 3, 7, 5,
-	4, 2, 4, 2, 2, 2, 2, 3, 3, 5,
-	2, 3, 1, 1, 0, 1, 1, 1, 0, 2,
-	2, 0, 2, 2, 0, 1, 1, 2, 1, 1,
-	2, 1, 1, 2, 2, 2, 2, 2, 3, 3,
-	2, 0, 2, 0, 2, 1, 2, 2, 0, 1,
-	1, 0, 1, 0, 1, 0, 1, 1, 3, 1,
-	2, 3, 5, 0, 1, 2, 1, 1, 0, 2,
-	1, 3, 1, 1, 1, 3, 1, 3, 6, 3,
-	7, 0, 1, 1, 3, 3, 1, 4, 4, 1,
-	3, 1, 3, 4, 4, 4, 3, 2, 4, 0,
-	1, 0, 2, 0, 1, 0, 1, 2, 1, 1,
-	1, 2, 2, 1, 2, 3, 2, 3, 2, 2,
+	1, 1, 1, 1, 0, 4, 4, 6, 7, 0,
+	1, 1, 3, 5, 5, 11, 3, 1, 3, 7,
+	8, 1, 1, 9, 8, 7, 6, 6, 1, 1,
+	1, 3, 1, 3, 0, 4, 3, 4, 5, 4,
+	3, 1, 3, 3, 2, 2, 2, 2, 2, 1,
+	1, 1, 2, 2, 5, 8, 4, 6, 5, 5,
+	0, 2, 1, 0, 2, 1, 3, 3, 4, 4,
+	2, 4, 1, 3, 3, 3, 8, 3, 1, 1,
+	1, 2, 1, 1, 1, 1, 1, 1, 1, 1,
+	1, 1, 2, 2, 2, 2, 2, 1, 2, 2,
+	2, 1, 4, 4, 2, 2, 3, 3, 3, 3,
+	1, 1, 1, 1, 1, 6, 6, 1, 1, 1,
+	1, 1, 1, 1, 1, 1, 3, 0, 3, 0,
+	5, 0, 3, 5, 0, 1, 0, 1, 0, 1,
+	2, 0, 2, 0, 3, 0, 1, 0, 3, 3,
+	0, 2, 2, 0, 2, 1, 2, 1, 0, 2,
+	5, 4, 1, 2, 2, 3, 2, 0, 1, 2,
+	3, 3, 2, 2, 1, 1, 0, 1, 1, 3,
+	2, 3, 1, 10, 11, 11, 12, 3, 3, 1,
+	1, 2, 2, 2, 0, 1, 3, 1, 2, 3,
+	1, 1, 1, 6, 7, 7, 7, 7, 4, 5,
+	7, 5, 5, 5, 12, 7, 1, 1, 1, 1,
+	1, 1, 1, 1, 1, 1, 1, 7, 1, 3,
+	8, 8, 3, 3, 5, 4, 6, 5, 4, 4,
+	3, 2, 3, 2, 4, 4, 3, 4, 4, 4,
+	4, 4, 4, 3, 3, 2, 3, 3, 2, 3,
+	4, 3, 7, 5, 4, 2, 4, 2, 2, 2,
+	2, 3, 3, 5, 2, 3, 1, 1, 0, 1,
+	1, 1, 0, 2, 2, 0, 2, 2, 0, 1,
+	1, 2, 1, 1, 2, 1, 1, 2, 3, 2,
+	2, 2, 2, 3, 3, 2, 0, 2, 0, 2,
+	1, 2, 2, 1, 1, 0, 1, 1, 0, 1,
+	0, 1, 0, 1, 1, 3, 3, 2, 5, 0,
+	4, 1, 3, 0, 4, 3, 5, 0, 1, 2,
+	1, 1, 0, 2, 1, 3, 1, 1, 1, 3,
+	1, 3, 6, 6, 6, 3, 7, 0, 1, 1,
+	3, 3, 1, 1, 4, 4, 1, 3, 1, 3,
+	4, 4, 4, 4, 3, 2, 4, 0, 1, 0,
+	2, 0, 1, 0, 1, 2, 1, 1, 1, 2,
+	2, 1, 2, 3, 2, 3, 2, 3, 2, 2,
 	2, 1, 1, 3, 3, 0, 5, 5, 5, 0,
 	2, 1, 3, 3, 2, 3, 1, 2, 0, 3,
-	1, 1, 3, 3, 4, 4, 5, 3, 4, 5,
-	6, 2, 1, 2, 1, 2, 1, 2, 1, 1,
-	1, 1, 1, 1, 1, 0, 2, 1, 1, 1,
-	3, 1, 3, 1, 1, 1, 1, 1, 3, 3,
-	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-	3, 3, 3, 2, 2, 2, 2, 2, 2, 2,
-	3, 1, 1, 1, 1, 4, 5, 6, 4, 4,
-	6, 6, 6, 8, 8, 8, 8, 9, 7, 5,
-	4, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-	2, 2, 2, 2, 2, 8, 8, 0, 2, 3,
-	4, 4, 4, 4, 4, 4, 0, 3, 4, 7,
-	3, 1, 1, 2, 3, 3, 1, 2, 2, 1,
-	2, 1, 2, 2, 1, 2, 0, 1, 0, 2,
-	1, 2, 4, 0, 2, 1, 3, 5, 1, 1,
-	1, 1, 1, 1, 1, 1, 1, 2, 2, 0,
-	3, 0, 2, 0, 3, 1, 3, 2, 0, 1,
-	1, 0, 2, 4, 4, 0, 2, 4, 0, 2,
-	1, 3, 2, 3, 2, 2, 1, 3, 5, 4,
-	6, 1, 3, 3, 5, 0, 5, 1, 3, 1,
-	2, 3, 1, 1, 3, 3, 1, 3, 3, 3,
-	3, 3, 1, 2, 1, 1, 1, 1, 1, 1,
-	0, 2, 0, 3, 0, 1, 1, 1, 1, 1,
-	1, 1, 1, 1, 1, 1, 0, 1, 1, 1,
-	1, 0, 1, 1, 0, 2, 1, 1, 1, 1,
+	1, 1, 3, 3, 4, 4, 5, 4, 5, 3,
+	3, 4, 5, 6, 2, 1, 2, 1, 2, 1,
+	2, 1, 1, 1, 1, 1, 1, 1, 0, 2,
+	1, 1, 1, 3, 1, 3, 1, 1, 1, 1,
+	1, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+	3, 3, 3, 3, 3, 3, 2, 2, 2, 2,
+	2, 2, 2, 4, 5, 3, 4, 3, 1, 1,
+	1, 1, 4, 5, 6, 4, 4, 6, 6, 6,
+	8, 8, 8, 8, 9, 7, 5, 4, 6, 2,
+	2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+	2, 2, 2, 8, 8, 0, 2, 3, 4, 4,
+	4, 4, 4, 4, 0, 3, 4, 7, 3, 1,
+	1, 2, 3, 3, 1, 2, 2, 1, 2, 1,
+	2, 2, 1, 2, 1, 1, 1, 1, 1, 1,
+	1, 1, 2, 0, 1, 0, 2, 1, 2, 4,
+	0, 2, 1, 1, 3, 3, 1, 1, 1, 1,
+	1, 1, 1, 1, 1, 2, 2, 0, 3, 0,
+	2, 0, 3, 1, 3, 3, 0, 1, 1, 0,
+	2, 2, 0, 2, 4, 4, 0, 2, 4, 0,
+	2, 1, 3, 2, 5, 3, 2, 2, 1, 3,
+	5, 4, 6, 1, 3, 3, 5, 0, 5, 1,
+	3, 1, 2, 3, 1, 1, 3, 3, 1, 3,
+	3, 3, 3, 3, 1, 2, 1, 1, 1, 1,
+	1, 1, 0, 2, 0, 3, 0, 1, 1, 1,
+	1, 1, 1, 1, 1, 1, 1, 1, 0, 1,
+	1, 1, 1, 0, 1, 1, 0, 2, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
@@ -2458,327 +2600,350 @@ var yyR2 = [...]int{
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
 	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-	0, 0, 1, 1,
+	1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+	1, 1, 1, 1, 1, 0, 0, 1, 1,
 }
 var yyChk = [...]int{
 
-	-1000, -200, -1, -2, -9, -10, -11, -12, -13, -14,
-	-15, -16, -17, -18, -22, -23, -24, -26, -27, -28,
-	-25, -19, -3, 271, -4, 6, 7, -32, 9, 10,
-	34, -20, 120, 121, 123, 122, 155, 124, 148, 54,
-	168, 169, 171, 172, 29, 149, 150, 153, 154, 35,
-	36, 126, -202, 8, 258, 58, -201, 275, -94, 15,
-	-8, -7, -140, -138, 63, 61, -131, 23, 268, 141,
-	168, 179, 173, 200, 192, 269, 142, 190, 193, 237,
-	220, 232, 70, 171, 246, 22, 151, 188, 184, 21,
-	182, 31, 234, 205, 273, 183, 233, 126, 144, 139,
-	206, 210, 238, 177, 178, 240, 204, 140, 37, 270,
-	39, 159, 241, 208, 203, 199, 202, 176, 198, 43,
-	212, 211, 213, 236, 195, 145, 185, 18, 244, 154,
-	157, 235, 207, 209, 136, 161, 272, 242, 181, 146,
-	158, 153, 245, 147, 172, 222, 239, 248, 42, 217,
-	175, 138, 169, 165, 223, 196, 160, 186, 187, 201,
-	174, 197, 170, 155, 247, 218, 274, 194, 191, 166,
-	131, 163, 164, 224, 225, 226, 227, 228, 229, 167,
-	20, 243, 189, 219, -31, 5, -29, -205, -29, -29,
-	-29, -29, -29, -176, -178, 58, 94, -129, 131, 76,
-	250, 127, 128, 135, -132, 61, -131, -117, 131, 227,
-	133, 128, 128, 130, 131, 250, 127, 128, -61, -138,
-	128, 113, 193, 237, 120, 221, 222, 234, 130, 37,
-	235, 161, -147, 128, -119, 220, 224, 225, 226, 229,
-	227, 167, 61, 239, 238, 230, -138, 170, -143, -143,
-	-143, -143, -143, 223, 223, -143, -2, -98, 17, 16,
-	-6, 59, 26, -5, -3, -202, 6, 24, 25, -35,
-	44, 45, -30, -41, 104, -42, -138, -67, 78, -72,
-	33, 61, -131, 27, -71, -68, -87, -85, -86, 113,
-	114, 115, 102, 103, 110, 79, 116, -76, -74, -75,
-	-77, 63, 62, 71, 64, 65, 66, 67, 72, 73,
-	74, -132, -83, -202, 48, 49, 259, 260, 261, 262,
-	267, 263, 81, 38, 249, 257, 256, 255, 253, 254,
-	251, 252, 265, 266, 134, 250, 108, 258, -117, -117,
-	11, -55, -56, -61, -63, -138, -109, -146, 170, -113,
-	239, 238, -133, -111, -132, -130, 237, 193, 236, 125,
-	77, 26, 28, 215, 80, 113, 16, 81, 112, 259,
-	120, 52, 251, 252, 249, 261, 262, 250, 221, 33,
-	10, 29, 149, 25, 106, 122, 84, 85, 152, 27,
-	150, 74, 19, 55, 11, 13, 14, 134, 133, 96,
-	130, 50, 8, 116, 30, 93, 46, 32, 48, 94,
-	17, 253, 254, 35, 267, 156, 108, 53, 40, 78,
-	72, 75, 56, 76, 15, 51, 95, 123, 258, 49,
-	127, 6, 264, 34, 148, 47, 128, 83, 265, 266,
-	132, 162, 73, 5, 135, 36, 9, 54, 57, 255,
-	256, 257, 38, 82, 12, 271, -177, 94, -170, 61,
-	-61, 130, -61, 258, -125, 134, -125, -125, 128, -61,
-	120, 122, 125, 56, -21, -61, -124, 134, 61, -124,
-	-124, -124, -61, 117, -61, 61, 34, -144, -202, -133,
-	250, 61, 161, 128, 162, 131, -144, -144, -144, -144,
-	-144, 165, 166, -144, -122, -121, 232, 233, 223, 231,
-	12, 223, 164, -144, -143, -143, -203, 60, -99, 19,
-	35, -42, -138, -95, -96, -42, -2, -7, -202, -94,
-	-2, -29, 40, -33, 25, 69, 11, -135, 77, 76,
-	93, -134, 26, -132, 63, 117, -42, -69, 96, 78,
-	94, 95, 80, 99, 98, 109, 102, 103, 104, 105,
-	106, 107, 108, 100, 101, 112, 86, 87, 88, 89,
-	90, 91, 92, -118, -202, -86, -202, 118, 119, -72,
-	-72, -72, -72, -72, -72, -72, -72, -202, -2, -81,
-	-42, -202, -202, -202, -202, -202, -202, -202, -202, -202,
-	-90, -42, -202, -206, -78, -202, -206, -78, -206, -78,
-	-206, -202, -206, -78, -206, -78, -206, -206, -78, -202,
-	-202, -202, -202, -202, -202, -62, 30, -61, -44, -45,
-	-46, -47, -64, -86, -202, 61, -61, -61, -55, -204,
-	59, 11, 57, -204, 59, 117, 59, -109, 170, -110,
-	-114, 240, 242, 86, -137, -132, 63, 33, 34, 60,
-	59, -61, -149, -152, -154, -153, -155, -150, -151, 190,
-	191, 113, 194, 196, 197, 198, 199, 200, 201, 202,
-	203, 204, 205, 34, 151, 186, 187, 188, 189, 206,
-	207, 208, 209, 210, 211, 212, 213, 173, 192, 269,
-	174, 175, 176, 177, 178, 179, 181, 182, 183, 184,
-	185, 61, -144, 131, 61, 78, 61, -61, -61, -144,
-	163, 163, 128, 128, -61, 59, 132, -55, 27, 56,
-	-61, 61, 61, -139, -138, -130, -144, -144, -144, -144,
-	-144, -61, -144, -144, -144, -144, 11, -120, 11, 96,
-	-42, 56, 9, 96, 59, 18, 117, 59, -97, 28,
-	29, -2, -98, -203, -35, -73, -132, 64, 67, -34,
-	47, -61, -42, -42, -79, 72, 78, 73, 74, -134,
-	104, -139, -133, -130, -72, -80, -83, -86, 68, 96,
-	94, 95, 80, -72, -72, -72, -72, -72, -72, -72,
-	-72, -72, -72, -72, -72, -72, -72, -72, -145, 61,
-	63, 61, -71, -71, -132, -40, 25, -39, -41, -203,
-	59, -203, -2, -39, -39, -42, -42, -87, 63, -39,
-	-87, 63, -39, -39, -33, -88, -89, 82, -87, -132,
-	-138, -203, -72, -132, -132, -39, -40, -39, -39, -105,
-	157, -61, 34, 59, -57, -59, -58, -60, 46, 50,
-	52, 47, 48, 49, 53, -142, 26, -44, -202, -202,
-	-141, 157, -140, 26, -105, 57, -44, -61, -44, -63,
-	-138, 104, -113, -110, 59, 241, 243, 244, 56, 75,
-	-42, -161, 112, -179, -180, -181, -133, 63, 64, -170,
-	-171, -172, -182, 143, -187, 136, 138, 135, -173, 144,
-	130, 32, 60, -166, 72, 78, -162, 218, -156, 58,
-	-156, -156, -156, -156, -160, 193, -160, -160, -160, 58,
-	58, -156, -156, -156, -164, 58, -164, -164, -165, 58,
-	-165, -136, 57, -61, -144, 27, -144, -126, 125, 122,
-	123, -190, 121, 215, 193, 70, 33, 15, 259, 157,
-	274, 61, 158, -132, -132, -61, -61, 125, 122, -61,
-	-61, -61, -144, -61, -123, 94, 12, -138, -138, -61,
-	42, -42, -42, -139, -96, -203, -99, -116, 19, 11,
-	38, 38, -39, 72, 73, 74, 117, -202, -80, -72,
-	-72, -72, -38, 152, 77, -203, -203, -39, 59, -42,
-	-203, -203, -203, 59, 57, 26, 11, 11, -203, 11,
-	11, -203, -203, -39, -91, -89, 84, -42, -203, 117,
-	-203, 59, 59, -203, -203, -203, -203, -70, 34, 38,
-	-2, -202, -202, -108, -112, -87, -45, -46, -46, -45,
-	-46, 46, 46, 46, 51, 46, 51, 46, -58, -138,
-	-203, -49, -48, -50, -132, -65, 54, 133, 55, -202,
-	-140, -66, 12, -44, -66, -66, 117, -114, -115, 245,
-	242, 248, 61, 63, 59, -181, 86, 58, 61, 32,
-	-173, -173, -174, 61, -174, 32, -158, 33, 72, -163,
-	219, 64, -160, -160, -161, 34, -161, -161, -161, -169,
-	63, -169, 64, 64, 56, -132, -144, -143, -196, 137,
-	143, 144, 139, 61, 130, 32, 136, 138, 157, 135,
-	-196, -127, -128, 132, 26, 130, 32, 157, -195, 57,
-	163, 163, 132, -144, -120, 63, -42, 43, 117, -61,
-	-43, 11, 104, -133, -40, -38, 77, -72, -72, -203,
-	-41, -148, 113, 190, 151, 188, 184, 204, 195, 217,
-	186, 218, -145, -148, -72, -72, -72, -72, 268, -94,
-	85, -42, 83, -133, -72, -72, -107, 56, -108, -82,
-	-84, -83, -202, -2, -103, -132, -106, -132, -66, 59,
-	86, -53, -52, 56, 57, -54, 56, -52, 46, 46,
-	-203, 59, 97, 130, 130, 130, -106, -94, -42, -66,
-	242, 246, 247, -180, -181, -184, -183, -132, -187, -174,
-	-174, 58, -159, 56, -72, 60, -161, -161, 61, 113,
-	60, 59, 60, 59, 60, 59, -61, -143, -143, -61,
-	-143, -132, -193, 271, -194, 61, -132, -132, -61, -123,
-	-66, -44, -203, -72, -203, -156, -156, -156, -165, -156,
-	178, -156, 178, -203, -203, 19, 19, 19, 19, -202,
-	-37, 264, -42, 59, 59, 31, -107, 59, -203, -203,
-	-203, 59, 117, -203, 59, -94, -112, -42, -42, 58,
-	-42, -142, -50, -51, -42, 128, 129, -202, -202, -202,
-	-203, -98, 60, 59, -156, -104, -132, -167, 215, 9,
-	-160, 63, -160, 64, 64, -144, 30, -192, -191, -133,
-	58, -92, 13, -160, 61, -72, -72, -72, -72, -72,
-	-203, 63, -72, -72, 32, -84, 38, -2, -202, -132,
-	-132, -132, -98, -104, -138, -202, -202, -104, -104, -104,
-	-141, -186, -185, 57, 140, 70, -183, 60, 59, -168,
-	136, 32, 135, -75, -161, -161, 60, 60, -202, 59,
-	86, -104, -93, 14, 16, -203, -203, -203, -203, -36,
-	96, 271, -203, -203, 9, -82, -2, 117, 60, -45,
-	-87, -203, -203, -203, -65, -185, 61, -175, 86, 63,
-	146, -132, -157, 70, 32, 32, -188, -189, 157, -191,
-	-181, 60, -100, 162, -42, -81, -203, 269, 53, 272,
-	-108, -203, -132, -203, -203, 64, -61, 63, -203, 59,
-	-132, -195, -101, -102, 56, 23, 22, 43, 270, 273,
-	58, -189, 38, -193, 59, 20, 21, -42, 43, -104,
-	159, -102, -42, 271, 60, 160, 272, -198, -199, 56,
-	-202, 273, -199, 56, 10, 9, -72, 156, -197, 147,
-	142, 145, 34, -197, -203, -203, 141, 33, 72,
+	-1000, -208, -1, -2, -9, -10, -11, -12, -13, -14,
+	-15, -16, -17, -18, -22, -23, -24, -27, -28, -29,
+	-25, -26, -19, -3, 287, -4, 8, 9, -33, 11,
+	12, 41, 42, -20, 131, 132, 134, 133, 166, 135,
+	159, 62, 180, 181, 183, 184, 161, 33, 160, 164,
+	165, 43, 44, 137, -210, 10, 271, 66, -209, 291,
+	-101, 17, -8, -7, -148, -146, 71, 69, -139, 25,
+	284, 152, 26, 180, 191, 185, 213, 205, 285, 153,
+	203, 206, 250, 233, 245, 78, 183, 259, 24, 162,
+	200, 196, 23, 194, 38, 247, 218, 276, 289, 35,
+	195, 246, 137, 155, 150, 219, 223, 251, 189, 190,
+	253, 217, 151, 45, 286, 36, 47, 27, 171, 42,
+	254, 221, 216, 212, 215, 188, 211, 51, 225, 224,
+	226, 249, 208, 156, 34, 197, 20, 257, 165, 169,
+	248, 220, 222, 147, 173, 288, 255, 193, 157, 170,
+	164, 258, 158, 184, 235, 252, 261, 50, 230, 187,
+	149, 181, 177, 236, 209, 172, 198, 199, 214, 186,
+	210, 182, 166, 260, 231, 290, 207, 204, 178, 142,
+	175, 176, 237, 238, 239, 240, 241, 242, 179, 22,
+	256, 201, 232, 202, -32, 5, 6, 7, -30, -213,
+	-30, -30, -30, -30, -30, -30, -184, -186, 66, 102,
+	139, -137, 142, 84, 263, 138, 146, -140, 69, -139,
+	-125, 142, 240, 144, 139, 139, 141, 142, 263, 138,
+	139, -67, -146, 139, 71, 124, 206, 250, 131, 234,
+	235, 247, 141, 45, 248, 173, -155, 139, -127, 233,
+	237, 238, 239, 242, 240, 179, 69, 252, 251, 243,
+	-146, 182, -2, 135, -151, -151, -151, -151, 236, 236,
+	-151, -2, -106, 19, 18, -6, 67, 30, -5, -3,
+	-210, 8, 28, 29, -36, 52, 53, -31, -42, 114,
+	-47, -146, -73, 86, -78, 40, 69, -139, 31, -77,
+	-74, -93, -91, -92, 124, 125, 126, 112, 113, 107,
+	87, 274, 127, -82, -80, -81, -83, 71, 70, 79,
+	72, 73, 74, 75, 80, 81, 82, -140, -94, -89,
+	-210, 56, 57, 272, 273, 277, 278, 283, 279, 89,
+	46, 167, 262, 270, 269, 268, 266, 267, 264, 265,
+	281, 282, 145, 263, 118, 271, -125, -125, 13, -60,
+	-61, -67, -69, -146, -117, -154, 182, -121, 252, 251,
+	-141, -119, -140, -138, 250, 206, 249, 136, 85, 274,
+	30, 32, 120, 228, 88, 124, 18, 89, 123, 272,
+	131, 60, 264, 265, 262, 277, 278, 263, 234, 40,
+	12, 33, 160, 29, 116, 133, 92, 93, 163, 31,
+	161, 167, 82, 21, 63, 13, 15, 16, 145, 144,
+	103, 105, 141, 58, 10, 127, 37, 101, 54, 39,
+	56, 102, 19, 266, 267, 43, 283, 168, 118, 61,
+	48, 86, 80, 83, 64, 84, 17, 59, 104, 134,
+	271, 57, 138, 8, 280, 41, 159, 55, 139, 91,
+	281, 282, 143, 174, 81, 5, 146, 44, 275, 11,
+	62, 65, 268, 269, 270, 46, 90, 14, 287, -117,
+	-185, 102, -178, 69, -67, 71, -133, 145, 141, -67,
+	271, -133, -133, 139, -67, 131, 133, 136, 64, -21,
+	-67, -132, 145, 69, -132, -132, -132, -67, 128, -67,
+	69, 41, -152, -210, -141, 263, 69, 173, 139, 174,
+	142, -152, -152, -152, -152, -152, 177, 178, -152, -130,
+	-129, 245, 246, 236, 244, 14, 236, 176, -152, -2,
+	-151, -151, -211, 68, -107, 21, 43, -47, -146, -102,
+	-103, -47, -2, -7, -210, -101, -2, -30, 48, -34,
+	29, 77, 13, -43, 276, -143, 85, 84, 101, -142,
+	30, -140, 71, 128, -47, -75, 105, 86, 102, 103,
+	107, 104, 88, 109, 108, 119, 112, 113, 114, 115,
+	116, 117, 118, 110, 111, 123, 122, 120, 94, 95,
+	96, 97, 98, 99, 100, -126, -210, -92, -210, 129,
+	130, -78, -78, -78, -78, -78, -78, -78, 122, -78,
+	-210, 128, -2, -87, -47, -210, -210, -210, -210, -210,
+	-210, -210, -210, -210, -97, -47, -210, -210, -214, -84,
+	-210, -214, -84, -214, -84, -214, -210, -214, -84, -214,
+	-84, -214, -214, -84, -210, -210, -210, -210, -210, -210,
+	-68, 37, -67, -49, -50, -51, -52, -70, -92, -210,
+	69, 252, 275, -67, -67, -60, -212, 67, 13, 65,
+	-212, 67, 128, 67, -117, 182, -118, -122, 253, 255,
+	94, -145, -140, 71, 40, 41, 68, 67, -67, -157,
+	-160, -162, -161, -163, -158, -159, 203, 204, 124, 207,
+	209, 210, 211, 212, 213, 214, 215, 216, 217, 218,
+	41, 162, 198, 199, 200, 201, 219, 220, 221, 222,
+	223, 224, 225, 226, 185, 205, 285, 186, 187, 188,
+	189, 190, 191, 193, 194, 195, 196, 197, 30, -67,
+	86, 69, -152, 142, 69, 69, -67, -152, 175, 175,
+	139, 139, -67, 67, 143, -60, 31, 64, -67, 69,
+	69, -147, -146, -138, -152, -152, -152, -152, -152, -67,
+	-152, -152, -152, -152, 13, -128, 13, 105, -47, 64,
+	11, 105, 67, 20, 128, 67, -104, 32, 33, -2,
+	-106, -211, -36, -79, -140, 72, 75, -35, 55, -67,
+	-45, 271, -210, -47, -47, -85, 80, 86, 81, 82,
+	-142, 114, -141, -78, -86, -89, -92, 76, 105, 102,
+	103, 104, 88, -78, -78, -78, -78, -78, -78, -78,
+	-78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
+	-153, 69, 71, -78, 198, 69, -77, -77, 292, -87,
+	-140, -41, 29, -40, -42, -141, -211, 67, -211, -2,
+	-40, -40, -47, -47, -93, 71, -40, -93, 71, -40,
+	-40, -34, -95, -96, 90, -93, -140, -146, -140, -211,
+	-78, -140, -140, -40, -41, -40, -40, -113, 169, -67,
+	41, 67, -62, -65, -63, -64, -66, 54, 58, 60,
+	55, 56, 57, 243, 61, -150, 30, -49, -210, -210,
+	-210, -210, -149, 169, -148, 30, -113, 65, -49, -67,
+	-49, -69, -146, 114, -121, -118, 67, 254, 256, 257,
+	64, 83, -47, -169, 123, -187, -188, -189, -141, 71,
+	72, -178, -179, -180, -190, 154, -195, 147, 149, 146,
+	-181, 155, 141, 39, 68, -174, 80, 86, -170, 231,
+	-164, 66, -164, -164, -164, -164, -168, 206, -168, -168,
+	-168, 66, 66, -164, -164, -164, -172, 66, -172, -172,
+	-173, 66, -173, -2, 31, -144, 65, -67, -152, -152,
+	-134, 136, 133, 134, -198, 132, 228, 206, 78, 40,
+	17, 272, 169, 290, 69, 170, -140, -140, -67, -67,
+	136, 133, -67, -67, -67, -152, -67, -131, 102, 14,
+	-146, -146, -67, 50, -47, -47, -103, -105, 34, -211,
+	-107, -124, 21, 13, 46, 46, -40, -210, -44, -93,
+	80, 81, 82, -43, -210, -86, -78, -78, -78, -78,
+	-39, 163, -39, 85, 292, 202, -211, 292, -211, -40,
+	67, -47, -211, -211, -211, 67, 65, 30, 13, 13,
+	-211, 13, 13, -211, -211, -40, -98, -96, 92, -47,
+	-211, 128, 13, -211, 67, 67, -211, -211, -211, -211,
+	-76, 41, 46, -2, -210, -210, -116, -120, -93, -50,
+	-51, -51, -50, -50, -51, 54, 54, 54, 59, 54,
+	59, 54, 59, 54, -63, -146, -211, -54, -53, -55,
+	-140, -56, -47, 139, 140, -54, -47, -71, 62, 144,
+	63, -210, -148, -72, 14, -49, -72, -72, 128, -122,
+	-123, 258, 255, 261, 69, 71, 67, -189, 94, 66,
+	69, 39, -181, -181, -182, 69, -182, 39, -166, 40,
+	80, -171, 232, 72, -168, -168, -169, 41, -169, -169,
+	-169, -177, 71, -177, 72, 72, 64, -140, -152, -151,
+	-204, 148, 154, 155, 150, 69, 141, 39, 147, 149,
+	169, 146, -204, -135, -136, 143, 30, 141, 39, 169,
+	-203, 65, 175, 175, 143, -152, -128, 71, -47, 51,
+	35, 36, -67, -48, 13, -46, -47, -211, 67, -45,
+	-41, -39, -39, 85, -78, -78, -78, -211, -42, -156,
+	124, 203, 162, 200, 196, 217, 208, 230, 198, 231,
+	189, 190, 195, 194, 193, 205, 285, 209, 204, -153,
+	-156, -78, -78, -78, -78, 284, -101, 93, -47, 91,
+	-141, -47, -78, -78, -115, 64, -116, -88, -90, -89,
+	-210, -2, -111, -140, -114, -140, -72, 67, 94, -58,
+	-57, 64, 65, -59, 64, -57, -57, 54, 54, 54,
+	-211, 67, 106, -210, -210, -211, -211, 141, 141, 141,
+	-114, -101, -47, -72, 255, 259, 260, -188, -189, -192,
+	-191, -140, -195, -182, -182, 66, -167, 64, -78, 68,
+	-169, -169, 69, 124, 68, 67, 68, 67, 68, 67,
+	-67, -151, -151, -67, -151, -140, -201, 287, -202, 69,
+	-140, -140, -67, -131, -72, -49, -211, 67, 30, -93,
+	-211, -78, -211, -164, -164, -164, -173, -164, 190, -164,
+	190, -164, -211, -211, 21, 21, 21, 21, -210, -38,
+	280, -47, -211, 67, 67, 38, -115, 67, -211, -211,
+	-211, 67, 128, -211, 67, -101, -120, -47, -47, 66,
+	-47, -150, -55, -56, -50, -93, -150, -150, -210, -210,
+	-210, -211, -106, 68, 67, -164, -112, -140, -175, 228,
+	11, -168, 71, -168, 72, 72, -152, 37, -200, -199,
+	-141, 66, -99, 15, -47, -142, -168, 69, -78, -78,
+	-78, -78, -78, -211, 71, -78, -78, 39, -90, 46,
+	-2, -210, -140, -140, -140, -106, -112, -146, -211, -211,
+	-146, -146, -112, -112, -112, -149, -194, -193, 65, 151,
+	78, -191, 68, 67, -176, 147, 39, 146, -81, -169,
+	-169, 68, 68, -210, 67, 94, -112, -100, 16, 18,
+	30, -211, -211, -211, -211, -37, 105, 287, -211, -211,
+	11, -88, -2, 128, 68, -211, -211, -211, -71, -193,
+	69, -183, 94, 71, 157, -140, -165, 78, 39, 39,
+	-196, -197, 169, -199, -189, 68, -108, 174, -47, -87,
+	-142, -211, 285, 61, 288, -116, -211, -140, 72, -67,
+	71, -211, 67, -140, -203, -109, -110, 64, 25, 24,
+	51, 286, 289, 66, -197, 46, -201, 67, 22, 23,
+	-47, 51, -112, 171, -110, 26, -47, 287, 68, 172,
+	27, 288, -206, -207, 64, -210, -47, 289, -207, 64,
+	12, 11, -78, 168, -205, 158, 153, 156, 41, -205,
+	-211, -211, 152, 40, 80,
 }
 var yyDef = [...]int{
 
-	23, -2, 2, 4, 5, 6, 7, 8, 9, 10,
+	24, -2, 2, 4, 5, 6, 7, 8, 9, 10,
 	11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
-	21, 22, 573, 0, 0, 321, 321, 321, 321, 321,
-	321, 0, 651, 634, 0, 0, 0, 0, -2, 308,
-	309, 0, 311, 312, 880, 880, 880, 880, 880, 0,
-	0, 880, 0, 40, 41, 878, 1, 3, 581, 0,
-	28, 30, 0, 388, 389, 660, 661, 761, 762, 763,
-	764, 765, 766, 767, 768, 769, 770, 771, 772, 773,
-	774, 775, 776, 777, 778, 779, 780, 781, 782, 783,
-	784, 785, 786, 787, 788, 789, 790, 791, 792, 793,
-	794, 795, 796, 797, 798, 799, 800, 801, 802, 803,
-	804, 805, 806, 807, 808, 809, 810, 811, 812, 813,
-	814, 815, 816, 817, 818, 819, 820, 821, 822, 823,
-	824, 825, 826, 827, 828, 829, 830, 831, 832, 833,
-	834, 835, 836, 837, 838, 839, 840, 841, 842, 843,
-	844, 845, 846, 847, 848, 849, 850, 851, 852, 853,
-	854, 855, 856, 857, 858, 859, 860, 861, 862, 863,
-	864, 865, 866, 867, 868, 869, 870, 871, 872, 873,
-	874, 875, 876, 877, 0, 325, 328, 323, 0, 634,
-	634, 0, 0, 70, 71, 0, 0, 0, 864, 0,
-	632, 632, 632, 652, 653, 656, 657, 0, 0, 0,
-	635, 0, 630, 0, 630, 630, 630, 0, 258, 402,
-	0, 0, 0, 881, 0, 881, 881, 271, 881, 881,
-	274, 881, 0, 881, 0, 281, 283, 284, 285, 286,
-	0, 290, 881, 305, 306, 295, 307, 310, 313, 314,
-	315, 316, 317, 880, 880, 320, 0, 585, 0, 0,
-	0, 29, 0, 573, 36, 0, 321, 326, 327, 331,
-	329, 330, 322, 0, 339, 343, 0, 411, 0, 416,
-	418, -2, -2, 0, 453, 454, 455, 456, 457, 0,
-	0, 0, 0, 0, 0, 0, 0, 481, 482, 483,
-	484, 558, 559, 560, 561, 562, 563, 564, 565, 420,
-	421, 555, 612, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 546, 0, 517, 517, 517, 517, 517, 517,
-	517, 517, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 49, 51, 402, 55, 0, 856, 616,
-	-2, -2, 0, 0, 658, 659, -2, 772, -2, 664,
-	665, 666, 667, 668, 669, 670, 671, 672, 673, 674,
-	675, 676, 677, 678, 679, 680, 681, 682, 683, 684,
-	685, 686, 687, 688, 689, 690, 691, 692, 693, 694,
-	695, 696, 697, 698, 699, 700, 701, 702, 703, 704,
-	705, 706, 707, 708, 709, 710, 711, 712, 713, 714,
-	715, 716, 717, 718, 719, 720, 721, 722, 723, 724,
-	725, 726, 727, 728, 729, 730, 731, 732, 733, 734,
-	735, 736, 737, 738, 739, 740, 741, 742, 743, 744,
-	745, 746, 747, 748, 749, 750, 751, 752, 753, 754,
-	755, 756, 757, 758, 759, 760, 0, 0, 89, 0,
-	87, 0, 881, 0, 0, 0, 0, 0, 0, 881,
-	0, 0, 0, 0, 249, 0, 0, 0, 0, 0,
-	0, 0, 257, 0, 259, 881, 881, 262, 882, 883,
-	881, 881, 881, 0, 881, 881, 269, 270, 272, 273,
-	275, 881, 881, 277, 0, 298, 296, 297, 292, 293,
-	0, 287, 288, 291, 318, 319, 35, 879, 24, 0,
-	0, 582, 0, 574, 575, 578, 25, 31, 0, 581,
-	0, 328, 0, 333, 332, 324, 0, 340, 0, 0,
-	0, 344, 0, 346, 347, 0, 414, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 0, 0, 0, 0, 0, 438, 439, 440, 441,
-	442, 443, 444, 417, 0, 431, 0, 0, 0, 473,
-	474, 475, 476, 477, 478, 479, 0, 335, 0, 0,
-	451, 0, 0, 0, 0, 0, 0, 0, 0, 331,
-	0, 547, 0, 501, 509, 0, 502, 510, 503, 511,
-	504, 0, 505, 512, 506, 513, 507, 508, 514, 0,
-	0, 0, 335, 0, 0, 53, 0, 401, 0, 350,
-	352, 353, 354, -2, 0, 660, 385, -2, 0, 0,
-	0, 47, 48, 0, 0, 0, 0, 56, 856, 58,
-	59, 0, 0, 0, 167, 625, 626, 627, 623, 211,
-	0, 0, 155, 151, 95, 96, 97, 144, 99, 144,
-	144, 144, 144, 164, 164, 164, 164, 127, 128, 129,
-	130, 131, 0, 0, 114, 144, 144, 144, 118, 134,
-	135, 136, 137, 138, 139, 140, 141, 100, 101, 102,
-	103, 104, 105, 106, 107, 108, 146, 146, 146, 148,
-	148, 654, 73, 0, 881, 0, 881, 85, 0, 225,
-	0, 0, 0, 0, 0, 0, 0, 252, 631, 0,
-	881, 255, 256, 403, 662, 663, 260, 261, 263, 264,
-	265, 266, 267, 268, 276, 280, 0, 301, 0, 0,
-	282, 0, 586, 0, 0, 0, 0, 0, 577, 579,
-	580, 0, 585, 37, 331, 0, 566, 0, 0, 0,
-	334, 33, 412, 413, 415, 432, 0, 434, 436, 345,
-	341, 0, 556, -2, 422, 423, 447, 448, 449, 0,
-	0, 0, 0, 445, 427, 0, 458, 459, 460, 461,
-	462, 463, 464, 465, 466, 467, 468, 469, 472, 531,
-	532, 0, 470, 471, 480, 0, 0, 336, 337, 450,
-	0, 611, 0, 0, 0, 0, 0, 455, 558, 0,
-	455, 558, 0, 0, 0, 553, 550, 0, 0, 555,
-	0, 518, 0, 0, 0, 0, 0, 0, 0, 0,
-	0, 400, 0, 0, 0, 0, 0, 0, 390, 0,
-	0, 393, 0, 0, 0, 0, 384, 0, 0, 361,
-	405, 824, 386, 0, 409, 0, 409, 50, 409, 52,
-	0, 404, 617, 57, 0, 0, 62, 63, 618, 619,
-	620, 621, 0, 86, 212, 214, 217, 218, 219, 90,
-	91, 92, 0, 0, 199, 0, 0, 193, 193, 0,
-	191, 192, 88, 158, 156, 0, 153, 152, 98, 0,
-	164, 164, 121, 122, 167, 0, 167, 167, 167, 0,
-	0, 115, 116, 117, 109, 0, 110, 111, 112, 0,
-	113, 0, 0, 881, 75, 633, 76, 880, 0, 0,
-	646, 226, 636, 637, 638, 639, 640, 641, 642, 643,
-	644, 645, 0, 77, 228, 230, 229, 0, 0, 0,
-	250, 881, 254, 298, 279, 0, 0, 299, 300, 289,
-	0, 583, 584, 0, 576, 32, 26, 0, 628, 629,
-	567, 568, 348, 433, 435, 437, 0, 335, 424, 445,
-	428, 0, 425, 0, 0, 419, 485, 0, 0, 452,
-	-2, 488, 489, 0, 0, 0, 0, 0, 524, 0,
-	0, 525, 0, 573, 0, 551, 0, 0, 500, 0,
-	519, 0, 0, 520, 521, 522, 523, 605, 0, 0,
-	596, 0, 0, 409, 613, 0, 351, 379, 381, 0,
-	376, 391, 392, 394, 0, 396, 0, 398, 399, 355,
-	357, 0, 362, 363, 0, 359, 0, 0, 0, 0,
-	387, 573, 0, 409, 45, 46, 0, 60, 61, 0,
-	0, 67, 168, 169, 0, 215, 0, 0, 0, 186,
-	193, 193, 189, 194, 190, 0, 160, 0, 157, 94,
-	154, 0, 167, 167, 123, 0, 124, 125, 126, 0,
-	142, 0, 0, 0, 0, 655, 74, 220, 880, 233,
-	234, 235, 236, 237, 238, 239, 240, 241, 242, 243,
-	880, 0, 880, 647, 648, 649, 650, 0, 80, 0,
-	0, 0, 0, 253, 301, 302, 303, 587, 0, 27,
-	409, 0, 342, 557, 0, 426, 0, 446, 429, 486,
-	338, 0, 144, 144, 536, 144, 148, 539, 144, 541,
-	144, 544, 0, 0, 0, 0, 0, 0, 0, 548,
-	499, 554, 0, 556, 0, 0, 38, 0, 605, 595,
-	607, 609, 0, 0, 0, 601, 0, 371, 573, 0,
-	0, 373, 380, 0, 0, 374, 0, 375, 395, 397,
-	383, 0, 0, 0, 0, 0, 0, 581, 410, 44,
-	64, 65, 66, 213, 216, 0, 195, 144, 198, 187,
-	188, 0, 162, 0, 159, 145, 119, 120, 165, 166,
-	164, 0, 164, 0, 149, 0, 881, 221, 222, 223,
-	224, 0, 227, 0, 78, 79, 0, 232, 251, 278,
-	569, 349, 487, 430, 490, 533, 164, 537, 538, 540,
-	542, 543, 545, 492, 491, 0, 0, 0, 0, 0,
-	0, 0, 552, 0, 0, 0, 39, 0, 610, -2,
-	0, 0, 0, 54, 0, 581, 614, 615, 377, 0,
-	382, 0, 364, 365, 366, 0, 0, 0, 0, 0,
-	385, 43, 178, 0, 197, 0, 369, 170, 163, 0,
-	167, 143, 167, 0, 0, 72, 0, 81, 82, 0,
-	0, 571, 0, 534, 535, 0, 0, 0, 0, 526,
-	498, 549, 0, 0, 0, 608, 0, 599, 0, 603,
-	602, 372, 42, 0, 358, 0, 0, 0, 0, 0,
-	405, 177, 179, 0, 184, 0, 196, 0, 0, 175,
-	0, 172, 174, 161, 132, 133, 147, 150, 0, 0,
-	0, 0, 588, 0, 0, 493, 495, 494, 496, 0,
-	0, 0, 515, 516, 0, 598, 0, 0, 378, 0,
-	0, 406, 407, 408, 360, 180, 181, 0, 185, 183,
-	0, 370, 93, 0, 171, 173, 0, 245, 0, 83,
-	84, 77, 34, 0, 572, 570, 497, 0, 0, 0,
-	606, -2, 604, 367, 368, 182, 0, 176, 244, 0,
-	0, 80, 589, 590, 0, 0, 0, 527, 0, 530,
-	0, 246, 0, 231, 0, 592, 0, 594, 528, 0,
-	0, 591, 593, 0, 200, 0, 0, 201, 202, 0,
-	0, 529, 203, 0, 0, 0, 0, 0, 204, 206,
-	207, 0, 0, 205, 247, 248, 208, 209, 210,
+	21, 22, 23, 611, 0, 0, 326, 326, 326, 326,
+	326, 326, 326, 0, 693, 676, 0, 0, 0, 0,
+	-2, 312, 313, 0, 315, 316, 0, 935, 935, 935,
+	935, 0, 0, 935, 0, 41, 42, 933, 1, 3,
+	622, 0, 29, 31, 0, 406, 407, 702, 703, 808,
+	809, 810, 811, 812, 813, 814, 815, 816, 817, 818,
+	819, 820, 821, 822, 823, 824, 825, 826, 827, 828,
+	829, 830, 831, 832, 833, 834, 835, 836, 837, 838,
+	839, 840, 841, 842, 843, 844, 845, 846, 847, 848,
+	849, 850, 851, 852, 853, 854, 855, 856, 857, 858,
+	859, 860, 861, 862, 863, 864, 865, 866, 867, 868,
+	869, 870, 871, 872, 873, 874, 875, 876, 877, 878,
+	879, 880, 881, 882, 883, 884, 885, 886, 887, 888,
+	889, 890, 891, 892, 893, 894, 895, 896, 897, 898,
+	899, 900, 901, 902, 903, 904, 905, 906, 907, 908,
+	909, 910, 911, 912, 913, 914, 915, 916, 917, 918,
+	919, 920, 921, 922, 923, 924, 925, 926, 927, 928,
+	929, 930, 931, 932, 0, 330, 333, 334, 335, 328,
+	0, 676, 676, 0, 0, 0, 72, 73, 0, 0,
+	674, 0, 918, 0, 674, 674, 694, 695, 698, 699,
+	0, 0, 0, 677, 0, 672, 0, 672, 672, 672,
+	0, 261, 422, 0, 263, 0, 0, 936, 0, 936,
+	936, 275, 936, 936, 278, 936, 0, 936, 0, 285,
+	287, 288, 289, 290, 0, 294, 936, 309, 310, 299,
+	311, 314, 317, 0, 319, 320, 321, 322, 935, 935,
+	325, 0, 626, 0, 0, 0, 30, 0, 611, 37,
+	0, 326, 331, 332, 338, 336, 337, 327, 0, 349,
+	357, 0, 431, 0, 436, 438, -2, -2, 0, 476,
+	477, 478, 479, 480, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 508, 509, 510, 511, 596, 597, 598,
+	599, 600, 601, 602, 603, 440, 441, 592, 593, 654,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 583,
+	0, 0, 545, 545, 545, 545, 545, 545, 545, 545,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 50, 52, 422, 56, 0, 910, 658, -2, -2,
+	0, 0, 700, 701, -2, 820, -2, 706, 707, 708,
+	709, 710, 711, 712, 713, 714, 715, 716, 717, 718,
+	719, 720, 721, 722, 723, 724, 725, 726, 727, 728,
+	729, 730, 731, 732, 733, 734, 735, 736, 737, 738,
+	739, 740, 741, 742, 743, 744, 745, 746, 747, 748,
+	749, 750, 751, 752, 753, 754, 755, 756, 757, 758,
+	759, 760, 761, 762, 763, 764, 765, 766, 767, 768,
+	769, 770, 771, 772, 773, 774, 775, 776, 777, 778,
+	779, 780, 781, 782, 783, 784, 785, 786, 787, 788,
+	789, 790, 791, 792, 793, 794, 795, 796, 797, 798,
+	799, 800, 801, 802, 803, 804, 805, 806, 807, 60,
+	0, 0, 92, 0, 90, 0, 0, 0, 0, 936,
+	0, 0, 0, 0, 936, 0, 0, 0, 0, 252,
+	0, 0, 0, 0, 0, 0, 0, 260, 0, 262,
+	936, 936, 266, 937, 938, 936, 936, 936, 0, 936,
+	936, 273, 274, 276, 277, 279, 936, 936, 281, 0,
+	302, 300, 301, 296, 297, 0, 291, 292, 295, 318,
+	323, 324, 36, 934, 25, 0, 0, 623, 0, 612,
+	613, 616, 26, 32, 0, 622, 0, 335, 0, 340,
+	339, 329, 0, 353, 0, 347, 0, 0, 0, 358,
+	0, 360, 361, 0, 434, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 461, 462,
+	463, 464, 465, 466, 467, 437, 0, 454, 0, 0,
+	0, 496, 497, 498, 499, 500, 501, 502, 0, 0,
+	342, 0, 0, 0, 474, 0, 0, 0, 0, 0,
+	0, 0, 0, 338, 0, 584, 0, 0, 529, 537,
+	0, 530, 538, 531, 539, 532, 0, 533, 540, 534,
+	541, 535, 536, 542, 0, 0, 0, 342, 0, 0,
+	54, 0, 421, 0, 364, 366, 367, 368, -2, 0,
+	702, 894, 0, 403, -2, 0, 0, 0, 48, 49,
+	0, 0, 0, 0, 57, 910, 59, 61, 0, 0,
+	0, 170, 667, 668, 669, 665, 214, 0, 0, 158,
+	154, 98, 99, 100, 147, 102, 147, 147, 147, 147,
+	167, 167, 167, 167, 130, 131, 132, 133, 134, 0,
+	0, 117, 147, 147, 147, 121, 137, 138, 139, 140,
+	141, 142, 143, 144, 103, 104, 105, 106, 107, 108,
+	109, 110, 111, 149, 149, 149, 151, 151, 0, 88,
+	0, 696, 76, 0, 936, 936, 0, 228, 0, 0,
+	0, 0, 0, 0, 0, 255, 673, 0, 936, 258,
+	259, 423, 704, 705, 264, 265, 267, 268, 269, 270,
+	271, 272, 280, 284, 0, 305, 0, 0, 286, 0,
+	627, 0, 0, 0, 0, 0, 619, 617, 618, 0,
+	626, 38, 338, 0, 604, 0, 0, 0, 341, 34,
+	346, 0, 0, 432, 433, 435, 455, 0, 457, 459,
+	359, 349, 594, 442, 443, 470, 471, 472, 0, 0,
+	0, 0, 0, 468, 468, 449, 450, 0, 481, 482,
+	483, 484, 485, 486, 487, 488, 489, 490, 491, 492,
+	495, 559, 560, 0, 0, 0, 493, 494, 505, 0,
+	507, 0, 0, 343, 344, 595, 473, 0, 653, 0,
+	0, 0, 0, 0, 478, 596, 0, 478, 596, 0,
+	0, 0, 590, 587, 0, 0, 592, 0, 0, 546,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 420,
+	0, 0, 0, 0, 0, 0, 0, 408, 0, 0,
+	411, 0, 0, 0, 0, 0, 402, 0, 0, 377,
+	377, 0, 425, 878, 404, 0, 429, 0, 429, 51,
+	429, 53, 0, 424, 659, 58, 0, 0, 64, 65,
+	660, 661, 662, 663, 0, 89, 215, 217, 220, 221,
+	222, 93, 94, 95, 0, 0, 202, 0, 0, 196,
+	196, 0, 194, 195, 91, 161, 159, 0, 156, 155,
+	101, 0, 167, 167, 124, 125, 170, 0, 170, 170,
+	170, 0, 0, 118, 119, 120, 112, 0, 113, 114,
+	115, 0, 116, 74, 675, 0, 0, 936, 78, 79,
+	935, 0, 0, 688, 229, 678, 679, 680, 681, 682,
+	683, 684, 685, 686, 687, 0, 80, 231, 233, 232,
+	0, 0, 0, 253, 936, 257, 302, 283, 0, 0,
+	303, 304, 293, 0, 624, 625, 614, 615, 0, 33,
+	27, 0, 670, 671, 605, 606, 362, 0, 0, 351,
+	456, 458, 460, 353, 342, 444, 468, 468, 451, 0,
+	445, 0, 447, 0, 503, 0, 439, 506, 512, 0,
+	0, 475, -2, 515, 516, 0, 0, 0, 0, 0,
+	552, 0, 0, 553, 0, 611, 0, 588, 0, 0,
+	527, 0, 0, 547, 0, 0, 548, 549, 550, 551,
+	647, 0, 0, 638, 0, 0, 429, 655, 0, 365,
+	397, 399, 0, 0, 394, 409, 410, 412, 0, 414,
+	0, 416, 0, 418, 419, 369, 371, 0, 378, 379,
+	592, 382, 383, 0, 0, 0, 0, 375, 0, 0,
+	0, 0, 405, 611, 0, 429, 46, 47, 0, 62,
+	63, 0, 0, 69, 171, 172, 0, 218, 0, 0,
+	0, 189, 196, 196, 192, 197, 193, 0, 163, 0,
+	160, 97, 157, 0, 170, 170, 126, 0, 127, 128,
+	129, 0, 145, 0, 0, 0, 0, 697, 77, 223,
+	935, 236, 237, 238, 239, 240, 241, 242, 243, 244,
+	245, 246, 935, 0, 935, 689, 690, 691, 692, 0,
+	83, 0, 0, 0, 0, 256, 305, 306, 307, 628,
+	620, 621, 28, 429, 0, 0, 0, 350, 0, 348,
+	0, 446, 448, 0, 469, 452, 504, 513, 345, 0,
+	147, 147, 564, 147, 151, 567, 147, 569, 147, 572,
+	574, 575, 576, 577, 578, 579, 580, 581, 147, 0,
+	0, 0, 0, 0, 0, 0, 585, 526, 591, 0,
+	594, 0, 0, 0, 39, 0, 647, 637, 649, 651,
+	0, 0, 0, 643, 0, 388, 611, 0, 0, 390,
+	398, 0, 0, 391, 0, 392, 393, 413, 415, 417,
+	401, 0, 0, 0, 0, 401, 401, 0, 0, 0,
+	0, 622, 430, 45, 66, 67, 68, 216, 219, 0,
+	198, 147, 201, 190, 191, 0, 165, 0, 162, 148,
+	122, 123, 168, 169, 167, 0, 167, 0, 152, 0,
+	936, 224, 225, 226, 227, 0, 230, 0, 81, 82,
+	0, 235, 254, 282, 607, 363, 354, 0, 0, 352,
+	514, 453, 517, 561, 167, 565, 566, 568, 570, 571,
+	573, 582, 519, 518, 0, 0, 0, 0, 0, 0,
+	0, 589, 528, 0, 0, 0, 40, 0, 652, -2,
+	0, 0, 0, 55, 0, 622, 656, 657, 395, 0,
+	400, 0, 380, 381, 0, 0, 0, 0, 0, 0,
+	0, 403, 44, 181, 0, 200, 0, 386, 173, 166,
+	0, 170, 146, 170, 0, 0, 75, 0, 84, 85,
+	0, 0, 609, 0, 0, 355, 562, 563, 0, 0,
+	0, 0, 554, 525, 586, 0, 0, 0, 650, 0,
+	641, 0, 645, 644, 389, 43, 0, 372, 384, 385,
+	373, 374, 0, 0, 0, 425, 180, 182, 0, 187,
+	0, 199, 0, 0, 178, 0, 175, 177, 164, 135,
+	136, 150, 153, 0, 0, 0, 0, 629, 0, 0,
+	0, 520, 522, 521, 523, 0, 0, 0, 543, 544,
+	0, 640, 0, 0, 396, 426, 427, 428, 376, 183,
+	184, 0, 188, 186, 0, 387, 96, 0, 174, 176,
+	0, 248, 0, 86, 87, 80, 35, 0, 610, 608,
+	356, 524, 0, 0, 0, 648, -2, 646, 185, 0,
+	179, 247, 0, 0, 83, 630, 631, 0, 0, 0,
+	555, 0, 558, 0, 249, 0, 234, 0, 633, 0,
+	636, 556, 0, 0, 632, 0, 635, 0, 203, 0,
+	0, 0, 204, 205, 0, 0, 634, 557, 206, 0,
+	0, 0, 0, 0, 207, 209, 210, 0, 0, 208,
+	250, 251, 211, 212, 213,
 }
 var yyTok1 = [...]int{
 
 	1, 3, 3, 3, 3, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-	3, 3, 3, 79, 3, 3, 3, 107, 99, 3,
-	58, 60, 104, 102, 59, 103, 117, 105, 3, 3,
-	3, 3, 3, 3, 3, 3, 3, 3, 3, 275,
-	87, 86, 88, 3, 3, 3, 3, 3, 3, 3,
+	3, 3, 3, 87, 3, 3, 3, 117, 109, 3,
+	66, 68, 114, 112, 67, 113, 128, 115, 3, 3,
+	3, 3, 3, 3, 3, 3, 3, 3, 3, 291,
+	95, 94, 96, 3, 3, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-	3, 3, 3, 3, 109, 3, 3, 3, 3, 3,
+	3, 122, 3, 292, 119, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
 	3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-	3, 3, 3, 3, 98, 3, 110,
+	3, 3, 3, 3, 108, 3, 107,
 }
 var yyTok2 = [...]int{
 
@@ -2787,29 +2952,32 @@ var yyTok2 = [...]int{
 	22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
 	32, 33, 34, 35, 36, 37, 38, 39, 40, 41,
 	42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
-	52, 53, 54, 55, 56, 57, 61, 62, 63, 64,
-	65, 66, 67, 68, 69, 70, 71, 72, 73, 74,
-	75, 76, 77, 78, 80, 81, 82, 83, 84, 85,
-	89, 90, 91, 92, 93, 94, 95, 96, 97, 100,
-	101, 106, 108, 111, 112, 113, 114, 115, 116, 118,
-	119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
-	129, 130, 131, 132, 133, 134, 135, 136, 137, 138,
-	139, 140, 141, 142, 143, 144, 145, 146, 147, 148,
-	149, 150, 151, 152, 153, 154, 155, 156, 157, 158,
-	159, 160, 161, 162, 163, 164, 165, 166, 167, 168,
-	169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
-	179, 180, 181, 182, 183, 184, 185, 186, 187, 188,
-	189, 190, 191, 192, 193, 194, 195, 196, 197, 198,
-	199, 200, 201, 202, 203, 204, 205, 206, 207, 208,
-	209, 210, 211, 212, 213, 214, 215, 216, 217, 218,
-	219, 220, 221, 222, 223, 224, 225, 226, 227, 228,
-	229, 230, 231, 232, 233, 234, 235, 236, 237, 238,
-	239, 240, 241, 242, 243, 244, 245, 246, 247, 248,
-	249, 250, 251, 252, 253, 254, 255, 256, 257, 258,
-	259, 260, 261, 262, 263, 264, 265, 266, 267, 268,
-	269, 270, 271, 272, 273, 274,
+	52, 53, 54, 55, 56, 57, 58, 59, 60, 61,
+	62, 63, 64, 65, 69, 70, 71, 72, 73, 74,
+	75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
+	85, 86, 88, 89, 90, 91, 92, 93, 97, 98,
+	99, 100, 101, 102, 103, 104, 105, 106, 110, 111,
+	116, 118, 120, 121, 123, 124, 125, 126, 127, 129,
+	130, 131, 132, 133, 134, 135, 136, 137, 138, 139,
+	140, 141, 142, 143, 144, 145, 146, 147, 148, 149,
+	150, 151, 152, 153, 154, 155, 156, 157, 158, 159,
+	160, 161, 162, 163, 164, 165, 166, 167, 168, 169,
+	170, 171, 172, 173, 174, 175, 176, 177, 178, 179,
+	180, 181, 182, 183, 184, 185, 186, 187, 188, 189,
+	190, 191, 192, 193, 194, 195, 196, 197, 198, 199,
+	200, 201, 202, 203, 204, 205, 206, 207, 208, 209,
+	210, 211, 212, 213, 214, 215, 216, 217, 218, 219,
+	220, 221, 222, 223, 224, 225, 226, 227, 228, 229,
+	230, 231, 232, 233, 234, 235, 236, 237, 238, 239,
+	240, 241, 242, 243, 244, 245, 246, 247, 248, 249,
+	250, 251, 252, 253, 254, 255, 256, 257, 258, 259,
+	260, 261, 262, 263, 264, 265, 266, 267, 268, 269,
+	270, 271, 272, 273, 274, 275,
 }
 var yyTok3 = [...]int{
+	57600, 276, 57601, 277, 57602, 278, 57603, 279, 57604, 280,
+	57605, 281, 57606, 282, 57607, 283, 57608, 284, 57609, 285,
+	57610, 286, 57611, 287, 57612, 288, 57613, 289, 57614, 290,
 	0,
 }
 
@@ -3152,35 +3320,35 @@ yydefault:
 
 	case 1:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:339
+//line sql.y:345
 		{
 			setParseTree(yylex, yyDollar[1].statement)
 		}
 	case 2:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:344
+//line sql.y:350
 		{
 		}
 	case 3:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:345
+//line sql.y:351
 		{
 		}
 	case 4:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:349
+//line sql.y:355
 		{
 			yyVAL.statement = yyDollar[1].selStmt
 		}
-	case 23:
+	case 24:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:371
+//line sql.y:378
 		{
 			setParseTree(yylex, nil)
 		}
-	case 24:
+	case 25:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:377
+//line sql.y:384
 		{
 			sel := yyDollar[1].selStmt.(*Select)
 			sel.OrderBy = yyDollar[2].orderBy
@@ -3188,87 +3356,87 @@ yydefault:
 			sel.Lock = yyDollar[4].str
 			yyVAL.selStmt = sel
 		}
-	case 25:
+	case 26:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:385
+//line sql.y:392
 		{
 			yyVAL.selStmt = &With{CommonTableExpressions: yyDollar[2].commonTableExpressions, Select: yyDollar[4].selStmt}
 		}
-	case 26:
+	case 27:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:389
+//line sql.y:396
 		{
 			yyVAL.selStmt = &Union{Type: yyDollar[2].str, Left: yyDollar[1].selStmt, Right: yyDollar[3].selStmt, OrderBy: yyDollar[4].orderBy, Limit: yyDollar[5].limit, Lock: yyDollar[6].str}
 		}
-	case 27:
+	case 28:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:393
+//line sql.y:400
 		{
 			yyVAL.selStmt = &Select{Comments: Comments(yyDollar[2].bytes2), Cache: yyDollar[3].str, SelectExprs: SelectExprs{Nextval{Expr: yyDollar[5].expr}}, From: TableExprs{&AliasedTableExpr{Expr: yyDollar[7].tableName}}}
 		}
-	case 28:
+	case 29:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:398
+//line sql.y:405
 		{
 			yyVAL.bytes = nil
 		}
-	case 29:
+	case 30:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:402
+//line sql.y:409
 		{
 			yyVAL.bytes = []byte(",")
 		}
-	case 30:
+	case 31:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:409
+//line sql.y:416
 		{
 			yyVAL.commonTableExpressions = []*CommonTableExpression{yyDollar[1].commonTableExpression}
 		}
-	case 31:
+	case 32:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:413
+//line sql.y:420
 		{
 			yyVAL.commonTableExpressions = append(yyDollar[1].commonTableExpressions, yyDollar[3].commonTableExpression)
 		}
-	case 32:
+	case 33:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:419
+//line sql.y:426
 		{
 			yyVAL.commonTableExpression = &CommonTableExpression{Name: yyDollar[1].tableIdent, Select: yyDollar[4].selStmt}
 		}
-	case 33:
+	case 34:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:426
+//line sql.y:433
 		{
 			yyVAL.statement = &Stream{Comments: Comments(yyDollar[2].bytes2), SelectExpr: yyDollar[3].selectExpr, Table: yyDollar[5].tableName}
 		}
-	case 34:
+	case 35:
 		yyDollar = yyS[yypt-11 : yypt+1]
-//line sql.y:433
+//line sql.y:440
 		{
 			yyVAL.selStmt = &Select{Comments: Comments(yyDollar[2].bytes2), Cache: yyDollar[3].str, Distinct: yyDollar[4].str, Hints: yyDollar[5].str, SelectExprs: yyDollar[6].selectExprs, From: yyDollar[7].tableExprs, Where: NewWhere(WhereStr, yyDollar[8].expr), GroupBy: GroupBy(yyDollar[9].exprs), Having: NewWhere(HavingStr, yyDollar[10].expr), Trigger: yyDollar[11].triggers}
 		}
-	case 35:
+	case 36:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:439
+//line sql.y:446
 		{
 			yyVAL.selStmt = &ParenSelect{Select: yyDollar[2].selStmt}
 		}
-	case 36:
+	case 37:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:445
+//line sql.y:452
 		{
 			yyVAL.selStmt = yyDollar[1].selStmt
 		}
-	case 37:
+	case 38:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:449
+//line sql.y:456
 		{
 			yyVAL.selStmt = &ParenSelect{Select: yyDollar[2].selStmt}
 		}
-	case 38:
+	case 39:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:456
+//line sql.y:463
 		{
 			// insert_data returns a *Insert pre-filled with Columns & Values
 			ins := yyDollar[6].ins
@@ -3280,9 +3448,9 @@ yydefault:
 			ins.OnDup = OnDup(yyDollar[7].updateExprs)
 			yyVAL.statement = ins
 		}
-	case 39:
+	case 40:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:468
+//line sql.y:475
 		{
 			cols := make(Columns, 0, len(yyDollar[7].updateExprs))
 			vals := make(ValTuple, 0, len(yyDollar[8].updateExprs))
@@ -3292,334 +3460,346 @@ yydefault:
 			}
 			yyVAL.statement = &Insert{Action: yyDollar[1].str, Comments: Comments(yyDollar[2].bytes2), Ignore: yyDollar[3].str, Table: yyDollar[4].tableName, Partitions: yyDollar[5].partitions, Columns: cols, Rows: Values{vals}, OnDup: OnDup(yyDollar[8].updateExprs)}
 		}
-	case 40:
+	case 41:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:480
+//line sql.y:487
 		{
 			yyVAL.str = InsertStr
 		}
-	case 41:
+	case 42:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:484
+//line sql.y:491
 		{
 			yyVAL.str = ReplaceStr
 		}
-	case 42:
+	case 43:
 		yyDollar = yyS[yypt-9 : yypt+1]
-//line sql.y:490
+//line sql.y:497
 		{
 			yyVAL.statement = &Update{Comments: Comments(yyDollar[2].bytes2), Ignore: yyDollar[3].str, TableExprs: yyDollar[4].tableExprs, Exprs: yyDollar[6].updateExprs, Where: NewWhere(WhereStr, yyDollar[7].expr), OrderBy: yyDollar[8].orderBy, Limit: yyDollar[9].limit}
 		}
-	case 43:
+	case 44:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:496
+//line sql.y:503
 		{
 			yyVAL.statement = &Delete{Comments: Comments(yyDollar[2].bytes2), TableExprs: TableExprs{&AliasedTableExpr{Expr: yyDollar[4].tableName}}, Partitions: yyDollar[5].partitions, Where: NewWhere(WhereStr, yyDollar[6].expr), OrderBy: yyDollar[7].orderBy, Limit: yyDollar[8].limit}
 		}
-	case 44:
+	case 45:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:500
+//line sql.y:507
 		{
 			yyVAL.statement = &Delete{Comments: Comments(yyDollar[2].bytes2), Targets: yyDollar[4].tableNames, TableExprs: yyDollar[6].tableExprs, Where: NewWhere(WhereStr, yyDollar[7].expr)}
 		}
-	case 45:
+	case 46:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:504
+//line sql.y:511
 		{
 			yyVAL.statement = &Delete{Comments: Comments(yyDollar[2].bytes2), Targets: yyDollar[3].tableNames, TableExprs: yyDollar[5].tableExprs, Where: NewWhere(WhereStr, yyDollar[6].expr)}
 		}
-	case 46:
+	case 47:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:508
+//line sql.y:515
 		{
 			yyVAL.statement = &Delete{Comments: Comments(yyDollar[2].bytes2), Targets: yyDollar[3].tableNames, TableExprs: yyDollar[5].tableExprs, Where: NewWhere(WhereStr, yyDollar[6].expr)}
 		}
-	case 47:
+	case 48:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:513
+//line sql.y:520
 		{
 		}
-	case 48:
+	case 49:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:514
+//line sql.y:521
 		{
 		}
-	case 49:
+	case 50:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:518
+//line sql.y:525
 		{
 			yyVAL.tableNames = TableNames{yyDollar[1].tableName}
 		}
-	case 50:
+	case 51:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:522
+//line sql.y:529
 		{
 			yyVAL.tableNames = append(yyVAL.tableNames, yyDollar[3].tableName)
 		}
-	case 51:
+	case 52:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:528
+//line sql.y:535
 		{
 			yyVAL.tableNames = TableNames{yyDollar[1].tableName}
 		}
-	case 52:
+	case 53:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:532
+//line sql.y:539
 		{
 			yyVAL.tableNames = append(yyVAL.tableNames, yyDollar[3].tableName)
 		}
-	case 53:
+	case 54:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:537
+//line sql.y:544
 		{
 			yyVAL.partitions = nil
 		}
-	case 54:
+	case 55:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:541
+//line sql.y:548
 		{
 			yyVAL.partitions = yyDollar[3].partitions
 		}
-	case 55:
+	case 56:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:547
+//line sql.y:554
 		{
 			yyVAL.statement = &Set{Comments: Comments(yyDollar[2].bytes2), Exprs: yyDollar[3].setExprs}
 		}
-	case 56:
+	case 57:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:551
+//line sql.y:558
 		{
 			yyVAL.statement = &Set{Comments: Comments(yyDollar[2].bytes2), Scope: yyDollar[3].str, Exprs: yyDollar[4].setExprs}
 		}
-	case 57:
+	case 58:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:555
+//line sql.y:562
 		{
 			yyVAL.statement = &Set{Comments: Comments(yyDollar[2].bytes2), Scope: yyDollar[3].str, Exprs: yyDollar[5].setExprs}
 		}
-	case 58:
+	case 59:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:559
+//line sql.y:566
 		{
 			yyVAL.statement = &Set{Comments: Comments(yyDollar[2].bytes2), Exprs: yyDollar[4].setExprs}
 		}
-	case 59:
+	case 60:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:570
+		{
+			yyVAL.statement = &Set{Comments: Comments(yyDollar[2].bytes2), Exprs: yyDollar[3].setExprs}
+		}
+	case 61:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:565
+//line sql.y:576
 		{
 			yyVAL.setExprs = SetExprs{yyDollar[1].setExpr}
 		}
-	case 60:
+	case 62:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:569
+//line sql.y:580
 		{
 			yyVAL.setExprs = append(yyVAL.setExprs, yyDollar[3].setExpr)
 		}
-	case 61:
+	case 63:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:575
+//line sql.y:586
 		{
 			yyVAL.setExpr = &SetExpr{Name: NewColIdent(TransactionStr), Expr: NewStrVal([]byte(yyDollar[3].str))}
 		}
-	case 62:
+	case 64:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:579
+//line sql.y:590
 		{
 			yyVAL.setExpr = &SetExpr{Name: NewColIdent(TransactionStr), Expr: NewStrVal([]byte(TxReadWrite))}
 		}
-	case 63:
+	case 65:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:583
+//line sql.y:594
 		{
 			yyVAL.setExpr = &SetExpr{Name: NewColIdent(TransactionStr), Expr: NewStrVal([]byte(TxReadOnly))}
 		}
-	case 64:
+	case 66:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:589
+//line sql.y:600
 		{
 			yyVAL.str = IsolationLevelRepeatableRead
 		}
-	case 65:
+	case 67:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:593
+//line sql.y:604
 		{
 			yyVAL.str = IsolationLevelReadCommitted
 		}
-	case 66:
+	case 68:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:597
+//line sql.y:608
 		{
 			yyVAL.str = IsolationLevelReadUncommitted
 		}
-	case 67:
+	case 69:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:601
+//line sql.y:612
 		{
 			yyVAL.str = IsolationLevelSerializable
 		}
-	case 68:
+	case 70:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:607
+//line sql.y:618
 		{
 			yyVAL.str = SessionStr
 		}
-	case 69:
+	case 71:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:611
+//line sql.y:622
 		{
 			yyVAL.str = GlobalStr
 		}
-	case 70:
+	case 72:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:617
+//line sql.y:628
 		{
 			yyDollar[1].ddl.TableSpec = yyDollar[2].TableSpec
 			yyVAL.statement = yyDollar[1].ddl
 		}
-	case 71:
+	case 73:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:622
+//line sql.y:633
 		{
 			// Create table [name] like [name]
 			yyDollar[1].ddl.OptLike = yyDollar[2].optLike
 			yyVAL.statement = yyDollar[1].ddl
 		}
-	case 72:
+	case 74:
+		yyDollar = yyS[yypt-5 : yypt+1]
+//line sql.y:639
+		{
+			yyVAL.statement = &CreateTableAs{Path: string(yyDollar[3].bytes), Select: yyDollar[5].selStmt}
+		}
+	case 75:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:628
+//line sql.y:643
 		{
 			// Change this to an alter statement
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[7].tableName}
 		}
-	case 73:
+	case 76:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:633
+//line sql.y:648
 		{
 			yyVAL.statement = &DDL{Action: CreateStr, Table: yyDollar[3].tableName.ToViewName()}
 		}
-	case 74:
+	case 77:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:637
+//line sql.y:652
 		{
 			yyVAL.statement = &DDL{Action: CreateStr, Table: yyDollar[5].tableName.ToViewName()}
 		}
-	case 75:
+	case 78:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:641
+//line sql.y:656
 		{
 			yyVAL.statement = &DBDDL{Action: CreateStr, DBName: string(yyDollar[4].bytes)}
 		}
-	case 76:
+	case 79:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:645
+//line sql.y:660
 		{
 			yyVAL.statement = &DBDDL{Action: CreateStr, DBName: string(yyDollar[4].bytes)}
 		}
-	case 77:
+	case 80:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:650
+//line sql.y:665
 		{
 			yyVAL.colIdent = NewColIdent("")
 		}
-	case 78:
+	case 81:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:654
+//line sql.y:669
 		{
 			yyVAL.colIdent = yyDollar[2].colIdent
 		}
-	case 79:
+	case 82:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:660
+//line sql.y:675
 		{
 			yyVAL.colIdent = NewColIdent(string(yyDollar[1].bytes))
 		}
-	case 80:
+	case 83:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:665
+//line sql.y:680
 		{
 			var v []VindexParam
 			yyVAL.vindexParams = v
 		}
-	case 81:
+	case 84:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:670
+//line sql.y:685
 		{
 			yyVAL.vindexParams = yyDollar[2].vindexParams
 		}
-	case 82:
+	case 85:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:676
+//line sql.y:691
 		{
 			yyVAL.vindexParams = make([]VindexParam, 0, 4)
 			yyVAL.vindexParams = append(yyVAL.vindexParams, yyDollar[1].vindexParam)
 		}
-	case 83:
+	case 86:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:681
+//line sql.y:696
 		{
 			yyVAL.vindexParams = append(yyVAL.vindexParams, yyDollar[3].vindexParam)
 		}
-	case 84:
+	case 87:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:687
+//line sql.y:702
 		{
 			yyVAL.vindexParam = VindexParam{Key: yyDollar[1].colIdent, Val: yyDollar[3].str}
 		}
-	case 85:
+	case 88:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:693
+//line sql.y:708
 		{
 			yyVAL.ddl = &DDL{Action: CreateStr, Table: yyDollar[4].tableName}
 			setDDL(yylex, yyVAL.ddl)
 		}
-	case 86:
+	case 89:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:700
+//line sql.y:715
 		{
 			yyVAL.TableSpec = yyDollar[2].TableSpec
 			yyVAL.TableSpec.Options = yyDollar[4].str
 		}
-	case 87:
+	case 90:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:707
+//line sql.y:722
 		{
 			yyVAL.optLike = &OptLike{LikeTable: yyDollar[2].tableName}
 		}
-	case 88:
+	case 91:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:711
+//line sql.y:726
 		{
 			yyVAL.optLike = &OptLike{LikeTable: yyDollar[3].tableName}
 		}
-	case 89:
+	case 92:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:717
+//line sql.y:732
 		{
 			yyVAL.TableSpec = &TableSpec{}
 			yyVAL.TableSpec.AddColumn(yyDollar[1].columnDefinition)
 		}
-	case 90:
+	case 93:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:722
+//line sql.y:737
 		{
 			yyVAL.TableSpec.AddColumn(yyDollar[3].columnDefinition)
 		}
-	case 91:
+	case 94:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:726
+//line sql.y:741
 		{
 			yyVAL.TableSpec.AddIndex(yyDollar[3].indexDefinition)
 		}
-	case 92:
+	case 95:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:730
+//line sql.y:745
 		{
 			yyVAL.TableSpec.AddConstraint(yyDollar[3].constraintDefinition)
 		}
-	case 93:
+	case 96:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:736
+//line sql.y:751
 		{
 			yyDollar[2].columnType.NotNull = yyDollar[3].boolVal
 			yyDollar[2].columnType.Default = yyDollar[4].optVal
@@ -3629,814 +3809,814 @@ yydefault:
 			yyDollar[2].columnType.Comment = yyDollar[8].sqlVal
 			yyVAL.columnDefinition = &ColumnDefinition{Name: NewColIdent(string(yyDollar[1].bytes)), Type: yyDollar[2].columnType}
 		}
-	case 94:
+	case 97:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:747
+//line sql.y:762
 		{
 			yyVAL.columnType = yyDollar[1].columnType
 			yyVAL.columnType.Unsigned = yyDollar[2].boolVal
 			yyVAL.columnType.Zerofill = yyDollar[3].boolVal
 		}
-	case 98:
+	case 101:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:758
+//line sql.y:773
 		{
 			yyVAL.columnType = yyDollar[1].columnType
 			yyVAL.columnType.Length = yyDollar[2].sqlVal
 		}
-	case 99:
+	case 102:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:763
+//line sql.y:778
 		{
 			yyVAL.columnType = yyDollar[1].columnType
 		}
-	case 100:
+	case 103:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:769
+//line sql.y:784
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 101:
+	case 104:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:773
+//line sql.y:788
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 102:
+	case 105:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:777
+//line sql.y:792
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 103:
+	case 106:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:781
+//line sql.y:796
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 104:
+	case 107:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:785
+//line sql.y:800
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 105:
+	case 108:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:789
+//line sql.y:804
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 106:
+	case 109:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:793
+//line sql.y:808
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 107:
+	case 110:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:797
+//line sql.y:812
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 108:
+	case 111:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:801
+//line sql.y:816
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 109:
+	case 112:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:807
+//line sql.y:822
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 			yyVAL.columnType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.columnType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 110:
+	case 113:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:813
+//line sql.y:828
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 			yyVAL.columnType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.columnType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 111:
+	case 114:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:819
+//line sql.y:834
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 			yyVAL.columnType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.columnType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 112:
+	case 115:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:825
+//line sql.y:840
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 			yyVAL.columnType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.columnType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 113:
+	case 116:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:831
+//line sql.y:846
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 			yyVAL.columnType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.columnType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 114:
+	case 117:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:839
+//line sql.y:854
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 115:
+	case 118:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:843
+//line sql.y:858
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 116:
+	case 119:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:847
+//line sql.y:862
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 117:
+	case 120:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:851
+//line sql.y:866
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 118:
+	case 121:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:855
+//line sql.y:870
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 119:
+	case 122:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:861
+//line sql.y:876
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal, Charset: yyDollar[3].str, Collate: yyDollar[4].str}
 		}
-	case 120:
+	case 123:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:865
+//line sql.y:880
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal, Charset: yyDollar[3].str, Collate: yyDollar[4].str}
 		}
-	case 121:
+	case 124:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:869
+//line sql.y:884
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 122:
+	case 125:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:873
+//line sql.y:888
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 123:
+	case 126:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:877
+//line sql.y:892
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Charset: yyDollar[2].str, Collate: yyDollar[3].str}
 		}
-	case 124:
+	case 127:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:881
+//line sql.y:896
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Charset: yyDollar[2].str, Collate: yyDollar[3].str}
 		}
-	case 125:
+	case 128:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:885
+//line sql.y:900
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Charset: yyDollar[2].str, Collate: yyDollar[3].str}
 		}
-	case 126:
+	case 129:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:889
+//line sql.y:904
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), Charset: yyDollar[2].str, Collate: yyDollar[3].str}
 		}
-	case 127:
+	case 130:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:893
+//line sql.y:908
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 128:
+	case 131:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:897
+//line sql.y:912
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 129:
+	case 132:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:901
+//line sql.y:916
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 130:
+	case 133:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:905
+//line sql.y:920
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 131:
+	case 134:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:909
+//line sql.y:924
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 132:
+	case 135:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:913
+//line sql.y:928
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), EnumValues: yyDollar[3].strs, Charset: yyDollar[5].str, Collate: yyDollar[6].str}
 		}
-	case 133:
+	case 136:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:918
+//line sql.y:933
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes), EnumValues: yyDollar[3].strs, Charset: yyDollar[5].str, Collate: yyDollar[6].str}
 		}
-	case 134:
+	case 137:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:924
+//line sql.y:939
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 135:
+	case 138:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:928
+//line sql.y:943
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 136:
+	case 139:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:932
+//line sql.y:947
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 137:
+	case 140:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:936
+//line sql.y:951
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 138:
+	case 141:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:940
+//line sql.y:955
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 139:
+	case 142:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:944
+//line sql.y:959
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 140:
+	case 143:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:948
+//line sql.y:963
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 141:
+	case 144:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:952
+//line sql.y:967
 		{
 			yyVAL.columnType = ColumnType{Type: string(yyDollar[1].bytes)}
 		}
-	case 142:
+	case 145:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:958
+//line sql.y:973
 		{
 			yyVAL.strs = make([]string, 0, 4)
 			yyVAL.strs = append(yyVAL.strs, "'"+string(yyDollar[1].bytes)+"'")
 		}
-	case 143:
+	case 146:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:963
+//line sql.y:978
 		{
 			yyVAL.strs = append(yyDollar[1].strs, "'"+string(yyDollar[3].bytes)+"'")
 		}
-	case 144:
+	case 147:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:968
+//line sql.y:983
 		{
 			yyVAL.sqlVal = nil
 		}
-	case 145:
+	case 148:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:972
+//line sql.y:987
 		{
 			yyVAL.sqlVal = NewIntVal(yyDollar[2].bytes)
 		}
-	case 146:
+	case 149:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:977
+//line sql.y:992
 		{
 			yyVAL.LengthScaleOption = LengthScaleOption{}
 		}
-	case 147:
+	case 150:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:981
+//line sql.y:996
 		{
 			yyVAL.LengthScaleOption = LengthScaleOption{
 				Length: NewIntVal(yyDollar[2].bytes),
 				Scale:  NewIntVal(yyDollar[4].bytes),
 			}
 		}
-	case 148:
+	case 151:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:989
+//line sql.y:1004
 		{
 			yyVAL.LengthScaleOption = LengthScaleOption{}
 		}
-	case 149:
+	case 152:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:993
+//line sql.y:1008
 		{
 			yyVAL.LengthScaleOption = LengthScaleOption{
 				Length: NewIntVal(yyDollar[2].bytes),
 			}
 		}
-	case 150:
+	case 153:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:999
+//line sql.y:1014
 		{
 			yyVAL.LengthScaleOption = LengthScaleOption{
 				Length: NewIntVal(yyDollar[2].bytes),
 				Scale:  NewIntVal(yyDollar[4].bytes),
 			}
 		}
-	case 151:
+	case 154:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1007
+//line sql.y:1022
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 152:
+	case 155:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1011
+//line sql.y:1026
 		{
 			yyVAL.boolVal = BoolVal(true)
 		}
-	case 153:
+	case 156:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1016
+//line sql.y:1031
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 154:
+	case 157:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1020
+//line sql.y:1035
 		{
 			yyVAL.boolVal = BoolVal(true)
 		}
-	case 155:
+	case 158:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1026
+//line sql.y:1041
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 156:
+	case 159:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1030
+//line sql.y:1045
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 157:
+	case 160:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1034
+//line sql.y:1049
 		{
 			yyVAL.boolVal = BoolVal(true)
 		}
-	case 158:
+	case 161:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1039
+//line sql.y:1054
 		{
 			yyVAL.optVal = nil
 		}
-	case 159:
+	case 162:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1043
+//line sql.y:1058
 		{
 			yyVAL.optVal = yyDollar[2].expr
 		}
-	case 160:
+	case 163:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1048
+//line sql.y:1063
 		{
 			yyVAL.optVal = nil
 		}
-	case 161:
+	case 164:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1052
+//line sql.y:1067
 		{
 			yyVAL.optVal = yyDollar[3].expr
 		}
-	case 162:
+	case 165:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1057
+//line sql.y:1072
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 163:
+	case 166:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1061
+//line sql.y:1076
 		{
 			yyVAL.boolVal = BoolVal(true)
 		}
-	case 164:
+	case 167:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1066
+//line sql.y:1081
 		{
 			yyVAL.str = ""
 		}
-	case 165:
+	case 168:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1070
+//line sql.y:1085
 		{
 			yyVAL.str = string(yyDollar[3].bytes)
 		}
-	case 166:
+	case 169:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1074
+//line sql.y:1089
 		{
 			yyVAL.str = string(yyDollar[3].bytes)
 		}
-	case 167:
+	case 170:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1079
+//line sql.y:1094
 		{
 			yyVAL.str = ""
 		}
-	case 168:
+	case 171:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1083
+//line sql.y:1098
 		{
 			yyVAL.str = string(yyDollar[2].bytes)
 		}
-	case 169:
+	case 172:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1087
+//line sql.y:1102
 		{
 			yyVAL.str = string(yyDollar[2].bytes)
 		}
-	case 170:
+	case 173:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1092
+//line sql.y:1107
 		{
 			yyVAL.colKeyOpt = colKeyNone
 		}
-	case 171:
+	case 174:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1096
+//line sql.y:1111
 		{
 			yyVAL.colKeyOpt = colKeyPrimary
 		}
-	case 172:
+	case 175:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1100
+//line sql.y:1115
 		{
 			yyVAL.colKeyOpt = colKey
 		}
-	case 173:
+	case 176:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1104
+//line sql.y:1119
 		{
 			yyVAL.colKeyOpt = colKeyUniqueKey
 		}
-	case 174:
+	case 177:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1108
+//line sql.y:1123
 		{
 			yyVAL.colKeyOpt = colKeyUnique
 		}
-	case 175:
+	case 178:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1113
+//line sql.y:1128
 		{
 			yyVAL.sqlVal = nil
 		}
-	case 176:
+	case 179:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1117
+//line sql.y:1132
 		{
 			yyVAL.sqlVal = NewStrVal(yyDollar[2].bytes)
 		}
-	case 177:
+	case 180:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1123
+//line sql.y:1138
 		{
 			yyVAL.indexDefinition = &IndexDefinition{Info: yyDollar[1].indexInfo, Columns: yyDollar[3].indexColumns, Options: yyDollar[5].indexOptions}
 		}
-	case 178:
+	case 181:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1127
+//line sql.y:1142
 		{
 			yyVAL.indexDefinition = &IndexDefinition{Info: yyDollar[1].indexInfo, Columns: yyDollar[3].indexColumns}
 		}
-	case 179:
+	case 182:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1133
+//line sql.y:1148
 		{
 			yyVAL.indexOptions = []*IndexOption{yyDollar[1].indexOption}
 		}
-	case 180:
+	case 183:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1137
+//line sql.y:1152
 		{
 			yyVAL.indexOptions = append(yyVAL.indexOptions, yyDollar[2].indexOption)
 		}
-	case 181:
+	case 184:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1143
+//line sql.y:1158
 		{
 			yyVAL.indexOption = &IndexOption{Name: string(yyDollar[1].bytes), Using: string(yyDollar[2].bytes)}
 		}
-	case 182:
+	case 185:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1147
+//line sql.y:1162
 		{
 			// should not be string
 			yyVAL.indexOption = &IndexOption{Name: string(yyDollar[1].bytes), Value: NewIntVal(yyDollar[3].bytes)}
 		}
-	case 183:
+	case 186:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1152
+//line sql.y:1167
 		{
 			yyVAL.indexOption = &IndexOption{Name: string(yyDollar[1].bytes), Value: NewStrVal(yyDollar[2].bytes)}
 		}
-	case 184:
+	case 187:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1158
+//line sql.y:1173
 		{
 			yyVAL.str = ""
 		}
-	case 185:
+	case 188:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1162
+//line sql.y:1177
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 186:
+	case 189:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1168
+//line sql.y:1183
 		{
 			yyVAL.indexInfo = &IndexInfo{Type: string(yyDollar[1].bytes) + " " + string(yyDollar[2].bytes), Name: NewColIdent("PRIMARY"), Primary: true, Unique: true}
 		}
-	case 187:
+	case 190:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1172
+//line sql.y:1187
 		{
 			yyVAL.indexInfo = &IndexInfo{Type: string(yyDollar[1].bytes) + " " + string(yyDollar[2].str), Name: NewColIdent(yyDollar[3].str), Spatial: true, Unique: false}
 		}
-	case 188:
+	case 191:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1176
+//line sql.y:1191
 		{
 			yyVAL.indexInfo = &IndexInfo{Type: string(yyDollar[1].bytes) + " " + string(yyDollar[2].str), Name: NewColIdent(yyDollar[3].str), Unique: true}
 		}
-	case 189:
+	case 192:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1180
+//line sql.y:1195
 		{
 			yyVAL.indexInfo = &IndexInfo{Type: string(yyDollar[1].bytes), Name: NewColIdent(yyDollar[2].str), Unique: true}
 		}
-	case 190:
+	case 193:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1184
+//line sql.y:1199
 		{
 			yyVAL.indexInfo = &IndexInfo{Type: string(yyDollar[1].str), Name: NewColIdent(yyDollar[2].str), Unique: false}
 		}
-	case 191:
+	case 194:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1190
+//line sql.y:1205
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 192:
+	case 195:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1194
+//line sql.y:1209
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 193:
+	case 196:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1199
+//line sql.y:1214
 		{
 			yyVAL.str = ""
 		}
-	case 194:
+	case 197:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1203
+//line sql.y:1218
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 195:
+	case 198:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1209
+//line sql.y:1224
 		{
 			yyVAL.indexColumns = []*IndexColumn{yyDollar[1].indexColumn}
 		}
-	case 196:
+	case 199:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1213
+//line sql.y:1228
 		{
 			yyVAL.indexColumns = append(yyVAL.indexColumns, yyDollar[3].indexColumn)
 		}
-	case 197:
+	case 200:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1219
+//line sql.y:1234
 		{
 			yyVAL.indexColumn = &IndexColumn{Column: yyDollar[1].colIdent, Length: yyDollar[2].sqlVal}
 		}
-	case 198:
+	case 201:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1225
+//line sql.y:1240
 		{
 			yyVAL.constraintDefinition = &ConstraintDefinition{Name: string(yyDollar[2].bytes), Details: yyDollar[3].constraintInfo}
 		}
-	case 199:
+	case 202:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1229
+//line sql.y:1244
 		{
 			yyVAL.constraintDefinition = &ConstraintDefinition{Details: yyDollar[1].constraintInfo}
 		}
-	case 200:
+	case 203:
 		yyDollar = yyS[yypt-10 : yypt+1]
-//line sql.y:1236
+//line sql.y:1251
 		{
 			yyVAL.constraintInfo = &ForeignKeyDefinition{Source: yyDollar[4].columns, ReferencedTable: yyDollar[7].tableName, ReferencedColumns: yyDollar[9].columns}
 		}
-	case 201:
+	case 204:
 		yyDollar = yyS[yypt-11 : yypt+1]
-//line sql.y:1240
+//line sql.y:1255
 		{
 			yyVAL.constraintInfo = &ForeignKeyDefinition{Source: yyDollar[4].columns, ReferencedTable: yyDollar[7].tableName, ReferencedColumns: yyDollar[9].columns, OnDelete: yyDollar[11].ReferenceAction}
 		}
-	case 202:
+	case 205:
 		yyDollar = yyS[yypt-11 : yypt+1]
-//line sql.y:1244
+//line sql.y:1259
 		{
 			yyVAL.constraintInfo = &ForeignKeyDefinition{Source: yyDollar[4].columns, ReferencedTable: yyDollar[7].tableName, ReferencedColumns: yyDollar[9].columns, OnUpdate: yyDollar[11].ReferenceAction}
 		}
-	case 203:
+	case 206:
 		yyDollar = yyS[yypt-12 : yypt+1]
-//line sql.y:1248
+//line sql.y:1263
 		{
 			yyVAL.constraintInfo = &ForeignKeyDefinition{Source: yyDollar[4].columns, ReferencedTable: yyDollar[7].tableName, ReferencedColumns: yyDollar[9].columns, OnDelete: yyDollar[11].ReferenceAction, OnUpdate: yyDollar[12].ReferenceAction}
 		}
-	case 204:
+	case 207:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1254
+//line sql.y:1269
 		{
 			yyVAL.ReferenceAction = yyDollar[3].ReferenceAction
 		}
-	case 205:
+	case 208:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1260
+//line sql.y:1275
 		{
 			yyVAL.ReferenceAction = yyDollar[3].ReferenceAction
 		}
-	case 206:
+	case 209:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1266
+//line sql.y:1281
 		{
 			yyVAL.ReferenceAction = Restrict
 		}
-	case 207:
+	case 210:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1270
+//line sql.y:1285
 		{
 			yyVAL.ReferenceAction = Cascade
 		}
-	case 208:
+	case 211:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1274
+//line sql.y:1289
 		{
 			yyVAL.ReferenceAction = NoAction
 		}
-	case 209:
+	case 212:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1278
+//line sql.y:1293
 		{
 			yyVAL.ReferenceAction = SetDefault
 		}
-	case 210:
+	case 213:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1282
+//line sql.y:1297
 		{
 			yyVAL.ReferenceAction = SetNull
 		}
-	case 211:
+	case 214:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1287
+//line sql.y:1302
 		{
 			yyVAL.str = ""
 		}
-	case 212:
+	case 215:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1291
+//line sql.y:1306
 		{
 			yyVAL.str = " " + string(yyDollar[1].str)
 		}
-	case 213:
+	case 216:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1295
+//line sql.y:1310
 		{
 			yyVAL.str = string(yyDollar[1].str) + ", " + string(yyDollar[3].str)
 		}
-	case 214:
+	case 217:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1303
+//line sql.y:1318
 		{
 			yyVAL.str = yyDollar[1].str
 		}
-	case 215:
+	case 218:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1307
+//line sql.y:1322
 		{
 			yyVAL.str = yyDollar[1].str + " " + yyDollar[2].str
 		}
-	case 216:
+	case 219:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1311
+//line sql.y:1326
 		{
 			yyVAL.str = yyDollar[1].str + "=" + yyDollar[3].str
 		}
-	case 217:
+	case 220:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1317
+//line sql.y:1332
 		{
 			yyVAL.str = yyDollar[1].colIdent.String()
 		}
-	case 218:
+	case 221:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1321
+//line sql.y:1336
 		{
 			yyVAL.str = "'" + string(yyDollar[1].bytes) + "'"
 		}
-	case 219:
+	case 222:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1325
+//line sql.y:1340
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 220:
+	case 223:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:1331
+//line sql.y:1346
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[4].tableName}
 		}
-	case 221:
+	case 224:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1335
+//line sql.y:1350
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[4].tableName}
 		}
-	case 222:
+	case 225:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1339
+//line sql.y:1354
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[4].tableName}
 		}
-	case 223:
+	case 226:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1343
+//line sql.y:1358
 		{
 			// Change this to a rename statement
 			yyVAL.statement = &DDL{Action: RenameStr, FromTables: TableNames{yyDollar[4].tableName}, ToTables: TableNames{yyDollar[7].tableName}}
 		}
-	case 224:
+	case 227:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1348
+//line sql.y:1363
 		{
 			// Rename an index can just be an alter
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[4].tableName}
 		}
-	case 225:
+	case 228:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1353
+//line sql.y:1368
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[3].tableName.ToViewName()}
 		}
-	case 226:
+	case 229:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1357
+//line sql.y:1372
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[4].tableName, PartitionSpec: yyDollar[5].partSpec}
 		}
-	case 227:
+	case 230:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1361
+//line sql.y:1376
 		{
 			yyVAL.statement = &DDL{Action: CreateVindexStr, VindexSpec: &VindexSpec{
 				Name:   yyDollar[5].colIdent,
@@ -4444,29 +4624,29 @@ yydefault:
 				Params: yyDollar[7].vindexParams,
 			}}
 		}
-	case 228:
+	case 231:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1369
+//line sql.y:1384
 		{
 			yyVAL.statement = &DDL{Action: DropVindexStr, VindexSpec: &VindexSpec{
 				Name: yyDollar[5].colIdent,
 			}}
 		}
-	case 229:
+	case 232:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1375
+//line sql.y:1390
 		{
 			yyVAL.statement = &DDL{Action: AddVschemaTableStr, Table: yyDollar[5].tableName}
 		}
-	case 230:
+	case 233:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1379
+//line sql.y:1394
 		{
 			yyVAL.statement = &DDL{Action: DropVschemaTableStr, Table: yyDollar[5].tableName}
 		}
-	case 231:
+	case 234:
 		yyDollar = yyS[yypt-12 : yypt+1]
-//line sql.y:1383
+//line sql.y:1398
 		{
 			yyVAL.statement = &DDL{
 				Action: AddColVindexStr,
@@ -4479,9 +4659,9 @@ yydefault:
 				VindexCols: yyDollar[9].columns,
 			}
 		}
-	case 232:
+	case 235:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1396
+//line sql.y:1411
 		{
 			yyVAL.statement = &DDL{
 				Action: DropColVindexStr,
@@ -4491,59 +4671,59 @@ yydefault:
 				},
 			}
 		}
-	case 244:
+	case 247:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1421
+//line sql.y:1436
 		{
 			yyVAL.partSpec = &PartitionSpec{Action: ReorganizeStr, Name: yyDollar[3].colIdent, Definitions: yyDollar[6].partDefs}
 		}
-	case 245:
+	case 248:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1427
+//line sql.y:1442
 		{
 			yyVAL.partDefs = []*PartitionDefinition{yyDollar[1].partDef}
 		}
-	case 246:
+	case 249:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1431
+//line sql.y:1446
 		{
 			yyVAL.partDefs = append(yyDollar[1].partDefs, yyDollar[3].partDef)
 		}
-	case 247:
+	case 250:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:1437
+//line sql.y:1452
 		{
 			yyVAL.partDef = &PartitionDefinition{Name: yyDollar[2].colIdent, Limit: yyDollar[7].expr}
 		}
-	case 248:
+	case 251:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:1441
+//line sql.y:1456
 		{
 			yyVAL.partDef = &PartitionDefinition{Name: yyDollar[2].colIdent, Maxvalue: true}
 		}
-	case 249:
+	case 252:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1447
+//line sql.y:1462
 		{
 			yyVAL.statement = yyDollar[3].ddl
 		}
-	case 250:
+	case 253:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1453
+//line sql.y:1468
 		{
 			yyVAL.ddl = &DDL{Action: RenameStr, FromTables: TableNames{yyDollar[1].tableName}, ToTables: TableNames{yyDollar[3].tableName}}
 		}
-	case 251:
+	case 254:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1457
+//line sql.y:1472
 		{
 			yyVAL.ddl = yyDollar[1].ddl
 			yyVAL.ddl.FromTables = append(yyVAL.ddl.FromTables, yyDollar[3].tableName)
 			yyVAL.ddl.ToTables = append(yyVAL.ddl.ToTables, yyDollar[5].tableName)
 		}
-	case 252:
+	case 255:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1465
+//line sql.y:1480
 		{
 			var exists bool
 			if yyDollar[3].byt != 0 {
@@ -4551,16 +4731,16 @@ yydefault:
 			}
 			yyVAL.statement = &DDL{Action: DropStr, FromTables: yyDollar[4].tableNames, IfExists: exists}
 		}
-	case 253:
+	case 256:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:1473
+//line sql.y:1488
 		{
 			// Change this to an alter statement
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[5].tableName}
 		}
-	case 254:
+	case 257:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1478
+//line sql.y:1493
 		{
 			var exists bool
 			if yyDollar[3].byt != 0 {
@@ -4568,154 +4748,160 @@ yydefault:
 			}
 			yyVAL.statement = &DDL{Action: DropStr, FromTables: TableNames{yyDollar[4].tableName.ToViewName()}, IfExists: exists}
 		}
-	case 255:
+	case 258:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1486
+//line sql.y:1501
 		{
 			yyVAL.statement = &DBDDL{Action: DropStr, DBName: string(yyDollar[4].bytes)}
 		}
-	case 256:
+	case 259:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1490
+//line sql.y:1505
 		{
 			yyVAL.statement = &DBDDL{Action: DropStr, DBName: string(yyDollar[4].bytes)}
 		}
-	case 257:
+	case 260:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1496
+//line sql.y:1511
 		{
 			yyVAL.statement = &DDL{Action: TruncateStr, Table: yyDollar[3].tableName}
 		}
-	case 258:
+	case 261:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1500
+//line sql.y:1515
 		{
 			yyVAL.statement = &DDL{Action: TruncateStr, Table: yyDollar[2].tableName}
 		}
-	case 259:
+	case 262:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1505
+//line sql.y:1520
 		{
 			yyVAL.statement = &DDL{Action: AlterStr, Table: yyDollar[3].tableName}
 		}
-	case 260:
+	case 263:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:1524
+		{
+			yyVAL.statement = &AnalyzeFile{Path: string(yyDollar[2].bytes)}
+		}
+	case 264:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1511
+//line sql.y:1530
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 261:
+	case 265:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1516
+//line sql.y:1535
 		{
 			yyVAL.statement = &Show{Type: CharsetStr}
 		}
-	case 262:
+	case 266:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1520
+//line sql.y:1539
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 263:
+	case 267:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1524
+//line sql.y:1543
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 264:
+	case 268:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1529
+//line sql.y:1548
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 265:
+	case 269:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1533
+//line sql.y:1552
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 266:
+	case 270:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1537
+//line sql.y:1556
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes), Table: yyDollar[4].tableName}
 		}
-	case 267:
+	case 271:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1541
+//line sql.y:1560
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 268:
+	case 272:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1545
+//line sql.y:1564
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 269:
+	case 273:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1549
+//line sql.y:1568
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 270:
+	case 274:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1553
+//line sql.y:1572
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 271:
+	case 275:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1557
+//line sql.y:1576
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 272:
+	case 276:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1561
+//line sql.y:1580
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 273:
+	case 277:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1565
+//line sql.y:1584
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 274:
+	case 278:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1569
+//line sql.y:1588
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 275:
+	case 279:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1573
+//line sql.y:1592
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 276:
+	case 280:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1577
+//line sql.y:1596
 		{
 			yyVAL.statement = &Show{Scope: yyDollar[2].str, Type: string(yyDollar[3].bytes)}
 		}
-	case 277:
+	case 281:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1581
+//line sql.y:1600
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 278:
+	case 282:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1585
+//line sql.y:1604
 		{
 			showTablesOpt := &ShowTablesOpt{Full: yyDollar[2].str, DbName: yyDollar[6].str, Filter: yyDollar[7].showFilter}
 			yyVAL.statement = &Show{Type: string(yyDollar[3].str), ShowTablesOpt: showTablesOpt, OnTable: yyDollar[5].tableName}
 		}
-	case 279:
+	case 283:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1590
+//line sql.y:1609
 		{
 			// this is ugly, but I couldn't find a better way for now
 			if yyDollar[3].str == "processlist" {
@@ -4725,704 +4911,800 @@ yydefault:
 				yyVAL.statement = &Show{Type: yyDollar[3].str, ShowTablesOpt: showTablesOpt}
 			}
 		}
-	case 280:
+	case 284:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1600
+//line sql.y:1619
 		{
 			yyVAL.statement = &Show{Scope: yyDollar[2].str, Type: string(yyDollar[3].bytes)}
 		}
-	case 281:
+	case 285:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1604
+//line sql.y:1623
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 282:
+	case 286:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:1608
+//line sql.y:1627
 		{
 			// Cannot dereference $4 directly, or else the parser stackcannot be pooled. See yyParsePooled
 			showCollationFilterOpt := yyDollar[4].expr
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes), ShowCollationFilterOpt: &showCollationFilterOpt}
 		}
-	case 283:
+	case 287:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1614
+//line sql.y:1633
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 284:
+	case 288:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1618
+//line sql.y:1637
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 285:
+	case 289:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1622
+//line sql.y:1641
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 286:
+	case 290:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1626
+//line sql.y:1645
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 287:
+	case 291:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1630
+//line sql.y:1649
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 288:
+	case 292:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1634
+//line sql.y:1653
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes)}
 		}
-	case 289:
+	case 293:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1638
+//line sql.y:1657
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes) + " " + string(yyDollar[3].bytes), OnTable: yyDollar[5].tableName}
 		}
-	case 290:
+	case 294:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1642
+//line sql.y:1661
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 291:
+	case 295:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1652
+//line sql.y:1671
 		{
 			yyVAL.statement = &Show{Type: string(yyDollar[2].bytes)}
 		}
-	case 292:
+	case 296:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1658
+//line sql.y:1677
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 293:
+	case 297:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1662
+//line sql.y:1681
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 294:
+	case 298:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1668
+//line sql.y:1687
 		{
 			yyVAL.str = ""
 		}
-	case 295:
+	case 299:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1672
+//line sql.y:1691
 		{
 			yyVAL.str = "full "
 		}
-	case 296:
+	case 300:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1678
+//line sql.y:1697
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 297:
+	case 301:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1682
+//line sql.y:1701
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 298:
+	case 302:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1688
+//line sql.y:1707
 		{
 			yyVAL.str = ""
 		}
-	case 299:
+	case 303:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1692
+//line sql.y:1711
 		{
 			yyVAL.str = yyDollar[2].tableIdent.v
 		}
-	case 300:
+	case 304:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1696
+//line sql.y:1715
 		{
 			yyVAL.str = yyDollar[2].tableIdent.v
 		}
-	case 301:
+	case 305:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1702
+//line sql.y:1721
 		{
 			yyVAL.showFilter = nil
 		}
-	case 302:
+	case 306:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1706
+//line sql.y:1725
 		{
 			yyVAL.showFilter = &ShowFilter{Like: string(yyDollar[2].bytes)}
 		}
-	case 303:
+	case 307:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1710
+//line sql.y:1729
 		{
 			yyVAL.showFilter = &ShowFilter{Filter: yyDollar[2].expr}
 		}
-	case 304:
+	case 308:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1716
+//line sql.y:1735
 		{
 			yyVAL.str = ""
 		}
-	case 305:
+	case 309:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1720
+//line sql.y:1739
 		{
 			yyVAL.str = SessionStr
 		}
-	case 306:
+	case 310:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1724
+//line sql.y:1743
 		{
 			yyVAL.str = GlobalStr
 		}
-	case 307:
+	case 311:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1730
+//line sql.y:1749
 		{
 			yyVAL.statement = &Use{DBName: yyDollar[2].tableIdent}
 		}
-	case 308:
+	case 312:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1734
+//line sql.y:1753
 		{
 			yyVAL.statement = &Use{DBName: TableIdent{v: ""}}
 		}
-	case 309:
+	case 313:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1740
+//line sql.y:1759
 		{
 			yyVAL.statement = &Begin{}
 		}
-	case 310:
+	case 314:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1744
+//line sql.y:1763
 		{
 			yyVAL.statement = &Begin{}
 		}
-	case 311:
+	case 315:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1750
+//line sql.y:1769
 		{
 			yyVAL.statement = &Commit{}
 		}
-	case 312:
+	case 316:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1756
+//line sql.y:1775
 		{
 			yyVAL.statement = &Rollback{}
 		}
-	case 313:
+	case 317:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1762
+//line sql.y:1781
 		{
-			yyVAL.statement = &OtherRead{}
+			yyVAL.statement = &Explain{Statement: yyDollar[2].selStmt}
 		}
-	case 314:
+	case 318:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:1785
+		{
+			yyVAL.statement = &Explain{Analyze: true, Statement: yyDollar[3].selStmt}
+		}
+	case 319:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1766
+//line sql.y:1791
 		{
 			yyVAL.statement = &OtherRead{}
 		}
-	case 315:
+	case 320:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1770
+//line sql.y:1795
 		{
 			yyVAL.statement = &OtherRead{}
 		}
-	case 316:
+	case 321:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1774
+//line sql.y:1799
 		{
 			yyVAL.statement = &OtherAdmin{}
 		}
-	case 317:
+	case 322:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1778
+//line sql.y:1803
 		{
 			yyVAL.statement = &OtherAdmin{}
 		}
-	case 318:
+	case 323:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1782
+//line sql.y:1807
 		{
 			yyVAL.statement = &OtherAdmin{}
 		}
-	case 319:
+	case 324:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1786
+//line sql.y:1811
 		{
 			yyVAL.statement = &OtherAdmin{}
 		}
-	case 320:
+	case 325:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1792
+//line sql.y:1817
 		{
 			yyVAL.statement = &DDL{Action: FlushStr}
 		}
-	case 321:
+	case 326:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1796
+//line sql.y:1821
 		{
 			setAllowComments(yylex, true)
 		}
-	case 322:
+	case 327:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1800
+//line sql.y:1825
 		{
 			yyVAL.bytes2 = yyDollar[2].bytes2
 			setAllowComments(yylex, false)
 		}
-	case 323:
+	case 328:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1806
+//line sql.y:1831
 		{
 			yyVAL.bytes2 = nil
 		}
-	case 324:
+	case 329:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1810
+//line sql.y:1835
 		{
 			yyVAL.bytes2 = append(yyDollar[1].bytes2, yyDollar[2].bytes)
 		}
-	case 325:
+	case 330:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1816
+//line sql.y:1841
 		{
 			yyVAL.str = UnionStr
 		}
-	case 326:
+	case 331:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1820
+//line sql.y:1845
 		{
 			yyVAL.str = UnionAllStr
 		}
-	case 327:
+	case 332:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1824
+//line sql.y:1849
 		{
 			yyVAL.str = UnionDistinctStr
 		}
-	case 328:
+	case 333:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:1853
+		{
+			yyVAL.str = IntersectStr
+		}
+	case 334:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:1857
+		{
+			yyVAL.str = ExceptStr
+		}
+	case 335:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1829
+//line sql.y:1862
 		{
 			yyVAL.str = ""
 		}
-	case 329:
+	case 336:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1833
+//line sql.y:1866
 		{
 			yyVAL.str = SQLNoCacheStr
 		}
-	case 330:
+	case 337:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1837
+//line sql.y:1870
 		{
 			yyVAL.str = SQLCacheStr
 		}
-	case 331:
+	case 338:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1842
+//line sql.y:1875
 		{
 			yyVAL.str = ""
 		}
-	case 332:
+	case 339:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1846
+//line sql.y:1879
 		{
 			yyVAL.str = DistinctStr
 		}
-	case 333:
+	case 340:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1851
+//line sql.y:1884
 		{
 			yyVAL.str = ""
 		}
-	case 334:
+	case 341:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1855
+//line sql.y:1888
 		{
 			yyVAL.str = StraightJoinHint
 		}
-	case 335:
+	case 342:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1860
+//line sql.y:1893
 		{
 			yyVAL.selectExprs = nil
 		}
-	case 336:
+	case 343:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1864
+//line sql.y:1897
 		{
 			yyVAL.selectExprs = yyDollar[1].selectExprs
 		}
-	case 337:
+	case 344:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1870
+//line sql.y:1903
 		{
 			yyVAL.selectExprs = SelectExprs{yyDollar[1].selectExpr}
 		}
-	case 338:
+	case 345:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1874
+//line sql.y:1907
 		{
 			yyVAL.selectExprs = append(yyVAL.selectExprs, yyDollar[3].selectExpr)
 		}
-	case 339:
-		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1880
+	case 346:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:1913
 		{
-			yyVAL.selectExpr = &StarExpr{}
+			yyVAL.selectExpr = &StarExpr{Exclude: yyDollar[2].exprs, Replace: yyDollar[3].selectExprs}
 		}
-	case 340:
+	case 347:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1884
+//line sql.y:1917
 		{
 			yyVAL.selectExpr = &AliasedExpr{Expr: yyDollar[1].expr, As: yyDollar[2].colIdent}
 		}
-	case 341:
+	case 348:
+		yyDollar = yyS[yypt-5 : yypt+1]
+//line sql.y:1921
+		{
+			yyVAL.selectExpr = &StarExpr{TableName: TableName{Name: yyDollar[1].tableIdent}, Exclude: yyDollar[4].exprs, Replace: yyDollar[5].selectExprs}
+		}
+	case 349:
+		yyDollar = yyS[yypt-0 : yypt+1]
+//line sql.y:1926
+		{
+			yyVAL.exprs = nil
+		}
+	case 350:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:1930
+		{
+			yyVAL.exprs = yyDollar[3].exprs
+		}
+	case 351:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:1936
+		{
+			yyVAL.exprs = Exprs{yyDollar[1].colName}
+		}
+	case 352:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1888
+//line sql.y:1940
 		{
-			yyVAL.selectExpr = &StarExpr{TableName: TableName{Name: yyDollar[1].tableIdent}}
+			yyVAL.exprs = append(yyVAL.exprs, yyDollar[3].colName)
 		}
-	case 342:
+	case 353:
+		yyDollar = yyS[yypt-0 : yypt+1]
+//line sql.y:1945
+		{
+			yyVAL.selectExprs = nil
+		}
+	case 354:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:1949
+		{
+			yyVAL.selectExprs = yyDollar[3].selectExprs
+		}
+	case 355:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:1955
+		{
+			yyVAL.selectExprs = SelectExprs{&AliasedExpr{Expr: yyDollar[1].expr, As: yyDollar[3].colIdent}}
+		}
+	case 356:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:1892
+//line sql.y:1959
 		{
-			yyVAL.selectExpr = &StarExpr{TableName: TableName{Qualifier: yyDollar[1].tableIdent, Name: yyDollar[3].tableIdent}}
+			yyVAL.selectExprs = append(yyVAL.selectExprs, &AliasedExpr{Expr: yyDollar[3].expr, As: yyDollar[5].colIdent})
 		}
-	case 343:
+	case 357:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1897
+//line sql.y:1964
 		{
 			yyVAL.colIdent = ColIdent{}
 		}
-	case 344:
+	case 358:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1901
+//line sql.y:1968
 		{
 			yyVAL.colIdent = yyDollar[1].colIdent
 		}
-	case 345:
+	case 359:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1905
+//line sql.y:1972
 		{
 			yyVAL.colIdent = yyDollar[2].colIdent
 		}
-	case 347:
+	case 361:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1912
+//line sql.y:1979
 		{
 			yyVAL.colIdent = NewColIdent(string(yyDollar[1].bytes))
 		}
-	case 348:
+	case 362:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1917
+//line sql.y:1984
 		{
 			yyVAL.tableExprs = TableExprs{&AliasedTableExpr{Expr: TableName{Name: NewTableIdent("dual")}}}
 		}
-	case 349:
+	case 363:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:1921
+//line sql.y:1988
 		{
 			yyVAL.tableExprs = yyDollar[2].tableExprs
 		}
-	case 350:
+	case 364:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1927
+//line sql.y:1994
 		{
 			yyVAL.tableExprs = TableExprs{yyDollar[1].tableExpr}
 		}
-	case 351:
+	case 365:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1931
+//line sql.y:1998
 		{
 			yyVAL.tableExprs = append(yyVAL.tableExprs, yyDollar[3].tableExpr)
 		}
-	case 354:
+	case 368:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1941
+//line sql.y:2008
 		{
 			yyVAL.tableExpr = yyDollar[1].aliasedTableName
 		}
-	case 355:
+	case 369:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1945
+//line sql.y:2012
 		{
 			yyVAL.tableExpr = &AliasedTableExpr{Expr: yyDollar[1].subquery, As: yyDollar[3].tableIdent}
 		}
-	case 356:
+	case 370:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1949
+//line sql.y:2016
 		{
 			// missed alias for subquery
 			yylex.Error("Every derived table must have its own alias")
 			return 1
 		}
-	case 357:
+	case 371:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1955
+//line sql.y:2022
 		{
 			yyVAL.tableExpr = &ParenTableExpr{Exprs: yyDollar[2].tableExprs}
 		}
-	case 358:
+	case 372:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:1959
+//line sql.y:2026
 		{
 			yyVAL.tableExpr = &TableValuedFunction{Name: NewColIdent(string(yyDollar[1].bytes)), Args: yyDollar[3].tableValuedFunctionArguments, As: yyDollar[6].tableIdent}
 		}
-	case 359:
+	case 373:
+		yyDollar = yyS[yypt-6 : yypt+1]
+//line sql.y:2031
+		{
+			yyVAL.tableExpr = &TableValuedFunction{Name: NewColIdent(string(yyDollar[1].bytes)), Args: yyDollar[3].tableValuedFunctionArguments, As: yyDollar[6].tableIdent}
+		}
+	case 374:
+		yyDollar = yyS[yypt-6 : yypt+1]
+//line sql.y:2035
+		{
+			yyVAL.tableExpr = &UnnestExpr{Expr: yyDollar[3].expr, As: yyDollar[6].tableIdent}
+		}
+	case 375:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1965
+//line sql.y:2041
 		{
 			yyVAL.aliasedTableName = &AliasedTableExpr{Expr: yyDollar[1].tableName, As: yyDollar[2].tableIdent, Hints: yyDollar[3].indexHints}
 		}
-	case 360:
+	case 376:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:1969
+//line sql.y:2045
 		{
 			yyVAL.aliasedTableName = &AliasedTableExpr{Expr: yyDollar[1].tableName, Partitions: yyDollar[4].partitions, As: yyDollar[6].tableIdent, Hints: yyDollar[7].indexHints}
 		}
-	case 361:
+	case 377:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:1974
+//line sql.y:2050
 		{
 			yyVAL.tableValuedFunctionArguments = nil
 		}
-	case 362:
+	case 378:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1978
+//line sql.y:2054
 		{
 			yyVAL.tableValuedFunctionArguments = yyDollar[1].tableValuedFunctionArguments
 		}
-	case 363:
+	case 379:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:1984
+//line sql.y:2060
 		{
 			yyVAL.tableValuedFunctionArguments = TableValuedFunctionArguments{yyDollar[1].tableValuedFunctionArgument}
 		}
-	case 364:
+	case 380:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1988
+//line sql.y:2064
 		{
 			yyVAL.tableValuedFunctionArguments = append(yyVAL.tableValuedFunctionArguments, yyDollar[3].tableValuedFunctionArgument)
 		}
-	case 365:
+	case 381:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:1994
+//line sql.y:2070
 		{
 			yyVAL.tableValuedFunctionArgument = &TableValuedFunctionArgument{Name: yyDollar[1].colIdent, Value: yyDollar[3].tableValuedFunctionArgumentValue}
 		}
-	case 366:
+	case 382:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2074
+		{
+			yyVAL.tableValuedFunctionArgument = &TableValuedFunctionArgument{Value: yyDollar[1].tableValuedFunctionArgumentValue}
+		}
+	case 383:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2000
+//line sql.y:2080
 		{
 			yyVAL.tableValuedFunctionArgumentValue = &ExprTableValuedFunctionArgumentValue{Expr: yyDollar[1].expr}
 		}
-	case 367:
+	case 384:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2004
+//line sql.y:2084
 		{
 			yyVAL.tableValuedFunctionArgumentValue = &TableDescriptorTableValuedFunctionArgumentValue{Table: yyDollar[3].tableExpr}
 		}
-	case 368:
+	case 385:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2008
+//line sql.y:2088
 		{
 			yyVAL.tableValuedFunctionArgumentValue = &FieldDescriptorTableValuedFunctionArgumentValue{Field: yyDollar[3].colName}
 		}
-	case 369:
+	case 386:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2014
+//line sql.y:2094
 		{
 			yyVAL.columns = Columns{yyDollar[1].colIdent}
 		}
-	case 370:
+	case 387:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2018
+//line sql.y:2098
 		{
 			yyVAL.columns = append(yyVAL.columns, yyDollar[3].colIdent)
 		}
-	case 371:
+	case 388:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2024
+//line sql.y:2104
 		{
 			yyVAL.partitions = Partitions{yyDollar[1].colIdent}
 		}
-	case 372:
+	case 389:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2028
+//line sql.y:2108
 		{
 			yyVAL.partitions = append(yyVAL.partitions, yyDollar[3].colIdent)
 		}
-	case 373:
+	case 390:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2041
+//line sql.y:2121
 		{
 			yyVAL.tableExpr = &JoinTableExpr{LeftExpr: yyDollar[1].tableExpr, Join: yyDollar[2].str, RightExpr: yyDollar[3].tableExpr, Condition: yyDollar[4].joinCondition}
 		}
-	case 374:
+	case 391:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2045
+//line sql.y:2125
 		{
 			yyVAL.tableExpr = &JoinTableExpr{LeftExpr: yyDollar[1].tableExpr, Join: yyDollar[2].str, RightExpr: yyDollar[3].tableExpr, Condition: yyDollar[4].joinCondition}
 		}
-	case 375:
+	case 392:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2049
+//line sql.y:2129
 		{
 			yyVAL.tableExpr = &JoinTableExpr{LeftExpr: yyDollar[1].tableExpr, Join: yyDollar[2].str, RightExpr: yyDollar[3].tableExpr, Condition: yyDollar[4].joinCondition}
 		}
-	case 376:
+	case 393:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:2133
+		{
+			yyVAL.tableExpr = &JoinTableExpr{LeftExpr: yyDollar[1].tableExpr, Join: yyDollar[2].str, RightExpr: yyDollar[3].tableExpr, Condition: yyDollar[4].joinCondition}
+		}
+	case 394:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2053
+//line sql.y:2137
 		{
 			yyVAL.tableExpr = &JoinTableExpr{LeftExpr: yyDollar[1].tableExpr, Join: yyDollar[2].str, RightExpr: yyDollar[3].tableExpr}
 		}
-	case 377:
+	case 395:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2059
+//line sql.y:2143
 		{
 			yyVAL.joinCondition = JoinCondition{On: yyDollar[2].expr}
 		}
-	case 378:
+	case 396:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2061
+//line sql.y:2145
 		{
 			yyVAL.joinCondition = JoinCondition{Using: yyDollar[3].columns}
 		}
-	case 379:
+	case 397:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2065
+//line sql.y:2149
 		{
 			yyVAL.joinCondition = JoinCondition{}
 		}
-	case 380:
+	case 398:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2067
+//line sql.y:2151
 		{
 			yyVAL.joinCondition = yyDollar[1].joinCondition
 		}
-	case 381:
+	case 399:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2071
+//line sql.y:2155
 		{
 			yyVAL.joinCondition = JoinCondition{}
 		}
-	case 382:
+	case 400:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2073
+//line sql.y:2157
 		{
 			yyVAL.joinCondition = JoinCondition{On: yyDollar[2].expr}
 		}
-	case 383:
+	case 401:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2076
+//line sql.y:2160
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 384:
+	case 402:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2078
+//line sql.y:2162
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 385:
+	case 403:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2081
+//line sql.y:2165
 		{
 			yyVAL.tableIdent = NewTableIdent("")
 		}
-	case 386:
+	case 404:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2085
+//line sql.y:2169
 		{
 			yyVAL.tableIdent = yyDollar[1].tableIdent
 		}
-	case 387:
+	case 405:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2089
+//line sql.y:2173
 		{
 			yyVAL.tableIdent = yyDollar[2].tableIdent
 		}
-	case 389:
+	case 407:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2096
+//line sql.y:2180
 		{
 			yyVAL.tableIdent = NewTableIdent(string(yyDollar[1].bytes))
 		}
-	case 390:
+	case 408:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2102
+//line sql.y:2186
 		{
 			yyVAL.str = JoinStr
 		}
-	case 391:
+	case 409:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2106
+//line sql.y:2190
 		{
 			yyVAL.str = JoinStr
 		}
-	case 392:
+	case 410:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2110
+//line sql.y:2194
 		{
 			yyVAL.str = JoinStr
 		}
-	case 393:
+	case 411:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2116
+//line sql.y:2200
 		{
 			yyVAL.str = StraightJoinStr
 		}
-	case 394:
+	case 412:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2122
+//line sql.y:2206
 		{
 			yyVAL.str = LeftJoinStr
 		}
-	case 395:
+	case 413:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2126
+//line sql.y:2210
 		{
 			yyVAL.str = LeftJoinStr
 		}
-	case 396:
+	case 414:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2130
+//line sql.y:2214
 		{
 			yyVAL.str = RightJoinStr
 		}
-	case 397:
+	case 415:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2134
+//line sql.y:2218
 		{
 			yyVAL.str = RightJoinStr
 		}
-	case 398:
+	case 416:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:2224
+		{
+			yyVAL.str = FullOuterJoinStr
+		}
+	case 417:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:2228
+		{
+			yyVAL.str = FullOuterJoinStr
+		}
+	case 418:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2140
+//line sql.y:2234
 		{
 			yyVAL.str = NaturalJoinStr
 		}
-	case 399:
+	case 419:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2144
+//line sql.y:2238
 		{
 			if yyDollar[2].str == LeftJoinStr {
 				yyVAL.str = NaturalLeftJoinStr
@@ -5430,465 +5712,483 @@ yydefault:
 				yyVAL.str = NaturalRightJoinStr
 			}
 		}
-	case 400:
+	case 420:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2154
+//line sql.y:2248
 		{
 			yyVAL.tableName = yyDollar[2].tableName
 		}
-	case 401:
+	case 421:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2158
+//line sql.y:2252
 		{
 			yyVAL.tableName = yyDollar[1].tableName
 		}
-	case 402:
+	case 422:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2164
+//line sql.y:2258
 		{
 			yyVAL.tableName = TableName{Name: yyDollar[1].tableIdent}
 		}
-	case 403:
+	case 423:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2168
+//line sql.y:2262
 		{
 			yyVAL.tableName = TableName{Qualifier: yyDollar[1].tableIdent, Name: yyDollar[3].tableIdent}
 		}
-	case 404:
+	case 424:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2174
+//line sql.y:2268
 		{
 			yyVAL.tableName = TableName{Name: yyDollar[1].tableIdent}
 		}
-	case 405:
+	case 425:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2179
+//line sql.y:2273
 		{
 			yyVAL.indexHints = nil
 		}
-	case 406:
+	case 426:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2183
+//line sql.y:2277
 		{
 			yyVAL.indexHints = &IndexHints{Type: UseStr, Indexes: yyDollar[4].columns}
 		}
-	case 407:
+	case 427:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2187
+//line sql.y:2281
 		{
 			yyVAL.indexHints = &IndexHints{Type: IgnoreStr, Indexes: yyDollar[4].columns}
 		}
-	case 408:
+	case 428:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2191
+//line sql.y:2285
 		{
 			yyVAL.indexHints = &IndexHints{Type: ForceStr, Indexes: yyDollar[4].columns}
 		}
-	case 409:
+	case 429:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2196
+//line sql.y:2290
 		{
 			yyVAL.expr = nil
 		}
-	case 410:
+	case 430:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2200
+//line sql.y:2294
 		{
 			yyVAL.expr = yyDollar[2].expr
 		}
-	case 411:
+	case 431:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2206
+//line sql.y:2300
 		{
 			yyVAL.expr = yyDollar[1].expr
 		}
-	case 412:
+	case 432:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2210
+//line sql.y:2304
 		{
 			yyVAL.expr = &AndExpr{Left: yyDollar[1].expr, Right: yyDollar[3].expr}
 		}
-	case 413:
+	case 433:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2214
+//line sql.y:2308
 		{
 			yyVAL.expr = &OrExpr{Left: yyDollar[1].expr, Right: yyDollar[3].expr}
 		}
-	case 414:
+	case 434:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2218
+//line sql.y:2312
 		{
 			yyVAL.expr = &NotExpr{Expr: yyDollar[2].expr}
 		}
-	case 415:
+	case 435:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2222
+//line sql.y:2316
 		{
 			yyVAL.expr = &IsExpr{Operator: yyDollar[3].str, Expr: yyDollar[1].expr}
 		}
-	case 416:
+	case 436:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2226
+//line sql.y:2320
 		{
 			yyVAL.expr = yyDollar[1].expr
 		}
-	case 417:
+	case 437:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2230
+//line sql.y:2324
 		{
 			yyVAL.expr = &Default{ColName: yyDollar[2].str}
 		}
-	case 418:
+	case 438:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2236
+//line sql.y:2330
 		{
 			yyVAL.str = ""
 		}
-	case 419:
+	case 439:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2240
+//line sql.y:2334
 		{
 			yyVAL.str = string(yyDollar[2].bytes)
 		}
-	case 420:
+	case 440:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2246
+//line sql.y:2340
 		{
 			yyVAL.boolVal = BoolVal(true)
 		}
-	case 421:
+	case 441:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2250
+//line sql.y:2344
 		{
 			yyVAL.boolVal = BoolVal(false)
 		}
-	case 422:
+	case 442:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2256
+//line sql.y:2350
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: yyDollar[2].str, Right: yyDollar[3].expr}
 		}
-	case 423:
+	case 443:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2260
+//line sql.y:2354
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: InStr, Right: yyDollar[3].colTuple}
 		}
-	case 424:
+	case 444:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2264
+//line sql.y:2358
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: NotInStr, Right: yyDollar[4].colTuple}
 		}
-	case 425:
+	case 445:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2268
+//line sql.y:2362
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: LikeStr, Right: yyDollar[3].expr, Escape: yyDollar[4].expr}
 		}
-	case 426:
+	case 446:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2272
+//line sql.y:2366
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: NotLikeStr, Right: yyDollar[4].expr, Escape: yyDollar[5].expr}
 		}
-	case 427:
+	case 447:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:2370
+		{
+			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: ILikeStr, Right: yyDollar[3].expr, Escape: yyDollar[4].expr}
+		}
+	case 448:
+		yyDollar = yyS[yypt-5 : yypt+1]
+//line sql.y:2374
+		{
+			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: NotILikeStr, Right: yyDollar[4].expr, Escape: yyDollar[5].expr}
+		}
+	case 449:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2276
+//line sql.y:2378
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: RegexpStr, Right: yyDollar[3].expr}
 		}
-	case 428:
+	case 450:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:2382
+		{
+			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: RegexpStr, Right: yyDollar[3].expr}
+		}
+	case 451:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2280
+//line sql.y:2386
 		{
 			yyVAL.expr = &ComparisonExpr{Left: yyDollar[1].expr, Operator: NotRegexpStr, Right: yyDollar[4].expr}
 		}
-	case 429:
+	case 452:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2284
+//line sql.y:2390
 		{
 			yyVAL.expr = &RangeCond{Left: yyDollar[1].expr, Operator: BetweenStr, From: yyDollar[3].expr, To: yyDollar[5].expr}
 		}
-	case 430:
+	case 453:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:2288
+//line sql.y:2394
 		{
 			yyVAL.expr = &RangeCond{Left: yyDollar[1].expr, Operator: NotBetweenStr, From: yyDollar[4].expr, To: yyDollar[6].expr}
 		}
-	case 431:
+	case 454:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2292
+//line sql.y:2398
 		{
 			yyVAL.expr = &ExistsExpr{Subquery: yyDollar[2].subquery}
 		}
-	case 432:
+	case 455:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2298
+//line sql.y:2404
 		{
 			yyVAL.str = IsNullStr
 		}
-	case 433:
+	case 456:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2302
+//line sql.y:2408
 		{
 			yyVAL.str = IsNotNullStr
 		}
-	case 434:
+	case 457:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2306
+//line sql.y:2412
 		{
 			yyVAL.str = IsTrueStr
 		}
-	case 435:
+	case 458:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2310
+//line sql.y:2416
 		{
 			yyVAL.str = IsNotTrueStr
 		}
-	case 436:
+	case 459:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2314
+//line sql.y:2420
 		{
 			yyVAL.str = IsFalseStr
 		}
-	case 437:
+	case 460:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2318
+//line sql.y:2424
 		{
 			yyVAL.str = IsNotFalseStr
 		}
-	case 438:
+	case 461:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2324
+//line sql.y:2430
 		{
 			yyVAL.str = EqualStr
 		}
-	case 439:
+	case 462:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2328
+//line sql.y:2434
 		{
 			yyVAL.str = LessThanStr
 		}
-	case 440:
+	case 463:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2332
+//line sql.y:2438
 		{
 			yyVAL.str = GreaterThanStr
 		}
-	case 441:
+	case 464:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2336
+//line sql.y:2442
 		{
 			yyVAL.str = LessEqualStr
 		}
-	case 442:
+	case 465:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2340
+//line sql.y:2446
 		{
 			yyVAL.str = GreaterEqualStr
 		}
-	case 443:
+	case 466:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2344
+//line sql.y:2450
 		{
 			yyVAL.str = NotEqualStr
 		}
-	case 444:
+	case 467:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2348
+//line sql.y:2454
 		{
 			yyVAL.str = NullSafeEqualStr
 		}
-	case 445:
+	case 468:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2353
+//line sql.y:2459
 		{
 			yyVAL.expr = nil
 		}
-	case 446:
+	case 469:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2357
+//line sql.y:2463
 		{
 			yyVAL.expr = yyDollar[2].expr
 		}
-	case 447:
+	case 470:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2363
+//line sql.y:2469
 		{
 			yyVAL.colTuple = yyDollar[1].valTuple
 		}
-	case 448:
+	case 471:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2367
+//line sql.y:2473
 		{
 			yyVAL.colTuple = yyDollar[1].subquery
 		}
-	case 449:
+	case 472:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2371
+//line sql.y:2477
 		{
 			yyVAL.colTuple = ListArg(yyDollar[1].bytes)
 		}
-	case 450:
+	case 473:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2377
+//line sql.y:2483
 		{
 			yyVAL.subquery = &Subquery{yyDollar[2].selStmt}
 		}
-	case 451:
+	case 474:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2383
+//line sql.y:2489
 		{
 			yyVAL.exprs = Exprs{yyDollar[1].expr}
 		}
-	case 452:
+	case 475:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2387
+//line sql.y:2493
 		{
 			yyVAL.exprs = append(yyDollar[1].exprs, yyDollar[3].expr)
 		}
-	case 453:
+	case 476:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2393
+//line sql.y:2499
 		{
 			yyVAL.expr = yyDollar[1].expr
 		}
-	case 454:
+	case 477:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2397
+//line sql.y:2503
 		{
 			yyVAL.expr = yyDollar[1].boolVal
 		}
-	case 455:
+	case 478:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2401
+//line sql.y:2507
 		{
 			yyVAL.expr = yyDollar[1].colName
 		}
-	case 456:
+	case 479:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2405
+//line sql.y:2511
 		{
 			yyVAL.expr = yyDollar[1].expr
 		}
-	case 457:
+	case 480:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2409
+//line sql.y:2515
 		{
 			yyVAL.expr = yyDollar[1].subquery
 		}
-	case 458:
+	case 481:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2413
+//line sql.y:2519
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: BitAndStr, Right: yyDollar[3].expr}
 		}
-	case 459:
+	case 482:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2417
+//line sql.y:2523
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: BitOrStr, Right: yyDollar[3].expr}
 		}
-	case 460:
+	case 483:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2421
+//line sql.y:2527
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: BitXorStr, Right: yyDollar[3].expr}
 		}
-	case 461:
+	case 484:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2425
+//line sql.y:2531
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: PlusStr, Right: yyDollar[3].expr}
 		}
-	case 462:
+	case 485:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2429
+//line sql.y:2535
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: MinusStr, Right: yyDollar[3].expr}
 		}
-	case 463:
+	case 486:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2433
+//line sql.y:2539
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: MultStr, Right: yyDollar[3].expr}
 		}
-	case 464:
+	case 487:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2437
+//line sql.y:2543
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: DivStr, Right: yyDollar[3].expr}
 		}
-	case 465:
+	case 488:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2441
+//line sql.y:2547
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: IntDivStr, Right: yyDollar[3].expr}
 		}
-	case 466:
+	case 489:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2445
+//line sql.y:2551
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: ModStr, Right: yyDollar[3].expr}
 		}
-	case 467:
+	case 490:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2449
+//line sql.y:2555
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: ModStr, Right: yyDollar[3].expr}
 		}
-	case 468:
+	case 491:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2453
+//line sql.y:2559
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: ShiftLeftStr, Right: yyDollar[3].expr}
 		}
-	case 469:
+	case 492:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2457
+//line sql.y:2563
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].expr, Operator: ShiftRightStr, Right: yyDollar[3].expr}
 		}
-	case 470:
+	case 493:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2461
+//line sql.y:2567
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].colName, Operator: JSONExtractOp, Right: yyDollar[3].expr}
 		}
-	case 471:
+	case 494:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2465
+//line sql.y:2571
 		{
 			yyVAL.expr = &BinaryExpr{Left: yyDollar[1].colName, Operator: JSONUnquoteExtractOp, Right: yyDollar[3].expr}
 		}
-	case 472:
+	case 495:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2469
+//line sql.y:2575
 		{
 			yyVAL.expr = &CollateExpr{Expr: yyDollar[1].expr, Charset: yyDollar[3].str}
 		}
-	case 473:
+	case 496:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2473
+//line sql.y:2579
 		{
 			yyVAL.expr = &UnaryExpr{Operator: BinaryStr, Expr: yyDollar[2].expr}
 		}
-	case 474:
+	case 497:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2477
+//line sql.y:2583
 		{
 			yyVAL.expr = &UnaryExpr{Operator: UBinaryStr, Expr: yyDollar[2].expr}
 		}
-	case 475:
+	case 498:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2481
+//line sql.y:2587
 		{
 			yyVAL.expr = &UnaryExpr{Operator: Utf8mb4Str, Expr: yyDollar[2].expr}
 		}
-	case 476:
+	case 499:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2485
+//line sql.y:2591
 		{
 			if num, ok := yyDollar[2].expr.(*SQLVal); ok && num.Type == IntVal {
 				yyVAL.expr = num
@@ -5896,9 +6196,9 @@ yydefault:
 				yyVAL.expr = &UnaryExpr{Operator: UPlusStr, Expr: yyDollar[2].expr}
 			}
 		}
-	case 477:
+	case 500:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2493
+//line sql.y:2599
 		{
 			if num, ok := yyDollar[2].expr.(*SQLVal); ok && num.Type == IntVal {
 				// Handle double negative
@@ -5912,21 +6212,45 @@ yydefault:
 				yyVAL.expr = &UnaryExpr{Operator: UMinusStr, Expr: yyDollar[2].expr}
 			}
 		}
-	case 478:
+	case 501:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2507
+//line sql.y:2613
 		{
 			yyVAL.expr = &UnaryExpr{Operator: TildaStr, Expr: yyDollar[2].expr}
 		}
-	case 479:
+	case 502:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2511
+//line sql.y:2617
 		{
 			yyVAL.expr = &UnaryExpr{Operator: BangStr, Expr: yyDollar[2].expr}
 		}
-	case 480:
+	case 503:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:2621
+		{
+			yyVAL.expr = &IndexExpr{Expr: yyDollar[1].expr, Index: yyDollar[3].expr}
+		}
+	case 504:
+		yyDollar = yyS[yypt-5 : yypt+1]
+//line sql.y:2625
+		{
+			yyVAL.expr = &FuncExpr{Name: NewColIdent("at_time_zone"), Exprs: SelectExprs{&AliasedExpr{Expr: yyDollar[1].expr}, &AliasedExpr{Expr: yyDollar[5].expr}}}
+		}
+	case 505:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2515
+//line sql.y:2629
+		{
+			yyVAL.expr = &ArrayExpr{}
+		}
+	case 506:
+		yyDollar = yyS[yypt-4 : yypt+1]
+//line sql.y:2633
+		{
+			yyVAL.expr = &ArrayExpr{Exprs: yyDollar[3].exprs}
+		}
+	case 507:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:2637
 		{
 			// This rule prevents the usage of INTERVAL
 			// as a function. If support is needed for that,
@@ -5934,485 +6258,546 @@ yydefault:
 			// will be non-trivial because of grammar conflicts.
 			yyVAL.expr = &IntervalExpr{Expr: yyDollar[2].expr, Unit: yyDollar[3].colIdent.String()}
 		}
-	case 485:
+	case 512:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2533
+//line sql.y:2655
 		{
 			yyVAL.expr = &FuncExpr{Name: yyDollar[1].colIdent, Exprs: yyDollar[3].selectExprs}
 		}
-	case 486:
+	case 513:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2537
+//line sql.y:2659
 		{
 			yyVAL.expr = &FuncExpr{Name: yyDollar[1].colIdent, Distinct: true, Exprs: yyDollar[4].selectExprs}
 		}
-	case 487:
+	case 514:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:2541
+//line sql.y:2663
 		{
 			yyVAL.expr = &FuncExpr{Qualifier: yyDollar[1].tableIdent, Name: yyDollar[3].colIdent, Exprs: yyDollar[5].selectExprs}
 		}
-	case 488:
+	case 515:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2551
+//line sql.y:2673
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("left"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 489:
+	case 516:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2555
+//line sql.y:2677
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("right"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 490:
+	case 517:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:2559
+//line sql.y:2681
 		{
 			yyVAL.expr = &ConvertExpr{Expr: yyDollar[3].expr, Type: yyDollar[5].convertType}
 		}
-	case 491:
+	case 518:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:2563
+//line sql.y:2685
 		{
 			yyVAL.expr = &ConvertExpr{Expr: yyDollar[3].expr, Type: yyDollar[5].convertType}
 		}
-	case 492:
+	case 519:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:2567
+//line sql.y:2689
 		{
 			yyVAL.expr = &ConvertUsingExpr{Expr: yyDollar[3].expr, Type: yyDollar[5].str}
 		}
-	case 493:
+	case 520:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2571
+//line sql.y:2693
 		{
 			yyVAL.expr = &SubstrExpr{Name: yyDollar[3].colName, From: yyDollar[5].expr, To: yyDollar[7].expr}
 		}
-	case 494:
+	case 521:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2575
+//line sql.y:2697
 		{
 			yyVAL.expr = &SubstrExpr{Name: yyDollar[3].colName, From: yyDollar[5].expr, To: yyDollar[7].expr}
 		}
-	case 495:
+	case 522:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2579
+//line sql.y:2701
 		{
 			yyVAL.expr = &SubstrExpr{StrVal: NewStrVal(yyDollar[3].bytes), From: yyDollar[5].expr, To: yyDollar[7].expr}
 		}
-	case 496:
+	case 523:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2583
+//line sql.y:2705
 		{
 			yyVAL.expr = &SubstrExpr{StrVal: NewStrVal(yyDollar[3].bytes), From: yyDollar[5].expr, To: yyDollar[7].expr}
 		}
-	case 497:
+	case 524:
 		yyDollar = yyS[yypt-9 : yypt+1]
-//line sql.y:2587
+//line sql.y:2709
 		{
 			yyVAL.expr = &MatchExpr{Columns: yyDollar[3].selectExprs, Expr: yyDollar[7].expr, Option: yyDollar[8].str}
 		}
-	case 498:
+	case 525:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:2591
+//line sql.y:2713
 		{
 			yyVAL.expr = &GroupConcatExpr{Distinct: yyDollar[3].str, Exprs: yyDollar[4].selectExprs, OrderBy: yyDollar[5].orderBy, Separator: yyDollar[6].str}
 		}
-	case 499:
+	case 526:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2595
+//line sql.y:2717
 		{
 			yyVAL.expr = &CaseExpr{Expr: yyDollar[2].expr, Whens: yyDollar[3].whens, Else: yyDollar[4].expr}
 		}
-	case 500:
+	case 527:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2599
+//line sql.y:2721
 		{
 			yyVAL.expr = &ValuesFuncExpr{Name: yyDollar[3].colName}
 		}
-	case 501:
+	case 528:
+		yyDollar = yyS[yypt-6 : yypt+1]
+//line sql.y:2725
+		{
+			yyVAL.expr = &FuncExpr{Name: NewColIdent("date_part"), Exprs: SelectExprs{&AliasedExpr{Expr: NewStrVal([]byte(yyDollar[3].colIdent.Lowered()))}, &AliasedExpr{Expr: yyDollar[5].expr}}}
+		}
+	case 529:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2609
+//line sql.y:2735
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("current_timestamp")}
 		}
-	case 502:
+	case 530:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2613
+//line sql.y:2739
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("utc_timestamp")}
 		}
-	case 503:
+	case 531:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2617
+//line sql.y:2743
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("utc_time")}
 		}
-	case 504:
+	case 532:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2622
+//line sql.y:2748
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("utc_date")}
 		}
-	case 505:
+	case 533:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2627
+//line sql.y:2753
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("localtime")}
 		}
-	case 506:
+	case 534:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2632
+//line sql.y:2758
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("localtimestamp")}
 		}
-	case 507:
+	case 535:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2638
+//line sql.y:2764
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("current_date")}
 		}
-	case 508:
+	case 536:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2643
+//line sql.y:2769
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("current_time")}
 		}
-	case 509:
+	case 537:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2648
+//line sql.y:2774
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("current_timestamp"), Fsp: yyDollar[2].expr}
 		}
-	case 510:
+	case 538:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2652
+//line sql.y:2778
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("utc_timestamp"), Fsp: yyDollar[2].expr}
 		}
-	case 511:
+	case 539:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2656
+//line sql.y:2782
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("utc_time"), Fsp: yyDollar[2].expr}
 		}
-	case 512:
+	case 540:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2661
+//line sql.y:2787
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("localtime"), Fsp: yyDollar[2].expr}
 		}
-	case 513:
+	case 541:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2666
+//line sql.y:2792
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("localtimestamp"), Fsp: yyDollar[2].expr}
 		}
-	case 514:
+	case 542:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2671
+//line sql.y:2797
 		{
 			yyVAL.expr = &CurTimeFuncExpr{Name: NewColIdent("current_time"), Fsp: yyDollar[2].expr}
 		}
-	case 515:
+	case 543:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2675
+//line sql.y:2801
 		{
 			yyVAL.expr = &TimestampFuncExpr{Name: string("timestampadd"), Unit: yyDollar[3].colIdent.String(), Expr1: yyDollar[5].expr, Expr2: yyDollar[7].expr}
 		}
-	case 516:
+	case 544:
 		yyDollar = yyS[yypt-8 : yypt+1]
-//line sql.y:2679
+//line sql.y:2805
 		{
 			yyVAL.expr = &TimestampFuncExpr{Name: string("timestampdiff"), Unit: yyDollar[3].colIdent.String(), Expr1: yyDollar[5].expr, Expr2: yyDollar[7].expr}
 		}
-	case 519:
+	case 547:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2689
+//line sql.y:2815
 		{
 			yyVAL.expr = yyDollar[2].expr
 		}
-	case 520:
+	case 548:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2699
+//line sql.y:2825
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("if"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 521:
+	case 549:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2703
+//line sql.y:2829
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("database"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 522:
+	case 550:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2707
+//line sql.y:2833
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("mod"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 523:
+	case 551:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2711
+//line sql.y:2837
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("replace"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 524:
+	case 552:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2715
+//line sql.y:2841
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("substr"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 525:
+	case 553:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2719
+//line sql.y:2845
 		{
 			yyVAL.expr = &FuncExpr{Name: NewColIdent("substr"), Exprs: yyDollar[3].selectExprs}
 		}
-	case 526:
+	case 554:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2725
+//line sql.y:2851
 		{
 			yyVAL.str = ""
 		}
-	case 527:
+	case 555:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2729
+//line sql.y:2855
 		{
 			yyVAL.str = BooleanModeStr
 		}
-	case 528:
+	case 556:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2733
+//line sql.y:2859
 		{
 			yyVAL.str = NaturalLanguageModeStr
 		}
-	case 529:
+	case 557:
 		yyDollar = yyS[yypt-7 : yypt+1]
-//line sql.y:2737
+//line sql.y:2863
 		{
 			yyVAL.str = NaturalLanguageModeWithQueryExpansionStr
 		}
-	case 530:
+	case 558:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2741
+//line sql.y:2867
 		{
 			yyVAL.str = QueryExpansionStr
 		}
-	case 531:
+	case 559:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2747
+//line sql.y:2873
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 532:
+	case 560:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2751
+//line sql.y:2877
 		{
 			yyVAL.str = string(yyDollar[1].bytes)
 		}
-	case 533:
+	case 561:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2757
+//line sql.y:2883
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 534:
+	case 562:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2761
+//line sql.y:2887
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal, Charset: yyDollar[3].str, Operator: CharacterSetStr}
 		}
-	case 535:
+	case 563:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2765
+//line sql.y:2891
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal, Charset: string(yyDollar[3].bytes)}
 		}
-	case 536:
+	case 564:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2769
+//line sql.y:2895
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 537:
+	case 565:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2773
+//line sql.y:2899
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 538:
+	case 566:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2777
+//line sql.y:2903
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 			yyVAL.convertType.Length = yyDollar[2].LengthScaleOption.Length
 			yyVAL.convertType.Scale = yyDollar[2].LengthScaleOption.Scale
 		}
-	case 539:
+	case 567:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2783
+//line sql.y:2909
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 540:
+	case 568:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2787
+//line sql.y:2913
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
+		}
+	case 569:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2917
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 570:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:2921
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 571:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:2925
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
+		}
+	case 572:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2929
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 573:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:2933
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 574:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2937
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 575:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2941
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 576:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2945
+		{
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+		}
+	case 577:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2949
 		{
-			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 541:
+	case 578:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2791
+//line sql.y:2953
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 542:
-		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2795
+	case 579:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2957
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 543:
-		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2799
+	case 580:
+		yyDollar = yyS[yypt-1 : yypt+1]
+//line sql.y:2961
 		{
-			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 544:
+	case 581:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2803
+//line sql.y:2965
 		{
 			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
 		}
-	case 545:
+	case 582:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2807
+//line sql.y:2969
 		{
-			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes)}
+			yyVAL.convertType = &ConvertType{Type: string(yyDollar[1].bytes), Length: yyDollar[2].sqlVal}
 		}
-	case 546:
+	case 583:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2812
+//line sql.y:2974
 		{
 			yyVAL.expr = nil
 		}
-	case 547:
+	case 584:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2816
+//line sql.y:2978
 		{
 			yyVAL.expr = yyDollar[1].expr
 		}
-	case 548:
+	case 585:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2821
+//line sql.y:2983
 		{
 			yyVAL.str = string("")
 		}
-	case 549:
+	case 586:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2825
+//line sql.y:2987
 		{
 			yyVAL.str = " separator '" + string(yyDollar[2].bytes) + "'"
 		}
-	case 550:
+	case 587:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2831
+//line sql.y:2993
 		{
 			yyVAL.whens = []*When{yyDollar[1].when}
 		}
-	case 551:
+	case 588:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2835
+//line sql.y:2997
 		{
 			yyVAL.whens = append(yyDollar[1].whens, yyDollar[2].when)
 		}
-	case 552:
+	case 589:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2841
+//line sql.y:3003
 		{
 			yyVAL.when = &When{Cond: yyDollar[2].expr, Val: yyDollar[4].expr}
 		}
-	case 553:
+	case 590:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2846
+//line sql.y:3008
 		{
 			yyVAL.expr = nil
 		}
-	case 554:
+	case 591:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2850
+//line sql.y:3012
 		{
 			yyVAL.expr = yyDollar[2].expr
 		}
-	case 555:
+	case 592:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2856
+//line sql.y:3018
 		{
 			yyVAL.colName = &ColName{Name: yyDollar[1].colIdent}
 		}
-	case 556:
+	case 594:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2860
+//line sql.y:3025
 		{
 			yyVAL.colName = &ColName{Qualifier: TableName{Name: yyDollar[1].tableIdent}, Name: yyDollar[3].colIdent}
 		}
-	case 557:
-		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:2864
+	case 595:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:3029
 		{
-			yyVAL.colName = &ColName{Qualifier: TableName{Qualifier: yyDollar[1].tableIdent, Name: yyDollar[3].tableIdent}, Name: yyDollar[5].colIdent}
+			yyVAL.colName = yyDollar[1].colName
+			yyVAL.colName.Path = append(yyVAL.colName.Path, yyDollar[3].colIdent)
 		}
-	case 558:
+	case 596:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2870
+//line sql.y:3036
 		{
 			yyVAL.expr = NewStrVal(yyDollar[1].bytes)
 		}
-	case 559:
+	case 597:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2874
+//line sql.y:3040
 		{
 			yyVAL.expr = NewHexVal(yyDollar[1].bytes)
 		}
-	case 560:
+	case 598:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2878
+//line sql.y:3044
 		{
 			yyVAL.expr = NewBitVal(yyDollar[1].bytes)
 		}
-	case 561:
+	case 599:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2882
+//line sql.y:3048
 		{
 			yyVAL.expr = NewIntVal(yyDollar[1].bytes)
 		}
-	case 562:
+	case 600:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2886
+//line sql.y:3052
 		{
 			yyVAL.expr = NewFloatVal(yyDollar[1].bytes)
 		}
-	case 563:
+	case 601:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2890
+//line sql.y:3056
 		{
 			yyVAL.expr = NewHexNum(yyDollar[1].bytes)
 		}
-	case 564:
+	case 602:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2894
+//line sql.y:3060
 		{
 			yyVAL.expr = NewValArg(yyDollar[1].bytes)
 		}
-	case 565:
+	case 603:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2898
+//line sql.y:3064
 		{
 			yyVAL.expr = &NullVal{}
 		}
-	case 566:
+	case 604:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2904
+//line sql.y:3070
 		{
 			// TODO(sougou): Deprecate this construct.
 			if yyDollar[1].colIdent.Lowered() != "value" {
@@ -6421,281 +6806,305 @@ yydefault:
 			}
 			yyVAL.expr = NewIntVal([]byte("1"))
 		}
-	case 567:
+	case 605:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2913
+//line sql.y:3079
 		{
 			yyVAL.expr = NewIntVal(yyDollar[1].bytes)
 		}
-	case 568:
+	case 606:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2917
+//line sql.y:3083
 		{
 			yyVAL.expr = NewValArg(yyDollar[1].bytes)
 		}
-	case 569:
+	case 607:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2922
+//line sql.y:3088
 		{
 			yyVAL.exprs = nil
 		}
-	case 570:
+	case 608:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2926
+//line sql.y:3092
 		{
 			yyVAL.exprs = yyDollar[3].exprs
 		}
-	case 571:
+	case 609:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2931
+//line sql.y:3097
 		{
 			yyVAL.expr = nil
 		}
-	case 572:
+	case 610:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2935
+//line sql.y:3101
 		{
 			yyVAL.expr = yyDollar[2].expr
 		}
-	case 573:
+	case 611:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2940
+//line sql.y:3106
 		{
 			yyVAL.orderBy = nil
 		}
-	case 574:
+	case 612:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2944
+//line sql.y:3110
 		{
 			yyVAL.orderBy = yyDollar[3].orderBy
 		}
-	case 575:
+	case 613:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2950
+//line sql.y:3116
 		{
 			yyVAL.orderBy = OrderBy{yyDollar[1].order}
 		}
-	case 576:
+	case 614:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:2954
+//line sql.y:3120
 		{
 			yyVAL.orderBy = append(yyDollar[1].orderBy, yyDollar[3].order)
 		}
-	case 577:
-		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2960
+	case 615:
+		yyDollar = yyS[yypt-3 : yypt+1]
+//line sql.y:3126
 		{
-			yyVAL.order = &Order{Expr: yyDollar[1].expr, Direction: yyDollar[2].str}
+			yyVAL.order = &Order{Expr: yyDollar[1].expr, Direction: yyDollar[2].str, Nulls: yyDollar[3].str}
 		}
-	case 578:
+	case 616:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2965
+//line sql.y:3131
 		{
 			yyVAL.str = AscScr
 		}
-	case 579:
+	case 617:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2969
+//line sql.y:3135
 		{
 			yyVAL.str = AscScr
 		}
-	case 580:
+	case 618:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:2973
+//line sql.y:3139
 		{
 			yyVAL.str = DescScr
 		}
-	case 581:
+	case 619:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2978
+//line sql.y:3144
+		{
+			yyVAL.str = ""
+		}
+	case 620:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:3148
+		{
+			yyVAL.str = NullsFirstStr
+		}
+	case 621:
+		yyDollar = yyS[yypt-2 : yypt+1]
+//line sql.y:3152
+		{
+			yyVAL.str = NullsLastStr
+		}
+	case 622:
+		yyDollar = yyS[yypt-0 : yypt+1]
+//line sql.y:3157
 		{
 			yyVAL.limit = nil
 		}
-	case 582:
+	case 623:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2982
+//line sql.y:3161
 		{
 			yyVAL.limit = &Limit{Rowcount: yyDollar[2].expr}
 		}
-	case 583:
+	case 624:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2986
+//line sql.y:3165
 		{
 			yyVAL.limit = &Limit{Offset: yyDollar[2].expr, Rowcount: yyDollar[4].expr}
 		}
-	case 584:
+	case 625:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:2990
+//line sql.y:3169
 		{
 			yyVAL.limit = &Limit{Offset: yyDollar[4].expr, Rowcount: yyDollar[2].expr}
 		}
-	case 585:
+	case 626:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:2995
+//line sql.y:3174
 		{
 			yyVAL.str = ""
 		}
-	case 586:
+	case 627:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:2999
+//line sql.y:3178
 		{
 			yyVAL.str = ForUpdateStr
 		}
-	case 587:
+	case 628:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:3003
+//line sql.y:3182
 		{
 			yyVAL.str = ShareModeStr
 		}
-	case 588:
+	case 629:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3008
+//line sql.y:3187
 		{
 			yyVAL.triggers = nil
 		}
-	case 589:
+	case 630:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3012
+//line sql.y:3191
 		{
 			yyVAL.triggers = yyDollar[2].triggers
 		}
-	case 590:
+	case 631:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3018
+//line sql.y:3197
 		{
 			yyVAL.triggers = []Trigger{yyDollar[1].trigger}
 		}
-	case 591:
+	case 632:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3022
+//line sql.y:3201
 		{
 			yyVAL.triggers = append(yyDollar[1].triggers, yyDollar[3].trigger)
 		}
-	case 592:
+	case 633:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3028
+//line sql.y:3207
 		{
 			yyVAL.trigger = &WatermarkTrigger{}
 		}
-	case 593:
+	case 634:
+		yyDollar = yyS[yypt-5 : yypt+1]
+//line sql.y:3211
+		{
+			yyVAL.trigger = &WatermarkTrigger{AllowedLateness: yyDollar[5].expr}
+		}
+	case 635:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3032
+//line sql.y:3215
 		{
 			yyVAL.trigger = &DelayTrigger{Delay: yyDollar[3].expr}
 		}
-	case 594:
+	case 636:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3036
+//line sql.y:3219
 		{
 			yyVAL.trigger = &CountingTrigger{Count: yyDollar[2].expr}
 		}
-	case 595:
+	case 637:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3049
+//line sql.y:3232
 		{
 			yyVAL.ins = &Insert{Rows: yyDollar[2].values}
 		}
-	case 596:
+	case 638:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3053
+//line sql.y:3236
 		{
 			yyVAL.ins = &Insert{Rows: yyDollar[1].selStmt}
 		}
-	case 597:
+	case 639:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3057
+//line sql.y:3240
 		{
 			// Drop the redundant parenthesis.
 			yyVAL.ins = &Insert{Rows: yyDollar[2].selStmt}
 		}
-	case 598:
+	case 640:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:3062
+//line sql.y:3245
 		{
 			yyVAL.ins = &Insert{Columns: yyDollar[2].columns, Rows: yyDollar[5].values}
 		}
-	case 599:
+	case 641:
 		yyDollar = yyS[yypt-4 : yypt+1]
-//line sql.y:3066
+//line sql.y:3249
 		{
 			yyVAL.ins = &Insert{Columns: yyDollar[2].columns, Rows: yyDollar[4].selStmt}
 		}
-	case 600:
+	case 642:
 		yyDollar = yyS[yypt-6 : yypt+1]
-//line sql.y:3070
+//line sql.y:3253
 		{
 			// Drop the redundant parenthesis.
 			yyVAL.ins = &Insert{Columns: yyDollar[2].columns, Rows: yyDollar[5].selStmt}
 		}
-	case 601:
+	case 643:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3077
+//line sql.y:3260
 		{
 			yyVAL.columns = Columns{yyDollar[1].colIdent}
 		}
-	case 602:
+	case 644:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3081
+//line sql.y:3264
 		{
 			yyVAL.columns = Columns{yyDollar[3].colIdent}
 		}
-	case 603:
+	case 645:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3085
+//line sql.y:3268
 		{
 			yyVAL.columns = append(yyVAL.columns, yyDollar[3].colIdent)
 		}
-	case 604:
+	case 646:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:3089
+//line sql.y:3272
 		{
 			yyVAL.columns = append(yyVAL.columns, yyDollar[5].colIdent)
 		}
-	case 605:
+	case 647:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3094
+//line sql.y:3277
 		{
 			yyVAL.updateExprs = nil
 		}
-	case 606:
+	case 648:
 		yyDollar = yyS[yypt-5 : yypt+1]
-//line sql.y:3098
+//line sql.y:3281
 		{
 			yyVAL.updateExprs = yyDollar[5].updateExprs
 		}
-	case 607:
+	case 649:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3104
+//line sql.y:3287
 		{
 			yyVAL.values = Values{yyDollar[1].valTuple}
 		}
-	case 608:
+	case 650:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3108
+//line sql.y:3291
 		{
 			yyVAL.values = append(yyDollar[1].values, yyDollar[3].valTuple)
 		}
-	case 609:
+	case 651:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3114
+//line sql.y:3297
 		{
 			yyVAL.valTuple = yyDollar[1].valTuple
 		}
-	case 610:
+	case 652:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3118
+//line sql.y:3301
 		{
 			yyVAL.valTuple = ValTuple{}
 		}
-	case 611:
+	case 653:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3124
+//line sql.y:3307
 		{
 			yyVAL.valTuple = ValTuple(yyDollar[2].exprs)
 		}
-	case 612:
+	case 654:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3130
+//line sql.y:3313
 		{
 			if len(yyDollar[1].valTuple) == 1 {
 				yyVAL.expr = &ParenExpr{yyDollar[1].valTuple[0]}
@@ -6703,312 +7112,312 @@ yydefault:
 				yyVAL.expr = yyDollar[1].valTuple
 			}
 		}
-	case 613:
+	case 655:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3140
+//line sql.y:3323
 		{
 			yyVAL.updateExprs = UpdateExprs{yyDollar[1].updateExpr}
 		}
-	case 614:
+	case 656:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3144
+//line sql.y:3327
 		{
 			yyVAL.updateExprs = append(yyDollar[1].updateExprs, yyDollar[3].updateExpr)
 		}
-	case 615:
+	case 657:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3150
+//line sql.y:3333
 		{
 			yyVAL.updateExpr = &UpdateExpr{Name: yyDollar[1].colName, Expr: yyDollar[3].expr}
 		}
-	case 616:
+	case 658:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3156
+//line sql.y:3339
 		{
 			yyVAL.setExprs = SetExprs{yyDollar[1].setExpr}
 		}
-	case 617:
+	case 659:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3160
+//line sql.y:3343
 		{
 			yyVAL.setExprs = append(yyDollar[1].setExprs, yyDollar[3].setExpr)
 		}
-	case 618:
+	case 660:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3166
+//line sql.y:3349
 		{
 			yyVAL.setExpr = &SetExpr{Name: yyDollar[1].colIdent, Expr: NewStrVal([]byte("on"))}
 		}
-	case 619:
+	case 661:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3170
+//line sql.y:3353
 		{
 			yyVAL.setExpr = &SetExpr{Name: yyDollar[1].colIdent, Expr: NewStrVal([]byte("off"))}
 		}
-	case 620:
+	case 662:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3174
+//line sql.y:3357
 		{
 			yyVAL.setExpr = &SetExpr{Name: yyDollar[1].colIdent, Expr: yyDollar[3].expr}
 		}
-	case 621:
+	case 663:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3178
+//line sql.y:3361
 		{
 			yyVAL.setExpr = &SetExpr{Name: NewColIdent(string(yyDollar[1].bytes)), Expr: yyDollar[2].expr}
 		}
-	case 623:
+	case 665:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3185
+//line sql.y:3368
 		{
 			yyVAL.bytes = []byte("charset")
 		}
-	case 625:
+	case 667:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3192
+//line sql.y:3375
 		{
 			yyVAL.expr = NewStrVal([]byte(yyDollar[1].colIdent.String()))
 		}
-	case 626:
+	case 668:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3196
+//line sql.y:3379
 		{
 			yyVAL.expr = NewStrVal(yyDollar[1].bytes)
 		}
-	case 627:
+	case 669:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3200
+//line sql.y:3383
 		{
 			yyVAL.expr = &Default{}
 		}
-	case 630:
+	case 672:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3209
+//line sql.y:3392
 		{
 			yyVAL.byt = 0
 		}
-	case 631:
+	case 673:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3211
+//line sql.y:3394
 		{
 			yyVAL.byt = 1
 		}
-	case 632:
+	case 674:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3214
+//line sql.y:3397
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 633:
+	case 675:
 		yyDollar = yyS[yypt-3 : yypt+1]
-//line sql.y:3216
+//line sql.y:3399
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 634:
+	case 676:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3219
+//line sql.y:3402
 		{
 			yyVAL.str = ""
 		}
-	case 635:
+	case 677:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3221
+//line sql.y:3404
 		{
 			yyVAL.str = IgnoreStr
 		}
-	case 636:
+	case 678:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3225
+//line sql.y:3408
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 637:
+	case 679:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3227
+//line sql.y:3410
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 638:
+	case 680:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3229
+//line sql.y:3412
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 639:
+	case 681:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3231
+//line sql.y:3414
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 640:
+	case 682:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3233
+//line sql.y:3416
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 641:
+	case 683:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3235
+//line sql.y:3418
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 642:
+	case 684:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3237
+//line sql.y:3420
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 643:
+	case 685:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3239
+//line sql.y:3422
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 644:
+	case 686:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3241
+//line sql.y:3424
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 645:
+	case 687:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3243
+//line sql.y:3426
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 646:
+	case 688:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3246
+//line sql.y:3429
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 647:
+	case 689:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3248
+//line sql.y:3431
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 648:
+	case 690:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3250
+//line sql.y:3433
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 649:
+	case 691:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3254
+//line sql.y:3437
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 650:
+	case 692:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3256
+//line sql.y:3439
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 651:
+	case 693:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3259
+//line sql.y:3442
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 652:
+	case 694:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3261
+//line sql.y:3444
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 653:
+	case 695:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3263
+//line sql.y:3446
 		{
 			yyVAL.empty = struct{}{}
 		}
-	case 654:
+	case 696:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3266
+//line sql.y:3449
 		{
 			yyVAL.colIdent = ColIdent{}
 		}
-	case 655:
+	case 697:
 		yyDollar = yyS[yypt-2 : yypt+1]
-//line sql.y:3268
+//line sql.y:3451
 		{
 			yyVAL.colIdent = yyDollar[2].colIdent
 		}
-	case 656:
+	case 698:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3272
+//line sql.y:3455
 		{
 			yyVAL.colIdent = NewColIdent(string(yyDollar[1].bytes))
 		}
-	case 657:
+	case 699:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3276
+//line sql.y:3459
 		{
 			yyVAL.colIdent = NewColIdent(string(yyDollar[1].bytes))
 		}
-	case 659:
+	case 701:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3283
+//line sql.y:3466
 		{
 			yyVAL.colIdent = NewColIdent(string(yyDollar[1].bytes))
 		}
-	case 660:
+	case 702:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3289
+//line sql.y:3472
 		{
 			yyVAL.tableIdent = NewTableIdent(string(yyDollar[1].bytes))
 		}
-	case 661:
+	case 703:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3293
+//line sql.y:3476
 		{
 			yyVAL.tableIdent = NewTableIdent(string(yyDollar[1].bytes))
 		}
-	case 663:
+	case 705:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3300
+//line sql.y:3483
 		{
 			yyVAL.tableIdent = NewTableIdent(string(yyDollar[1].bytes))
 		}
-	case 878:
+	case 933:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3540
+//line sql.y:3736
 		{
 			if incNesting(yylex) {
 				yylex.Error("max nesting level reached")
 				return 1
 			}
 		}
-	case 879:
+	case 934:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3549
+//line sql.y:3745
 		{
 			decNesting(yylex)
 		}
-	case 880:
+	case 935:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3554
+//line sql.y:3750
 		{
 			skipToEnd(yylex)
 		}
-	case 881:
+	case 936:
 		yyDollar = yyS[yypt-0 : yypt+1]
-//line sql.y:3559
+//line sql.y:3755
 		{
 			skipToEnd(yylex)
 		}
-	case 882:
+	case 937:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3563
+//line sql.y:3759
 		{
 			skipToEnd(yylex)
 		}
-	case 883:
+	case 938:
 		yyDollar = yyS[yypt-1 : yypt+1]
-//line sql.y:3567
+//line sql.y:3763
 		{
 			skipToEnd(yylex)
 		}
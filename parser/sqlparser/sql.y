@@ -132,9 +132,9 @@ func skipToEnd(yylex interface{}) {
 }
 
 %token LEX_ERROR
-%left <bytes> UNION
-%token <bytes> SELECT STREAM INSERT UPDATE DELETE FROM WHERE GROUP HAVING ORDER BY LIMIT OFFSET FOR WATERMARK DELAY COUNTING AFTER
-%token <bytes> ALL DISTINCT AS EXISTS ASC DESC INTO DUPLICATE KEY DEFAULT SET LOCK UNLOCK KEYS
+%left <bytes> UNION INTERSECT EXCEPT
+%token <bytes> SELECT STREAM INSERT UPDATE DELETE FROM WHERE GROUP HAVING ORDER BY LIMIT OFFSET FOR WATERMARK DELAY COUNTING AFTER ALLOWED LATENESS
+%token <bytes> ALL DISTINCT AS EXISTS ASC DESC NULLS FIRST LAST INTO DUPLICATE KEY DEFAULT SET LET LOCK UNLOCK KEYS
 %token <bytes> VALUES LAST_INSERT_ID
 %token <bytes> NEXT VALUE SHARE MODE
 %token <bytes> SQL_NO_CACHE SQL_CACHE
@@ -152,14 +152,16 @@ func skipToEnd(yylex interface{}) {
 %left <bytes> AND
 %right <bytes> NOT '!'
 %left <bytes> BETWEEN CASE WHEN THEN ELSE END
-%left <bytes> '=' '<' '>' LE GE NE NULL_SAFE_EQUAL IS LIKE REGEXP IN RIGHTARROW
+%left <bytes> '=' '<' '>' LE GE NE NULL_SAFE_EQUAL IS LIKE ILIKE REGEXP IN RIGHTARROW '~'
 %left <bytes> '|'
 %left <bytes> '&'
 %left <bytes> SHIFT_LEFT SHIFT_RIGHT
 %left <bytes> '+' '-'
 %left <bytes> '*' '/' DIV '%' MOD
 %left <bytes> '^'
-%right <bytes> '~' UNARY
+%left <bytes> AT
+%right <bytes> UNARY
+%left <bytes> '['
 %left <bytes> COLLATE
 %right <bytes> BINARY UNDERSCORE_BINARY UNDERSCORE_UTF8MB4
 %right <bytes> INTERVAL
@@ -174,7 +176,7 @@ func skipToEnd(yylex interface{}) {
 %token <bytes> CREATE ALTER DROP RENAME ANALYZE ADD FLUSH
 %token <bytes> SCHEMA TABLE DESCRIPTOR INDEX VIEW TO IGNORE IF UNIQUE PRIMARY COLUMN  SPATIAL FULLTEXT KEY_BLOCK_SIZE
 %token <bytes> ACTION CASCADE CONSTRAINT FOREIGN NO REFERENCES RESTRICT
-%token <bytes> SHOW DESCRIBE EXPLAIN DATE ESCAPE REPAIR OPTIMIZE TRUNCATE
+%token <bytes> SHOW DESCRIBE EXPLAIN DATE ESCAPE REPAIR OPTIMIZE TRUNCATE EXTRACT
 %token <bytes> MAXVALUE PARTITION REORGANIZE LESS THAN PROCEDURE TRIGGER
 %token <bytes> VINDEX VINDEXES
 %token <bytes> STATUS VARIABLES WARNINGS
@@ -185,7 +187,7 @@ func skipToEnd(yylex interface{}) {
 // Type Tokens
 %token <bytes> BIT TINYINT SMALLINT MEDIUMINT INT INTEGER BIGINT INTNUM
 %token <bytes> REAL DOUBLE FLOAT_TYPE DECIMAL NUMERIC
-%token <bytes> TIME TIMESTAMP DATETIME YEAR
+%token <bytes> TIME TIMESTAMP DATETIME YEAR ZONE
 %token <bytes> CHAR VARCHAR BOOL CHARACTER VARBINARY NCHAR
 %token <bytes> TEXT TINYTEXT MEDIUMTEXT LONGTEXT
 %token <bytes> BLOB TINYBLOB MEDIUMBLOB LONGBLOB JSON ENUM
@@ -206,6 +208,8 @@ func skipToEnd(yylex interface{}) {
 %token <bytes> UTC_DATE UTC_TIME UTC_TIMESTAMP
 %token <bytes> REPLACE
 %token <bytes> CONVERT CAST
+%token <bytes> ARRAY UNNEST
+%token <bytes> EXCLUDE
 %token <bytes> SUBSTR SUBSTRING
 %token <bytes> GROUP_CONCAT SEPARATOR
 %token <bytes> TIMESTAMPADD TIMESTAMPDIFF
@@ -224,7 +228,7 @@ func skipToEnd(yylex interface{}) {
 %type <statement> stream_statement insert_statement update_statement delete_statement set_statement
 %type <statement> create_statement alter_statement rename_statement drop_statement truncate_statement flush_statement
 %type <ddl> create_table_prefix rename_list
-%type <statement> analyze_statement show_statement use_statement other_statement
+%type <statement> analyze_statement show_statement use_statement explain_statement other_statement
 %type <statement> begin_statement commit_statement rollback_statement
 %type <bytes2> comment_opt comment_list
 %type <str> union_op insert_or_replace
@@ -232,6 +236,8 @@ func skipToEnd(yylex interface{}) {
 %type <expr> like_escape_opt
 %type <selectExprs> select_expression_list select_expression_list_opt
 %type <selectExpr> select_expression
+%type <exprs> star_exclude_opt star_exclude_list
+%type <selectExprs> star_replace_opt star_replace_list
 %type <expr> expression
 %type <tableExprs> from_opt table_references
 %type <tableExpr> table_reference table_factor join_table
@@ -240,7 +246,7 @@ func skipToEnd(yylex interface{}) {
 %type <tableValuedFunctionArgumentValue> table_valued_function_argument_value
 %type <joinCondition> join_condition join_condition_opt on_expression_opt
 %type <tableNames> table_name_list delete_table_list
-%type <str> inner_join outer_join straight_join natural_join
+%type <str> inner_join outer_join full_outer_join straight_join natural_join
 %type <tableName> table_name into_table_name delete_table_name
 %type <aliasedTableName> aliased_table_name
 %type <indexHints> index_hint_list
@@ -258,7 +264,7 @@ func skipToEnd(yylex interface{}) {
 %type <valTuple> row_tuple tuple_or_empty
 %type <expr> tuple_expression
 %type <subquery> subquery
-%type <colName> column_name
+%type <colName> column_name qualified_column_name
 %type <whens> when_expression_list
 %type <when> when_expression
 %type <expr> expression_opt else_expression_opt
@@ -266,7 +272,7 @@ func skipToEnd(yylex interface{}) {
 %type <expr> having_opt
 %type <orderBy> order_by_opt order_list
 %type <order> order
-%type <str> asc_desc_opt
+%type <str> asc_desc_opt nulls_order_opt
 %type <limit> limit_opt
 %type <str> lock_opt
 %type <triggers> trigger_opt
@@ -365,6 +371,7 @@ command:
 | begin_statement
 | commit_statement
 | rollback_statement
+| explain_statement
 | other_statement
 | flush_statement
 | /*empty*/
@@ -559,6 +566,10 @@ set_statement:
   {
     $$ = &Set{Comments: Comments($2), Exprs: $4}
   }
+| LET comment_opt set_list
+  {
+    $$ = &Set{Comments: Comments($2), Exprs: $3}
+  }
 
 transaction_chars:
   transaction_char
@@ -624,6 +635,10 @@ create_statement:
     $1.OptLike = $2
     $$ = $1
   }
+| CREATE TABLE STRING AS select_statement
+  {
+    $$ = &CreateTableAs{Path: string($3), Select: $5}
+  }
 | CREATE constraint_opt INDEX ID using_opt ON table_name ddl_skip_to_end
   {
     // Change this to an alter statement
@@ -1505,6 +1520,10 @@ analyze_statement:
   {
     $$ = &DDL{Action: AlterStr, Table: $3}
   }
+| ANALYZE STRING
+  {
+    $$ = &AnalyzeFile{Path: string($2)}
+  }
 
 show_statement:
   SHOW BINARY ID ddl_skip_to_end /* SHOW BINARY LOGS */
@@ -1757,6 +1776,16 @@ rollback_statement:
     $$ = &Rollback{}
   }
 
+explain_statement:
+  EXPLAIN select_statement
+  {
+    $$ = &Explain{Statement: $2}
+  }
+| EXPLAIN ANALYZE select_statement
+  {
+    $$ = &Explain{Analyze: true, Statement: $3}
+  }
+
 other_statement:
   DESC skip_to_end
   {
@@ -1766,10 +1795,6 @@ other_statement:
   {
     $$ = &OtherRead{}
   }
-| EXPLAIN skip_to_end
-  {
-    $$ = &OtherRead{}
-  }
 | REPAIR skip_to_end
   {
     $$ = &OtherAdmin{}
@@ -1824,6 +1849,14 @@ union_op:
   {
     $$ = UnionDistinctStr
   }
+| INTERSECT
+  {
+    $$ = IntersectStr
+  }
+| EXCEPT
+  {
+    $$ = ExceptStr
+  }
 
 cache_opt:
 {
@@ -1876,21 +1909,55 @@ select_expression_list:
   }
 
 select_expression:
-  '*'
+  '*' star_exclude_opt star_replace_opt
   {
-    $$ = &StarExpr{}
+    $$ = &StarExpr{Exclude: $2, Replace: $3}
   }
 | expression as_ci_opt
   {
     $$ = &AliasedExpr{Expr: $1, As: $2}
   }
-| table_id '.' '*'
+| table_id '.' '*' star_exclude_opt star_replace_opt
+  {
+    $$ = &StarExpr{TableName: TableName{Name: $1}, Exclude: $4, Replace: $5}
+  }
+
+star_exclude_opt:
   {
-    $$ = &StarExpr{TableName: TableName{Name: $1}}
+    $$ = nil
   }
-| table_id '.' reserved_table_id '.' '*'
+| EXCLUDE openb star_exclude_list closeb
   {
-    $$ = &StarExpr{TableName: TableName{Qualifier: $1, Name: $3}}
+    $$ = $3
+  }
+
+star_exclude_list:
+  column_name
+  {
+    $$ = Exprs{$1}
+  }
+| star_exclude_list ',' column_name
+  {
+    $$ = append($$, $3)
+  }
+
+star_replace_opt:
+  {
+    $$ = nil
+  }
+| REPLACE openb star_replace_list closeb
+  {
+    $$ = $3
+  }
+
+star_replace_list:
+  expression AS col_alias
+  {
+    $$ = SelectExprs{&AliasedExpr{Expr: $1, As: $3}}
+  }
+| star_replace_list ',' expression AS col_alias
+  {
+    $$ = append($$, &AliasedExpr{Expr: $3, As: $5})
   }
 
 as_ci_opt:
@@ -1959,6 +2026,15 @@ table_factor:
   {
     $$ = &TableValuedFunction{Name: NewColIdent(string($1)), Args: $3, As: $6}
   }
+/* SESSION is a keyword, but it's also the name of the session window table valued function. */
+| SESSION openb table_valued_function_arguments_opt closeb as_opt table_id
+  {
+    $$ = &TableValuedFunction{Name: NewColIdent(string($1)), Args: $3, As: $6}
+  }
+| UNNEST openb expression closeb as_opt table_id
+  {
+    $$ = &UnnestExpr{Expr: $3, As: $6}
+  }
 
 aliased_table_name:
 table_name as_opt_id index_hint_list
@@ -1994,6 +2070,10 @@ table_valued_function_argument:
   {
     $$ = &TableValuedFunctionArgument{Name: $1, Value: $3}
   }
+| table_valued_function_argument_value
+  {
+    $$ = &TableValuedFunctionArgument{Value: $1}
+  }
 
 table_valued_function_argument_value:
   expression
@@ -2049,6 +2129,10 @@ join_table:
   {
     $$ = &JoinTableExpr{LeftExpr: $1, Join: $2, RightExpr: $3, Condition: $4}
   }
+| table_reference full_outer_join table_reference join_condition
+  {
+    $$ = &JoinTableExpr{LeftExpr: $1, Join: $2, RightExpr: $3, Condition: $4}
+  }
 | table_reference natural_join table_factor
   {
     $$ = &JoinTableExpr{LeftExpr: $1, Join: $2, RightExpr: $3}
@@ -2135,6 +2219,16 @@ outer_join:
     $$ = RightJoinStr
   }
 
+full_outer_join:
+  FULL JOIN
+  {
+    $$ = FullOuterJoinStr
+  }
+| FULL OUTER JOIN
+  {
+    $$ = FullOuterJoinStr
+  }
+
 natural_join:
  NATURAL JOIN
   {
@@ -2272,6 +2366,18 @@ condition:
   {
     $$ = &ComparisonExpr{Left: $1, Operator: NotLikeStr, Right: $4, Escape: $5}
   }
+| value_expression ILIKE value_expression like_escape_opt
+  {
+    $$ = &ComparisonExpr{Left: $1, Operator: ILikeStr, Right: $3, Escape: $4}
+  }
+| value_expression NOT ILIKE value_expression like_escape_opt
+  {
+    $$ = &ComparisonExpr{Left: $1, Operator: NotILikeStr, Right: $4, Escape: $5}
+  }
+| value_expression '~' value_expression
+  {
+    $$ = &ComparisonExpr{Left: $1, Operator: RegexpStr, Right: $3}
+  }
 | value_expression REGEXP value_expression
   {
     $$ = &ComparisonExpr{Left: $1, Operator: RegexpStr, Right: $3}
@@ -2503,7 +2609,7 @@ value_expression:
       $$ = &UnaryExpr{Operator: UMinusStr, Expr: $2}
     }
   }
-| '~'  value_expression
+| '~'  value_expression %prec UNARY
   {
     $$ = &UnaryExpr{Operator: TildaStr, Expr: $2}
   }
@@ -2511,6 +2617,22 @@ value_expression:
   {
     $$ = &UnaryExpr{Operator: BangStr, Expr: $2}
   }
+| value_expression '[' value_expression ']'
+  {
+    $$ = &IndexExpr{Expr: $1, Index: $3}
+  }
+| value_expression AT TIME ZONE value_expression %prec AT
+  {
+    $$ = &FuncExpr{Name: NewColIdent("at_time_zone"), Exprs: SelectExprs{&AliasedExpr{Expr: $1}, &AliasedExpr{Expr: $5}}}
+  }
+| ARRAY '[' ']'
+  {
+    $$ = &ArrayExpr{}
+  }
+| ARRAY '[' expression_list ']'
+  {
+    $$ = &ArrayExpr{Exprs: $3}
+  }
 | INTERVAL value_expression sql_id
   {
     // This rule prevents the usage of INTERVAL
@@ -2599,6 +2721,10 @@ function_call_keyword:
   {
     $$ = &ValuesFuncExpr{Name: $3}
   }
+| EXTRACT openb sql_id FROM expression closeb
+  {
+    $$ = &FuncExpr{Name: NewColIdent("date_part"), Exprs: SelectExprs{&AliasedExpr{Expr: NewStrVal([]byte($3.Lowered()))}, &AliasedExpr{Expr: $5}}}
+  }
 
 /*
   Function calls using non reserved keywords but with special syntax forms.
@@ -2807,6 +2933,42 @@ convert_type:
   {
     $$ = &ConvertType{Type: string($1)}
   }
+| INT
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| INTEGER
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| FLOAT_TYPE
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| DOUBLE
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| REAL
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| BOOL
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| BOOLEAN
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| TEXT
+  {
+    $$ = &ConvertType{Type: string($1)}
+  }
+| VARCHAR length_opt
+  {
+    $$ = &ConvertType{Type: string($1), Length: $2}
+  }
 
 expression_opt:
   {
@@ -2856,13 +3018,17 @@ column_name:
   {
     $$ = &ColName{Name: $1}
   }
-| table_id '.' reserved_sql_id
+| qualified_column_name
+
+qualified_column_name:
+  table_id '.' reserved_sql_id
   {
     $$ = &ColName{Qualifier: TableName{Name: $1}, Name: $3}
   }
-| table_id '.' reserved_table_id '.' reserved_sql_id
+| qualified_column_name '.' reserved_sql_id
   {
-    $$ = &ColName{Qualifier: TableName{Qualifier: $1, Name: $3}, Name: $5}
+    $$ = $1
+    $$.Path = append($$.Path, $3)
   }
 
 value:
@@ -2956,9 +3122,9 @@ order_list:
   }
 
 order:
-  expression asc_desc_opt
+  expression asc_desc_opt nulls_order_opt
   {
-    $$ = &Order{Expr: $1, Direction: $2}
+    $$ = &Order{Expr: $1, Direction: $2, Nulls: $3}
   }
 
 asc_desc_opt:
@@ -2974,6 +3140,19 @@ asc_desc_opt:
     $$ = DescScr
   }
 
+nulls_order_opt:
+  {
+    $$ = ""
+  }
+| NULLS FIRST
+  {
+    $$ = NullsFirstStr
+  }
+| NULLS LAST
+  {
+    $$ = NullsLastStr
+  }
+
 limit_opt:
   {
     $$ = nil
@@ -3028,6 +3207,10 @@ trigger:
 	{
 		$$ = &WatermarkTrigger{}
 	}
+| ON WATERMARK ALLOWED LATENESS expression
+	{
+		$$ = &WatermarkTrigger{AllowedLateness: $5}
+	}
 | AFTER DELAY expression
 	{
 		$$ = &DelayTrigger{Delay: $3}
@@ -3313,8 +3496,10 @@ reserved_table_id:
 reserved_keyword:
   ADD
 | AND
+| ARRAY
 | AS
 | ASC
+| AT
 | AUTO_INCREMENT
 | BETWEEN
 | BINARY
@@ -3343,6 +3528,7 @@ reserved_keyword:
 | ESCAPE
 | EXISTS
 | EXPLAIN
+| EXTRACT
 | FALSE
 | FOR
 | FORCE
@@ -3351,6 +3537,7 @@ reserved_keyword:
 | HAVING
 | IF
 | IGNORE
+| ILIKE
 | IN
 | INDEX
 | INNER
@@ -3398,6 +3585,7 @@ reserved_keyword:
 | UNION
 | UNIQUE
 | UNLOCK
+| UNNEST
 | UPDATE
 | USE
 | USING
@@ -3420,6 +3608,7 @@ non_reserved_keyword:
 	AFTER
 | AGAINST
 | ACTION
+| ALLOWED
 | BEGIN
 | BIGINT
 | BIT
@@ -3444,7 +3633,9 @@ non_reserved_keyword:
 | DUPLICATE
 | ENGINES
 | ENUM
+| EXCLUDE
 | EXPANSION
+| FIRST
 | FLOAT_TYPE
 | FIELDS
 | FLUSH
@@ -3460,8 +3651,11 @@ non_reserved_keyword:
 | KEY_BLOCK_SIZE
 | KEYS
 | LANGUAGE
+| LAST
 | LAST_INSERT_ID
+| LATENESS
 | LESS
+| LET
 | LEVEL
 | LINESTRING
 | LONGBLOB
@@ -3476,6 +3670,7 @@ non_reserved_keyword:
 | NAMES
 | NCHAR
 | NO
+| NULLS
 | NUMERIC
 | OFFSET
 | ONLY
@@ -3534,6 +3729,7 @@ non_reserved_keyword:
 | WRITE
 | YEAR
 | ZEROFILL
+| ZONE
 
 openb:
   '('
@@ -96,12 +96,15 @@ var keywords = map[string]int{
 	"after":               AFTER,
 	"against":             AGAINST,
 	"all":                 ALL,
+	"allowed":             ALLOWED,
 	"alter":               ALTER,
 	"analyze":             ANALYZE,
 	"and":                 AND,
+	"array":               ARRAY,
 	"as":                  AS,
 	"asc":                 ASC,
 	"asensitive":          UNUSED,
+	"at":                  AT,
 	"auto_increment":      AUTO_INCREMENT,
 	"before":              UNUSED,
 	"begin":               BEGIN,
@@ -179,14 +182,18 @@ var keywords = map[string]int{
 	"engines":             ENGINES,
 	"enum":                ENUM,
 	"escape":              ESCAPE,
+	"except":              EXCEPT,
+	"exclude":             EXCLUDE,
 	"escaped":             UNUSED,
 	"exists":              EXISTS,
 	"exit":                UNUSED,
 	"explain":             EXPLAIN,
 	"expansion":           EXPANSION,
+	"extract":             EXTRACT,
 	"false":               FALSE,
 	"fetch":               UNUSED,
 	"fields":              FIELDS,
+	"first":               FIRST,
 	"float":               FLOAT_TYPE,
 	"float4":              UNUSED,
 	"float8":              UNUSED,
@@ -212,6 +219,7 @@ var keywords = map[string]int{
 	"hour_second":         UNUSED,
 	"if":                  IF,
 	"ignore":              IGNORE,
+	"ilike":               ILIKE,
 	"in":                  IN,
 	"index":               INDEX,
 	"infile":              UNUSED,
@@ -226,6 +234,7 @@ var keywords = map[string]int{
 	"int4":                UNUSED,
 	"int8":                UNUSED,
 	"integer":             INTEGER,
+	"intersect":           INTERSECT,
 	"interval":            INTERVAL,
 	"into":                INTO,
 	"io_after_gtids":      UNUSED,
@@ -239,11 +248,14 @@ var keywords = map[string]int{
 	"key_block_size":      KEY_BLOCK_SIZE,
 	"kill":                UNUSED,
 	"language":            LANGUAGE,
+	"last":                LAST,
 	"last_insert_id":      LAST_INSERT_ID,
+	"lateness":            LATENESS,
 	"leading":             UNUSED,
 	"leave":               UNUSED,
 	"left":                LEFT,
 	"less":                LESS,
+	"let":                 LET,
 	"level":               LEVEL,
 	"like":                LIKE,
 	"limit":               LIMIT,
@@ -282,6 +294,7 @@ var keywords = map[string]int{
 	"not":                 NOT,
 	"no_write_to_binlog":  UNUSED,
 	"null":                NULL,
+	"nulls":               NULLS,
 	"numeric":             NUMERIC,
 	"off":                 OFF,
 	"offset":              OFFSET,
@@ -382,6 +395,7 @@ var keywords = map[string]int{
 	"union":               UNION,
 	"unique":              UNIQUE,
 	"unlock":              UNLOCK,
+	"unnest":              UNNEST,
 	"unsigned":            UNSIGNED,
 	"update":              UPDATE,
 	"usage":               UNUSED,
@@ -417,6 +431,7 @@ var keywords = map[string]int{
 	"year":                YEAR,
 	"year_month":          UNUSED,
 	"zerofill":            ZEROFILL,
+	"zone":                ZONE,
 }
 
 // keywordStrings contains the reverse mapping of token to keyword strings
@@ -544,6 +559,8 @@ func (tkn *Tokenizer) Scan() (int, []byte) {
 				return RIGHTARROW, nil
 			}
 			return int(ch), nil
+		case '[', ']':
+			return int(ch), nil
 		case '&':
 			if tkn.lastChar == '&' {
 				tkn.next()
@@ -559,7 +576,19 @@ func (tkn *Tokenizer) Scan() (int, []byte) {
 		case '?':
 			tkn.posVarIndex++
 			buf := new(bytes2.Buffer)
-			fmt.Fprintf(buf, ":v%d", tkn.posVarIndex)
+			fmt.Fprintf(buf, ":%d", tkn.posVarIndex)
+			return VALUE_ARG, buf.Bytes()
+		case '$':
+			// $1, $2, ... are positional arguments, the same as the consecutive ? placeholders.
+			if !isDigit(tkn.lastChar) {
+				return LEX_ERROR, []byte("$")
+			}
+			buf := new(bytes2.Buffer)
+			buf.WriteByte(':')
+			for isDigit(tkn.lastChar) {
+				buf.WriteByte(byte(tkn.lastChar))
+				tkn.next()
+			}
 			return VALUE_ARG, buf.Bytes()
 		case '.':
 			if isDigit(tkn.lastChar) {
@@ -745,7 +774,7 @@ func (tkn *Tokenizer) scanBindVar() (int, []byte) {
 		buffer.WriteByte(byte(tkn.lastChar))
 		tkn.next()
 	}
-	if !isLetter(tkn.lastChar) {
+	if !isLetter(tkn.lastChar) && !isDigit(tkn.lastChar) {
 		return LEX_ERROR, buffer.Bytes()
 	}
 	for isLetter(tkn.lastChar) || isDigit(tkn.lastChar) || tkn.lastChar == '.' {
@@ -0,0 +1,66 @@
+package physical
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+// Analyze collects execution statistics of its source node for EXPLAIN ANALYZE.
+// It's transparent otherwise, the visualization is the one of its source, annotated with the statistics.
+type Analyze struct {
+	Stats  *execution.NodeStats
+	Source Node
+}
+
+func NewAnalyze(stats *execution.NodeStats, child Node) *Analyze {
+	return &Analyze{Stats: stats, Source: child}
+}
+
+// AnalyzeAll wraps each node of the plan in an Analyze node.
+func AnalyzeAll(ctx context.Context, plan Node) Node {
+	return plan.Transform(ctx, &Transformers{
+		NodeT: func(node Node) Node {
+			if _, ok := node.(*Analyze); ok {
+				return node
+			}
+			return NewAnalyze(execution.NewNodeStats(), node)
+		},
+	})
+}
+
+func (node *Analyze) Transform(ctx context.Context, transformers *Transformers) Node {
+	var transformed Node = &Analyze{
+		Stats:  node.Stats,
+		Source: node.Source.Transform(ctx, transformers),
+	}
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *Analyze) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materialized, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize Source node")
+	}
+	return execution.NewAnalyze(node.Stats, materialized), nil
+}
+
+func (node *Analyze) Metadata() *metadata.NodeMetadata {
+	return node.Source.Metadata()
+}
+
+func (node *Analyze) Visualize() *graph.Node {
+	n := node.Source.Visualize()
+	n.AddField("records", fmt.Sprint(node.Stats.Records()))
+	n.AddField("retractions", fmt.Sprint(node.Stats.Retractions()))
+	n.AddField("time", node.Stats.Duration().String())
+	return n
+}
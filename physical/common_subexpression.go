@@ -0,0 +1,135 @@
+package physical
+
+import (
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/execution/functions"
+	"github.com/cube2222/octosql/graph"
+)
+
+// withCommonSubexpressions returns a materialization context in which the deterministic function calls
+// which occur more than once in the given expressions and formulas get evaluated once per record.
+// Subqueries are skipped, as they're evaluated in their own context.
+func (matCtx *MaterializationContext) withCommonSubexpressions(expressions []Expression, formulas []Formula) *MaterializationContext {
+	counter := &functionCallCounter{
+		counts: make(map[string]int),
+	}
+	for i := range expressions {
+		counter.countExpression(expressions[i])
+	}
+	for i := range formulas {
+		counter.countFormula(formulas[i])
+	}
+
+	commonSubexpressions := make(map[string]*execution.CommonSubexpression)
+	for key, count := range counter.counts {
+		if count > 1 {
+			commonSubexpressions[key] = execution.NewCommonSubexpression()
+		}
+	}
+	if len(commonSubexpressions) == 0 {
+		return matCtx.withoutCommonSubexpressions()
+	}
+
+	out := *matCtx
+	out.commonSubexpressions = commonSubexpressions
+	return &out
+}
+
+// withoutCommonSubexpressions returns a materialization context with no function calls being shared.
+func (matCtx *MaterializationContext) withoutCommonSubexpressions() *MaterializationContext {
+	if matCtx.commonSubexpressions == nil {
+		return matCtx
+	}
+
+	out := *matCtx
+	out.commonSubexpressions = nil
+	return &out
+}
+
+// functionCallCounter counts the occurrences of deterministic function calls, keyed by their visualization.
+type functionCallCounter struct {
+	counts map[string]int
+}
+
+// countExpression counts the function calls in the expression and returns true if it's deterministic.
+func (counter *functionCallCounter) countExpression(expr Expression) bool {
+	switch expr := expr.(type) {
+	case *FunctionExpression:
+		deterministic := true
+		for i := range expr.Arguments {
+			if !counter.countExpression(expr.Arguments[i]) {
+				deterministic = false
+			}
+		}
+		if descriptor, ok := functions.Lookup(expr.Name); !ok || descriptor.Nondeterministic {
+			deterministic = false
+		}
+		if deterministic {
+			counter.counts[graph.ShowTree(expr.Visualize())]++
+		}
+		return deterministic
+
+	case *Tuple:
+		deterministic := true
+		for i := range expr.Expressions {
+			if !counter.countExpression(expr.Expressions[i]) {
+				deterministic = false
+			}
+		}
+		return deterministic
+
+	case *AliasedExpression:
+		return counter.countExpression(expr.Expr)
+
+	case *StarExpression:
+		deterministic := true
+		for i := range expr.Replace {
+			if !counter.countExpression(expr.Replace[i]) {
+				deterministic = false
+			}
+		}
+		return deterministic
+
+	case *LogicExpression:
+		return counter.countFormula(expr.Formula)
+
+	case *Variable, *RecordExpression:
+		return true
+
+	default:
+		// Subqueries may depend on nondeterministic functions or sources.
+		return false
+	}
+}
+
+// countFormula counts the function calls in the formula and returns true if it's deterministic.
+func (counter *functionCallCounter) countFormula(formula Formula) bool {
+	switch formula := formula.(type) {
+	case *And:
+		left := counter.countFormula(formula.Left)
+		right := counter.countFormula(formula.Right)
+		return left && right
+
+	case *Or:
+		left := counter.countFormula(formula.Left)
+		right := counter.countFormula(formula.Right)
+		return left && right
+
+	case *Not:
+		return counter.countFormula(formula.Child)
+
+	case *Predicate:
+		left := counter.countExpression(formula.Left)
+		right := counter.countExpression(formula.Right)
+		return left && right
+
+	case *IsNull:
+		return counter.countExpression(formula.Expression)
+
+	case *Constant:
+		return true
+
+	default:
+		return false
+	}
+}
@@ -3,8 +3,10 @@ package physical
 import (
 	"context"
 	"fmt"
+	"sort"
 
 	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/config"
 	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/graph"
 	"github.com/cube2222/octosql/physical/metadata"
@@ -43,7 +45,8 @@ func (repo *DataSourceRepository) Get(dataSourceName, alias string) ([]Node, err
 		for k := range repo.factories {
 			dss = append(dss, k)
 		}
-		return nil, errors.Errorf("no such datasource or common table expression: %s, available datasources and CTEs: %+v", dataSourceName, dss)
+		sort.Strings(dss)
+		return nil, errors.Errorf("no such datasource or common table expression: %s%s, available datasources and CTEs: %+v", dataSourceName, octosql.DidYouMean(dataSourceName, dss), dss)
 	}
 
 	return ds(dataSourceName, alias), nil
@@ -88,6 +91,10 @@ type DataSourceBuilder struct {
 
 	// This field will be used to decide on join strategies or if the source is a stream.
 	Cardinality metadata.Cardinality
+
+	// ConfigOptional is set for data sources which don't have to be present in the configuration,
+	// like the ones registered directly in the repository by applications using OctoSQL as a library.
+	ConfigOptional bool
 }
 
 func NewDataSourceBuilderFactory(materializer DataSourceMaterializerFunc, primaryKeys []octosql.VariableName, availableFilters map[FieldType]map[Relation]struct{}, cardinality metadata.Cardinality, partitions int) DataSourceBuilderFactory {
@@ -109,6 +116,19 @@ func NewDataSourceBuilderFactory(materializer DataSourceMaterializerFunc, primar
 	}
 }
 
+// WithOptionalConfig makes the data sources created by the factory work without an entry in the configuration.
+func WithOptionalConfig(factory DataSourceBuilderFactory) DataSourceBuilderFactory {
+	return func(name, alias string) []Node {
+		nodes := factory(name, alias)
+		for i := range nodes {
+			if dsb, ok := nodes[i].(*DataSourceBuilder); ok {
+				dsb.ConfigOptional = true
+			}
+		}
+		return nodes
+	}
+}
+
 func (dsb *DataSourceBuilder) Transform(ctx context.Context, transformers *Transformers) Node {
 	var transformed Node = &DataSourceBuilder{
 		Materializer:     dsb.Materializer,
@@ -117,6 +137,8 @@ func (dsb *DataSourceBuilder) Transform(ctx context.Context, transformers *Trans
 		Filter:           dsb.Filter.Transform(ctx, transformers),
 		Name:             dsb.Name,
 		Alias:            dsb.Alias,
+		Cardinality:      dsb.Cardinality,
+		ConfigOptional:   dsb.ConfigOptional,
 	}
 	if transformers.NodeT != nil {
 		transformed = transformers.NodeT(transformed)
@@ -126,7 +148,9 @@ func (dsb *DataSourceBuilder) Transform(ctx context.Context, transformers *Trans
 
 func (dsb *DataSourceBuilder) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
 	dbConfig, err := matCtx.Config.GetDataSourceConfig(dsb.Name)
-	if err != nil {
+	if errors.Cause(err) == config.ErrNotFound && dsb.ConfigOptional {
+		dbConfig = map[string]interface{}{}
+	} else if err != nil {
 		return nil, errors.Wrapf(err, "couldn't get config for database %v", dsb.Name)
 	}
 
@@ -0,0 +1,45 @@
+package physical
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+func TestDataSourceBuilder_Materialize(t *testing.T) {
+	materializer := func(ctx context.Context, matCtx *MaterializationContext, dbConfig map[string]interface{}, filter Formula, alias string, partition int) (execution.Node, error) {
+		return execution.NewDummyNode(nil), nil
+	}
+	factory := NewDataSourceBuilderFactory(materializer, nil, nil, metadata.BoundedFitsInLocalStorage, 1)
+
+	tests := []struct {
+		name    string
+		factory DataSourceBuilderFactory
+		wantErr bool
+	}{
+		{
+			name:    "configured source without configuration",
+			factory: factory,
+			wantErr: true,
+		},
+		{
+			name:    "source with optional configuration",
+			factory: WithOptionalConfig(factory),
+			wantErr: false,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			// Transformations, like the optimizer's, have to keep the configuration optional.
+			node := tt.factory("t", "t")[0].Transform(context.Background(), &Transformers{})
+
+			_, err := node.Materialize(context.Background(), &MaterializationContext{Config: &config.Config{}})
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Materialize() error = %v, wantErr %v", err, tt.wantErr)
+			}
+		})
+	}
+}
@@ -30,7 +30,7 @@ func (node *Filter) Transform(ctx context.Context, transformers *Transformers) N
 }
 
 func (node *Filter) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
-	materializedFormula, err := node.Formula.Materialize(ctx, matCtx)
+	materializedFormula, err := node.Formula.Materialize(ctx, matCtx.withCommonSubexpressions(nil, []Formula{node.Formula}))
 	if err != nil {
 		return nil, errors.Wrap(err, "couldn't materialize formula")
 	}
@@ -4,6 +4,7 @@ import (
 	"context"
 	"fmt"
 
+	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/execution"
 	"github.com/cube2222/octosql/execution/functions"
 	"github.com/cube2222/octosql/graph"
@@ -46,9 +47,14 @@ func (fe *FunctionExpression) Transform(ctx context.Context, transformers *Trans
 }
 
 func (fe *FunctionExpression) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Expression, error) {
-	function, ok := functions.FunctionTable[fe.Name]
+	function, ok := functions.Lookup(fe.Name)
 	if !ok {
-		return nil, errors.Errorf("No function %v found", fe.Name)
+		return nil, errors.Errorf("No function %v found%s", fe.Name, octosql.DidYouMean(fe.Name, functions.Names()))
+	}
+
+	shared, isShared := matCtx.commonSubexpressions[graph.ShowTree(fe.Visualize())]
+	if isShared && shared.IsSet() {
+		return shared, nil
 	}
 
 	materialized := make([]execution.Expression, 0)
@@ -61,6 +67,10 @@ func (fe *FunctionExpression) Materialize(ctx context.Context, matCtx *Materiali
 		materialized = append(materialized, materializedArg)
 	}
 
+	if isShared {
+		shared.SetExpression(execution.NewFunctionExpression(function, materialized))
+		return shared, nil
+	}
 	return execution.NewFunctionExpression(function, materialized), nil
 }
 
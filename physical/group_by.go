@@ -17,17 +17,22 @@ import (
 type Aggregate string
 
 const (
-	Avg           Aggregate = "avg"
-	AvgDistinct   Aggregate = "avg_distinct"
-	Count         Aggregate = "count"
-	CountDistinct Aggregate = "count_distinct"
-	First         Aggregate = "first"
-	Key           Aggregate = "key"
-	Last          Aggregate = "last"
-	Max           Aggregate = "max"
-	Min           Aggregate = "min"
-	Sum           Aggregate = "sum"
-	SumDistinct   Aggregate = "sum_distinct"
+	ApproxCountDistinct Aggregate = "approx_count_distinct"
+	ApproxPercentile    Aggregate = "approx_percentile"
+	ArrayAgg            Aggregate = "array_agg"
+	Avg                 Aggregate = "avg"
+	AvgDistinct         Aggregate = "avg_distinct"
+	Count               Aggregate = "count"
+	CountDistinct       Aggregate = "count_distinct"
+	First               Aggregate = "first"
+	Key                 Aggregate = "key"
+	Last                Aggregate = "last"
+	Max                 Aggregate = "max"
+	Min                 Aggregate = "min"
+	Stddev              Aggregate = "stddev"
+	Sum                 Aggregate = "sum"
+	SumDistinct         Aggregate = "sum_distinct"
+	Variance            Aggregate = "variance"
 )
 
 func NewAggregate(aggregate string) Aggregate {
@@ -106,14 +111,23 @@ func (c *DelayTrigger) Visualize() *graph.Node {
 }
 
 type WatermarkTrigger struct {
+	AllowedLateness Expression
 }
 
 func NewWatermarkTrigger() *WatermarkTrigger {
 	return &WatermarkTrigger{}
 }
 
+func (c *WatermarkTrigger) WithAllowedLateness(allowedLateness Expression) *WatermarkTrigger {
+	return &WatermarkTrigger{AllowedLateness: allowedLateness}
+}
+
 func (c *WatermarkTrigger) Transform(ctx context.Context, transformers *Transformers) Trigger {
-	var transformed Trigger = &WatermarkTrigger{}
+	transformedTrigger := &WatermarkTrigger{}
+	if c.AllowedLateness != nil {
+		transformedTrigger.AllowedLateness = c.AllowedLateness.Transform(ctx, transformers)
+	}
+	var transformed Trigger = transformedTrigger
 	if transformers.TriggerT != nil {
 		transformed = transformers.TriggerT(transformed)
 	}
@@ -121,11 +135,21 @@ func (c *WatermarkTrigger) Transform(ctx context.Context, transformers *Transfor
 }
 
 func (c *WatermarkTrigger) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.TriggerPrototype, error) {
-	return execution.NewWatermarkTrigger(), nil
+	if c.AllowedLateness == nil {
+		return execution.NewWatermarkTrigger(), nil
+	}
+	allowedLatenessExpr, err := c.AllowedLateness.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize allowed lateness expression")
+	}
+	return execution.NewWatermarkTrigger().WithAllowedLateness(allowedLatenessExpr), nil
 }
 
 func (c *WatermarkTrigger) Visualize() *graph.Node {
 	n := graph.NewNode("Watermark Trigger")
+	if c.AllowedLateness != nil {
+		n.AddChild("allowed_lateness", c.AllowedLateness.Visualize())
+	}
 	return n
 }
 
@@ -0,0 +1,149 @@
+package physical
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+	"github.com/pkg/errors"
+)
+
+// IntervalJoin is an inner join of two streams, where the joined record time minus the source record time
+// has to be between the lower and upper bound. A nil bound is zero.
+type IntervalJoin struct {
+	Source          Node
+	Joined          Node
+	SourceKey       []Expression
+	JoinedKey       []Expression
+	SourceTimeField octosql.VariableName
+	JoinedTimeField octosql.VariableName
+	LowerBound      Expression
+	UpperBound      Expression
+	EventTimeField  octosql.VariableName
+}
+
+func NewIntervalJoin(source, joined Node, sourceKey, joinedKey []Expression, sourceTimeField, joinedTimeField octosql.VariableName, lowerBound, upperBound Expression, eventTimeField octosql.VariableName) *IntervalJoin {
+	return &IntervalJoin{
+		Source:          source,
+		Joined:          joined,
+		SourceKey:       sourceKey,
+		JoinedKey:       joinedKey,
+		SourceTimeField: sourceTimeField,
+		JoinedTimeField: joinedTimeField,
+		LowerBound:      lowerBound,
+		UpperBound:      upperBound,
+		EventTimeField:  eventTimeField,
+	}
+}
+
+func (node *IntervalJoin) Transform(ctx context.Context, transformers *Transformers) Node {
+	var lowerBound, upperBound Expression
+	if node.LowerBound != nil {
+		lowerBound = node.LowerBound.Transform(ctx, transformers)
+	}
+	if node.UpperBound != nil {
+		upperBound = node.UpperBound.Transform(ctx, transformers)
+	}
+
+	var transformed Node = &IntervalJoin{
+		Source:          node.Source.Transform(ctx, transformers),
+		Joined:          node.Joined.Transform(ctx, transformers),
+		SourceKey:       node.SourceKey,
+		JoinedKey:       node.JoinedKey,
+		SourceTimeField: node.SourceTimeField,
+		JoinedTimeField: node.JoinedTimeField,
+		LowerBound:      lowerBound,
+		UpperBound:      upperBound,
+		EventTimeField:  node.EventTimeField,
+	}
+
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *IntervalJoin) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materializedSource, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize source node")
+	}
+
+	materializedJoined, err := node.Joined.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize joined node")
+	}
+
+	materializedSourceKey := make([]execution.Expression, len(node.SourceKey))
+	materializedJoinedKey := make([]execution.Expression, len(node.JoinedKey))
+
+	for i := range node.SourceKey {
+		materializedSourceKey[i], err = node.SourceKey[i].Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize source key expression with index %v", i)
+		}
+
+		materializedJoinedKey[i], err = node.JoinedKey[i].Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize joined key expression with index %v", i)
+		}
+	}
+
+	var materializedLowerBound, materializedUpperBound execution.Expression
+	if node.LowerBound != nil {
+		materializedLowerBound, err = node.LowerBound.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't materialize lower bound expression")
+		}
+	}
+	if node.UpperBound != nil {
+		materializedUpperBound, err = node.UpperBound.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't materialize upper bound expression")
+		}
+	}
+
+	return execution.NewIntervalJoin(materializedSource, materializedJoined, materializedSourceKey, materializedJoinedKey, node.SourceTimeField, node.JoinedTimeField, materializedLowerBound, materializedUpperBound, matCtx.Storage, node.EventTimeField), nil
+}
+
+func (node *IntervalJoin) Metadata() *metadata.NodeMetadata {
+	sourceMetadata := node.Source.Metadata()
+	joinedMetadata := node.Joined.Metadata()
+	cardinality := metadata.CombineCardinalities(sourceMetadata.Cardinality(), joinedMetadata.Cardinality())
+
+	namespace := sourceMetadata.Namespace()
+	namespace.MergeWith(joinedMetadata.Namespace())
+
+	return metadata.NewNodeMetadata(cardinality, node.EventTimeField, namespace)
+}
+
+func (node *IntervalJoin) Visualize() *graph.Node {
+	n := graph.NewNode("Interval Join")
+	n.AddChild("source", node.Source.Visualize())
+	n.AddChild("joined", node.Joined.Visualize())
+
+	for i, expr := range node.SourceKey {
+		n.AddChild(fmt.Sprintf("source_key_%d", i), expr.Visualize())
+	}
+
+	for i, expr := range node.JoinedKey {
+		n.AddChild(fmt.Sprintf("joined_key_%d", i), expr.Visualize())
+	}
+
+	n.AddField("source_time_field", node.SourceTimeField.String())
+	n.AddField("joined_time_field", node.JoinedTimeField.String())
+	if node.LowerBound != nil {
+		n.AddChild("lower_bound", node.LowerBound.Visualize())
+	}
+	if node.UpperBound != nil {
+		n.AddChild("upper_bound", node.UpperBound.Visualize())
+	}
+
+	if node.EventTimeField.String() != "" {
+		n.AddField("event_time_field", node.EventTimeField.String())
+	}
+	return n
+}
@@ -0,0 +1,69 @@
+package physical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+// Let evaluates each of the bound expressions once, and makes their values available to the source as variables.
+type Let struct {
+	Names       []octosql.VariableName
+	Expressions []Expression
+	Source      Node
+}
+
+func NewLet(names []octosql.VariableName, expressions []Expression, child Node) *Let {
+	return &Let{Names: names, Expressions: expressions, Source: child}
+}
+
+func (node *Let) Transform(ctx context.Context, transformers *Transformers) Node {
+	exprs := make([]Expression, len(node.Expressions))
+	for i := range node.Expressions {
+		exprs[i] = node.Expressions[i].Transform(ctx, transformers)
+	}
+	var transformed Node = &Let{
+		Names:       node.Names,
+		Expressions: exprs,
+		Source:      node.Source.Transform(ctx, transformers),
+	}
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *Let) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	matExprs := make([]execution.Expression, len(node.Expressions))
+	for i := range node.Expressions {
+		materialized, err := node.Expressions[i].Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize expression bound to %s", node.Names[i])
+		}
+		matExprs[i] = materialized
+	}
+	materialized, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize Source node")
+	}
+
+	return execution.NewLet(node.Names, matExprs, materialized), nil
+}
+
+func (node *Let) Metadata() *metadata.NodeMetadata {
+	return node.Source.Metadata()
+}
+
+func (node *Let) Visualize() *graph.Node {
+	n := graph.NewNode("Let")
+	for i := range node.Expressions {
+		n.AddChild(node.Names[i].String(), node.Expressions[i].Visualize())
+	}
+	n.AddChild("source", node.Source.Visualize())
+	return n
+}
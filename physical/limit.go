@@ -0,0 +1,56 @@
+package physical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+// Limit ends the stream after the given number of records.
+// It's only correct for sources which don't produce retractions.
+type Limit struct {
+	Limit  Expression
+	Source Node
+}
+
+func NewLimit(limit Expression, child Node) *Limit {
+	return &Limit{Limit: limit, Source: child}
+}
+
+func (node *Limit) Transform(ctx context.Context, transformers *Transformers) Node {
+	var transformed Node = &Limit{
+		Limit:  node.Limit.Transform(ctx, transformers),
+		Source: node.Source.Transform(ctx, transformers),
+	}
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *Limit) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materializedLimit, err := node.Limit.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize limit expression")
+	}
+	materializedSource, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize Source")
+	}
+	return execution.NewLimit(materializedLimit, materializedSource), nil
+}
+
+func (node *Limit) Metadata() *metadata.NodeMetadata {
+	return metadata.NewNodeMetadataFromMetadata(node.Source.Metadata())
+}
+
+func (node *Limit) Visualize() *graph.Node {
+	n := graph.NewNode("Limit")
+	n.AddChild("limit", node.Limit.Visualize())
+	n.AddChild("source", node.Source.Visualize())
+	return n
+}
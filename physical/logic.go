@@ -259,3 +259,93 @@ func (f *Predicate) Visualize() *graph.Node {
 	n.AddChild("right", f.Right.Visualize())
 	return n
 }
+
+// IsNull describes a formula which is true if the value of the expression is null.
+type IsNull struct {
+	Expression Expression
+}
+
+func NewIsNull(expression Expression) *IsNull {
+	return &IsNull{Expression: expression}
+}
+
+func (f *IsNull) Transform(ctx context.Context, transformers *Transformers) Formula {
+	var formula Formula = &IsNull{
+		Expression: f.Expression.Transform(ctx, transformers),
+	}
+	if transformers.FormulaT != nil {
+		formula = transformers.FormulaT(formula)
+	}
+	return formula
+}
+
+func (f *IsNull) SplitByAnd() []Formula {
+	return []Formula{f}
+}
+
+func (f *IsNull) ExtractPredicates() []*Predicate {
+	return []*Predicate{}
+}
+
+func (f *IsNull) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Formula, error) {
+	materialized, err := f.Expression.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize expression")
+	}
+	return execution.NewIsNull(materialized), nil
+}
+
+func (f *IsNull) DoesMatchNamespace(namespace *metadata.Namespace) bool {
+	return f.Expression.DoesMatchNamespace(namespace)
+}
+
+func (f *IsNull) Visualize() *graph.Node {
+	n := graph.NewNode("IsNull")
+	n.AddChild("expression", f.Expression.Visualize())
+	return n
+}
+
+// Exists describes a formula which is true if the subquery underneath returns any records.
+type Exists struct {
+	Subquery Node
+}
+
+func NewExists(subquery Node) *Exists {
+	return &Exists{Subquery: subquery}
+}
+
+func (f *Exists) Transform(ctx context.Context, transformers *Transformers) Formula {
+	var formula Formula = &Exists{
+		Subquery: f.Subquery.Transform(ctx, transformers),
+	}
+	if transformers.FormulaT != nil {
+		formula = transformers.FormulaT(formula)
+	}
+	return formula
+}
+
+func (f *Exists) SplitByAnd() []Formula {
+	return []Formula{f}
+}
+
+func (f *Exists) ExtractPredicates() []*Predicate {
+	return []*Predicate{}
+}
+
+func (f *Exists) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Formula, error) {
+	materialized, err := f.Subquery.Materialize(ctx, matCtx.withoutCommonSubexpressions())
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize subquery")
+	}
+	return execution.NewExists(materialized, matCtx.Storage), nil
+}
+
+func (f *Exists) DoesMatchNamespace(namespace *metadata.Namespace) bool {
+	return namespace.Contains(f.Subquery.Metadata().Namespace())
+}
+
+func (f *Exists) Visualize() *graph.Node {
+	n := graph.NewNode("Exists")
+	n.AddChild("subquery", f.Subquery.Visualize())
+	return n
+}
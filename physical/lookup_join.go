@@ -51,7 +51,7 @@ func (node *LookupJoin) Materialize(ctx context.Context, matCtx *Materialization
 		return nil, errors.Wrap(err, "couldn't materialize joined node")
 	}
 
-	return execution.NewLookupJoin(prefetchCount, matCtx.Storage, materializedSource, materializedJoined, true), nil
+	return execution.NewLookupJoin(prefetchCount, matCtx.Storage, materializedSource, materializedJoined, node.isLeftJoin), nil
 }
 
 func (node *LookupJoin) Metadata() *metadata.NodeMetadata {
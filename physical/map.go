@@ -39,9 +39,15 @@ func (node *Map) Transform(ctx context.Context, transformers *Transformers) Node
 }
 
 func (node *Map) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	exprs := make([]Expression, len(node.Expressions))
+	for i := range node.Expressions {
+		exprs[i] = node.Expressions[i]
+	}
+	exprMatCtx := matCtx.withCommonSubexpressions(exprs, nil)
+
 	matExprs := make([]execution.NamedExpression, len(node.Expressions))
 	for i := range node.Expressions {
-		materialized, err := node.Expressions[i].MaterializeNamed(ctx, matCtx)
+		materialized, err := node.Expressions[i].MaterializeNamed(ctx, exprMatCtx)
 		if err != nil {
 			return nil, errors.Wrapf(err, "couldn't materialize expression with index %v", i)
 		}
@@ -111,7 +117,7 @@ func (node *Map) Metadata() *metadata.NodeMetadata {
 	var newEventTimeField octosql.VariableName
 	for _, expr := range node.Expressions {
 		if expr, ok := expr.(*StarExpression); ok {
-			if expr.Qualifier == "" || expr.Qualifier == eventTimeField.Source() {
+			if expr.doesKeepVariable(eventTimeField) {
 				newEventTimeField = eventTimeField
 				break
 			}
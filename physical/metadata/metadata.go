@@ -75,6 +75,10 @@ func EmptyNamespace() *Namespace {
 	return NewNamespace(nil)
 }
 
+func (nm *Namespace) Prefixes() []string {
+	return nm.prefixes
+}
+
 func (nm *Namespace) MergeWith(other *Namespace) {
 	for _, prefix := range other.prefixes {
 		nm.AddPrefix(prefix)
@@ -102,6 +102,7 @@ var MergeDataSourceBuilderWithRequalifier = Scenario{
 			Filter:           newFilter,
 			Name:             dataSourceBuilder.Name,
 			Alias:            match.Strings["qualifier"],
+			ConfigOptional:   dataSourceBuilder.ConfigOptional,
 		}
 	},
 }
@@ -234,6 +235,7 @@ var MergeDataSourceBuilderWithFilter = Scenario{
 			Filter:           dsFilter,
 			Name:             ds.Name,
 			Alias:            ds.Alias,
+			ConfigOptional:   ds.ConfigOptional,
 		}
 
 		if len(filters) > 0 {
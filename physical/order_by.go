@@ -13,8 +13,10 @@ import (
 type OrderDirection string
 
 const (
-	Ascending  OrderDirection = "asc"
-	Descending OrderDirection = "desc"
+	Ascending            OrderDirection = "asc"
+	Descending           OrderDirection = "desc"
+	AscendingNullsLast   OrderDirection = "asc nulls last"
+	DescendingNullsFirst OrderDirection = "desc nulls first"
 )
 
 type OrderBy struct {
@@ -9,6 +9,7 @@ import (
 	"github.com/cube2222/octosql"
 	"github.com/cube2222/octosql/config"
 	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/execution/functions"
 	"github.com/cube2222/octosql/graph"
 	"github.com/cube2222/octosql/physical/metadata"
 	"github.com/cube2222/octosql/storage"
@@ -29,6 +30,9 @@ type Transformers struct {
 type MaterializationContext struct {
 	Config  *config.Config
 	Storage storage.Storage
+
+	// commonSubexpressions are the function calls shared by the expressions of the Map or Filter being materialized.
+	commonSubexpressions map[string]*execution.CommonSubexpression
 }
 
 func NewMaterializationContext(config *config.Config, storage storage.Storage) *MaterializationContext {
@@ -43,6 +47,7 @@ type OutputOptions struct {
 	OrderByDirections  []OrderDirection
 	Limit              Expression
 	Offset             Expression
+	OutputFile         string
 }
 
 func NewOutputOptions(
@@ -50,12 +55,14 @@ func NewOutputOptions(
 	orderByDirections []OrderDirection,
 	limit Expression,
 	offset Expression,
+	outputFile string,
 ) *OutputOptions {
 	return &OutputOptions{
 		OrderByExpressions: orderByExpressions,
 		OrderByDirections:  orderByDirections,
 		Limit:              limit,
 		Offset:             offset,
+		OutputFile:         outputFile,
 	}
 }
 
@@ -96,7 +103,7 @@ func (opts *OutputOptions) Materialize(ctx context.Context, matCtx *Materializat
 		offset = offsetExpression
 	}
 
-	return execution.NewOutputOptions(orderByExpressions, orderByDirections, limit, offset), nil
+	return execution.NewOutputOptions(orderByExpressions, orderByDirections, limit, offset, opts.OutputFile), nil
 }
 
 // Node describes a single record stream source.
@@ -128,12 +135,18 @@ type NamedExpression interface {
 
 type StarExpression struct {
 	Qualifier string
+	Exclude   []octosql.VariableName
+	Replace   []NamedExpression
 }
 
 func NewStarExpression(qualifier string) *StarExpression {
 	return &StarExpression{Qualifier: qualifier}
 }
 
+func NewStarExpressionWithModifiers(qualifier string, exclude []octosql.VariableName, replace []NamedExpression) *StarExpression {
+	return &StarExpression{Qualifier: qualifier, Exclude: exclude, Replace: replace}
+}
+
 func (se *StarExpression) Transform(ctx context.Context, transformers *Transformers) Expression {
 	return se.TransformNamed(ctx, transformers)
 }
@@ -150,8 +163,15 @@ func (se *StarExpression) Materialize(ctx context.Context, matCtx *Materializati
 }
 
 func (se *StarExpression) TransformNamed(ctx context.Context, transformers *Transformers) NamedExpression {
+	var replace []NamedExpression
+	for i := range se.Replace {
+		replace = append(replace, se.Replace[i].TransformNamed(ctx, transformers))
+	}
+
 	var expr NamedExpression = &StarExpression{
 		Qualifier: se.Qualifier,
+		Exclude:   se.Exclude,
+		Replace:   replace,
 	}
 
 	if transformers.NamedExprT != nil {
@@ -162,16 +182,53 @@ func (se *StarExpression) TransformNamed(ctx context.Context, transformers *Tran
 }
 
 func (se *StarExpression) MaterializeNamed(ctx context.Context, matCtx *MaterializationContext) (execution.NamedExpression, error) {
-	return execution.NewStarExpression(se.Qualifier), nil
+	if len(se.Exclude) == 0 && len(se.Replace) == 0 {
+		return execution.NewStarExpression(se.Qualifier), nil
+	}
+
+	replace := make([]execution.NamedExpression, len(se.Replace))
+	for i := range se.Replace {
+		materialized, err := se.Replace[i].MaterializeNamed(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize replacement of %v", se.Replace[i].ExpressionName())
+		}
+		replace[i] = materialized
+	}
+
+	return execution.NewStarExpressionWithModifiers(se.Qualifier, se.Exclude, replace), nil
 }
 
 func (se *StarExpression) DoesMatchNamespace(namespace *metadata.Namespace) bool {
 	return true // doesn't really matter since a star expression won't appear in a Predicate
 }
 
+// doesKeepVariable checks if the variable is passed through unchanged.
+func (se *StarExpression) doesKeepVariable(name octosql.VariableName) bool {
+	if se.Qualifier != "" && se.Qualifier != name.Source() {
+		return false
+	}
+	for _, column := range se.Exclude {
+		if name.MatchesColumn(column) {
+			return false
+		}
+	}
+	for _, replacement := range se.Replace {
+		if name.MatchesColumn(replacement.ExpressionName()) {
+			return false
+		}
+	}
+	return true
+}
+
 func (se *StarExpression) Visualize() *graph.Node {
 	n := graph.NewNode("StarExpression")
 	n.AddField("name", se.name())
+	for i, column := range se.Exclude {
+		n.AddField(fmt.Sprintf("exclude_%d", i), column.String())
+	}
+	for i, replacement := range se.Replace {
+		n.AddChild(fmt.Sprintf("replace_%d", i), replacement.Visualize())
+	}
 	return n
 }
 
@@ -304,11 +361,52 @@ func (ne *NodeExpression) Transform(ctx context.Context, transformers *Transform
 }
 
 func (ne *NodeExpression) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Expression, error) {
-	materialized, err := ne.Node.Materialize(ctx, matCtx)
+	materialized, err := ne.Node.Materialize(ctx, matCtx.withoutCommonSubexpressions())
 	if err != nil {
 		return nil, errors.Wrap(err, "couldn't materialize node")
 	}
-	return execution.NewNodeExpression(materialized, matCtx.Storage), nil
+	if !ne.isMemoizable(ctx) {
+		return execution.NewNodeExpression(materialized, matCtx.Storage), nil
+	}
+	return execution.NewMemoizedNodeExpression(materialized, matCtx.Storage, ne.referencedVariables(ctx)), nil
+}
+
+// isMemoizable checks if the subquery always returns the same records for the same variable values during a run,
+// which requires it to read only bounded sources and to use only deterministic functions.
+func (ne *NodeExpression) isMemoizable(ctx context.Context) bool {
+	if ne.Node.Metadata().Cardinality() == metadata.Unbounded {
+		return false
+	}
+
+	deterministic := true
+	ne.Node.Transform(ctx, &Transformers{
+		ExprT: func(expr Expression) Expression {
+			if function, ok := expr.(*FunctionExpression); ok {
+				if descriptor, ok := functions.Lookup(function.Name); !ok || descriptor.Nondeterministic {
+					deterministic = false
+				}
+			}
+			return expr
+		},
+	})
+	return deterministic
+}
+
+// referencedVariables returns the names of all the variables used in the subquery.
+// Only the ones coming from outside of it change its result.
+func (ne *NodeExpression) referencedVariables(ctx context.Context) []octosql.VariableName {
+	var names []octosql.VariableName
+	seen := make(map[octosql.VariableName]bool)
+	ne.Node.Transform(ctx, &Transformers{
+		NamedExprT: func(expr NamedExpression) NamedExpression {
+			if variable, ok := expr.(*Variable); ok && !seen[variable.Name] {
+				seen[variable.Name] = true
+				names = append(names, variable.Name)
+			}
+			return expr
+		},
+	})
+	return names
 }
 
 func (ne *NodeExpression) DoesMatchNamespace(namespace *metadata.Namespace) bool {
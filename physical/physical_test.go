@@ -0,0 +1,117 @@
+package physical
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+func TestNodeExpression_isMemoizable(t *testing.T) {
+	source := func(cardinality metadata.Cardinality) Node {
+		return NewDataSourceBuilderFactory(nil, nil, nil, cardinality, 1)("t", "t")[0]
+	}
+	mapped := func(expr Expression, source Node) Node {
+		return NewMap([]NamedExpression{NewAliasedExpression(octosql.NewVariableName("x"), expr)}, source, false)
+	}
+
+	tests := []struct {
+		name string
+		node Node
+		want bool
+	}{
+		{
+			name: "deterministic over bounded source",
+			node: mapped(NewFunctionExpression("upper", []Expression{NewVariable(octosql.NewVariableName("t.name"))}), source(metadata.BoundedFitsInLocalStorage)),
+			want: true,
+		},
+		{
+			name: "nondeterministic function",
+			node: mapped(NewFunctionExpression("randint", []Expression{}), source(metadata.BoundedFitsInLocalStorage)),
+			want: false,
+		},
+		{
+			name: "unknown function",
+			node: mapped(NewFunctionExpression("no_such_function", []Expression{}), source(metadata.BoundedFitsInLocalStorage)),
+			want: false,
+		},
+		{
+			name: "unbounded source",
+			node: mapped(NewVariable(octosql.NewVariableName("t.name")), source(metadata.Unbounded)),
+			want: false,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := NewNodeExpression(tt.node).isMemoizable(context.Background()); got != tt.want {
+				t.Errorf("isMemoizable() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestMaterializationContext_withCommonSubexpressions(t *testing.T) {
+	variable := func(name string) Expression {
+		return NewVariable(octosql.NewVariableName(name))
+	}
+	upper := func(arg Expression) Expression {
+		return NewFunctionExpression("upper", []Expression{arg})
+	}
+	subquery := func(expr Expression) Expression {
+		return NewNodeExpression(NewMap([]NamedExpression{NewAliasedExpression(octosql.NewVariableName("x"), expr)}, NewStubNode(metadata.NewNodeMetadata(
+			metadata.BoundedFitsInLocalStorage,
+			octosql.NewVariableName(""),
+			metadata.EmptyNamespace(),
+		)), false))
+	}
+
+	tests := []struct {
+		name        string
+		expressions []Expression
+		formulas    []Formula
+		want        int
+	}{
+		{
+			name:        "repeated function call",
+			expressions: []Expression{upper(variable("a")), NewAliasedExpression(octosql.NewVariableName("b"), upper(variable("a")))},
+			want:        1,
+		},
+		{
+			name:        "nested repeated function calls",
+			expressions: []Expression{upper(upper(variable("a"))), upper(upper(variable("a"))), upper(variable("b"))},
+			want:        2,
+		},
+		{
+			name:        "different arguments",
+			expressions: []Expression{upper(variable("a")), upper(variable("b"))},
+			want:        0,
+		},
+		{
+			name:        "nondeterministic function",
+			expressions: []Expression{NewFunctionExpression("randint", []Expression{}), NewFunctionExpression("randint", []Expression{})},
+			want:        0,
+		},
+		{
+			name:        "repeated in subquery",
+			expressions: []Expression{upper(variable("a")), subquery(upper(variable("a")))},
+			want:        0,
+		},
+		{
+			name: "repeated in formula",
+			formulas: []Formula{NewAnd(
+				NewPredicate(upper(variable("a")), Equal, variable("b")),
+				NewIsNull(upper(variable("a"))),
+			)},
+			want: 1,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			matCtx := (&MaterializationContext{}).withCommonSubexpressions(tt.expressions, tt.formulas)
+			if got := len(matCtx.commonSubexpressions); got != tt.want {
+				t.Errorf("withCommonSubexpressions() shares %v expressions, want %v", got, tt.want)
+			}
+		})
+	}
+}
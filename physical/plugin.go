@@ -0,0 +1,33 @@
+package physical
+
+import (
+	"plugin"
+
+	"github.com/pkg/errors"
+)
+
+// PluginFactoriesSymbol is the name of the variable a data source plugin has to export.
+// It has to be of type map[string]physical.Factory, keyed by the data source type used in the configuration.
+const PluginFactoriesSymbol = "Factories"
+
+// LoadDataSourcePlugin opens a Go plugin built with -buildmode=plugin and returns the data source factories it exports.
+// This only works on Linux and macOS, with a plugin built by the same Go version as the binary,
+// against the same versions of OctoSQL and of the other packages they share.
+func LoadDataSourcePlugin(path string) (map[string]Factory, error) {
+	p, err := plugin.Open(path)
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't open data source plugin %v", path)
+	}
+
+	symbol, err := p.Lookup(PluginFactoriesSymbol)
+	if err != nil {
+		return nil, errors.Wrapf(err, "couldn't find %v in data source plugin %v", PluginFactoriesSymbol, path)
+	}
+
+	factories, ok := symbol.(*map[string]Factory)
+	if !ok {
+		return nil, errors.Errorf("invalid %v in data source plugin %v, expected map[string]physical.Factory, got %T", PluginFactoriesSymbol, path, symbol)
+	}
+
+	return *factories, nil
+}
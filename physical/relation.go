@@ -16,6 +16,7 @@ const (
 	MoreThan     Relation = "more_than"
 	LessThan     Relation = "less_than"
 	Like         Relation = "like"
+	ILike        Relation = "ilike"
 	In           Relation = "in"
 	NotIn        Relation = "not_in"
 	GreaterEqual Relation = "greater_equal"
@@ -39,6 +40,8 @@ func (rel Relation) Materialize(ctx context.Context, matCtx *MaterializationCont
 		return execution.NewLessThan()
 	case Like:
 		return execution.NewLike()
+	case ILike:
+		return execution.NewILike()
 	case In:
 		return execution.NewIn()
 	case NotIn:
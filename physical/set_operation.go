@@ -0,0 +1,76 @@
+package physical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+type SetOperation struct {
+	Left           Node
+	Right          Node
+	Operation      execution.SetOperationType
+	EventTimeField octosql.VariableName
+}
+
+func NewSetOperation(left, right Node, operation execution.SetOperationType, eventTimeField octosql.VariableName) *SetOperation {
+	return &SetOperation{
+		Left:           left,
+		Right:          right,
+		Operation:      operation,
+		EventTimeField: eventTimeField,
+	}
+}
+
+func (node *SetOperation) Transform(ctx context.Context, transformers *Transformers) Node {
+	var transformed Node = &SetOperation{
+		Left:           node.Left.Transform(ctx, transformers),
+		Right:          node.Right.Transform(ctx, transformers),
+		Operation:      node.Operation,
+		EventTimeField: node.EventTimeField,
+	}
+
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *SetOperation) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materializedLeft, err := node.Left.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize left node")
+	}
+
+	materializedRight, err := node.Right.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize right node")
+	}
+
+	return execution.NewSetOperation(materializedLeft, materializedRight, node.Operation, matCtx.Storage, node.EventTimeField), nil
+}
+
+func (node *SetOperation) Metadata() *metadata.NodeMetadata {
+	leftMetadata := node.Left.Metadata()
+	cardinality := metadata.CombineCardinalities(leftMetadata.Cardinality(), node.Right.Metadata().Cardinality())
+
+	// Only records of the left node are sent.
+	return metadata.NewNodeMetadata(cardinality, node.EventTimeField, leftMetadata.Namespace())
+}
+
+func (node *SetOperation) Visualize() *graph.Node {
+	n := graph.NewNode("Set Operation")
+	n.AddChild("left", node.Left.Visualize())
+	n.AddChild("right", node.Right.Visualize())
+	n.AddField("operation", string(node.Operation))
+
+	if node.EventTimeField.String() != "" {
+		n.AddField("event_time_field", node.EventTimeField.String())
+	}
+	return n
+}
@@ -0,0 +1,48 @@
+package physical
+
+// Statistics are estimates of the sizes of data sources, used to choose the order of joins.
+type Statistics struct {
+	rowCounts map[string]int64
+}
+
+func NewStatistics() *Statistics {
+	return &Statistics{
+		rowCounts: make(map[string]int64),
+	}
+}
+
+// SetRowCount sets the estimated number of records of the data source with the given name.
+func (stats *Statistics) SetRowCount(dataSourceName string, rowCount int64) {
+	stats.rowCounts[dataSourceName] = rowCount
+}
+
+// RowCount returns the estimated number of records of the data source with the given name, if it's known.
+func (stats *Statistics) RowCount(dataSourceName string) (int64, bool) {
+	rowCount, ok := stats.rowCounts[dataSourceName]
+	return rowCount, ok
+}
+
+// EstimateRowCount estimates the number of records produced by all the partitions of the node.
+// Filters are assumed to keep all the records, so the estimate is an upper bound.
+// It returns false for nodes reading data sources without statistics, or combining multiple sources.
+func (stats *Statistics) EstimateRowCount(node Node) (int64, bool) {
+	switch node := node.(type) {
+	case *DataSourceBuilder:
+		return stats.RowCount(node.Name)
+	case *Filter:
+		return stats.EstimateRowCount(node.Source)
+	case *Map:
+		return stats.EstimateRowCount(node.Source)
+	case *Requalifier:
+		return stats.EstimateRowCount(node.Source)
+	case *Shuffle:
+		if len(node.Sources) == 0 {
+			return 0, false
+		}
+		return stats.EstimateRowCount(node.Sources[0])
+	case *NextShuffleMetadataChange:
+		return stats.EstimateRowCount(node.Source)
+	default:
+		return 0, false
+	}
+}
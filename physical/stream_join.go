@@ -99,8 +99,11 @@ func (node *StreamJoin) Metadata() *metadata.NodeMetadata {
 	joinedMetadata := node.Joined.Metadata()
 	cardinality := metadata.CombineCardinalities(sourceMetadata.Cardinality(), joinedMetadata.Cardinality())
 
+	// Semi and anti joins only output the records of the source.
 	sourceNamespace := sourceMetadata.Namespace()
-	sourceNamespace.MergeWith(joinedMetadata.Namespace())
+	if node.JoinType != execution.SEMI_JOIN && node.JoinType != execution.ANTI_JOIN {
+		sourceNamespace.MergeWith(joinedMetadata.Namespace())
+	}
 
 	return metadata.NewNodeMetadata(cardinality, node.EventTimeField, sourceNamespace)
 }
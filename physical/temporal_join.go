@@ -0,0 +1,118 @@
+package physical
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+	"github.com/pkg/errors"
+)
+
+// TemporalJoin joins a stream with the versions of a table which were valid at the event time of its records.
+type TemporalJoin struct {
+	Source         Node
+	Table          Node
+	SourceKey      []Expression
+	TableKey       []Expression
+	ValidFromField octosql.VariableName
+	ValidToField   octosql.VariableName
+	IsLeftJoin     bool
+	EventTimeField octosql.VariableName
+}
+
+func NewTemporalJoin(source, table Node, sourceKey, tableKey []Expression, validFromField, validToField octosql.VariableName, isLeftJoin bool, eventTimeField octosql.VariableName) *TemporalJoin {
+	return &TemporalJoin{
+		Source:         source,
+		Table:          table,
+		SourceKey:      sourceKey,
+		TableKey:       tableKey,
+		ValidFromField: validFromField,
+		ValidToField:   validToField,
+		IsLeftJoin:     isLeftJoin,
+		EventTimeField: eventTimeField,
+	}
+}
+
+func (node *TemporalJoin) Transform(ctx context.Context, transformers *Transformers) Node {
+	var transformed Node = &TemporalJoin{
+		Source:         node.Source.Transform(ctx, transformers),
+		Table:          node.Table.Transform(ctx, transformers),
+		SourceKey:      node.SourceKey,
+		TableKey:       node.TableKey,
+		ValidFromField: node.ValidFromField,
+		ValidToField:   node.ValidToField,
+		IsLeftJoin:     node.IsLeftJoin,
+		EventTimeField: node.EventTimeField,
+	}
+
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *TemporalJoin) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materializedSource, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize source node")
+	}
+
+	materializedTable, err := node.Table.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize table node")
+	}
+
+	materializedSourceKey := make([]execution.Expression, len(node.SourceKey))
+	materializedTableKey := make([]execution.Expression, len(node.TableKey))
+
+	for i := range node.SourceKey {
+		materializedSourceKey[i], err = node.SourceKey[i].Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize source key expression with index %v", i)
+		}
+
+		materializedTableKey[i], err = node.TableKey[i].Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Wrapf(err, "couldn't materialize table key expression with index %v", i)
+		}
+	}
+
+	return execution.NewTemporalJoin(materializedSource, materializedTable, materializedSourceKey, materializedTableKey, node.ValidFromField, node.ValidToField, node.IsLeftJoin, matCtx.Storage, node.EventTimeField), nil
+}
+
+func (node *TemporalJoin) Metadata() *metadata.NodeMetadata {
+	sourceMetadata := node.Source.Metadata()
+	tableMetadata := node.Table.Metadata()
+	cardinality := metadata.CombineCardinalities(sourceMetadata.Cardinality(), tableMetadata.Cardinality())
+
+	namespace := sourceMetadata.Namespace()
+	namespace.MergeWith(tableMetadata.Namespace())
+
+	return metadata.NewNodeMetadata(cardinality, node.EventTimeField, namespace)
+}
+
+func (node *TemporalJoin) Visualize() *graph.Node {
+	n := graph.NewNode("Temporal Join")
+	n.AddChild("source", node.Source.Visualize())
+	n.AddChild("table", node.Table.Visualize())
+
+	for i, expr := range node.SourceKey {
+		n.AddChild(fmt.Sprintf("source_key_%d", i), expr.Visualize())
+	}
+
+	for i, expr := range node.TableKey {
+		n.AddChild(fmt.Sprintf("table_key_%d", i), expr.Visualize())
+	}
+
+	n.AddField("valid_from_field", node.ValidFromField.String())
+	n.AddField("valid_to_field", node.ValidToField.String())
+	n.AddField("is_left_join", fmt.Sprint(node.IsLeftJoin))
+
+	if node.EventTimeField.String() != "" {
+		n.AddField("event_time_field", node.EventTimeField.String())
+	}
+	return n
+}
@@ -170,6 +170,40 @@ func (node *TableValuedFunction) Materialize(ctx context.Context, matCtx *Materi
 
 		return tvf.NewRange(startMat, endMat), nil
 
+	case "generate_series":
+		startExpr, err := node.getArgumentExpression(octosql.NewVariableName("start"))
+		if err != nil {
+			return nil, err
+		}
+		stopExpr, err := node.getArgumentExpression(octosql.NewVariableName("stop"))
+		if err != nil {
+			return nil, err
+		}
+
+		startMat, err := startExpr.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize start of series expression")
+		}
+		stopMat, err := stopExpr.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize stop of series expression")
+		}
+
+		// The step is optional.
+		var stepMat execution.Expression
+		if _, ok := node.Arguments[octosql.NewVariableName("step")]; ok {
+			stepExpr, err := node.getArgumentExpression(octosql.NewVariableName("step"))
+			if err != nil {
+				return nil, err
+			}
+			stepMat, err = stepExpr.Materialize(ctx, matCtx)
+			if err != nil {
+				return nil, errors.Errorf("couldn't materialize step of series expression")
+			}
+		}
+
+		return tvf.NewGenerateSeries(startMat, stopMat, stepMat), nil
+
 	case "tumble":
 		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
 		if err != nil {
@@ -183,7 +217,45 @@ func (node *TableValuedFunction) Materialize(ctx context.Context, matCtx *Materi
 		if err != nil {
 			return nil, err
 		}
-		windowOffset, err := node.getArgumentExpression(octosql.NewVariableName("offset"))
+
+		matSource, err := source.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize source")
+		}
+		matWindowLength, err := windowLength.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize window length expression")
+		}
+
+		// The offset is optional.
+		var matWindowOffset execution.Expression
+		if _, ok := node.Arguments[octosql.NewVariableName("offset")]; ok {
+			windowOffset, err := node.getArgumentExpression(octosql.NewVariableName("offset"))
+			if err != nil {
+				return nil, err
+			}
+			matWindowOffset, err = windowOffset.Materialize(ctx, matCtx)
+			if err != nil {
+				return nil, errors.Errorf("couldn't materialize window offset expression")
+			}
+		}
+
+		return tvf.NewTumble(matSource, timeField, matWindowLength, matWindowOffset), nil
+
+	case "hop":
+		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
+		if err != nil {
+			return nil, err
+		}
+		timeField, err := node.getArgumentDescriptor(octosql.NewVariableName("time_field"))
+		if err != nil {
+			return nil, err
+		}
+		slide, err := node.getArgumentExpression(octosql.NewVariableName("slide"))
+		if err != nil {
+			return nil, err
+		}
+		windowLength, err := node.getArgumentExpression(octosql.NewVariableName("window_length"))
 		if err != nil {
 			return nil, err
 		}
@@ -192,16 +264,63 @@ func (node *TableValuedFunction) Materialize(ctx context.Context, matCtx *Materi
 		if err != nil {
 			return nil, errors.Errorf("couldn't materialize source")
 		}
+		matSlide, err := slide.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize window slide expression")
+		}
 		matWindowLength, err := windowLength.Materialize(ctx, matCtx)
 		if err != nil {
 			return nil, errors.Errorf("couldn't materialize window length expression")
 		}
-		matWindowOffset, err := windowOffset.Materialize(ctx, matCtx)
+
+		// The offset is optional.
+		var matWindowOffset execution.Expression
+		if _, ok := node.Arguments[octosql.NewVariableName("offset")]; ok {
+			windowOffset, err := node.getArgumentExpression(octosql.NewVariableName("offset"))
+			if err != nil {
+				return nil, err
+			}
+			matWindowOffset, err = windowOffset.Materialize(ctx, matCtx)
+			if err != nil {
+				return nil, errors.Errorf("couldn't materialize window offset expression")
+			}
+		}
+
+		return tvf.NewHop(matSource, timeField, matSlide, matWindowLength, matWindowOffset), nil
+
+	case "session":
+		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
 		if err != nil {
-			return nil, errors.Errorf("couldn't materialize window offset expression")
+			return nil, err
+		}
+		timeField, err := node.getArgumentDescriptor(octosql.NewVariableName("time_field"))
+		if err != nil {
+			return nil, err
+		}
+		gap, err := node.getArgumentExpression(octosql.NewVariableName("gap"))
+		if err != nil {
+			return nil, err
 		}
 
-		return tvf.NewTumble(matSource, timeField, matWindowLength, matWindowOffset), nil
+		// The key is optional.
+		var key octosql.VariableName
+		if _, ok := node.Arguments[octosql.NewVariableName("key")]; ok {
+			key, err = node.getArgumentDescriptor(octosql.NewVariableName("key"))
+			if err != nil {
+				return nil, err
+			}
+		}
+
+		matSource, err := source.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize source")
+		}
+		matGap, err := gap.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize session gap expression")
+		}
+
+		return tvf.NewSession(matSource, timeField, matGap, key), nil
 
 	case "max_diff_watermark":
 		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
@@ -228,6 +347,44 @@ func (node *TableValuedFunction) Materialize(ctx context.Context, matCtx *Materi
 
 		return tvf.NewMaximumDifferenceWatermarkGenerator(matSource, timeField, matOffset), nil
 
+	case "monotonic_watermark":
+		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
+		if err != nil {
+			return nil, err
+		}
+		timeField, err := node.getArgumentDescriptor(octosql.NewVariableName("time_field"))
+		if err != nil {
+			return nil, err
+		}
+
+		matSource, err := source.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize source")
+		}
+
+		return tvf.NewMonotonicWatermarkGenerator(matSource, timeField), nil
+
+	case "refresh":
+		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
+		if err != nil {
+			return nil, err
+		}
+		interval, err := node.getArgumentExpression(octosql.NewVariableName("interval"))
+		if err != nil {
+			return nil, err
+		}
+
+		matSource, err := source.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize source")
+		}
+		matInterval, err := interval.Materialize(ctx, matCtx)
+		if err != nil {
+			return nil, errors.Errorf("couldn't materialize refresh interval expression")
+		}
+
+		return tvf.NewRefresh(matSource, matInterval, matCtx.Storage), nil
+
 	case "percentile_watermark":
 		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
 		if err != nil {
@@ -277,9 +434,9 @@ func (node *TableValuedFunction) Materialize(ctx context.Context, matCtx *Materi
 func (node *TableValuedFunction) Metadata() *metadata.NodeMetadata {
 	namespace := metadata.EmptyNamespace()
 	switch node.Name {
-	case "range":
+	case "range", "generate_series":
 		return metadata.NewNodeMetadata(metadata.BoundedFitsInLocalStorage, octosql.NewVariableName(""), namespace)
-	case "tumble":
+	case "tumble", "hop", "session":
 		var cardinality metadata.Cardinality
 		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
 		if err == nil {
@@ -288,7 +445,7 @@ func (node *TableValuedFunction) Metadata() *metadata.NodeMetadata {
 			cardinality = metadata.BoundedFitsInLocalStorage
 		}
 		return metadata.NewNodeMetadata(cardinality, octosql.NewVariableName("window_end"), namespace)
-	case "max_diff_watermark":
+	case "max_diff_watermark", "monotonic_watermark":
 		var cardinality metadata.Cardinality
 		source, err := node.getArgumentTable(octosql.NewVariableName("source"))
 		if err == nil {
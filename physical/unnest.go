@@ -0,0 +1,63 @@
+package physical
+
+import (
+	"context"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/graph"
+	"github.com/cube2222/octosql/physical/metadata"
+)
+
+type Unnest struct {
+	Expression Expression
+	Field      octosql.VariableName
+	Source     Node
+}
+
+func NewUnnest(expression Expression, field octosql.VariableName, child Node) *Unnest {
+	return &Unnest{Expression: expression, Field: field, Source: child}
+}
+
+func (node *Unnest) Transform(ctx context.Context, transformers *Transformers) Node {
+	var transformed Node = &Unnest{
+		Expression: node.Expression.Transform(ctx, transformers),
+		Field:      node.Field,
+		Source:     node.Source.Transform(ctx, transformers),
+	}
+	if transformers.NodeT != nil {
+		transformed = transformers.NodeT(transformed)
+	}
+	return transformed
+}
+
+func (node *Unnest) Materialize(ctx context.Context, matCtx *MaterializationContext) (execution.Node, error) {
+	materializedExpression, err := node.Expression.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize unnested expression")
+	}
+	materializedSource, err := node.Source.Materialize(ctx, matCtx)
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't materialize Source")
+	}
+	return execution.NewUnnest(materializedExpression, node.Field, materializedSource), nil
+}
+
+func (node *Unnest) Metadata() *metadata.NodeMetadata {
+	sourceMetadata := node.Source.Metadata()
+	namespace := metadata.EmptyNamespace()
+	namespace.MergeWith(sourceMetadata.Namespace())
+	namespace.AddPrefix(node.Field.Source())
+
+	return metadata.NewNodeMetadata(sourceMetadata.Cardinality(), sourceMetadata.EventTimeField(), namespace)
+}
+
+func (node *Unnest) Visualize() *graph.Node {
+	n := graph.NewNode("Unnest")
+	n.AddField("field", node.Field.String())
+	n.AddChild("expression", node.Expression.Visualize())
+	n.AddChild("source", node.Source.Visualize())
+	return n
+}
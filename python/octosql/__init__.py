@@ -0,0 +1,171 @@
+"""Python bindings of OctoSQL, which run queries using the liboctosql shared library.
+
+    import octosql
+
+    with octosql.Context() as ctx:
+        ctx.register_table("people", "people.csv")
+        ctx.register_table("cities", cities_data_frame)
+        table = ctx.sql("SELECT p.name, c.country FROM people p JOIN cities c ON p.city = c.name")
+
+Results are pyarrow Tables, imported from the library using the Arrow C stream interface, so they aren't copied.
+"""
+
+import ctypes
+import os
+import shutil
+import sys
+import tempfile
+
+import pyarrow
+import pyarrow.parquet
+
+__all__ = ["Context", "OctoSQLError"]
+
+
+class OctoSQLError(Exception):
+    """Raised when the library returns an error, with the error message it returned."""
+
+
+class _ArrowArrayStream(ctypes.Structure):
+    # The struct only has to be allocated here, it gets filled by the library and moved into pyarrow.
+    _fields_ = [
+        ("get_schema", ctypes.c_void_p),
+        ("get_next", ctypes.c_void_p),
+        ("get_last_error", ctypes.c_void_p),
+        ("release", ctypes.c_void_p),
+        ("private_data", ctypes.c_void_p),
+    ]
+
+
+def _library_name():
+    if sys.platform == "darwin":
+        return "liboctosql.dylib"
+    if sys.platform == "win32":
+        return "liboctosql.dll"
+    return "liboctosql.so"
+
+
+def _load_library():
+    # The library is built into the package when it's installed, OCTOSQL_LIBRARY can point to another build of it.
+    path = os.environ.get("OCTOSQL_LIBRARY")
+    if not path:
+        path = os.path.join(os.path.dirname(os.path.abspath(__file__)), _library_name())
+    lib = ctypes.CDLL(path)
+
+    lib.octosql_context_new.argtypes = []
+    lib.octosql_context_new.restype = ctypes.c_int64
+    lib.octosql_context_free.argtypes = [ctypes.c_int64]
+    lib.octosql_context_free.restype = None
+    lib.octosql_last_error.argtypes = [ctypes.c_int64]
+    lib.octosql_last_error.restype = ctypes.c_char_p
+    lib.octosql_register_table.argtypes = [ctypes.c_int64, ctypes.c_char_p, ctypes.c_char_p]
+    lib.octosql_register_table.restype = ctypes.c_int
+    lib.octosql_execute.argtypes = [ctypes.c_int64, ctypes.c_char_p, ctypes.POINTER(_ArrowArrayStream)]
+    lib.octosql_execute.restype = ctypes.c_int
+    return lib
+
+
+_lib = _load_library()
+
+
+class Context:
+    """Holds the registered tables, and runs queries using them.
+
+    The context has to be closed when it's no longer used, which frees its storage
+    and removes the files written for the registered data frames and Arrow tables.
+    """
+
+    def __init__(self):
+        self._handle = _lib.octosql_context_new()
+        if self._handle == 0:
+            self._raise_error("couldn't create context")
+        self._directory = None
+        self._written_tables = 0
+
+    def register_table(self, name, data):
+        """Registers the data as a table with the given name.
+
+        The data can be the path of a file, with its type inferred from the extension:
+        .csv, .tsv, .json, .jsonl, .ndjson, .parquet or .xlsx.
+        It can also be a pyarrow Table, RecordBatch or RecordBatchReader, or a pandas DataFrame.
+        Those get written to a temporary Parquet file, as the library can only read tables from files.
+        """
+        self._check_open()
+        if isinstance(data, (str, os.PathLike)):
+            path = os.fspath(data)
+        else:
+            path = self._write_table(_to_arrow(data))
+
+        if _lib.octosql_register_table(self._handle, name.encode(), path.encode()) != 0:
+            self._raise_error("couldn't register table {}".format(name))
+
+    def sql(self, query):
+        """Runs the query to completion, and returns its result as a pyarrow Table."""
+        return self.sql_reader(query).read_all()
+
+    def sql_reader(self, query):
+        """Runs the query to completion, and returns its result as a pyarrow RecordBatchReader."""
+        self._check_open()
+        stream = _ArrowArrayStream()
+        if _lib.octosql_execute(self._handle, query.encode(), ctypes.byref(stream)) != 0:
+            self._raise_error("couldn't run query")
+        # Importing moves the stream, so pyarrow becomes responsible for releasing it.
+        return pyarrow.RecordBatchReader._import_from_c(ctypes.addressof(stream))
+
+    def close(self):
+        """Frees the context. Results already returned stay valid."""
+        if self._handle == 0:
+            return
+        _lib.octosql_context_free(self._handle)
+        self._handle = 0
+        if self._directory is not None:
+            shutil.rmtree(self._directory, ignore_errors=True)
+            self._directory = None
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        self.close()
+
+    def __del__(self):
+        self.close()
+
+    def _check_open(self):
+        if self._handle == 0:
+            raise OctoSQLError("context is closed")
+
+    def _raise_error(self, message):
+        error = _lib.octosql_last_error(self._handle)
+        if error is not None:
+            message = "{}: {}".format(message, error.decode(errors="replace"))
+        raise OctoSQLError(message)
+
+    def _write_table(self, table):
+        if self._directory is None:
+            self._directory = tempfile.mkdtemp(prefix="octosql-")
+        path = os.path.join(self._directory, "table_{}.parquet".format(self._written_tables))
+        self._written_tables += 1
+        # Only plain encoded version 1.0 files are written, which the Parquet data source is able to read.
+        pyarrow.parquet.write_table(
+            table,
+            path,
+            version="1.0",
+            data_page_version="1.0",
+            use_dictionary=False,
+            compression="none",
+        )
+        return path
+
+
+def _to_arrow(data):
+    if isinstance(data, pyarrow.Table):
+        return data
+    if isinstance(data, pyarrow.RecordBatch):
+        return pyarrow.Table.from_batches([data])
+    if isinstance(data, pyarrow.RecordBatchReader):
+        return data.read_all()
+    # pandas is imported only if it's used.
+    if type(data).__module__.startswith("pandas") and type(data).__name__ == "DataFrame":
+        return pyarrow.Table.from_pandas(data, preserve_index=False)
+    raise TypeError("unsupported table data type: {}".format(type(data).__name__))
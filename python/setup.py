@@ -0,0 +1,51 @@
+import os
+import subprocess
+import sys
+
+from setuptools import setup
+from setuptools.command.build_py import build_py
+from setuptools.dist import Distribution
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+
+
+def library_name():
+    if sys.platform == "darwin":
+        return "liboctosql.dylib"
+    if sys.platform == "win32":
+        return "liboctosql.dll"
+    return "liboctosql.so"
+
+
+class BuildLibrary(build_py):
+    """Builds liboctosql using the Go toolchain, into the package."""
+
+    def run(self):
+        super().run()
+        output = os.path.join(os.path.abspath(self.build_lib), "octosql", library_name())
+        subprocess.check_call(["go", "build", "-buildmode=c-shared", "-o", output, "./cmd/liboctosql"], cwd=ROOT)
+        # The generated header is only needed by C code.
+        os.remove(os.path.splitext(output)[0] + ".h")
+
+
+class BinaryDistribution(Distribution):
+    """Makes the wheel platform specific, as it contains the library."""
+
+    def has_ext_modules(self):
+        return True
+
+
+setup(
+    name="octosql",
+    version="0.1.0",
+    description="Query files, pandas data frames and Arrow tables using SQL, with OctoSQL.",
+    url="https://github.com/cube2222/octosql",
+    license="MIT",
+    packages=["octosql"],
+    python_requires=">=3.6",
+    install_requires=["pyarrow>=4.0.0"],
+    extras_require={"pandas": ["pandas"]},
+    cmdclass={"build_py": BuildLibrary},
+    distclass=BinaryDistribution,
+    zip_safe=False,
+)
@@ -0,0 +1,56 @@
+import os
+
+import pyarrow
+import pytest
+
+import octosql
+
+FIXTURES = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "datasources", "csv", "fixtures")
+
+
+@pytest.fixture
+def ctx():
+    with octosql.Context() as ctx:
+        yield ctx
+
+
+def test_file_table(ctx):
+    ctx.register_table("people", os.path.join(FIXTURES, "people.csv"))
+
+    table = ctx.sql("SELECT p.name, p.age FROM people p WHERE p.age > 3 ORDER BY p.age")
+
+    assert table.column_names == ["p.name", "p.age"]
+    assert table.column("p.age").to_pylist() == [4, 5]
+
+
+def test_arrow_table(ctx):
+    ctx.register_table("cities", pyarrow.table({"name": ["warsaw", "ciechanowo"], "population": [1790658, 44814]}))
+
+    table = ctx.sql("SELECT c.name FROM cities c WHERE c.population > 100000")
+
+    assert table.column("c.name").to_pylist() == ["warsaw"]
+
+
+def test_join(ctx):
+    ctx.register_table("people", os.path.join(FIXTURES, "people.csv"))
+    ctx.register_table("cities", pyarrow.table({"name": ["warsaw"], "country": ["poland"]}))
+
+    reader = ctx.sql_reader("SELECT p.name, c.country FROM people p JOIN cities c ON p.city = c.name ORDER BY p.name")
+
+    assert reader.read_all().column("p.name").to_pylist() == ["jan", "kuba", "wojtek"]
+
+
+def test_errors(ctx):
+    with pytest.raises(octosql.OctoSQLError, match="couldn't register table"):
+        ctx.register_table("people", "people.txt")
+    with pytest.raises(octosql.OctoSQLError, match="couldn't run query"):
+        ctx.sql("SELECT * FROM missing m")
+    with pytest.raises(TypeError):
+        ctx.register_table("people", [1, 2, 3])
+
+
+def test_closed_context():
+    ctx = octosql.Context()
+    ctx.close()
+    with pytest.raises(octosql.OctoSQLError, match="context is closed"):
+        ctx.sql("SELECT 1")
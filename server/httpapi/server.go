@@ -0,0 +1,334 @@
+package httpapi
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"io"
+	"io/ioutil"
+	"log"
+	"mime"
+	"net"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/go-chi/chi"
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/logical"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	batcharrow "github.com/cube2222/octosql/output/batch/arrow"
+	batchcsv "github.com/cube2222/octosql/output/batch/csv"
+	batchjson "github.com/cube2222/octosql/output/batch/json"
+	streamingjson "github.com/cube2222/octosql/output/streaming/json"
+	"github.com/cube2222/octosql/parser"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// planCacheCapacity is the number of logical plans of queries kept by the server.
+const planCacheCapacity = 256
+
+// maxRequestSize is the maximum size of a request body.
+const maxRequestSize = 1 << 20
+
+type resultFormat struct {
+	contentType string
+	formatter   batch.TableFormatter
+}
+
+var resultFormats = map[string]resultFormat{
+	"json":  {contentType: "application/x-ndjson", formatter: batchjson.TableFormatter()},
+	"csv":   {contentType: "text/csv", formatter: batchcsv.TableFormatter(',')},
+	"arrow": {contentType: batcharrow.ContentType, formatter: batcharrow.TableFormatter()},
+}
+
+// Server serves queries to the configured tables over HTTP, with the results sent as JSON, CSV or in the Arrow IPC stream format.
+// There's no authentication.
+type Server struct {
+	cfg                  *config.Config
+	telemetryInfo        app.TelemetryInfo
+	dataSourceRepository *physical.DataSourceRepository
+	stateStorage         storage.Storage
+	planCache            *app.PlanCache
+}
+
+func NewServer(cfg *config.Config, telemetryInfo app.TelemetryInfo, dataSourceRepository *physical.DataSourceRepository, stateStorage storage.Storage) *Server {
+	return &Server{
+		cfg:                  cfg,
+		telemetryInfo:        telemetryInfo,
+		dataSourceRepository: dataSourceRepository,
+		stateStorage:         stateStorage,
+		planCache:            app.NewPlanCache(planCacheCapacity),
+	}
+}
+
+func (s *Server) Handler() http.Handler {
+	r := chi.NewRouter()
+	r.Post("/query", s.handleQuery)
+	return r
+}
+
+// ListenAndServe serves requests on the given TCP address until the context gets canceled.
+func (s *Server) ListenAndServe(ctx context.Context, address string) error {
+	listener, err := net.Listen("tcp", address)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't listen on %s", address)
+	}
+
+	return s.Serve(ctx, listener)
+}
+
+// Serve serves requests accepted by the listener until the context gets canceled, which also cancels the running queries.
+func (s *Server) Serve(ctx context.Context, listener net.Listener) error {
+	server := &http.Server{
+		Handler: s.Handler(),
+		BaseContext: func(net.Listener) context.Context {
+			return ctx
+		},
+	}
+	go func() {
+		<-ctx.Done()
+		server.Close()
+	}()
+
+	if err := server.Serve(listener); err != nil && err != http.ErrServerClosed {
+		return errors.Wrap(err, "couldn't serve http")
+	}
+	return ctx.Err()
+}
+
+// queryRequest is a query with the values of its parameters and the way its result should be sent.
+type queryRequest struct {
+	query      string
+	parameters map[string]octosql.Value
+	format     string
+	stream     bool
+}
+
+// parseQueryRequest reads the query from the request body, which is either the query itself,
+// or a JSON object with the query, its parameters, the format and whether to stream.
+// The format and streaming may also be given as URL query parameters, and parameters of queries sent as text as "param=name=value".
+// Without a format, it's chosen based on the Accept header.
+func parseQueryRequest(r *http.Request) (*queryRequest, error) {
+	request := &queryRequest{
+		parameters: make(map[string]octosql.Value),
+		format:     r.URL.Query().Get("format"),
+	}
+	if stream := r.URL.Query().Get("stream"); stream != "" {
+		value, err := strconv.ParseBool(stream)
+		if err != nil {
+			return nil, errors.Wrapf(err, "invalid stream value %s", stream)
+		}
+		request.stream = value
+	}
+
+	body, err := ioutil.ReadAll(io.LimitReader(r.Body, maxRequestSize+1))
+	if err != nil {
+		return nil, errors.Wrap(err, "couldn't read request body")
+	}
+	if len(body) > maxRequestSize {
+		return nil, errors.Errorf("request body is larger than %d bytes", maxRequestSize)
+	}
+
+	if mediaType, _, _ := mime.ParseMediaType(r.Header.Get("Content-Type")); mediaType == "application/json" {
+		var jsonRequest struct {
+			Query      string                 `json:"query"`
+			Parameters map[string]interface{} `json:"parameters"`
+			Format     string                 `json:"format"`
+			Stream     *bool                  `json:"stream"`
+		}
+		decoder := json.NewDecoder(bytes.NewReader(body))
+		decoder.UseNumber()
+		if err := decoder.Decode(&jsonRequest); err != nil {
+			return nil, errors.Wrap(err, "couldn't decode request")
+		}
+
+		request.query = jsonRequest.Query
+		for name, value := range jsonRequest.Parameters {
+			normalized, err := octosql.TryNormalizeType(normalizeJSONNumbers(value))
+			if err != nil {
+				return nil, errors.Wrapf(err, "invalid value of query parameter %s", name)
+			}
+			request.parameters[name] = normalized
+		}
+		if jsonRequest.Format != "" {
+			request.format = jsonRequest.Format
+		}
+		if jsonRequest.Stream != nil {
+			request.stream = *jsonRequest.Stream
+		}
+	} else {
+		request.query = string(body)
+		for _, param := range r.URL.Query()["param"] {
+			parts := strings.SplitN(param, "=", 2)
+			if len(parts) != 2 {
+				return nil, errors.Errorf("invalid query parameter %s, expected name=value", param)
+			}
+			request.parameters[parts[0]] = execution.ParseType(parts[1])
+		}
+	}
+
+	if strings.TrimSpace(request.query) == "" {
+		return nil, errors.New("no query given")
+	}
+
+	if request.format == "" {
+		request.format = "json"
+		accept := r.Header.Get("Accept")
+		if strings.Contains(accept, "text/csv") {
+			request.format = "csv"
+		} else if strings.Contains(accept, batcharrow.ContentType) {
+			request.format = "arrow"
+		}
+	}
+	if _, ok := resultFormats[request.format]; !ok {
+		return nil, errors.Errorf("invalid format %s, available formats: json, csv, arrow", request.format)
+	}
+	if request.stream && request.format != "json" {
+		return nil, errors.New("streaming is only available in the json format")
+	}
+
+	return request, nil
+}
+
+// normalizeJSONNumbers turns the numbers decoded from JSON into ints, if they're integers, or floats.
+func normalizeJSONNumbers(value interface{}) interface{} {
+	switch value := value.(type) {
+	case json.Number:
+		if i, err := value.Int64(); err == nil {
+			return i
+		}
+		f, _ := value.Float64()
+		return f
+	case []interface{}:
+		for i := range value {
+			value[i] = normalizeJSONNumbers(value[i])
+		}
+	case map[string]interface{}:
+		for k := range value {
+			value[k] = normalizeJSONNumbers(value[k])
+		}
+	}
+	return value
+}
+
+func (s *Server) handleQuery(w http.ResponseWriter, r *http.Request) {
+	request, err := parseQueryRequest(r)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err)
+		return
+	}
+
+	plan, outputOptions, err := s.planCache.GetOrCreate(request.query, s.cfg.DataSources, parser.ParseQuery)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, errors.Wrap(err, "couldn't parse query"))
+		return
+	}
+	if err := app.CheckServable(outputOptions); err != nil {
+		writeError(w, http.StatusBadRequest, err)
+		return
+	}
+
+	if request.stream {
+		s.streamQuery(w, r, request, plan, outputOptions)
+		return
+	}
+
+	format := resultFormats[request.format]
+	var result bytes.Buffer
+	var queryErr error
+	// The error which stopped the query is only given to the formatter, so it's kept instead of being formatted with the partial result.
+	formatter := func(w io.Writer, records []*execution.Record, watermark time.Time, err error) error {
+		if err != nil {
+			queryErr = err
+			return nil
+		}
+		return format.formatter(w, records, watermark, nil)
+	}
+	outputSinkFn := func(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+		sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+		return sink, batch.NewWholeTableWriterPrinter(stateStorage, sink, formatter, &result)
+	}
+	status, err := s.run(r.Context(), request, outputSinkFn, plan, outputOptions)
+	if err == nil && queryErr != nil {
+		status, err = http.StatusInternalServerError, errors.Wrap(queryErr, "couldn't run query")
+	}
+	if err != nil {
+		writeError(w, status, err)
+		return
+	}
+
+	w.Header().Set("Content-Type", format.contentType)
+	if _, err := result.WriteTo(w); err != nil {
+		log.Println("couldn't write http response: ", err)
+	}
+}
+
+// streamQuery sends every record of the result as soon as it's available, as a JSON object on a separate line.
+// Records which change an earlier result are sent as a retraction of the old record followed by the new one.
+func (s *Server) streamQuery(w http.ResponseWriter, r *http.Request, request *queryRequest, plan logical.Node, outputOptions *logical.OutputOptions) {
+	flusher, ok := w.(http.Flusher)
+	if !ok {
+		writeError(w, http.StatusInternalServerError, errors.New("streaming isn't supported by the connection"))
+		return
+	}
+
+	w.Header().Set("Content-Type", "application/x-ndjson")
+	response := &startedWriter{Writer: w}
+	outputSinkFn := app.ChangesOutputSinkFn(streamingjson.JSONWriterPrinter(response, func() error {
+		flusher.Flush()
+		return nil
+	}))
+	if status, err := s.run(r.Context(), request, outputSinkFn, plan, outputOptions); err != nil {
+		if !response.started {
+			writeError(w, status, err)
+			return
+		}
+		// The status has already been sent, so the error is sent as the last line.
+		if err := json.NewEncoder(w).Encode(map[string]string{"error": err.Error()}); err != nil {
+			log.Println("couldn't write http response: ", err)
+		}
+	}
+}
+
+// run runs the query, returning the response status to send if that fails.
+func (s *Server) run(ctx context.Context, request *queryRequest, outputSinkFn app.OutputSinkFn, plan logical.Node, outputOptions *logical.OutputOptions) (int, error) {
+	queryApp := app.NewApp(s.cfg, s.telemetryInfo, s.dataSourceRepository, outputSinkFn, false)
+
+	prepared, err := queryApp.Prepare(ctx, s.stateStorage, plan, outputOptions)
+	if err != nil {
+		return http.StatusBadRequest, errors.Wrap(err, "couldn't prepare query")
+	}
+
+	if err := queryApp.RunPrepared(ctx, s.stateStorage, prepared, request.parameters); err != nil {
+		return http.StatusInternalServerError, errors.Wrap(err, "couldn't run query")
+	}
+	return http.StatusOK, nil
+}
+
+// startedWriter records whether anything has been written, after which the response status can't be changed anymore.
+type startedWriter struct {
+	io.Writer
+	started bool
+}
+
+func (w *startedWriter) Write(data []byte) (int, error) {
+	w.started = true
+	return w.Writer.Write(data)
+}
+
+func writeError(w http.ResponseWriter, status int, err error) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	if err := json.NewEncoder(w).Encode(map[string]string{"error": err.Error()}); err != nil {
+		log.Println("couldn't write http response: ", err)
+	}
+}
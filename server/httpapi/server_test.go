@@ -0,0 +1,258 @@
+package httpapi
+
+import (
+	"fmt"
+	"io/ioutil"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/memory"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+func TestParseQueryRequest(t *testing.T) {
+	tests := []struct {
+		name        string
+		url         string
+		contentType string
+		accept      string
+		body        string
+		want        *queryRequest
+		wantErr     bool
+	}{
+		{
+			name: "text",
+			url:  "/query",
+			body: "SELECT * FROM people",
+			want: &queryRequest{query: "SELECT * FROM people", parameters: map[string]octosql.Value{}, format: "json"},
+		},
+		{
+			name: "text with parameters and format",
+			url:  "/query?format=csv&param=1=42&param=name=Jan",
+			body: "SELECT * FROM people p WHERE p.age > $1 AND p.name = :name",
+			want: &queryRequest{
+				query:      "SELECT * FROM people p WHERE p.age > $1 AND p.name = :name",
+				parameters: map[string]octosql.Value{"1": octosql.MakeInt(42), "name": octosql.MakeString("Jan")},
+				format:     "csv",
+			},
+		},
+		{
+			name:   "format from accept header",
+			url:    "/query",
+			accept: "application/vnd.apache.arrow.stream",
+			body:   "SELECT * FROM people",
+			want:   &queryRequest{query: "SELECT * FROM people", parameters: map[string]octosql.Value{}, format: "arrow"},
+		},
+		{
+			name:        "json",
+			url:         "/query?format=csv",
+			contentType: "application/json; charset=utf-8",
+			body:        `{"query": "SELECT * FROM people", "parameters": {"1": 42, "2": 1.5, "3": "Jan", "4": [1, null]}, "format": "json", "stream": true}`,
+			want: &queryRequest{
+				query: "SELECT * FROM people",
+				parameters: map[string]octosql.Value{
+					"1": octosql.MakeInt(42),
+					"2": octosql.MakeFloat(1.5),
+					"3": octosql.MakeString("Jan"),
+					"4": octosql.MakeTuple([]octosql.Value{octosql.MakeInt(1), octosql.MakeNull()}),
+				},
+				format: "json",
+				stream: true,
+			},
+		},
+		{
+			name:        "invalid json",
+			url:         "/query",
+			contentType: "application/json",
+			body:        `{"query": `,
+			wantErr:     true,
+		},
+		{
+			name:    "no query",
+			url:     "/query",
+			body:    "  ",
+			wantErr: true,
+		},
+		{
+			name:    "invalid format",
+			url:     "/query?format=xml",
+			body:    "SELECT * FROM people",
+			wantErr: true,
+		},
+		{
+			name:    "streaming csv",
+			url:     "/query?format=csv&stream=true",
+			body:    "SELECT * FROM people",
+			wantErr: true,
+		},
+		{
+			name:    "invalid parameter",
+			url:     "/query?param=42",
+			body:    "SELECT * FROM people",
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			r := httptest.NewRequest(http.MethodPost, tt.url, strings.NewReader(tt.body))
+			if tt.contentType != "" {
+				r.Header.Set("Content-Type", tt.contentType)
+			}
+			if tt.accept != "" {
+				r.Header.Set("Accept", tt.accept)
+			}
+
+			got, err := parseQueryRequest(r)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("parseQueryRequest() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("parseQueryRequest() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestServer_InvalidRequests(t *testing.T) {
+	tests := []struct {
+		name       string
+		method     string
+		body       string
+		wantStatus int
+		wantBody   string
+	}{
+		{name: "no query", method: http.MethodPost, body: "", wantStatus: http.StatusBadRequest, wantBody: `{"error":"no query given"}` + "\n"},
+		{name: "get", method: http.MethodGet, wantStatus: http.StatusMethodNotAllowed},
+	}
+
+	server := NewServer(nil, app.TelemetryInfo{}, nil, nil)
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			w := httptest.NewRecorder()
+			server.Handler().ServeHTTP(w, httptest.NewRequest(tt.method, "/query", strings.NewReader(tt.body)))
+			if w.Code != tt.wantStatus {
+				t.Errorf("status = %d, want %d", w.Code, tt.wantStatus)
+			}
+			if tt.wantBody != "" && w.Body.String() != tt.wantBody {
+				t.Errorf("body = %q, want %q", w.Body.String(), tt.wantBody)
+			}
+		})
+	}
+}
+
+func TestServer_Query(t *testing.T) {
+	repo := physical.NewDataSourceRepository()
+	if err := repo.Register("people", memory.NewDataSourceBuilderFactory([]map[string]interface{}{
+		{"id": 1, "name": "wojtek"},
+	})); err != nil {
+		t.Fatal(err)
+	}
+	server := NewServer(&config.Config{}, app.TelemetryInfo{}, repo, storage.GetTestStorage(t))
+
+	tests := []struct {
+		name       string
+		url        string
+		body       string
+		wantStatus int
+		wantPrefix string
+	}{
+		{
+			name:       "csv",
+			url:        "/query?format=csv",
+			body:       "SELECT p.name FROM people p",
+			wantStatus: http.StatusOK,
+			wantPrefix: "p.name,sys.id\nwojtek,",
+		},
+		{
+			name:       "runtime error in json",
+			url:        "/query?format=json",
+			body:       "SELECT p.id / (p.id - p.id) AS x FROM people p",
+			wantStatus: http.StatusInternalServerError,
+		},
+		{
+			name:       "runtime error in csv",
+			url:        "/query?format=csv",
+			body:       "SELECT p.id / (p.id - p.id) AS x FROM people p",
+			wantStatus: http.StatusInternalServerError,
+		},
+		{
+			name:       "runtime error in arrow",
+			url:        "/query?format=arrow",
+			body:       "SELECT p.id / (p.id - p.id) AS x FROM people p",
+			wantStatus: http.StatusInternalServerError,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			w := httptest.NewRecorder()
+			server.Handler().ServeHTTP(w, httptest.NewRequest(http.MethodPost, tt.url, strings.NewReader(tt.body)))
+			if w.Code != tt.wantStatus {
+				t.Errorf("status = %d, want %d", w.Code, tt.wantStatus)
+			}
+			if tt.wantStatus != http.StatusOK {
+				if contentType := w.Header().Get("Content-Type"); contentType != "application/json" {
+					t.Errorf("content type = %s, want application/json", contentType)
+				}
+				if !strings.Contains(w.Body.String(), "division by zero") {
+					t.Errorf("body = %q, want an error about division by zero", w.Body.String())
+				}
+			} else if !strings.HasPrefix(w.Body.String(), tt.wantPrefix) {
+				t.Errorf("body = %q, want it to start with %q", w.Body.String(), tt.wantPrefix)
+			}
+		})
+	}
+}
+
+func TestServer_UnsupportedStatements(t *testing.T) {
+	dir, err := ioutil.TempDir("", "octosql-httpapi")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+	output := filepath.Join(dir, "out.csv")
+
+	repo := physical.NewDataSourceRepository()
+	if err := repo.Register("people", memory.NewDataSourceBuilderFactory([]map[string]interface{}{
+		{"id": 1, "name": "wojtek"},
+	})); err != nil {
+		t.Fatal(err)
+	}
+	server := NewServer(&config.Config{}, app.TelemetryInfo{}, repo, storage.GetTestStorage(t))
+
+	tests := []struct {
+		name string
+		url  string
+		body string
+	}{
+		{name: "create table", url: "/query", body: fmt.Sprintf("CREATE TABLE '%s' AS SELECT p.name FROM people p", output)},
+		{name: "streamed create table", url: "/query?stream=true", body: fmt.Sprintf("CREATE TABLE '%s' AS SELECT p.name FROM people p", output)},
+		{name: "explain", url: "/query", body: "EXPLAIN SELECT p.name FROM people p"},
+		{name: "explain analyze", url: "/query", body: "EXPLAIN ANALYZE SELECT p.name FROM people p"},
+		{name: "analyze", url: "/query", body: "ANALYZE 'people.parquet'"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			w := httptest.NewRecorder()
+			server.Handler().ServeHTTP(w, httptest.NewRequest(http.MethodPost, tt.url, strings.NewReader(tt.body)))
+			if w.Code != http.StatusBadRequest {
+				t.Errorf("status = %d, want %d", w.Code, http.StatusBadRequest)
+			}
+			if !strings.Contains(w.Body.String(), "supported by the server") {
+				t.Errorf("body = %q, want an error about the statement not being supported", w.Body.String())
+			}
+		})
+	}
+
+	if _, err := os.Stat(output); !os.IsNotExist(err) {
+		t.Errorf("Stat() error = %v, want the output file not to be written", err)
+	}
+}
@@ -0,0 +1,172 @@
+package postgres
+
+import (
+	"encoding/binary"
+	"io"
+
+	"github.com/pkg/errors"
+)
+
+// Codes of the messages sent before the startup message, in place of the protocol version.
+const (
+	protocolVersion       = 196608 // 3.0
+	sslRequestCode        = 80877103
+	gssEncryptRequestCode = 80877104
+	cancelRequestCode     = 80877102
+)
+
+// maxMessageLength protects the server from allocating huge buffers for malformed messages.
+const maxMessageLength = 1 << 26
+
+var errMalformedMessage = errors.New("malformed message")
+
+// readStartupMessage reads the first message of a connection, which has no type byte.
+func readStartupMessage(r io.Reader) (int32, *messageReader, error) {
+	var header [8]byte
+	if _, err := io.ReadFull(r, header[:]); err != nil {
+		return 0, nil, errors.Wrap(err, "couldn't read startup message header")
+	}
+	length := int32(binary.BigEndian.Uint32(header[:4]))
+	if length < 8 || length > maxMessageLength {
+		return 0, nil, errors.Errorf("invalid startup message length %d", length)
+	}
+	code := int32(binary.BigEndian.Uint32(header[4:]))
+
+	body := make([]byte, length-8)
+	if _, err := io.ReadFull(r, body); err != nil {
+		return 0, nil, errors.Wrap(err, "couldn't read startup message")
+	}
+
+	return code, &messageReader{data: body}, nil
+}
+
+// readMessage reads a message sent by the client, returning its type and body.
+func readMessage(r io.Reader) (byte, *messageReader, error) {
+	var header [5]byte
+	if _, err := io.ReadFull(r, header[:]); err != nil {
+		return 0, nil, err
+	}
+	length := int32(binary.BigEndian.Uint32(header[1:]))
+	if length < 4 || length > maxMessageLength {
+		return 0, nil, errors.Errorf("invalid message length %d", length)
+	}
+
+	body := make([]byte, length-4)
+	if _, err := io.ReadFull(r, body); err != nil {
+		return 0, nil, errors.Wrap(err, "couldn't read message")
+	}
+
+	return header[0], &messageReader{data: body}, nil
+}
+
+// messageReader reads the fields of a message body.
+// Once the body turns out to be too short, all the reads return zero values and err is set.
+type messageReader struct {
+	data []byte
+	err  error
+}
+
+func (m *messageReader) take(n int) []byte {
+	if m.err != nil || n < 0 || n > len(m.data) {
+		m.err = errMalformedMessage
+		return nil
+	}
+	out := m.data[:n]
+	m.data = m.data[n:]
+	return out
+}
+
+func (m *messageReader) byte() byte {
+	data := m.take(1)
+	if data == nil {
+		return 0
+	}
+	return data[0]
+}
+
+func (m *messageReader) int16() int16 {
+	data := m.take(2)
+	if data == nil {
+		return 0
+	}
+	return int16(binary.BigEndian.Uint16(data))
+}
+
+func (m *messageReader) int32() int32 {
+	data := m.take(4)
+	if data == nil {
+		return 0
+	}
+	return int32(binary.BigEndian.Uint32(data))
+}
+
+// string reads a null terminated string.
+func (m *messageReader) string() string {
+	for i := range m.data {
+		if m.data[i] == 0 {
+			return string(m.take(i + 1)[:i])
+		}
+	}
+	m.err = errMalformedMessage
+	return ""
+}
+
+// bytes reads a value preceded by its length, which is nil if the length is -1.
+func (m *messageReader) bytes() []byte {
+	length := m.int32()
+	if length == -1 {
+		return nil
+	}
+	data := m.take(int(length))
+	if data == nil {
+		return nil
+	}
+	return append([]byte{}, data...)
+}
+
+// messageWriter builds a message sent to the client.
+type messageWriter struct {
+	data []byte
+}
+
+func newMessage(typ byte) *messageWriter {
+	return &messageWriter{data: []byte{typ, 0, 0, 0, 0}}
+}
+
+func (m *messageWriter) byte(v byte) *messageWriter {
+	m.data = append(m.data, v)
+	return m
+}
+
+func (m *messageWriter) int16(v int16) *messageWriter {
+	m.data = append(m.data, byte(v>>8), byte(v))
+	return m
+}
+
+func (m *messageWriter) int32(v int32) *messageWriter {
+	m.data = append(m.data, byte(v>>24), byte(v>>16), byte(v>>8), byte(v))
+	return m
+}
+
+// string writes a null terminated string.
+func (m *messageWriter) string(v string) *messageWriter {
+	m.data = append(m.data, v...)
+	m.data = append(m.data, 0)
+	return m
+}
+
+// bytes writes a value preceded by its length, with nil written as -1, which stands for null.
+func (m *messageWriter) bytes(v []byte) *messageWriter {
+	if v == nil {
+		return m.int32(-1)
+	}
+	m.int32(int32(len(v)))
+	m.data = append(m.data, v...)
+	return m
+}
+
+// finish fills in the length of the message and returns it.
+func (m *messageWriter) finish() []byte {
+	binary.BigEndian.PutUint32(m.data[1:5], uint32(len(m.data)-1))
+	return m.data
+}
@@ -0,0 +1,716 @@
+package postgres
+
+import (
+	"bufio"
+	"context"
+	"fmt"
+	"io"
+	"io/ioutil"
+	"log"
+	"math/rand"
+	"net"
+	"strconv"
+	"strings"
+	"sync/atomic"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/execution"
+	"github.com/cube2222/octosql/output"
+	"github.com/cube2222/octosql/output/batch"
+	"github.com/cube2222/octosql/parser"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// Error codes sent to the client.
+const (
+	codeSyntaxError           = "42601"
+	codeFeatureNotSupported   = "0A000"
+	codeProtocolViolation     = "08P01"
+	codeInvalidStatementName  = "26000"
+	codeInvalidCursorName     = "34000"
+	codeInvalidParameterValue = "22023"
+	codeInternalError         = "XX000"
+)
+
+// serverVersion is the Postgres version reported to the clients, which some of them use to choose the features they use.
+const serverVersion = "12.0 (OctoSQL)"
+
+// planCacheCapacity is the number of logical plans of queries kept by the server.
+const planCacheCapacity = 256
+
+// Server serves queries over the Postgres wire protocol, so that psql, drivers and BI tools can query the configured tables.
+// Both the simple and the extended query protocols are supported. There's no authentication.
+type Server struct {
+	cfg                  *config.Config
+	telemetryInfo        app.TelemetryInfo
+	dataSourceRepository *physical.DataSourceRepository
+	stateStorage         storage.Storage
+	planCache            *app.PlanCache
+	lastProcessID        int32
+}
+
+func NewServer(cfg *config.Config, telemetryInfo app.TelemetryInfo, dataSourceRepository *physical.DataSourceRepository, stateStorage storage.Storage) *Server {
+	return &Server{
+		cfg:                  cfg,
+		telemetryInfo:        telemetryInfo,
+		dataSourceRepository: dataSourceRepository,
+		stateStorage:         stateStorage,
+		planCache:            app.NewPlanCache(planCacheCapacity),
+	}
+}
+
+// ListenAndServe serves connections on the given TCP address until the context gets canceled.
+func (s *Server) ListenAndServe(ctx context.Context, address string) error {
+	listener, err := net.Listen("tcp", address)
+	if err != nil {
+		return errors.Wrapf(err, "couldn't listen on %s", address)
+	}
+
+	return s.Serve(ctx, listener)
+}
+
+// Serve serves connections accepted by the listener until the context gets canceled.
+func (s *Server) Serve(ctx context.Context, listener net.Listener) error {
+	go func() {
+		<-ctx.Done()
+		listener.Close()
+	}()
+
+	for {
+		netConn, err := listener.Accept()
+		if err != nil {
+			if ctx.Err() != nil {
+				return ctx.Err()
+			}
+			return errors.Wrap(err, "couldn't accept connection")
+		}
+
+		go func() {
+			defer netConn.Close()
+			if err := s.newConn(netConn).serve(ctx); err != nil && errors.Cause(err) != io.EOF {
+				log.Println("couldn't serve postgres connection: ", err)
+			}
+		}()
+	}
+}
+
+// queryError is an error which is sent to the client, after which the connection is kept.
+type queryError struct {
+	code string
+	err  error
+}
+
+func newQueryError(code string, err error) *queryError {
+	return &queryError{code: code, err: err}
+}
+
+func (e *queryError) Error() string {
+	return e.err.Error()
+}
+
+type conn struct {
+	server    *Server
+	netConn   net.Conn
+	reader    *bufio.Reader
+	writer    *bufio.Writer
+	app       *app.App
+	processID int32
+
+	statements map[string]*statement
+	portals    map[string]*portal
+
+	// After an error in the extended query protocol, messages are ignored until the next Sync.
+	ignoreUntilSync bool
+
+	// records and runErr are the output of the last query run.
+	records []*execution.Record
+	runErr  error
+}
+
+func (s *Server) newConn(netConn net.Conn) *conn {
+	c := &conn{
+		server:     s,
+		netConn:    netConn,
+		reader:     bufio.NewReader(netConn),
+		writer:     bufio.NewWriter(netConn),
+		processID:  atomic.AddInt32(&s.lastProcessID, 1),
+		statements: make(map[string]*statement),
+		portals:    make(map[string]*portal),
+	}
+	c.app = app.NewApp(s.cfg, s.telemetryInfo, s.dataSourceRepository, c.outputSinkFn, false)
+	return c
+}
+
+// outputSinkFn creates outputs which keep the records of the whole result in the connection, once the query ends.
+func (c *conn) outputSinkFn(stateStorage storage.Storage, streamID *execution.StreamID, eventTimeField octosql.VariableName, outputOptions *app.OutputOptions) (execution.IntermediateRecordStore, output.Printer) {
+	sink := batch.NewTableOutput(streamID, eventTimeField, outputOptions.OrderByExpressions, outputOptions.OrderByDirections, outputOptions.Limit, outputOptions.Offset, outputOptions.RetractionFree)
+	printer := batch.NewWholeTableWriterPrinter(stateStorage, sink, c.collectRecords, ioutil.Discard)
+	return sink, printer
+}
+
+// collectRecords keeps the result, together with the error which stopped the query, if there was one.
+func (c *conn) collectRecords(w io.Writer, records []*execution.Record, watermark time.Time, err error) error {
+	c.records = records
+	c.runErr = err
+	return nil
+}
+
+// statement is a parsed and planned query.
+type statement struct {
+	// Queries like SET or BEGIN, which BI tools and drivers send, are accepted but ignored, with their command tag sent back.
+	ignoredCommandTag string
+	empty             bool
+
+	prepared       *app.PreparedPlan
+	parameterTypes []int32
+
+	// described is the result of running the statement to describe it,
+	// which is used by its next execution, so that it doesn't run twice.
+	described *queryResult
+}
+
+// portal is a statement with the values of its parameters.
+type portal struct {
+	statement     *statement
+	parameters    map[string]octosql.Value
+	resultFormats []int16
+	result        *queryResult
+	sent          int
+}
+
+type column struct {
+	name string
+	oid  int32
+}
+
+type queryResult struct {
+	columns []column
+	rows    [][]octosql.Value
+}
+
+func (c *conn) serve(ctx context.Context) error {
+	if err := c.startUp(); err != nil {
+		return err
+	}
+
+	for {
+		typ, body, err := readMessage(c.reader)
+		if err != nil {
+			return err
+		}
+		if typ == 'X' {
+			return nil
+		}
+		if c.ignoreUntilSync && typ != 'S' {
+			continue
+		}
+
+		switch typ {
+		case 'Q':
+			err = c.handleQuery(ctx, body)
+		case 'P':
+			err = c.handleParse(ctx, body)
+		case 'B':
+			err = c.handleBind(body)
+		case 'D':
+			err = c.handleDescribe(ctx, body)
+		case 'E':
+			err = c.handleExecute(ctx, body)
+		case 'C':
+			err = c.handleClose(body)
+		case 'S':
+			c.ignoreUntilSync = false
+			err = c.sendReadyForQuery()
+		case 'H':
+			err = c.writer.Flush()
+		default:
+			err = newQueryError(codeProtocolViolation, errors.Errorf("unsupported message type %c", typ))
+		}
+
+		if queryErr, ok := err.(*queryError); ok {
+			c.sendError(queryErr)
+			if typ == 'Q' {
+				err = c.sendReadyForQuery()
+			} else {
+				c.ignoreUntilSync = true
+				err = nil
+			}
+		}
+		if err != nil {
+			return err
+		}
+	}
+}
+
+// startUp handles the startup message, sent after an optional SSL or GSSAPI encryption request, which gets declined.
+func (c *conn) startUp() error {
+	for {
+		code, body, err := readStartupMessage(c.reader)
+		if err != nil {
+			return err
+		}
+
+		switch code {
+		case sslRequestCode, gssEncryptRequestCode:
+			if _, err := c.netConn.Write([]byte{'N'}); err != nil {
+				return errors.Wrap(err, "couldn't decline encryption")
+			}
+			continue
+		case cancelRequestCode:
+			// Running queries can't be canceled.
+			return io.EOF
+		case protocolVersion:
+		default:
+			c.write(newMessage('E').
+				byte('S').string("FATAL").
+				byte('C').string(codeFeatureNotSupported).
+				byte('M').string(fmt.Sprintf("unsupported protocol version %d.%d", code>>16, code&0xffff)).
+				byte(0))
+			return c.writer.Flush()
+		}
+
+		// The parameters, like the user and the database, are ignored.
+		for body.string() != "" {
+			body.string()
+		}
+		if body.err != nil {
+			return errors.Wrap(body.err, "couldn't read startup parameters")
+		}
+
+		c.write(newMessage('R').int32(0)) // AuthenticationOk
+		for _, parameter := range [][2]string{
+			{"server_version", serverVersion},
+			{"server_encoding", "UTF8"},
+			{"client_encoding", "UTF8"},
+			{"DateStyle", "ISO, MDY"},
+			{"TimeZone", "UTC"},
+			{"integer_datetimes", "on"},
+			{"standard_conforming_strings", "on"},
+		} {
+			c.write(newMessage('S').string(parameter[0]).string(parameter[1]))
+		}
+		c.write(newMessage('K').int32(c.processID).int32(rand.Int31()))
+
+		return c.sendReadyForQuery()
+	}
+}
+
+func (c *conn) handleQuery(ctx context.Context, body *messageReader) error {
+	query := body.string()
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	stmt, err := c.prepare(ctx, query)
+	if err != nil {
+		return err
+	}
+
+	p := &portal{statement: stmt}
+	if stmt.prepared != nil {
+		if err := c.sendRowDescription(ctx, p); err != nil {
+			return err
+		}
+	}
+	if err := c.sendRows(ctx, p, 0); err != nil {
+		return err
+	}
+
+	return c.sendReadyForQuery()
+}
+
+func (c *conn) handleParse(ctx context.Context, body *messageReader) error {
+	name := body.string()
+	query := body.string()
+	parameterTypes := make([]int32, body.int16())
+	for i := range parameterTypes {
+		parameterTypes[i] = body.int32()
+	}
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	stmt, err := c.prepare(ctx, query)
+	if err != nil {
+		return err
+	}
+
+	if stmt.prepared != nil {
+		count, err := parameterCount(stmt.prepared.Parameters())
+		if err != nil {
+			return err
+		}
+		for len(parameterTypes) < count {
+			parameterTypes = append(parameterTypes, oidUnspecified)
+		}
+	}
+	stmt.parameterTypes = parameterTypes
+	c.statements[name] = stmt
+
+	c.write(newMessage('1')) // ParseComplete
+	return nil
+}
+
+func (c *conn) handleBind(body *messageReader) error {
+	portalName := body.string()
+	statementName := body.string()
+	parameterFormats := make([]int16, body.int16())
+	for i := range parameterFormats {
+		parameterFormats[i] = body.int16()
+	}
+	parameterValues := make([][]byte, body.int16())
+	for i := range parameterValues {
+		parameterValues[i] = body.bytes()
+	}
+	resultFormats := make([]int16, body.int16())
+	for i := range resultFormats {
+		resultFormats[i] = body.int16()
+	}
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	stmt, ok := c.statements[statementName]
+	if !ok {
+		return newQueryError(codeInvalidStatementName, errors.Errorf("prepared statement \"%s\" doesn't exist", statementName))
+	}
+	if len(parameterValues) != len(stmt.parameterTypes) {
+		return newQueryError(codeProtocolViolation, errors.Errorf("got %d parameters, but the statement has %d", len(parameterValues), len(stmt.parameterTypes)))
+	}
+
+	parameters := make(map[string]octosql.Value, len(parameterValues))
+	for i := range parameterValues {
+		value, err := decodeParameter(parameterValues[i], formatOf(parameterFormats, i), stmt.parameterTypes[i])
+		if err != nil {
+			return newQueryError(codeInvalidParameterValue, errors.Wrapf(err, "couldn't decode parameter $%d", i+1))
+		}
+		parameters[strconv.Itoa(i+1)] = value
+	}
+
+	p := &portal{
+		statement:     stmt,
+		parameters:    parameters,
+		resultFormats: resultFormats,
+	}
+	if len(parameters) == 0 {
+		p.result, stmt.described = stmt.described, nil
+	}
+	c.portals[portalName] = p
+
+	c.write(newMessage('2')) // BindComplete
+	return nil
+}
+
+func (c *conn) handleDescribe(ctx context.Context, body *messageReader) error {
+	kind := body.byte()
+	name := body.string()
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	switch kind {
+	case 'S':
+		stmt, ok := c.statements[name]
+		if !ok {
+			return newQueryError(codeInvalidStatementName, errors.Errorf("prepared statement \"%s\" doesn't exist", name))
+		}
+
+		description := newMessage('t').int16(int16(len(stmt.parameterTypes)))
+		for _, oid := range stmt.parameterTypes {
+			if oid == oidUnspecified {
+				oid = oidText
+			}
+			description.int32(oid)
+		}
+		c.write(description)
+
+		// The columns depend on the records, so they're only known after running the statement, which needs the parameter values.
+		if len(stmt.parameterTypes) > 0 {
+			c.write(newMessage('n')) // NoData
+			return nil
+		}
+		p := &portal{statement: stmt}
+		if err := c.sendRowDescription(ctx, p); err != nil {
+			return err
+		}
+		stmt.described = p.result
+		return nil
+
+	case 'P':
+		p, ok := c.portals[name]
+		if !ok {
+			return newQueryError(codeInvalidCursorName, errors.Errorf("portal \"%s\" doesn't exist", name))
+		}
+		return c.sendRowDescription(ctx, p)
+
+	default:
+		return newQueryError(codeProtocolViolation, errors.Errorf("invalid describe kind %c", kind))
+	}
+}
+
+func (c *conn) handleExecute(ctx context.Context, body *messageReader) error {
+	name := body.string()
+	maxRows := body.int32()
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	p, ok := c.portals[name]
+	if !ok {
+		return newQueryError(codeInvalidCursorName, errors.Errorf("portal \"%s\" doesn't exist", name))
+	}
+
+	return c.sendRows(ctx, p, int(maxRows))
+}
+
+func (c *conn) handleClose(body *messageReader) error {
+	kind := body.byte()
+	name := body.string()
+	if body.err != nil {
+		return newQueryError(codeProtocolViolation, body.err)
+	}
+
+	switch kind {
+	case 'S':
+		delete(c.statements, name)
+	case 'P':
+		delete(c.portals, name)
+	default:
+		return newQueryError(codeProtocolViolation, errors.Errorf("invalid close kind %c", kind))
+	}
+
+	c.write(newMessage('3')) // CloseComplete
+	return nil
+}
+
+// prepare parses and plans the query.
+func (c *conn) prepare(ctx context.Context, query string) (*statement, error) {
+	query = strings.TrimSpace(query)
+	query = strings.TrimSpace(strings.TrimSuffix(query, ";"))
+	if query == "" {
+		return &statement{empty: true}, nil
+	}
+	if tag, ok := ignoredCommandTag(query); ok {
+		return &statement{ignoredCommandTag: tag}, nil
+	}
+
+	plan, outputOptions, err := c.server.planCache.GetOrCreate(query, c.server.cfg.DataSources, parser.ParseQuery)
+	if err != nil {
+		return nil, newQueryError(codeSyntaxError, errors.Wrap(err, "couldn't parse query"))
+	}
+	if err := app.CheckServable(outputOptions); err != nil {
+		return nil, newQueryError(codeFeatureNotSupported, err)
+	}
+
+	prepared, err := c.app.Prepare(ctx, c.server.stateStorage, plan, outputOptions)
+	if err != nil {
+		return nil, newQueryError(codeInternalError, err)
+	}
+
+	return &statement{prepared: prepared}, nil
+}
+
+// ignoredCommandTag returns the command tag of queries which are accepted, but have no effect.
+func ignoredCommandTag(query string) (string, bool) {
+	fields := strings.Fields(strings.ToUpper(query))
+	switch fields[0] {
+	case "SET", "RESET", "DEALLOCATE", "LISTEN", "UNLISTEN":
+		return fields[0], true
+	case "BEGIN", "START":
+		return "BEGIN", true
+	case "COMMIT", "END":
+		return "COMMIT", true
+	case "ROLLBACK", "ABORT":
+		return "ROLLBACK", true
+	case "DISCARD":
+		return "DISCARD ALL", true
+	default:
+		return "", false
+	}
+}
+
+// parameterCount returns the number of parameters of the query, which have to be positional, like $1.
+func parameterCount(names []string) (int, error) {
+	count := 0
+	for _, name := range names {
+		position, err := strconv.Atoi(name)
+		if err != nil || position < 1 {
+			return 0, newQueryError(codeFeatureNotSupported, errors.Errorf("invalid parameter %s, only positional parameters like $1 are supported", name))
+		}
+		if position > count {
+			count = position
+		}
+	}
+	return count, nil
+}
+
+// run runs the statement of the portal, unless it's already been run.
+func (c *conn) run(ctx context.Context, p *portal) error {
+	if p.result != nil {
+		return nil
+	}
+
+	c.records, c.runErr = nil, nil
+	err := c.app.RunPrepared(ctx, c.server.stateStorage, p.statement.prepared, p.parameters)
+	if err == nil {
+		err = c.runErr
+	}
+	if err != nil {
+		// The records are only a part of the result, so none of them are sent.
+		c.records, c.runErr = nil, nil
+		return newQueryError(codeInternalError, err)
+	}
+
+	// Records are schemaless, so the columns are all the fields of the records.
+	result := &queryResult{}
+	columnIndices := make(map[string]int)
+	for _, record := range c.records {
+		for _, field := range record.Fields() {
+			if _, ok := columnIndices[field.Name.String()]; !ok {
+				columnIndices[field.Name.String()] = len(result.columns)
+				result.columns = append(result.columns, column{name: field.Name.String()})
+			}
+		}
+	}
+	for _, record := range c.records {
+		row := make([]octosql.Value, len(result.columns))
+		for i := range result.columns {
+			row[i] = record.Value(octosql.NewVariableName(result.columns[i].name))
+		}
+		result.rows = append(result.rows, row)
+	}
+
+	// Columns with values of a single type are sent as that type, and other ones as text.
+	for i := range result.columns {
+		columnType := octosql.TypeNull
+		for _, row := range result.rows {
+			valueType := row[i].GetType()
+			if valueType == octosql.TypeNull {
+				continue
+			}
+			if columnType == octosql.TypeNull {
+				columnType = valueType
+			} else if columnType != valueType {
+				columnType = octosql.TypeString
+				break
+			}
+		}
+		result.columns[i].oid = typeOID(columnType)
+	}
+
+	p.result = result
+	c.records = nil
+	return nil
+}
+
+func (c *conn) sendRowDescription(ctx context.Context, p *portal) error {
+	if p.statement.prepared == nil {
+		c.write(newMessage('n')) // NoData
+		return nil
+	}
+	if err := c.run(ctx, p); err != nil {
+		return err
+	}
+
+	// Each column has a name, table OID, attribute number, type OID, type size, type modifier and format.
+	description := newMessage('T').int16(int16(len(p.result.columns)))
+	for i, column := range p.result.columns {
+		description.
+			string(column.name).
+			int32(0).
+			int16(0).
+			int32(column.oid).
+			int16(typeSize(column.oid)).
+			int32(-1).
+			int16(formatOf(p.resultFormats, i))
+	}
+	c.write(description)
+	return nil
+}
+
+// sendRows sends at most maxRows of the remaining rows of the portal, or all of them if maxRows is 0.
+func (c *conn) sendRows(ctx context.Context, p *portal, maxRows int) error {
+	switch {
+	case p.statement.empty:
+		c.write(newMessage('I')) // EmptyQueryResponse
+		return nil
+	case p.statement.ignoredCommandTag != "":
+		c.write(newMessage('C').string(p.statement.ignoredCommandTag))
+		return nil
+	}
+	if err := c.run(ctx, p); err != nil {
+		return err
+	}
+
+	for sent := 0; p.sent < len(p.result.rows); sent++ {
+		if maxRows > 0 && sent == maxRows {
+			c.write(newMessage('s')) // PortalSuspended
+			return nil
+		}
+		if err := c.sendRow(p, p.result.rows[p.sent]); err != nil {
+			return err
+		}
+		p.sent++
+	}
+
+	c.write(newMessage('C').string(fmt.Sprintf("SELECT %d", len(p.result.rows))))
+	return nil
+}
+
+func (c *conn) sendRow(p *portal, row []octosql.Value) error {
+	dataRow := newMessage('D').int16(int16(len(row)))
+	for i := range row {
+		if formatOf(p.resultFormats, i) == formatBinary {
+			data, err := encodeBinary(row[i], p.result.columns[i].oid)
+			if err != nil {
+				return newQueryError(codeFeatureNotSupported, err)
+			}
+			dataRow.bytes(data)
+		} else {
+			dataRow.bytes(encodeText(row[i]))
+		}
+	}
+	c.write(dataRow)
+	return nil
+}
+
+// formatOf returns the format of the value with the given index.
+// No formats mean all the values are text, and a single format applies to all of them.
+func formatOf(formats []int16, i int) int16 {
+	switch {
+	case len(formats) == 0:
+		return formatText
+	case len(formats) == 1:
+		return formats[0]
+	case i < len(formats):
+		return formats[i]
+	default:
+		return formatText
+	}
+}
+
+func (c *conn) sendError(err *queryError) {
+	c.write(newMessage('E').
+		byte('S').string("ERROR").
+		byte('V').string("ERROR").
+		byte('C').string(err.code).
+		byte('M').string(err.Error()).
+		byte(0))
+}
+
+func (c *conn) sendReadyForQuery() error {
+	c.write(newMessage('Z').byte('I'))
+	return c.writer.Flush()
+}
+
+// write buffers the message, which gets sent on the next flush.
+// Write errors are returned by the flush too.
+func (c *conn) write(message *messageWriter) {
+	c.writer.Write(message.finish())
+}
@@ -0,0 +1,271 @@
+package postgres
+
+import (
+	"context"
+	"encoding/binary"
+	"fmt"
+	"io/ioutil"
+	"net"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/cube2222/octosql/app"
+	"github.com/cube2222/octosql/config"
+	"github.com/cube2222/octosql/datasources/memory"
+	"github.com/cube2222/octosql/physical"
+	"github.com/cube2222/octosql/storage"
+)
+
+// testClient speaks the client side of the protocol, reading the messages sent by the server.
+type testClient struct {
+	t    *testing.T
+	conn net.Conn
+}
+
+// newTestServer creates a server with a people table.
+func newTestServer(t *testing.T) *Server {
+	repo := physical.NewDataSourceRepository()
+	if err := repo.Register("people", memory.NewDataSourceBuilderFactory([]map[string]interface{}{
+		{"id": 1, "name": "wojtek"},
+		{"id": 2, "name": "janek"},
+	})); err != nil {
+		t.Fatal(err)
+	}
+
+	return NewServer(&config.Config{}, app.TelemetryInfo{}, repo, storage.GetTestStorage(t))
+}
+
+func startTestConnection(t *testing.T, server *Server) (*testClient, func()) {
+	clientConn, serverConn := net.Pipe()
+
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		if err := server.newConn(serverConn).serve(context.Background()); err != nil {
+			t.Errorf("serve() error = %v", err)
+		}
+		serverConn.Close()
+	}()
+
+	return &testClient{t: t, conn: clientConn}, func() {
+		clientConn.Write(newMessage('X').finish())
+		<-done
+		clientConn.Close()
+	}
+}
+
+func (c *testClient) send(message *messageWriter) {
+	if _, err := c.conn.Write(message.finish()); err != nil {
+		c.t.Fatal(err)
+	}
+}
+
+func (c *testClient) sendStartup(code int32, parameters ...string) {
+	m := &messageWriter{data: []byte{0, 0, 0, 0}}
+	m.int32(code)
+	for _, parameter := range parameters {
+		m.string(parameter)
+	}
+	if len(parameters) > 0 {
+		m.byte(0)
+	}
+	binary.BigEndian.PutUint32(m.data, uint32(len(m.data)))
+	if _, err := c.conn.Write(m.data); err != nil {
+		c.t.Fatal(err)
+	}
+}
+
+// receive returns the type and the body of the next message.
+func (c *testClient) receive() (byte, *messageReader) {
+	typ, body, err := readMessage(c.conn)
+	if err != nil {
+		c.t.Fatal(err)
+	}
+	return typ, body
+}
+
+// receiveUntilReady returns the types of the messages received up to and including ReadyForQuery.
+func (c *testClient) receiveUntilReady() []byte {
+	var types []byte
+	for {
+		typ, _ := c.receive()
+		types = append(types, typ)
+		if typ == 'Z' {
+			return types
+		}
+	}
+}
+
+func (c *testClient) startUp() {
+	c.sendStartup(sslRequestCode)
+	var response [1]byte
+	if _, err := c.conn.Read(response[:]); err != nil {
+		c.t.Fatal(err)
+	}
+	if response[0] != 'N' {
+		c.t.Fatalf("response to SSL request = %c, want N", response[0])
+	}
+
+	c.sendStartup(protocolVersion, "user", "octosql", "database", "octosql")
+	types := c.receiveUntilReady()
+	if types[0] != 'R' || types[len(types)-2] != 'K' {
+		c.t.Fatalf("startup messages = %q, want authentication, parameters and backend key data", types)
+	}
+}
+
+func TestServer_SimpleQuery(t *testing.T) {
+	tests := []struct {
+		query string
+		want  []byte
+	}{
+		{query: "SET client_min_messages TO warning;", want: []byte("CZ")},
+		{query: "begin", want: []byte("CZ")},
+		{query: " ; ", want: []byte("IZ")},
+	}
+
+	client, stop := startTestConnection(t, NewServer(nil, app.TelemetryInfo{}, nil, nil))
+	defer stop()
+	client.startUp()
+
+	for _, tt := range tests {
+		client.send(newMessage('Q').string(tt.query))
+		if got := client.receiveUntilReady(); !reflect.DeepEqual(got, tt.want) {
+			t.Errorf("messages for query %q = %q, want %q", tt.query, got, tt.want)
+		}
+	}
+}
+
+func TestServer_ExtendedQuery(t *testing.T) {
+	client, stop := startTestConnection(t, NewServer(nil, app.TelemetryInfo{}, nil, nil))
+	defer stop()
+	client.startUp()
+
+	client.send(newMessage('P').string("set").string("SET datestyle = 'ISO'").int16(0))
+	client.send(newMessage('B').string("").string("set").int16(0).int16(0).int16(0))
+	client.send(newMessage('D').byte('P').string(""))
+	client.send(newMessage('E').string("").int32(0))
+	client.send(newMessage('S'))
+	if got, want := client.receiveUntilReady(), []byte("12nCZ"); !reflect.DeepEqual(got, want) {
+		t.Errorf("messages = %q, want %q", got, want)
+	}
+
+	// After an error, the messages are ignored until the next Sync.
+	client.send(newMessage('B').string("").string("missing").int16(0).int16(0).int16(0))
+	client.send(newMessage('E').string("").int32(0))
+	client.send(newMessage('S'))
+	if got, want := client.receiveUntilReady(), []byte("EZ"); !reflect.DeepEqual(got, want) {
+		t.Errorf("messages = %q, want %q", got, want)
+	}
+
+	client.send(newMessage('C').byte('S').string("set"))
+	client.send(newMessage('S'))
+	if got, want := client.receiveUntilReady(), []byte("3Z"); !reflect.DeepEqual(got, want) {
+		t.Errorf("messages = %q, want %q", got, want)
+	}
+}
+
+func TestServer_ExtendedQueryWithParameter(t *testing.T) {
+	client, stop := startTestConnection(t, newTestServer(t))
+	defer stop()
+	client.startUp()
+
+	client.send(newMessage('P').string("by_id").string("SELECT p.name FROM people p WHERE p.id = $1").int16(0))
+	client.send(newMessage('B').string("").string("by_id").int16(0).int16(1).bytes([]byte("2")).int16(0))
+	client.send(newMessage('D').byte('P').string(""))
+	client.send(newMessage('E').string("").int32(0))
+	client.send(newMessage('S'))
+
+	var types []byte
+	var rows []string
+	for {
+		typ, body := client.receive()
+		types = append(types, typ)
+		switch typ {
+		case 'T':
+			if count, name := body.int16(), body.string(); count != 1 || name != "p.name" {
+				t.Errorf("row description = %d columns starting with %q, want 1 column p.name", count, name)
+			}
+		case 'D':
+			if count := body.int16(); count != 1 {
+				t.Errorf("data row has %d values, want 1", count)
+			}
+			rows = append(rows, string(body.bytes()))
+		}
+		if typ == 'Z' {
+			break
+		}
+	}
+	if want := []byte("12TDCZ"); !reflect.DeepEqual(types, want) {
+		t.Errorf("messages = %q, want %q", types, want)
+	}
+	if want := []string{"janek"}; !reflect.DeepEqual(rows, want) {
+		t.Errorf("rows = %q, want %q", rows, want)
+	}
+}
+
+func TestServer_RuntimeError(t *testing.T) {
+	client, stop := startTestConnection(t, newTestServer(t))
+	defer stop()
+	client.startUp()
+
+	// The division by zero only fails once the records are read.
+	client.send(newMessage('Q').string("SELECT p.id / (p.id - p.id) AS x FROM people p"))
+	typ, body := client.receive()
+	if typ != 'E' {
+		t.Fatalf("message = %c, want error response", typ)
+	}
+	if fields := string(body.data); !strings.Contains(fields, "C"+codeInternalError+"\x00") || !strings.Contains(fields, "division by zero") {
+		t.Errorf("error response fields = %q, want internal error code and division by zero message", fields)
+	}
+	if got, want := client.receiveUntilReady(), []byte("Z"); !reflect.DeepEqual(got, want) {
+		t.Errorf("messages = %q, want %q", got, want)
+	}
+
+	client.send(newMessage('P').string("").string("SELECT p.id / (p.id - p.id) AS x FROM people p").int16(0))
+	client.send(newMessage('B').string("").string("").int16(0).int16(0).int16(0))
+	client.send(newMessage('E').string("").int32(0))
+	client.send(newMessage('S'))
+	if got, want := client.receiveUntilReady(), []byte("12EZ"); !reflect.DeepEqual(got, want) {
+		t.Errorf("messages = %q, want %q", got, want)
+	}
+}
+
+func TestServer_UnsupportedStatements(t *testing.T) {
+	client, stop := startTestConnection(t, newTestServer(t))
+	defer stop()
+	client.startUp()
+
+	dir, err := ioutil.TempDir("", "octosql-postgres")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer os.RemoveAll(dir)
+
+	output := filepath.Join(dir, "out.csv")
+	queries := []string{
+		fmt.Sprintf("CREATE TABLE '%s' AS SELECT p.name FROM people p", output),
+		"EXPLAIN SELECT p.name FROM people p",
+		"EXPLAIN ANALYZE SELECT p.name FROM people p",
+		"ANALYZE 'people.parquet'",
+	}
+	for _, query := range queries {
+		client.send(newMessage('Q').string(query))
+		typ, body := client.receive()
+		if typ != 'E' {
+			t.Fatalf("message for query %q = %c, want error response", query, typ)
+		}
+		if fields := string(body.data); !strings.Contains(fields, "C"+codeFeatureNotSupported+"\x00") {
+			t.Errorf("error response fields for query %q = %q, want feature not supported code", query, fields)
+		}
+		if got, want := client.receiveUntilReady(), []byte("Z"); !reflect.DeepEqual(got, want) {
+			t.Errorf("messages for query %q = %q, want %q", query, got, want)
+		}
+	}
+
+	if _, err := os.Stat(output); !os.IsNotExist(err) {
+		t.Errorf("Stat() error = %v, want the output file not to be written", err)
+	}
+}
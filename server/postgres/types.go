@@ -0,0 +1,396 @@
+package postgres
+
+import (
+	"encoding/binary"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"math"
+	"math/big"
+	"net"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/pkg/errors"
+
+	"github.com/cube2222/octosql"
+	"github.com/cube2222/octosql/execution"
+)
+
+// Object IDs of the Postgres types which OctoSQL values are sent as.
+const (
+	oidUnspecified = 0
+	oidBool        = 16
+	oidBytea       = 17
+	oidName        = 19
+	oidInt8        = 20
+	oidInt2        = 21
+	oidInt4        = 23
+	oidText        = 25
+	oidJSON        = 114
+	oidFloat4      = 700
+	oidFloat8      = 701
+	oidUnknown     = 705
+	oidInet        = 869
+	oidVarchar     = 1043
+	oidTimestamp   = 1114
+	oidTimestamptz = 1184
+	oidInterval    = 1186
+	oidNumeric     = 1700
+	oidUUID        = 2950
+)
+
+const (
+	formatText   = 0
+	formatBinary = 1
+)
+
+// Postgres counts time from the beginning of 2000, in microseconds.
+var postgresEpoch = time.Date(2000, time.January, 1, 0, 0, 0, 0, time.UTC)
+
+const timestampFormat = "2006-01-02 15:04:05.999999-07:00"
+
+// typeOID returns the Postgres type which values of the given type are sent as.
+// Tuples and Objects are sent as JSON, and all the values of unknown types as text.
+func typeOID(t octosql.Type) int32 {
+	switch t {
+	case octosql.TypeInt:
+		return oidInt8
+	case octosql.TypeFloat:
+		return oidFloat8
+	case octosql.TypeBool:
+		return oidBool
+	case octosql.TypeTime:
+		return oidTimestamptz
+	case octosql.TypeDuration:
+		return oidInterval
+	case octosql.TypeTuple, octosql.TypeObject:
+		return oidJSON
+	case octosql.TypeDecimal:
+		return oidNumeric
+	case octosql.TypeUUID:
+		return oidUUID
+	case octosql.TypeIP:
+		return oidInet
+	case octosql.TypeBytes:
+		return oidBytea
+	default:
+		return oidText
+	}
+}
+
+// typeSize returns the size of values of the Postgres type, or -1 if they're variable length.
+func typeSize(oid int32) int16 {
+	switch oid {
+	case oidBool:
+		return 1
+	case oidInt8, oidFloat8, oidTimestamptz:
+		return 8
+	case oidInterval, oidUUID:
+		return 16
+	default:
+		return -1
+	}
+}
+
+// encodeText returns the Postgres text representation of the value, or nil for null.
+func encodeText(value octosql.Value) []byte {
+	switch value.GetType() {
+	case octosql.TypeNull, octosql.TypeZero:
+		return nil
+	case octosql.TypeInt:
+		return []byte(strconv.Itoa(value.AsInt()))
+	case octosql.TypeFloat:
+		f := value.AsFloat()
+		switch {
+		case math.IsInf(f, 1):
+			return []byte("Infinity")
+		case math.IsInf(f, -1):
+			return []byte("-Infinity")
+		case math.IsNaN(f):
+			return []byte("NaN")
+		}
+		return []byte(strconv.FormatFloat(f, 'g', -1, 64))
+	case octosql.TypeBool:
+		if value.AsBool() {
+			return []byte("t")
+		}
+		return []byte("f")
+	case octosql.TypeString:
+		return []byte(value.AsString())
+	case octosql.TypeTime:
+		return []byte(value.AsTime().Format(timestampFormat))
+	case octosql.TypeDuration:
+		return []byte(formatInterval(value.AsDuration()))
+	case octosql.TypeTuple, octosql.TypeObject:
+		data, err := json.Marshal(value.ToRawValue())
+		if err != nil {
+			return []byte(value.Show())
+		}
+		return data
+	case octosql.TypeDecimal:
+		return []byte(value.AsDecimal().String())
+	case octosql.TypeUUID:
+		return []byte(value.AsUUID().String())
+	case octosql.TypeIP:
+		return []byte(value.AsIP().String())
+	case octosql.TypeBytes:
+		return []byte(`\x` + hex.EncodeToString(value.AsBytes()))
+	default:
+		return []byte(value.Show())
+	}
+}
+
+// formatInterval formats the duration like Postgres formats intervals, e.g. 26:03:04.5.
+func formatInterval(d time.Duration) string {
+	sign := ""
+	if d < 0 {
+		sign = "-"
+		d = -d
+	}
+	hours := d / time.Hour
+	minutes := d % time.Hour / time.Minute
+	seconds := d % time.Minute / time.Second
+	micros := d % time.Second / time.Microsecond
+
+	out := fmt.Sprintf("%s%02d:%02d:%02d", sign, hours, minutes, seconds)
+	if micros > 0 {
+		out += strings.TrimRight(fmt.Sprintf(".%06d", micros), "0")
+	}
+	return out
+}
+
+// encodeBinary returns the Postgres binary representation of the value sent as the given type, or nil for null.
+func encodeBinary(value octosql.Value, oid int32) ([]byte, error) {
+	switch value.GetType() {
+	case octosql.TypeNull, octosql.TypeZero:
+		return nil, nil
+	}
+
+	// Columns with values of different types are sent as text, in which case the value may be of any type.
+	switch oid {
+	case oidText, oidJSON:
+		return encodeText(value), nil
+	}
+
+	out := make([]byte, 0, 16)
+	switch value.GetType() {
+	case octosql.TypeInt:
+		return appendInt64(out, int64(value.AsInt())), nil
+	case octosql.TypeFloat:
+		return appendInt64(out, int64(math.Float64bits(value.AsFloat()))), nil
+	case octosql.TypeBool:
+		if value.AsBool() {
+			return []byte{1}, nil
+		}
+		return []byte{0}, nil
+	case octosql.TypeTime:
+		micros := value.AsTime().Sub(postgresEpoch) / time.Microsecond
+		return appendInt64(out, int64(micros)), nil
+	case octosql.TypeDuration:
+		out = appendInt64(out, int64(value.AsDuration()/time.Microsecond))
+		return append(out, 0, 0, 0, 0, 0, 0, 0, 0), nil // No days and months.
+	case octosql.TypeDecimal:
+		return encodeNumeric(value.AsDecimal()), nil
+	case octosql.TypeUUID:
+		u := value.AsUUID()
+		return u[:], nil
+	case octosql.TypeIP:
+		ip := value.AsIP()
+		if ipv4 := ip.To4(); ipv4 != nil {
+			return append([]byte{2, 32, 0, net.IPv4len}, ipv4...), nil
+		}
+		return append([]byte{3, 128, 0, net.IPv6len}, ip.To16()...), nil
+	case octosql.TypeBytes:
+		return value.AsBytes(), nil
+	default:
+		return nil, errors.Errorf("binary format isn't supported for %v values", value.GetType())
+	}
+}
+
+func appendInt64(out []byte, v int64) []byte {
+	var data [8]byte
+	binary.BigEndian.PutUint64(data[:], uint64(v))
+	return append(out, data[:]...)
+}
+
+// encodeNumeric returns the binary representation of the decimal,
+// which consists of groups of 4 decimal digits, aligned at the decimal point.
+func encodeNumeric(d octosql.Decimal) []byte {
+	digits := new(big.Int).Abs(d.Unscaled()).String()
+	scale := d.Scale()
+	if scale < 0 {
+		digits += strings.Repeat("0", -scale)
+		scale = 0
+	}
+	if len(digits) <= scale {
+		digits = strings.Repeat("0", scale-len(digits)+1) + digits
+	}
+	integer := digits[:len(digits)-scale]
+	fraction := digits[len(digits)-scale:]
+	integer = strings.Repeat("0", (4-len(integer)%4)%4) + integer
+	fraction += strings.Repeat("0", (4-len(fraction)%4)%4)
+
+	var groups []int16
+	for i := 0; i < len(integer); i += 4 {
+		group, _ := strconv.Atoi(integer[i : i+4])
+		groups = append(groups, int16(group))
+	}
+	weight := len(groups) - 1
+	for i := 0; i < len(fraction); i += 4 {
+		group, _ := strconv.Atoi(fraction[i : i+4])
+		groups = append(groups, int16(group))
+	}
+
+	for len(groups) > 0 && groups[0] == 0 {
+		groups = groups[1:]
+		weight--
+	}
+	for len(groups) > 0 && groups[len(groups)-1] == 0 {
+		groups = groups[:len(groups)-1]
+	}
+	if len(groups) == 0 {
+		weight = 0
+	}
+
+	var sign int16
+	if d.Sign() < 0 {
+		sign = 0x4000
+	}
+
+	m := &messageWriter{}
+	m.int16(int16(len(groups))).int16(int16(weight)).int16(sign).int16(int16(scale))
+	for _, group := range groups {
+		m.int16(group)
+	}
+	return m.data
+}
+
+// decodeParameter decodes the value of a query parameter of the given type.
+// Text parameters of unspecified type are parsed like the ones given on the command line.
+func decodeParameter(data []byte, format int16, oid int32) (octosql.Value, error) {
+	if data == nil {
+		return octosql.MakeNull(), nil
+	}
+
+	if format == formatBinary {
+		return decodeBinaryParameter(data, oid)
+	}
+
+	text := string(data)
+	switch oid {
+	case oidUnspecified:
+		return execution.ParseType(text), nil
+	case oidText, oidVarchar, oidName, oidUnknown:
+		return octosql.MakeString(text), nil
+	case oidInt2, oidInt4, oidInt8:
+		i, err := strconv.ParseInt(text, 10, 64)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse integer")
+		}
+		return octosql.MakeInt(int(i)), nil
+	case oidFloat4, oidFloat8:
+		f, err := strconv.ParseFloat(text, 64)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse float")
+		}
+		return octosql.MakeFloat(f), nil
+	case oidBool:
+		b, err := strconv.ParseBool(text)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse bool")
+		}
+		return octosql.MakeBool(b), nil
+	case oidNumeric:
+		d, err := octosql.ParseDecimal(text)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse numeric")
+		}
+		return octosql.MakeDecimal(d), nil
+	case oidUUID:
+		u, err := octosql.ParseUUID(text)
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse uuid")
+		}
+		return octosql.MakeUUID(u), nil
+	case oidInet:
+		ip := net.ParseIP(text)
+		if ip == nil {
+			return octosql.ZeroValue(), errors.Errorf("invalid inet value %s", text)
+		}
+		return octosql.MakeIP(ip), nil
+	case oidBytea:
+		if !strings.HasPrefix(text, `\x`) {
+			return octosql.MakeBytes(data), nil
+		}
+		b, err := hex.DecodeString(text[2:])
+		if err != nil {
+			return octosql.ZeroValue(), errors.Wrap(err, "couldn't parse bytea")
+		}
+		return octosql.MakeBytes(b), nil
+	default:
+		return execution.ParseType(text), nil
+	}
+}
+
+func decodeBinaryParameter(data []byte, oid int32) (octosql.Value, error) {
+	checkLength := func(length int) error {
+		if len(data) != length {
+			return errors.Errorf("invalid length %d of binary value of type %d", len(data), oid)
+		}
+		return nil
+	}
+
+	switch oid {
+	case oidText, oidVarchar, oidName, oidUnknown:
+		return octosql.MakeString(string(data)), nil
+	case oidBytea:
+		return octosql.MakeBytes(data), nil
+	case oidBool:
+		if err := checkLength(1); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeBool(data[0] != 0), nil
+	case oidInt2:
+		if err := checkLength(2); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeInt(int(int16(binary.BigEndian.Uint16(data)))), nil
+	case oidInt4:
+		if err := checkLength(4); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeInt(int(int32(binary.BigEndian.Uint32(data)))), nil
+	case oidInt8:
+		if err := checkLength(8); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeInt(int(int64(binary.BigEndian.Uint64(data)))), nil
+	case oidFloat4:
+		if err := checkLength(4); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeFloat(float64(math.Float32frombits(binary.BigEndian.Uint32(data)))), nil
+	case oidFloat8:
+		if err := checkLength(8); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		return octosql.MakeFloat(math.Float64frombits(binary.BigEndian.Uint64(data))), nil
+	case oidTimestamp, oidTimestamptz:
+		if err := checkLength(8); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		micros := int64(binary.BigEndian.Uint64(data))
+		return octosql.MakeTime(postgresEpoch.Add(time.Duration(micros) * time.Microsecond)), nil
+	case oidUUID:
+		if err := checkLength(16); err != nil {
+			return octosql.ZeroValue(), err
+		}
+		var u octosql.UUID
+		copy(u[:], data)
+		return octosql.MakeUUID(u), nil
+	default:
+		return octosql.ZeroValue(), errors.Errorf("binary format isn't supported for parameters of type %d", oid)
+	}
+}
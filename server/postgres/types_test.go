@@ -0,0 +1,104 @@
+package postgres
+
+import (
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/cube2222/octosql"
+)
+
+func TestEncodeText(t *testing.T) {
+	decimal, err := octosql.ParseDecimal("-12.50")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	tests := []struct {
+		value octosql.Value
+		want  []byte
+	}{
+		{value: octosql.MakeNull(), want: nil},
+		{value: octosql.MakeInt(-42), want: []byte("-42")},
+		{value: octosql.MakeFloat(1.5), want: []byte("1.5")},
+		{value: octosql.MakeBool(true), want: []byte("t")},
+		{value: octosql.MakeString("text"), want: []byte("text")},
+		{value: octosql.MakeTime(time.Date(2019, 3, 17, 15, 44, 16, 500000000, time.UTC)), want: []byte("2019-03-17 15:44:16.5+00:00")},
+		{value: octosql.MakeDuration(26*time.Hour + 3*time.Minute + 4*time.Second), want: []byte("26:03:04")},
+		{value: octosql.MakeDuration(-time.Second - time.Millisecond), want: []byte("-00:00:01.001")},
+		{value: octosql.MakeDecimal(decimal), want: []byte("-12.50")},
+		{value: octosql.MakeBytes([]byte{0xde, 0xad}), want: []byte(`\xdead`)},
+		{value: octosql.MakeTuple([]octosql.Value{octosql.MakeInt(1), octosql.MakeString("a")}), want: []byte(`[1,"a"]`)},
+	}
+	for _, tt := range tests {
+		t.Run(tt.value.Show(), func(t *testing.T) {
+			if got := encodeText(tt.value); !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("encodeText() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestEncodeNumeric(t *testing.T) {
+	tests := []struct {
+		decimal string
+		want    []int16
+	}{
+		{decimal: "123.45", want: []int16{2, 0, 0, 2, 123, 4500}},
+		{decimal: "-10000.5", want: []int16{3, 1, 0x4000, 1, 1, 0, 5000}},
+		{decimal: "0.0001", want: []int16{1, -1, 0, 4, 1}},
+		{decimal: "0.00", want: []int16{0, 0, 0, 2}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.decimal, func(t *testing.T) {
+			d, err := octosql.ParseDecimal(tt.decimal)
+			if err != nil {
+				t.Fatal(err)
+			}
+			m := &messageWriter{}
+			for _, v := range tt.want {
+				m.int16(v)
+			}
+			if got := encodeNumeric(d); !reflect.DeepEqual(got, m.data) {
+				t.Errorf("encodeNumeric() = %v, want %v", got, m.data)
+			}
+		})
+	}
+}
+
+func TestDecodeParameter(t *testing.T) {
+	tests := []struct {
+		name    string
+		data    []byte
+		format  int16
+		oid     int32
+		want    octosql.Value
+		wantErr bool
+	}{
+		{name: "null", data: nil, oid: oidInt8, want: octosql.MakeNull()},
+		{name: "unspecified int", data: []byte("42"), oid: oidUnspecified, want: octosql.MakeInt(42)},
+		{name: "text", data: []byte("42"), oid: oidText, want: octosql.MakeString("42")},
+		{name: "int4", data: []byte("-7"), oid: oidInt4, want: octosql.MakeInt(-7)},
+		{name: "invalid int4", data: []byte("seven"), oid: oidInt4, wantErr: true},
+		{name: "bool", data: []byte("t"), oid: oidBool, want: octosql.MakeBool(true)},
+		{name: "bytea", data: []byte(`\x0102`), oid: oidBytea, want: octosql.MakeBytes([]byte{1, 2})},
+		{name: "binary int8", data: []byte{0, 0, 0, 0, 0, 0, 1, 0}, format: formatBinary, oid: oidInt8, want: octosql.MakeInt(256)},
+		{name: "binary int8 too short", data: []byte{1, 0}, format: formatBinary, oid: oidInt8, wantErr: true},
+		{name: "binary float8", data: []byte{0x3f, 0xf8, 0, 0, 0, 0, 0, 0}, format: formatBinary, oid: oidFloat8, want: octosql.MakeFloat(1.5)},
+		{name: "binary numeric", data: []byte{0, 0}, format: formatBinary, oid: oidNumeric, wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := decodeParameter(tt.data, tt.format, tt.oid)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("decodeParameter() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				return
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("decodeParameter() = %v, want %v", got.Show(), tt.want.Show())
+			}
+		})
+	}
+}
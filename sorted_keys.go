@@ -3,6 +3,8 @@ package octosql
 import (
 	"encoding/binary"
 	"math"
+	"math/big"
+	"net"
 	"sort"
 	"time"
 
@@ -24,12 +26,25 @@ const (
 	ObjectIdentifier    = 10 /* Object is a tuple */
 	StringDelimiter     = 11
 	TupleDelimiter      = 12
+	DecimalIdentifier   = 13 /* Sign, exponent and digits until DecimalDelimiter */
+	UUIDIdentifier      = 14 /* Bytes */
+	IPIdentifier        = 15 /* Bytes of the IPv6 form */
+	BytesIdentifier     = 16 /* Until StringDelimiter */
+)
+
+const (
+	DecimalNegative  = 0
+	DecimalZero      = 1
+	DecimalPositive  = 2
+	DecimalDelimiter = 0
 )
 
 const (
 	NumberMarshalLength      = 1 + 8 // b[0] = type, b[1:] = marshal
 	BoolMarshalLength        = 1 + 1 // b[0] = type, b[1] = 0/1
 	NonexistentMarshalLength = 1     // null and phantom
+	UUIDMarshalLength        = 1 + 16
+	IPMarshalLength          = 1 + net.IPv6len
 )
 
 const (
@@ -134,6 +149,34 @@ func (v *Value) MonotonicUnmarshal(bytes []byte) error {
 		}
 
 		finalValue = MakeObject(result)
+	case DecimalIdentifier:
+		result, err := MonotonicUnmarshalDecimal(bytes)
+		if err != nil {
+			return err
+		}
+
+		finalValue = MakeDecimal(result)
+	case UUIDIdentifier:
+		result, err := MonotonicUnmarshalUUID(bytes)
+		if err != nil {
+			return err
+		}
+
+		finalValue = MakeUUID(result)
+	case IPIdentifier:
+		result, err := MonotonicUnmarshalIP(bytes)
+		if err != nil {
+			return err
+		}
+
+		finalValue = MakeIP(result)
+	case BytesIdentifier:
+		result, err := MonotonicUnmarshalBytes(bytes)
+		if err != nil {
+			return err
+		}
+
+		finalValue = MakeBytes(result)
 	default:
 		panic("unsupported type")
 	}
@@ -164,6 +207,14 @@ func monotonicMarshal(v *Value) []byte {
 		return MonotonicMarshalTuple(v.AsSlice())
 	case TypeObject:
 		return MarshalObject(v.AsMap())
+	case TypeDecimal:
+		return MonotonicMarshalDecimal(v.AsDecimal())
+	case TypeUUID:
+		return MonotonicMarshalUUID(v.AsUUID())
+	case TypeIP:
+		return MonotonicMarshalIP(v.AsIP())
+	case TypeBytes:
+		return MonotonicMarshalBytes(v.AsBytes())
 	default:
 		panic("unknown type!")
 	}
@@ -303,6 +354,24 @@ func MonotonicUnmarshalString(b []byte) (string, error) {
 	return string(packedBytes), nil
 }
 
+/* Marshal bytes
+	Bytes are marshaled like strings, with a different identifier.
+*/
+func MonotonicMarshalBytes(b []byte) []byte {
+	bytes := MonotonicMarshalString(string(b))
+	bytes[0] = BytesIdentifier
+	return bytes
+}
+
+func MonotonicUnmarshalBytes(b []byte) ([]byte, error) {
+	s, err := MonotonicUnmarshalString(b)
+	if err != nil {
+		return nil, errors.Wrap(err, "incorrect bytes key representation")
+	}
+
+	return []byte(s), nil
+}
+
 /* Marshal Timestamp */
 func MonotonicMarshalTime(t time.Time) []byte {
 	bytes := make([]byte, 2*NumberMarshalLength)
@@ -350,6 +419,98 @@ func MonotonicUnmarshalDuration(b []byte) (time.Duration, error) {
 	return time.Duration(value), nil
 }
 
+/*	Marshal Decimal
+	Decimals equal in value have the same marshal, regardless of their scales.
+	Nonzero ones are written as 0.digits * 10^exponent, with the exponent and the digits following the sign,
+	all negated for negative decimals, so that the greater the absolute value, the smaller the marshal.
+*/
+func MonotonicMarshalDecimal(d Decimal) []byte {
+	bytes := []byte{DecimalIdentifier, DecimalZero}
+	if d.Sign() == 0 {
+		return bytes
+	}
+
+	digits, exponent := d.normalize()
+	magnitude := MonotonicMarshalInt64(int64(exponent))[1:] // Omit integer identifier
+	magnitude = append(magnitude, digits...)
+	magnitude = append(magnitude, DecimalDelimiter)
+
+	if d.Sign() > 0 {
+		bytes[1] = DecimalPositive
+	} else {
+		bytes[1] = DecimalNegative
+		for i := range magnitude {
+			magnitude[i] ^= 255
+		}
+	}
+
+	return append(bytes, magnitude...)
+}
+
+func MonotonicUnmarshalDecimal(b []byte) (Decimal, error) {
+	length, err := getDecimalMarshalLength(b)
+	if err != nil {
+		return Decimal{}, err
+	}
+	if length != len(b) {
+		return Decimal{}, errors.New("incorrect decimal key size")
+	}
+	if b[1] == DecimalZero {
+		return Decimal{}, nil
+	}
+
+	magnitude := append([]byte{}, b[2:]...)
+	if b[1] == DecimalNegative {
+		for i := range magnitude {
+			magnitude[i] ^= 255
+		}
+	}
+
+	// MonotonicUnmarshalInt64 ignores the first byte.
+	exponent, err := MonotonicUnmarshalInt64(append([]byte{IntIdentifier}, magnitude[:NumberMarshalLength-1]...))
+	if err != nil {
+		return Decimal{}, errors.Wrap(err, "incorrect exponent representation")
+	}
+	digits := string(magnitude[NumberMarshalLength-1 : len(magnitude)-1])
+
+	unscaled, ok := new(big.Int).SetString(digits, 10)
+	if !ok {
+		return Decimal{}, errors.New("incorrect decimal digits")
+	}
+	if b[1] == DecimalNegative {
+		unscaled.Neg(unscaled)
+	}
+	return NewDecimal(unscaled, len(digits)-int(exponent)), nil
+}
+
+/* Marshal UUID */
+func MonotonicMarshalUUID(u UUID) []byte {
+	return append([]byte{UUIDIdentifier}, u[:]...)
+}
+
+func MonotonicUnmarshalUUID(b []byte) (UUID, error) {
+	if len(b) != UUIDMarshalLength {
+		return UUID{}, errors.New("incorrect uuid key size")
+	}
+
+	var u UUID
+	copy(u[:], b[1:])
+	return u, nil
+}
+
+/* Marshal IP */
+func MonotonicMarshalIP(ip net.IP) []byte {
+	return append([]byte{IPIdentifier}, ip.To16()...)
+}
+
+func MonotonicUnmarshalIP(b []byte) (net.IP, error) {
+	if len(b) != IPMarshalLength {
+		return nil, errors.New("incorrect ip key size")
+	}
+
+	return net.IP(append([]byte{}, b[1:]...)), nil
+}
+
 /* Marshal Tuple */
 func MonotonicMarshalTuple(vs []Value) []byte {
 	result := make([]byte, 1)
@@ -509,8 +670,10 @@ func getMarshalLength(b []byte) (int, error) {
 
 	if isConstantLengthIdentifier(identifier) {
 		return getConstantMarshalLength(identifier)
-	} else if identifier == StringIdentifier {
+	} else if identifier == StringIdentifier || identifier == BytesIdentifier {
 		return getStringMarshalLength(b)
+	} else if identifier == DecimalIdentifier {
+		return getDecimalMarshalLength(b)
 	}
 
 	return -1, errors.New("unknown type")
@@ -526,6 +689,10 @@ func getConstantMarshalLength(identifier byte) (int, error) {
 		return NumberMarshalLength, nil
 	case BoolIdentifier:
 		return BoolMarshalLength, nil
+	case UUIDIdentifier:
+		return UUIDMarshalLength, nil
+	case IPIdentifier:
+		return IPMarshalLength, nil
 	}
 
 	return -1, errors.New("given identifier doesn't represent a constant length type")
@@ -538,8 +705,8 @@ func getStringMarshalLength(b []byte) (int, error) {
 		return -1, errors.New("invalid marshal string length")
 	}
 
-	if b[0] != StringIdentifier {
-		return -1, errors.New("expected a string, but got some other identifier")
+	if b[0] != StringIdentifier && b[0] != BytesIdentifier {
+		return -1, errors.New("expected a string or bytes, but got some other identifier")
 	}
 
 	for index := 1; index < length; index++ {
@@ -551,9 +718,36 @@ func getStringMarshalLength(b []byte) (int, error) {
 	return -1, errors.New("didn't find the StringDelimiter in string marshal")
 }
 
+func getDecimalMarshalLength(b []byte) (int, error) {
+	if len(b) < 2 || b[0] != DecimalIdentifier {
+		return -1, errors.New("invalid marshal decimal length")
+	}
+
+	var delimiter byte
+	switch b[1] {
+	case DecimalZero:
+		return 2, nil
+	case DecimalPositive:
+		delimiter = DecimalDelimiter
+	case DecimalNegative:
+		delimiter = DecimalDelimiter ^ 255
+	default:
+		return -1, errors.New("incorrect decimal sign")
+	}
+
+	// The exponent may contain any bytes, so the search for the delimiter starts after it.
+	for index := 1 + NumberMarshalLength; index < len(b); index++ {
+		if b[index] == delimiter {
+			return index + 1, nil // this is +1 because we return length and index starts at 0
+		}
+	}
+
+	return -1, errors.New("didn't find the DecimalDelimiter in decimal marshal")
+}
+
 func isConstantLengthIdentifier(identifier byte) bool {
 	switch identifier {
-	case NullIdentifier, PhantomIdentifier, IntIdentifier, BoolIdentifier, FloatIdentifier, TimestampIdentifier, DurationIdentifier:
+	case NullIdentifier, PhantomIdentifier, IntIdentifier, BoolIdentifier, FloatIdentifier, TimestampIdentifier, DurationIdentifier, UUIDIdentifier, IPIdentifier:
 		return true
 	}
 
@@ -2,6 +2,7 @@ package octosql
 
 import (
 	"math"
+	"net"
 	"reflect"
 	"testing"
 	"time"
@@ -62,6 +63,52 @@ func TestMarshal(t *testing.T) {
 				v: MakeFloat(-192.11239),
 			},
 		},
+		/* decimal tests */
+		{
+			name: "decimal test - positive decimal",
+			args: args{
+				v: MakeDecimal(mustParseDecimal("1827.128852")),
+			},
+		},
+		{
+			name: "decimal test - negative decimal",
+			args: args{
+				v: MakeDecimal(mustParseDecimal("-0.00192")),
+			},
+		},
+		{
+			name: "decimal test - zero",
+			args: args{
+				v: MakeDecimal(mustParseDecimal("0")),
+			},
+		},
+		/* uuid tests */
+		{
+			name: "uuid test",
+			args: args{
+				v: MakeUUID(mustParseUUID("123e4567-e89b-12d3-a456-426614174000")),
+			},
+		},
+		/* ip tests */
+		{
+			name: "ip test - ipv4",
+			args: args{
+				v: MakeIP(net.ParseIP("192.168.11.12")),
+			},
+		},
+		{
+			name: "ip test - ipv6",
+			args: args{
+				v: MakeIP(net.ParseIP("2001:db8::ff00:42:8329")),
+			},
+		},
+		/* bytes tests */
+		{
+			name: "bytes test",
+			args: args{
+				v: MakeBytes([]byte{0, 255, StringDelimiter, TupleDelimiter, 128}),
+			},
+		},
 		/* bool tests */
 		{
 			name: "bool test - false",
@@ -180,6 +227,8 @@ func TestMarshal(t *testing.T) {
 							MakeString("tutaj też coś"),
 						}),
 						MakeInt(TupleDelimiter),
+						MakeDecimal(mustParseDecimal("-12000")),
+						MakeUUID(mustParseUUID("0b0c0d0e-0c0b-0a09-0807-060504030201")),
 						MakeString("pa tera"),
 					}),
 					MakeInt(TupleDelimiter),
@@ -402,6 +451,73 @@ func TestIfMarshalMonotonic(t *testing.T) {
 				},
 			},
 		},
+		{
+			name: "decimal test",
+			args: args{
+				values: []Value{
+					MakeDecimal(mustParseDecimal("-123456789012345678901234567890")),
+					MakeDecimal(mustParseDecimal("-1000")),
+					MakeDecimal(mustParseDecimal("-254.15")),
+					MakeDecimal(mustParseDecimal("-254.1")),
+					MakeDecimal(mustParseDecimal("-9.99")),
+					MakeDecimal(mustParseDecimal("-0.001")),
+					MakeDecimal(mustParseDecimal("0.00")),
+					MakeDecimal(mustParseDecimal("0.001")),
+					MakeDecimal(mustParseDecimal("0.0010001")),
+					MakeDecimal(mustParseDecimal("0.1")),
+					MakeDecimal(mustParseDecimal("9.99")),
+					MakeDecimal(mustParseDecimal("10")),
+					MakeDecimal(mustParseDecimal("254.1")),
+					MakeDecimal(mustParseDecimal("254.15")),
+					MakeDecimal(mustParseDecimal("1000")),
+					MakeDecimal(mustParseDecimal("123456789012345678901234567890")),
+				},
+			},
+		},
+		{
+			name: "uuid test",
+			args: args{
+				values: []Value{
+					MakeUUID(mustParseUUID("00000000-0000-0000-0000-000000000000")),
+					MakeUUID(mustParseUUID("00000000-0000-0000-0000-0000000000ff")),
+					MakeUUID(mustParseUUID("123e4567-e89b-12d3-a456-426614174000")),
+					MakeUUID(mustParseUUID("123E4567-E89B-12D3-A456-426614174001")),
+					MakeUUID(mustParseUUID("ffffffff-ffff-ffff-ffff-ffffffffffff")),
+				},
+			},
+		},
+		{
+			name: "ip test",
+			args: args{
+				values: []Value{
+					MakeIP(net.ParseIP("::1")),
+					MakeIP(net.ParseIP("0.0.0.0")),
+					MakeIP(net.ParseIP("9.255.255.255")),
+					MakeIP(net.ParseIP("10.0.0.1")),
+					MakeIP(net.ParseIP("10.0.0.2")),
+					MakeIP(net.ParseIP("10.0.0.10")),
+					MakeIP(net.ParseIP("192.168.0.1")),
+					MakeIP(net.ParseIP("255.255.255.255")),
+					MakeIP(net.ParseIP("2001:db8::1")),
+					MakeIP(net.ParseIP("fe80::1")),
+				},
+			},
+		},
+		{
+			name: "bytes test",
+			args: args{
+				values: []Value{
+					MakeBytes([]byte{}),
+					MakeBytes([]byte{0}),
+					MakeBytes([]byte{0, 0}),
+					MakeBytes([]byte{0, 1}),
+					MakeBytes([]byte{1}),
+					MakeBytes([]byte{127, 255}),
+					MakeBytes([]byte{128}),
+					MakeBytes([]byte{255, 255, 255}),
+				},
+			},
+		},
 	}
 
 	for _, tt := range tests {
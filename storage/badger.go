@@ -0,0 +1,313 @@
+// +build !js
+
+package storage
+
+import (
+	"bytes"
+	"context"
+	"log"
+	"time"
+
+	"github.com/dgraph-io/badger/v2"
+	"github.com/golang/protobuf/proto"
+	"github.com/pkg/errors"
+)
+
+// The badger storage isn't available on js/wasm, as badger memory-maps its files using golang.org/x/sys/unix.
+// MemoryStorage can be used there instead.
+
+type BadgerStorage struct {
+	db     *badger.DB
+	prefix []byte
+	codec  Codec
+}
+
+func NewBadgerStorage(db *badger.DB) *BadgerStorage {
+	return &BadgerStorage{
+		db: db,
+	}
+}
+
+// WithValueCompression returns a copy of the storage which compresses each value using the codec.
+// It's meant for codecs badger can't compress its blocks with, like lz4.
+// Values are stored without a codec marker, so the same database always has to be opened with the same codec.
+func (bs *BadgerStorage) WithValueCompression(codec Codec) *BadgerStorage {
+	copyStorage := *bs
+	copyStorage.codec = codec
+
+	return &copyStorage
+}
+
+func (bs *BadgerStorage) BeginTransaction() StateTransaction {
+	tx := bs.db.NewTransaction(true)
+	return &badgerTransaction{tx: tx, prefix: bs.prefix, codec: bs.codec, storage: bs}
+}
+
+func (bs *BadgerStorage) DropAll(prefix []byte) error {
+	err := bs.db.DropPrefix(prefix)
+	return err
+}
+
+func (bs *BadgerStorage) WithPrefix(prefix []byte) Storage {
+	copyStorage := *bs
+	copyStorage.prefix = append(copyStorage.prefix, prefix...)
+
+	return &copyStorage
+}
+
+func (bs *BadgerStorage) Subscribe(ctx context.Context) *Subscription {
+	return NewSubscription(ctx, func(ctx context.Context, changes chan<- struct{}) error {
+		return bs.db.Subscribe(ctx, func(kv *badger.KVList) error {
+			select {
+			case changes <- struct{}{}:
+				return ErrChangeSent
+			case <-ctx.Done():
+				return ctx.Err()
+			}
+			return nil
+		}, bs.prefix)
+	})
+}
+
+// Checkpoint makes sure all committed transactions are written to disk,
+// so that a query can be resumed from them after a crash.
+func (bs *BadgerStorage) Checkpoint() error {
+	if err := bs.db.Sync(); err != nil {
+		return errors.Wrap(err, "couldn't sync badger database")
+	}
+	return nil
+}
+
+// CheckpointPeriodically creates a checkpoint every interval, until the context gets canceled.
+// It's meant for databases which don't sync each write on their own.
+func (bs *BadgerStorage) CheckpointPeriodically(ctx context.Context, interval time.Duration) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ticker.C:
+			if err := bs.Checkpoint(); err != nil {
+				log.Printf("couldn't checkpoint state storage: %s", err)
+			}
+		case <-ctx.Done():
+			return
+		}
+	}
+}
+
+func (bs *BadgerStorage) Close() error {
+	return bs.db.Close()
+}
+
+type badgerTransaction struct {
+	tx      *badger.Txn
+	prefix  []byte
+	codec   Codec
+	storage Storage
+}
+
+func (tx *badgerTransaction) getKeyWithPrefix(key []byte) []byte {
+	var buf bytes.Buffer
+	buf.Write(tx.prefix)
+	buf.Write(key)
+	return buf.Bytes()
+}
+
+func (tx *badgerTransaction) Set(key, value []byte) error {
+	if tx.codec != "" {
+		var err error
+		value, err = tx.codec.Compress(value)
+		if err != nil {
+			return errors.Wrap(err, "couldn't compress value")
+		}
+	}
+	return badgerError(tx.tx.Set(tx.getKeyWithPrefix(key), value))
+}
+
+func (tx *badgerTransaction) Get(key []byte) ([]byte, error) {
+	var value []byte
+
+	item, err := tx.tx.Get(tx.getKeyWithPrefix(key))
+	if err == badger.ErrKeyNotFound {
+		return nil, ErrNotFound
+	} else if err != nil {
+		return nil, errors.Wrap(err, "couldn't get byte key")
+	}
+
+	value, err = item.ValueCopy(value)
+	if err != nil {
+		return nil, err
+	}
+	if tx.codec != "" {
+		value, err = tx.codec.Decompress(value)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't decompress value")
+		}
+	}
+	return value, err
+}
+
+func (tx *badgerTransaction) Delete(key []byte) error {
+	return badgerError(tx.tx.Delete(tx.getKeyWithPrefix(key)))
+}
+
+func (tx *badgerTransaction) GetPrefixLength() int {
+	return len(tx.prefix)
+}
+
+func (tx *badgerTransaction) WithPrefix(prefix []byte) StateTransaction {
+	return &badgerTransaction{
+		tx:      tx.tx,
+		prefix:  tx.getKeyWithPrefix(prefix),
+		codec:   tx.codec,
+		storage: tx.storage.WithPrefix(prefix),
+	}
+}
+
+func (tx *badgerTransaction) Prefix() string {
+	return string(tx.prefix)
+}
+
+func (tx *badgerTransaction) Iterator(opts ...IteratorOption) Iterator {
+	options := &IteratorOptions{
+		Prefix: tx.prefix,
+	}
+
+	for _, opt := range opts {
+		opt(options)
+	}
+
+	it := tx.tx.NewIterator(options.ToBadgerOptions())
+
+	if options.Seek != nil {
+		it.Seek(append(append([]byte{}, options.Prefix...), options.Seek...))
+	} else if options.Reverse {
+		it.Seek(append(options.Prefix, 255))
+	} else {
+		it.Rewind()
+	}
+
+	iterator := NewBadgerIterator(it, tx.GetPrefixLength())
+	iterator.codec = tx.codec
+	return iterator
+}
+
+func (tx *badgerTransaction) Commit() error {
+	return badgerError(tx.tx.Commit())
+}
+
+func (tx *badgerTransaction) Abort() {
+	tx.tx.Discard()
+}
+
+func (tx *badgerTransaction) GetUnderlyingStorage() Storage {
+	return tx.storage
+}
+
+// badgerError replaces the badger errors the users of the storage check for with their storage equivalents.
+func badgerError(err error) error {
+	if err == badger.ErrTxnTooBig {
+		return ErrTransactionTooBig
+	}
+	if err == badger.ErrConflict {
+		return ErrConflict
+	}
+	return err
+}
+
+func (io *IteratorOptions) ToBadgerOptions() badger.IteratorOptions {
+	return badger.IteratorOptions{
+		PrefetchValues: io.PrefetchValues,
+		PrefetchSize:   io.PrefetchSize,
+		Reverse:        io.Reverse,
+		AllVersions:    io.AllVersions,
+		Prefix:         io.Prefix,
+		InternalAccess: io.InternalAccess,
+	}
+}
+
+//BadgerIterator is a wrapper around *badger.Iterator that implements
+//the Iterator interface
+type BadgerIterator struct {
+	it           *badger.Iterator
+	prefixLength int
+	codec        Codec
+}
+
+func NewBadgerIterator(it *badger.Iterator, prefixLength int) *BadgerIterator {
+	return &BadgerIterator{
+		it:           it,
+		prefixLength: prefixLength,
+	}
+}
+
+func (bi *BadgerIterator) NextWithKey(key MonotonicallySerializable, value proto.Message) error {
+	err := bi.currentKey(key)
+	if err != nil {
+		return err
+	}
+
+	err = bi.currentValue(value)
+	if err != nil {
+		return err
+	}
+
+	bi.it.Next()
+	return nil
+}
+
+func (bi *BadgerIterator) Next(value proto.Message) error {
+	err := bi.currentValue(value)
+	if err != nil {
+		return err
+	}
+
+	bi.it.Next()
+	return nil
+}
+
+func (bi *BadgerIterator) Close() error {
+	bi.it.Close()
+	return nil
+}
+
+func (bi *BadgerIterator) currentValue(value proto.Message) error {
+	if !bi.it.Valid() {
+		return ErrEndOfIterator
+	}
+
+	item := bi.it.Item() //important: this doesn't call Next()
+
+	err := item.Value(func(val []byte) error {
+		if bi.codec != "" {
+			var err error
+			val, err = bi.codec.Decompress(val)
+			if err != nil {
+				return errors.Wrap(err, "couldn't decompress value")
+			}
+		}
+		err := proto.Unmarshal(val, value)
+		return err
+	})
+
+	if err != nil {
+		return errors.Wrap(err, "couldn't unmarshal data")
+	}
+
+	return nil
+}
+
+func (bi *BadgerIterator) currentKey(key MonotonicallySerializable) error {
+	if !bi.it.Valid() {
+		return ErrEndOfIterator
+	}
+
+	item := bi.it.Item() //important: this doesn't call Next()
+
+	byteKey := item.Key()
+	strippedKey := byteKey[bi.prefixLength:] //TODO: maybe add function to do that
+
+	err := key.MonotonicUnmarshal(strippedKey)
+	return err
+}
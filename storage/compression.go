@@ -0,0 +1,86 @@
+package storage
+
+import (
+	"bytes"
+	"io/ioutil"
+
+	"github.com/golang/snappy"
+	"github.com/klauspost/compress/zstd"
+	"github.com/pierrec/lz4"
+	"github.com/pkg/errors"
+)
+
+// Codec is a compression algorithm for data written to disk, trading CPU for smaller files.
+type Codec string
+
+const (
+	CodecNone   Codec = "none"
+	CodecSnappy Codec = "snappy"
+	CodecLZ4    Codec = "lz4"
+	CodecZSTD   Codec = "zstd"
+)
+
+var Codecs = []Codec{CodecNone, CodecSnappy, CodecLZ4, CodecZSTD}
+
+func ParseCodec(name string) (Codec, error) {
+	for _, codec := range Codecs {
+		if string(codec) == name {
+			return codec, nil
+		}
+	}
+	return "", errors.Errorf("invalid compression codec %s, available codecs: %v", name, Codecs)
+}
+
+// The zstd encoder and decoder can be used concurrently, as long as only EncodeAll and DecodeAll are used.
+var zstdEncoder, _ = zstd.NewWriter(nil)
+var zstdDecoder, _ = zstd.NewReader(nil)
+
+func (codec Codec) Compress(data []byte) ([]byte, error) {
+	switch codec {
+	case CodecNone:
+		return data, nil
+	case CodecSnappy:
+		return snappy.Encode(nil, data), nil
+	case CodecLZ4:
+		var buf bytes.Buffer
+		w := lz4.NewWriter(&buf)
+		if _, err := w.Write(data); err != nil {
+			return nil, errors.Wrap(err, "couldn't compress data using lz4")
+		}
+		if err := w.Close(); err != nil {
+			return nil, errors.Wrap(err, "couldn't compress data using lz4")
+		}
+		return buf.Bytes(), nil
+	case CodecZSTD:
+		return zstdEncoder.EncodeAll(data, nil), nil
+	default:
+		return nil, errors.Errorf("invalid compression codec %s", codec)
+	}
+}
+
+func (codec Codec) Decompress(data []byte) ([]byte, error) {
+	switch codec {
+	case CodecNone:
+		return data, nil
+	case CodecSnappy:
+		out, err := snappy.Decode(nil, data)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't decompress data using snappy")
+		}
+		return out, nil
+	case CodecLZ4:
+		out, err := ioutil.ReadAll(lz4.NewReader(bytes.NewReader(data)))
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't decompress data using lz4")
+		}
+		return out, nil
+	case CodecZSTD:
+		out, err := zstdDecoder.DecodeAll(data, nil)
+		if err != nil {
+			return nil, errors.Wrap(err, "couldn't decompress data using zstd")
+		}
+		return out, nil
+	default:
+		return nil, errors.Errorf("invalid compression codec %s", codec)
+	}
+}
@@ -0,0 +1,113 @@
+package storage
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+
+	"github.com/cube2222/octosql"
+)
+
+func TestCodec(t *testing.T) {
+	inputs := [][]byte{
+		nil,
+		[]byte("a"),
+		[]byte(strings.Repeat("ala ma kota i psa ", 1000)),
+	}
+
+	for _, codec := range Codecs {
+		t.Run(string(codec), func(t *testing.T) {
+			parsed, err := ParseCodec(string(codec))
+			if err != nil {
+				t.Fatal(err)
+			}
+			if parsed != codec {
+				t.Errorf("ParseCodec() = %v, want %v", parsed, codec)
+			}
+
+			for _, input := range inputs {
+				compressed, err := codec.Compress(input)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if codec != CodecNone && len(input) > 1000 && len(compressed) >= len(input) {
+					t.Errorf("compressed %d bytes into %d bytes, want less", len(input), len(compressed))
+				}
+
+				got, err := codec.Decompress(compressed)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if !bytes.Equal(got, input) {
+					t.Errorf("Decompress(Compress(%q)) = %q", input, got)
+				}
+			}
+		})
+	}
+
+	if _, err := ParseCodec("gzip"); err == nil {
+		t.Errorf("expected an error for an unknown codec")
+	}
+}
+
+func TestBadgerStorage_WithValueCompression(t *testing.T) {
+	store := GetTestStorage(t).(*BadgerStorage)
+	compressed := store.WithValueCompression(CodecLZ4)
+
+	keys := []octosql.Value{octosql.MakeString("a"), octosql.MakeString("b")}
+	values := []octosql.Value{
+		octosql.MakeString(strings.Repeat("ala ma kota i psa ", 100)),
+		octosql.MakeInt(17238),
+	}
+
+	tx := compressed.BeginTransaction().WithPrefix([]byte("map_"))
+	m := NewMap(tx)
+	for i := range keys {
+		if err := m.Set(&keys[i], &values[i]); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if err := tx.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	tx = compressed.BeginTransaction().WithPrefix([]byte("map_"))
+	defer tx.Abort()
+	m = NewMap(tx)
+	for i := range keys {
+		var value octosql.Value
+		if err := m.Get(&keys[i], &value); err != nil {
+			t.Fatal(err)
+		}
+		if !octosql.AreEqual(value, values[i]) {
+			t.Errorf("Get(%v) = %v, want %v", keys[i], value, values[i])
+		}
+	}
+	it := m.GetIterator()
+	ok, err := TestMapIteratorCorrectness(it, keys, values)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !ok {
+		t.Errorf("the iterator returned wrong values")
+	}
+	if err := it.Close(); err != nil {
+		t.Fatal(err)
+	}
+
+	// Without the codec, the stored values are read as they are on disk.
+	rawTx := store.BeginTransaction().WithPrefix([]byte("map_"))
+	defer rawTx.Abort()
+	rawKey := keys[0].MonotonicMarshal()
+	raw, err := rawTx.Get(rawKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+	data, err := tx.Get(rawKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(raw) >= len(data) {
+		t.Errorf("stored %d bytes for a value of %d bytes, want it compressed", len(raw), len(data))
+	}
+}
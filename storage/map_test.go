@@ -70,6 +70,31 @@ func TestMap(t *testing.T) {
 		log.Fatal(errors.Wrap(err, "the iterator isn't correct"))
 	}
 
+	/* test seeking iterators */
+	it = badgerMap.GetIterator(WithSeek(keys[2].MonotonicMarshal()))
+	areEqual, err = TestMapIteratorCorrectness(it, keys[2:], values[2:])
+	_ = it.Close()
+
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	if !areEqual {
+		log.Fatal(errors.Wrap(err, "the seeking iterator isn't correct"))
+	}
+
+	it = badgerMap.GetIterator(WithReverse(), WithSeek(keys[2].MonotonicMarshal()))
+	areEqual, err = TestMapIteratorCorrectness(it, reverseValues(keys[:3]), reverseValues(values[:3]))
+	_ = it.Close()
+
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	if !areEqual {
+		log.Fatal(errors.Wrap(err, "the reverse seeking iterator isn't correct"))
+	}
+
 	/* test delete */
 	err = badgerMap.Delete(&keys[0])
 	if err != nil {
@@ -0,0 +1,467 @@
+package storage
+
+import (
+	"bytes"
+	"context"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/golang/protobuf/proto"
+	"github.com/pkg/errors"
+)
+
+// MemoryStorage keeps the state in memory, without using badger, so that it's available on js/wasm too.
+// Transactions read the state committed before they began together with their own changes,
+// which become visible to others at once when they commit.
+// Like with badger, committing a transaction fails with ErrConflict if a key it read has been changed
+// by a transaction committed after it began. Transactions have to be committed or aborted.
+type MemoryStorage struct {
+	db     *memoryDB
+	prefix []byte
+}
+
+type memoryDB struct {
+	mutex sync.Mutex
+	// The entries are sorted by key. A commit replaces the slice instead of modifying it,
+	// so transactions can keep reading the entries committed before they began.
+	entries     []memoryEntry
+	subscribers map[*memorySubscriber]struct{}
+
+	// The version is incremented by each commit with changes.
+	version uint64
+	// The keys changed by each commit, kept as long as a transaction which began before it is active.
+	commits []memoryCommit
+	active  map[*memoryTxn]struct{}
+}
+
+type memoryCommit struct {
+	version uint64
+	keys    map[string]struct{}
+}
+
+type memoryEntry struct {
+	key   []byte
+	value []byte
+}
+
+type memorySubscriber struct {
+	prefix  []byte
+	changed chan struct{}
+}
+
+func NewMemoryStorage() *MemoryStorage {
+	return &MemoryStorage{
+		db: &memoryDB{
+			subscribers: make(map[*memorySubscriber]struct{}),
+			active:      make(map[*memoryTxn]struct{}),
+		},
+	}
+}
+
+func (ms *MemoryStorage) BeginTransaction() StateTransaction {
+	txn := &memoryTxn{
+		db:     ms.db,
+		writes: make(map[string][]byte),
+		reads:  make(map[string]struct{}),
+	}
+
+	ms.db.mutex.Lock()
+	txn.entries = ms.db.entries
+	txn.readVersion = ms.db.version
+	ms.db.active[txn] = struct{}{}
+	ms.db.mutex.Unlock()
+
+	return &memoryTransaction{
+		txn:     txn,
+		prefix:  ms.prefix,
+		storage: ms,
+	}
+}
+
+func (ms *MemoryStorage) DropAll(prefix []byte) error {
+	ms.db.mutex.Lock()
+	defer ms.db.mutex.Unlock()
+
+	entries := make([]memoryEntry, 0, len(ms.db.entries))
+	for _, entry := range ms.db.entries {
+		if !bytes.HasPrefix(entry.key, prefix) {
+			entries = append(entries, entry)
+		}
+	}
+	ms.db.entries = entries
+	return nil
+}
+
+func (ms *MemoryStorage) WithPrefix(prefix []byte) Storage {
+	return &MemoryStorage{
+		db:     ms.db,
+		prefix: append(append([]byte{}, ms.prefix...), prefix...),
+	}
+}
+
+func (ms *MemoryStorage) Subscribe(ctx context.Context) *Subscription {
+	// The subscriber is registered before returning, so that no commit made afterwards gets missed.
+	subscriber := &memorySubscriber{
+		prefix:  ms.prefix,
+		changed: make(chan struct{}, 1),
+	}
+	ms.db.mutex.Lock()
+	ms.db.subscribers[subscriber] = struct{}{}
+	ms.db.mutex.Unlock()
+
+	return NewSubscription(ctx, func(ctx context.Context, changes chan<- struct{}) error {
+		defer func() {
+			ms.db.mutex.Lock()
+			delete(ms.db.subscribers, subscriber)
+			ms.db.mutex.Unlock()
+		}()
+
+		select {
+		case <-subscriber.changed:
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+
+		select {
+		case changes <- struct{}{}:
+			return ErrChangeSent
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	})
+}
+
+func (ms *MemoryStorage) Close() error {
+	return nil
+}
+
+// memoryTxn is shared by a transaction and all the transactions derived from it using WithPrefix.
+type memoryTxn struct {
+	db          *memoryDB
+	entries     []memoryEntry
+	readVersion uint64
+	// The values set by the transaction by key, with nil values for deleted keys.
+	writes map[string][]byte
+	// The keys read from the committed state, which mustn't change before the transaction commits.
+	reads map[string]struct{}
+}
+
+func (txn *memoryTxn) get(key []byte) ([]byte, bool) {
+	if value, ok := txn.writes[string(key)]; ok {
+		return value, value != nil
+	}
+	txn.reads[string(key)] = struct{}{}
+
+	i := sort.Search(len(txn.entries), func(i int) bool {
+		return bytes.Compare(txn.entries[i].key, key) >= 0
+	})
+	if i < len(txn.entries) && bytes.Equal(txn.entries[i].key, key) {
+		return txn.entries[i].value, true
+	}
+	return nil, false
+}
+
+// entriesWithPrefix returns the entries with the given prefix, as seen by the transaction, sorted by key.
+func (txn *memoryTxn) entriesWithPrefix(prefix []byte) []memoryEntry {
+	start := sort.Search(len(txn.entries), func(i int) bool {
+		return bytes.Compare(txn.entries[i].key, prefix) >= 0
+	})
+	end := start
+	for end < len(txn.entries) && bytes.HasPrefix(txn.entries[end].key, prefix) {
+		end++
+	}
+	entries := txn.entries[start:end]
+
+	var changes []memoryEntry
+	for key, value := range txn.writes {
+		if strings.HasPrefix(key, string(prefix)) {
+			changes = append(changes, memoryEntry{key: []byte(key), value: value})
+		}
+	}
+	if len(changes) == 0 {
+		return entries
+	}
+	sortEntries(changes)
+
+	return mergeEntries(entries, changes)
+}
+
+func (txn *memoryTxn) commit() error {
+	db := txn.db
+	db.mutex.Lock()
+	defer db.mutex.Unlock()
+	defer txn.end()
+
+	if len(txn.writes) == 0 {
+		return nil
+	}
+
+	for _, commit := range db.commits {
+		if commit.version <= txn.readVersion {
+			continue
+		}
+		for key := range txn.reads {
+			if _, ok := commit.keys[key]; ok {
+				return ErrConflict
+			}
+		}
+	}
+
+	changes := make([]memoryEntry, 0, len(txn.writes))
+	keys := make(map[string]struct{}, len(txn.writes))
+	for key, value := range txn.writes {
+		changes = append(changes, memoryEntry{key: []byte(key), value: value})
+		keys[key] = struct{}{}
+	}
+	sortEntries(changes)
+
+	db.version++
+	db.entries = mergeEntries(db.entries, changes)
+	db.commits = append(db.commits, memoryCommit{
+		version: db.version,
+		keys:    keys,
+	})
+
+	for subscriber := range db.subscribers {
+		for i := range changes {
+			if bytes.HasPrefix(changes[i].key, subscriber.prefix) {
+				select {
+				case subscriber.changed <- struct{}{}:
+				default:
+				}
+				break
+			}
+		}
+	}
+
+	return nil
+}
+
+// end resets the transaction and stops tracking it, dropping the commits no active transaction can conflict with.
+// The mutex of the database has to be held.
+func (txn *memoryTxn) end() {
+	txn.writes = make(map[string][]byte)
+	txn.reads = make(map[string]struct{})
+
+	db := txn.db
+	delete(db.active, txn)
+	oldest := db.version
+	for active := range db.active {
+		if active.readVersion < oldest {
+			oldest = active.readVersion
+		}
+	}
+	i := 0
+	for i < len(db.commits) && db.commits[i].version <= oldest {
+		i++
+	}
+	db.commits = db.commits[i:]
+}
+
+func sortEntries(entries []memoryEntry) {
+	sort.Slice(entries, func(i, j int) bool {
+		return bytes.Compare(entries[i].key, entries[j].key) < 0
+	})
+}
+
+// mergeEntries returns new entries with the changes applied, where changes with nil values delete the entry.
+// Both the entries and the changes have to be sorted by key.
+func mergeEntries(entries, changes []memoryEntry) []memoryEntry {
+	out := make([]memoryEntry, 0, len(entries)+len(changes))
+
+	i, j := 0, 0
+	for i < len(entries) || j < len(changes) {
+		var cmp int
+		switch {
+		case i == len(entries):
+			cmp = 1
+		case j == len(changes):
+			cmp = -1
+		default:
+			cmp = bytes.Compare(entries[i].key, changes[j].key)
+		}
+
+		if cmp < 0 {
+			out = append(out, entries[i])
+			i++
+			continue
+		}
+		if changes[j].value != nil {
+			out = append(out, changes[j])
+		}
+		if cmp == 0 {
+			i++
+		}
+		j++
+	}
+
+	return out
+}
+
+type memoryTransaction struct {
+	txn     *memoryTxn
+	prefix  []byte
+	storage Storage
+}
+
+func (tx *memoryTransaction) getKeyWithPrefix(key []byte) []byte {
+	var buf bytes.Buffer
+	buf.Write(tx.prefix)
+	buf.Write(key)
+	return buf.Bytes()
+}
+
+func (tx *memoryTransaction) Set(key, value []byte) error {
+	// Copying the value makes it non-nil even if it's empty, as nil marks deleted keys.
+	tx.txn.writes[string(tx.getKeyWithPrefix(key))] = append([]byte{}, value...)
+	return nil
+}
+
+func (tx *memoryTransaction) Get(key []byte) ([]byte, error) {
+	value, ok := tx.txn.get(tx.getKeyWithPrefix(key))
+	if !ok {
+		return nil, ErrNotFound
+	}
+	return append([]byte{}, value...), nil
+}
+
+func (tx *memoryTransaction) Delete(key []byte) error {
+	tx.txn.writes[string(tx.getKeyWithPrefix(key))] = nil
+	return nil
+}
+
+func (tx *memoryTransaction) GetPrefixLength() int {
+	return len(tx.prefix)
+}
+
+func (tx *memoryTransaction) WithPrefix(prefix []byte) StateTransaction {
+	return &memoryTransaction{
+		txn:     tx.txn,
+		prefix:  tx.getKeyWithPrefix(prefix),
+		storage: tx.storage.WithPrefix(prefix),
+	}
+}
+
+func (tx *memoryTransaction) Prefix() string {
+	return string(tx.prefix)
+}
+
+// Iterator iterates over the keys the same way the badger iterator does. It starts at the seek key if it's given,
+// otherwise at the first key with the prefix, or at the prefix followed by 0xFF when iterating in reverse.
+func (tx *memoryTransaction) Iterator(opts ...IteratorOption) Iterator {
+	options := &IteratorOptions{
+		Prefix: tx.prefix,
+	}
+
+	for _, opt := range opts {
+		opt(options)
+	}
+
+	prefix := append([]byte{}, options.Prefix...)
+	entries := tx.txn.entriesWithPrefix(prefix)
+
+	var start []byte
+	if options.Seek != nil {
+		start = append(prefix, options.Seek...)
+	} else if options.Reverse {
+		start = append(prefix, 255)
+	}
+
+	position := 0
+	if options.Reverse {
+		// The position of the last entry not greater than the start.
+		position = sort.Search(len(entries), func(i int) bool {
+			return bytes.Compare(entries[i].key, start) > 0
+		}) - 1
+	} else if start != nil {
+		position = sort.Search(len(entries), func(i int) bool {
+			return bytes.Compare(entries[i].key, start) >= 0
+		})
+	}
+
+	return &memoryIterator{
+		txn:          tx.txn,
+		entries:      entries,
+		position:     position,
+		reverse:      options.Reverse,
+		prefixLength: tx.GetPrefixLength(),
+	}
+}
+
+func (tx *memoryTransaction) Commit() error {
+	return tx.txn.commit()
+}
+
+func (tx *memoryTransaction) Abort() {
+	tx.txn.db.mutex.Lock()
+	defer tx.txn.db.mutex.Unlock()
+	tx.txn.end()
+}
+
+func (tx *memoryTransaction) GetUnderlyingStorage() Storage {
+	return tx.storage
+}
+
+type memoryIterator struct {
+	txn          *memoryTxn
+	entries      []memoryEntry
+	position     int
+	reverse      bool
+	prefixLength int
+}
+
+func (mi *memoryIterator) NextWithKey(key MonotonicallySerializable, value proto.Message) error {
+	entry, err := mi.current()
+	if err != nil {
+		return err
+	}
+
+	if err := key.MonotonicUnmarshal(entry.key[mi.prefixLength:]); err != nil {
+		return err
+	}
+	if err := proto.Unmarshal(entry.value, value); err != nil {
+		return errors.Wrap(err, "couldn't unmarshal data")
+	}
+
+	mi.advance()
+	return nil
+}
+
+func (mi *memoryIterator) Next(value proto.Message) error {
+	entry, err := mi.current()
+	if err != nil {
+		return err
+	}
+
+	if err := proto.Unmarshal(entry.value, value); err != nil {
+		return errors.Wrap(err, "couldn't unmarshal data")
+	}
+
+	mi.advance()
+	return nil
+}
+
+func (mi *memoryIterator) Close() error {
+	return nil
+}
+
+func (mi *memoryIterator) current() (memoryEntry, error) {
+	if mi.position < 0 || mi.position >= len(mi.entries) {
+		return memoryEntry{}, ErrEndOfIterator
+	}
+	entry := mi.entries[mi.position]
+	// Like with badger, the keys read using the iterator are checked for conflicts, unless the transaction has changed them.
+	if _, ok := mi.txn.writes[string(entry.key)]; !ok {
+		mi.txn.reads[string(entry.key)] = struct{}{}
+	}
+	return entry, nil
+}
+
+func (mi *memoryIterator) advance() {
+	if mi.reverse {
+		mi.position--
+	} else {
+		mi.position++
+	}
+}
@@ -0,0 +1,287 @@
+package storage
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/golang/protobuf/proto"
+
+	"github.com/cube2222/octosql"
+)
+
+func TestMemoryStorage_Transactions(t *testing.T) {
+	store := NewMemoryStorage().WithPrefix([]byte("prefix_"))
+	key := []byte("key")
+
+	tx1 := store.BeginTransaction()
+	if err := tx1.Set(key, []byte("value")); err != nil {
+		t.Fatal(err)
+	}
+	if value, err := tx1.Get(key); err != nil || string(value) != "value" {
+		t.Fatalf("Get() = %s, %v, want the value set in the transaction", value, err)
+	}
+
+	tx2 := store.BeginTransaction()
+	if err := tx1.Commit(); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := tx2.Get(key); err != ErrNotFound {
+		t.Errorf("Get() error = %v, want ErrNotFound for a value committed after the transaction began", err)
+	}
+
+	tx3 := store.BeginTransaction()
+	if value, err := tx3.Get(key); err != nil || string(value) != "value" {
+		t.Fatalf("Get() = %s, %v, want the committed value", value, err)
+	}
+	if err := tx3.Delete(key); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := tx3.Get(key); err != ErrNotFound {
+		t.Errorf("Get() error = %v, want ErrNotFound for a deleted value", err)
+	}
+	tx3.Abort()
+
+	tx4 := store.BeginTransaction()
+	if err := tx4.Set([]byte("empty"), nil); err != nil {
+		t.Fatal(err)
+	}
+	if err := tx4.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	tx5 := store.BeginTransaction()
+	if value, err := tx5.Get(key); err != nil || string(value) != "value" {
+		t.Errorf("Get() = %s, %v, want the value which was deleted in an aborted transaction", value, err)
+	}
+	if value, err := tx5.Get([]byte("empty")); err != nil || len(value) != 0 {
+		t.Errorf("Get() = %s, %v, want an empty value", value, err)
+	}
+	if _, err := store.WithPrefix([]byte("other_")).BeginTransaction().Get(key); err != ErrNotFound {
+		t.Errorf("Get() error = %v, want ErrNotFound for a value with another prefix", err)
+	}
+}
+
+func TestMemoryStorage_Iterator(t *testing.T) {
+	store := NewMemoryStorage()
+
+	keys := []octosql.Value{
+		octosql.MakeString("klucz1"),
+		octosql.MakeString("klucz2"),
+		octosql.MakeString("klucz3"),
+		octosql.MakeString("klucz4"),
+		octosql.MakeString("klucz5"),
+	}
+	values := []octosql.Value{
+		octosql.MakeNull(),
+		octosql.MakeInt(17238),
+		octosql.MakeFloat(1823.1823),
+		octosql.MakeString("ala ma kota"),
+		octosql.MakeBool(true),
+	}
+
+	// Some of the values get committed, so iterators have to merge them with the ones set in the transaction.
+	tx := store.BeginTransaction().WithPrefix([]byte("map_prefix_"))
+	m := NewMap(tx)
+	for _, i := range []int{1, 3} {
+		if err := m.Set(&keys[i], &values[i]); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if err := tx.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	tx = store.BeginTransaction().WithPrefix([]byte("map_prefix_"))
+	m = NewMap(tx)
+	for _, i := range []int{0, 2, 4} {
+		if err := m.Set(&keys[i], &values[i]); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	other := store.BeginTransaction().WithPrefix([]byte("other_prefix_"))
+	if err := NewMap(other).Set(&keys[0], &values[1]); err != nil {
+		t.Fatal(err)
+	}
+	if err := other.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	tests := []struct {
+		name       string
+		opts       []IteratorOption
+		wantKeys   []octosql.Value
+		wantValues []octosql.Value
+	}{
+		{
+			name:       "forward",
+			wantKeys:   keys,
+			wantValues: values,
+		},
+		{
+			name:       "reverse",
+			opts:       []IteratorOption{WithReverse()},
+			wantKeys:   reverseValues(keys),
+			wantValues: reverseValues(values),
+		},
+		{
+			name:       "seek",
+			opts:       []IteratorOption{WithSeek(keys[2].MonotonicMarshal())},
+			wantKeys:   keys[2:],
+			wantValues: values[2:],
+		},
+		{
+			name:       "reverse seek",
+			opts:       []IteratorOption{WithReverse(), WithSeek(keys[2].MonotonicMarshal())},
+			wantKeys:   reverseValues(keys[:3]),
+			wantValues: reverseValues(values[:3]),
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			it := m.GetIterator(tt.opts...)
+			defer it.Close()
+
+			if _, err := TestMapIteratorCorrectness(it, tt.wantKeys, tt.wantValues); err != nil {
+				t.Error(err)
+			}
+		})
+	}
+
+	if err := m.Delete(&keys[1]); err != nil {
+		t.Fatal(err)
+	}
+	it := m.GetIterator()
+	defer it.Close()
+	if _, err := TestMapIteratorCorrectness(it, []octosql.Value{keys[0], keys[2], keys[3], keys[4]}, []octosql.Value{values[0], values[2], values[3], values[4]}); err != nil {
+		t.Errorf("iterator after delete: %v", err)
+	}
+}
+
+func TestMemoryStorage_Subscribe(t *testing.T) {
+	ctx := context.Background()
+	store := NewMemoryStorage()
+
+	subscription := store.WithPrefix([]byte("a")).Subscribe(ctx)
+	defer subscription.Close()
+
+	// The subscription is registered once Subscribe returns, so there's no need to wait for it.
+	tx := store.BeginTransaction()
+	if err := tx.Set([]byte("b-key"), []byte("b-value")); err != nil {
+		t.Fatal(err)
+	}
+	if err := tx.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	waitCtx, cancel := context.WithTimeout(ctx, time.Millisecond*100)
+	defer cancel()
+	if err := subscription.ListenForChanges(waitCtx); err != context.DeadlineExceeded {
+		t.Fatalf("ListenForChanges() error = %v, want no change for a key with another prefix", err)
+	}
+
+	tx = store.BeginTransaction()
+	if err := tx.Set([]byte("a-key"), []byte("a-value")); err != nil {
+		t.Fatal(err)
+	}
+	if err := tx.Commit(); err != nil {
+		t.Fatal(err)
+	}
+
+	waitCtx, cancel = context.WithTimeout(ctx, time.Millisecond*100)
+	defer cancel()
+	if err := subscription.ListenForChanges(waitCtx); err != nil {
+		t.Fatalf("ListenForChanges() error = %v, want a change", err)
+	}
+}
+
+func TestMemoryStorage_Conflicts(t *testing.T) {
+	store := NewMemoryStorage()
+
+	// Values are encoded, so that they can be read using iterators.
+	set := func(tx StateTransaction, key string, value int) {
+		v := octosql.MakeInt(value)
+		data, err := proto.Marshal(&v)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if err := tx.Set([]byte(key), data); err != nil {
+			t.Fatal(err)
+		}
+	}
+	commit := func(tx StateTransaction) {
+		if err := tx.Commit(); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	tx := store.BeginTransaction()
+	set(tx, "counter", 0)
+	set(tx, "other", 0)
+	commit(tx)
+
+	// Both transactions read the counter, so the one committing last conflicts.
+	tx1 := store.BeginTransaction()
+	tx2 := store.BeginTransaction()
+	if _, err := tx1.Get([]byte("counter")); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := tx2.Get([]byte("counter")); err != nil {
+		t.Fatal(err)
+	}
+	set(tx1, "counter", 1)
+	set(tx2, "counter", 2)
+	commit(tx1)
+	if err := tx2.Commit(); err != ErrConflict {
+		t.Errorf("Commit() error = %v, want ErrConflict", err)
+	}
+
+	// Keys read using an iterator are checked too.
+	tx3 := store.BeginTransaction()
+	tx4 := store.BeginTransaction()
+	it := tx3.Iterator()
+	var value octosql.Value
+	for {
+		if err := it.Next(&value); err == ErrEndOfIterator {
+			break
+		} else if err != nil {
+			t.Fatal(err)
+		}
+	}
+	if err := it.Close(); err != nil {
+		t.Fatal(err)
+	}
+	set(tx3, "iterated", 1)
+	set(tx4, "other", 1)
+	commit(tx4)
+	if err := tx3.Commit(); err != ErrConflict {
+		t.Errorf("Commit() error = %v, want ErrConflict for a key read using an iterator", err)
+	}
+
+	// Transactions writing keys they didn't read, and read-only transactions, don't conflict.
+	tx5 := store.BeginTransaction()
+	tx6 := store.BeginTransaction()
+	tx7 := store.BeginTransaction()
+	if _, err := tx7.Get([]byte("counter")); err != nil {
+		t.Fatal(err)
+	}
+	set(tx5, "counter", 3)
+	set(tx6, "counter", 4)
+	commit(tx5)
+	commit(tx6)
+	commit(tx7)
+
+	tx = store.BeginTransaction()
+	data, err := tx.Get([]byte("counter"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := proto.Unmarshal(data, &value); err != nil {
+		t.Fatal(err)
+	}
+	if !octosql.AreEqual(value, octosql.MakeInt(4)) {
+		t.Errorf("Get() = %v, want the value of the last commit", value.Show())
+	}
+	tx.Abort()
+}
@@ -0,0 +1,50 @@
+package octosql
+
+import "testing"
+
+func TestDidYouMean(t *testing.T) {
+	tests := []struct {
+		name       string
+		value      string
+		candidates []string
+		want       string
+	}{
+		{
+			name:       "single typo",
+			value:      "peple",
+			candidates: []string{"cats", "people", "bikes"},
+			want:       " (did you mean people?)",
+		},
+		{
+			name:       "different case",
+			value:      "UPPER",
+			candidates: []string{"lower", "upper"},
+			want:       " (did you mean upper?)",
+		},
+		{
+			name:       "closest candidate",
+			value:      "bikess",
+			candidates: []string{"bike", "bikes"},
+			want:       " (did you mean bikes?)",
+		},
+		{
+			name:       "nothing similar",
+			value:      "animals",
+			candidates: []string{"cats", "people", "bikes"},
+			want:       "",
+		},
+		{
+			name:       "no candidates",
+			value:      "people",
+			candidates: nil,
+			want:       "",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := DidYouMean(tt.value, tt.candidates); got != tt.want {
+				t.Errorf("DidYouMean() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,68 @@
+package octosql
+
+import (
+	"sync"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+var timeZones sync.Map
+
+// LoadTimeZone returns the time zone with the given IANA name, like Europe/Warsaw, or the fixed offset from UTC, like +02:00.
+// Time zones are cached, as they're loaded for each time value which has one.
+func LoadTimeZone(name string) (*time.Location, error) {
+	if loc, ok := timeZones.Load(name); ok {
+		return loc.(*time.Location), nil
+	}
+
+	loc, err := loadTimeZone(name)
+	if err != nil {
+		return nil, err
+	}
+
+	timeZones.Store(name, loc)
+	return loc, nil
+}
+
+func loadTimeZone(name string) (*time.Location, error) {
+	if len(name) > 0 && (name[0] == '+' || name[0] == '-') {
+		for _, layout := range []string{"-07:00", "-0700", "-07"} {
+			if t, err := time.Parse(layout, name); err == nil {
+				_, offset := t.Zone()
+				return time.FixedZone("", offset), nil
+			}
+		}
+		return nil, errors.Errorf("invalid time zone offset %s", name)
+	}
+
+	loc, err := time.LoadLocation(name)
+	if err != nil {
+		return nil, errors.Wrapf(err, "unknown time zone %s", name)
+	}
+	if loc == time.UTC {
+		// Times explicitly in UTC have to keep it, unlike the ones without a time zone, which are shown in UTC too.
+		return time.FixedZone("UTC", 0), nil
+	}
+	return loc, nil
+}
+
+// timeZoneName returns the name under which the time zone of the time is kept in a value.
+// Times in UTC and in the local time zone don't keep their time zone.
+// Time zones which can't be loaded by their name, like the ones parsed from offsets, are kept as offsets.
+func timeZoneName(t time.Time) string {
+	loc := t.Location()
+	if loc == time.UTC || loc == time.Local {
+		return ""
+	}
+
+	_, offset := t.Zone()
+	if loc.String() != "" {
+		if named, err := LoadTimeZone(loc.String()); err == nil {
+			if _, namedOffset := t.In(named).Zone(); namedOffset == offset {
+				return loc.String()
+			}
+		}
+	}
+	return t.Format("-07:00")
+}
@@ -0,0 +1,52 @@
+package octosql
+
+import (
+	"crypto/rand"
+	"database/sql/driver"
+	"encoding/hex"
+
+	"github.com/pkg/errors"
+)
+
+// UUID is a universally unique identifier, kept in its 16 byte binary form.
+type UUID [16]byte
+
+// NewRandomUUID generates a random (version 4) UUID.
+func NewRandomUUID() (UUID, error) {
+	var u UUID
+	if _, err := rand.Read(u[:]); err != nil {
+		return UUID{}, errors.Wrap(err, "couldn't read random bytes")
+	}
+	u[6] = u[6]&0x0f | 0x40 // Version 4
+	u[8] = u[8]&0x3f | 0x80 // RFC 4122 variant
+	return u, nil
+}
+
+// ParseUUID parses a UUID in the canonical form, like 123e4567-e89b-12d3-a456-426614174000, in either case.
+func ParseUUID(s string) (UUID, error) {
+	var u UUID
+	if len(s) != 36 || s[8] != '-' || s[13] != '-' || s[18] != '-' || s[23] != '-' {
+		return UUID{}, errors.Errorf("invalid uuid %s", s)
+	}
+
+	digits := s[0:8] + s[9:13] + s[14:18] + s[19:23] + s[24:36]
+	if _, err := hex.Decode(u[:], []byte(digits)); err != nil {
+		return UUID{}, errors.Errorf("invalid uuid %s", s)
+	}
+	return u, nil
+}
+
+// String returns the canonical form of the UUID, in lower case.
+func (u UUID) String() string {
+	digits := hex.EncodeToString(u[:])
+	return digits[0:8] + "-" + digits[8:12] + "-" + digits[12:16] + "-" + digits[16:20] + "-" + digits[20:32]
+}
+
+func (u UUID) MarshalJSON() ([]byte, error) {
+	return []byte(`"` + u.String() + `"`), nil
+}
+
+// Value passes the UUID to SQL databases in its canonical form.
+func (u UUID) Value() (driver.Value, error) {
+	return u.String(), nil
+}
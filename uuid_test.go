@@ -0,0 +1,75 @@
+package octosql
+
+import (
+	"testing"
+)
+
+func mustParseUUID(s string) UUID {
+	u, err := ParseUUID(s)
+	if err != nil {
+		panic(err)
+	}
+	return u
+}
+
+func TestParseUUID(t *testing.T) {
+	tests := []struct {
+		input   string
+		want    string
+		wantErr bool
+	}{
+		{input: "123e4567-e89b-12d3-a456-426614174000", want: "123e4567-e89b-12d3-a456-426614174000"},
+		{input: "123E4567-E89B-12D3-A456-426614174000", want: "123e4567-e89b-12d3-a456-426614174000"},
+		{input: "00000000-0000-0000-0000-000000000000", want: "00000000-0000-0000-0000-000000000000"},
+		{input: "123e4567e89b12d3a456426614174000", wantErr: true},
+		{input: "123e4567-e89b-12d3-a456-42661417400", wantErr: true},
+		{input: "123e4567-e89b-12d3-a456_426614174000", wantErr: true},
+		{input: "123e4567-e89b-12d3-a456-42661417400g", wantErr: true},
+		{input: "", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			got, err := ParseUUID(tt.input)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseUUID() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil {
+				return
+			}
+			if got.String() != tt.want {
+				t.Errorf("ParseUUID() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestNewRandomUUID(t *testing.T) {
+	first, err := NewRandomUUID()
+	if err != nil {
+		t.Fatal(err)
+	}
+	second, err := NewRandomUUID()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if first == second {
+		t.Errorf("NewRandomUUID() returned %v twice", first)
+	}
+	if version := first.String()[14]; version != '4' {
+		t.Errorf("NewRandomUUID() version = %c, want 4", version)
+	}
+	if parsed, err := ParseUUID(first.String()); err != nil || parsed != first {
+		t.Errorf("ParseUUID(%v) = %v, %v", first, parsed, err)
+	}
+}
+
+func TestAreEqual_UUIDs(t *testing.T) {
+	u := mustParseUUID("123e4567-e89b-12d3-a456-426614174000")
+	if !AreEqual(MakeUUID(u), NormalizeType(u)) {
+		t.Errorf("AreEqual() = false for equal uuids")
+	}
+	if AreEqual(MakeUUID(u), MakeString(u.String())) {
+		t.Errorf("AreEqual() = true for a uuid and a string")
+	}
+}
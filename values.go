@@ -1,9 +1,11 @@
 package octosql
 
 import (
-	"encoding/base32"
+	"bytes"
 	"fmt"
 	"log"
+	"math/big"
+	"net"
 	"reflect"
 	"strings"
 	"time"
@@ -69,12 +71,13 @@ func ZeroString() Value {
 	return Value{Value: &Value_String_{String_: ""}}
 }
 
+// MakeTime creates a time value, which keeps the time zone of the time, unless it's UTC or the local one.
 func MakeTime(v time.Time) Value {
 	t, err := ptypes.TimestampProto(v)
 	if err != nil {
 		panic(err)
 	}
-	return Value{Value: &Value_Time{Time: t}}
+	return Value{Value: &Value_Time{Time: t}, TimeZone: timeZoneName(v)}
 }
 func ZeroTime() Value {
 	return Value{Value: &Value_Time{Time: &timestamp.Timestamp{}}}
@@ -121,72 +124,136 @@ func ZeroObject() Value {
 	}}}
 }
 
+func MakeDecimal(v Decimal) Value {
+	return Value{Value: &Value_Decimal{Decimal: &DecimalValue{
+		Unscaled: v.value().Bytes(),
+		Negative: v.Sign() < 0,
+		Scale:    int32(v.scale),
+	}}}
+}
+func ZeroDecimal() Value {
+	return Value{Value: &Value_Decimal{Decimal: &DecimalValue{}}}
+}
+
+func MakeUUID(v UUID) Value {
+	return Value{Value: &Value_Uuid{Uuid: v[:]}}
+}
+func ZeroUUID() Value {
+	return Value{Value: &Value_Uuid{Uuid: make([]byte, 16)}}
+}
+
+// MakeIP keeps IPv4 addresses in their IPv6 form, so that all the addresses are sorted by their bytes.
+func MakeIP(v net.IP) Value {
+	return Value{Value: &Value_Ip{Ip: v.To16()}}
+}
+func ZeroIP() Value {
+	return Value{Value: &Value_Ip{Ip: net.IPv4zero.To16()}}
+}
+
+func MakeBytes(v []byte) Value {
+	return Value{Value: &Value_Bytes{Bytes: v}}
+}
+func ZeroBytes() Value {
+	return Value{Value: &Value_Bytes{Bytes: []byte{}}}
+}
+
 // NormalizeType brings various primitive types into the type we want them to be.
 // All types coming out of data sources have to be already normalized this way.
 func NormalizeType(value interface{}) Value {
+	out, err := TryNormalizeType(value)
+	if err != nil {
+		log.Fatal(err)
+	}
+	return out
+}
+
+// TryNormalizeType works like NormalizeType, but returns an error instead of exiting
+// when the value, or a value nested in it, is of an unsupported type.
+func TryNormalizeType(value interface{}) (Value, error) {
 	switch value := value.(type) {
 	case nil:
-		return MakeNull()
+		return MakeNull(), nil
 	case bool:
-		return MakeBool(value)
+		return MakeBool(value), nil
 	case int:
-		return MakeInt(value)
+		return MakeInt(value), nil
 	case int8:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case int16:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case int32:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case int64:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case uint8:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case uint16:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case uint32:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case uint64:
-		return MakeInt(int(value))
+		return MakeInt(int(value)), nil
 	case float32:
-		return MakeFloat(float64(value))
+		return MakeFloat(float64(value)), nil
 	case float64:
-		return MakeFloat(value)
+		return MakeFloat(value), nil
 	case []byte:
-		return MakeString(base32.StdEncoding.EncodeToString(value))
+		return MakeBytes(value), nil
 	case string:
-		return MakeString(value)
+		return MakeString(value), nil
 	case []interface{}:
 		out := make([]Value, len(value))
 		for i := range value {
-			out[i] = NormalizeType(value[i])
+			normalized, err := TryNormalizeType(value[i])
+			if err != nil {
+				return ZeroValue(), errors.Wrapf(err, "couldn't normalize tuple element with index %d", i)
+			}
+			out[i] = normalized
 		}
-		return MakeTuple(out)
+		return MakeTuple(out), nil
 	case map[string]interface{}:
 		out := make(map[string]Value)
 		for k, v := range value {
-			out[k] = NormalizeType(v)
+			normalized, err := TryNormalizeType(v)
+			if err != nil {
+				return ZeroValue(), errors.Wrapf(err, "couldn't normalize object field %s", k)
+			}
+			out[k] = normalized
 		}
-		return MakeObject(out)
+		return MakeObject(out), nil
 	case *interface{}:
 		if value != nil {
-			return NormalizeType(*value)
+			return TryNormalizeType(*value)
 		}
-		return MakeNull()
+		return MakeNull(), nil
 	case time.Time:
-		return MakeTime(value)
+		return MakeTime(value), nil
 	case time.Duration:
-		return MakeDuration(value)
+		return MakeDuration(value), nil
+	case Decimal:
+		return MakeDecimal(value), nil
+	case UUID:
+		return MakeUUID(value), nil
+	case net.IP:
+		return MakeIP(value), nil
 	case struct{}:
-		return MakePhantom()
+		return MakePhantom(), nil
 	case Value:
-		return value
+		return value, nil
 	}
-	log.Fatalf("invalid type to normalize: %s", reflect.TypeOf(value).String())
-	panic("unreachable")
+	return ZeroValue(), errors.Errorf("invalid type to normalize: %s", reflect.TypeOf(value).String())
 }
 
 // octosql.AreEqual checks the equality of the given values, returning false if the types don't match.
+// Decimals are equal if their values are, regardless of their scales,
+// and times if they're the same instant, regardless of their time zones.
 func AreEqual(left, right Value) bool {
+	if left.GetType() == TypeDecimal && right.GetType() == TypeDecimal {
+		return left.AsDecimal().Cmp(right.AsDecimal()) == 0
+	}
+	if left.GetType() == TypeTime && right.GetType() == TypeTime {
+		return left.AsTime().Equal(right.AsTime())
+	}
 	return proto.Equal(&left, &right)
 }
 
@@ -274,6 +341,30 @@ func Compare(x, y Value) (Comparison, error) {
 		}
 
 		return 1, nil
+	case TypeDecimal:
+		if y.GetType() != TypeDecimal {
+			return 0, errors.Errorf("type mismatch between values")
+		}
+
+		return Comparison(x.AsDecimal().Cmp(y.AsDecimal())), nil
+	case TypeUUID:
+		if y.GetType() != TypeUUID {
+			return 0, errors.Errorf("type mismatch between values")
+		}
+
+		return Comparison(bytes.Compare(x.GetUuid(), y.GetUuid())), nil
+	case TypeIP:
+		if y.GetType() != TypeIP {
+			return 0, errors.Errorf("type mismatch between values")
+		}
+
+		return Comparison(bytes.Compare(x.GetIp(), y.GetIp())), nil
+	case TypeBytes:
+		if y.GetType() != TypeBytes {
+			return 0, errors.Errorf("type mismatch between values")
+		}
+
+		return Comparison(bytes.Compare(x.AsBytes(), y.AsBytes())), nil
 
 	case TypeNull, TypePhantom, TypeDuration, TypeTuple, TypeObject:
 		return 0, errors.Errorf("unsupported type in sorting")
@@ -302,11 +393,19 @@ func (v Value) AsString() string {
 	return v.GetString_()
 }
 
+// AsTime returns the time in its time zone, or in UTC if it doesn't have one.
 func (v Value) AsTime() time.Time {
 	t, err := ptypes.Timestamp(v.GetTime())
 	if err != nil {
 		panic(err)
 	}
+	if zone := v.GetTimeZone(); zone != "" {
+		loc, err := LoadTimeZone(zone)
+		if err != nil {
+			panic(err)
+		}
+		return t.In(loc)
+	}
 	return t
 }
 
@@ -327,6 +426,29 @@ func (v Value) AsSlice() []Value {
 	return out
 }
 
+func (v Value) AsDecimal() Decimal {
+	d := v.GetDecimal()
+	unscaled := new(big.Int).SetBytes(d.GetUnscaled())
+	if d.GetNegative() {
+		unscaled.Neg(unscaled)
+	}
+	return Decimal{unscaled: unscaled, scale: int(d.GetScale())}
+}
+
+func (v Value) AsUUID() UUID {
+	var u UUID
+	copy(u[:], v.GetUuid())
+	return u
+}
+
+func (v Value) AsIP() net.IP {
+	return net.IP(v.GetIp())
+}
+
+func (v Value) AsBytes() []byte {
+	return v.GetBytes()
+}
+
 func (v Value) AsMap() map[string]Value {
 	obj := v.GetObject()
 	out := make(map[string]Value)
@@ -350,6 +472,10 @@ const (
 	TypeDuration
 	TypeTuple
 	TypeObject
+	TypeDecimal
+	TypeUUID
+	TypeIP
+	TypeBytes
 )
 
 func (t Type) String() string {
@@ -376,6 +502,14 @@ func (t Type) String() string {
 		return "Tuple"
 	case TypeObject:
 		return "Object"
+	case TypeDecimal:
+		return "Decimal"
+	case TypeUUID:
+		return "UUID"
+	case TypeIP:
+		return "IP"
+	case TypeBytes:
+		return "Bytes"
 	default:
 		panic("invalid type")
 	}
@@ -404,6 +538,14 @@ func (v Value) GetType() Type {
 		return TypeTuple
 	case *Value_Object:
 		return TypeObject
+	case *Value_Decimal:
+		return TypeDecimal
+	case *Value_Uuid:
+		return TypeUUID
+	case *Value_Ip:
+		return TypeIP
+	case *Value_Bytes:
+		return TypeBytes
 	default:
 		return TypeZero
 	}
@@ -433,6 +575,14 @@ func (v Value) Document() docs.Documentation {
 		return docs.Text("Tuple")
 	case TypeObject:
 		return docs.Text("Object")
+	case TypeDecimal:
+		return docs.Text("Decimal")
+	case TypeUUID:
+		return docs.Text("UUID")
+	case TypeIP:
+		return docs.Text("IP")
+	case TypeBytes:
+		return docs.Text("Bytes")
 	default:
 		panic("invalid type")
 	}
@@ -470,6 +620,14 @@ func (v Value) Show() string {
 			pairStrings = append(pairStrings, fmt.Sprintf("%s: %s", k, v.Show()))
 		}
 		return fmt.Sprintf("{%s}", strings.Join(pairStrings, ", "))
+	case TypeDecimal:
+		return v.AsDecimal().String()
+	case TypeUUID:
+		return v.AsUUID().String()
+	case TypeIP:
+		return v.AsIP().String()
+	case TypeBytes:
+		return fmt.Sprintf("x'%x'", v.AsBytes())
 	default:
 		panic("invalid type")
 	}
@@ -507,6 +665,14 @@ func (v Value) ToRawValue() interface{} {
 			out[k] = v.ToRawValue()
 		}
 		return out
+	case TypeDecimal:
+		return v.AsDecimal()
+	case TypeUUID:
+		return v.AsUUID()
+	case TypeIP:
+		return v.AsIP()
+	case TypeBytes:
+		return v.AsBytes()
 	default:
 		return nil
 	}
@@ -34,7 +34,12 @@ type Value struct {
 	//	*Value_Duration
 	//	*Value_Tuple
 	//	*Value_Object
+	//	*Value_Decimal
+	//	*Value_Uuid
+	//	*Value_Ip
+	//	*Value_Bytes
 	Value                isValue_Value `protobuf_oneof:"value"`
+	TimeZone             string        `protobuf:"bytes,12,opt,name=time_zone,json=timeZone,proto3" json:"time_zone,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}      `json:"-"`
 	XXX_unrecognized     []byte        `json:"-"`
 	XXX_sizecache        int32         `json:"-"`
@@ -109,6 +114,22 @@ type Value_Object struct {
 	Object *Object `protobuf:"bytes,10,opt,name=object,proto3,oneof"`
 }
 
+type Value_Decimal struct {
+	Decimal *DecimalValue `protobuf:"bytes,11,opt,name=decimal,proto3,oneof"`
+}
+
+type Value_Uuid struct {
+	Uuid []byte `protobuf:"bytes,13,opt,name=uuid,proto3,oneof"`
+}
+
+type Value_Ip struct {
+	Ip []byte `protobuf:"bytes,14,opt,name=ip,proto3,oneof"`
+}
+
+type Value_Bytes struct {
+	Bytes []byte `protobuf:"bytes,15,opt,name=bytes,proto3,oneof"`
+}
+
 func (*Value_Null) isValue_Value() {}
 
 func (*Value_Phantom) isValue_Value() {}
@@ -129,6 +150,14 @@ func (*Value_Tuple) isValue_Value() {}
 
 func (*Value_Object) isValue_Value() {}
 
+func (*Value_Decimal) isValue_Value() {}
+
+func (*Value_Uuid) isValue_Value() {}
+
+func (*Value_Ip) isValue_Value() {}
+
+func (*Value_Bytes) isValue_Value() {}
+
 func (m *Value) GetValue() isValue_Value {
 	if m != nil {
 		return m.Value
@@ -206,6 +235,41 @@ func (m *Value) GetObject() *Object {
 	return nil
 }
 
+func (m *Value) GetDecimal() *DecimalValue {
+	if x, ok := m.GetValue().(*Value_Decimal); ok {
+		return x.Decimal
+	}
+	return nil
+}
+
+func (m *Value) GetUuid() []byte {
+	if x, ok := m.GetValue().(*Value_Uuid); ok {
+		return x.Uuid
+	}
+	return nil
+}
+
+func (m *Value) GetIp() []byte {
+	if x, ok := m.GetValue().(*Value_Ip); ok {
+		return x.Ip
+	}
+	return nil
+}
+
+func (m *Value) GetBytes() []byte {
+	if x, ok := m.GetValue().(*Value_Bytes); ok {
+		return x.Bytes
+	}
+	return nil
+}
+
+func (m *Value) GetTimeZone() string {
+	if m != nil {
+		return m.TimeZone
+	}
+	return ""
+}
+
 // XXX_OneofWrappers is for the internal use of the proto package.
 func (*Value) XXX_OneofWrappers() []interface{} {
 	return []interface{}{
@@ -219,6 +283,10 @@ func (*Value) XXX_OneofWrappers() []interface{} {
 		(*Value_Duration)(nil),
 		(*Value_Tuple)(nil),
 		(*Value_Object)(nil),
+		(*Value_Decimal)(nil),
+		(*Value_Uuid)(nil),
+		(*Value_Ip)(nil),
+		(*Value_Bytes)(nil),
 	}
 }
 
@@ -300,39 +368,101 @@ func (m *Object) GetFields() map[string]*Value {
 	return nil
 }
 
+type DecimalValue struct {
+	Unscaled             []byte   `protobuf:"bytes,1,opt,name=unscaled,proto3" json:"unscaled,omitempty"`
+	Negative             bool     `protobuf:"varint,2,opt,name=negative,proto3" json:"negative,omitempty"`
+	Scale                int32    `protobuf:"varint,3,opt,name=scale,proto3" json:"scale,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DecimalValue) Reset()         { *m = DecimalValue{} }
+func (m *DecimalValue) String() string { return proto.CompactTextString(m) }
+func (*DecimalValue) ProtoMessage()    {}
+func (*DecimalValue) Descriptor() ([]byte, []int) {
+	return fileDescriptor_5d19e76c3b90e014, []int{3}
+}
+
+func (m *DecimalValue) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_DecimalValue.Unmarshal(m, b)
+}
+func (m *DecimalValue) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_DecimalValue.Marshal(b, m, deterministic)
+}
+func (m *DecimalValue) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DecimalValue.Merge(m, src)
+}
+func (m *DecimalValue) XXX_Size() int {
+	return xxx_messageInfo_DecimalValue.Size(m)
+}
+func (m *DecimalValue) XXX_DiscardUnknown() {
+	xxx_messageInfo_DecimalValue.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DecimalValue proto.InternalMessageInfo
+
+func (m *DecimalValue) GetUnscaled() []byte {
+	if m != nil {
+		return m.Unscaled
+	}
+	return nil
+}
+
+func (m *DecimalValue) GetNegative() bool {
+	if m != nil {
+		return m.Negative
+	}
+	return false
+}
+
+func (m *DecimalValue) GetScale() int32 {
+	if m != nil {
+		return m.Scale
+	}
+	return 0
+}
+
 func init() {
 	proto.RegisterType((*Value)(nil), "octosql.Value")
 	proto.RegisterType((*Tuple)(nil), "octosql.Tuple")
 	proto.RegisterType((*Object)(nil), "octosql.Object")
 	proto.RegisterMapType((map[string]*Value)(nil), "octosql.Object.FieldsEntry")
+	proto.RegisterType((*DecimalValue)(nil), "octosql.DecimalValue")
 }
 
 func init() { proto.RegisterFile("values.proto", fileDescriptor_5d19e76c3b90e014) }
 
 var fileDescriptor_5d19e76c3b90e014 = []byte{
-	// 369 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x64, 0x51, 0xcd, 0x4a, 0xc3, 0x40,
-	0x10, 0xce, 0x36, 0x4d, 0xd2, 0x4e, 0x45, 0x65, 0x10, 0x59, 0x23, 0x68, 0x28, 0x52, 0xe2, 0x25,
-	0x15, 0x7b, 0x50, 0x3c, 0x8a, 0x4a, 0x3c, 0x09, 0xa1, 0x78, 0x4f, 0xda, 0xb4, 0x46, 0xb7, 0xd9,
-	0x98, 0x6c, 0x84, 0xbe, 0x81, 0x0f, 0xe9, 0xc3, 0xc8, 0xee, 0x26, 0x45, 0xdb, 0xdb, 0xce, 0x7c,
-	0x3f, 0x3b, 0xdf, 0x0c, 0xec, 0x7d, 0xc5, 0xac, 0x4e, 0xab, 0xa0, 0x28, 0xb9, 0xe0, 0xe8, 0xf0,
-	0x99, 0xe0, 0xd5, 0x27, 0x73, 0xcf, 0x97, 0x9c, 0x2f, 0x59, 0x3a, 0x56, 0xed, 0xa4, 0x5e, 0x8c,
-	0x45, 0xb6, 0x4a, 0x2b, 0x11, 0xaf, 0x0a, 0xcd, 0x74, 0xcf, 0xb6, 0x09, 0xf3, 0xba, 0x8c, 0x45,
-	0xc6, 0x73, 0x8d, 0x0f, 0x7f, 0x3a, 0x60, 0xbd, 0x4a, 0x6b, 0x3c, 0x82, 0x6e, 0x5e, 0x33, 0x46,
-	0x89, 0x47, 0xfc, 0x5e, 0x68, 0x44, 0xaa, 0x42, 0x17, 0x9c, 0xe2, 0x2d, 0xce, 0x05, 0x5f, 0xd1,
-	0x4e, 0x03, 0xb4, 0x0d, 0x44, 0x30, 0xb3, 0x5c, 0x50, 0xd3, 0x23, 0xbe, 0x19, 0x1a, 0x91, 0x2c,
-	0xf0, 0x18, 0xac, 0x05, 0xe3, 0xb1, 0xa0, 0x5d, 0x8f, 0xf8, 0x24, 0x34, 0x22, 0x5d, 0x4a, 0xf7,
-	0x84, 0x73, 0x46, 0xad, 0xd6, 0x5d, 0x56, 0x48, 0xc1, 0xae, 0x44, 0x99, 0xe5, 0x4b, 0x6a, 0x7b,
-	0xc4, 0xef, 0x87, 0x46, 0xd4, 0xd4, 0x78, 0x05, 0x5d, 0x19, 0x85, 0x3a, 0x1e, 0xf1, 0x07, 0xd7,
-	0x6e, 0xa0, 0x63, 0x04, 0x6d, 0x8c, 0x60, 0xda, 0xe6, 0x94, 0x5e, 0x92, 0x89, 0x37, 0xd0, 0x6b,
-	0xb3, 0xd1, 0x9e, 0x52, 0x9d, 0xec, 0xa8, 0x1e, 0x1a, 0x42, 0x68, 0x44, 0x1b, 0x32, 0x8e, 0xc0,
-	0x12, 0x75, 0xc1, 0x52, 0xda, 0x57, 0xaa, 0xfd, 0xa0, 0x59, 0x6e, 0x30, 0x95, 0x5d, 0x19, 0x41,
-	0xc1, 0x78, 0x09, 0x36, 0x4f, 0xde, 0xd3, 0x99, 0xa0, 0xa0, 0x88, 0x07, 0x1b, 0xe2, 0x8b, 0x6a,
-	0xcb, 0xe9, 0x35, 0xe1, 0xde, 0x01, 0x4b, 0xdd, 0x6b, 0x38, 0x06, 0x4b, 0xb9, 0xe0, 0x08, 0xec,
-	0x45, 0x96, 0xb2, 0x79, 0x45, 0x89, 0x67, 0xfe, 0xfb, 0x45, 0x6d, 0x3f, 0x6a, 0xd0, 0xe1, 0x37,
-	0x01, 0x5b, 0xdb, 0xe1, 0x64, 0x4b, 0x72, 0xba, 0xf5, 0x5f, 0xf0, 0xa4, 0xd0, 0xc7, 0x5c, 0x94,
-	0xeb, 0x56, 0xef, 0x3e, 0xc3, 0xe0, 0x4f, 0x1b, 0x0f, 0xc1, 0xfc, 0x48, 0xd7, 0xea, 0xa6, 0xfd,
-	0x48, 0x3e, 0xf1, 0xa2, 0x19, 0x4d, 0x9d, 0x73, 0x77, 0x0e, 0x0d, 0xde, 0x75, 0x6e, 0x49, 0x62,
-	0xab, 0xb5, 0x4d, 0x7e, 0x03, 0x00, 0x00, 0xff, 0xff, 0x3e, 0xba, 0x26, 0x88, 0x7b, 0x02, 0x00,
-	0x00,
+	// 476 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x65, 0x52, 0x4d, 0x6f, 0xd3, 0x40,
+	0x10, 0xc5, 0x71, 0xfc, 0x91, 0x89, 0x69, 0xd1, 0x0a, 0xd0, 0xe2, 0x4a, 0x80, 0x22, 0x84, 0xe0,
+	0xe2, 0x14, 0x7a, 0x00, 0x71, 0x44, 0x05, 0xc1, 0x09, 0xc9, 0xaa, 0x38, 0x20, 0x24, 0x64, 0xc7,
+	0x9b, 0xe0, 0xb2, 0xd9, 0x75, 0xe3, 0x75, 0xa5, 0xf0, 0x0b, 0xf8, 0xb1, 0xfc, 0x08, 0x76, 0x66,
+	0x6d, 0xab, 0xa4, 0xb7, 0x7d, 0x6f, 0xde, 0x8c, 0xe6, 0xbd, 0x1d, 0x48, 0xae, 0x0b, 0xd9, 0x89,
+	0x36, 0x6b, 0x76, 0xda, 0x68, 0x16, 0xe9, 0x95, 0xd1, 0xed, 0x95, 0x4c, 0x9f, 0x6c, 0xb4, 0xde,
+	0x48, 0xb1, 0x24, 0xba, 0xec, 0xd6, 0x4b, 0x53, 0x6f, 0x45, 0x6b, 0x8a, 0x6d, 0xe3, 0x94, 0xe9,
+	0xe3, 0x43, 0x41, 0xd5, 0xed, 0x0a, 0x53, 0x6b, 0xe5, 0xea, 0x8b, 0xbf, 0x3e, 0x04, 0x5f, 0x71,
+	0x34, 0xbb, 0x0f, 0x53, 0xd5, 0x49, 0xc9, 0xbd, 0xa7, 0xde, 0x8b, 0xf8, 0xd3, 0x9d, 0x9c, 0x10,
+	0x4b, 0x21, 0x6a, 0x7e, 0x16, 0xca, 0xe8, 0x2d, 0x9f, 0xf4, 0x85, 0x81, 0x60, 0x0c, 0xfc, 0x5a,
+	0x19, 0xee, 0x5b, 0xde, 0xb7, 0x3c, 0x02, 0xf6, 0x10, 0x82, 0xb5, 0xd4, 0x85, 0xe1, 0x53, 0xcb,
+	0x7a, 0x96, 0x75, 0x10, 0xa7, 0x97, 0x5a, 0x4b, 0x1e, 0x0c, 0xd3, 0x11, 0x31, 0x0e, 0x61, 0x6b,
+	0x76, 0xb5, 0xda, 0xf0, 0xd0, 0xf2, 0x33, 0xcb, 0xf7, 0x98, 0x9d, 0xc2, 0x14, 0xad, 0xf0, 0xc8,
+	0xf2, 0xf3, 0xd7, 0x69, 0xe6, 0x6c, 0x64, 0x83, 0x8d, 0xec, 0x62, 0xf0, 0x89, 0xb3, 0x50, 0xc9,
+	0xde, 0x40, 0x3c, 0x78, 0xe3, 0x31, 0x75, 0x3d, 0xba, 0xd5, 0x75, 0xde, 0x0b, 0x6c, 0xd3, 0x28,
+	0x66, 0xcf, 0x21, 0x30, 0x5d, 0x23, 0x05, 0x9f, 0x51, 0xd7, 0x51, 0xd6, 0x87, 0x9b, 0x5d, 0x20,
+	0x8b, 0x16, 0xa8, 0xcc, 0x5e, 0x42, 0xa8, 0xcb, 0x4b, 0xb1, 0x32, 0x1c, 0x48, 0x78, 0x3c, 0x0a,
+	0xbf, 0x10, 0x8d, 0xdb, 0x3b, 0x01, 0x7b, 0x05, 0x51, 0x25, 0x56, 0xf5, 0xb6, 0x90, 0x7c, 0x4e,
+	0xda, 0x07, 0xa3, 0xf6, 0xdc, 0xf1, 0x94, 0x39, 0x86, 0xd9, 0xeb, 0x30, 0xa0, 0xae, 0xab, 0x2b,
+	0x7e, 0xd7, 0xea, 0x13, 0x34, 0x85, 0x88, 0xdd, 0x83, 0x49, 0xdd, 0xf0, 0xa3, 0x9e, 0xb3, 0x6f,
+	0x0c, 0xb8, 0xdc, 0x1b, 0xd1, 0xf2, 0xe3, 0x9e, 0x74, 0x90, 0x9d, 0xc0, 0x0c, 0x63, 0xf8, 0xf1,
+	0x5b, 0x2b, 0xc1, 0x13, 0x4c, 0x33, 0x8f, 0x91, 0xf8, 0x66, 0xf1, 0xfb, 0x08, 0x02, 0xba, 0x9f,
+	0xc5, 0x12, 0x02, 0x72, 0x65, 0x4d, 0x87, 0xeb, 0x5a, 0xc8, 0xaa, 0xb5, 0xff, 0xed, 0xff, 0xe7,
+	0x9a, 0x36, 0xcb, 0xfb, 0xea, 0xe2, 0x8f, 0x07, 0xa1, 0xb3, 0xc7, 0xce, 0x0e, 0x5a, 0x4e, 0x0e,
+	0xfc, 0x67, 0x1f, 0xa9, 0xfa, 0x41, 0x99, 0xdd, 0x7e, 0xe8, 0x4f, 0x3f, 0xc3, 0xfc, 0x06, 0x6d,
+	0xfd, 0xf8, 0xbf, 0xc4, 0x9e, 0x6e, 0x6c, 0x96, 0xe3, 0x93, 0x3d, 0xeb, 0x57, 0xa3, 0xf3, 0xba,
+	0xbd, 0x87, 0x2b, 0xbe, 0x9b, 0xbc, 0xf5, 0x16, 0xdf, 0x21, 0xb9, 0x19, 0x9e, 0x3d, 0xcd, 0xb8,
+	0x53, 0xed, 0xaa, 0x90, 0xa2, 0xa2, 0x81, 0x49, 0x3e, 0x62, 0xac, 0x29, 0xb1, 0xb1, 0xff, 0x7b,
+	0xed, 0x06, 0xc7, 0xf9, 0x88, 0x6d, 0xd2, 0x01, 0xa9, 0xe8, 0x70, 0x83, 0xdc, 0x81, 0x32, 0xa4,
+	0x23, 0x39, 0xfb, 0x07, 0x9c, 0x72, 0xba, 0x9d, 0x69, 0x03, 0x00, 0x00,
 }
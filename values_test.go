@@ -1,8 +1,8 @@
 package octosql
 
 import (
-	"encoding/base32"
 	"math"
+	"net"
 	"testing"
 	"time"
 
@@ -27,7 +27,7 @@ func TestNormalizeType(t *testing.T) {
 				"array": []interface{}{[]interface{}{float32(1), uint8(2), int64(3)}, true},
 			},
 			want: MakeObject(map[string]Value{
-				"name": MakeString(base32.StdEncoding.EncodeToString([]byte("Jakub"))),
+				"name": MakeBytes([]byte("Jakub")),
 				"age":  MakeInt(3),
 				"city": MakeObject(map[string]Value{
 					"name":       MakeString("warsaw"),
@@ -53,6 +53,41 @@ func TestNormalizeType(t *testing.T) {
 	}
 }
 
+func TestTryNormalizeType(t *testing.T) {
+	tests := []struct {
+		name    string
+		value   interface{}
+		want    Value
+		wantErr bool
+	}{
+		{
+			name:  "supported type",
+			value: []interface{}{uint16(3), "warsaw"},
+			want:  MakeTuple([]Value{MakeInt(3), MakeString("warsaw")}),
+		},
+		{
+			name: "unsupported nested type",
+			value: map[string]interface{}{
+				"name":   "Jakub",
+				"scores": []interface{}{1, complex(1, 2)},
+			},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := TryNormalizeType(tt.value)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("TryNormalizeType() error = %v, wantErr %v", err, tt.wantErr)
+				return
+			}
+			if err == nil && !AreEqual(got, tt.want) {
+				t.Errorf("TryNormalizeType() = %s, want %s", got.String(), tt.want.String())
+			}
+		})
+	}
+}
+
 func TestAreEqual(t *testing.T) {
 	type args struct {
 		left  Value
@@ -87,6 +122,14 @@ func TestAreEqual(t *testing.T) {
 			},
 			want: true,
 		},
+		{
+			name: "compare ipv4 addresses in both forms",
+			args: args{
+				left:  MakeIP(net.ParseIP("10.0.0.1")),
+				right: MakeIP(net.ParseIP("10.0.0.1").To4()),
+			},
+			want: true,
+		},
 		{
 			name: "compare tuples",
 			args: args{
@@ -188,6 +231,66 @@ func TestAreEqual(t *testing.T) {
 	}
 }
 
+func TestMakeTime_TimeZone(t *testing.T) {
+	warsaw, err := time.LoadLocation("Europe/Warsaw")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	tests := []struct {
+		name string
+		time time.Time
+		want string
+	}{
+		{
+			name: "utc",
+			time: time.Date(2019, 03, 17, 15, 44, 16, 0, time.UTC),
+			want: "",
+		},
+		{
+			name: "local",
+			time: time.Date(2019, 03, 17, 15, 44, 16, 0, time.Local),
+			want: "",
+		},
+		{
+			name: "explicit utc",
+			time: time.Date(2019, 03, 17, 15, 44, 16, 0, time.FixedZone("UTC", 0)),
+			want: "UTC",
+		},
+		{
+			name: "iana name",
+			time: time.Date(2019, 07, 17, 15, 44, 16, 0, warsaw),
+			want: "Europe/Warsaw",
+		},
+		{
+			name: "offset",
+			time: time.Date(2019, 03, 17, 15, 44, 16, 0, time.FixedZone("", -3600*5-1800)),
+			want: "-05:30",
+		},
+		{
+			name: "unknown name",
+			time: time.Date(2019, 03, 17, 15, 44, 16, 0, time.FixedZone("anything", 3600*5)),
+			want: "+05:00",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			value := MakeTime(tt.time)
+			if value.GetTimeZone() != tt.want {
+				t.Errorf("MakeTime() time zone = %v, want %v", value.GetTimeZone(), tt.want)
+			}
+
+			got := value.AsTime()
+			if !got.Equal(tt.time) {
+				t.Errorf("AsTime() = %v, want %v", got, tt.time)
+			}
+			if tt.want != "" && got.Format(time.RFC3339) != tt.time.Format(time.RFC3339) {
+				t.Errorf("AsTime() = %v, want %v", got.Format(time.RFC3339), tt.time.Format(time.RFC3339))
+			}
+		})
+	}
+}
+
 func TestSerialize(t *testing.T) {
 	tests := []struct {
 		value Value
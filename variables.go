@@ -18,6 +18,24 @@ func NewVariableName(varname string) VariableName {
 	return VariableName(strings.ToLower(varname))
 }
 
+const parameterPrefix = "$"
+
+// NewParameterVariableName creates the name of the variable which holds the value of a query parameter.
+// The name of a named parameter like :name is name, the name of a positional one like $1 or ? is its position.
+func NewParameterVariableName(name string) VariableName {
+	return NewVariableName(parameterPrefix + name)
+}
+
+// IsParameter checks if the variable holds the value of a query parameter.
+func (vn VariableName) IsParameter() bool {
+	return strings.HasPrefix(vn.String(), parameterPrefix)
+}
+
+// ParameterName returns the name of the query parameter held by the variable.
+func (vn VariableName) ParameterName() string {
+	return strings.TrimPrefix(vn.String(), parameterPrefix)
+}
+
 func (vn VariableName) String() string {
 	return string(vn)
 }
@@ -39,6 +57,15 @@ func (vn VariableName) Name() string {
 	return vn.String()[i+1:]
 }
 
+// MatchesColumn checks if the variable is the given column.
+// A column without a qualifier matches the column of that name coming from any source.
+func (vn VariableName) MatchesColumn(column VariableName) bool {
+	if column.Source() == "" {
+		return vn.Name() == column.Name()
+	}
+	return vn == column
+}
+
 func (vn VariableName) Empty() bool {
 	return len(vn) == 0
 }